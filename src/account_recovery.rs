@@ -0,0 +1,198 @@
+//! Guardian-based recovery of a sender's account.
+//!
+//! Savings balances, savings goals, templates, and pending remittances held
+//! inside the contract are tied to the address that owns them, so losing that
+//! key would otherwise strand them. An owner can register up to
+//! [`MAX_RECOVERY_GUARDIANS`] guardians and a threshold in advance. If the key
+//! is lost:
+//!
+//! 1. Guardians approve rotation to a new address. The first approval names
+//!    the new address; later approvals must agree with it.
+//! 2. Once `threshold` guardians have approved, a challenge period of
+//!    [`ACCOUNT_RECOVERY_CHALLENGE_SECONDS`] starts. The owner can cancel the
+//!    recovery at any time before it executes, so guardians colluding against
+//!    an owner who still holds the key are stopped.
+//! 3. After the challenge period the new address executes the recovery and
+//!    takes over the owner's internal state.
+//!
+//! Pending remittances are not indexed by sender, so the new address lists the
+//! ones to migrate, at most [`MAX_MIGRATION_BATCH_SIZE`] per call.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::config::{
+    ACCOUNT_RECOVERY_CHALLENGE_SECONDS, MAX_MIGRATION_BATCH_SIZE, MAX_RECOVERY_GUARDIANS,
+};
+use crate::storage::{
+    get_account_recovery, get_all_whitelisted_tokens, get_owner_savings_goals,
+    get_recovery_guardians, get_remittance, get_remittance_template, get_savings_balance,
+    get_savings_goal, get_savings_share, get_sender_templates, remove_account_recovery,
+    remove_recovery_guardians, set_account_recovery, set_owner_savings_goals,
+    set_payout_commitment, set_recovery_guardians, set_remittance, set_remittance_template,
+    set_savings_balance, set_savings_goal, set_savings_share, set_sender_templates,
+};
+use crate::verification::compute_payout_commitment;
+use crate::{ContractError, RemittanceStatus};
+
+/// An owner's recovery guardians.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryGuardians {
+    pub guardians: Vec<Address>,
+    /// Approvals needed to start a recovery
+    pub threshold: u32,
+}
+
+/// A recovery in progress.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountRecovery {
+    pub new_owner: Address,
+    pub approvals: Vec<Address>,
+    /// Set once `threshold` guardians have approved
+    pub executable_at: Option<u64>,
+}
+
+/// Validates and stores `owner`'s guardians, discarding any recovery in progress.
+pub fn set_guardians(
+    env: &Env,
+    owner: &Address,
+    guardians: Vec<Address>,
+    threshold: u32,
+) -> Result<(), ContractError> {
+    if guardians.is_empty() {
+        remove_recovery_guardians(env, owner);
+        remove_account_recovery(env, owner);
+        return Ok(());
+    }
+    if guardians.len() > MAX_RECOVERY_GUARDIANS || threshold == 0 || threshold > guardians.len() {
+        return Err(ContractError::InvalidRecoveryGuardians);
+    }
+    for (i, guardian) in guardians.iter().enumerate() {
+        if guardian == *owner || guardians.iter().take(i).any(|g| g == guardian) {
+            return Err(ContractError::InvalidRecoveryGuardians);
+        }
+    }
+    set_recovery_guardians(env, owner, &RecoveryGuardians { guardians, threshold });
+    remove_account_recovery(env, owner);
+    Ok(())
+}
+
+/// Records `guardian`'s approval of rotating `owner` to `new_owner`.
+///
+/// Returns the end of the challenge period if this approval met the threshold.
+pub fn approve(
+    env: &Env,
+    guardian: &Address,
+    owner: &Address,
+    new_owner: &Address,
+) -> Result<Option<u64>, ContractError> {
+    let config = get_recovery_guardians(env, owner).ok_or(ContractError::RecoveryGuardiansNotSet)?;
+    if !config.guardians.contains(guardian) {
+        return Err(ContractError::Unauthorized);
+    }
+    if new_owner == owner {
+        return Err(ContractError::InvalidAddress);
+    }
+    let mut recovery = get_account_recovery(env, owner).unwrap_or(AccountRecovery {
+        new_owner: new_owner.clone(),
+        approvals: Vec::new(env),
+        executable_at: None,
+    });
+    if recovery.new_owner != *new_owner {
+        return Err(ContractError::InvalidAddress);
+    }
+    if recovery.approvals.contains(guardian) {
+        return Err(ContractError::Unauthorized);
+    }
+    recovery.approvals.push_back(guardian.clone());
+    let mut armed = None;
+    if recovery.executable_at.is_none() && recovery.approvals.len() >= config.threshold {
+        armed = Some(
+            env.ledger()
+                .timestamp()
+                .saturating_add(ACCOUNT_RECOVERY_CHALLENGE_SECONDS),
+        );
+        recovery.executable_at = armed;
+    }
+    set_account_recovery(env, owner, &recovery);
+    Ok(armed)
+}
+
+/// Moves `owner`'s savings, goals, templates, guardians, and the listed
+/// pending remittances to the recovery's new owner.
+///
+/// Returns the new owner and the IDs of the remittances migrated.
+pub fn execute(
+    env: &Env,
+    owner: &Address,
+    remittance_ids: &Vec<u64>,
+) -> Result<(Address, Vec<u64>), ContractError> {
+    if remittance_ids.len() > MAX_MIGRATION_BATCH_SIZE {
+        return Err(ContractError::InvalidAmount);
+    }
+    let recovery = get_account_recovery(env, owner).ok_or(ContractError::AccountRecoveryNotFound)?;
+    match recovery.executable_at {
+        Some(at) if env.ledger().timestamp() >= at => {}
+        _ => return Err(ContractError::AccountRecoveryNotReady),
+    }
+    let new_owner = recovery.new_owner;
+    new_owner.require_auth();
+
+    for token in get_all_whitelisted_tokens(env).iter() {
+        let balance = get_savings_balance(env, owner, &token);
+        if balance > 0 {
+            let merged = get_savings_balance(env, &new_owner, &token)
+                .checked_add(balance)
+                .ok_or(ContractError::Overflow)?;
+            set_savings_balance(env, &new_owner, &token, merged);
+            set_savings_balance(env, owner, &token, 0);
+        }
+    }
+    let share = get_savings_share(env, owner);
+    if share > 0 {
+        set_savings_share(env, &new_owner, share);
+        set_savings_share(env, owner, 0);
+    }
+
+    let mut goals = get_owner_savings_goals(env, &new_owner);
+    for id in get_owner_savings_goals(env, owner).iter() {
+        if let Some(mut goal) = get_savings_goal(env, id) {
+            goal.owner = new_owner.clone();
+            set_savings_goal(env, &goal);
+            goals.push_back(id);
+        }
+    }
+    set_owner_savings_goals(env, &new_owner, &goals);
+    set_owner_savings_goals(env, owner, &Vec::new(env));
+
+    let mut templates = get_sender_templates(env, &new_owner);
+    for id in get_sender_templates(env, owner).iter() {
+        if let Some(mut template) = get_remittance_template(env, id) {
+            template.owner = new_owner.clone();
+            set_remittance_template(env, &template);
+            templates.push_back(id);
+        }
+    }
+    set_sender_templates(env, &new_owner, &templates);
+    set_sender_templates(env, owner, &Vec::new(env));
+
+    let mut migrated = Vec::new(env);
+    for id in remittance_ids.iter() {
+        let mut remittance = get_remittance(env, id)?;
+        if remittance.sender != *owner || remittance.status != RemittanceStatus::Pending {
+            continue;
+        }
+        remittance.sender = new_owner.clone();
+        set_remittance(env, id, &remittance);
+        set_payout_commitment(env, id, &compute_payout_commitment(env, &remittance));
+        migrated.push_back(id);
+    }
+
+    if let Some(config) = get_recovery_guardians(env, owner) {
+        set_recovery_guardians(env, &new_owner, &config);
+        remove_recovery_guardians(env, owner);
+    }
+    remove_account_recovery(env, owner);
+    Ok((new_owner, migrated))
+}
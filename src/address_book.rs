@@ -0,0 +1,72 @@
+//! Per-sender address books.
+//!
+//! A sender can save the recipients and agents they pay regularly under
+//! aliases of their choosing. Only a hash of each alias is stored (the wallet
+//! hashes the human-readable name client-side), entries live under the
+//! sender's own key space, and the contract's getters return them only to the
+//! authenticated owner. No events are emitted, so changes do not surface in
+//! indexer feeds. A book holds at most [`MAX_ADDRESS_BOOK_ENTRIES`] entries.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
+
+use crate::config::MAX_ADDRESS_BOOK_ENTRIES;
+use crate::storage::{
+    get_address_book_aliases, get_address_book_entry, remove_address_book_entry,
+    set_address_book_aliases, set_address_book_entry,
+};
+use crate::ContractError;
+
+/// What kind of counterparty an address book entry points at.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ContactKind {
+    Recipient,
+    Agent,
+}
+
+/// One saved contact.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressBookEntry {
+    /// Hash of the owner's alias for this contact
+    pub alias_hash: BytesN<32>,
+    pub address: Address,
+    pub kind: ContactKind,
+}
+
+/// Adds or replaces the entry stored under `entry.alias_hash`.
+pub fn put(env: &Env, owner: &Address, entry: &AddressBookEntry) -> Result<(), ContractError> {
+    let mut aliases = get_address_book_aliases(env, owner);
+    if !aliases.contains(&entry.alias_hash) {
+        if aliases.len() >= MAX_ADDRESS_BOOK_ENTRIES {
+            return Err(ContractError::AddressBookFull);
+        }
+        aliases.push_back(entry.alias_hash.clone());
+        set_address_book_aliases(env, owner, &aliases);
+    }
+    set_address_book_entry(env, owner, entry);
+    Ok(())
+}
+
+/// Removes the entry stored under `alias_hash`.
+pub fn remove(env: &Env, owner: &Address, alias_hash: &BytesN<32>) -> Result<(), ContractError> {
+    let mut aliases = get_address_book_aliases(env, owner);
+    let index = aliases
+        .first_index_of(alias_hash)
+        .ok_or(ContractError::AddressBookEntryNotFound)?;
+    aliases.remove(index);
+    set_address_book_aliases(env, owner, &aliases);
+    remove_address_book_entry(env, owner, alias_hash);
+    Ok(())
+}
+
+/// Returns all of an owner's entries in the order their aliases were added.
+pub fn list(env: &Env, owner: &Address) -> Vec<AddressBookEntry> {
+    let mut entries = Vec::new(env);
+    for alias_hash in get_address_book_aliases(env, owner).iter() {
+        if let Some(entry) = get_address_book_entry(env, owner, &alias_hash) {
+            entries.push_back(entry);
+        }
+    }
+    entries
+}
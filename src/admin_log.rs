@@ -0,0 +1,76 @@
+//! On-chain audit log of privileged calls.
+//!
+//! Every admin-gated entry point appends an [`AdminLogEntry`] naming the
+//! caller, the entry point, a SHA-256 hash of its arguments, and the time.
+//! Entries are numbered from 0 and only the latest [`ADMIN_LOG_CAPACITY`] are
+//! kept, so governance reviews can read recent admin activity from contract
+//! state with `get_admin_log` rather than depending on historical RPC
+//! event retention. Reviewers recompute the hash from the arguments they
+//! expect to confirm what was actually passed.
+//!
+//! A failed call is rolled back together with its entry, so the log only
+//! holds actions that took effect.
+
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::config::ADMIN_LOG_CAPACITY;
+use crate::storage::{
+    get_admin_log_entry, get_admin_log_len, paginate_keyed, remove_admin_log_entry,
+    set_admin_log_entry, set_admin_log_len, Page,
+};
+
+/// One privileged call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminLogEntry {
+    pub seq: u64,
+    pub caller: Address,
+    /// Entry point that was called
+    pub action: Symbol,
+    /// SHA-256 of the XDR-encoded argument tuple, excluding the caller
+    pub params_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+/// One page of the admin log, oldest entry first.
+///
+/// Follows the shared [`Page`] cursor convention; cursors are entry
+/// sequence numbers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminLogPage {
+    pub items: Vec<AdminLogEntry>,
+    pub next_cursor: u32,
+    pub has_more: bool,
+}
+
+/// Appends a privileged call to the log, evicting the oldest entry once the
+/// log is full.
+pub fn record<T: IntoVal<Env, Val>>(env: &Env, caller: &Address, action: &str, params: T) {
+    let seq = get_admin_log_len(env);
+    set_admin_log_entry(
+        env,
+        &AdminLogEntry {
+            seq,
+            caller: caller.clone(),
+            action: Symbol::new(env, action),
+            params_hash: env.crypto().sha256(&params.to_xdr(env)).to_bytes(),
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    if seq >= ADMIN_LOG_CAPACITY {
+        remove_admin_log_entry(env, seq - ADMIN_LOG_CAPACITY);
+    }
+    set_admin_log_len(env, seq + 1);
+}
+
+/// Returns up to `limit` entries starting at sequence number `cursor`.
+///
+/// A cursor pointing at an evicted entry starts at the oldest one retained.
+pub fn page(env: &Env, cursor: u32, limit: u32) -> Page<AdminLogEntry> {
+    let len = u32::try_from(get_admin_log_len(env)).unwrap_or(u32::MAX);
+    let oldest = len.saturating_sub(ADMIN_LOG_CAPACITY as u32);
+    paginate_keyed(env, len, cursor.max(oldest), limit, |seq| {
+        get_admin_log_entry(env, seq.into())
+    })
+}
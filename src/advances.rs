@@ -0,0 +1,220 @@
+//! Working-capital advances for agents.
+//!
+//! Agents front cash to recipients before their settlements clear. To ease
+//! that, an agent with at least [`ADVANCE_MIN_REPUTATION`] that has locked a
+//! stake in a token may draw an advance from the per-token advance pool the
+//! admin funds. The advance is collateralised by the agent's own unsettled
+//! (Pending or Processing) remittances in that token and bounded by both
+//! [`ADVANCE_MAX_LTV_BPS`] of their value and [`ADVANCE_STAKE_MULTIPLIER`]
+//! times the stake.
+//!
+//! While an advance is outstanding, every direct payout to the agent in that
+//! token is withheld towards repayment and returned to the pool. An advance
+//! still outstanding [`ADVANCE_TERM_SECONDS`] after it was drawn is in default
+//! and can be liquidated against the agent's stake; any shortfall beyond the
+//! stake is written off. An agent holds at most one advance per token and
+//! cannot unstake in a token while it has one there.
+
+use soroban_sdk::{contracttype, token, Address, Env, Vec};
+
+use crate::config::{
+    ADVANCE_MAX_LTV_BPS, ADVANCE_MIN_REPUTATION, ADVANCE_STAKE_MULTIPLIER, ADVANCE_TERM_SECONDS,
+    FEE_DIVISOR,
+};
+use crate::storage::{
+    compute_agent_reputation, get_advance_pool, get_agent_advance, get_agent_stake,
+    get_agent_stats, get_remittance, remove_agent_advance, set_advance_pool, set_agent_advance,
+    set_agent_stake,
+};
+use crate::token_transfer;
+use crate::{ContractError, RemittanceStatus};
+
+/// An agent's outstanding advance in one token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentAdvance {
+    /// Amount originally drawn
+    pub principal: i128,
+    /// Amount still to be repaid
+    pub outstanding: i128,
+    /// Remittances whose value backed the advance when it was drawn
+    pub collateral: Vec<u64>,
+    pub drawn_at: u64,
+    /// Timestamp after which the advance is in default
+    pub due_at: u64,
+}
+
+/// Locks `amount` of the agent's tokens as stake. Returns the new stake.
+pub fn stake(env: &Env, agent: &Address, token: &Address, amount: i128) -> Result<i128, ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    token_transfer::transfer(
+        &token::Client::new(env, token),
+        agent,
+        &env.current_contract_address(),
+        amount,
+    )?;
+    let staked = get_agent_stake(env, agent, token)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    set_agent_stake(env, agent, token, staked);
+    Ok(staked)
+}
+
+/// Returns `amount` of stake to the agent. Returns the remaining stake.
+pub fn unstake(env: &Env, agent: &Address, token: &Address, amount: i128) -> Result<i128, ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    if get_agent_advance(env, agent, token).is_some() {
+        return Err(ContractError::AdvanceOutstanding);
+    }
+    let staked = get_agent_stake(env, agent, token);
+    if amount > staked {
+        return Err(ContractError::InvalidAmount);
+    }
+    set_agent_stake(env, agent, token, staked - amount);
+    token_transfer::transfer(
+        &token::Client::new(env, token),
+        &env.current_contract_address(),
+        agent,
+        amount,
+    )?;
+    Ok(staked - amount)
+}
+
+/// Maximum advance the agent could draw against `collateral` right now.
+pub fn limit(
+    env: &Env,
+    agent: &Address,
+    token: &Address,
+    collateral: &Vec<u64>,
+) -> Result<i128, ContractError> {
+    if compute_agent_reputation(&get_agent_stats(env, agent)) < ADVANCE_MIN_REPUTATION {
+        return Err(ContractError::AdvanceNotEligible);
+    }
+    let staked = get_agent_stake(env, agent, token);
+    if staked <= 0 {
+        return Err(ContractError::AdvanceNotEligible);
+    }
+
+    let mut seen: Vec<u64> = Vec::new(env);
+    let mut value: i128 = 0;
+    for id in collateral.iter() {
+        if seen.contains(id) {
+            return Err(ContractError::InvalidAmount);
+        }
+        seen.push_back(id);
+        let remittance = get_remittance(env, id)?;
+        if remittance.agent != *agent || remittance.token != *token {
+            return Err(ContractError::Unauthorized);
+        }
+        if !matches!(
+            remittance.status,
+            RemittanceStatus::Pending | RemittanceStatus::Processing
+        ) {
+            return Err(ContractError::InvalidStatus);
+        }
+        value = value
+            .checked_add(remittance.amount)
+            .ok_or(ContractError::Overflow)?;
+    }
+
+    let by_collateral = value
+        .checked_mul(ADVANCE_MAX_LTV_BPS as i128)
+        .ok_or(ContractError::Overflow)?
+        / FEE_DIVISOR;
+    let by_stake = staked
+        .checked_mul(ADVANCE_STAKE_MULTIPLIER)
+        .ok_or(ContractError::Overflow)?;
+    Ok(by_collateral.min(by_stake))
+}
+
+/// Pays an advance of `amount` from the pool to the agent.
+pub fn draw(
+    env: &Env,
+    agent: &Address,
+    token: &Address,
+    collateral: Vec<u64>,
+    amount: i128,
+) -> Result<AgentAdvance, ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    if get_agent_advance(env, agent, token).is_some() {
+        return Err(ContractError::AdvanceOutstanding);
+    }
+    if amount > limit(env, agent, token, &collateral)? {
+        return Err(ContractError::AdvanceLimitExceeded);
+    }
+    let pool = get_advance_pool(env, token);
+    if amount > pool {
+        return Err(ContractError::InsufficientAdvanceLiquidity);
+    }
+
+    set_advance_pool(env, token, pool - amount);
+    let now = env.ledger().timestamp();
+    let advance = AgentAdvance {
+        principal: amount,
+        outstanding: amount,
+        collateral,
+        drawn_at: now,
+        due_at: now.saturating_add(ADVANCE_TERM_SECONDS),
+    };
+    set_agent_advance(env, agent, token, &advance);
+    token_transfer::transfer(
+        &token::Client::new(env, token),
+        &env.current_contract_address(),
+        agent,
+        amount,
+    )?;
+    Ok(advance)
+}
+
+/// Withholds up to `payout` towards the agent's outstanding advance in `token`.
+///
+/// Returns the amount withheld, which the caller must deduct from the payout.
+pub fn repay(env: &Env, agent: &Address, token: &Address, payout: i128) -> Result<i128, ContractError> {
+    let Some(mut advance) = get_agent_advance(env, agent, token) else {
+        return Ok(0);
+    };
+    let withheld = advance.outstanding.min(payout).max(0);
+    if withheld == 0 {
+        return Ok(0);
+    }
+
+    let pool = get_advance_pool(env, token)
+        .checked_add(withheld)
+        .ok_or(ContractError::Overflow)?;
+    set_advance_pool(env, token, pool);
+    advance.outstanding -= withheld;
+    if advance.outstanding == 0 {
+        remove_agent_advance(env, agent, token);
+    } else {
+        set_agent_advance(env, agent, token, &advance);
+    }
+    crate::events::emit_advance_repaid(env, agent.clone(), token.clone(), withheld, advance.outstanding);
+    Ok(withheld)
+}
+
+/// Seizes the stake of an agent whose advance is past due.
+///
+/// Returns the amount seized into the pool. The advance is closed even if the
+/// stake does not cover it.
+pub fn liquidate(env: &Env, agent: &Address, token: &Address) -> Result<i128, ContractError> {
+    let advance = get_agent_advance(env, agent, token).ok_or(ContractError::AdvanceNotFound)?;
+    if env.ledger().timestamp() < advance.due_at {
+        return Err(ContractError::AdvanceNotDue);
+    }
+
+    let staked = get_agent_stake(env, agent, token);
+    let seized = staked.min(advance.outstanding);
+    set_agent_stake(env, agent, token, staked - seized);
+    let pool = get_advance_pool(env, token)
+        .checked_add(seized)
+        .ok_or(ContractError::Overflow)?;
+    set_advance_pool(env, token, pool);
+    remove_agent_advance(env, agent, token);
+    Ok(seized)
+}
@@ -0,0 +1,212 @@
+//! Agent tiers and the privileges attached to them.
+//!
+//! The admin places every agent in one of three tiers. The tier bounds how
+//! much value the agent may hold in `Pending` remittances at once, decides
+//! whether remittances at or above [`LARGE_REMITTANCE_THRESHOLD`] may be
+//! assigned to it, and carries the agent's open-pool claim access and
+//! commission share. Agents that were never assigned a tier are Basic.
+//!
+//! Independently of tier, agents whose business verification (KYB) has not
+//! been recorded are held to [`UNVERIFIED_AGENT_MAX_REMITTANCE`] per
+//! remittance and [`UNVERIFIED_AGENT_DAILY_LIMIT`] per day of assignments.
+//!
+//! The admin can also cap an individual agent's pending value with
+//! `set_agent_capacity`, for example to match the liquidity it has shown;
+//! the cap applies alongside the tier's own bound.
+//!
+//! Pending value is tracked per remittance, so releasing it when a remittance
+//! leaves `Pending` is idempotent and safe to call from every exit path.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::config::{
+    BASIC_TIER_COMMISSION_SHARE_BPS, BASIC_TIER_MAX_PENDING_VALUE, DAILY_LIMIT_WINDOW_SECONDS,
+    LARGE_REMITTANCE_THRESHOLD, PREMIER_TIER_COMMISSION_SHARE_BPS,
+    PREMIER_TIER_MAX_PENDING_VALUE, UNVERIFIED_AGENT_DAILY_LIMIT,
+    UNVERIFIED_AGENT_MAX_REMITTANCE, VERIFIED_TIER_COMMISSION_SHARE_BPS,
+    VERIFIED_TIER_MAX_PENDING_VALUE,
+};
+use crate::storage::{
+    get_agent_capacity, get_agent_pending_amount, get_agent_pending_value, get_agent_tier,
+    get_unverified_agent_volume, is_agent_kyb_verified, remove_agent_pending_amount,
+    set_agent_pending_amount, set_agent_pending_value, set_unverified_agent_volume,
+};
+use crate::{ContractError, Remittance};
+
+/// Agent tier, set by the admin.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AgentTier {
+    Basic,
+    Verified,
+    Premier,
+}
+
+/// Privileges granted by an agent tier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentTierPrivileges {
+    pub tier: AgentTier,
+    /// Maximum total value of Pending remittances assigned to the agent
+    pub max_pending_value: i128,
+    /// Whether the agent may claim remittances from the open pool
+    pub open_pool_access: bool,
+    /// Share of the platform fee credited to the agent, in basis points
+    pub commission_share_bps: u32,
+    /// Whether remittances at or above `LARGE_REMITTANCE_THRESHOLD` may be assigned
+    pub large_remittances: bool,
+}
+
+impl AgentTier {
+    pub fn privileges(self) -> AgentTierPrivileges {
+        let (max_pending_value, commission_share_bps) = match self {
+            AgentTier::Basic => (BASIC_TIER_MAX_PENDING_VALUE, BASIC_TIER_COMMISSION_SHARE_BPS),
+            AgentTier::Verified => (
+                VERIFIED_TIER_MAX_PENDING_VALUE,
+                VERIFIED_TIER_COMMISSION_SHARE_BPS,
+            ),
+            AgentTier::Premier => (
+                PREMIER_TIER_MAX_PENDING_VALUE,
+                PREMIER_TIER_COMMISSION_SHARE_BPS,
+            ),
+        };
+        AgentTierPrivileges {
+            tier: self,
+            max_pending_value,
+            open_pool_access: self != AgentTier::Basic,
+            commission_share_bps,
+            large_remittances: self != AgentTier::Basic,
+        }
+    }
+}
+
+/// Returns the privileges of an agent's current tier.
+pub fn privileges(env: &Env, agent: &Address) -> AgentTierPrivileges {
+    get_agent_tier(env, agent).privileges()
+}
+
+/// Checks that an agent's tier and verification status allow it to take on
+/// a remittance of `amount`.
+pub fn check_assignment(env: &Env, agent: &Address, amount: i128) -> Result<(), ContractError> {
+    if !is_agent_kyb_verified(env, agent) {
+        let assigned_today = unverified_volume_today(env, agent)
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        if amount > UNVERIFIED_AGENT_MAX_REMITTANCE || assigned_today > UNVERIFIED_AGENT_DAILY_LIMIT {
+            return Err(ContractError::UnverifiedAgentLimitExceeded);
+        }
+    }
+
+    let privileges = privileges(env, agent);
+    if amount >= LARGE_REMITTANCE_THRESHOLD && !privileges.large_remittances {
+        return Err(ContractError::AgentTierTooLow);
+    }
+    let pending = get_agent_pending_value(env, agent)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    if get_agent_capacity(env, agent).is_some_and(|cap| pending > cap) {
+        return Err(ContractError::AgentCapacityExceeded);
+    }
+    if pending > privileges.max_pending_value {
+        return Err(ContractError::AgentPendingLimitExceeded);
+    }
+    Ok(())
+}
+
+/// Checks the assigned agent's tier and counts a new Pending remittance
+/// towards its pending value.
+pub fn track_pending(env: &Env, remittance: &Remittance) -> Result<(), ContractError> {
+    check_assignment(env, &remittance.agent, remittance.amount)?;
+    let pending = get_agent_pending_value(env, &remittance.agent)
+        .checked_add(remittance.amount)
+        .ok_or(ContractError::Overflow)?;
+    set_agent_pending_value(env, &remittance.agent, pending);
+    set_agent_pending_amount(env, remittance.id, remittance.amount);
+
+    if !is_agent_kyb_verified(env, &remittance.agent) {
+        let assigned_today = unverified_volume_today(env, &remittance.agent)
+            .checked_add(remittance.amount)
+            .ok_or(ContractError::Overflow)?;
+        set_unverified_agent_volume(env, &remittance.agent, current_day(env), assigned_today);
+    }
+    Ok(())
+}
+
+fn current_day(env: &Env) -> u64 {
+    env.ledger().timestamp() / DAILY_LIMIT_WINDOW_SECONDS
+}
+
+/// Value assigned to an unverified agent so far in the current day.
+fn unverified_volume_today(env: &Env, agent: &Address) -> i128 {
+    let (day, value) = get_unverified_agent_volume(env, agent);
+    if day == current_day(env) {
+        value
+    } else {
+        0
+    }
+}
+
+/// Removes a remittance from its agent's pending value once it leaves `Pending`
+/// or is handed to another agent.
+///
+/// No-op for remittances that were never tracked or were already released.
+pub fn release_pending(env: &Env, remittance: &Remittance) {
+    let Some(amount) = get_agent_pending_amount(env, remittance.id) else {
+        return;
+    };
+    let pending = get_agent_pending_value(env, &remittance.agent)
+        .saturating_sub(amount)
+        .max(0);
+    set_agent_pending_value(env, &remittance.agent, pending);
+    remove_agent_pending_amount(env, remittance.id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_tier_is_most_restricted() {
+        let basic = AgentTier::Basic.privileges();
+        assert!(!basic.open_pool_access);
+        assert!(!basic.large_remittances);
+        assert_eq!(basic.max_pending_value, BASIC_TIER_MAX_PENDING_VALUE);
+    }
+
+    #[test]
+    fn test_privileges_grow_with_tier() {
+        let basic = AgentTier::Basic.privileges();
+        let verified = AgentTier::Verified.privileges();
+        let premier = AgentTier::Premier.privileges();
+        assert!(verified.large_remittances && premier.large_remittances);
+        assert!(verified.open_pool_access && premier.open_pool_access);
+        assert!(basic.max_pending_value < verified.max_pending_value);
+        assert!(verified.max_pending_value < premier.max_pending_value);
+        assert!(basic.commission_share_bps < premier.commission_share_bps);
+    }
+
+    #[test]
+    fn test_unverified_agent_limits() {
+        use soroban_sdk::testutils::Address as _;
+
+        let env = Env::default();
+        let contract = env.register(crate::SwiftRemitContract, ());
+        let agent = Address::generate(&env);
+        env.as_contract(&contract, || {
+            assert_eq!(
+                check_assignment(&env, &agent, UNVERIFIED_AGENT_MAX_REMITTANCE + 1),
+                Err(ContractError::UnverifiedAgentLimitExceeded)
+            );
+            assert!(check_assignment(&env, &agent, UNVERIFIED_AGENT_MAX_REMITTANCE).is_ok());
+
+            set_unverified_agent_volume(&env, &agent, current_day(&env), UNVERIFIED_AGENT_DAILY_LIMIT);
+            assert_eq!(
+                check_assignment(&env, &agent, 1),
+                Err(ContractError::UnverifiedAgentLimitExceeded)
+            );
+
+            crate::storage::set_agent_kyb_verified(&env, &agent, true);
+            assert!(check_assignment(&env, &agent, UNVERIFIED_AGENT_MAX_REMITTANCE + 1).is_ok());
+        });
+    }
+}
@@ -0,0 +1,121 @@
+//! Commit–reveal amounts for privacy-sensitive transfers.
+//!
+//! A private remittance records only a commitment to its amount. The sender
+//! escrows a deposit of at least the amount (typically rounded up, so the
+//! token transfer does not disclose the exact figure) and submits the
+//! commitment; the amount and salt are shared with the agent off-chain. At
+//! settlement the agent reveals both, the contract checks them against the
+//! commitment, pays the agent the amount minus fees, and refunds the rest of
+//! the deposit to the sender. The exact amount therefore appears on-chain only
+//! once, at settlement.
+//!
+//! ## Commitment (canonical)
+//!
+//! `sha256(AMOUNT_COMMITMENT_DOMAIN || amount || salt)`, concatenated without
+//! separators, where `amount` is the i128 amount as 16 big-endian bytes and
+//! `salt` is 32 random bytes chosen by the sender.
+
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env};
+
+use crate::storage::{
+    get_private_remittance, get_private_remittance_counter, set_private_remittance,
+    set_private_remittance_counter,
+};
+use crate::{ContractError, RemittanceStatus};
+
+/// Domain separation tag prefixed to every amount commitment.
+pub const AMOUNT_COMMITMENT_DOMAIN: &[u8] = b"SWIFTREMIT_AMOUNT_V1";
+
+/// A remittance whose amount is hidden behind a commitment until settlement.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrivateRemittance {
+    pub id: u64,
+    pub sender: Address,
+    pub agent: Address,
+    pub token: Address,
+    /// Amount escrowed by the sender, at least the committed amount
+    pub deposit: i128,
+    pub commitment: BytesN<32>,
+    pub status: RemittanceStatus,
+    pub created_at: u64,
+    /// Amount revealed at settlement
+    pub amount: Option<i128>,
+}
+
+/// Computes the commitment to `amount` under `salt`.
+pub fn commit(env: &Env, amount: i128, salt: &BytesN<32>) -> BytesN<32> {
+    let mut buf = Bytes::from_slice(env, AMOUNT_COMMITMENT_DOMAIN);
+    buf.extend_from_array(&amount.to_be_bytes());
+    buf.extend_from_array(&salt.to_array());
+    env.crypto().sha256(&buf).into()
+}
+
+/// Records a new Pending private remittance whose deposit is already escrowed.
+pub fn create(
+    env: &Env,
+    sender: &Address,
+    agent: &Address,
+    token: &Address,
+    deposit: i128,
+    commitment: BytesN<32>,
+) -> Result<PrivateRemittance, ContractError> {
+    let id = get_private_remittance_counter(env)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    let remittance = PrivateRemittance {
+        id,
+        sender: sender.clone(),
+        agent: agent.clone(),
+        token: token.clone(),
+        deposit,
+        commitment,
+        status: RemittanceStatus::Pending,
+        created_at: env.ledger().timestamp(),
+        amount: None,
+    };
+    set_private_remittance(env, &remittance);
+    set_private_remittance_counter(env, id);
+    Ok(remittance)
+}
+
+/// Loads a private remittance that is still Pending.
+pub fn pending(env: &Env, id: u64) -> Result<PrivateRemittance, ContractError> {
+    let remittance = get_private_remittance(env, id).ok_or(ContractError::RemittanceNotFound)?;
+    if remittance.status != RemittanceStatus::Pending {
+        return Err(ContractError::InvalidStatus);
+    }
+    Ok(remittance)
+}
+
+/// Checks a revealed amount against the remittance's commitment and deposit.
+pub fn verify_reveal(
+    env: &Env,
+    remittance: &PrivateRemittance,
+    amount: i128,
+    salt: &BytesN<32>,
+) -> Result<(), ContractError> {
+    if commit(env, amount, salt) != remittance.commitment {
+        return Err(ContractError::AmountCommitmentMismatch);
+    }
+    if amount <= 0 || amount > remittance.deposit {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commitment_binds_amount_and_salt() {
+        let env = Env::default();
+        let salt = BytesN::from_array(&env, &[7u8; 32]);
+        let other_salt = BytesN::from_array(&env, &[8u8; 32]);
+        let commitment = commit(&env, 1_000, &salt);
+        assert_eq!(commitment, commit(&env, 1_000, &salt));
+        assert_ne!(commitment, commit(&env, 1_001, &salt));
+        assert_ne!(commitment, commit(&env, 1_000, &other_salt));
+    }
+}
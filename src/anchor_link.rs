@@ -0,0 +1,107 @@
+//! Linkage between remittances and SEP-31 anchor transactions.
+//!
+//! In hybrid flows the on-chain remittance settles with a receiving anchor
+//! that delivers fiat off-chain. An address holding `Role::AnchorOperator`
+//! links the remittance to the anchor's transaction ID with `link_anchor_tx`
+//! and relays each SEP-31 status change with `update_anchor_status`.
+//!
+//! The latest status is kept on the [`AnchorLink`], and every change emits an
+//! event, so auditors can follow the fiat leg from contract state and event
+//! history without access to the anchor. A link is permanent: it survives
+//! the remittance reaching a terminal status, and a transaction ID can only
+//! be linked to one remittance.
+
+use soroban_sdk::{contracttype, Address, Env, String};
+
+use crate::storage::{get_anchor_link, get_anchor_tx_remittance, set_anchor_link, set_anchor_tx_remittance};
+use crate::ContractError;
+
+/// SEP-31 transaction status reported by the receiving anchor.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AnchorTxStatus {
+    PendingSender,
+    PendingStellar,
+    PendingCustomerInfoUpdate,
+    PendingReceiver,
+    PendingExternal,
+    Completed,
+    Refunded,
+    Expired,
+    /// SEP-31 `error`
+    Failed,
+}
+
+impl AnchorTxStatus {
+    /// Whether the anchor transaction can no longer change status.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            AnchorTxStatus::Completed | AnchorTxStatus::Refunded | AnchorTxStatus::Expired
+        )
+    }
+}
+
+/// A remittance's anchor transaction and its last reported status.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnchorLink {
+    /// Stellar account of the receiving anchor
+    pub anchor: Address,
+    /// The anchor's SEP-31 transaction ID
+    pub tx_id: String,
+    pub status: AnchorTxStatus,
+    pub linked_at: u64,
+    pub updated_at: u64,
+}
+
+/// Links `remittance_id` to the anchor transaction `tx_id`.
+///
+/// # Errors
+///
+/// * `ContractError::AnchorTxAlreadyLinked` - The remittance or `tx_id` is already linked
+pub fn link(
+    env: &Env,
+    remittance_id: u64,
+    anchor: Address,
+    tx_id: String,
+) -> Result<AnchorLink, ContractError> {
+    if get_anchor_link(env, remittance_id).is_some()
+        || get_anchor_tx_remittance(env, &tx_id).is_some()
+    {
+        return Err(ContractError::AnchorTxAlreadyLinked);
+    }
+
+    let now = env.ledger().timestamp();
+    let link = AnchorLink {
+        anchor,
+        tx_id,
+        status: AnchorTxStatus::PendingSender,
+        linked_at: now,
+        updated_at: now,
+    };
+    set_anchor_link(env, remittance_id, &link);
+    set_anchor_tx_remittance(env, &link.tx_id, remittance_id);
+    Ok(link)
+}
+
+/// Records a new status for the anchor transaction linked to `remittance_id`.
+///
+/// # Errors
+///
+/// * `ContractError::AnchorTxNotLinked` - The remittance has no anchor transaction
+/// * `ContractError::InvalidStatus` - The anchor transaction already reached a final status
+pub fn update_status(
+    env: &Env,
+    remittance_id: u64,
+    status: AnchorTxStatus,
+) -> Result<AnchorLink, ContractError> {
+    let mut link = get_anchor_link(env, remittance_id).ok_or(ContractError::AnchorTxNotLinked)?;
+    if link.status.is_final() {
+        return Err(ContractError::InvalidStatus);
+    }
+    link.status = status;
+    link.updated_at = env.ledger().timestamp();
+    set_anchor_link(env, remittance_id, &link);
+    Ok(link)
+}
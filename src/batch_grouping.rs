@@ -0,0 +1,221 @@
+//! Token-aware grouping for batch settlement.
+//!
+//! Remittances denominated in different tokens can neither be netted against
+//! each other nor paid out through the same token client, so
+//! `batch_settle_with_netting` splits its input into one [`TokenBatch`] per
+//! token and nets each group independently. Payouts within a group are then
+//! aggregated per recipient, so every (token, agent) pair receives exactly one
+//! transfer no matter how many of its remittances the batch contains.
+//!
+//! Admins may cap the volume a single batch moves in any one token with
+//! `set_token_batch_limit`; a batch whose group for that token exceeds the
+//! limit is rejected as a whole.
+
+use soroban_sdk::{contracttype, Address, Env, Map, Vec};
+
+use crate::storage::get_token_batch_limit;
+use crate::{ContractError, NetTransfer, Remittance};
+
+/// Remittances in a batch that share a token.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TokenBatch {
+    pub token: Address,
+    pub remittances: Vec<Remittance>,
+    /// Sum of the group's remittance amounts
+    pub total_amount: i128,
+}
+
+/// Aggregated transfers for one token group after netting.
+#[derive(Clone, Debug)]
+pub struct TokenPayouts {
+    /// Net payout owed to each recipient
+    pub payouts: Map<Address, i128>,
+    /// Fees retained by the contract across the group
+    pub fees: i128,
+}
+
+/// Splits remittances into per-token groups, ordered by token address.
+///
+/// Rejects the batch with `TokenBatchLimitExceeded` if any group's total
+/// amount exceeds the limit configured for its token.
+pub fn group_by_token(
+    env: &Env,
+    remittances: &Vec<Remittance>,
+) -> Result<Vec<TokenBatch>, ContractError> {
+    let mut groups: Map<Address, Vec<Remittance>> = Map::new(env);
+    for remittance in remittances.iter() {
+        let mut group = groups
+            .get(remittance.token.clone())
+            .unwrap_or(Vec::new(env));
+        group.push_back(remittance.clone());
+        groups.set(remittance.token.clone(), group);
+    }
+
+    let mut batches = Vec::new(env);
+    for (token, group) in groups.iter() {
+        let mut total_amount: i128 = 0;
+        for remittance in group.iter() {
+            total_amount = total_amount
+                .checked_add(remittance.amount)
+                .ok_or(ContractError::Overflow)?;
+        }
+        if let Some(limit) = get_token_batch_limit(env, &token) {
+            if total_amount > limit {
+                return Err(ContractError::TokenBatchLimitExceeded);
+            }
+        }
+        batches.push_back(TokenBatch {
+            token,
+            remittances: group,
+            total_amount,
+        });
+    }
+    Ok(batches)
+}
+
+/// Aggregates a group's net transfers into one payout per recipient.
+///
+/// Each net transfer pays its receiving party the net amount minus the fees
+/// of the remittances it covers. Zero-value net positions need no transfer
+/// and are skipped.
+pub fn aggregate_payouts(
+    env: &Env,
+    net_transfers: &Vec<NetTransfer>,
+) -> Result<TokenPayouts, ContractError> {
+    let mut payouts: Map<Address, i128> = Map::new(env);
+    let mut fees: i128 = 0;
+
+    for transfer in net_transfers.iter() {
+        let (to, amount) = if transfer.net_amount > 0 {
+            (transfer.party_b.clone(), transfer.net_amount)
+        } else if transfer.net_amount < 0 {
+            (transfer.party_a.clone(), -transfer.net_amount)
+        } else {
+            continue;
+        };
+
+        let payout = amount
+            .checked_sub(transfer.total_fees)
+            .ok_or(ContractError::Overflow)?;
+        let total = payouts
+            .get(to.clone())
+            .unwrap_or(0)
+            .checked_add(payout)
+            .ok_or(ContractError::Overflow)?;
+        payouts.set(to, total);
+        fees = fees
+            .checked_add(transfer.total_fees)
+            .ok_or(ContractError::Overflow)?;
+    }
+
+    Ok(TokenPayouts { payouts, fees })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MaybeBytes32, MaybeCorridor, MaybeExpiry, MaybeSettlementConfig, RemittanceStatus};
+    use soroban_sdk::testutils::Address as _;
+
+    fn remittance(env: &Env, id: u64, token: &Address, agent: &Address, amount: i128) -> Remittance {
+        Remittance {
+            id,
+            sender: Address::generate(env),
+            agent: agent.clone(),
+            amount,
+            fee: amount / 100,
+            status: RemittanceStatus::Pending,
+            expiry: MaybeExpiry::None,
+            settlement_config: MaybeSettlementConfig::None,
+            token: token.clone(),
+            created_at: 0,
+            failed_at: None,
+            dispute_evidence: MaybeBytes32::None,
+            expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: MaybeCorridor::None,
+        }
+    }
+
+    fn contract(env: &Env) -> Address {
+        env.register(crate::SwiftRemitContract, ())
+    }
+
+    #[test]
+    fn test_groups_by_token() {
+        let env = Env::default();
+        let usdc = Address::generate(&env);
+        let eurc = Address::generate(&env);
+        let agent = Address::generate(&env);
+        let mut remittances = Vec::new(&env);
+        remittances.push_back(remittance(&env, 1, &usdc, &agent, 100));
+        remittances.push_back(remittance(&env, 2, &eurc, &agent, 200));
+        remittances.push_back(remittance(&env, 3, &usdc, &agent, 300));
+
+        let batches = env
+            .as_contract(&contract(&env), || group_by_token(&env, &remittances))
+            .unwrap();
+        assert_eq!(batches.len(), 2);
+        for batch in batches.iter() {
+            if batch.token == usdc {
+                assert_eq!(batch.remittances.len(), 2);
+                assert_eq!(batch.total_amount, 400);
+            } else {
+                assert_eq!(batch.token, eurc);
+                assert_eq!(batch.remittances.len(), 1);
+                assert_eq!(batch.total_amount, 200);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejects_group_over_token_limit() {
+        let env = Env::default();
+        let usdc = Address::generate(&env);
+        let eurc = Address::generate(&env);
+        let agent = Address::generate(&env);
+        let mut remittances = Vec::new(&env);
+        remittances.push_back(remittance(&env, 1, &usdc, &agent, 100));
+        remittances.push_back(remittance(&env, 2, &eurc, &agent, 200));
+        remittances.push_back(remittance(&env, 3, &eurc, &agent, 300));
+
+        let id = contract(&env);
+        env.as_contract(&id, || {
+            crate::storage::set_token_batch_limit(&env, &usdc, Some(100));
+            crate::storage::set_token_batch_limit(&env, &eurc, Some(400));
+            assert_eq!(
+                group_by_token(&env, &remittances).err(),
+                Some(ContractError::TokenBatchLimitExceeded)
+            );
+
+            crate::storage::set_token_batch_limit(&env, &eurc, None);
+            assert!(group_by_token(&env, &remittances).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_aggregates_payouts_per_recipient() {
+        let env = Env::default();
+        let agent = Address::generate(&env);
+        let sender_a = Address::generate(&env);
+        let sender_b = Address::generate(&env);
+        let mut transfers = Vec::new(&env);
+        for sender in [&sender_a, &sender_b] {
+            let (party_a, party_b, direction) = crate::netting::normalize_pair(sender, &agent);
+            transfers.push_back(NetTransfer {
+                party_a,
+                party_b,
+                net_amount: 100 * direction,
+                total_fees: 1,
+            });
+        }
+
+        let result = aggregate_payouts(&env, &transfers).unwrap();
+        assert_eq!(result.payouts.len(), 1);
+        assert_eq!(result.payouts.get(agent), Some(198));
+        assert_eq!(result.fees, 2);
+    }
+}
@@ -0,0 +1,166 @@
+//! Cause registry and per-remittance donations.
+//!
+//! The admin curates a registry of verified cause addresses. While a
+//! remittance is `Pending`, its sender may pledge a percentage of it to a
+//! registered cause, taken either from the remittance amount (reducing the
+//! agent payout) or from the platform fee. The donation is carved out when the
+//! payout settles and credited to the cause, which withdraws its accumulated
+//! balance per token.
+//!
+//! Donations are settled on the standard payout path only; partial payouts and
+//! netted batch settlement reject remittances carrying a pledge.
+
+use soroban_sdk::{contracttype, token, Address, Env, String};
+
+use crate::config::{FEE_DIVISOR, MAX_DONATION_BPS};
+use crate::storage::{
+    get_cause, get_cause_balance, get_donation_pledge, set_cause_balance, set_donation_pledge,
+};
+use crate::token_transfer;
+use crate::{ContractError, Remittance, RemittanceStatus};
+
+/// A verified cause in the registry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cause {
+    pub name: String,
+    /// Inactive causes keep their balance but accept no new pledges
+    pub active: bool,
+}
+
+/// Part of a remittance a donation is taken from.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DonationSource {
+    /// Deducted from the agent payout
+    Amount,
+    /// Deducted from the platform fee
+    Fee,
+}
+
+/// A sender's pledge to donate part of a remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationPledge {
+    pub cause: Address,
+    pub bps: u32,
+    pub source: DonationSource,
+}
+
+/// Donations accumulated by a cause in one token.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CauseBalance {
+    /// Lifetime total donated
+    pub donated: i128,
+    /// Lifetime total withdrawn by the cause
+    pub withdrawn: i128,
+}
+
+impl CauseBalance {
+    pub fn available(&self) -> i128 {
+        self.donated - self.withdrawn
+    }
+}
+
+/// Records a donation pledge against a Pending remittance.
+pub fn pledge(
+    env: &Env,
+    remittance: &Remittance,
+    pledge: &DonationPledge,
+) -> Result<(), ContractError> {
+    if remittance.status != RemittanceStatus::Pending {
+        return Err(ContractError::InvalidStatus);
+    }
+    if pledge.bps == 0 || pledge.bps > MAX_DONATION_BPS {
+        return Err(ContractError::InvalidDonation);
+    }
+    match get_cause(env, &pledge.cause) {
+        Some(cause) if cause.active => {}
+        _ => return Err(ContractError::CauseNotFound),
+    }
+    set_donation_pledge(env, remittance.id, pledge);
+    Ok(())
+}
+
+/// Rejects settlement paths that cannot apportion a donation.
+pub fn require_no_pledge(env: &Env, remittance_id: u64) -> Result<(), ContractError> {
+    if get_donation_pledge(env, remittance_id).is_some() {
+        return Err(ContractError::DonationNotSupported);
+    }
+    Ok(())
+}
+
+/// Credits a settling remittance's donation to its cause.
+///
+/// Returns the amounts to deduct from the payout and from the fee.
+pub fn settle(
+    env: &Env,
+    remittance: &Remittance,
+    payout: i128,
+    fee: i128,
+) -> Result<(i128, i128), ContractError> {
+    let Some(pledge) = get_donation_pledge(env, remittance.id) else {
+        return Ok((0, 0));
+    };
+    let base = match pledge.source {
+        DonationSource::Amount => payout,
+        DonationSource::Fee => fee,
+    };
+    let donation = base
+        .checked_mul(pledge.bps as i128)
+        .ok_or(ContractError::Overflow)?
+        / FEE_DIVISOR;
+
+    let mut balance = get_cause_balance(env, &pledge.cause, &remittance.token);
+    balance.donated = balance
+        .donated
+        .checked_add(donation)
+        .ok_or(ContractError::Overflow)?;
+    set_cause_balance(env, &pledge.cause, &remittance.token, &balance);
+    crate::events::emit_donation_made(
+        env,
+        remittance.id,
+        pledge.cause.clone(),
+        remittance.token.clone(),
+        donation,
+    );
+
+    Ok(match pledge.source {
+        DonationSource::Amount => (donation, 0),
+        DonationSource::Fee => (0, donation),
+    })
+}
+
+/// Transfers a cause's available balance in `token` to the cause.
+pub fn withdraw(env: &Env, cause: &Address, token: &Address) -> Result<i128, ContractError> {
+    let mut balance = get_cause_balance(env, cause, token);
+    let amount = balance.available();
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    balance.withdrawn = balance.donated;
+    set_cause_balance(env, cause, token, &balance);
+    token_transfer::transfer(
+        &token::Client::new(env, token),
+        &env.current_contract_address(),
+        cause,
+        amount,
+    )?;
+    Ok(amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_available_balance() {
+        let balance = CauseBalance {
+            donated: 1_000,
+            withdrawn: 400,
+        };
+        assert_eq!(balance.available(), 600);
+        assert_eq!(CauseBalance::default().available(), 0);
+    }
+}
@@ -0,0 +1,36 @@
+//! Periodic checkpoint events for indexers.
+//!
+//! Every remittance status change is numbered, and every
+//! [`CHECKPOINT_INTERVAL`]th one emits a `("sys", "chkpt")` event with the
+//! remittance counter, open remittance counts, and fee totals at that point.
+//! An indexer that finds its own tallies disagree with a checkpoint, or sees a
+//! gap in checkpoint numbers, knows it missed events and can re-sync from the
+//! last checkpoint that matched.
+
+use soroban_sdk::Env;
+
+use crate::config::CHECKPOINT_INTERVAL;
+use crate::events::emit_checkpoint;
+use crate::storage::{
+    get_accumulated_fees, get_accumulated_integrator_fees, get_remittance_counter,
+    get_state_change_count, set_state_change_count,
+};
+use crate::RemittanceCounts;
+
+/// Counts a status change, emitting a checkpoint on every interval.
+pub fn record(env: &Env, counts: &RemittanceCounts) {
+    let changes = get_state_change_count(env).saturating_add(1);
+    set_state_change_count(env, changes);
+    if !changes.is_multiple_of(CHECKPOINT_INTERVAL) {
+        return;
+    }
+    emit_checkpoint(
+        env,
+        changes / CHECKPOINT_INTERVAL,
+        get_remittance_counter(env).unwrap_or(0),
+        counts.pending_count,
+        counts.processing_count,
+        get_accumulated_fees(env).unwrap_or(0),
+        get_accumulated_integrator_fees(env),
+    );
+}
@@ -16,8 +16,8 @@ use soroban_sdk::{Address, Env};
 use crate::{
     circuit_breaker_storage as cb_storage,
     events::{emit_circuit_breaker_paused, emit_circuit_breaker_unpaused},
-    storage::{is_paused, require_role_admin, set_paused},
-    types::{CircuitBreakerStatus, PauseReason, PauseRecord, UnpauseRecord},
+    storage::{is_paused, require_admin_or_role, require_role_admin, set_paused},
+    types::{CircuitBreakerStatus, PauseReason, PauseRecord, Role, UnpauseRecord},
     ContractError,
 };
 
@@ -29,11 +29,12 @@ use crate::{
 /// - `caller`         — address initiating the pause; `require_auth()` is called unless
 ///                      `bypass_checks` is `true`.
 /// - `reason`         — structured [`PauseReason`] stored in the audit record.
-/// - `bypass_checks`  — when `true` (legacy `pause` wrapper), skips the admin role check
+/// - `bypass_checks`  — when `true` (legacy `pause` wrapper), skips the role check
 ///                      and the already-paused guard.
 ///
 /// # Errors
-/// - [`ContractError::Unauthorized`]  — caller does not hold `Role::Admin` (unless bypassed).
+/// - [`ContractError::Unauthorized`]  — caller is neither an admin nor holds `Role::Operator`
+///                                       (unless bypassed).
 /// - [`ContractError::AlreadyPaused`] — contract is already paused (unless bypassed).
 pub fn do_emergency_pause(
     env: &Env,
@@ -42,9 +43,8 @@ pub fn do_emergency_pause(
     bypass_checks: bool,
 ) -> Result<(), ContractError> {
     if !bypass_checks {
-        // Require caller authentication before the admin check.
-        caller.require_auth();
-        require_role_admin(env, caller)?;
+        // Operators may pause; only admins may unpause.
+        require_admin_or_role(env, caller, &Role::Operator)?;
 
         if is_paused(env) {
             return Err(ContractError::AlreadyPaused);
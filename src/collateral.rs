@@ -0,0 +1,94 @@
+//! Collateral agents must lock to take large remittances, and slashing of it.
+//!
+//! The admin can require, with `set_collateral_requirement`, that an agent
+//! hold at least `min_stake` of the default token as stake (see
+//! `stake_agent`) before it is assigned a remittance above `threshold`. The
+//! requirement is checked whenever a remittance is assigned to an agent, at
+//! creation or on reassignment, and while an agent has pending remittances it
+//! cannot unstake below it.
+//!
+//! If an agent fails a sender, an admin or claim resolver can slash the
+//! agent's stake in the remittance's token with `slash_agent`, paying the
+//! slashed amount to the remittance's sender as compensation.
+
+use soroban_sdk::{contracttype, token, Address, Env};
+
+use crate::storage::{
+    get_agent_pending_value, get_agent_stake, get_collateral_requirement, get_usdc_token,
+    set_agent_stake,
+};
+use crate::{token_transfer, ContractError, Remittance};
+
+/// Stake an agent must hold to take remittances above a threshold.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralRequirement {
+    /// Remittances above this amount need the stake
+    pub threshold: i128,
+    /// Least stake in the default token the agent must hold
+    pub min_stake: i128,
+}
+
+/// Checks that `agent` holds enough stake to be assigned a remittance of `amount`.
+///
+/// # Errors
+///
+/// * `ContractError::AgentStakeRequired` - The amount is above the threshold
+///   and the agent's stake is below the minimum
+pub fn check_assignment(env: &Env, agent: &Address, amount: i128) -> Result<(), ContractError> {
+    let Some(requirement) = get_collateral_requirement(env) else {
+        return Ok(());
+    };
+    if amount > requirement.threshold
+        && get_agent_stake(env, agent, &get_usdc_token(env)?) < requirement.min_stake
+    {
+        return Err(ContractError::AgentStakeRequired);
+    }
+    Ok(())
+}
+
+/// Checks that `agent` may bring its stake in `token` down to `remaining`.
+///
+/// # Errors
+///
+/// * `ContractError::AgentStakeRequired` - The agent has pending remittances
+///   and `remaining` is below the required stake
+pub fn check_unstake(
+    env: &Env,
+    agent: &Address,
+    token: &Address,
+    remaining: i128,
+) -> Result<(), ContractError> {
+    let Some(requirement) = get_collateral_requirement(env) else {
+        return Ok(());
+    };
+    if *token == get_usdc_token(env)?
+        && remaining < requirement.min_stake
+        && get_agent_pending_value(env, agent) > 0
+    {
+        return Err(ContractError::AgentStakeRequired);
+    }
+    Ok(())
+}
+
+/// Moves `amount` of the agent's stake in the remittance's token to its
+/// sender. Returns the agent's remaining stake.
+///
+/// # Errors
+///
+/// * `ContractError::InvalidAmount` - Amount is not positive or exceeds the stake
+pub fn slash(env: &Env, remittance: &Remittance, amount: i128) -> Result<i128, ContractError> {
+    let staked = get_agent_stake(env, &remittance.agent, &remittance.token);
+    if amount <= 0 || amount > staked {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    set_agent_stake(env, &remittance.agent, &remittance.token, staked - amount);
+    token_transfer::transfer(
+        &token::Client::new(env, &remittance.token),
+        &env.current_contract_address(),
+        &remittance.sender,
+        amount,
+    )?;
+    Ok(staked - amount)
+}
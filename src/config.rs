@@ -12,6 +12,19 @@
 /// escrow record would otherwise expire.
 pub const PROCESSING_WINDOW_LEDGERS: u32 = 120_960; // ~7 days at 5s/ledger
 
+/// Approximate ledger close time, used to convert ledger-sequence expiries
+/// into a lock duration for expiry-weighted fees.
+pub const SECONDS_PER_LEDGER: u64 = 5;
+
+/// Period over which the expiry fee slope (`bps_per_day`) is expressed.
+pub const EXPIRY_FEE_PERIOD_SECONDS: u64 = 24 * 60 * 60;
+
+/// Upper bound for the admin-configured expiry tolerance, in seconds.
+///
+/// The tolerance only absorbs clock skew around a deadline; anything longer
+/// would effectively extend every timestamp expiry.
+pub const MAX_EXPIRY_TOLERANCE_SECONDS: u64 = 300;
+
 // ============================================================================
 // Batch Processing Limits
 // ============================================================================
@@ -35,6 +48,21 @@ pub const MAX_EXPIRED_BATCH_SIZE: u32 = 50;
 /// operations. Used by migration export/import functions to validate batch size.
 pub const MAX_MIGRATION_BATCH_SIZE: u32 = 100;
 
+/// Maximum number of corridors updated by a single `update_corridors` call.
+pub const MAX_CORRIDOR_UPDATES: u32 = 50;
+
+/// Maximum number of tiers in a tiered fee schedule.
+pub const MAX_FEE_TIERS: u32 = 10;
+
+/// Maximum number of configuration changes pending at once.
+///
+/// Fee and limit getters scan the pending list on every call, so the cap
+/// bounds that cost.
+pub const MAX_SCHEDULED_CONFIG_CHANGES: u32 = 20;
+
+/// Maximum number of maintenance windows scheduled at once.
+pub const MAX_MAINTENANCE_WINDOWS: u32 = 10;
+
 /// Maximum number of remittances that can be netted in a single compute_net_settlements call.
 ///
 /// This limit prevents DoS attacks via large remittance batches that could cause
@@ -60,6 +88,61 @@ const _: () = assert!(
     "MAX_VEC_SIZE must be strictly greater than MAX_QUERIES_PER_WINDOW to avoid silent data loss in the sliding-window rate limiter",
 );
 
+/// Maximum number of items returned by a single page of a cursor-paginated
+/// list endpoint. Larger `limit` arguments are clamped to this value.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of vacated positions trimmed from the head of a keyed ID
+/// list (status buckets, agent pending queues) per removal.
+pub const MAX_LIST_TRIM: u32 = 8;
+
+/// Maximum number of observer contracts notified on lifecycle events.
+///
+/// Every observer is invoked synchronously inside the triggering call, so this
+/// cap bounds the extra budget a single remittance operation can consume.
+pub const MAX_OBSERVERS: u32 = 5;
+
+/// Maximum number of over-limit requests an organization may have awaiting
+/// owner approval at once.
+///
+/// The pending list is stored as a single entry per organization, so the cap
+/// keeps it well within the ledger entry size limit.
+pub const MAX_ORG_PENDING_REQUESTS: u32 = 50;
+
+/// Maximum number of sender-defined tags attached to a remittance.
+///
+/// Each tag produces its own event on creation and completion, so the cap
+/// bounds the events emitted per remittance.
+pub const MAX_REMITTANCE_TAGS: u32 = 3;
+
+/// Maximum number of intermediate hops on a routed remittance.
+pub const MAX_ROUTE_HOPS: u32 = 3;
+
+/// Maximum size of an encrypted memo envelope attached to a remittance.
+///
+/// Large enough for pickup instructions plus an ephemeral public key and MAC,
+/// small enough to keep the stored entry and its event cheap.
+pub const MAX_ENCRYPTED_MEMO_BYTES: u32 = 512;
+
+/// Maximum number of saved remittance templates per sender.
+pub const MAX_TEMPLATES_PER_SENDER: u32 = 20;
+
+/// Maximum number of favorite agents a sender may keep.
+pub const MAX_FAVORITE_AGENTS: u32 = 10;
+
+/// Maximum number of entries in a sender's address book.
+pub const MAX_ADDRESS_BOOK_ENTRIES: u32 = 50;
+
+/// Maximum length in bytes of a plaintext remittance memo.
+pub const MAX_MEMO_BYTES: u32 = 128;
+
+/// Upper bounds (seconds) of the settlement latency histogram buckets used
+/// for p95 time-to-settlement. Settlements slower than the last bound fall
+/// into a final overflow bucket.
+pub const SETTLEMENT_LATENCY_BUCKETS: [u64; 12] = [
+    300, 900, 1_800, 3_600, 7_200, 14_400, 28_800, 43_200, 86_400, 172_800, 259_200, 604_800,
+];
+
 // ============================================================================
 // Fee Calculation Constants
 // ============================================================================
@@ -86,6 +169,23 @@ pub const MIN_FEE: i128 = 1;
 /// - Value: 10000 (basis points scale)
 pub const FEE_DIVISOR: i128 = 10000;
 
+/// Absolute ceiling for the platform fee set through update_fee().
+///
+/// Kept well below `MAX_FEE_BPS` so a mistaken or malicious update cannot
+/// set a fee that consumes most of a remittance.
+/// - Value: 3000 bps (30%)
+pub const MAX_PLATFORM_FEE_BPS: u32 = 3000;
+
+/// Maximum total change to the platform fee within one guardrail window.
+///
+/// Measured against the fee in effect when the window opened, so several
+/// small updates cannot add up to a larger jump.
+/// - Value: 500 bps (5 percentage points)
+pub const MAX_FEE_CHANGE_BPS: u32 = 500;
+
+/// Duration of the fee-change guardrail window in seconds (24 hours).
+pub const FEE_CHANGE_WINDOW_SECONDS: u64 = 24 * 60 * 60;
+
 // ============================================================================
 // Rate Limiting Configuration
 // ============================================================================
@@ -131,6 +231,12 @@ pub const TRANSFER_COOLDOWN_SECONDS: u64 = 5;
 /// - Value: 86400 seconds (24 hours)
 pub const DAILY_LIMIT_WINDOW_SECONDS: u64 = 24 * 60 * 60;
 
+/// Default time senders have to dispute a failed payout.
+///
+/// Admins can change it with `set_dispute_window`.
+/// - Value: 604800 seconds (7 days)
+pub const DEFAULT_DISPUTE_WINDOW_SECONDS: u64 = 7 * 24 * 60 * 60;
+
 /// Default currency code for daily send limits.
 ///
 /// Used when no specific currency is provided for daily limit checks.
@@ -165,6 +271,248 @@ pub const SENDER_VOLUME_TIER_THRESHOLD_10K: i128 = 10_000;
 /// This is the fee applied when the sender reaches the 30-day volume threshold.
 pub const SENDER_VOLUME_TIER_FEE_BPS_10K: u32 = 150;
 
+// ============================================================================
+// Agent Tiers
+// ============================================================================
+
+/// Maximum total value of Pending remittances a Basic-tier agent may hold.
+pub const BASIC_TIER_MAX_PENDING_VALUE: i128 = 100_000_000_000;
+
+/// Maximum total value of Pending remittances a Verified-tier agent may hold.
+pub const VERIFIED_TIER_MAX_PENDING_VALUE: i128 = 1_000_000_000_000;
+
+/// Maximum total value of Pending remittances a Premier-tier agent may hold.
+pub const PREMIER_TIER_MAX_PENDING_VALUE: i128 = 10_000_000_000_000;
+
+/// Remittance amount at or above which the agent must be Verified or Premier.
+pub const LARGE_REMITTANCE_THRESHOLD: i128 = 50_000_000_000;
+
+/// Largest single remittance that may be assigned to an agent without KYB verification.
+pub const UNVERIFIED_AGENT_MAX_REMITTANCE: i128 = 5_000_000_000;
+
+/// Total remittance value an agent without KYB verification may be assigned per day.
+pub const UNVERIFIED_AGENT_DAILY_LIMIT: i128 = 20_000_000_000;
+
+/// Share of the platform fee, in basis points, credited to Basic-tier agents.
+pub const BASIC_TIER_COMMISSION_SHARE_BPS: u32 = 1000;
+
+/// Share of the platform fee, in basis points, credited to Verified-tier agents.
+pub const VERIFIED_TIER_COMMISSION_SHARE_BPS: u32 = 2000;
+
+/// Share of the platform fee, in basis points, credited to Premier-tier agents.
+pub const PREMIER_TIER_COMMISSION_SHARE_BPS: u32 = 3000;
+
+// ============================================================================
+// Insurance
+// ============================================================================
+
+/// Premium charged to insure a remittance, in basis points of its amount.
+pub const INSURANCE_PREMIUM_BPS: u32 = 50;
+
+/// Compensation paid on an insured remittance that is not delivered, in basis
+/// points of its amount.
+pub const INSURANCE_COVERAGE_BPS: u32 = 1000;
+
+// ============================================================================
+// Donations
+// ============================================================================
+
+/// Largest share of a remittance (or its fee) a sender may pledge to a cause.
+pub const MAX_DONATION_BPS: u32 = 2000;
+
+// ============================================================================
+// Savings Goals
+// ============================================================================
+
+/// Fee on withdrawals from a savings goal before it reaches its target or
+/// deadline, paid into the insurance fund (1%).
+pub const GOAL_EARLY_WITHDRAWAL_FEE_BPS: u32 = 100;
+
+// ============================================================================
+// Agent Advances
+// ============================================================================
+
+/// Minimum agent reputation (0-100) required to draw a working-capital advance.
+pub const ADVANCE_MIN_REPUTATION: u32 = 80;
+
+/// Largest advance as a share of the value of the collateral remittances (50%).
+pub const ADVANCE_MAX_LTV_BPS: u32 = 5000;
+
+/// Largest advance as a multiple of the agent's stake in the token.
+pub const ADVANCE_STAKE_MULTIPLIER: i128 = 2;
+
+/// Time an agent has to repay an advance before it can be liquidated (7 days).
+pub const ADVANCE_TERM_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+// ============================================================================
+// Credit Signals
+// ============================================================================
+
+/// Version of the credit-signal event payload, bumped on any layout change.
+pub const CREDIT_SIGNAL_VERSION: u32 = 1;
+
+/// Length of the period each credit-signal summary covers (30 days).
+pub const CREDIT_SIGNAL_PERIOD_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+/// Settlements completed within this time of creation count as on time (24 hours).
+pub const CREDIT_SIGNAL_ON_TIME_SECONDS: u64 = 24 * 60 * 60;
+
+// ============================================================================
+// Handles
+// ============================================================================
+
+/// Shortest permitted handle, in bytes.
+pub const MIN_HANDLE_LEN: u32 = 3;
+
+/// Longest permitted handle, in bytes.
+pub const MAX_HANDLE_LEN: u32 = 32;
+
+/// Fee for registering or renewing a handle, in the default token's smallest
+/// unit (1 USDC at 7 decimals). Paid to the treasury.
+pub const HANDLE_REGISTRATION_FEE: i128 = 10_000_000;
+
+/// How long a handle registration or renewal lasts (365 days).
+pub const HANDLE_TERM_SECONDS: u64 = 365 * 24 * 60 * 60;
+
+// ============================================================================
+// Emergency Evacuation
+// ============================================================================
+
+/// Countdown between the second approval of an emergency evacuation and the
+/// earliest time it can execute (72 hours), leaving room to cancel it if
+/// either approving key turns out to be compromised.
+pub const EVACUATION_DELAY_SECONDS: u64 = 72 * 60 * 60;
+
+// ============================================================================
+// Account Recovery
+// ============================================================================
+
+/// Maximum number of recovery guardians an owner may register.
+pub const MAX_RECOVERY_GUARDIANS: u32 = 5;
+
+/// Challenge period between guardians reaching their threshold and the
+/// earliest time a recovery can execute (7 days), during which the owner can
+/// cancel it.
+pub const ACCOUNT_RECOVERY_CHALLENGE_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+// ============================================================================
+// Batch Cost Budget
+// ============================================================================
+
+/// CPU instructions a single Soroban transaction may consume.
+pub const TX_CPU_INSTRUCTION_LIMIT: u64 = 100_000_000;
+
+/// Share of the transaction limit a payout batch plans to use, in basis
+/// points. The figures below are measured in native test runs, which meter
+/// host functions but not the contract's own code; the rest of the limit is
+/// headroom for executing that code as Wasm, authorization, and variation
+/// the measurements do not capture.
+pub const BATCH_CPU_BUDGET_BPS: u64 = 4_000;
+
+/// Fixed CPU cost of a `confirm_batch_payout` call, measured in the test
+/// harness.
+pub const BATCH_BASE_CPU_COST: u64 = 500_000;
+
+/// CPU cost of settling one batch entry, measured in the test harness.
+pub const BATCH_ENTRY_CPU_COST: u64 = 1_900_000;
+
+/// CPU cost added per pair of batch entries. Every entry adds ledger entries
+/// to the call's storage footprint, which makes each later storage write in
+/// the same call dearer, so batch cost grows with the square of its size.
+pub const BATCH_ENTRY_PAIR_CPU_COST: u64 = 175_000;
+
+/// Estimated CPU instructions for a payout batch of `entries` remittances.
+pub const fn estimate_batch_cost(entries: u32) -> u64 {
+    let entries = entries as u64;
+    BATCH_BASE_CPU_COST
+        .saturating_add(BATCH_ENTRY_CPU_COST.saturating_mul(entries))
+        .saturating_add(BATCH_ENTRY_PAIR_CPU_COST.saturating_mul(entries.saturating_mul(entries)))
+}
+
+/// Largest payout batch whose estimated cost fits the planned budget.
+///
+/// Payout batches above this size fail up front with `BatchTooLarge` rather
+/// than exhausting the budget part-way through.
+pub const MAX_SAFE_BATCH_SIZE: u32 =
+    max_batch_within(TX_CPU_INSTRUCTION_LIMIT * BATCH_CPU_BUDGET_BPS / 10_000);
+
+const fn max_batch_within(budget: u64) -> u32 {
+    let mut entries = 0;
+    while estimate_batch_cost(entries + 1) <= budget {
+        entries += 1;
+    }
+    entries
+}
+
+// ============================================================================
+// Volume History
+// ============================================================================
+
+/// Length of each volume history bucket (24 hours).
+pub const VOLUME_BUCKET_SECONDS: u64 = 24 * 60 * 60;
+
+/// Number of daily buckets kept before the oldest is overwritten.
+pub const VOLUME_HISTORY_BUCKETS: u32 = 90;
+
+// ============================================================================
+// Indexer Checkpoints
+// ============================================================================
+
+/// Remittance status changes between `("sys", "chkpt")` checkpoint events.
+pub const CHECKPOINT_INTERVAL: u64 = 100;
+
+// ============================================================================
+// Admin Audit Log
+// ============================================================================
+
+/// Number of most recent privileged calls kept in the admin audit log.
+pub const ADMIN_LOG_CAPACITY: u64 = 1_000;
+
+// ============================================================================
+// Escrow Claim Fallback
+// ============================================================================
+
+/// Age after which a pending escrow nobody has released can be turned into a
+/// claim its recipient redeems directly (14 days).
+pub const ESCROW_CLAIM_FALLBACK_SECONDS: u64 = 14 * 24 * 60 * 60;
+
+// ============================================================================
+// Firm Quotes
+// ============================================================================
+
+/// Fixed-point scale of a quote's exchange rate: a rate of
+/// `QUOTE_RATE_SCALE` converts one source unit into one destination unit.
+pub const QUOTE_RATE_SCALE: i128 = 10_000_000;
+
+/// Longest a posted quote can stay open for acceptance (1 hour).
+pub const MAX_QUOTE_TTL_SECONDS: u64 = 60 * 60;
+
+// ============================================================================
+// Payout Preferences
+// ============================================================================
+
+/// Largest slippage tolerance an agent can set for converting payouts into
+/// its preferred token (10%).
+pub const MAX_PAYOUT_SLIPPAGE_BPS: u32 = 1000;
+
+// ============================================================================
+// Priority Boosts
+// ============================================================================
+
+/// Service-level deadline of a remittance at priority 0, counted from
+/// creation (48 hours). Each priority level halves it.
+pub const BASE_SLA_SECONDS: u64 = 48 * 60 * 60;
+
+/// Highest priority level a sender can boost a remittance to.
+pub const MAX_PRIORITY_LEVEL: u32 = 3;
+
+/// Expedite fee charged per priority level, in basis points of the amount.
+pub const EXPEDITE_FEE_BPS: u32 = 50;
+
+/// Share of a completed remittance's expedite fees paid to its agent, in
+/// basis points. The rest joins the platform fees.
+pub const EXPEDITE_AGENT_SHARE_BPS: u32 = 5000;
+
 // ============================================================================
 // Storage and Event Schema
 // ============================================================================
@@ -232,6 +580,8 @@ mod tests {
     fn test_fee_constants() {
         assert_eq!(MAX_FEE_BPS, 10000);
         assert_eq!(FEE_DIVISOR, 10000);
+        assert!(MAX_PLATFORM_FEE_BPS < MAX_FEE_BPS);
+        assert!(MAX_FEE_CHANGE_BPS <= MAX_PLATFORM_FEE_BPS);
     }
 
     #[test]
@@ -247,6 +597,116 @@ mod tests {
         assert_eq!(DEFAULT_DAILY_LIMIT_COUNTRY, "GLOBAL");
     }
 
+    #[test]
+    fn test_agent_tier_constants() {
+        assert!(BASIC_TIER_MAX_PENDING_VALUE < VERIFIED_TIER_MAX_PENDING_VALUE);
+        assert!(VERIFIED_TIER_MAX_PENDING_VALUE < PREMIER_TIER_MAX_PENDING_VALUE);
+        assert!(LARGE_REMITTANCE_THRESHOLD <= BASIC_TIER_MAX_PENDING_VALUE);
+        assert!(BASIC_TIER_COMMISSION_SHARE_BPS <= VERIFIED_TIER_COMMISSION_SHARE_BPS);
+        assert!(VERIFIED_TIER_COMMISSION_SHARE_BPS <= PREMIER_TIER_COMMISSION_SHARE_BPS);
+        assert!(PREMIER_TIER_COMMISSION_SHARE_BPS <= MAX_FEE_BPS);
+        assert!(UNVERIFIED_AGENT_MAX_REMITTANCE <= UNVERIFIED_AGENT_DAILY_LIMIT);
+        assert!(UNVERIFIED_AGENT_MAX_REMITTANCE < LARGE_REMITTANCE_THRESHOLD);
+    }
+
+    #[test]
+    fn test_insurance_constants() {
+        assert!(INSURANCE_PREMIUM_BPS > 0);
+        assert!(INSURANCE_PREMIUM_BPS < INSURANCE_COVERAGE_BPS);
+        assert!(INSURANCE_COVERAGE_BPS <= MAX_FEE_BPS);
+    }
+
+    #[test]
+    fn test_donation_constants() {
+        assert!(MAX_DONATION_BPS > 0);
+        assert!(MAX_DONATION_BPS <= MAX_FEE_BPS);
+    }
+
+    #[test]
+    fn test_goal_early_withdrawal_fee() {
+        assert!(GOAL_EARLY_WITHDRAWAL_FEE_BPS > 0);
+        assert!(GOAL_EARLY_WITHDRAWAL_FEE_BPS < MAX_FEE_BPS);
+    }
+
+    #[test]
+    fn test_advance_constants() {
+        assert!(ADVANCE_MIN_REPUTATION <= 100);
+        assert!(ADVANCE_MAX_LTV_BPS > 0 && ADVANCE_MAX_LTV_BPS < MAX_FEE_BPS);
+        assert!(ADVANCE_STAKE_MULTIPLIER >= 1);
+        assert!(ADVANCE_TERM_SECONDS > 0);
+    }
+
+    #[test]
+    fn test_credit_signal_constants() {
+        assert!(CREDIT_SIGNAL_VERSION > 0);
+        assert!(CREDIT_SIGNAL_ON_TIME_SECONDS < CREDIT_SIGNAL_PERIOD_SECONDS);
+    }
+
+    #[test]
+    fn test_handle_constants() {
+        assert!(MIN_HANDLE_LEN > 0 && MIN_HANDLE_LEN <= MAX_HANDLE_LEN);
+        assert!(HANDLE_REGISTRATION_FEE > 0);
+        assert!(HANDLE_TERM_SECONDS > 0);
+    }
+
+    #[test]
+    fn test_evacuation_delay() {
+        assert!(EVACUATION_DELAY_SECONDS >= 24 * 60 * 60);
+    }
+
+    #[test]
+    fn test_account_recovery_constants() {
+        assert!(MAX_RECOVERY_GUARDIANS > 0);
+        assert!(ACCOUNT_RECOVERY_CHALLENGE_SECONDS >= EVACUATION_DELAY_SECONDS);
+    }
+
+    #[test]
+    fn test_batch_cost_budget() {
+        assert!(MAX_SAFE_BATCH_SIZE > 0 && MAX_SAFE_BATCH_SIZE <= MAX_BATCH_SIZE);
+        let budget = TX_CPU_INSTRUCTION_LIMIT * BATCH_CPU_BUDGET_BPS / 10_000;
+        assert!(estimate_batch_cost(MAX_SAFE_BATCH_SIZE) <= budget);
+        assert!(estimate_batch_cost(MAX_SAFE_BATCH_SIZE + 1) > budget);
+    }
+
+    #[test]
+    fn test_volume_history_constants() {
+        assert!(VOLUME_HISTORY_BUCKETS > 0);
+        assert_eq!(VOLUME_BUCKET_SECONDS, DAILY_LIMIT_WINDOW_SECONDS);
+    }
+
+    #[test]
+    fn test_checkpoint_interval() {
+        assert!(CHECKPOINT_INTERVAL > 0);
+    }
+
+    #[test]
+    fn test_admin_log_capacity() {
+        assert!(ADMIN_LOG_CAPACITY >= MAX_PAGE_SIZE as u64);
+    }
+
+    #[test]
+    fn test_escrow_claim_fallback() {
+        assert!(ESCROW_CLAIM_FALLBACK_SECONDS >= DAILY_LIMIT_WINDOW_SECONDS);
+    }
+
+    #[test]
+    fn test_firm_quotes() {
+        assert!(QUOTE_RATE_SCALE > 0);
+        assert!(MAX_QUOTE_TTL_SECONDS > 0);
+    }
+
+    #[test]
+    fn test_payout_preferences() {
+        assert!((MAX_PAYOUT_SLIPPAGE_BPS as i128) < FEE_DIVISOR);
+    }
+
+    #[test]
+    fn test_priority_boosts() {
+        assert!(BASE_SLA_SECONDS >> MAX_PRIORITY_LEVEL > 0);
+        assert!(EXPEDITE_FEE_BPS > 0);
+        assert!(EXPEDITE_AGENT_SHARE_BPS <= MAX_FEE_BPS);
+    }
+
     #[test]
     fn test_schema_version() {
         assert!(SCHEMA_VERSION > 0);
@@ -0,0 +1,111 @@
+//! Batched corridor configuration.
+//!
+//! A pricing change usually touches many corridors at once. Instead of one
+//! transaction per corridor and setting, the admin submits a list of
+//! [`CorridorConfigUpdate`]s that may each change a corridor's fee override,
+//! the daily send limit for its destination country, and whether it accepts
+//! new remittances. The whole list is validated before anything is written,
+//! so a batch either applies completely or not at all.
+
+use soroban_sdk::{contracttype, Env, String, Vec};
+
+use crate::config::{DEFAULT_DAILY_LIMIT_CURRENCY, MAX_CORRIDOR_UPDATES, MAX_FEE_BPS};
+use crate::fee_service::FeeCorridor;
+use crate::storage::{
+    remove_fee_corridor, set_corridor_disabled, set_daily_limit, set_fee_corridor,
+    MAX_PROTOCOL_FEE_BPS,
+};
+use crate::{ContractError, FeeStrategy};
+
+/// Change to a corridor's fee override.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CorridorFeeUpdate {
+    Unchanged,
+    /// Sets the fee strategy and optional protocol fee override
+    Set(FeeStrategy, Option<u32>),
+    /// Removes the override so the global strategy applies
+    Remove,
+}
+
+/// Changes to one corridor's configuration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CorridorConfigUpdate {
+    /// Source country code (ISO 3166-1 alpha-2)
+    pub from_country: String,
+    /// Destination country code (ISO 3166-1 alpha-2)
+    pub to_country: String,
+    pub fee: CorridorFeeUpdate,
+    /// New daily send limit for the destination country, if changing
+    pub daily_limit: Option<i128>,
+    /// Whether the corridor accepts new remittances, if changing
+    pub enabled: Option<bool>,
+}
+
+fn validate_update(update: &CorridorConfigUpdate) -> Result<(), ContractError> {
+    if update.from_country.len() != 2 || update.to_country.len() != 2 {
+        return Err(ContractError::InvalidCorridorUpdate);
+    }
+    if let CorridorFeeUpdate::Set(strategy, protocol_fee_bps) = &update.fee {
+        match strategy {
+            FeeStrategy::Percentage(bps) | FeeStrategy::Dynamic(bps) if *bps > MAX_FEE_BPS => {
+                return Err(ContractError::InvalidFeeBps);
+            }
+            FeeStrategy::Flat(amount) if *amount < 0 => return Err(ContractError::InvalidAmount),
+            FeeStrategy::Tiered(tiers) => crate::fee_strategy::validate_tiers(tiers)?,
+            // A corridor override that delegates to the corridor config would recurse.
+            FeeStrategy::Corridor => return Err(ContractError::InvalidCorridorUpdate),
+            _ => {}
+        }
+        if protocol_fee_bps.is_some_and(|bps| bps > MAX_PROTOCOL_FEE_BPS) {
+            return Err(ContractError::InvalidFeeBps);
+        }
+    }
+    if update.daily_limit.is_some_and(|limit| limit <= 0) {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(())
+}
+
+/// Validates every update in the batch, rejecting corridors listed twice.
+pub fn validate(updates: &Vec<CorridorConfigUpdate>) -> Result<(), ContractError> {
+    if updates.is_empty() || updates.len() > MAX_CORRIDOR_UPDATES {
+        return Err(ContractError::InvalidCorridorUpdate);
+    }
+    for (i, update) in updates.iter().enumerate() {
+        validate_update(&update)?;
+        for earlier in updates.iter().take(i) {
+            if earlier.from_country == update.from_country && earlier.to_country == update.to_country {
+                return Err(ContractError::InvalidCorridorUpdate);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Writes one validated update.
+pub fn apply(env: &Env, update: &CorridorConfigUpdate) {
+    match &update.fee {
+        CorridorFeeUpdate::Unchanged => {}
+        CorridorFeeUpdate::Set(strategy, protocol_fee_bps) => set_fee_corridor(
+            env,
+            &FeeCorridor {
+                from_country: update.from_country.clone(),
+                to_country: update.to_country.clone(),
+                strategy: strategy.clone(),
+                protocol_fee_bps: *protocol_fee_bps,
+            },
+        ),
+        CorridorFeeUpdate::Remove => {
+            remove_fee_corridor(env, &update.from_country, &update.to_country)
+        }
+    }
+    if let Some(limit) = update.daily_limit {
+        let currency = String::from_str(env, DEFAULT_DAILY_LIMIT_CURRENCY);
+        set_daily_limit(env, &currency, &update.to_country, limit);
+    }
+    if let Some(enabled) = update.enabled {
+        set_corridor_disabled(env, &update.from_country, &update.to_country, !enabled);
+    }
+}
@@ -0,0 +1,190 @@
+//! Utilization-based dynamic pricing for fee corridors.
+//!
+//! Agents register how much payout liquidity they can provide on a corridor.
+//! The contract tracks the corridor's total registered capacity alongside the
+//! volume of corridor remittances still waiting in `Pending` state. When the
+//! admin sets utilization bounds for a corridor, its percentage fee floats
+//! linearly between `min_bps` (idle) and `max_bps` (pending volume at or above
+//! capacity), repricing scarce corridors without manual fee updates.
+//!
+//! Pending volume is tracked per remittance, so releasing it when a remittance
+//! leaves `Pending` is idempotent and safe to call from every exit path.
+
+use soroban_sdk::{contracttype, Address, Env, String};
+
+use crate::config::{FEE_DIVISOR, MAX_FEE_BPS};
+use crate::storage::{
+    get_agent_corridor_capacity, get_corridor_agents, get_corridor_liquidity,
+    get_corridor_pending_amount, get_utilization_fee_bounds, remove_corridor_pending_amount,
+    set_agent_corridor_capacity, set_corridor_agents, set_corridor_liquidity,
+    set_corridor_pending_amount,
+};
+use crate::{ContractError, MaybeCorridor, Remittance};
+
+/// Admin-set fee bounds for a utilization-priced corridor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UtilizationFeeBounds {
+    /// Fee charged when the corridor has no pending volume
+    pub min_bps: u32,
+    /// Fee charged when pending volume reaches registered capacity
+    pub max_bps: u32,
+}
+
+/// Registered agent capacity and in-flight volume for a corridor.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CorridorLiquidity {
+    /// Sum of payout capacity registered by agents serving the corridor
+    pub capacity: i128,
+    /// Total amount of corridor remittances currently in `Pending` state
+    pub pending_volume: i128,
+}
+
+impl CorridorLiquidity {
+    /// Ratio of pending volume to capacity in basis points, capped at 10000.
+    ///
+    /// A corridor with no registered capacity is treated as fully utilized.
+    pub fn utilization_bps(&self) -> u32 {
+        if self.capacity <= 0 {
+            return MAX_FEE_BPS;
+        }
+        let ratio = self
+            .pending_volume
+            .max(0)
+            .saturating_mul(FEE_DIVISOR)
+            / self.capacity;
+        ratio.min(MAX_FEE_BPS as i128) as u32
+    }
+}
+
+/// Validates utilization bounds before they are stored.
+pub fn validate_bounds(bounds: &UtilizationFeeBounds) -> Result<(), ContractError> {
+    if bounds.min_bps > bounds.max_bps || bounds.max_bps > MAX_FEE_BPS {
+        return Err(ContractError::InvalidUtilizationBounds);
+    }
+    Ok(())
+}
+
+/// Returns the current dynamic fee for a corridor, or `None` if the corridor
+/// is not utilization-priced.
+pub fn dynamic_fee_bps(env: &Env, from_country: &String, to_country: &String) -> Option<u32> {
+    let bounds = get_utilization_fee_bounds(env, from_country, to_country)?;
+    let utilization = get_corridor_liquidity(env, from_country, to_country).utilization_bps() as u64;
+    let spread = (bounds.max_bps - bounds.min_bps) as u64;
+    Some(bounds.min_bps + (spread * utilization / MAX_FEE_BPS as u64) as u32)
+}
+
+/// Sets an agent's capacity on a corridor and updates the corridor total.
+///
+/// Agents with non-zero capacity are listed for corridor discovery; setting
+/// the capacity to zero removes the agent from the listing.
+///
+/// Returns the corridor's new total capacity.
+pub fn set_agent_capacity(
+    env: &Env,
+    agent: &Address,
+    from_country: &String,
+    to_country: &String,
+    capacity: i128,
+) -> Result<i128, ContractError> {
+    if capacity < 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    let previous = get_agent_corridor_capacity(env, agent, from_country, to_country);
+    let mut liquidity = get_corridor_liquidity(env, from_country, to_country);
+    liquidity.capacity = liquidity
+        .capacity
+        .checked_sub(previous)
+        .and_then(|v| v.checked_add(capacity))
+        .ok_or(ContractError::Overflow)?;
+    set_agent_corridor_capacity(env, agent, from_country, to_country, capacity);
+    set_corridor_liquidity(env, from_country, to_country, &liquidity);
+
+    let mut agents = get_corridor_agents(env, from_country, to_country);
+    match (agents.first_index_of(agent), capacity > 0) {
+        (None, true) => agents.push_back(agent.clone()),
+        (Some(index), false) => {
+            agents.remove(index);
+        }
+        _ => return Ok(liquidity.capacity),
+    }
+    set_corridor_agents(env, from_country, to_country, &agents);
+    Ok(liquidity.capacity)
+}
+
+/// Counts a new corridor remittance towards the corridor's pending volume.
+pub fn track_pending(
+    env: &Env,
+    remittance_id: u64,
+    from_country: &String,
+    to_country: &String,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let mut liquidity = get_corridor_liquidity(env, from_country, to_country);
+    liquidity.pending_volume = liquidity
+        .pending_volume
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    set_corridor_liquidity(env, from_country, to_country, &liquidity);
+    set_corridor_pending_amount(env, remittance_id, amount);
+    Ok(())
+}
+
+/// Removes a remittance from its corridor's pending volume once it leaves `Pending`.
+///
+/// No-op for remittances that were never tracked or were already released.
+pub fn release_pending(env: &Env, remittance: &Remittance) {
+    let MaybeCorridor::Some(ref corridor) = remittance.corridor else {
+        return;
+    };
+    let Some(amount) = get_corridor_pending_amount(env, remittance.id) else {
+        return;
+    };
+    let mut liquidity = get_corridor_liquidity(env, &corridor.from_country, &corridor.to_country);
+    liquidity.pending_volume = liquidity.pending_volume.saturating_sub(amount).max(0);
+    set_corridor_liquidity(env, &corridor.from_country, &corridor.to_country, &liquidity);
+    remove_corridor_pending_amount(env, remittance.id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn liquidity(capacity: i128, pending_volume: i128) -> CorridorLiquidity {
+        CorridorLiquidity {
+            capacity,
+            pending_volume,
+        }
+    }
+
+    #[test]
+    fn test_utilization_is_ratio_of_pending_to_capacity() {
+        assert_eq!(liquidity(1_000, 0).utilization_bps(), 0);
+        assert_eq!(liquidity(1_000, 250).utilization_bps(), 2_500);
+        assert_eq!(liquidity(1_000, 1_000).utilization_bps(), 10_000);
+    }
+
+    #[test]
+    fn test_utilization_caps_when_oversubscribed() {
+        assert_eq!(liquidity(1_000, 5_000).utilization_bps(), 10_000);
+    }
+
+    #[test]
+    fn test_no_capacity_is_fully_utilized() {
+        assert_eq!(liquidity(0, 0).utilization_bps(), 10_000);
+    }
+
+    #[test]
+    fn test_validate_bounds() {
+        assert!(validate_bounds(&UtilizationFeeBounds { min_bps: 50, max_bps: 300 }).is_ok());
+        assert_eq!(
+            validate_bounds(&UtilizationFeeBounds { min_bps: 300, max_bps: 50 }),
+            Err(ContractError::InvalidUtilizationBounds)
+        );
+        assert_eq!(
+            validate_bounds(&UtilizationFeeBounds { min_bps: 0, max_bps: 10_001 }),
+            Err(ContractError::InvalidUtilizationBounds)
+        );
+    }
+}
@@ -0,0 +1,139 @@
+//! Periodic credit-signal events for senders and agents.
+//!
+//! Third-party credit scorers and microfinance partners can model repayment
+//! capacity and reliability from remittance history, but only if that history
+//! is readable from chain data. Every milestone counted by the performance
+//! metrics is therefore also accumulated into a per-party [`ActivitySummary`]
+//! for the current period of [`CREDIT_SIGNAL_PERIOD_SECONDS`], once for the
+//! sender and once for the agent.
+//!
+//! When a party's first activity in a new period is recorded, the summary for
+//! its previous period is emitted as a `("credit", "sender"|"agent")` event
+//! tagged with [`CREDIT_SIGNAL_VERSION`]. Parties that go quiet can have their
+//! closed period published by anyone through `publish_credit_signal`.
+//!
+//! A settlement counts as on time when it completes within
+//! [`CREDIT_SIGNAL_ON_TIME_SECONDS`] of creation.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::config::{CREDIT_SIGNAL_ON_TIME_SECONDS, CREDIT_SIGNAL_PERIOD_SECONDS};
+use crate::performance::PerformanceEvent;
+use crate::storage::{get_activity_summary, remove_activity_summary, set_activity_summary};
+use crate::Remittance;
+
+/// Side of a remittance a party's activity is summarized for.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CreditParty {
+    Sender,
+    Agent,
+}
+
+/// A party's remittance activity within one period.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ActivitySummary {
+    /// Period index (`timestamp / CREDIT_SIGNAL_PERIOD_SECONDS`)
+    pub period: u64,
+    pub created: u32,
+    pub completed: u32,
+    /// Completed within `CREDIT_SIGNAL_ON_TIME_SECONDS` of creation
+    pub on_time: u32,
+    pub expired: u32,
+    pub disputed: u32,
+    /// Value of remittances created in the period
+    pub created_volume: i128,
+    /// Value of remittances completed in the period
+    pub completed_volume: i128,
+}
+
+impl ActivitySummary {
+    fn is_empty(&self) -> bool {
+        self.created == 0 && self.completed == 0 && self.expired == 0 && self.disputed == 0
+    }
+
+    /// Share of completed remittances settled on time, in basis points.
+    pub fn on_time_rate_bps(&self) -> u32 {
+        if self.completed == 0 {
+            return 0;
+        }
+        (self.on_time as u64 * 10_000 / self.completed as u64) as u32
+    }
+
+    fn apply(&mut self, event: PerformanceEvent, remittance: &Remittance) {
+        match event {
+            PerformanceEvent::Created => {
+                self.created = self.created.saturating_add(1);
+                self.created_volume = self.created_volume.saturating_add(remittance.amount);
+            }
+            PerformanceEvent::Completed => {
+                self.completed = self.completed.saturating_add(1);
+                self.completed_volume = self.completed_volume.saturating_add(remittance.amount);
+                if remittance
+                    .settlement_latency()
+                    .is_some_and(|secs| secs <= CREDIT_SIGNAL_ON_TIME_SECONDS)
+                {
+                    self.on_time = self.on_time.saturating_add(1);
+                }
+            }
+            PerformanceEvent::Expired => self.expired = self.expired.saturating_add(1),
+            PerformanceEvent::Disputed => self.disputed = self.disputed.saturating_add(1),
+        }
+    }
+}
+
+fn current_period(env: &Env) -> u64 {
+    env.ledger().timestamp() / CREDIT_SIGNAL_PERIOD_SECONDS
+}
+
+/// Accumulates a lifecycle milestone into the sender's and agent's summaries,
+/// emitting any summary whose period has closed.
+pub fn record(env: &Env, event: PerformanceEvent, remittance: &Remittance) {
+    let period = current_period(env);
+    for (party, role) in [
+        (&remittance.sender, CreditParty::Sender),
+        (&remittance.agent, CreditParty::Agent),
+    ] {
+        let mut summary = get_activity_summary(env, party, role).unwrap_or_default();
+        if summary.period != period {
+            if !summary.is_empty() {
+                crate::events::emit_credit_signal(env, party.clone(), role, summary);
+            }
+            summary = ActivitySummary {
+                period,
+                ..Default::default()
+            };
+        }
+        summary.apply(event, remittance);
+        set_activity_summary(env, party, role, &summary);
+    }
+}
+
+/// Emits and clears a party's summary if its period has closed.
+///
+/// Returns the published summary, or `None` if the party has no closed period
+/// pending.
+pub fn publish(env: &Env, party: &Address, role: CreditParty) -> Option<ActivitySummary> {
+    let summary = get_activity_summary(env, party, role)?;
+    if summary.period >= current_period(env) {
+        return None;
+    }
+    remove_activity_summary(env, party, role);
+    crate::events::emit_credit_signal(env, party.clone(), role, summary.clone());
+    Some(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_on_time_rate() {
+        let mut summary = ActivitySummary::default();
+        assert_eq!(summary.on_time_rate_bps(), 0);
+        summary.completed = 4;
+        summary.on_time = 3;
+        assert_eq!(summary.on_time_rate_bps(), 7_500);
+    }
+}
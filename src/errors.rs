@@ -318,6 +318,694 @@ pub enum ContractError {
     /// This operation requires the remittance to be in a Disputed state.
     NotDisputed = 71,
 
+    /// The dispute window for the remittance has closed.
+    /// Cause: Raising a dispute after the window set with `set_dispute_window`.
+    DisputeWindowExpired = 72,
+
     /// Evidence hash for a dispute is not a valid 32-byte SHA-256 commitment.
     MalformedEvidenceHash = 83,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Observer Errors (84-86)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Observer contract is already registered.
+    /// Cause: Calling register_observer with an address that is already in the registry.
+    ObserverAlreadyRegistered = 84,
+
+    /// Observer contract is not registered.
+    /// Cause: Calling remove_observer with an unknown address.
+    ObserverNotFound = 85,
+
+    /// Observer registry is full.
+    /// Cause: Registering more than MAX_OBSERVERS observer contracts.
+    TooManyObservers = 86,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Sub-Operator Errors (87-88)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Address is not a sub-operator of the remittance's agent.
+    /// Cause: Revoking an unknown sub-operator, or confirming on behalf of another agent.
+    SubOperatorNotFound = 87,
+
+    /// Sub-operator daily limit exceeded.
+    /// Cause: Confirmations in the current 24-hour window would exceed the sub-operator's limit.
+    SubOperatorLimitExceeded = 88,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Organization Errors (89-94)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Organization not found.
+    /// Cause: Referencing an organization ID that was never created.
+    OrganizationNotFound = 89,
+
+    /// Address is not a member of the organization.
+    NotOrgMember = 90,
+
+    /// Member spending limit exceeded.
+    /// Cause: Member's spending in the current 24-hour window would exceed their limit.
+    OrgMemberLimitExceeded = 91,
+
+    /// Organization balance is too low for the requested remittance or withdrawal.
+    InsufficientOrgBalance = 92,
+
+    /// Organization remittance request not found.
+    /// Cause: Referencing a request ID that does not exist for this organization.
+    OrgRequestNotFound = 93,
+
+    /// Organization remittance request has already been approved or rejected.
+    OrgRequestNotPending = 94,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Voucher Errors (95-96)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Agent has not registered a voucher signing key.
+    /// Cause: Submitting a voucher before calling set_voucher_key.
+    VoucherKeyNotSet = 95,
+
+    /// Voucher nonce has already been consumed.
+    /// Cause: Replaying a previously submitted voucher.
+    VoucherNonceUsed = 96,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Pickup Secret Errors (97-99)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Pickup secret required but not available.
+    /// Cause: Confirming a remittance with a registered pickup hash without presenting
+    /// the secret, or a high-value remittance that has no pickup hash registered.
+    PickupSecretRequired = 97,
+
+    /// Presented pickup secret does not hash to the registered value.
+    InvalidPickupSecret = 98,
+
+    /// A pickup secret hash is already registered for this remittance.
+    PickupSecretAlreadySet = 99,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Tag Errors (100)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Too many tags supplied for a remittance.
+    /// Cause: More than MAX_REMITTANCE_TAGS tags, or the same tag given twice.
+    InvalidTags = 100,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Routing Errors (101-103)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Route is malformed.
+    /// Cause: No hops or more than MAX_ROUTE_HOPS, an unregistered or repeated hop
+    /// agent, or hop fees totalling 100% or more of the payout.
+    InvalidRoute = 101,
+
+    /// Caller is not the agent of the next unconfirmed leg.
+    /// Cause: Confirming a leg out of order or confirming on a non-routed remittance.
+    RouteLegOutOfOrder = 102,
+
+    /// Final payout attempted before every intermediate leg was confirmed.
+    RouteIncomplete = 103,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Netting Pair Errors (104-106)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// No netting pair is registered between the two agents.
+    NettingPairNotFound = 104,
+
+    /// A netting pair is already registered between the two agents.
+    NettingPairExists = 105,
+
+    /// Netting pair still has an unsettled net position.
+    /// Cause: Removing a pair before running a netting checkpoint.
+    NettingPositionOpen = 106,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Auto-Settlement Errors (107-109)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Agent has not enabled auto-settlement for this operator.
+    AutoSettlementNotEnabled = 107,
+
+    /// Auto-settlement limit exceeded.
+    /// Cause: Remittance above the agent's per-remittance cap, or the operator's
+    /// settlements in the current 24-hour window would exceed the daily limit.
+    AutoSettlementLimitExceeded = 108,
+
+    /// No platform settlement operator has been designated by the admin.
+    SettlementOperatorNotSet = 109,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Corridor Utilization Errors (110-111)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// No fee corridor is configured for the country pair.
+    /// Cause: Setting utilization bounds before calling `set_fee_corridor`.
+    FeeCorridorNotFound = 110,
+
+    /// Utilization fee bounds are invalid.
+    /// Cause: `min_bps` above `max_bps`, or `max_bps` above 10000.
+    InvalidUtilizationBounds = 111,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Fee Guardrail Errors (112-113)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Platform fee exceeds the absolute ceiling.
+    /// Cause: `update_fee` with a value above `MAX_PLATFORM_FEE_BPS`.
+    FeeAboveCeiling = 112,
+
+    /// Platform fee change is too large for the current window.
+    /// Cause: Moving the fee more than `MAX_FEE_CHANGE_BPS` away from the value
+    /// in effect at the start of the 24-hour guardrail window.
+    FeeChangeTooLarge = 113,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Expiry Tolerance Errors (114)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Expiry tolerance exceeds `MAX_EXPIRY_TOLERANCE_SECONDS`.
+    ExpiryToleranceTooLarge = 114,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Batch Settlement Errors (115)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// A batch moves more of a token than its per-batch limit allows.
+    /// Cause: `batch_settle_with_netting` entries in one token summing above
+    /// the limit set with `set_token_batch_limit`.
+    TokenBatchLimitExceeded = 115,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Tier Errors (116-117)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Assignment would push the agent past its tier's pending value limit.
+    /// Cause: Creating or reassigning a remittance to an agent whose Pending
+    /// remittances already approach the tier maximum.
+    AgentPendingLimitExceeded = 116,
+
+    /// Agent's tier does not allow this remittance.
+    /// Cause: Assigning a remittance at or above `LARGE_REMITTANCE_THRESHOLD`
+    /// to a Basic-tier agent.
+    AgentTierTooLow = 117,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Verification Errors (118)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Remittance exceeds the limits for agents without KYB verification.
+    /// Cause: Assigning more than `UNVERIFIED_AGENT_MAX_REMITTANCE` in one
+    /// remittance, or more than `UNVERIFIED_AGENT_DAILY_LIMIT` in a day, to an
+    /// agent whose KYB flag is not set.
+    UnverifiedAgentLimitExceeded = 118,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Insurance Errors (119-123)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Remittance already has an insurance policy.
+    /// Cause: Calling `insure_remittance` twice for the same remittance.
+    AlreadyInsured = 119,
+
+    /// Remittance has no insurance policy.
+    /// Cause: Filing a claim for a remittance that was never insured.
+    NotInsured = 120,
+
+    /// A claim was already filed or the policy already paid out.
+    /// Cause: Filing a second claim for the same remittance.
+    ClaimAlreadyFiled = 121,
+
+    /// No claim has been filed for the remittance.
+    ClaimNotFound = 122,
+
+    /// Claim was already approved or denied.
+    ClaimAlreadyResolved = 123,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Donation Errors (124-126)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Cause is not registered or has been deactivated.
+    CauseNotFound = 124,
+
+    /// Donation percentage is invalid.
+    /// Cause: Pledging 0 bps or more than `MAX_DONATION_BPS`.
+    InvalidDonation = 125,
+
+    /// Settlement path cannot apportion a donation.
+    /// Cause: Partial payout or netted batch settlement of a remittance with
+    /// a donation pledge.
+    DonationNotSupported = 126,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Savings Errors (127-129)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Savings balance is too low for the requested withdrawal.
+    InsufficientSavingsBalance = 127,
+
+    /// Savings goal not found.
+    /// Cause: Goal ID does not exist or belongs to another owner.
+    SavingsGoalNotFound = 128,
+
+    /// Savings goal parameters are invalid.
+    /// Cause: Non-positive target or a deadline that is not in the future.
+    InvalidSavingsGoal = 129,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Advance Errors (130-135)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Agent may not draw an advance.
+    /// Cause: Reputation below `ADVANCE_MIN_REPUTATION` or no stake in the token.
+    AdvanceNotEligible = 130,
+
+    /// Requested advance exceeds the collateral or stake limit.
+    AdvanceLimitExceeded = 131,
+
+    /// Agent already has an outstanding advance in the token.
+    /// Cause: Drawing a second advance, or unstaking while one is outstanding.
+    AdvanceOutstanding = 132,
+
+    /// Agent has no outstanding advance in the token.
+    AdvanceNotFound = 133,
+
+    /// Advance cannot be liquidated before it is due.
+    AdvanceNotDue = 134,
+
+    /// Advance pool holds too little of the token for the requested advance.
+    InsufficientAdvanceLiquidity = 135,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Private Remittance Errors (136)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Revealed amount and salt do not match the stored commitment.
+    AmountCommitmentMismatch = 136,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Encrypted Memo Errors (137-138)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Encrypted memo is empty or larger than `MAX_ENCRYPTED_MEMO_BYTES`.
+    InvalidEncryptedMemo = 137,
+
+    /// An encrypted memo is already attached to this remittance.
+    EncryptedMemoAlreadySet = 138,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Hash-Only Record Errors (139-140)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Presented remittance details do not match the stored hash.
+    DetailsHashMismatch = 139,
+
+    /// Corridor only accepts hash-only remittances.
+    /// Cause: Creating a full remittance on a corridor marked hash-only.
+    HashOnlyCorridor = 140,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Remittance Template Errors (141-142)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Template does not exist or belongs to another sender.
+    TemplateNotFound = 141,
+
+    /// Template parameters are invalid.
+    /// Cause: Empty name, non-positive amount, memo over `MAX_MEMO_BYTES`, or
+    /// `MAX_TEMPLATES_PER_SENDER` reached.
+    InvalidTemplate = 142,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Favorite Agent Errors (143)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Sender already has `MAX_FAVORITE_AGENTS` favorite agents.
+    FavoriteAgentLimit = 143,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Address Book Errors (144-145)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Address book already holds `MAX_ADDRESS_BOOK_ENTRIES` entries.
+    AddressBookFull = 144,
+
+    /// No address book entry exists for the alias hash.
+    AddressBookEntryNotFound = 145,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Handle Registry Errors (146-149)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Handle is too short, too long, or uses characters other than `a-z`, `0-9`, `_`.
+    InvalidHandle = 146,
+
+    /// Handle is currently registered to another owner.
+    HandleTaken = 147,
+
+    /// Handle is not registered or its registration has lapsed.
+    HandleNotFound = 148,
+
+    /// Handle has been frozen by the admin.
+    HandleFrozen = 149,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Corridor Configuration Errors (150-151)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Corridor has been disabled by the admin.
+    CorridorDisabled = 150,
+
+    /// Corridor configuration batch is invalid.
+    /// Cause: Empty batch, more than `MAX_CORRIDOR_UPDATES` entries, a corridor
+    /// listed twice, a malformed country code, or a `Corridor` fee strategy.
+    InvalidCorridorUpdate = 151,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Scheduled Configuration Errors (152-153)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Scheduled change cannot be accepted.
+    /// Cause: Effective time sooner than the governance timelock allows, or
+    /// `MAX_SCHEDULED_CONFIG_CHANGES` already pending.
+    InvalidSchedule = 152,
+
+    /// No pending scheduled change has this ID.
+    ScheduledChangeNotFound = 153,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Maintenance Window Errors (154-155)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// New remittances are refused during a scheduled maintenance window.
+    /// Cause: Creating a remittance while a window is active; retry after the
+    /// `end` returned by `get_active_maintenance_window`.
+    MaintenanceWindow = 154,
+
+    /// Maintenance window is invalid or unknown.
+    /// Cause: Start not before end, end already past, `MAX_MAINTENANCE_WINDOWS`
+    /// reached, or cancelling a window that does not exist.
+    InvalidMaintenanceWindow = 155,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Emergency Evacuation Errors (156-159)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// No guardian has been appointed.
+    GuardianNotSet = 156,
+
+    /// An evacuation is already pending.
+    EvacuationPending = 157,
+
+    /// No evacuation is pending.
+    EvacuationNotFound = 158,
+
+    /// Evacuation has not been approved or its countdown has not ended.
+    EvacuationNotReady = 159,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Recovery Errors (160)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// No key-loss recovery is open for the remittance's agent.
+    /// Cause: Approving a reassignment before the admin proposed a replacement,
+    /// or after the recovery was cancelled.
+    AgentRecoveryNotFound = 160,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Account Recovery Errors (161-164)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Owner has not registered recovery guardians.
+    RecoveryGuardiansNotSet = 161,
+
+    /// Recovery guardian set is invalid.
+    /// Cause: More than `MAX_RECOVERY_GUARDIANS`, a duplicate guardian, the
+    /// owner listed as its own guardian, or a threshold of zero or above the
+    /// number of guardians.
+    InvalidRecoveryGuardians = 162,
+
+    /// No account recovery is in progress for the owner.
+    AccountRecoveryNotFound = 163,
+
+    /// Account recovery has not reached its threshold or its challenge period
+    /// has not ended.
+    AccountRecoveryNotReady = 164,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Passkey Errors (165-166)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Passkey public key is not an uncompressed SEC-1 point.
+    /// Cause: Registering a compressed or malformed secp256r1 key.
+    InvalidPasskey = 165,
+
+    /// Escrow has no recipient passkey to verify a claim code against.
+    /// Cause: Claiming an escrow before its sender set a claim key.
+    ClaimKeyNotSet = 166,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Token Transfer Errors (167-171)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Token contract rejected a transfer for a reason without a specific mapping.
+    TokenTransferFailed = 167,
+
+    /// Payer's token balance is too low for the transfer.
+    TokenBalanceInsufficient = 168,
+
+    /// Payer's or payee's token balance is frozen by the asset issuer.
+    /// Cause: The issuer revoked authorization on the account's trustline.
+    TokenAccountFrozen = 169,
+
+    /// Payee has no account or no trustline for the token.
+    TokenTrustlineMissing = 170,
+
+    /// Payee has no undelivered payout held in the token.
+    NoUndeliveredPayout = 171,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Batch Budget Errors (172)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Batch's estimated CPU cost exceeds the transaction budget.
+    /// Cause: More than `MAX_SAFE_BATCH_SIZE` entries; split the batch.
+    BatchTooLarge = 172,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Instance Factory Errors (173)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// An instance is already registered for the corridor.
+    CorridorInstanceExists = 173,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Hub Registry Errors (174)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// The hub registry contract rejected or failed to record the instance.
+    HubRegistrationFailed = 174,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Remittance Forwarding Errors (175)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// The other SwiftRemit instance is not trusted for forwarding.
+    /// Cause: The admin has not called `set_trusted_instance` for it.
+    UntrustedInstance = 175,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Escrow Claim Errors (176)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// The escrow has not been pending long enough to become claimable.
+    /// Cause: Less than `ESCROW_CLAIM_FALLBACK_SECONDS` since it was created.
+    EscrowClaimNotDue = 176,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Path Payout Errors (177-178)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Payouts cannot be converted to another asset on this contract.
+    /// Cause: The admin has not set a path payment adapter.
+    PathPayoutUnavailable = 177,
+
+    /// The path payment for a converted payout did not go through.
+    /// Cause: The adapter failed or the agent received less than `min_receive`.
+    PathPaymentFailed = 178,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Anchor Linkage Errors (179-180)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// The remittance or the anchor transaction is already linked.
+    /// Cause: `link_anchor_tx` called twice for the same remittance or transaction ID.
+    AnchorTxAlreadyLinked = 179,
+
+    /// The remittance has no linked anchor transaction.
+    /// Cause: `update_anchor_status` called before `link_anchor_tx`.
+    AnchorTxNotLinked = 180,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Firm Quote Errors (181-185)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// The quote terms are invalid.
+    /// Cause: Non-positive rate, negative fee, or an expiry not within
+    /// `MAX_QUOTE_TTL_SECONDS` from now.
+    InvalidQuote = 181,
+
+    /// The quote does not exist.
+    /// Cause: Unknown quote ID.
+    QuoteNotFound = 182,
+
+    /// The quote is no longer open for acceptance.
+    /// Cause: Accepted after its `expires_at`.
+    QuoteExpired = 183,
+
+    /// The quote was already accepted for another remittance.
+    /// Cause: Quotes are firm and single-use.
+    QuoteAlreadyAccepted = 184,
+
+    /// Settlement would pay out a different amount than the quote was accepted for.
+    /// Cause: Protocol fees changed between acceptance and settlement.
+    QuoteTermsChanged = 185,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Delivery Proof Errors (186)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// A high-value payout was confirmed without a delivery proof.
+    /// Cause: Amount at or above the delivery-proof threshold, confirmed
+    /// other than through `confirm_with_delivery_proof`.
+    DeliveryProofRequired = 186,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Compliance Restriction Errors (187-188)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// The token or corridor is blocked by compliance.
+    /// Cause: A `Restriction::Blocked` is set on it.
+    Restricted = 187,
+
+    /// The party's KYC tier is too low for the token or corridor.
+    /// Cause: A `Restriction::MinKycTier` above the party's current tier.
+    KycTierTooLow = 188,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Priority Boost Errors (189)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// The remittance is already at the highest priority level.
+    /// Cause: `boost_priority` called at `MAX_PRIORITY_LEVEL`.
+    PriorityAtMaximum = 189,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Cancellation Errors (190-191)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// The sender can no longer cancel the remittance alone.
+    /// Cause: The agent has accepted it and its SLA deadline has not passed;
+    /// use `mutual_cancel_remittance` or the dispute flow instead.
+    CancelWindowClosed = 190,
+
+    /// The sender cannot cancel the remittance yet.
+    /// Cause: The cancel cooldown since creation has not elapsed and the agent
+    /// has not rejected the remittance.
+    CancelCooldownActive = 191,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Limits Errors (192)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// The remittance amount is outside the platform amount bounds.
+    /// Cause: Below `Limits::min_amount` or above a non-zero `Limits::max_amount`.
+    AmountOutsideLimits = 192,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Expiry Errors (193)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// The remittance has not expired.
+    /// Cause: `expire_remittance` called on a remittance with no expiry, or
+    /// before its expiry is reached.
+    RemittanceNotExpired = 193,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Exposure Errors (194-195)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// The agent does not hold the stake the collateral requirement asks for.
+    /// Cause: Assigning a remittance above the threshold to an agent staking
+    /// less than the minimum, or unstaking below the minimum while the agent
+    /// has pending remittances.
+    AgentStakeRequired = 194,
+
+    /// The assignment would take the agent past its outstanding capacity.
+    /// Cause: The agent's pending value plus the remittance amount exceeds the
+    /// cap set with `set_agent_capacity`.
+    AgentCapacityExceeded = 195,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Lookup and Migration Errors (196-197)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// The requested record does not exist.
+    NotFound = 196,
+
+    /// Migrated state did not pass post-migration validation.
+    MigrationValidationFailed = 197,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Circuit Breaker Errors (198-199)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Timelock duration is outside the allowed range.
+    InvalidTimelockDuration = 198,
+
+    /// No pause record exists for the given sequence number.
+    PauseRecordNotFound = 199,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Reputation Errors (200)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Agent reputation is below the configured minimum.
+    /// Cause: Assigning a remittance to an agent scoring under
+    /// `set_min_agent_reputation`.
+    BelowMinReputation = 200,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Recipient Hash Errors (201-203)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// No recipient hash was stored for the remittance.
+    MissingRecipientHash = 201,
+
+    /// The stored recipient hash uses a different schema version.
+    RecipientHashSchemaMismatch = 202,
+
+    /// The supplied recipient details do not match the stored hash.
+    RecipientHashMismatch = 203,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Agent Withdrawal Errors (204)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// The payout would take the agent past its daily withdrawal cap.
+    /// Cause: Payouts in the current 24-hour window exceed the cap set with
+    /// `set_agent_daily_cap`.
+    AgentDailyCapExceeded = 204,
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Organization Approval Errors (205)
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Organization already has the maximum number of requests awaiting approval.
+    /// Cause: Owner must approve or reject pending requests before members can open more.
+    OrgPendingRequestsFull = 205,
 }
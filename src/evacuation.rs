@@ -0,0 +1,140 @@
+//! Guardian-gated emergency evacuation of escrowed funds.
+//!
+//! If a critical vulnerability is found, every token the contract holds can be
+//! moved to a recovery address. Because that is the most dangerous operation
+//! in the contract, no single key can trigger it:
+//!
+//! 1. An admin or the guardian proposes an evacuation to a recovery address.
+//! 2. The other party approves it, which arms a countdown of
+//!    [`EVACUATION_DELAY_SECONDS`]. The countdown is announced by event, and
+//!    anyone can re-announce the remaining time while it runs.
+//! 3. Once the countdown ends, anyone may execute it: the contract is paused
+//!    and the full balance of every whitelisted token goes to the recovery
+//!    address.
+//!
+//! Either party can cancel at any point before execution. The guardian is a
+//! dedicated address that cannot be an admin, and appointing or rotating it
+//! needs the consent of the admin, the new guardian, and any current guardian.
+//! If the guardian key is also an admin key, it only ever counts as the
+//! guardian, so one key can never both propose and approve.
+
+use soroban_sdk::{contracttype, token, Address, Env, Vec};
+
+use crate::config::EVACUATION_DELAY_SECONDS;
+use crate::storage::{
+    get_all_whitelisted_tokens, get_evacuation, get_guardian, is_admin, remove_evacuation,
+    set_evacuation, set_paused,
+};
+use crate::token_transfer;
+use crate::ContractError;
+
+/// Which key holder acted on an evacuation.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EvacuationParty {
+    Admin,
+    Guardian,
+}
+
+/// A proposed evacuation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EvacuationRequest {
+    pub recovery: Address,
+    pub proposed_by: EvacuationParty,
+    pub proposed_at: u64,
+    /// Set once the other party approves
+    pub executable_at: Option<u64>,
+}
+
+/// Authenticates `caller` and returns the role it acts in.
+pub fn party(env: &Env, caller: &Address) -> Result<EvacuationParty, ContractError> {
+    caller.require_auth();
+    let guardian = get_guardian(env).ok_or(ContractError::GuardianNotSet)?;
+    if *caller == guardian {
+        Ok(EvacuationParty::Guardian)
+    } else if is_admin(env, caller) {
+        Ok(EvacuationParty::Admin)
+    } else {
+        Err(ContractError::Unauthorized)
+    }
+}
+
+/// Records a new evacuation proposal.
+pub fn propose(
+    env: &Env,
+    caller: &Address,
+    recovery: Address,
+) -> Result<EvacuationRequest, ContractError> {
+    let proposed_by = party(env, caller)?;
+    if get_evacuation(env).is_some() {
+        return Err(ContractError::EvacuationPending);
+    }
+    let request = EvacuationRequest {
+        recovery,
+        proposed_by,
+        proposed_at: env.ledger().timestamp(),
+        executable_at: None,
+    };
+    set_evacuation(env, &request);
+    Ok(request)
+}
+
+/// Approves the pending proposal on behalf of the party that did not propose
+/// it, starting the countdown.
+pub fn approve(env: &Env, caller: &Address) -> Result<EvacuationRequest, ContractError> {
+    let approver = party(env, caller)?;
+    let mut request = get_evacuation(env).ok_or(ContractError::EvacuationNotFound)?;
+    if approver == request.proposed_by || request.executable_at.is_some() {
+        return Err(ContractError::Unauthorized);
+    }
+    request.executable_at = Some(
+        env.ledger()
+            .timestamp()
+            .saturating_add(EVACUATION_DELAY_SECONDS),
+    );
+    set_evacuation(env, &request);
+    Ok(request)
+}
+
+/// Cancels the pending evacuation.
+pub fn cancel(env: &Env, caller: &Address) -> Result<(), ContractError> {
+    party(env, caller)?;
+    get_evacuation(env).ok_or(ContractError::EvacuationNotFound)?;
+    remove_evacuation(env);
+    Ok(())
+}
+
+/// Recovery address and seconds left on an armed countdown.
+pub fn remaining(env: &Env) -> Result<(Address, u64), ContractError> {
+    let request = get_evacuation(env).ok_or(ContractError::EvacuationNotFound)?;
+    let executable_at = request.executable_at.ok_or(ContractError::EvacuationNotReady)?;
+    Ok((
+        request.recovery,
+        executable_at.saturating_sub(env.ledger().timestamp()),
+    ))
+}
+
+/// Pauses the contract and moves every whitelisted token balance to the
+/// recovery address. Returns the request and the `(token, amount)` pairs moved.
+pub fn execute(env: &Env) -> Result<(EvacuationRequest, Vec<(Address, i128)>), ContractError> {
+    let request = get_evacuation(env).ok_or(ContractError::EvacuationNotFound)?;
+    match request.executable_at {
+        Some(at) if env.ledger().timestamp() >= at => {}
+        _ => return Err(ContractError::EvacuationNotReady),
+    }
+
+    set_paused(env, true);
+    remove_evacuation(env);
+    let contract = env.current_contract_address();
+    let mut moved = Vec::new(env);
+    for token_address in get_all_whitelisted_tokens(env).iter() {
+        let client = token::Client::new(env, &token_address);
+        let balance = client.balance(&contract);
+        if balance > 0 {
+            token_transfer::transfer(&client, &contract, &request.recovery, balance)?;
+            moved.push_back((token_address, balance));
+        }
+    }
+    Ok((request, moved))
+}
@@ -22,7 +22,9 @@
 //! emit_event!(env, "domain", "action", field1, field2);
 //! ```
 
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{
+    contractevent, symbol_short, Address, Bytes, BytesN, Env, Map, String, Symbol, Vec,
+};
 
 // ============================================================================
 // Event Schema Version
@@ -38,7 +40,7 @@ use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 //   and perform migrations when the event format evolves
 // ============================================================================
 
-use crate::config::SCHEMA_VERSION;
+use crate::config::{CREDIT_SIGNAL_VERSION, SCHEMA_VERSION};
 
 // ============================================================================
 // Core emit_event! macro (issue #475)
@@ -108,110 +110,1196 @@ pub fn emit_admin_nominated(env: &Env, nominator: Address, nominee: Address) {
     emit_event!(env, "admin", "nominated", nominator, nominee);
 }
 
+/// Emits an event when the admin changes the expiry tolerance.
+pub fn emit_expiry_tolerance_updated(env: &Env, admin: Address, old_seconds: u64, new_seconds: u64) {
+    emit_event!(env, "admin", "exp_tol", admin, old_seconds, new_seconds);
+}
+
+/// Emits an event when an address gains or loses a role.
+pub fn emit_role_updated(
+    env: &Env,
+    caller: Address,
+    address: Address,
+    role: crate::Role,
+    old_held: bool,
+    new_held: bool,
+) {
+    emit_event!(env, "role", "updated", caller, address, role, old_held, new_held);
+}
+
 /// Emits an event when admin key rotation completes: old admin removed, new admin confirmed (#842).
 pub fn emit_admin_rotated(env: &Env, old_admin: Address, new_admin: Address) {
     emit_event!(env, "admin", "rotated", old_admin, new_admin);
 }
 
-// ── Remittance Events ──────────────────────────────────────────────
+// ── Remittance Events ──────────────────────────────────────────────
+
+/// Emits an event when a new remittance is created.
+///
+/// Includes the full fee breakdown so downstream analytics and the SDK can
+/// distinguish platform fee, protocol fee, and net payout amount without
+/// re-deriving them from on-chain config. `fee_exempt` is set when the
+/// sender's fee exemption waived the platform and expiry fees.
+#[allow(clippy::too_many_arguments)]
+pub fn emit_remittance_created(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    agent: Address,
+    amount: i128,
+    fee: i128,
+    integrator_fee: i128,
+    platform_fee: i128,
+    protocol_fee: i128,
+    net_amount: i128,
+    fee_exempt: bool,
+) {
+    emit_event!(
+        env, "remit", "created",
+        remittance_id, sender, agent,
+        amount, fee, integrator_fee,
+        platform_fee, protocol_fee, net_amount, fee_exempt
+    );
+}
+
+/// Emits an event when a remittance payout is completed.
+///
+/// `settlement_latency` is `completed_at - created_at`; `processing_latency`
+/// is `completed_at - accepted_at`, or `None` if the remittance was never
+/// accepted into Processing.
+pub fn emit_remittance_completed(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    agent: Address,
+    settlement_latency: u64,
+    processing_latency: Option<u64>,
+) {
+    emit_event!(
+        env,
+        "remit",
+        "complete",
+        remittance_id,
+        sender,
+        agent,
+        settlement_latency,
+        processing_latency
+    );
+}
+
+/// Emits a lifecycle event for a minimal-disclosure remittance.
+///
+/// Stands in for the full lifecycle events and carries only the remittance ID
+/// and its new status.
+pub fn emit_remittance_status(env: &Env, remittance_id: u64, status: crate::RemittanceStatus) {
+    emit_event!(env, "remit", "status", remittance_id, status);
+}
+
+/// Emits a single summary event for a batch payout confirmed in compact mode.
+///
+/// Stands in for the per-remittance completion events: `payouts` holds the
+/// `(remittance_id, payout_amount)` pairs and `agent_totals` the amount paid
+/// out to each agent across the batch.
+pub fn emit_batch_payout_completed(
+    env: &Env,
+    payouts: Vec<(u64, i128)>,
+    agent_totals: Map<Address, i128>,
+) {
+    emit_event!(env, "batch", "complete", payouts, agent_totals);
+}
+
+#[contractevent(topics = ["batch", "paid"], data_format = "single-value")]
+struct BatchPaid {
+    count: u32,
+}
+
+/// Emits an event when a batch payout call finishes, carrying the number of
+/// remittances it paid out.
+pub fn emit_batch_paid(env: &Env, count: u32) {
+    BatchPaid { count }.publish(env);
+}
+
+/// Emits an event when a remittance is handed from one agent to another.
+///
+/// The sender is included so wallets can notify them of the new agent.
+pub fn emit_remittance_reassigned(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    from_agent: Address,
+    to_agent: Address,
+) {
+    emit_event!(env, "remit", "reassign", remittance_id, sender, from_agent, to_agent);
+}
+
+/// Emits an event when an agent rejects a pending remittance, so the sender
+/// can cancel or reassign it.
+pub fn emit_remittance_rejected(env: &Env, remittance_id: u64, sender: Address, agent: Address) {
+    emit_event!(env, "remit", "rejected", remittance_id, sender, agent);
+}
+
+#[contractevent(topics = ["remit", "tagged"], data_format = "vec")]
+struct RemittanceTagged {
+    #[topic]
+    tag: Symbol,
+    #[topic]
+    action: Symbol,
+    schema_version: u32,
+    sequence: u32,
+    timestamp: u64,
+    remittance_id: u64,
+    sender: Address,
+    agent: Address,
+    amount: i128,
+}
+
+/// Emits a per-tag event for a tagged remittance.
+///
+/// Topics are `("remit", "tagged", tag, action)` so senders can subscribe to a
+/// single tag with a standard RPC topic filter, e.g. `remit/tagged/payroll/*`.
+/// `action` mirrors the untagged event (`created` or `complete`).
+pub fn emit_remittance_tagged(
+    env: &Env,
+    tag: Symbol,
+    action: Symbol,
+    remittance_id: u64,
+    sender: Address,
+    agent: Address,
+    amount: i128,
+) {
+    RemittanceTagged {
+        tag,
+        action,
+        schema_version: SCHEMA_VERSION,
+        sequence: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+        remittance_id,
+        sender,
+        agent,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Emits an event when a remittance is cancelled.
+pub fn emit_remittance_cancelled(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    agent: Address,
+    token: Address,
+    amount: i128,
+) {
+    emit_event!(env, "remit", "cancel", remittance_id, sender, agent, token, amount);
+}
+
+/// Emits a summary event for remittances a sender cancelled together, after
+/// the per-remittance cancellation events.
+pub fn emit_batch_cancelled(env: &Env, sender: Address, remittance_ids: Vec<u64>) {
+    emit_event!(env, "batch", "cancelled", sender, remittance_ids);
+}
+
+/// Emits an event when a remittance is cancelled with a structured reason.
+pub fn emit_remittance_cancelled_with_reason(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    agent: Address,
+    token: Address,
+    amount: i128,
+    reason: String,
+) {
+    emit_event!(env, "remit", "cancel_r", remittance_id, sender, agent, token, amount, reason);
+}
+
+// ── Agent Events ───────────────────────────────────────────────────
+
+/// Emits an event when a new agent is registered.
+pub fn emit_agent_registered(env: &Env, agent: Address, caller: Address, kyc_hash: Option<soroban_sdk::BytesN<32>>) {
+    emit_event!(env, "agent", "register", agent, caller, kyc_hash);
+}
+
+/// Emits an event when an agent is removed.
+pub fn emit_agent_removed(env: &Env, agent: Address, caller: Address) {
+    emit_event!(env, "agent", "removed", agent, caller);
+}
+
+/// Emits a single event for the agents registered by a batch, with the
+/// number of entries that failed.
+pub fn emit_agents_registered(env: &Env, agents: Vec<Address>, failed: u32, caller: Address) {
+    emit_event!(env, "agent", "reg_batch", agents, failed, caller);
+}
+
+/// Emits a single event for the agents removed by a batch, with the number
+/// of entries that failed.
+pub fn emit_agents_removed(env: &Env, agents: Vec<Address>, failed: u32, caller: Address) {
+    emit_event!(env, "agent", "rem_batch", agents, failed, caller);
+}
+
+/// Emits an event when an agent's KYB verification flag is changed.
+pub fn emit_agent_kyb_updated(env: &Env, agent: Address, verified: bool, caller: Address) {
+    emit_event!(env, "agent", "kyb", agent, verified, caller);
+}
+
+/// Emits an event when an agent's tier is changed.
+pub fn emit_agent_tier_updated(env: &Env, agent: Address, tier: crate::AgentTier, caller: Address) {
+    emit_event!(env, "agent", "tier", agent, tier, caller);
+}
+
+/// Emits an event when an agent's pending-value cap changes; 0 means none.
+pub fn emit_agent_capacity_set(env: &Env, agent: Address, cap: i128, caller: Address) {
+    emit_event!(env, "agent", "capacity", agent, cap, caller);
+}
+
+/// Emits an event when an agent authorizes a sub-operator.
+pub fn emit_sub_operator_added(env: &Env, agent: Address, operator: Address, daily_limit: i128) {
+    emit_event!(env, "subop", "added", agent, operator, daily_limit);
+}
+
+/// Emits an event when an agent revokes a sub-operator.
+pub fn emit_sub_operator_revoked(env: &Env, agent: Address, operator: Address) {
+    emit_event!(env, "subop", "revoked", agent, operator);
+}
+
+// ── Auto-Settlement Events ─────────────────────────────────────────
+
+/// Emits an event when the admin designates the platform settlement operator.
+pub fn emit_settlement_operator_set(env: &Env, operator: Address, admin: Address) {
+    emit_event!(env, "autoset", "operator", operator, admin);
+}
+
+/// Emits an event when an agent enables or updates auto-settlement.
+pub fn emit_auto_settlement_enabled(
+    env: &Env,
+    agent: Address,
+    operator: Address,
+    max_per_remittance: i128,
+    daily_limit: i128,
+) {
+    emit_event!(env, "autoset", "enabled", agent, operator, max_per_remittance, daily_limit);
+}
+
+/// Emits an event when an agent revokes auto-settlement.
+pub fn emit_auto_settlement_disabled(env: &Env, agent: Address) {
+    emit_event!(env, "autoset", "disabled", agent);
+}
+
+// ── Organization Events ────────────────────────────────────────────
+
+/// Emits an event when an organization account is created.
+pub fn emit_org_created(env: &Env, org_id: u64, owner: Address, token: Address) {
+    emit_event!(env, "org", "created", org_id, owner, token);
+}
+
+/// Emits an event when funds are deposited into an organization's shared balance.
+pub fn emit_org_deposit(env: &Env, org_id: u64, from: Address, amount: i128, balance: i128) {
+    emit_event!(env, "org", "deposit", org_id, from, amount, balance);
+}
+
+/// Emits an event when the owner withdraws from the shared balance.
+pub fn emit_org_withdraw(env: &Env, org_id: u64, to: Address, amount: i128, balance: i128) {
+    emit_event!(env, "org", "withdraw", org_id, to, amount, balance);
+}
+
+/// Emits an event when a member is added or their spending limit changes.
+pub fn emit_org_member_set(env: &Env, org_id: u64, member: Address, spending_limit: i128) {
+    emit_event!(env, "org", "mem_set", org_id, member, spending_limit);
+}
+
+/// Emits an event when a member is removed from an organization.
+pub fn emit_org_member_removed(env: &Env, org_id: u64, member: Address) {
+    emit_event!(env, "org", "mem_rm", org_id, member);
+}
+
+/// Emits an event when a member initiates a remittance from the shared balance.
+pub fn emit_org_remittance(env: &Env, org_id: u64, member: Address, remittance_id: u64, amount: i128) {
+    emit_event!(env, "org", "remit", org_id, member, remittance_id, amount);
+}
+
+/// Emits an event when an over-limit member remittance is held for owner approval.
+pub fn emit_org_approval_requested(env: &Env, org_id: u64, request_id: u64, member: Address, amount: i128) {
+    emit_event!(env, "org", "appr_req", org_id, request_id, member, amount);
+}
+
+/// Emits an event when the owner approves a held request and its remittance is created.
+pub fn emit_org_request_approved(env: &Env, org_id: u64, request_id: u64, remittance_id: u64) {
+    emit_event!(env, "org", "approved", org_id, request_id, remittance_id);
+}
+
+/// Emits an event when the owner rejects a held request.
+pub fn emit_org_request_rejected(env: &Env, org_id: u64, request_id: u64) {
+    emit_event!(env, "org", "rejected", org_id, request_id);
+}
+
+// ── Netting Pair Events ────────────────────────────────────────────
+
+/// Emits an event when two agents register a bilateral netting pair.
+pub fn emit_netting_pair_registered(env: &Env, agent_a: Address, agent_b: Address, token: Address) {
+    emit_event!(env, "netpair", "register", agent_a, agent_b, token);
+}
+
+/// Emits an event when a netting pair is removed.
+pub fn emit_netting_pair_removed(env: &Env, agent_a: Address, agent_b: Address) {
+    emit_event!(env, "netpair", "removed", agent_a, agent_b);
+}
+
+/// Emits an event when an obligation is recorded against a netting pair.
+pub fn emit_netting_obligation(env: &Env, debtor: Address, creditor: Address, amount: i128, net_position: i128) {
+    emit_event!(env, "netpair", "oblig", debtor, creditor, amount, net_position);
+}
+
+/// Emits an event when a netting checkpoint settles a pair's net position.
+pub fn emit_netting_checkpoint(env: &Env, debtor: Address, creditor: Address, amount: i128, obligations: u32) {
+    emit_event!(env, "netpair", "checkpt", debtor, creditor, amount, obligations);
+}
+
+// ── Routing Events ─────────────────────────────────────────────────
+
+/// Emits an event when a remittance is created with a multi-hop route.
+pub fn emit_route_created(env: &Env, remittance_id: u64, hops: u32) {
+    emit_event!(env, "route", "created", remittance_id, hops);
+}
+
+/// Emits an event when an agent confirms its leg of a routed remittance.
+pub fn emit_route_leg_confirmed(env: &Env, remittance_id: u64, leg: u32, agent: Address) {
+    emit_event!(env, "route", "leg_ok", remittance_id, leg, agent);
+}
+
+/// Emits an event when a hop receives its share of a routed payout.
+pub fn emit_route_hop_paid(env: &Env, remittance_id: u64, agent: Address, amount: i128) {
+    emit_event!(env, "route", "hop_paid", remittance_id, agent, amount);
+}
+
+// ── Voucher Events ─────────────────────────────────────────────────
+
+/// Emits an event when an agent registers or rotates its voucher signing key.
+pub fn emit_voucher_key_set(env: &Env, agent: Address, public_key: BytesN<32>) {
+    emit_event!(env, "voucher", "key_set", agent, public_key);
+}
+
+/// Emits an event when an agent registers or rotates a passkey for vouchers.
+pub fn emit_passkey_voucher_key_set(env: &Env, agent: Address, public_key: BytesN<65>) {
+    emit_event!(env, "voucher", "passkey", agent, public_key);
+}
+
+/// Emits an event when a remittance is settled with a signed voucher.
+pub fn emit_voucher_settled(env: &Env, remittance_id: u64, agent: Address, nonce: u64) {
+    emit_event!(env, "voucher", "settled", remittance_id, agent, nonce);
+}
+
+// ── Observer Events ────────────────────────────────────────────────
+
+/// Emits an event when an observer contract is registered.
+pub fn emit_observer_added(env: &Env, observer: Address, caller: Address) {
+    emit_event!(env, "observer", "added", observer, caller);
+}
+
+/// Emits an event when an observer contract is removed.
+pub fn emit_observer_removed(env: &Env, observer: Address, caller: Address) {
+    emit_event!(env, "observer", "removed", observer, caller);
+}
+
+/// Emits an event when an observer call reverts; the triggering operation still succeeds.
+pub fn emit_observer_failed(
+    env: &Env,
+    observer: Address,
+    remittance_id: u64,
+    event: crate::observers::ObserverEvent,
+) {
+    emit_event!(env, "observer", "failed", observer, remittance_id, event);
+}
+
+/// Emits an event when a user is added to the blacklist.
+pub fn emit_user_blacklisted(env: &Env, user: Address, caller: Address) {
+    emit_event!(env, "blacklist", "added", user, caller);
+}
+
+/// Emits an event when a user is removed from the blacklist.
+pub fn emit_user_removed_from_blacklist(env: &Env, user: Address, caller: Address) {
+    emit_event!(env, "blacklist", "removed", user, caller);
+}
+
+// ── Token Whitelist Events ─────────────────────────────────────────
+
+/// Emits an event when a token's per-batch settlement limit changes.
+pub fn emit_token_batch_limit_updated(
+    env: &Env,
+    token: Address,
+    old_limit: Option<i128>,
+    new_limit: Option<i128>,
+    caller: Address,
+) {
+    emit_event!(env, "token", "batch_lim", token, old_limit, new_limit, caller);
+}
+
+/// Emits an event when a token is added to the whitelist.
+pub fn emit_token_whitelisted(env: &Env, token: Address, caller: Address) {
+    emit_event!(env, "token", "whitelist", token, caller);
+}
+
+/// Emits an event when a token is removed from the whitelist.
+pub fn emit_token_removed_from_whitelist(env: &Env, token: Address, caller: Address) {
+    emit_event!(env, "token", "rm_white", token, caller);
+}
+
+/// Emits an event when a token-specific fee configuration is updated.
+///
+/// `old_fee_bps` is `None` when the token previously used the platform fee.
+pub fn emit_token_fee_updated(
+    env: &Env,
+    caller: Address,
+    token: Address,
+    old_fee_bps: Option<u32>,
+    new_fee_bps: u32,
+) {
+    emit_event!(env, "token", "fee_upd", caller, token, old_fee_bps, new_fee_bps);
+}
+
+// ── Corridor Utilization Events ────────────────────────────────────
+
+/// Emits an event when the admin sets utilization fee bounds for a corridor.
+pub fn emit_utilization_bounds_set(
+    env: &Env,
+    from_country: String,
+    to_country: String,
+    min_bps: u32,
+    max_bps: u32,
+) {
+    emit_event!(env, "corridor", "util_set", from_country, to_country, min_bps, max_bps);
+}
+
+/// Emits an event when the admin removes utilization pricing from a corridor.
+pub fn emit_utilization_bounds_removed(env: &Env, from_country: String, to_country: String) {
+    emit_event!(env, "corridor", "util_rm", from_country, to_country);
+}
+
+/// Emits an event when an agent updates its registered capacity on a corridor.
+pub fn emit_agent_capacity_updated(
+    env: &Env,
+    agent: Address,
+    from_country: String,
+    to_country: String,
+    capacity: i128,
+    corridor_capacity: i128,
+) {
+    emit_event!(env, "corridor", "capacity", agent, from_country, to_country, capacity, corridor_capacity);
+}
+
+// ── Insurance Events ───────────────────────────────────────────────
+
+/// Emits an event when a sender insures a remittance.
+pub fn emit_insurance_purchased(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    premium: i128,
+    coverage: i128,
+) {
+    emit_event!(env, "insure", "bought", remittance_id, sender, premium, coverage);
+}
+
+/// Emits an event when an insured remittance's sender is compensated.
+pub fn emit_insurance_paid(env: &Env, remittance_id: u64, sender: Address, amount: i128) {
+    emit_event!(env, "insure", "paid", remittance_id, sender, amount);
+}
+
+/// Emits an event when a sender files an insurance claim.
+pub fn emit_insurance_claim_filed(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    evidence_hash: BytesN<32>,
+) {
+    emit_event!(env, "insure", "claimed", remittance_id, sender, evidence_hash);
+}
+
+/// Emits an event when a resolver approves or denies an insurance claim.
+pub fn emit_insurance_claim_resolved(
+    env: &Env,
+    remittance_id: u64,
+    resolver: Address,
+    approved: bool,
+    payout: i128,
+) {
+    emit_event!(env, "insure", "resolved", remittance_id, resolver, approved, payout);
+}
+
+/// Emits an event when the insurance fund is topped up.
+pub fn emit_insurance_funded(env: &Env, token: Address, amount: i128, funder: Address) {
+    emit_event!(env, "insure", "funded", token, amount, funder);
+}
+
+// ── Agent Advance Events ───────────────────────────────────────────
+
+/// Emits an event when an agent's stake in a token changes.
+pub fn emit_agent_stake_updated(env: &Env, agent: Address, token: Address, delta: i128, staked: i128) {
+    emit_event!(env, "stake", "updated", agent, token, delta, staked);
+}
+
+/// Emits the new collateral requirement; a zero `min_stake` means none.
+pub fn emit_collateral_requirement_updated(env: &Env, threshold: i128, min_stake: i128) {
+    emit_event!(env, "stake", "required", threshold, min_stake);
+}
+
+/// Emits an event when an agent's stake is slashed to compensate a sender.
+pub fn emit_agent_slashed(
+    env: &Env,
+    remittance_id: u64,
+    agent: Address,
+    sender: Address,
+    token: Address,
+    amount: i128,
+    staked: i128,
+) {
+    emit_event!(env, "stake", "slashed", remittance_id, agent, sender, token, amount, staked);
+}
+
+/// Emits an event when the admin tops up the advance pool.
+pub fn emit_advance_pool_funded(env: &Env, token: Address, amount: i128, caller: Address) {
+    emit_event!(env, "advance", "funded", token, amount, caller);
+}
+
+/// Emits an event when an agent draws a working-capital advance.
+pub fn emit_advance_drawn(env: &Env, agent: Address, token: Address, amount: i128, due_at: u64) {
+    emit_event!(env, "advance", "drawn", agent, token, amount, due_at);
+}
+
+/// Emits an event when part of a payout is withheld to repay an advance.
+pub fn emit_advance_repaid(env: &Env, agent: Address, token: Address, amount: i128, outstanding: i128) {
+    emit_event!(env, "advance", "repaid", agent, token, amount, outstanding);
+}
+
+/// Emits an event when a defaulted advance is liquidated against the agent's stake.
+pub fn emit_advance_liquidated(env: &Env, agent: Address, token: Address, seized: i128, written_off: i128) {
+    emit_event!(env, "advance", "liquidate", agent, token, seized, written_off);
+}
+
+// ── Private Remittance Events ──────────────────────────────────────
+
+/// Emits an event when a private remittance is created. Carries only the
+/// commitment, never the amount.
+pub fn emit_private_remittance_created(
+    env: &Env,
+    id: u64,
+    sender: Address,
+    agent: Address,
+    commitment: BytesN<32>,
+) {
+    emit_event!(env, "private", "created", id, sender, agent, commitment);
+}
+
+/// Emits an event when a private remittance settles and its amount is revealed.
+pub fn emit_private_remittance_settled(env: &Env, id: u64, agent: Address, amount: i128, payout: i128) {
+    emit_event!(env, "private", "settled", id, agent, amount, payout);
+}
+
+/// Emits an event when the sender cancels a private remittance.
+pub fn emit_private_remittance_cancelled(env: &Env, id: u64, sender: Address) {
+    emit_event!(env, "private", "cancelled", id, sender);
+}
+
+// ── Hash-Only Remittance Events ────────────────────────────────────
+
+/// Emits an event when a hash-only remittance is created. Carries only the
+/// details hash, never the counterparties.
+pub fn emit_hashed_remittance_created(env: &Env, id: u64, amount: i128, details_hash: BytesN<32>) {
+    emit_event!(env, "hashed", "created", id, amount, details_hash);
+}
+
+/// Emits an event when a hash-only remittance settles.
+pub fn emit_hashed_remittance_settled(env: &Env, id: u64, payout: i128) {
+    emit_event!(env, "hashed", "settled", id, payout);
+}
+
+/// Emits an event when a hash-only remittance is cancelled and refunded.
+pub fn emit_hashed_remittance_cancelled(env: &Env, id: u64) {
+    emit_event!(env, "hashed", "cancelled", id);
+}
+
+// ── Remittance Template Events ─────────────────────────────────────
+
+/// Emits an event when a sender saves a remittance template.
+pub fn emit_template_saved(env: &Env, template_id: u64, owner: Address) {
+    emit_event!(env, "template", "saved", template_id, owner);
+}
+
+/// Emits an event when a sender deletes a remittance template.
+pub fn emit_template_deleted(env: &Env, template_id: u64, owner: Address) {
+    emit_event!(env, "template", "deleted", template_id, owner);
+}
+
+/// Emits an event when a remittance is created from a template.
+pub fn emit_template_used(env: &Env, template_id: u64, remittance_id: u64) {
+    emit_event!(env, "template", "used", template_id, remittance_id);
+}
+
+// ── Favorite Agent Events ──────────────────────────────────────────
+
+/// Emits an event when a sender adds an agent to their favorites.
+pub fn emit_favorite_agent_added(env: &Env, sender: Address, agent: Address) {
+    emit_event!(env, "favorite", "added", sender, agent);
+}
+
+/// Emits an event when a sender removes an agent from their favorites.
+pub fn emit_favorite_agent_removed(env: &Env, sender: Address, agent: Address) {
+    emit_event!(env, "favorite", "removed", sender, agent);
+}
+
+// ── Handle Events ──────────────────────────────────────────────────
+
+/// Emits an event when a handle is registered.
+pub fn emit_handle_registered(env: &Env, handle: String, owner: Address, expires_at: u64) {
+    emit_event!(env, "handle", "claimed", handle, owner, expires_at);
+}
+
+/// Emits an event when a handle's registration is extended.
+pub fn emit_handle_renewed(env: &Env, handle: String, owner: Address, expires_at: u64) {
+    emit_event!(env, "handle", "renewed", handle, owner, expires_at);
+}
+
+/// Emits an event when a handle moves to a new owner.
+pub fn emit_handle_transferred(env: &Env, handle: String, from: Address, to: Address) {
+    emit_event!(env, "handle", "transfer", handle, from, to);
+}
+
+/// Emits an event when the admin freezes or unfreezes a handle.
+pub fn emit_handle_frozen(env: &Env, handle: String, frozen: bool) {
+    emit_event!(env, "handle", "frozen", handle, frozen);
+}
+
+// ── Corridor Configuration Events ──────────────────────────────────
+
+/// Emits an event for each corridor changed by a batched configuration update.
+pub fn emit_corridor_config_updated(env: &Env, from_country: String, to_country: String) {
+    emit_event!(env, "corridor", "updated", from_country, to_country);
+}
+
+// ── Scheduled Configuration Events ─────────────────────────────────
+
+/// Emits an event when a configuration change is scheduled, announcing it ahead
+/// of its effective time.
+pub fn emit_config_change_scheduled(
+    env: &Env,
+    id: u64,
+    change: crate::scheduled_config::ConfigChange,
+    effective_at: u64,
+) {
+    emit_event!(env, "config", "scheduled", id, change, effective_at);
+}
+
+/// Emits an event when a scheduled configuration change is withdrawn.
+pub fn emit_config_change_cancelled(env: &Env, id: u64) {
+    emit_event!(env, "config", "cancelled", id);
+}
+
+// ── Maintenance Window Events ──────────────────────────────────────
+
+/// Emits an event when a maintenance window is scheduled.
+pub fn emit_maintenance_scheduled(env: &Env, start: u64, end: u64) {
+    emit_event!(env, "maint", "scheduled", start, end);
+}
+
+/// Emits an event when a maintenance window is cancelled.
+pub fn emit_maintenance_cancelled(env: &Env, start: u64) {
+    emit_event!(env, "maint", "cancelled", start);
+}
+
+// ── Emergency Evacuation Events ────────────────────────────────────
+
+/// Emits an event when a guardian is appointed or rotated.
+pub fn emit_guardian_set(env: &Env, guardian: Address, admin: Address) {
+    emit_event!(env, "guardian", "set", guardian, admin);
+}
+
+/// Emits an event when an emergency evacuation is proposed.
+pub fn emit_evacuation_proposed(
+    env: &Env,
+    recovery: Address,
+    proposed_by: crate::evacuation::EvacuationParty,
+) {
+    emit_event!(env, "evac", "proposed", recovery, proposed_by);
+}
+
+/// Emits an event when an evacuation is approved and its countdown starts.
+pub fn emit_evacuation_armed(env: &Env, recovery: Address, executable_at: u64) {
+    emit_event!(env, "evac", "armed", recovery, executable_at);
+}
+
+/// Emits the time remaining on an armed evacuation countdown.
+pub fn emit_evacuation_countdown(env: &Env, recovery: Address, remaining_seconds: u64) {
+    emit_event!(env, "evac", "countdown", recovery, remaining_seconds);
+}
+
+/// Emits an event when a pending evacuation is cancelled.
+pub fn emit_evacuation_cancelled(env: &Env, cancelled_by: Address) {
+    emit_event!(env, "evac", "cancelled", cancelled_by);
+}
+
+/// Emits an event for each token balance moved by an executed evacuation.
+pub fn emit_evacuation_executed(env: &Env, recovery: Address, token: Address, amount: i128) {
+    emit_event!(env, "evac", "executed", recovery, token, amount);
+}
+
+// ── Agent Recovery Events ──────────────────────────────────────────
+
+/// Emits an event when a replacement is proposed for an agent that lost its key.
+pub fn emit_agent_recovery_proposed(env: &Env, lost_agent: Address, replacement: Address) {
+    emit_event!(env, "agentrec", "proposed", lost_agent, replacement);
+}
+
+/// Emits an event when an open agent recovery is cancelled.
+pub fn emit_agent_recovery_cancelled(env: &Env, lost_agent: Address) {
+    emit_event!(env, "agentrec", "cancelled", lost_agent);
+}
+
+// ── Account Recovery Events ────────────────────────────────────────
+
+/// Emits an event when an owner changes their recovery guardians.
+pub fn emit_recovery_guardians_set(env: &Env, owner: Address, count: u32, threshold: u32) {
+    emit_event!(env, "acctrec", "guardians", owner, count, threshold);
+}
+
+/// Emits an event when a guardian approves an account recovery.
+pub fn emit_account_recovery_approved(
+    env: &Env,
+    owner: Address,
+    new_owner: Address,
+    guardian: Address,
+) {
+    emit_event!(env, "acctrec", "approved", owner, new_owner, guardian);
+}
+
+/// Emits an event when an account recovery reaches its threshold and its
+/// challenge period starts.
+pub fn emit_account_recovery_armed(env: &Env, owner: Address, new_owner: Address, executable_at: u64) {
+    emit_event!(env, "acctrec", "armed", owner, new_owner, executable_at);
+}
+
+/// Emits an event when the owner cancels an account recovery.
+pub fn emit_account_recovery_cancelled(env: &Env, owner: Address) {
+    emit_event!(env, "acctrec", "cancelled", owner);
+}
+
+/// Emits an event when an account recovery executes.
+pub fn emit_account_recovery_executed(
+    env: &Env,
+    owner: Address,
+    new_owner: Address,
+    remittances_migrated: u32,
+) {
+    emit_event!(env, "acctrec", "executed", owner, new_owner, remittances_migrated);
+}
+
+// ── Undelivered Payout Events ──────────────────────────────────────
+
+/// Emits an event when a best-effort batch holds a payout it could not
+/// deliver. `error` is the `ContractError` code of the failed transfer.
+pub fn emit_payout_held(
+    env: &Env,
+    remittance_id: u64,
+    payee: Address,
+    token: Address,
+    amount: i128,
+    error: u32,
+) {
+    emit_event!(env, "payout", "held", remittance_id, payee, token, amount, error);
+}
+
+/// Emits an event when a payee withdraws its undelivered payouts.
+pub fn emit_undelivered_payout_claimed(env: &Env, payee: Address, token: Address, amount: i128) {
+    emit_event!(env, "payout", "claimed", payee, token, amount);
+}
+
+// ── External Call Events ───────────────────────────────────────────
+
+/// Emits a diagnostic when a call to another contract fails.
+///
+/// `remittance_id` is 0 when the call was not made for a remittance, and
+/// `error_code` is the callee's contract error code, or 0 if it trapped
+/// without one.
+pub fn emit_external_call_failed(
+    env: &Env,
+    callee: Address,
+    function: Symbol,
+    remittance_id: u64,
+    error_code: u32,
+) {
+    emit_event!(env, "extcall", "failed", callee, function, remittance_id, error_code);
+}
+
+// ── Checkpoint Events ──────────────────────────────────────────────
+
+/// Emits an indexer checkpoint. `checkpoint` numbers checkpoints from 1, so a
+/// gap shows that events were missed.
+pub fn emit_checkpoint(
+    env: &Env,
+    checkpoint: u64,
+    remittance_counter: u64,
+    pending_count: u64,
+    processing_count: u64,
+    accumulated_fees: i128,
+    accumulated_integrator_fees: i128,
+) {
+    emit_event!(
+        env,
+        "sys",
+        "chkpt",
+        checkpoint,
+        remittance_counter,
+        pending_count,
+        processing_count,
+        accumulated_fees,
+        accumulated_integrator_fees
+    );
+}
+
+// ── Instance Factory Events ────────────────────────────────────────
+
+/// Emits an event when the factory deploys and registers a corridor instance.
+pub fn emit_instance_deployed(
+    env: &Env,
+    corridor: crate::RemittanceCorridor,
+    instance: Address,
+    token: Address,
+    caller: Address,
+) {
+    emit_event!(env, "factory", "deployed", corridor, instance, token, caller);
+}
+
+// ── Remittance Forwarding Events ───────────────────────────────────
+
+/// Emits an event when an admin trusts or distrusts another instance for
+/// remittance forwarding.
+pub fn emit_instance_trusted(env: &Env, instance: Address, trusted: bool, caller: Address) {
+    emit_event!(env, "fwd", "trusted", instance, trusted, caller);
+}
+
+/// Emits an event when a remittance is forwarded to another instance.
+pub fn emit_remittance_forwarded(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    target: Address,
+    target_id: u64,
+) {
+    emit_event!(env, "fwd", "sent", remittance_id, sender, target, target_id);
+}
+
+/// Emits an event when a remittance forwarded by another instance is recreated here.
+pub fn emit_remittance_received(
+    env: &Env,
+    remittance_id: u64,
+    sender: Address,
+    source: Address,
+    source_id: u64,
+) {
+    emit_event!(env, "fwd", "received", remittance_id, sender, source, source_id);
+}
+
+// ── Hub Registry Events ────────────────────────────────────────────
+
+/// Emits an event when the instance registers itself with a hub registry.
+pub fn emit_hub_registered(
+    env: &Env,
+    hub: Address,
+    corridor: crate::RemittanceCorridor,
+    token: Address,
+    version: String,
+) {
+    emit_event!(env, "hub", "register", hub, corridor, token, version);
+}
+
+// ── Migration Rehearsal Events ─────────────────────────────────────
+
+/// Emits the comparison for one legacy remittance in a migration rehearsal:
+/// SHA-256 hashes of its stored V1 record and of the V2 record it would be
+/// rewritten as, and whether the V2 record preserves every V1 field.
+pub fn emit_migration_rehearsed(
+    env: &Env,
+    remittance_id: u64,
+    v1_hash: BytesN<32>,
+    v2_hash: BytesN<32>,
+    preserved: bool,
+) {
+    emit_event!(env, "mig", "rehearse", remittance_id, v1_hash, v2_hash, preserved);
+}
+
+// ── Path Payout Events ─────────────────────────────────────────────
 
-/// Emits an event when a new remittance is created.
-///
-/// Includes the full fee breakdown so downstream analytics and the SDK can
-/// distinguish platform fee, protocol fee, and net payout amount without
-/// re-deriving them from on-chain config.
-pub fn emit_remittance_created(
+/// Emits the asset and minimum an agent chose for a remittance's payout.
+pub fn emit_path_payout_set(
     env: &Env,
     remittance_id: u64,
-    sender: Address,
     agent: Address,
-    amount: i128,
-    fee: i128,
-    integrator_fee: i128,
-    platform_fee: i128,
-    protocol_fee: i128,
-    net_amount: i128,
+    dest_token: Address,
+    min_receive: i128,
 ) {
-    emit_event!(
-        env, "remit", "created",
-        remittance_id, sender, agent,
-        amount, fee, integrator_fee,
-        platform_fee, protocol_fee, net_amount
-    );
+    emit_event!(env, "path", "set", remittance_id, agent, dest_token, min_receive);
 }
 
-/// Emits an event when a remittance payout is completed.
-pub fn emit_remittance_completed(
+/// Emits a payout delivered through a path payment: `sent` of the escrowed
+/// token converted into `received` of `dest_token`.
+pub fn emit_path_payout_paid(
     env: &Env,
     remittance_id: u64,
-    sender: Address,
     agent: Address,
+    sent: i128,
+    dest_token: Address,
+    received: i128,
 ) {
-    emit_event!(env, "remit", "complete", remittance_id, sender, agent);
+    emit_event!(env, "path", "paid", remittance_id, agent, sent, dest_token, received);
 }
 
-/// Emits an event when a remittance is cancelled.
-pub fn emit_remittance_cancelled(
+/// Emits a payout made in the escrowed token because converting it into the
+/// agent's preferred token failed.
+pub fn emit_path_payout_fallback(
     env: &Env,
     remittance_id: u64,
-    sender: Address,
     agent: Address,
-    token: Address,
-    amount: i128,
+    preferred_token: Address,
 ) {
-    emit_event!(env, "remit", "cancel", remittance_id, sender, agent, token, amount);
+    emit_event!(env, "path", "fallback", remittance_id, agent, preferred_token);
 }
 
-/// Emits an event when a remittance is cancelled with a structured reason.
-pub fn emit_remittance_cancelled_with_reason(
+// ── Anchor Linkage Events ──────────────────────────────────────────
+
+/// Emits the link between a remittance and an anchor's SEP-31 transaction.
+pub fn emit_anchor_tx_linked(
+    env: &Env,
+    remittance_id: u64,
+    anchor: Address,
+    tx_id: String,
+    operator: Address,
+) {
+    emit_event!(env, "anchor", "linked", remittance_id, anchor, tx_id, operator);
+}
+
+/// Emits a status change of the anchor transaction linked to a remittance.
+pub fn emit_anchor_status_updated(
+    env: &Env,
+    remittance_id: u64,
+    tx_id: String,
+    status: crate::AnchorTxStatus,
+    operator: Address,
+) {
+    emit_event!(env, "anchor", "status", remittance_id, tx_id, status, operator);
+}
+
+// ── Firm Quote Events ──────────────────────────────────────────────
+
+/// Emits a quote posted by a receiving agent.
+pub fn emit_quote_posted(
+    env: &Env,
+    quote_id: u64,
+    agent: Address,
+    corridor: crate::RemittanceCorridor,
+    rate: i128,
+    fee: i128,
+    expires_at: u64,
+) {
+    emit_event!(env, "quote", "posted", quote_id, agent, corridor, rate, fee, expires_at);
+}
+
+/// Emits the acceptance of a quote with the payout and destination amount
+/// it fixes for the remittance.
+pub fn emit_quote_accepted(
+    env: &Env,
+    quote_id: u64,
+    remittance_id: u64,
+    payout_amount: i128,
+    dest_amount: i128,
+) {
+    emit_event!(env, "quote", "accepted", quote_id, remittance_id, payout_amount, dest_amount);
+}
+
+/// Emits the settlement of a quoted remittance and the destination amount
+/// the agent owes the recipient.
+pub fn emit_quote_settled(env: &Env, remittance_id: u64, quote_id: u64, dest_amount: i128) {
+    emit_event!(env, "quote", "settled", remittance_id, quote_id, dest_amount);
+}
+
+// ── Delivery Status Events ─────────────────────────────────────────
+
+/// Emits a delivery status reported by the agent of a remittance.
+pub fn emit_delivery_status(
+    env: &Env,
+    remittance_id: u64,
+    agent: Address,
+    status: crate::DeliveryStatus,
+) {
+    emit_event!(env, "delivery", "status", remittance_id, agent, status);
+}
+
+// ── Compliance Restriction Events ──────────────────────────────────
+
+/// Emits a restriction set on a token or corridor.
+pub fn emit_restriction_set(
+    env: &Env,
+    target: crate::RestrictionTarget,
+    restriction: crate::Restriction,
+    officer: Address,
+) {
+    emit_event!(env, "restrict", "set", target, restriction, officer);
+}
+
+/// Emits a restriction lifted from a token or corridor.
+pub fn emit_restriction_lifted(env: &Env, target: crate::RestrictionTarget, officer: Address) {
+    emit_event!(env, "restrict", "lifted", target, officer);
+}
+
+/// Emits a KYC tier assigned to a party.
+pub fn emit_kyc_tier_set(env: &Env, user: Address, tier: u32, officer: Address) {
+    emit_event!(env, "kyc", "tier", user, tier, officer);
+}
+
+// ── Priority Boost Events ──────────────────────────────────────────
+
+/// Emits a priority boost paid for by the sender, with the new level and SLA deadline.
+pub fn emit_priority_boosted(
     env: &Env,
     remittance_id: u64,
     sender: Address,
+    level: u32,
+    fee: i128,
+    sla_deadline: u64,
+) {
+    emit_event!(env, "priority", "boosted", remittance_id, sender, level, fee, sla_deadline);
+}
+
+// ── Limits Events ──────────────────────────────────────────────────
+
+/// Emits a limits change scheduled behind the governance timelock.
+pub fn emit_limits_scheduled(env: &Env, caller: Address, limits: crate::Limits, effective_at: u64) {
+    emit_event!(env, "limits", "sched", caller, limits, effective_at);
+}
+
+/// Emits the old and new limits when a limits change takes effect.
+pub fn emit_limits_updated(env: &Env, old: crate::Limits, new: crate::Limits) {
+    emit_event!(env, "limits", "updated", old, new);
+}
+
+// ── Agent Commission Events ────────────────────────────────────────
+
+/// Emits the new agent commission rate, in basis points of the platform fee.
+pub fn emit_commission_rate_updated(env: &Env, caller: Address, old_bps: u32, new_bps: u32) {
+    emit_event!(env, "comm", "rate", caller, old_bps, new_bps);
+}
+
+/// Emits a commission credited to an agent at settlement, with its new balance.
+pub fn emit_commission_credited(
+    env: &Env,
     agent: Address,
     token: Address,
     amount: i128,
-    reason: String,
+    balance: i128,
 ) {
-    emit_event!(env, "remit", "cancel_r", remittance_id, sender, agent, token, amount, reason);
+    emit_event!(env, "comm", "credited", agent, token, amount, balance);
 }
 
-// ── Agent Events ───────────────────────────────────────────────────
+/// Emits an event when an agent withdraws its commission.
+pub fn emit_commission_withdrawn(env: &Env, agent: Address, token: Address, amount: i128) {
+    emit_event!(env, "comm", "withdrawn", agent, token, amount);
+}
 
-/// Emits an event when a new agent is registered.
-pub fn emit_agent_registered(env: &Env, agent: Address, caller: Address, kyc_hash: Option<soroban_sdk::BytesN<32>>) {
-    emit_event!(env, "agent", "register", agent, caller, kyc_hash);
+// ── Upgrade Events ─────────────────────────────────────────────────
+
+/// Emits the new WASM hash when the contract code is replaced, with the
+/// storage schema version in force at the time.
+pub fn emit_contract_upgraded(
+    env: &Env,
+    caller: Address,
+    new_wasm_hash: BytesN<32>,
+    schema_version: u32,
+) {
+    emit_event!(env, "upgrade", "wasm", caller, new_wasm_hash, schema_version);
 }
 
-/// Emits an event when an agent is removed.
-pub fn emit_agent_removed(env: &Env, agent: Address, caller: Address) {
-    emit_event!(env, "agent", "removed", agent, caller);
+// ── Credit Signal Events ───────────────────────────────────────────
+
+/// Emits a party's activity summary for a closed credit-signal period.
+///
+/// The payload carries `CREDIT_SIGNAL_VERSION` ahead of the summary so
+/// scorers can detect layout changes independently of `SCHEMA_VERSION`.
+pub fn emit_credit_signal(
+    env: &Env,
+    party: Address,
+    role: crate::credit_signals::CreditParty,
+    summary: crate::credit_signals::ActivitySummary,
+) {
+    let on_time_rate_bps = summary.on_time_rate_bps();
+    match role {
+        crate::credit_signals::CreditParty::Sender => emit_event!(
+            env, "credit", "sender", CREDIT_SIGNAL_VERSION, party, summary, on_time_rate_bps
+        ),
+        crate::credit_signals::CreditParty::Agent => emit_event!(
+            env, "credit", "agent", CREDIT_SIGNAL_VERSION, party, summary, on_time_rate_bps
+        ),
+    }
 }
 
-/// Emits an event when a user is added to the blacklist.
-pub fn emit_user_blacklisted(env: &Env, user: Address, caller: Address) {
-    emit_event!(env, "blacklist", "added", user, caller);
+// ── Savings Events ─────────────────────────────────────────────────
+
+/// Emits an event when a recipient changes their savings share.
+pub fn emit_savings_share_set(env: &Env, recipient: Address, bps: u32) {
+    emit_event!(env, "savings", "share", recipient, bps);
 }
 
-/// Emits an event when a user is removed from the blacklist.
-pub fn emit_user_removed_from_blacklist(env: &Env, user: Address, caller: Address) {
-    emit_event!(env, "blacklist", "removed", user, caller);
+/// Emits an event when part of a payout is kept in a recipient's savings.
+pub fn emit_savings_deposited(env: &Env, owner: Address, token: Address, amount: i128, balance: i128) {
+    emit_event!(env, "savings", "deposit", owner, token, amount, balance);
 }
 
-// ── Token Whitelist Events ─────────────────────────────────────────
+/// Emits an event when an owner withdraws from their savings.
+pub fn emit_savings_withdrawn(env: &Env, owner: Address, token: Address, amount: i128, balance: i128) {
+    emit_event!(env, "savings", "withdraw", owner, token, amount, balance);
+}
 
-/// Emits an event when a token is added to the whitelist.
-pub fn emit_token_whitelisted(env: &Env, token: Address, caller: Address) {
-    emit_event!(env, "token", "whitelist", token, caller);
+/// Emits an event when an owner creates a savings goal.
+pub fn emit_savings_goal_created(env: &Env, goal_id: u64, owner: Address, target: i128) {
+    emit_event!(env, "goal", "created", goal_id, owner, target);
 }
 
-/// Emits an event when a token is removed from the whitelist.
-pub fn emit_token_removed_from_whitelist(env: &Env, token: Address, caller: Address) {
-    emit_event!(env, "token", "rm_white", token, caller);
+/// Emits an event when a savings goal is funded.
+pub fn emit_savings_goal_funded(env: &Env, goal_id: u64, amount: i128, from_vault: bool, balance: i128) {
+    emit_event!(env, "goal", "funded", goal_id, amount, from_vault, balance);
 }
 
-/// Emits an event when a token-specific fee configuration is updated.
-pub fn emit_token_fee_updated(env: &Env, caller: Address, token: Address, fee_bps: u32) {
-    emit_event!(env, "token", "fee_upd", caller, token, fee_bps);
+/// Emits an event when funds are withdrawn from a savings goal.
+pub fn emit_savings_goal_withdrawn(env: &Env, goal_id: u64, amount: i128, fee: i128, balance: i128) {
+    emit_event!(env, "goal", "withdraw", goal_id, amount, fee, balance);
+}
+
+// ── Donation Events ────────────────────────────────────────────────
+
+/// Emits an event when the admin registers or updates a cause.
+pub fn emit_cause_registered(env: &Env, cause: Address, name: String, caller: Address) {
+    emit_event!(env, "cause", "added", cause, name, caller);
+}
+
+/// Emits an event when the admin deactivates a cause.
+pub fn emit_cause_deactivated(env: &Env, cause: Address, caller: Address) {
+    emit_event!(env, "cause", "removed", cause, caller);
+}
+
+/// Emits an event when a sender pledges part of a remittance to a cause.
+pub fn emit_donation_pledged(
+    env: &Env,
+    remittance_id: u64,
+    cause: Address,
+    bps: u32,
+    source: crate::causes::DonationSource,
+) {
+    emit_event!(env, "cause", "pledged", remittance_id, cause, bps, source);
+}
+
+/// Emits an event when a pledged donation is credited at settlement.
+pub fn emit_donation_made(env: &Env, remittance_id: u64, cause: Address, token: Address, amount: i128) {
+    emit_event!(env, "cause", "donated", remittance_id, cause, token, amount);
+}
+
+/// Emits an event when a cause withdraws its accumulated donations.
+pub fn emit_cause_withdrawal(env: &Env, cause: Address, token: Address, amount: i128) {
+    emit_event!(env, "cause", "withdraw", cause, token, amount);
 }
 
 // ── Fee Events ─────────────────────────────────────────────────────
@@ -229,8 +1317,50 @@ pub fn emit_daily_limit_updated(
 }
 
 /// Emits an event when the platform fee is updated.
-pub fn emit_fee_updated(env: &Env, fee_bps: u32) {
-    emit_event!(env, "fee", "updated", fee_bps);
+pub fn emit_fee_updated(env: &Env, old_fee_bps: u32, new_fee_bps: u32) {
+    emit_event!(env, "fee", "updated", old_fee_bps, new_fee_bps);
+}
+
+/// Emits an event when the admin replaces the fee strategy.
+pub fn emit_fee_strategy_updated(
+    env: &Env,
+    caller: Address,
+    old_strategy: crate::FeeStrategy,
+    new_strategy: crate::FeeStrategy,
+) {
+    emit_event!(env, "fee", "strategy", caller, old_strategy, new_strategy);
+}
+
+/// Emits an event when the flat platform fee component changes.
+pub fn emit_flat_fee_updated(env: &Env, caller: Address, old_flat_fee: i128, new_flat_fee: i128) {
+    emit_event!(env, "fee", "flat", caller, old_flat_fee, new_flat_fee);
+}
+
+/// Emits an event when a sender is added to or removed from the fee exemption allowlist.
+pub fn emit_fee_exemption_updated(env: &Env, caller: Address, sender: Address, exempt: bool) {
+    emit_event!(env, "fee", "exemption", caller, sender, exempt);
+}
+
+/// Emits an event when the admin changes the rate limit configuration.
+///
+/// `old_config` is `None` if rate limiting had not been configured.
+pub fn emit_rate_limit_updated(
+    env: &Env,
+    caller: Address,
+    old_config: Option<crate::RateLimitConfig>,
+    new_config: crate::RateLimitConfig,
+) {
+    emit_event!(env, "limit", "rate", caller, old_config, new_config);
+}
+
+/// Emits an event when the admin changes the per-sender settlement cooldown.
+pub fn emit_rate_limit_cooldown_updated(
+    env: &Env,
+    admin: Address,
+    old_seconds: u64,
+    new_seconds: u64,
+) {
+    emit_event!(env, "limit", "cooldown", admin, old_seconds, new_seconds);
 }
 
 /// Emits an event when accumulated fees are withdrawn.
@@ -244,8 +1374,27 @@ pub fn emit_fees_flushed(env: &Env, treasury: Address, token: Address, amount: i
 }
 
 /// Emits an event when the protocol fee is updated.
-pub fn emit_protocol_fee_updated(env: &Env, caller: Address, fee_bps: u32) {
-    emit_event!(env, "fee", "proto_upd", caller, fee_bps);
+pub fn emit_protocol_fee_updated(env: &Env, caller: Address, old_fee_bps: u32, new_fee_bps: u32) {
+    emit_event!(env, "fee", "proto_upd", caller, old_fee_bps, new_fee_bps);
+}
+
+/// Emits an event when the admin changes the expiry-weighted fee slope.
+///
+/// `old_config` is `None` if no slope was configured before.
+pub fn emit_expiry_fee_config_updated(
+    env: &Env,
+    caller: Address,
+    old_config: Option<crate::fee_service::ExpiryFeeConfig>,
+    new_config: crate::fee_service::ExpiryFeeConfig,
+) {
+    emit_event!(env, "fee", "exp_cfg", caller, old_config, new_config);
+}
+
+/// Emits an event when a remittance is charged an expiry-weighted fee.
+///
+/// `lock_seconds` is `u64::MAX` for remittances without an expiry.
+pub fn emit_expiry_fee_charged(env: &Env, remittance_id: u64, lock_seconds: u64, expiry_fee: i128) {
+    emit_event!(env, "fee", "expiry", remittance_id, lock_seconds, expiry_fee);
 }
 
 /// Emits an event when a sender raises a dispute on a failed remittance.
@@ -285,16 +1434,16 @@ pub fn emit_partial_payout(env: &Env, remittance_id: u64, agent: Address, amount
 /// Emits an event when a pending remittance is expired by any caller after its expiry window.
 ///
 /// Topics: `("remit", "expired")`
-/// Payload: `(schema_version, ledger_seq, ledger_ts, remittance_id, sender, token, refund_amount, expires_at)`
+/// Payload: `(schema_version, ledger_seq, ledger_ts, remittance_id, sender, token, refund_amount, expiry)`
 pub fn emit_remittance_expired(
     env: &Env,
     remittance_id: u64,
     sender: Address,
     token: Address,
     refund_amount: i128,
-    expires_at: u64,
+    expiry: crate::Expiry,
 ) {
-    emit_event!(env, "remit", "expired", remittance_id, sender, token, refund_amount, expires_at);
+    emit_event!(env, "remit", "expired", remittance_id, sender, token, refund_amount, expiry);
 }
 
 pub fn emit_agent_cap_set(env: &Env, agent: Address, cap: i128, caller: Address) {
@@ -327,6 +1476,21 @@ pub fn emit_circuit_breaker_unpaused(env: &Env, caller: Address, timestamp: u64)
     );
 }
 
+/// Emits an event when the unpause timelock changes.
+pub fn emit_pause_timelock_updated(env: &Env, caller: Address, old_seconds: u64, new_seconds: u64) {
+    emit_event!(env, "cb", "timelock", caller, old_seconds, new_seconds);
+}
+
+/// Emits an event when the number of admin votes needed to unpause changes.
+pub fn emit_unpause_quorum_updated(env: &Env, caller: Address, old_quorum: u32, new_quorum: u32) {
+    emit_event!(env, "cb", "quorum", caller, old_quorum, new_quorum);
+}
+
+/// Emits an event when the post-unpause cooldown period changes.
+pub fn emit_cooldown_period_updated(env: &Env, caller: Address, old_seconds: u64, new_seconds: u64) {
+    emit_event!(env, "cb", "cooldown", caller, old_seconds, new_seconds);
+}
+
 // ── Recipient Address Verification Events ─────────────────────────
 
 /// Emits an event when a recipient hash is registered for a remittance.
@@ -383,6 +1547,100 @@ pub fn emit_recipient_verification_failed(env: &Env, remittance_id: u64, agent:
     );
 }
 
+#[contractevent(topics = ["pickup_reg"], data_format = "single-value")]
+struct PickupSecretRegistered {
+    #[topic]
+    remittance_id: u64,
+    sender: Address,
+}
+
+/// Emits an event when a sender registers a pickup secret hash for a remittance.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the remittance
+/// * `sender` - Address of the sender who registered the hash
+pub fn emit_pickup_secret_registered(env: &Env, remittance_id: u64, sender: Address) {
+    PickupSecretRegistered {
+        remittance_id,
+        sender,
+    }
+    .publish(env);
+}
+
+#[contractevent(topics = ["pickup_ok"], data_format = "single-value")]
+struct PickupSecretVerified {
+    #[topic]
+    remittance_id: u64,
+    agent: Address,
+}
+
+/// Emits an event when an agent presents the correct pickup secret at payout time.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the remittance
+/// * `agent` - Address of the agent who confirmed the payout
+pub fn emit_pickup_secret_verified(env: &Env, remittance_id: u64, agent: Address) {
+    PickupSecretVerified {
+        remittance_id,
+        agent,
+    }
+    .publish(env);
+}
+
+#[contractevent(topics = ["delivery_proof"], data_format = "vec")]
+struct DeliveryProofRecorded {
+    #[topic]
+    remittance_id: u64,
+    agent: Address,
+    proof: BytesN<32>,
+}
+
+/// Emits an event when a delivery proof is recorded at payout.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the remittance
+/// * `agent` - Address of the agent who confirmed the payout
+/// * `proof` - Hash of the agent's delivery evidence
+pub fn emit_delivery_proof_recorded(env: &Env, remittance_id: u64, agent: Address, proof: BytesN<32>) {
+    DeliveryProofRecorded {
+        remittance_id,
+        agent,
+        proof,
+    }
+    .publish(env);
+}
+
+#[contractevent(topics = ["memo_attached"], data_format = "single-value")]
+struct EncryptedMemoAttached {
+    #[topic]
+    remittance_id: u64,
+    ciphertext: Bytes,
+}
+
+/// Emits the encrypted memo envelope attached to a remittance.
+///
+/// This is the only event carrying the ciphertext; it is emitted once, when
+/// the memo is attached.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - ID of the remittance
+/// * `ciphertext` - Memo encrypted to the recipient's key
+pub fn emit_encrypted_memo_attached(env: &Env, remittance_id: u64, ciphertext: Bytes) {
+    EncryptedMemoAttached {
+        remittance_id,
+        ciphertext,
+    }
+    .publish(env);
+}
+
 // ── Settlement / Escrow / Treasury Events (stubs for backward compatibility) ──
 
 /// Emits an event when a settlement is completed (alias for emit_remittance_completed with extra fields).
@@ -441,6 +1699,22 @@ pub fn emit_escrow_released(
     );
 }
 
+#[contractevent(topics = ["escrow_claim_key"], data_format = "single-value")]
+struct EscrowClaimKeySet {
+    #[topic]
+    transfer_id: u64,
+    public_key: BytesN<65>,
+}
+
+/// Emits an event when a recipient passkey is attached to an escrow transfer.
+pub fn emit_escrow_claim_key_set(env: &Env, transfer_id: u64, public_key: BytesN<65>) {
+    EscrowClaimKeySet {
+        transfer_id,
+        public_key,
+    }
+    .publish(env);
+}
+
 /// Emits an event when an escrow transfer is refunded to the sender.
 pub fn emit_escrow_refunded(
     env: &Env,
@@ -454,6 +1728,42 @@ pub fn emit_escrow_refunded(
     );
 }
 
+#[contractevent(topics = ["escrow_claimable"], data_format = "vec")]
+struct EscrowClaimable {
+    #[topic]
+    transfer_id: u64,
+    recipient: Address,
+    amount: i128,
+}
+
+/// Emits an event when an unreleased escrow becomes claimable by its recipient.
+pub fn emit_escrow_claimable(env: &Env, transfer_id: u64, recipient: Address, amount: i128) {
+    EscrowClaimable {
+        transfer_id,
+        recipient,
+        amount,
+    }
+    .publish(env);
+}
+
+#[contractevent(topics = ["escrow_redeemed"], data_format = "vec")]
+struct EscrowRedeemed {
+    #[topic]
+    transfer_id: u64,
+    recipient: Address,
+    amount: i128,
+}
+
+/// Emits an event when a recipient redeems a claimable escrow.
+pub fn emit_escrow_redeemed(env: &Env, transfer_id: u64, recipient: Address, amount: i128) {
+    EscrowRedeemed {
+        transfer_id,
+        recipient,
+        amount,
+    }
+    .publish(env);
+}
+
 /// Emits an event when the treasury address is updated.
 pub fn emit_treasury_updated(
     env: &Env,
@@ -557,20 +1867,6 @@ pub fn emit_agent_suspended(env: &Env, agent: Address, reputation: u32, min_thre
     emit_event!(env, "agent", "suspnded", agent, reputation, min_threshold);
 }
 
-/// Emits when an agent's reputation score drops below the minimum threshold (#833).
-///
-/// Fired at the point of the failed eligibility check inside `create_remittance`
-/// so off-chain systems can detect and flag low-reputation agents automatically.
-///
-/// # Topics
-/// `("agent", "suspended")`
-///
-/// # Payload
-/// `(schema_version, ledger_sequence, ledger_timestamp, agent, reputation_score, min_threshold)`
-pub fn emit_agent_suspended(env: &Env, agent: Address, reputation: u32, min_threshold: u32) {
-    emit_event!(env, "agent", "suspnded", agent, reputation, min_threshold);
-}
-
 /// Emits when a cross-contract migration is aborted and state is reset to Idle.
 pub fn emit_migration_aborted(env: &Env, caller: Address) {
     env.events().publish(
@@ -609,14 +1905,6 @@ pub fn emit_admin_transfer_accepted(env: &Env, old_admin: Address, new_admin: Ad
     );
 }
 
-pub fn emit_dispute_resolved(env: &Env, id: u64, in_favour_of_sender: bool) {
-    env.events().publish((Symbol::new(env, "dispute_resolved"), id), in_favour_of_sender);
-}
-
-pub fn emit_remittance_failed(env: &Env, id: u64, agent: Address) {
-    env.events().publish((Symbol::new(env, "remittance_failed"), id), agent);
-}
-
 // ── Multi-Sig Events ──────────────────────────────────────────────
 
 pub fn emit_operation_proposed(env: &Env, op_id: u64, proposer: Address, op_type_tag: u32) {
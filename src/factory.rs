@@ -0,0 +1,111 @@
+//! Factory for per-corridor SwiftRemit instances.
+//!
+//! Each new market runs on its own instance so that its limits, fees, and
+//! pause state stay isolated. [`deploy`] installs an uploaded SwiftRemit WASM
+//! at an address derived from this contract and a salt, initializes it, and
+//! wires up the same roles as this contract:
+//!
+//! - the calling admin becomes the instance's initial admin;
+//! - every other admin of this contract is added as an instance admin;
+//! - this contract's treasury receives the instance's protocol fees;
+//! - any extra role grants in the [`InstanceConfig`] are applied.
+//!
+//! The instance is then recorded in a registry keyed by corridor, readable
+//! with `get_corridor_instance` and `get_corridor_instances`, so scaling to a
+//! new market is one call rather than a bespoke deployment script.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
+
+use crate::storage::{
+    add_corridor_instance, get_admin_list, get_corridor_instance, get_treasury,
+};
+use crate::{ContractError, RemittanceCorridor, Role, SwiftRemitContractClient};
+
+/// Settings for a new corridor instance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstanceConfig {
+    pub corridor: RemittanceCorridor,
+    /// Settlement token of the instance
+    pub token: Address,
+    pub fee_bps: u32,
+    pub protocol_fee_bps: u32,
+    pub rate_limit_cooldown: u64,
+    /// Additional `(address, role)` grants applied after initialization
+    pub roles: Vec<(Address, Role)>,
+}
+
+/// A factory-deployed instance in the corridor registry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CorridorInstance {
+    pub corridor: RemittanceCorridor,
+    pub address: Address,
+    pub token: Address,
+    /// WASM the instance was deployed from
+    pub wasm_hash: BytesN<32>,
+    pub deployed_at: u64,
+}
+
+/// Deploys, initializes, and registers an instance for `config.corridor`.
+///
+/// `caller` must already be authenticated as an admin; it also authorizes the
+/// role wiring calls made on the new instance.
+///
+/// # Errors
+///
+/// * `ContractError::CorridorInstanceExists` - The corridor already has an instance
+pub fn deploy(
+    env: &Env,
+    caller: &Address,
+    wasm_hash: BytesN<32>,
+    salt: BytesN<32>,
+    config: InstanceConfig,
+) -> Result<CorridorInstance, ContractError> {
+    if get_corridor_instance(env, &config.corridor).is_some() {
+        return Err(ContractError::CorridorInstanceExists);
+    }
+
+    let address = env
+        .deployer()
+        .with_current_contract(salt)
+        .deploy_v2(wasm_hash.clone(), ());
+    let instance = SwiftRemitContractClient::new(env, &address);
+    instance.initialize(
+        caller,
+        &config.token,
+        &config.fee_bps,
+        &config.rate_limit_cooldown,
+        &config.protocol_fee_bps,
+        &get_treasury(env)?,
+    );
+    for admin in get_admin_list(env).iter() {
+        if admin != *caller {
+            instance.add_admin(caller, &admin);
+        }
+    }
+    for (holder, role) in config.roles.iter() {
+        instance.assign_role(caller, &holder, &role);
+    }
+
+    let record = CorridorInstance {
+        corridor: config.corridor,
+        address,
+        token: config.token,
+        wasm_hash,
+        deployed_at: env.ledger().timestamp(),
+    };
+    add_corridor_instance(env, &record);
+    Ok(record)
+}
+
+/// Returns every registered instance, in deployment order.
+pub fn instances(env: &Env) -> Vec<CorridorInstance> {
+    let mut instances = Vec::new(env);
+    for corridor in crate::storage::get_corridor_instance_list(env).iter() {
+        if let Some(instance) = get_corridor_instance(env, &corridor) {
+            instances.push_back(instance);
+        }
+    }
+    instances
+}
@@ -20,8 +20,15 @@
 
 use soroban_sdk::{token, Address, Env};
 
+use crate::config::FEE_DIVISOR;
+use crate::token_transfer;
+use crate::storage::{
+    get_agent_commission, get_agent_commission_bps, get_token_fees, set_agent_commission,
+    set_token_fees,
+};
 use crate::{
-    emit_fees_flushed, get_accumulated_fees, get_treasury, set_accumulated_fees, ContractError,
+    emit_commission_credited, emit_fees_flushed, get_accumulated_fees, get_treasury,
+    get_usdc_token, set_accumulated_fees, ContractError,
 };
 
 /// Maximum allowed accumulated fees threshold.
@@ -104,6 +111,76 @@ pub fn safe_add_accumulated_fee(env: &Env, new_fee: i128) -> Result<(), Contract
     Ok(())
 }
 
+/// Adds a fee collected in `token` to the accumulated fees of that token.
+///
+/// Fees in the default token go to the main total through
+/// [`safe_add_accumulated_fee`]; fees in other whitelisted tokens are kept
+/// per token, so each is withdrawn in the asset it was paid in.
+///
+/// # Errors
+///
+/// * `ContractError::InvalidAmount` - new_fee is negative
+/// * `ContractError::Overflow` - Addition would overflow
+pub fn add_token_fee(env: &Env, token: &Address, new_fee: i128) -> Result<(), ContractError> {
+    if *token == get_usdc_token(env)? {
+        return safe_add_accumulated_fee(env, new_fee);
+    }
+    if new_fee < 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    let total = get_token_fees(env, token)
+        .checked_add(new_fee)
+        .ok_or(ContractError::Overflow)?;
+    set_token_fees(env, token, total);
+    Ok(())
+}
+
+/// Returns the accumulated fees collected in `token`.
+pub fn token_fees(env: &Env, token: &Address) -> Result<i128, ContractError> {
+    if *token == get_usdc_token(env)? {
+        return get_accumulated_fees(env);
+    }
+    Ok(get_token_fees(env, token))
+}
+
+/// Resets the accumulated fees collected in `token`, returning them.
+pub fn take_token_fees(env: &Env, token: &Address) -> Result<i128, ContractError> {
+    let fees = token_fees(env, token)?;
+    if *token == get_usdc_token(env)? {
+        set_accumulated_fees(env, 0);
+    } else {
+        set_token_fees(env, token, 0);
+    }
+    Ok(fees)
+}
+
+/// Credits the agent's commission, `agent_commission_bps` of a settled
+/// remittance's platform fee, to its commission balance in `token`.
+///
+/// Returns the commission, which the caller must deduct from the fee it
+/// accumulates.
+pub fn credit_commission(
+    env: &Env,
+    agent: &Address,
+    token: &Address,
+    fee: i128,
+) -> Result<i128, ContractError> {
+    let commission = fee
+        .checked_mul(get_agent_commission_bps(env) as i128)
+        .ok_or(ContractError::Overflow)?
+        / FEE_DIVISOR;
+    if commission <= 0 {
+        return Ok(0);
+    }
+
+    let balance = get_agent_commission(env, agent, token)
+        .checked_add(commission)
+        .ok_or(ContractError::Overflow)?;
+    set_agent_commission(env, agent, token, balance);
+    emit_commission_credited(env, agent.clone(), token.clone(), commission, balance);
+    Ok(commission)
+}
+
 /// Validates if adding a new fee would trigger a flush.
 ///
 /// This is useful for pre-checking before committing to a transaction.
@@ -186,15 +263,16 @@ pub fn trigger_flush(env: &Env, fees_to_flush: i128) -> Result<(), ContractError
     let usdc_token = crate::get_usdc_token(env)?;
     let token_client = token::Client::new(env, &usdc_token);
 
+    // Reset the stored accumulated fees before paying them out.
+    set_accumulated_fees(env, 0);
+
     // Transfer accumulated fees to treasury
-    token_client.transfer(
+    token_transfer::transfer(
+        &token_client,
         &env.current_contract_address(),
         &treasury,
-        &fees_to_flush,
-    );
-
-    // Reset the stored accumulated fees after successful flush.
-    set_accumulated_fees(env, 0);
+        fees_to_flush,
+    )?;
 
     // Emit event logging the flush
     emit_fees_flushed(env, treasury, usdc_token, fees_to_flush);
@@ -1,9 +1,11 @@
 //! Centralized fee calculation service for the SwiftRemit contract.
 //!
 //! This module provides a unified interface for all fee calculations, supporting:
-//! - Multiple fee strategies (Percentage, Flat, Dynamic)
+//! - Multiple fee strategies (Percentage, Flat, Dynamic, Tiered)
+//! - An optional flat component added to rate-based platform fees
 //! - Protocol fees for treasury
 //! - Country-to-country corridor-specific fees
+//! - Expiry-weighted fees compensating for escrow duration
 //! - Complete fee breakdowns for transparency
 //!
 //! All fee calculations route through this module to ensure consistency
@@ -13,12 +15,19 @@ use soroban_sdk::{contracttype, Address, Env, String};
 
 use crate::{
     config::{
+        EXPIRY_FEE_PERIOD_SECONDS,
+        FEE_CHANGE_WINDOW_SECONDS,
         FEE_DIVISOR,
+        MAX_FEE_BPS,
+        MAX_FEE_CHANGE_BPS,
+        MAX_PLATFORM_FEE_BPS,
         MIN_FEE,
+        SECONDS_PER_LEDGER,
         SENDER_VOLUME_TIER_FEE_BPS_10K,
         SENDER_VOLUME_TIER_THRESHOLD_10K,
     },
-    get_fee_strategy, get_platform_fee_bps, get_protocol_fee_bps, storage, ContractError,
+    corridor_utilization,
+    get_fee_strategy, get_platform_fee_bps, get_protocol_fee_bps, storage, ContractError, Expiry,
     FeeStrategy,
 };
 
@@ -85,6 +94,64 @@ pub struct FeeCorridor {
     pub protocol_fee_bps: Option<u32>,
 }
 
+/// Slope of the expiry-weighted fee component.
+///
+/// The component is `bps_per_day` basis points of the amount for every day
+/// the funds can stay locked in escrow (pro-rated by the second), capped at
+/// `max_bps`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExpiryFeeConfig {
+    pub bps_per_day: u32,
+    pub max_bps: u32,
+}
+
+/// Platform fee settings in force.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    pub strategy: FeeStrategy,
+    /// Default platform fee in basis points, for percentage strategies
+    pub fee_bps: u32,
+    /// Flat amount, in token stroops, added to every rate-based platform fee
+    pub flat_fee: i128,
+    pub protocol_fee_bps: u32,
+}
+
+/// Returns the platform fee settings in force.
+pub fn fee_config(env: &Env) -> Result<FeeConfig, ContractError> {
+    Ok(FeeConfig {
+        strategy: get_fee_strategy(env),
+        fee_bps: get_platform_fee_bps(env)?,
+        flat_fee: storage::get_flat_fee(env),
+        protocol_fee_bps: get_protocol_fee_bps(env),
+    })
+}
+
+/// Fee quote including the expiry-weighted component.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeQuote {
+    /// Strategy and protocol fee breakdown, excluding the expiry component
+    pub breakdown: FeeBreakdown,
+    /// Seconds the funds can be locked, as used for the expiry component
+    pub lock_seconds: u64,
+    /// Expiry-weighted fee component
+    pub expiry_fee: i128,
+    /// Amount the agent receives after all fees
+    pub net_amount: i128,
+}
+
+/// Rolling window used to bound platform fee changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeChangeWindow {
+    /// Ledger timestamp at which the window opened
+    pub window_start: u64,
+    /// Platform fee in effect when the window opened
+    pub baseline_bps: u32,
+}
+
 /// Calculates the platform fee for a given amount using the current fee strategy.
 ///
 /// This is the primary entry point for simple fee calculations during remittance creation.
@@ -109,7 +176,7 @@ pub fn calculate_platform_fee(
     }
 
     let strategy = get_effective_fee_strategy(env, token)?;
-    calculate_fee_by_strategy(amount, &strategy)
+    platform_fee(env, amount, &strategy)
 }
 
 /// Calculates the platform fee for a specific sender using rolling volume discounts.
@@ -129,7 +196,7 @@ pub fn calculate_platform_fee_for_sender(
         .checked_add(amount)
         .ok_or(ContractError::Overflow)?;
     let discounted_strategy = apply_volume_discount(total_volume, strategy)?;
-    calculate_fee_by_strategy(amount, &discounted_strategy)
+    platform_fee(env, amount, &discounted_strategy)
 }
 
 /// Calculates the platform fee for a sender given a pre-computed rolling volume.
@@ -145,7 +212,15 @@ pub fn calculate_platform_fee_for_volume(
 
     let strategy = get_effective_fee_strategy(env, token)?;
     let discounted_strategy = apply_volume_discount(total_volume, strategy)?;
-    calculate_fee_by_strategy(amount, &discounted_strategy)
+    platform_fee(env, amount, &discounted_strategy)
+}
+
+/// Calculates the protocol fee sent to the treasury when `amount` is paid out.
+///
+/// Unlike the platform fee, which is fixed on the remittance at creation, the
+/// protocol fee is taken at payout under the rate in effect at that time.
+pub fn calculate_payout_protocol_fee(env: &Env, amount: i128) -> Result<i128, ContractError> {
+    calculate_protocol_fee(amount, get_protocol_fee_bps(env))
 }
 
 /// Calculates complete fee breakdown including platform and protocol fees.
@@ -178,7 +253,7 @@ pub fn calculate_fees_with_breakdown(
             .protocol_fee_bps
             .unwrap_or_else(|| get_protocol_fee_bps(env));
         let id = format_corridor_id(env, &c.from_country, &c.to_country);
-        (c.strategy.clone(), protocol_bps, Some(id))
+        (corridor_strategy(env, c), protocol_bps, Some(id))
     } else {
         (get_fee_strategy(env), get_protocol_fee_bps(env), None)
     };
@@ -186,7 +261,7 @@ pub fn calculate_fees_with_breakdown(
     let effective_strategy = get_effective_fee_strategy_for_strategy(env, &strategy, token)?;
 
     // Calculate platform fee
-    let platform_fee = calculate_fee_by_strategy(amount, &effective_strategy)?;
+    let platform_fee = platform_fee(env, amount, &effective_strategy)?;
 
     // Calculate protocol fee
     let protocol_fee = calculate_protocol_fee(amount, protocol_fee_bps)?;
@@ -239,7 +314,7 @@ pub fn calculate_fees_with_breakdown_for_sender(
             .protocol_fee_bps
             .unwrap_or_else(|| get_protocol_fee_bps(env));
         let id = format_corridor_id(env, &c.from_country, &c.to_country);
-        (c.strategy.clone(), protocol_bps, Some(id))
+        (corridor_strategy(env, c), protocol_bps, Some(id))
     } else {
         (get_fee_strategy(env), get_protocol_fee_bps(env), None)
     };
@@ -252,7 +327,7 @@ pub fn calculate_fees_with_breakdown_for_sender(
     let discounted_strategy = apply_volume_discount(total_volume, effective_strategy)?;
 
     // Calculate platform fee
-    let platform_fee = calculate_fee_by_strategy(amount, &discounted_strategy)?;
+    let platform_fee = platform_fee(env, amount, &discounted_strategy)?;
 
     // Calculate protocol fee
     let protocol_fee = calculate_protocol_fee(amount, protocol_fee_bps)?;
@@ -285,6 +360,113 @@ pub fn calculate_fees_with_breakdown_for_sender(
     Ok(breakdown)
 }
 
+/// Enforces the platform fee guardrails for `update_fee` and records the change.
+///
+/// The new fee must not exceed `MAX_PLATFORM_FEE_BPS`, and must stay within
+/// `MAX_FEE_CHANGE_BPS` of the fee in effect when the current
+/// `FEE_CHANGE_WINDOW_SECONDS` window opened.
+///
+/// # Errors
+///
+/// * `ContractError::FeeAboveCeiling` - Fee is above the absolute ceiling
+/// * `ContractError::FeeChangeTooLarge` - Fee moves too far within the window
+pub fn enforce_fee_change_guardrails(env: &Env, fee_bps: u32) -> Result<(), ContractError> {
+    if fee_bps > MAX_PLATFORM_FEE_BPS {
+        return Err(ContractError::FeeAboveCeiling);
+    }
+
+    let now = env.ledger().timestamp();
+    let window = match storage::get_fee_change_window(env) {
+        Some(window) if now < window.window_start.saturating_add(FEE_CHANGE_WINDOW_SECONDS) => window,
+        _ => FeeChangeWindow {
+            window_start: now,
+            baseline_bps: get_platform_fee_bps(env)?,
+        },
+    };
+
+    if fee_bps.abs_diff(window.baseline_bps) > MAX_FEE_CHANGE_BPS {
+        return Err(ContractError::FeeChangeTooLarge);
+    }
+
+    storage::set_fee_change_window(env, &window);
+    Ok(())
+}
+
+/// Returns how long escrowed funds can stay locked for a given expiry.
+///
+/// Durations count from now; ledger expiries are converted at
+/// `SECONDS_PER_LEDGER`. `None` means the lock is unbounded.
+pub fn lock_duration_seconds(env: &Env, expiry: Option<Expiry>) -> Option<u64> {
+    match expiry? {
+        Expiry::Time(t) => Some(t.saturating_sub(env.ledger().timestamp())),
+        Expiry::Ledger(seq) => Some(
+            (seq.saturating_sub(env.ledger().sequence()) as u64).saturating_mul(SECONDS_PER_LEDGER),
+        ),
+    }
+}
+
+/// Calculates the expiry-weighted fee component for `amount`.
+///
+/// Returns `(lock_seconds, fee)`. The fee is zero when no slope is configured;
+/// unbounded locks are charged the configured cap.
+pub fn calculate_expiry_fee(
+    env: &Env,
+    amount: i128,
+    expiry: Option<Expiry>,
+) -> Result<(u64, i128), ContractError> {
+    let config = match storage::get_expiry_fee_config(env) {
+        Some(config) if config.bps_per_day > 0 => config,
+        _ => return Ok((0, 0)),
+    };
+
+    let lock_seconds = lock_duration_seconds(env, expiry);
+    let bps = match lock_seconds {
+        Some(seconds) => {
+            let weighted = (config.bps_per_day as u128)
+                .saturating_mul(seconds as u128)
+                / EXPIRY_FEE_PERIOD_SECONDS as u128;
+            weighted.min(config.max_bps as u128) as i128
+        }
+        None => config.max_bps as i128,
+    };
+
+    let fee = amount
+        .checked_mul(bps)
+        .and_then(|v| v.checked_div(FEE_DIVISOR))
+        .ok_or(ContractError::Overflow)?;
+    Ok((lock_seconds.unwrap_or(u64::MAX), fee))
+}
+
+/// Validates an expiry fee slope before it is stored.
+pub fn validate_expiry_fee_config(config: &ExpiryFeeConfig) -> Result<(), ContractError> {
+    if config.max_bps > MAX_FEE_BPS || config.bps_per_day > config.max_bps {
+        return Err(ContractError::InvalidFeeBps);
+    }
+    Ok(())
+}
+
+/// Quotes the full fee for a remittance, including the expiry-weighted component.
+pub fn quote_fees(
+    env: &Env,
+    amount: i128,
+    expiry: Option<Expiry>,
+    token: Option<&Address>,
+) -> Result<FeeQuote, ContractError> {
+    let breakdown = calculate_fees_with_breakdown(env, amount, token, None)?;
+    let (lock_seconds, expiry_fee) = calculate_expiry_fee(env, amount, expiry)?;
+    let net_amount = breakdown
+        .net_amount
+        .checked_sub(expiry_fee)
+        .filter(|v| *v >= 0)
+        .ok_or(ContractError::InvalidAmount)?;
+    Ok(FeeQuote {
+        breakdown,
+        lock_seconds,
+        expiry_fee,
+        net_amount,
+    })
+}
+
 fn apply_volume_discount(total_volume: i128, strategy: FeeStrategy) -> Result<FeeStrategy, ContractError> {
     match strategy {
         FeeStrategy::Percentage(base_fee_bps) => {
@@ -349,6 +531,26 @@ fn get_effective_fee_strategy_for_strategy(
     }
 }
 
+/// Platform fee under `strategy`, including the flat component unless the
+/// strategy is itself flat.
+///
+/// # Errors
+///
+/// * `ContractError::InvalidAmount` - The fee with the flat component exceeds the amount
+/// * `ContractError::Overflow` - Arithmetic overflow
+fn platform_fee(env: &Env, amount: i128, strategy: &FeeStrategy) -> Result<i128, ContractError> {
+    let fee = calculate_fee_by_strategy(amount, strategy)?;
+    let flat_fee = storage::get_flat_fee(env);
+    if flat_fee == 0 || matches!(strategy, FeeStrategy::Flat(_)) {
+        return Ok(fee);
+    }
+    let fee = fee.checked_add(flat_fee).ok_or(ContractError::Overflow)?;
+    if fee > amount {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(fee)
+}
+
 /// Calculates fee based on the specified strategy.
 ///
 /// # Arguments
@@ -395,6 +597,13 @@ fn calculate_fee_by_strategy(amount: i128, strategy: &FeeStrategy) -> Result<i12
                 .ok_or(ContractError::Overflow)?;
             Ok(fee.max(MIN_FEE))
         }
+        FeeStrategy::Tiered(tiers) => {
+            let fee = amount
+                .checked_mul(crate::fee_strategy::tier_bps(tiers, amount) as i128)
+                .and_then(|v| v.checked_div(FEE_DIVISOR))
+                .ok_or(ContractError::Overflow)?;
+            Ok(fee.max(MIN_FEE))
+        }
         // Corridor is resolved to Percentage before reaching this function.
         // If it somehow arrives here, treat as zero fee (safe fallback).
         FeeStrategy::Corridor => Ok(MIN_FEE),
@@ -424,6 +633,17 @@ fn calculate_protocol_fee(amount: i128, protocol_fee_bps: u32) -> Result<i128, C
     Ok(fee)
 }
 
+/// Returns the strategy in effect for a corridor.
+///
+/// Utilization-priced corridors use their current dynamic percentage in
+/// place of the configured strategy.
+fn corridor_strategy(env: &Env, corridor: &FeeCorridor) -> FeeStrategy {
+    match corridor_utilization::dynamic_fee_bps(env, &corridor.from_country, &corridor.to_country) {
+        Some(bps) => FeeStrategy::Percentage(bps),
+        None => corridor.strategy.clone(),
+    }
+}
+
 /// Formats a corridor identifier string.
 ///
 /// # Arguments
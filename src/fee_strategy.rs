@@ -8,9 +8,13 @@
 //! - Percentage (PercentageBps): Fee based on percentage of amount (basis points)
 //! - Flat: Fixed fee regardless of amount
 //! - Dynamic (Tiered): Fee varies based on amount tiers
+//! - Tiered: Percentage chosen from an admin-defined schedule of amount tiers
 //! - Corridor: Delegates to a per-corridor fee configuration
 
-use soroban_sdk::contracttype;
+use soroban_sdk::{contracttype, Vec};
+
+use crate::config::{MAX_FEE_BPS, MAX_FEE_TIERS};
+use crate::ContractError;
 
 /// On-chain fee strategy selector.
 ///
@@ -30,4 +34,58 @@ pub enum FeeStrategy {
     /// Falls back to `Percentage` with the stored `PlatformFeeBps` when no
     /// corridor is configured for the given country pair.
     Corridor,
+    /// Percentage fee from a schedule of amount tiers, in ascending order of
+    /// `min_amount`; see [`validate_tiers`]
+    Tiered(Vec<FeeTier>),
+}
+
+/// One tier of a tiered fee schedule.
+///
+/// Applies to amounts from `min_amount` up to the next tier's `min_amount`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTier {
+    pub min_amount: i128,
+    pub fee_bps: u32,
+}
+
+/// Checks a tiered fee schedule: between 1 and `MAX_FEE_TIERS` tiers, the
+/// first starting at 0, strictly ascending, and each rate within `MAX_FEE_BPS`.
+///
+/// # Errors
+///
+/// * `ContractError::InvalidAmount` - Wrong number of tiers, or bounds not
+///   starting at 0 and strictly ascending
+/// * `ContractError::InvalidFeeBps` - A rate exceeds `MAX_FEE_BPS`
+pub fn validate_tiers(tiers: &Vec<FeeTier>) -> Result<(), ContractError> {
+    if tiers.is_empty() || tiers.len() > MAX_FEE_TIERS {
+        return Err(ContractError::InvalidAmount);
+    }
+    let mut floor: Option<i128> = None;
+    for tier in tiers.iter() {
+        let ascending = match floor {
+            None => tier.min_amount == 0,
+            Some(previous) => tier.min_amount > previous,
+        };
+        if !ascending {
+            return Err(ContractError::InvalidAmount);
+        }
+        if tier.fee_bps > MAX_FEE_BPS {
+            return Err(ContractError::InvalidFeeBps);
+        }
+        floor = Some(tier.min_amount);
+    }
+    Ok(())
+}
+
+/// Returns the rate of the tier `amount` falls in.
+pub fn tier_bps(tiers: &Vec<FeeTier>, amount: i128) -> u32 {
+    let mut fee_bps = 0;
+    for tier in tiers.iter() {
+        if amount < tier.min_amount {
+            break;
+        }
+        fee_bps = tier.fee_bps;
+    }
+    fee_bps
 }
@@ -0,0 +1,145 @@
+//! Cross-instance remittance forwarding.
+//!
+//! A sender who picked the wrong corridor instance can move a pending
+//! remittance to another SwiftRemit instance instead of cancelling it and
+//! paying fees again on a new one. The handoff is a single transaction:
+//!
+//! 1. The source instance cancels its record and transfers the escrowed
+//!    amount to the target instance.
+//! 2. It then calls the target's [`ACCEPT_ENTRY_POINT`] with a
+//!    [`ForwardedRemittance`], and the target recreates the remittance with
+//!    the same sender, amount, fee, token, and expiry for the chosen agent.
+//!
+//! If the target rejects the remittance the whole transaction reverts, so
+//! funds are never stranded between instances. Each side only deals with
+//! instances its admin has marked as trusted: the target relies on a trusted
+//! source having transferred the escrow before calling it.
+
+use soroban_sdk::{contracttype, token, Address, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::storage::{
+    get_remittance_route, is_paused, is_token_whitelisted, is_trusted_instance, set_forwarded_from,
+    set_forwarded_to, set_payout_commitment, set_remittance,
+};
+use crate::{
+    agent_tiers, causes, compute_payout_commitment, corridor_utilization, priority, token_transfer,
+    validate_agent_registered, ContractError, MaybeExpiry, Remittance, RemittanceStatus,
+};
+
+/// Entry point invoked on the target instance.
+pub const ACCEPT_ENTRY_POINT: &str = "accept_forwarded";
+
+/// A remittance handed from one instance to another.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForwardedRemittance {
+    /// ID of the cancelled remittance on the source instance
+    pub source_id: u64,
+    pub sender: Address,
+    /// Agent on the target instance
+    pub agent: Address,
+    pub amount: i128,
+    /// Fee already charged at creation, carried over unchanged
+    pub fee: i128,
+    pub token: Address,
+    pub expiry: MaybeExpiry,
+}
+
+/// Cancels a pending remittance here and recreates it on `target` for
+/// `agent`. Returns the remittance ID on the target.
+///
+/// `remittance` must be Pending and its sender already authenticated.
+///
+/// # Errors
+///
+/// * `ContractError::UntrustedInstance` - `target` is not a trusted instance
+/// * `ContractError::InvalidRoute` - The remittance is routed through intermediaries
+pub fn forward(
+    env: &Env,
+    mut remittance: Remittance,
+    target: &Address,
+    agent: Address,
+) -> Result<u64, ContractError> {
+    if !is_trusted_instance(env, target) {
+        return Err(ContractError::UntrustedInstance);
+    }
+    // Route legs and donation pledges are bound to this instance
+    if get_remittance_route(env, remittance.id).is_some() {
+        return Err(ContractError::InvalidRoute);
+    }
+    causes::require_no_pledge(env, remittance.id)?;
+
+    let forwarded = ForwardedRemittance {
+        source_id: remittance.id,
+        sender: remittance.sender.clone(),
+        agent,
+        amount: remittance.amount,
+        fee: remittance.fee,
+        token: remittance.token.clone(),
+        expiry: remittance.expiry,
+    };
+
+    remittance.status = RemittanceStatus::Cancelled;
+    remittance.amount = 0;
+    set_remittance(env, remittance.id, &remittance);
+    corridor_utilization::release_pending(env, &remittance);
+    agent_tiers::release_pending(env, &remittance);
+    priority::refund(env, &remittance)?;
+
+    token_transfer::transfer_for(
+        &token::Client::new(env, &forwarded.token),
+        remittance.id,
+        &env.current_contract_address(),
+        target,
+        forwarded.amount,
+    )?;
+    let args: Vec<Val> = (env.current_contract_address(), forwarded).into_val(env);
+    let target_id: u64 = env.invoke_contract(target, &Symbol::new(env, ACCEPT_ENTRY_POINT), args);
+    set_forwarded_to(env, remittance.id, target, target_id);
+    Ok(target_id)
+}
+
+/// Recreates a remittance forwarded by `source`, whose escrow `source` has
+/// already transferred to this instance.
+///
+/// # Errors
+///
+/// * `ContractError::UntrustedInstance` - `source` is not a trusted instance
+/// * `ContractError::ContractPaused` - This instance is paused
+/// * `ContractError::AgentNotRegistered` - The agent is not registered here
+/// * `ContractError::TokenNotWhitelisted` - The token is not accepted here
+pub fn accept(
+    env: &Env,
+    source: &Address,
+    forwarded: ForwardedRemittance,
+) -> Result<Remittance, ContractError> {
+    source.require_auth();
+    if !is_trusted_instance(env, source) {
+        return Err(ContractError::UntrustedInstance);
+    }
+    if is_paused(env) {
+        return Err(ContractError::ContractPaused);
+    }
+    validate_agent_registered(env, &forwarded.agent)?;
+    if !is_token_whitelisted(env, &forwarded.token) {
+        return Err(ContractError::TokenNotWhitelisted);
+    }
+
+    // The fee already covers any expiry component, so none is added here
+    let mut remittance = crate::record_new_remittance(
+        env,
+        &forwarded.sender,
+        &forwarded.agent,
+        forwarded.amount,
+        forwarded.fee,
+        &forwarded.token,
+        None,
+    )?;
+    if forwarded.expiry != MaybeExpiry::None {
+        remittance.expiry = forwarded.expiry;
+        set_remittance(env, remittance.id, &remittance);
+        set_payout_commitment(env, remittance.id, &compute_payout_commitment(env, &remittance));
+    }
+    set_forwarded_from(env, remittance.id, source, forwarded.source_id);
+    Ok(remittance)
+}
@@ -355,9 +355,10 @@ fn dispatch_action(
 ) -> Result<(), ContractError> {
     match action {
         ProposalAction::UpdateFee(bps) => {
+            let old_bps = storage::get_platform_fee_bps(env)?;
             storage::set_platform_fee_bps(env, *bps);
             set_active_fee_proposal(env, None);
-            emit_fee_updated(env, *bps);
+            emit_fee_updated(env, old_bps, *bps);
         }
         ProposalAction::RegisterAgent(agent) => {
             if is_agent_registered(env, agent) {
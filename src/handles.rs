@@ -0,0 +1,159 @@
+//! `@handle` registry.
+//!
+//! Users can claim a short human-readable handle that resolves to their
+//! address, so senders can pay `@amina` instead of pasting a Stellar address.
+//! Handles are lowercase ASCII letters, digits, and underscores, between
+//! [`MIN_HANDLE_LEN`] and [`MAX_HANDLE_LEN`] bytes, stored without the `@`.
+//! Restricting the alphabet to a single case means two handles collide exactly
+//! when their bytes are equal.
+//!
+//! To discourage squatting, every registration or renewal costs
+//! [`HANDLE_REGISTRATION_FEE`] in the default token, paid to the treasury, and
+//! lasts [`HANDLE_TERM_SECONDS`]. A handle that has lapsed stops resolving and
+//! can be claimed by anyone. The admin can freeze a handle involved in abuse;
+//! a frozen handle neither resolves nor can be renewed, transferred, or
+//! re-registered until it is unfrozen.
+//!
+//! Entry points that take a [`Payee`] accept either a raw address or a handle,
+//! resolved within the same call.
+
+use soroban_sdk::{contracttype, token, Address, Env, String};
+
+use crate::config::{
+    HANDLE_REGISTRATION_FEE, HANDLE_TERM_SECONDS, MAX_HANDLE_LEN, MIN_HANDLE_LEN,
+};
+use crate::storage::{get_handle, get_treasury, get_usdc_token, set_handle};
+use crate::token_transfer;
+use crate::ContractError;
+
+/// Registration state of a handle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HandleRecord {
+    pub handle: String,
+    pub owner: Address,
+    /// Timestamp at which the registration lapses
+    pub expires_at: u64,
+    pub frozen: bool,
+}
+
+impl HandleRecord {
+    /// Whether the registration is still current at `now`.
+    pub fn is_active(&self, now: u64) -> bool {
+        now < self.expires_at
+    }
+}
+
+/// A counterparty given either as an address or as a registered handle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Payee {
+    Address(Address),
+    Handle(String),
+}
+
+/// Checks that `handle` uses the permitted alphabet and length.
+pub fn validate(handle: &String) -> Result<(), ContractError> {
+    let len = handle.len() as usize;
+    if len < MIN_HANDLE_LEN as usize || len > MAX_HANDLE_LEN as usize {
+        return Err(ContractError::InvalidHandle);
+    }
+    let mut buf = [0u8; MAX_HANDLE_LEN as usize];
+    handle.copy_into_slice(&mut buf[..len]);
+    if !buf[..len]
+        .iter()
+        .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || *b == b'_')
+    {
+        return Err(ContractError::InvalidHandle);
+    }
+    Ok(())
+}
+
+fn charge_fee(env: &Env, payer: &Address) -> Result<(), ContractError> {
+    token_transfer::transfer(
+        &token::Client::new(env, &get_usdc_token(env)?),
+        payer,
+        &get_treasury(env)?,
+        HANDLE_REGISTRATION_FEE,
+    )?;
+    Ok(())
+}
+
+/// Registers a free or lapsed handle to `owner` for one term.
+pub fn register(env: &Env, owner: &Address, handle: String) -> Result<HandleRecord, ContractError> {
+    validate(&handle)?;
+    let now = env.ledger().timestamp();
+    if let Some(existing) = get_handle(env, &handle) {
+        if existing.frozen {
+            return Err(ContractError::HandleFrozen);
+        }
+        if existing.is_active(now) {
+            return Err(ContractError::HandleTaken);
+        }
+    }
+    charge_fee(env, owner)?;
+    let record = HandleRecord {
+        handle,
+        owner: owner.clone(),
+        expires_at: now.saturating_add(HANDLE_TERM_SECONDS),
+        frozen: false,
+    };
+    set_handle(env, &record);
+    Ok(record)
+}
+
+/// Loads a current, unfrozen handle held by `owner`.
+pub fn owned(env: &Env, owner: &Address, handle: &String) -> Result<HandleRecord, ContractError> {
+    let record = get_handle(env, handle)
+        .filter(|record| record.is_active(env.ledger().timestamp()))
+        .ok_or(ContractError::HandleNotFound)?;
+    if record.owner != *owner {
+        return Err(ContractError::Unauthorized);
+    }
+    if record.frozen {
+        return Err(ContractError::HandleFrozen);
+    }
+    Ok(record)
+}
+
+/// Extends a handle by one term from its current expiry.
+pub fn renew(env: &Env, owner: &Address, handle: &String) -> Result<HandleRecord, ContractError> {
+    let mut record = owned(env, owner, handle)?;
+    charge_fee(env, owner)?;
+    record.expires_at = record.expires_at.saturating_add(HANDLE_TERM_SECONDS);
+    set_handle(env, &record);
+    Ok(record)
+}
+
+/// Resolves a handle to its owner's address.
+pub fn resolve(env: &Env, handle: &String) -> Result<Address, ContractError> {
+    let record = get_handle(env, handle)
+        .filter(|record| record.is_active(env.ledger().timestamp()))
+        .ok_or(ContractError::HandleNotFound)?;
+    if record.frozen {
+        return Err(ContractError::HandleFrozen);
+    }
+    Ok(record.owner)
+}
+
+/// Resolves a payee to an address.
+pub fn resolve_payee(env: &Env, payee: Payee) -> Result<Address, ContractError> {
+    match payee {
+        Payee::Address(address) => Ok(address),
+        Payee::Handle(handle) => resolve(env, &handle),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_alphabet_and_length() {
+        let env = Env::default();
+        assert!(validate(&String::from_str(&env, "amina_01")).is_ok());
+        for bad in ["ab", "Amina", "amina!", "a-b-c", "abcdefghijklmnopqrstuvwxyz_0123456"] {
+            assert_eq!(validate(&String::from_str(&env, bad)), Err(ContractError::InvalidHandle));
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! Hash-only remittance records for data minimization.
+//!
+//! Some corridors forbid keeping counterparty details on a public ledger. For
+//! those, a remittance can be created in hash-only mode: the contract stores
+//! just the token, the escrowed amount, and a hash of the full
+//! [`RemittanceDetails`], which the parties hold off-chain. Whoever settles or
+//! cancels the remittance presents the details, the contract checks them
+//! against the stored hash, and only then learns who to pay.
+//!
+//! The admin can mark a corridor as hash-only, after which
+//! `create_remittance_with_corridor` refuses it and senders must use
+//! `create_hashed_remittance` instead.
+//!
+//! ## Details hash (canonical)
+//!
+//! `sha256(HASHED_RECORD_DOMAIN || xdr(details))`, where `xdr(details)` is the
+//! XDR encoding of the [`RemittanceDetails`] struct as a contract value. The
+//! salt keeps low-entropy details from being recovered by guessing.
+
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+use crate::storage::{
+    get_hashed_remittance, get_hashed_remittance_counter, set_hashed_remittance,
+    set_hashed_remittance_counter,
+};
+use crate::{ContractError, RemittanceStatus};
+
+/// Domain separation tag prefixed to every details hash.
+pub const HASHED_RECORD_DOMAIN: &[u8] = b"SWIFTREMIT_DETAILS_V1";
+
+/// Full details of a hash-only remittance, kept off-chain by its parties.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittanceDetails {
+    /// Address refunded on cancellation; must be the address that escrowed
+    pub sender: Address,
+    /// Registered agent paid at settlement
+    pub agent: Address,
+    /// Corridor-specific details such as recipient and purpose, opaque to the contract
+    pub payload: Bytes,
+    /// 32 random bytes chosen by the sender
+    pub salt: BytesN<32>,
+}
+
+/// The on-chain part of a hash-only remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HashedRemittance {
+    pub id: u64,
+    pub token: Address,
+    /// Amount escrowed by the sender
+    pub amount: i128,
+    pub details_hash: BytesN<32>,
+    pub status: RemittanceStatus,
+    pub created_at: u64,
+}
+
+/// Computes the hash stored for `details`.
+pub fn hash_details(env: &Env, details: &RemittanceDetails) -> BytesN<32> {
+    let mut buf = Bytes::from_slice(env, HASHED_RECORD_DOMAIN);
+    buf.append(&details.clone().to_xdr(env));
+    env.crypto().sha256(&buf).into()
+}
+
+/// Records a new Pending hash-only remittance whose amount is already escrowed.
+pub fn create(
+    env: &Env,
+    token: &Address,
+    amount: i128,
+    details_hash: BytesN<32>,
+) -> Result<HashedRemittance, ContractError> {
+    let id = get_hashed_remittance_counter(env)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    let remittance = HashedRemittance {
+        id,
+        token: token.clone(),
+        amount,
+        details_hash,
+        status: RemittanceStatus::Pending,
+        created_at: env.ledger().timestamp(),
+    };
+    set_hashed_remittance(env, &remittance);
+    set_hashed_remittance_counter(env, id);
+    Ok(remittance)
+}
+
+/// Loads a Pending hash-only remittance and checks `details` against its hash.
+pub fn verified_pending(
+    env: &Env,
+    id: u64,
+    details: &RemittanceDetails,
+) -> Result<HashedRemittance, ContractError> {
+    let remittance = get_hashed_remittance(env, id).ok_or(ContractError::RemittanceNotFound)?;
+    if remittance.status != RemittanceStatus::Pending {
+        return Err(ContractError::InvalidStatus);
+    }
+    if hash_details(env, details) != remittance.details_hash {
+        return Err(ContractError::DetailsHashMismatch);
+    }
+    Ok(remittance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_details_hash_binds_every_field() {
+        let env = Env::default();
+        let details = RemittanceDetails {
+            sender: Address::generate(&env),
+            agent: Address::generate(&env),
+            payload: Bytes::from_slice(&env, b"recipient=alice"),
+            salt: BytesN::from_array(&env, &[1u8; 32]),
+        };
+        let hash = hash_details(&env, &details);
+        assert_eq!(hash, hash_details(&env, &details.clone()));
+
+        let mut other = details.clone();
+        other.agent = Address::generate(&env);
+        assert_ne!(hash, hash_details(&env, &other));
+        let mut other = details.clone();
+        other.payload = Bytes::from_slice(&env, b"recipient=bob");
+        assert_ne!(hash, hash_details(&env, &other));
+        let mut other = details;
+        other.salt = BytesN::from_array(&env, &[2u8; 32]);
+        assert_ne!(hash, hash_details(&env, &other));
+    }
+}
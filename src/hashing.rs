@@ -13,7 +13,8 @@
 //! 3. `agent`          — Address, XDR-encoded bytes
 //! 4. `amount`         — i128, big-endian 16 bytes
 //! 5. `fee`            — i128, big-endian 16 bytes
-//! 6. `expiry`         — u64,  big-endian 8 bytes (0x0000000000000000 if None)
+//! 6. `expiry`         — u64,  big-endian 8 bytes (0x0000000000000000 if None;
+//!    ledger-sequence expiries set the top bit)
 //!
 //! Note: `status` is intentionally excluded — it changes over the remittance
 //! lifecycle and must not affect the settlement ID.
@@ -36,6 +37,8 @@
 
 use soroban_sdk::{Address, Bytes, BytesN, Env};
 
+use crate::types::Expiry;
+
 /// Canonical field ordering version for settlement ID hashing.
 ///
 /// This version must be incremented whenever the hash schema changes in a way
@@ -87,7 +90,7 @@ pub const HASH_SCHEMA_VERSION: u32 = 1;
 /// * `agent`          - Agent address
 /// * `amount`         - Payment amount in USDC (7 decimal places)
 /// * `fee`            - Fee amount in USDC (7 decimal places)
-/// * `expiry`         - Optional expiry timestamp (Unix seconds), None → 0
+/// * `expiry`         - Optional expiry (see [`Expiry::to_hash_value`]), None → 0
 ///
 /// # Returns
 /// SHA-256 hash as BytesN<32> — usable as a settlement ID
@@ -98,7 +101,7 @@ pub fn compute_settlement_id(
     agent: &Address,
     amount: i128,
     fee: i128,
-    expiry: Option<u64>,
+    expiry: Option<Expiry>,
 ) -> BytesN<32> {
     let mut buf = Bytes::new(env);
 
@@ -120,7 +123,7 @@ pub fn compute_settlement_id(
     buf.extend_from_array(&fee.to_be_bytes());
 
     // Field 6: expiry — u64 big-endian (8 bytes), 0 if None
-    let expiry_val: u64 = expiry.unwrap_or(0);
+    let expiry_val: u64 = expiry.map(|e| e.to_hash_value()).unwrap_or(0);
     buf.extend_from_array(&expiry_val.to_be_bytes());
 
     // SHA-256 over the canonical byte sequence
@@ -140,7 +143,7 @@ pub fn compute_settlement_id_from_remittance(
         &remittance.agent,
         remittance.amount,
         remittance.fee,
-        remittance.expiry,
+        remittance.expiry.into(),
     )
 }
 
@@ -161,7 +164,7 @@ fn address_to_bytes(env: &Env, address: &Address) -> Bytes {
 /// * `sender` - Sender address
 /// * `agent`  - Agent address
 /// * `amount` - Payment amount in USDC
-/// * `expiry` - Optional expiry timestamp
+/// * `expiry` - Optional settlement deadline
 ///
 /// # Returns
 /// SHA-256 hash as BytesN<32>
@@ -170,7 +173,7 @@ pub fn compute_request_hash(
     sender: &Address,
     agent: &Address,
     amount: i128,
-    expiry: Option<u64>,
+    expiry: Option<Expiry>,
 ) -> BytesN<32> {
     let mut buf = Bytes::new(env);
 
@@ -183,7 +186,7 @@ pub fn compute_request_hash(
 
     buf.extend_from_array(&amount.to_be_bytes());
 
-    let expiry_val: u64 = expiry.unwrap_or(0);
+    let expiry_val: u64 = expiry.map(|e| e.to_hash_value()).unwrap_or(0);
     buf.extend_from_array(&expiry_val.to_be_bytes());
 
     env.crypto().sha256(&buf).into()
@@ -200,8 +203,8 @@ mod tests {
         let sender = Address::generate(&env);
         let agent = Address::generate(&env);
 
-        let hash1 = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, Some(1234567890));
-        let hash2 = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, Some(1234567890));
+        let hash1 = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, Some(Expiry::Time(1_234_567_890)));
+        let hash2 = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, Some(Expiry::Time(1_234_567_890)));
 
         assert_eq!(hash1, hash2, "Same inputs must produce identical hashes");
     }
@@ -212,8 +215,8 @@ mod tests {
         let sender = Address::generate(&env);
         let agent = Address::generate(&env);
 
-        let hash1 = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, Some(1234567890));
-        let hash2 = compute_settlement_id(&env, 2, &sender, &agent, 1000, 25, Some(1234567890));
+        let hash1 = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, Some(Expiry::Time(1_234_567_890)));
+        let hash2 = compute_settlement_id(&env, 2, &sender, &agent, 1000, 25, Some(Expiry::Time(1_234_567_890)));
 
         assert_ne!(hash1, hash2, "Different remittance IDs must produce different hashes");
     }
@@ -237,7 +240,7 @@ mod tests {
         let agent = Address::generate(&env);
 
         let hash_none = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, None);
-        let hash_zero = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, Some(0));
+        let hash_zero = compute_settlement_id(&env, 1, &sender, &agent, 1000, 25, Some(Expiry::Time(0)));
 
         assert_eq!(hash_none, hash_zero, "None and Some(0) must produce identical hashes");
     }
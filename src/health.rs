@@ -1,8 +1,13 @@
-use soroban_sdk::{contracttype, Env};
+use soroban_sdk::{contracttype, token, Env};
 
-use crate::storage::{get_accumulated_fees, get_admin_count, get_remittance_counter, has_admin, is_paused};
-use crate::circuit_breaker_storage::{get_active_pause_seq, get_pause_record_by_seq};
-use crate::MaybePauseReason;
+use crate::storage::{
+    get_accumulated_fees, get_accumulated_integrator_fees, get_admin_count, get_agent_list,
+    get_remittance_counter, get_total_completed_volume, get_usdc_token, has_admin, is_paused,
+};
+use crate::circuit_breaker_storage::{
+    get_active_pause_seq, get_cooldown_period, get_last_unpause_at, get_pause_record_by_seq,
+};
+use crate::RemittanceCounts;
 
 /// Health check response for contract monitoring.
 #[contracttype]
@@ -41,3 +46,56 @@ pub fn health(env: &Env) -> HealthStatus {
         accumulated_fees,
     }
 }
+
+/// Snapshot of the contract's state for monitoring, returned by `get_metrics`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractMetrics {
+    pub paused: bool,
+    /// A maintenance window is in effect
+    pub in_maintenance: bool,
+    /// Within the post-unpause cooldown, when rate limits are halved
+    pub in_unpause_cooldown: bool,
+    pub counts: RemittanceCounts,
+    pub total_remittances: u64,
+    /// Cumulative value of completed remittances
+    pub total_volume: i128,
+    /// Settlement token balance held by the contract
+    pub tvl: i128,
+    pub accumulated_fees: i128,
+    pub accumulated_integrator_fees: i128,
+    /// Whether `tvl` covers the fees owed to the platform and integrators
+    pub fees_covered: bool,
+    pub registered_agents: u32,
+}
+
+/// Returns the per-status counts.
+pub fn remittance_counts(env: &Env) -> RemittanceCounts {
+    crate::storage::get_remittance_counts(env)
+}
+
+/// Assembles a [`ContractMetrics`] snapshot from storage.
+pub fn metrics(env: &Env) -> ContractMetrics {
+    let now = env.ledger().timestamp();
+    let in_unpause_cooldown = get_last_unpause_at(env)
+        .is_some_and(|at| now.saturating_sub(at) < get_cooldown_period(env));
+    let tvl = get_usdc_token(env)
+        .map(|token| token::Client::new(env, &token).balance(&env.current_contract_address()))
+        .unwrap_or(0);
+    let accumulated_fees = get_accumulated_fees(env).unwrap_or(0);
+    let accumulated_integrator_fees = get_accumulated_integrator_fees(env);
+
+    ContractMetrics {
+        paused: is_paused(env),
+        in_maintenance: crate::maintenance::active(env).is_some(),
+        in_unpause_cooldown,
+        counts: remittance_counts(env),
+        total_remittances: get_remittance_counter(env).unwrap_or(0),
+        total_volume: get_total_completed_volume(env),
+        tvl,
+        accumulated_fees,
+        accumulated_integrator_fees,
+        fees_covered: tvl >= accumulated_fees.saturating_add(accumulated_integrator_fees),
+        registered_agents: get_agent_list(env).len(),
+    }
+}
@@ -0,0 +1,69 @@
+//! Self-registration with a central hub registry.
+//!
+//! A hub registry is a contract that lists SwiftRemit instances so wallets can
+//! find the one serving a user's corridor on-chain. An admin points an instance
+//! at a hub with `register_with_hub`; the instance then calls the hub's
+//! [`HUB_REGISTER_ENTRY_POINT`] with a [`RegistryEntry`] describing itself.
+//! The call comes from the instance, so the hub can authenticate the entry
+//! with `entry.instance.require_auth()`.
+//!
+//! The last entry sent is kept locally and returned by `get_registry_entry`.
+//! Re-registering after an upgrade or token change sends a fresh entry.
+
+use soroban_sdk::{
+    contracttype, Address, Env, IntoVal, InvokeError, String, Symbol, Val, Vec,
+};
+
+use crate::events::emit_external_call_failed;
+use crate::storage::{get_usdc_token, set_registry_entry};
+use crate::{ContractError, RemittanceCorridor};
+
+/// Entry point invoked on the hub registry contract.
+pub const HUB_REGISTER_ENTRY_POINT: &str = "register_instance";
+
+/// How an instance describes itself to a hub registry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistryEntry {
+    pub hub: Address,
+    pub instance: Address,
+    pub corridor: RemittanceCorridor,
+    /// Settlement token of the instance
+    pub token: Address,
+    /// Contract version, as returned by `get_version`
+    pub version: String,
+    pub registered_at: u64,
+}
+
+/// Registers this instance for `corridor` with `hub` and stores the entry.
+///
+/// # Errors
+///
+/// * `ContractError::HubRegistrationFailed` - The hub rejected the entry
+pub fn register(
+    env: &Env,
+    hub: Address,
+    corridor: RemittanceCorridor,
+) -> Result<RegistryEntry, ContractError> {
+    let entry = RegistryEntry {
+        hub: hub.clone(),
+        instance: env.current_contract_address(),
+        corridor,
+        token: get_usdc_token(env)?,
+        version: String::from_str(env, env!("CARGO_PKG_VERSION")),
+        registered_at: env.ledger().timestamp(),
+    };
+
+    let func = Symbol::new(env, HUB_REGISTER_ENTRY_POINT);
+    let args: Vec<Val> = (entry.clone(),).into_val(env);
+    let code = match env.try_invoke_contract::<Val, InvokeError>(&hub, &func, args) {
+        Ok(Ok(_)) => {
+            set_registry_entry(env, &entry);
+            return Ok(entry);
+        }
+        Err(Ok(InvokeError::Contract(code))) => code,
+        _ => 0,
+    };
+    emit_external_call_failed(env, hub, func, 0, code);
+    Err(ContractError::HubRegistrationFailed)
+}
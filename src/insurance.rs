@@ -0,0 +1,215 @@
+//! Opt-in remittance insurance.
+//!
+//! While a remittance is still `Pending`, its sender may insure it by paying a
+//! premium of [`INSURANCE_PREMIUM_BPS`] of the amount. Premiums are pooled in a
+//! per-token insurance fund held by the contract. If the remittance later ends
+//! in an agent default (`mark_failed`) or a dispute resolved in the sender's
+//! favour, the sender is compensated automatically with [`INSURANCE_COVERAGE_BPS`]
+//! of the amount, paid from the fund. Remittances that complete normally let
+//! their policy lapse and the premium stays in the fund.
+//!
+//! Losses the automatic triggers do not see, such as an agent confirming a
+//! payout the recipient never received, are handled through claims: once the
+//! remittance has left `Pending`/`Processing`, the sender may file one claim
+//! with an evidence hash, which a `ClaimResolver` approves (paying the
+//! coverage) or denies.
+//!
+//! Each policy pays out at most once, whichever path triggers it.
+
+use soroban_sdk::{contracttype, token, Address, BytesN, Env};
+
+use crate::config::{FEE_DIVISOR, INSURANCE_COVERAGE_BPS, INSURANCE_PREMIUM_BPS};
+use crate::storage::{
+    append_sender_claim, get_insurance_claim, get_insurance_fund, get_insurance_policy,
+    set_insurance_claim, set_insurance_fund, set_insurance_policy,
+};
+use crate::token_transfer;
+use crate::{ContractError, Remittance, RemittanceStatus};
+
+/// Insurance bought for a single remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsurancePolicy {
+    /// Premium the sender paid into the insurance fund
+    pub premium: i128,
+    /// Compensation owed to the sender if the remittance is not delivered
+    pub coverage: i128,
+    /// Whether the policy has already paid out
+    pub paid_out: bool,
+}
+
+/// Review state of an insurance claim.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClaimStatus {
+    Pending,
+    Approved,
+    Denied,
+}
+
+/// A sender's claim against a remittance's insurance policy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceClaim {
+    pub remittance_id: u64,
+    pub sender: Address,
+    /// Hash of the off-chain evidence supporting the claim
+    pub evidence_hash: BytesN<32>,
+    pub status: ClaimStatus,
+    pub filed_at: u64,
+    /// Resolver that approved or denied the claim
+    pub resolver: Option<Address>,
+    pub resolved_at: Option<u64>,
+    /// Amount paid from the insurance fund on approval
+    pub payout: i128,
+}
+
+/// Premium and coverage for insuring `amount`.
+pub fn quote(amount: i128) -> Result<(i128, i128), ContractError> {
+    let premium = amount
+        .checked_mul(INSURANCE_PREMIUM_BPS as i128)
+        .ok_or(ContractError::Overflow)?
+        / FEE_DIVISOR;
+    let coverage = amount
+        .checked_mul(INSURANCE_COVERAGE_BPS as i128)
+        .ok_or(ContractError::Overflow)?
+        / FEE_DIVISOR;
+    Ok((premium, coverage))
+}
+
+/// Collects the premium from the sender and records a policy for the remittance.
+pub fn insure(env: &Env, remittance: &Remittance) -> Result<InsurancePolicy, ContractError> {
+    if remittance.status != RemittanceStatus::Pending {
+        return Err(ContractError::InvalidStatus);
+    }
+    if get_insurance_policy(env, remittance.id).is_some() {
+        return Err(ContractError::AlreadyInsured);
+    }
+
+    let (premium, coverage) = quote(remittance.amount)?;
+    if premium <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    token_transfer::transfer(
+        &token::Client::new(env, &remittance.token),
+        &remittance.sender,
+        &env.current_contract_address(),
+        premium,
+    )?;
+    let fund = get_insurance_fund(env, &remittance.token)
+        .checked_add(premium)
+        .ok_or(ContractError::Overflow)?;
+    set_insurance_fund(env, &remittance.token, fund);
+
+    let policy = InsurancePolicy {
+        premium,
+        coverage,
+        paid_out: false,
+    };
+    set_insurance_policy(env, remittance.id, &policy);
+    Ok(policy)
+}
+
+/// Pays out a remittance's policy to its sender, if it has an unpaid one.
+///
+/// Pays the full coverage, or whatever the fund holds if it is short.
+/// Returns the amount paid.
+pub fn compensate(env: &Env, remittance: &Remittance) -> Result<i128, ContractError> {
+    let Some(mut policy) = get_insurance_policy(env, remittance.id) else {
+        return Ok(0);
+    };
+    if policy.paid_out {
+        return Ok(0);
+    }
+
+    let fund = get_insurance_fund(env, &remittance.token);
+    let payout = policy.coverage.min(fund).max(0);
+    policy.paid_out = true;
+    set_insurance_policy(env, remittance.id, &policy);
+    if payout > 0 {
+        set_insurance_fund(env, &remittance.token, fund - payout);
+        token_transfer::transfer(
+            &token::Client::new(env, &remittance.token),
+            &env.current_contract_address(),
+            &remittance.sender,
+            payout,
+        )?;
+    }
+    crate::events::emit_insurance_paid(env, remittance.id, remittance.sender.clone(), payout);
+    Ok(payout)
+}
+
+/// Files the single claim allowed against an insured remittance.
+pub fn file_claim(
+    env: &Env,
+    remittance: &Remittance,
+    evidence_hash: BytesN<32>,
+) -> Result<InsuranceClaim, ContractError> {
+    let policy = get_insurance_policy(env, remittance.id).ok_or(ContractError::NotInsured)?;
+    if matches!(
+        remittance.status,
+        RemittanceStatus::Pending | RemittanceStatus::Processing
+    ) {
+        return Err(ContractError::InvalidStatus);
+    }
+    if get_insurance_claim(env, remittance.id).is_some() || policy.paid_out {
+        return Err(ContractError::ClaimAlreadyFiled);
+    }
+
+    let claim = InsuranceClaim {
+        remittance_id: remittance.id,
+        sender: remittance.sender.clone(),
+        evidence_hash,
+        status: ClaimStatus::Pending,
+        filed_at: env.ledger().timestamp(),
+        resolver: None,
+        resolved_at: None,
+        payout: 0,
+    };
+    set_insurance_claim(env, remittance.id, &claim);
+    append_sender_claim(env, &remittance.sender, remittance.id);
+    Ok(claim)
+}
+
+/// Approves or denies a pending claim, paying the coverage on approval.
+pub fn resolve_claim(
+    env: &Env,
+    remittance: &Remittance,
+    resolver: &Address,
+    approve: bool,
+) -> Result<InsuranceClaim, ContractError> {
+    let mut claim = get_insurance_claim(env, remittance.id).ok_or(ContractError::ClaimNotFound)?;
+    if claim.status != ClaimStatus::Pending {
+        return Err(ContractError::ClaimAlreadyResolved);
+    }
+
+    if approve {
+        claim.status = ClaimStatus::Approved;
+        claim.payout = compensate(env, remittance)?;
+    } else {
+        claim.status = ClaimStatus::Denied;
+    }
+    claim.resolver = Some(resolver.clone());
+    claim.resolved_at = Some(env.ledger().timestamp());
+    set_insurance_claim(env, remittance.id, &claim);
+    Ok(claim)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote() {
+        let (premium, coverage) = quote(1_000_000).unwrap();
+        assert_eq!(premium, 1_000_000 * INSURANCE_PREMIUM_BPS as i128 / FEE_DIVISOR);
+        assert_eq!(coverage, 1_000_000 * INSURANCE_COVERAGE_BPS as i128 / FEE_DIVISOR);
+        assert!(premium < coverage);
+    }
+
+    #[test]
+    fn test_quote_overflow() {
+        assert_eq!(quote(i128::MAX), Err(ContractError::Overflow));
+    }
+}
@@ -0,0 +1,134 @@
+//! Core invariant checks for fuzzing, tests, and testnet runbooks.
+//!
+//! [`check`] walks the per-status indexes and reports every violation it
+//! finds instead of stopping at the first, so a fuzz run or an operator sees
+//! the whole picture in one call:
+//!
+//! - every indexed remittance exists, has the status of its index, and has an
+//!   ID the remittance counter has already issued;
+//! - each status count matches the number of entries in its index;
+//! - every `Completed` remittance has a settlement timestamp;
+//! - the settlement token balance covers the fees owed plus the principal of
+//!   every remittance that has not reached a terminal status.
+//!
+//! The scan reads every indexed remittance, so it is meant for simulation and
+//! test environments rather than regular transactions.
+
+use soroban_sdk::{contracttype, token, Env, Vec};
+
+use crate::storage::{
+    get_accumulated_fees, get_accumulated_integrator_fees, get_remittance,
+    get_remittance_counter, get_remittance_counts, get_status_bounds, get_status_entry,
+    get_usdc_token,
+};
+use crate::RemittanceStatus;
+
+const STATUSES: [RemittanceStatus; 6] = [
+    RemittanceStatus::Pending,
+    RemittanceStatus::Processing,
+    RemittanceStatus::Completed,
+    RemittanceStatus::Cancelled,
+    RemittanceStatus::Failed,
+    RemittanceStatus::Disputed,
+];
+
+/// Which invariant a [`InvariantViolation`] breaks.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InvariantKind {
+    /// An indexed remittance is missing or has a different status
+    IndexMismatch,
+    /// An indexed remittance ID is above the remittance counter
+    CounterBehind,
+    /// A status count differs from the number of entries in its index
+    CountMismatch,
+    /// A `Completed` remittance has no settlement timestamp
+    MissingSettlement,
+    /// The settlement token balance is below what the contract owes
+    Insolvent,
+}
+
+/// One broken invariant.
+///
+/// `remittance_id` is 0 for contract-wide invariants. `expected` and `actual`
+/// carry the compared counts or amounts, and are 0 where nothing is compared.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvariantViolation {
+    pub kind: InvariantKind,
+    pub remittance_id: u64,
+    pub expected: i128,
+    pub actual: i128,
+}
+
+fn violation(kind: InvariantKind, remittance_id: u64, expected: i128, actual: i128) -> InvariantViolation {
+    InvariantViolation {
+        kind,
+        remittance_id,
+        expected,
+        actual,
+    }
+}
+
+/// Checks the core invariants and returns every violation found.
+pub fn check(env: &Env) -> Vec<InvariantViolation> {
+    let mut violations = Vec::new(env);
+    let counter = get_remittance_counter(env).unwrap_or(0);
+    let settlement_token = get_usdc_token(env).ok();
+    let counts = get_remittance_counts(env);
+    let mut escrowed: i128 = 0;
+
+    for status in STATUSES.iter() {
+        let count = counts.count(status) as u32;
+        let (head, tail) = get_status_bounds(env, status);
+        let mut ids = Vec::new(env);
+        for pos in head..tail {
+            if let Some(id) = get_status_entry(env, status, pos) {
+                ids.push_back(id);
+            }
+        }
+        if ids.len() != count || get_status_entry(env, status, tail).is_some() {
+            violations.push_back(violation(
+                InvariantKind::CountMismatch,
+                0,
+                ids.len() as i128,
+                count as i128,
+            ));
+        }
+
+        for id in ids.iter() {
+            if id > counter {
+                violations.push_back(violation(
+                    InvariantKind::CounterBehind,
+                    id,
+                    id as i128,
+                    counter as i128,
+                ));
+            }
+            let remittance = match get_remittance(env, id) {
+                Ok(remittance) if remittance.status == *status => remittance,
+                _ => {
+                    violations.push_back(violation(InvariantKind::IndexMismatch, id, 0, 0));
+                    continue;
+                }
+            };
+            if *status == RemittanceStatus::Completed && remittance.completed_at.is_none() {
+                violations.push_back(violation(InvariantKind::MissingSettlement, id, 0, 0));
+            }
+            if !status.is_terminal() && Some(&remittance.token) == settlement_token.as_ref() {
+                escrowed = escrowed.saturating_add(remittance.amount);
+            }
+        }
+    }
+
+    if let Some(token) = settlement_token {
+        let owed = escrowed
+            .saturating_add(get_accumulated_fees(env).unwrap_or(0))
+            .saturating_add(get_accumulated_integrator_fees(env));
+        let balance = token::Client::new(env, &token).balance(&env.current_contract_address());
+        if balance < owed {
+            violations.push_back(violation(InvariantKind::Insolvent, 0, owed, balance));
+        }
+    }
+    violations
+}
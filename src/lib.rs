@@ -8,24 +8,63 @@
 #[cfg(test)]
 extern crate std;
 mod abuse_protection;
+mod admin_log;
+mod account_recovery;
+mod address_book;
+mod advances;
+mod agent_tiers;
+mod amount_commitment;
+mod anchor_link;
 mod asset_verification;
+mod batch_grouping;
+mod causes;
+mod checkpoints;
+mod collateral;
 mod config;
+mod credit_signals;
+mod corridor_config;
+mod corridor_utilization;
 mod debug;
 mod error_handler;
 mod errors;
+mod evacuation;
 mod events;
+mod factory;
 mod fee_management;
 mod fee_service;
 mod fee_strategy;
+mod forwarding;
+mod handles;
+mod hashed_records;
 mod hashing;
 mod health;
+mod hub_registry;
+mod insurance;
+mod invariants;
+mod limits;
+mod maintenance;
 #[cfg(test)]
 mod health_test;
 mod migration;
 mod multisig;
 mod netting;
+mod observers;
+mod netting_pairs;
+mod organization;
+mod path_payout;
+mod performance;
+mod priority;
+mod quotes;
+mod restrictions;
+mod routing;
+mod savings;
+mod scheduled_config;
+mod voucher;
 mod rate_limit;
 mod storage;
+mod templates;
+mod token_transfer;
+mod volume_history;
 pub mod circuit_breaker;
 pub mod circuit_breaker_storage;
 #[cfg(all(test, feature = "legacy-tests"))]
@@ -94,29 +133,205 @@ mod test_dispute;
 #[cfg(test)]
 mod test_features_589_592;
 #[cfg(test)]
+mod test_golden_state;
+#[cfg(test)]
 mod test_state_machine_property;
 #[cfg(test)]
 mod test_contract_upgrade;
+#[cfg(test)]
+mod test_custom_account;
+#[cfg(test)]
+mod test_reentrancy;
+#[cfg(test)]
+mod test_utils;
+#[cfg(test)]
+mod test_organization;
+#[cfg(test)]
+mod test_agent_recovery;
+#[cfg(test)]
+mod test_remittance_queries;
+#[cfg(test)]
+mod test_pagination;
+#[cfg(test)]
+mod test_schema_migration;
+#[cfg(test)]
+mod test_expiry;
+#[cfg(test)]
+mod test_fee_schedule;
+#[cfg(test)]
+mod test_fee_exemption;
+#[cfg(test)]
+mod test_voucher;
+#[cfg(test)]
+mod test_escrow_claim;
+#[cfg(test)]
+mod test_dry_run;
+#[cfg(test)]
+mod test_observers;
+#[cfg(test)]
+mod test_sub_operators;
+#[cfg(test)]
+mod test_batch_settlement;
+#[cfg(test)]
+mod test_pickup_secret;
+#[cfg(test)]
+mod test_tags;
+#[cfg(test)]
+mod test_routing;
+#[cfg(test)]
+mod test_reassign;
+#[cfg(test)]
+mod test_netting_pairs;
+#[cfg(test)]
+mod test_auto_settlement;
+#[cfg(test)]
+mod test_expiry_fee;
+#[cfg(test)]
+mod test_fee_guardrails;
+#[cfg(test)]
+mod test_completion_latency;
+#[cfg(test)]
+mod test_duplicate_settlement;
+#[cfg(test)]
+mod test_savings;
+#[cfg(test)]
+mod test_encrypted_memo;
+#[cfg(test)]
+mod test_corridor_updates;
+#[cfg(test)]
+mod test_batch_remittance;
+#[cfg(test)]
+mod test_maintenance;
+#[cfg(test)]
+mod test_causes;
+#[cfg(test)]
+mod test_advances;
+#[cfg(test)]
+mod test_credit_signals;
+#[cfg(test)]
+mod test_private_remittances;
+#[cfg(test)]
+mod test_minimal_disclosure;
+#[cfg(test)]
+mod test_hashed_records;
+#[cfg(test)]
+mod test_templates;
+#[cfg(test)]
+mod test_address_book;
+#[cfg(test)]
+mod test_handles;
+#[cfg(test)]
+mod test_scheduled_config;
+#[cfg(test)]
+mod test_evacuation;
+#[cfg(test)]
+mod test_account_recovery;
+#[cfg(test)]
+mod test_token_transfer;
+#[cfg(test)]
+mod test_volume_history;
+#[cfg(test)]
+mod test_metrics;
+#[cfg(test)]
+mod test_checkpoints;
+#[cfg(test)]
+mod test_admin_log;
+#[cfg(test)]
+mod test_config_events;
+#[cfg(test)]
+mod test_invariant_checks;
+#[cfg(test)]
+mod test_factory;
+#[cfg(test)]
+mod test_hub_registry;
+#[cfg(test)]
+mod test_forwarding;
+#[cfg(test)]
+mod test_path_payout;
+#[cfg(test)]
+mod test_anchor_link;
+#[cfg(test)]
+mod test_quotes;
+#[cfg(test)]
+mod test_delivery;
+#[cfg(test)]
+mod test_restrictions;
+#[cfg(test)]
+mod test_priority;
+#[cfg(test)]
+mod test_cancel_window;
+#[cfg(test)]
+mod test_limits;
+#[cfg(test)]
+mod test_agent_registration;
+#[cfg(test)]
+mod test_token_fees;
+#[cfg(test)]
+mod test_collateral;
+#[cfg(test)]
+mod test_agent_capacity;
+#[cfg(test)]
+mod test_agent_commission;
 #[cfg(all(test, feature = "legacy-tests"))]
 mod test_circuit_breaker;
 
-use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env, String, Vec};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, token, Address, Bytes, BytesN, Env, IntoVal, Map, String,
+    Symbol, Vec,
+};
 
 pub use abuse_protection::*;
+pub use admin_log::{AdminLogEntry, AdminLogPage};
+pub use account_recovery::{AccountRecovery, RecoveryGuardians};
+pub use address_book::{AddressBookEntry, ContactKind};
+pub use advances::AgentAdvance;
+pub use agent_tiers::{AgentTier, AgentTierPrivileges};
+pub use amount_commitment::PrivateRemittance;
+pub use anchor_link::{AnchorLink, AnchorTxStatus};
 pub use asset_verification::*;
+pub use causes::{Cause, CauseBalance, DonationPledge, DonationSource};
+pub use collateral::CollateralRequirement;
 pub use config::*;
+pub use corridor_config::{CorridorConfigUpdate, CorridorFeeUpdate};
+pub use corridor_utilization::{CorridorLiquidity, UtilizationFeeBounds};
+pub use credit_signals::{ActivitySummary, CreditParty};
 pub use debug::*;
 pub use error_handler::*;
 pub use errors::ContractError;
+pub use evacuation::{EvacuationParty, EvacuationRequest};
 pub use events::*;
+pub use factory::{CorridorInstance, InstanceConfig};
 pub use fee_management::*;
 pub use fee_service::*;
 pub use fee_strategy::*;
+pub use forwarding::ForwardedRemittance;
+pub use handles::{HandleRecord, Payee};
+pub use hashed_records::{HashedRemittance, RemittanceDetails};
 pub use hashing::*;
+pub use hub_registry::RegistryEntry;
+pub use insurance::{ClaimStatus, InsuranceClaim, InsurancePolicy};
+pub use invariants::{InvariantKind, InvariantViolation};
+pub use limits::{Limits, PendingLimits};
+pub use maintenance::MaintenanceWindow;
 pub use migration::*;
 pub use netting::*;
+pub use netting_pairs::{NettingCheckpoint, NettingPair};
+pub use observers::{ObserverEvent, RemittanceNotification, OBSERVER_ENTRY_POINT};
+pub use organization::{
+    OrgMember, OrgRemittanceOutcome, OrgRemittanceRequest, OrgRequestStatus, Organization,
+};
+pub use path_payout::{PathPayout, PayoutPreference, PATH_PAYMENT_ENTRY_POINT, QUOTE_ENTRY_POINT};
+pub use performance::{PerformanceCounters, PerformanceStats};
+pub use priority::PriorityBoost;
+pub use quotes::{AcceptedQuote, Quote};
+use performance::PerformanceEvent;
 pub use rate_limit::*;
+pub use restrictions::{Restriction, RestrictionRecord, RestrictionTarget};
+pub use routing::{LegStatus, RouteHop, RouteLeg};
+pub use savings::SavingsGoal;
+pub use scheduled_config::{ConfigChange, ScheduledConfigChange};
 pub use storage::*;
+pub use templates::{RemittanceTemplate, TemplateOverrides};
 pub use transaction_controller::*;
 pub use transitions::*;
 pub use recipient_verification::{
@@ -125,6 +340,7 @@ pub use recipient_verification::{
     RECIPIENT_HASH_SCHEMA_VERSION, compute_recipient_hash,
 };
 pub use types::*;
+pub use volume_history::VolumeBucket;
 pub use validation::*;
 pub use verification::*;
 
@@ -135,6 +351,28 @@ fn enforce_daily_send_limit(
     country: &String,
     amount: i128,
 ) -> Result<(), ContractError> {
+    let (mut pruned, _) = check_daily_send_limit(env, sender, currency, country, amount)?;
+    pruned.push_back(TransferRecord {
+        timestamp: env.ledger().timestamp(),
+        amount,
+        currency: currency.clone(),
+        country: country.clone(),
+    });
+    set_user_transfers(env, sender, &pruned);
+
+    Ok(())
+}
+
+/// Checks `amount` against the sender's rolling daily limit without recording
+/// it. Returns the sender's transfers still inside the window and, when a limit
+/// is configured, the headroom left after `amount`.
+fn check_daily_send_limit(
+    env: &Env,
+    sender: &Address,
+    currency: &String,
+    country: &String,
+    amount: i128,
+) -> Result<(Vec<TransferRecord>, Option<i128>), ContractError> {
     let now = env.ledger().timestamp();
     let window_start = now.saturating_sub(DAILY_LIMIT_WINDOW_SECONDS);
 
@@ -154,6 +392,7 @@ fn enforce_daily_send_limit(
         }
     }
 
+    let mut remaining = None;
     if let Some(limit_cfg) = get_daily_limit(env, currency, country) {
         let next_total = rolling_total
             .checked_add(amount)
@@ -161,147 +400,980 @@ fn enforce_daily_send_limit(
         if next_total > limit_cfg.limit {
             return Err(ContractError::DailySendLimitExceeded);
         }
+        remaining = Some(limit_cfg.limit - next_total);
     }
 
-    pruned.push_back(TransferRecord {
-        timestamp: now,
-        amount,
-        currency: currency.clone(),
-        country: country.clone(),
-    });
-    set_user_transfers(env, sender, &pruned);
-
-    Ok(())
+    Ok((pruned, remaining))
 }
 
-/// The main SwiftRemit contract for managing cross-border remittances.
+/// Persists a new Pending remittance whose funds are already held by the contract.
 ///
-/// This contract handles the complete lifecycle of remittance transactions including:
-/// - Agent registration and management
-/// - Remittance creation with automatic fee calculation
-/// - Settlement confirmation with duplicate protection
-/// - Cancellation and refund processing
-/// - Platform fee collection and withdrawal
-#[contract]
-pub struct SwiftRemitContract;
-
-// ============================================================================
-// Configuration Constants
-// ============================================================================
-//
-// These constants define validation limits and calculation parameters.
-// They are intentionally hardcoded in the contract to ensure consistent
-// on-chain behavior across all deployments.
-//
-// MAX_FEE_BPS: Maximum allowed fee in basis points (100% = 10000 bps)
-// - This limit prevents accidentally setting fees above 100%
-// - Used in initialize() and update_fee() for validation
-// - Value: 10000 (represents 100%)
-//
-// FEE_DIVISOR: Divisor for converting basis points to actual fee amount
-// - Formula: fee_amount = amount * fee_bps / FEE_DIVISOR
-// - Used in create_remittance() for fee calculation
-// - Value: 10000 (basis points scale)
-//
-// Configurable Values at Deployment:
-// - initial fee_bps: Set during initialize(), can be any value 0-10000
-//   This value can be configured via the INITIAL_FEE_BPS environment variable
-//   in deployment scripts (deploy.sh, deploy.ps1)
-//
-// Runtime Configurable Values:
-// - fee_bps: Can be updated by admin via update_fee()
-// ============================================================================
+/// Performs the bookkeeping shared by creation paths that do not pull funds
+/// from the sender themselves (ID allocation, payout commitment, indexes,
+/// analytics, and observer notification). Callers are responsible for
+/// validation, fee calculation, and escrowing `amount` beforehand; `fee` is
+/// waived here if the sender is fee-exempt.
+pub(crate) fn record_new_remittance(
+    env: &Env,
+    sender: &Address,
+    agent: &Address,
+    amount: i128,
+    fee: i128,
+    token: &Address,
+    expiry: Option<Expiry>,
+) -> Result<Remittance, ContractError> {
+    restrictions::check(env, sender, token, None)?;
+    restrictions::check(env, agent, token, None)?;
+    let charged = creation_fee(env, sender, amount, expiry, || Ok(fee))?;
+    let fee = charged.fee;
+
+    let counter = get_remittance_counter(env)?;
+    let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+
+    let created_at = env.ledger().timestamp();
+    let expiry_window = storage::get_remittance_expiry_window(env);
+    let expires_at = if expiry_window > 0 {
+        Some(created_at.saturating_add(expiry_window))
+    } else {
+        None
+    };
+    let remittance = Remittance {
+        id: remittance_id,
+        sender: sender.clone(),
+        agent: agent.clone(),
+        amount,
+        fee,
+        status: RemittanceStatus::Pending,
+        expiry: expiry.into(),
+        settlement_config: MaybeSettlementConfig::None,
+        token: token.clone(),
+        created_at,
+        failed_at: None,
+        dispute_evidence: None.into(),
+        expires_at,
+        accepted_at: None,
+        completed_at: None,
+        memo: None,
+        corridor: MaybeCorridor::None,
+    };
+
+    let payout_commitment = compute_payout_commitment(env, &remittance);
+
+    set_remittance(env, remittance_id, &remittance);
+    set_payout_commitment(env, remittance_id, &payout_commitment);
+    set_remittance_counter(env, remittance_id);
+    set_transfer_state(env, remittance_id, RemittanceStatus::Pending)?;
+    agent_tiers::track_pending(env, &remittance)?;
+    storage::record_sender_volume(env, sender, amount, created_at)?;
+    storage::increment_remittance_count(env)?;
+    storage::append_sender_remittance(env, sender, remittance_id);
+    storage::append_agent_remittance(env, agent, remittance_id);
+    record_expiry_fee(env, remittance_id, charged.lock_seconds, charged.expiry_fee);
+    emit_remittance_created(
+        env,
+        remittance_id,
+        sender.clone(),
+        agent.clone(),
+        amount,
+        fee,
+        0,
+        fee,
+        0,
+        amount - fee,
+        charged.exempt,
+    );
+
+    observers::notify_observers(env, ObserverEvent::Created, &remittance);
+    performance::record(env, PerformanceEvent::Created, &remittance);
+
+    Ok(remittance)
+}
 
-#[contractimpl]
-impl SwiftRemitContract {
-    fn set_blacklist_status(
-        env: &Env,
-        user: Address,
-        blacklisted: bool,
-    ) -> Result<(), ContractError> {
-        let caller = get_admin(env)?;
-        require_admin(env, &caller)?;
+/// Adds the expiry-weighted component to a creation-time platform fee.
+///
+/// Returns the total fee together with the lock duration and expiry fee so
+/// they can be recorded once the remittance ID is known.
+fn add_expiry_fee(
+    env: &Env,
+    amount: i128,
+    platform_fee: i128,
+    expiry: Option<Expiry>,
+) -> Result<(i128, u64, i128), ContractError> {
+    let (lock_seconds, expiry_fee) = fee_service::calculate_expiry_fee(env, amount, expiry)?;
+    let fee = platform_fee
+        .checked_add(expiry_fee)
+        .ok_or(ContractError::Overflow)?;
+    if fee >= amount {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok((fee, lock_seconds, expiry_fee))
+}
 
-        set_user_blacklisted(env, &user, blacklisted);
+/// Fee fixed on a new remittance at creation.
+struct CreationFee {
+    /// Platform plus expiry fee stored on the remittance
+    fee: i128,
+    lock_seconds: u64,
+    expiry_fee: i128,
+    /// The sender's fee exemption waived both components
+    exempt: bool,
+}
 
-        if blacklisted {
-            emit_user_blacklisted(env, user, caller);
-        } else {
-            emit_user_removed_from_blacklist(env, user, caller);
-        }
+/// Fixes the fee for a new remittance from `sender`.
+///
+/// Shared by every creation path so a fee-exempt sender is never charged,
+/// whichever entry point they use. `platform_fee` is only evaluated for
+/// senders who pay.
+fn creation_fee(
+    env: &Env,
+    sender: &Address,
+    amount: i128,
+    expiry: Option<Expiry>,
+    platform_fee: impl FnOnce() -> Result<i128, ContractError>,
+) -> Result<CreationFee, ContractError> {
+    if storage::is_fee_exempt(env, sender) {
+        return Ok(CreationFee {
+            fee: 0,
+            lock_seconds: 0,
+            expiry_fee: 0,
+            exempt: true,
+        });
+    }
+    let (fee, lock_seconds, expiry_fee) = add_expiry_fee(env, amount, platform_fee()?, expiry)?;
+    Ok(CreationFee {
+        fee,
+        lock_seconds,
+        expiry_fee,
+        exempt: false,
+    })
+}
 
-        Ok(())
+/// Stores and announces the expiry-weighted fee charged on a new remittance.
+fn record_expiry_fee(env: &Env, remittance_id: u64, lock_seconds: u64, expiry_fee: i128) {
+    if expiry_fee > 0 {
+        storage::set_remittance_expiry_fee(env, remittance_id, expiry_fee);
+        events::emit_expiry_fee_charged(env, remittance_id, lock_seconds, expiry_fee);
     }
+}
 
-    /// Initializes the contract with admin, token, and fee configuration.
-    ///
-    /// This function can only be called once. It sets up the contract's core parameters
-    /// and initializes all counters and accumulators to zero.
-    ///
-    /// # Arguments
-    ///
-    /// * `env` - The contract execution environment
-    /// * `admin` - Address that will have administrative privileges
-    /// * `usdc_token` - Address of the USDC token contract used for transactions
-    /// * `fee_bps` - Platform fee in basis points (1 bps = 0.01%, max 10000 = 100%)
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(())` - Contract successfully initialized
-    /// * `Err(ContractError::AlreadyInitialized)` - Contract was already initialized
-    /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds maximum allowed (10000 bps)
-    ///
-    /// # Examples
-    ///
-    /// ```ignore
-    /// contract.initialize(env, admin_addr, usdc_addr, 250); // 2.5% fee
-    /// ```
-    pub fn initialize(
-        env: Env,
-        admin: Address,
-        usdc_token: Address,
-        fee_bps: u32,
-        rate_limit_cooldown: u64,
-        protocol_fee_bps: u32,
-        treasury: Address,
-    ) -> Result<(), ContractError> {
-        // Centralized validation before business logic
-        validate_initialize_request(&env, &admin, &usdc_token, fee_bps)?;
+/// Moves a pending remittance past `expiry` to `Expired` and refunds the
+/// sender, including any expedite fees.
+///
+/// Shared by `expire_remittance` and `process_expired_remittances`; callers
+/// check the status and the deadline first.
+fn expire_pending(
+    env: &Env,
+    mut remittance: Remittance,
+    expiry: Expiry,
+) -> Result<(), ContractError> {
+    let remittance_id = remittance.id;
+    let refund = remittance.amount;
+    crate::transitions::transition_status(env, &mut remittance, RemittanceStatus::Expired)?;
+    corridor_utilization::release_pending(env, &remittance);
+    agent_tiers::release_pending(env, &remittance);
+    performance::record(env, PerformanceEvent::Expired, &remittance);
+    remittance.amount = 0;
+    set_remittance(env, remittance_id, &remittance);
+
+    token_transfer::transfer_for(
+        &token::Client::new(env, &remittance.token),
+        remittance_id,
+        &env.current_contract_address(),
+        &remittance.sender,
+        refund,
+    )?;
+    priority::refund(env, &remittance)?;
+
+    if !emit_minimal_lifecycle(env, &remittance) {
+        emit_remittance_expired(
+            env,
+            remittance_id,
+            remittance.sender,
+            remittance.token,
+            refund,
+            expiry,
+        );
+    }
 
-        // Set legacy admin for backward compatibility
-        set_admin(&env, &admin);
+    if let Some(idem_key) = storage::take_remittance_idempotency_key(env, remittance_id) {
+        storage::remove_idempotency_record(env, &idem_key);
+    }
+    Ok(())
+}
 
-        // Initialize new admin role system
-        set_admin_role(&env, &admin, true);
-        set_admin_count(&env, 1);
+/// Emits the ID-and-status-only lifecycle event for remittances whose sender
+/// asked for minimal disclosure.
+///
+/// Returns `true` when the caller must suppress its full lifecycle events,
+/// which carry amounts and counterparties.
+pub(crate) fn emit_minimal_lifecycle(env: &Env, remittance: &Remittance) -> bool {
+    if !storage::is_minimal_disclosure(env, remittance.id) {
+        return false;
+    }
+    emit_remittance_status(env, remittance.id, remittance.status.clone());
+    true
+}
 
-        // Assign Admin role to initial admin
-        assign_role(&env, &admin, &Role::Admin);
+/// Checks that a remittance can be handed to `to_agent`: it must be Pending,
+/// have no partial disbursements, and not already belong to `to_agent`.
+fn ensure_reassignable(
+    env: &Env,
+    remittance: &Remittance,
+    to_agent: &Address,
+) -> Result<(), ContractError> {
+    if remittance.status != RemittanceStatus::Pending
+        || storage::get_disbursed_amount(env, remittance.id) > 0
+    {
+        return Err(ContractError::InvalidStatus);
+    }
+    if remittance.agent == *to_agent {
+        return Err(ContractError::InvalidAddress);
+    }
+    collateral::check_assignment(env, to_agent, remittance.amount)?;
+    let corridor = match &remittance.corridor {
+        MaybeCorridor::Some(corridor) => Some(corridor),
+        MaybeCorridor::None => None,
+    };
+    restrictions::check(env, to_agent, &remittance.token, corridor)
+}
 
-        set_usdc_token(&env, &usdc_token);
-        set_token_whitelisted(&env, &usdc_token, true);
-        set_platform_fee_bps(&env, fee_bps);
-        set_token_fee_bps(&env, &usdc_token, fee_bps)?;
-        set_fee_strategy(&env, &FeeStrategy::Percentage(fee_bps));
-        set_remittance_counter(&env, 0);
-        set_accumulated_fees(&env, 0);
-        set_rate_limit_cooldown(&env, rate_limit_cooldown);
-        set_escrow_counter(&env, 0);
-        set_escrow_ttl(&env, 0);
+/// Moves a pending remittance to `to_agent`, updating the routed cash-out leg,
+/// tier tracking, and payout commitment, and notifies the sender.
+fn move_to_agent(
+    env: &Env,
+    remittance: &mut Remittance,
+    to_agent: Address,
+) -> Result<(), ContractError> {
+    let from_agent = remittance.agent.clone();
+    routing::reassign_cash_out(env, remittance.id, &to_agent)?;
+
+    // Move the pending value over, subject to the new agent's tier
+    agent_tiers::release_pending(env, remittance);
+    remittance.agent = to_agent.clone();
+    agent_tiers::track_pending(env, remittance)?;
+    set_remittance(env, remittance.id, remittance);
+    set_payout_commitment(env, remittance.id, &compute_payout_commitment(env, remittance));
+    storage::append_agent_remittance(env, &to_agent, remittance.id);
+    storage::remove_agent_rejection(env, remittance.id);
+
+    emit_remittance_reassigned(env, remittance.id, remittance.sender.clone(), from_agent, to_agent);
+    Ok(())
+}
 
-        // Initialize protocol fee and treasury
-        set_protocol_fee_bps(&env, protocol_fee_bps)?;
-        set_treasury(&env, &treasury);
+/// Checks that the sender may cancel a pending remittance alone: the cancel
+/// cooldown has elapsed, and the agent has not accepted it or has breached its
+/// SLA. Neither applies once the agent has rejected the remittance.
+fn check_sender_may_cancel(env: &Env, remittance: &Remittance) -> Result<(), ContractError> {
+    if storage::get_agent_rejection(env, remittance.id).is_some() {
+        return Ok(());
+    }
+    let cooldown = storage::get_cancel_cooldown(env);
+    if env.ledger().timestamp() < remittance.created_at.saturating_add(cooldown) {
+        return Err(ContractError::CancelCooldownActive);
+    }
+    if storage::get_delivery_update(env, remittance.id).is_some()
+        && !priority::sla_breached(env, remittance)
+    {
+        return Err(ContractError::CancelWindowClosed);
+    }
+    Ok(())
+}
 
-        // Initialize rate limiting with default configuration
-        init_rate_limit(&env);
+/// Cancels a pending remittance and refunds its amount and any expedite fees
+/// to the sender.
+fn cancel_and_refund(env: &Env, mut remittance: Remittance) -> Result<(), ContractError> {
+    let remittance_id = remittance.id;
+    let remittance_token = remittance.token.clone();
+    let refund = remittance.amount;
+
+    remittance.status = RemittanceStatus::Cancelled;
+    // Fix #378: zero out the amount field so querying the remittance after
+    // cancellation does not return a stale USDC balance.
+    remittance.amount = 0;
+    set_remittance(env, remittance_id, &remittance);
+    corridor_utilization::release_pending(env, &remittance);
+    agent_tiers::release_pending(env, &remittance);
+
+    let token_client = token::Client::new(env, &remittance_token);
+    token_transfer::transfer_for(
+        &token_client,
+        remittance_id,
+        &env.current_contract_address(),
+        &remittance.sender,
+        refund,
+    )?;
+    priority::refund(env, &remittance)?;
+
+    // Event: Remittance cancelled - Fires when sender cancels a pending remittance and receives full refund
+    // Used by off-chain systems to track cancellations and update transaction status
+    if !emit_minimal_lifecycle(env, &remittance) {
+        emit_remittance_cancelled(
+            env,
+            remittance_id,
+            remittance.sender,
+            remittance.agent,
+            remittance_token,
+            remittance.amount,
+        );
+    }
 
-        log_initialize(&env, &admin, &usdc_token, fee_bps);
+    log_cancel_remittance(env, remittance_id);
 
-        Ok(())
+    // Cleanup: remove idempotency record on terminal state (Cancelled)
+    if let Some(idem_key) = storage::take_remittance_idempotency_key(env, remittance_id) {
+        storage::remove_idempotency_record(env, &idem_key);
     }
 
-    /// Registers a new agent authorized to receive remittance payouts.
+    // #831: Post-cancel cleanup — removes controller-layer bookkeeping entries
+    // so stale transaction records and anchor mappings do not persist after cancellation.
+    transaction_controller::TransactionController::post_cancel_cleanup(env, remittance_id)?;
+
+    Ok(())
+}
+
+/// Pays an escrow in status `from` out to its recipient, keeping the
+/// recipient's savings share in their vault.
+///
+/// Every path that releases escrowed funds to a recipient goes through here.
+fn release_to_recipient(
+    env: &Env,
+    escrow: &mut Escrow,
+    from: EscrowStatus,
+) -> Result<(), ContractError> {
+    if escrow.status != from {
+        return Err(ContractError::InvalidEscrowStatus);
+    }
+
+    let usdc_token = get_usdc_token(env)?;
+    let token_client = token::Client::new(env, &usdc_token);
+
+    // Keep the recipient's chosen share in their savings vault
+    let (paid, saved) = savings::split(env, &escrow.recipient, escrow.amount)?;
+    escrow.status = EscrowStatus::Released;
+    set_escrow(env, escrow.transfer_id, escrow);
+    let balance = if saved > 0 {
+        Some(savings::deposit(env, &escrow.recipient, &usdc_token, saved)?)
+    } else {
+        None
+    };
+
+    if paid > 0 {
+        token_transfer::transfer(
+            &token_client,
+            &env.current_contract_address(),
+            &escrow.recipient,
+            paid,
+        )?;
+    }
+    if let Some(balance) = balance {
+        emit_savings_deposited(env, escrow.recipient.clone(), usdc_token, saved, balance);
+    }
+    emit_escrow_released(env, escrow.transfer_id, escrow.recipient.clone(), escrow.amount);
+    Ok(())
+}
+
+/// Emits one `remit/tagged` event per sender-defined tag on `remittance`.
+///
+/// Tag events carry amounts and counterparties, so minimal-disclosure
+/// remittances emit none.
+fn emit_tag_events(env: &Env, remittance: &Remittance, action: Symbol) {
+    if storage::is_minimal_disclosure(env, remittance.id) {
+        return;
+    }
+    for tag in storage::get_remittance_tags(env, remittance.id).iter() {
+        emit_remittance_tagged(
+            env,
+            tag,
+            action.clone(),
+            remittance.id,
+            remittance.sender.clone(),
+            remittance.agent.clone(),
+            remittance.amount,
+        );
+    }
+}
+
+/// Checks that a netting batch may run: the contract is not paused and the
+/// batch holds between 1 and `MAX_BATCH_SIZE` entries.
+fn validate_netting_batch(env: &Env, entries: &Vec<BatchSettlementEntry>) -> Result<(), ContractError> {
+    if is_paused(env) {
+        return Err(ContractError::ContractPaused);
+    }
+    if entries.is_empty() || entries.len() > MAX_BATCH_SIZE {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(())
+}
+
+/// Loads a remittance for `batch_settle_with_netting` and checks it can be
+/// netted: Pending, not already settled, not expired, and with no route or
+/// donation pledge.
+fn load_netting_entry(env: &Env, remittance_id: u64) -> Result<Remittance, ContractError> {
+    let remittance = get_remittance(env, remittance_id)?;
+
+    // Verify remittance is pending
+    if remittance.status != RemittanceStatus::Pending {
+        return Err(ContractError::InvalidStatus);
+    }
+
+    // Check for duplicate settlement execution
+    if remittance.is_settlement_executed() {
+        return Err(ContractError::DuplicateSettlement);
+    }
+
+    // Check expiry
+    validate_settlement_not_expired(env, remittance.expiry.into())?;
+
+    // Netting pays agents directly and cannot apportion hop fees or donations
+    if storage::get_remittance_route(env, remittance_id).is_some() {
+        return Err(ContractError::InvalidRoute);
+    }
+    causes::require_no_pledge(env, remittance_id)?;
+
+    // Address type is guaranteed valid by the Soroban SDK runtime; no further
+    // address validation is required or possible at the contract level.
+    Ok(remittance)
+}
+
+/// `(token, recipient, amount)` transfers and `(token, fees)` of a netting settlement.
+type NettingPlan = (Vec<(Address, Address, i128)>, Vec<(Address, i128)>);
+
+/// Groups validated netting remittances by token, nets each group, and
+/// returns the `(token, recipient, amount)` transfers and the `(token, fees)`
+/// the settlement would make.
+fn plan_netting_transfers(
+    env: &Env,
+    remittances: &Vec<Remittance>,
+) -> Result<NettingPlan, ContractError> {
+    let mut transfers = Vec::new(env);
+    let mut fees = Vec::new(env);
+    for token_batch in batch_grouping::group_by_token(env, remittances)?.iter() {
+        let net_transfers = compute_net_settlements(env, &token_batch.remittances)?.net_transfers;
+        validate_net_settlement(&token_batch.remittances, &net_transfers)?;
+        let plan = batch_grouping::aggregate_payouts(env, &net_transfers)?;
+        fees.push_back((token_batch.token.clone(), plan.fees));
+        for (to, amount) in plan.payouts.iter() {
+            transfers.push_back((token_batch.token.clone(), to, amount));
+        }
+    }
+    Ok((transfers, fees))
+}
+
+/// Settles a validated, authorized payout: runs the pre-confirm hook, checks
+/// proof, recipient, and pickup-secret commitments, moves the remittance through Processing to
+/// Completed, and releases funds to the agent and treasury.
+///
+/// Shared by `confirm_payout`, `confirm_with_delivery_proof`, and
+/// `settle_with_voucher`, which differ only in how the agent's authorization
+/// is established and whether a delivery proof is supplied. Returns the
+/// payout amount.
+fn execute_payout(
+    env: &Env,
+    remittance: Remittance,
+    proof: Option<BytesN<32>>,
+    recipient_details_hash: Option<BytesN<32>>,
+    pickup_secret: Option<Bytes>,
+    delivery_proof: Option<BytesN<32>>,
+) -> Result<i128, ContractError> {
+    let committed = commit_payout(
+        env,
+        remittance,
+        proof,
+        recipient_details_hash,
+        pickup_secret,
+        delivery_proof,
+        None,
+    )?;
+    release_payout(env, committed, None)
+}
+
+/// A payout whose state is committed and whose tokens have yet to move: the
+/// Completed remittance, payout amount, protocol fee, and accepted quote.
+type CommittedPayout = (Remittance, i128, i128, Option<AcceptedQuote>);
+
+/// Checks and effects of a payout: validates it and commits fee accounting,
+/// volume, and the Completed status without moving any tokens.
+///
+/// When `batch` is provided a fee in the default token is added to its
+/// running total instead of storage, for the caller to commit once before
+/// the batch's first transfer.
+fn commit_payout(
+    env: &Env,
+    mut remittance: Remittance,
+    proof: Option<BytesN<32>>,
+    recipient_details_hash: Option<BytesN<32>>,
+    pickup_secret: Option<Bytes>,
+    delivery_proof: Option<BytesN<32>>,
+    batch: Option<&mut BatchPayout>,
+) -> Result<CommittedPayout, ContractError> {
+    let remittance_id = remittance.id;
+
+    // #831: Pre-confirm lifecycle hook — validates sender eligibility and KYC
+    // before any state mutation occurs.
+    transaction_controller::TransactionController::pre_confirm_validation(env, &remittance)?;
+
+    // Routed remittances can only be cashed out once every intermediate hop has confirmed
+    routing::require_route_ready(env, remittance_id)?;
+
+    // Validate proof against settlement config if required
+    if let crate::MaybeSettlementConfig::Some(ref config) = remittance.settlement_config {
+        if config.require_proof {
+            match proof {
+                None => return Err(ContractError::MissingProof),
+                Some(ref submitted) => {
+                    let expected = get_payout_commitment(env, remittance_id);
+                    if let Some(ref expected_hash) = expected {
+                        if !verification::verify_proof_commitment(submitted, expected_hash) {
+                            return Err(ContractError::InvalidProof);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Require Settler role
+    require_role_settler(env, &remittance.agent)?;
+
+    // Fix #379: Refuse to pay out a remittance that has already been settled.
+    // Settlement state lives on the remittance record itself (status plus
+    // lifecycle timestamps), so no separate dedup entry is read or written.
+    validate_no_duplicate_settlement(&remittance)?;
+
+    // Transition to Processing state
+    crate::transitions::transition_status(env, &mut remittance, RemittanceStatus::Processing)?;
+    storage::add_processing_volume(env, remittance.amount)?;
+    corridor_utilization::release_pending(env, &remittance);
+    agent_tiers::release_pending(env, &remittance);
+
+    // Extend the remittance TTL when entering Processing so the escrow
+    // does not expire while the agent is completing the off-chain payout (#624).
+    crate::storage::extend_remittance_ttl(
+        env,
+        remittance_id,
+        crate::config::PROCESSING_WINDOW_LEDGERS,
+    );
+
+    // Verify recipient hash before any token transfer (Task 7.2)
+    recipient_verification::verify_recipient_hash(
+        env,
+        remittance_id,
+        &remittance.agent,
+        recipient_details_hash,
+    )?;
+
+    // Recipient must have been present at pickup before funds are released
+    recipient_verification::verify_pickup_secret(env, &remittance, pickup_secret)?;
+
+    // High-value payouts must leave a delivery proof as dispute evidence
+    recipient_verification::record_delivery_proof(env, &remittance, delivery_proof)?;
+
+    // Update Agent Stats
+    let mut stats = crate::storage::get_agent_stats(env, &remittance.agent);
+    stats.total_settlements += 1;
+    stats.total_settlement_time += env
+        .ledger()
+        .timestamp()
+        .saturating_sub(remittance.created_at);
+    stats.last_active_timestamp = env.ledger().timestamp();
+    let successful = stats.total_settlements.saturating_sub(stats.failed_settlements);
+    stats.success_rate_bps = successful
+        .saturating_mul(10000)
+        .checked_div(stats.total_settlements)
+        .unwrap_or(10000);
+    crate::storage::set_agent_stats(env, &remittance.agent, &stats);
+
+    // Check rate limit for sender
+    check_settlement_rate_limit(env, &remittance.sender)?;
+
+    // Enforce per-agent daily withdrawal cap
+    storage::check_and_record_agent_withdrawal(env, &remittance.agent, remittance.amount)?;
+
+    // The fee was fixed when the remittance was created (platform fee, expiry
+    // fee, and any exemption), so later schedule changes never apply to it.
+    // Only the payout-time protocol fee is computed now.
+    let protocol_fee = fee_service::calculate_payout_protocol_fee(env, remittance.amount)?;
+    let payout_amount = remittance
+        .amount
+        .checked_sub(remittance.fee)
+        .and_then(|v| v.checked_sub(protocol_fee))
+        .filter(|v| *v >= 0)
+        .ok_or(ContractError::Overflow)?;
+
+    // A quoted remittance only settles on the terms it was accepted under
+    let accepted_quote = quotes::enforce(env, remittance_id, payout_amount)?;
+
+    // Carve any pledged donation out of the payout or the platform fee
+    let (payout_donation, fee_donation) =
+        causes::settle(env, &remittance, payout_amount, remittance.fee)?;
+    let payout_amount = payout_amount - payout_donation;
+    let retained_fee = remittance.fee - fee_donation;
+    let retained_fee = retained_fee
+        - credit_commission(env, &remittance.agent, &remittance.token, retained_fee)?;
+
+    // Withhold repayment of an outstanding advance from direct payouts
+    let payout_amount = if storage::get_remittance_route(env, remittance_id).is_none() {
+        payout_amount - advances::repay(env, &remittance.agent, &remittance.token, payout_amount)?
+    } else {
+        payout_amount
+    };
+
+    let current_time = env.ledger().timestamp();
+
+    // Checks-effects-interactions: fee accounting, volume, and the Completed
+    // status are committed before any token moves, so a token contract that
+    // calls back into this contract finds the remittance already settled.
+
+    // Update accumulated fees with overflow protection and automatic flush,
+    // or defer to the batch total so storage is touched once per batch
+    // Fees in other tokens are accounted per token as they come in.
+    match batch {
+        Some(batch) if remittance.token == get_usdc_token(env)? => {
+            batch.fees = batch
+                .fees
+                .checked_add(retained_fee)
+                .ok_or(ContractError::Overflow)?;
+        }
+        _ => add_token_fee(env, &remittance.token, retained_fee)?,
+    }
+
+    // Update analytics: move volume from in-flight to completed
+    storage::sub_processing_volume(env, remittance.amount)?;
+    storage::add_completed_volume(env, remittance.amount)?;
+
+    // Update remittance status via validated transition
+    crate::transitions::transition_status(env, &mut remittance, RemittanceStatus::Completed)?;
+    set_remittance(env, remittance_id, &remittance);
+
+    // Update last settlement time for rate limiting
+    set_last_settlement_time(env, &remittance.sender, current_time);
+
+    Ok((remittance, payout_amount, protocol_fee, accepted_quote))
+}
+
+/// Interactions of a committed payout: moves its tokens to the agent and
+/// treasury and emits its completion events.
+///
+/// When `batch` is provided, transfer failures are recorded in best-effort
+/// mode, and in compact mode the per-remittance completion events are left to
+/// the caller's batch summary. Returns the payout amount.
+fn release_payout(
+    env: &Env,
+    committed: CommittedPayout,
+    batch: Option<&mut BatchPayout>,
+) -> Result<i128, ContractError> {
+    let (remittance, payout_amount, protocol_fee, accepted_quote) = committed;
+    let remittance_id = remittance.id;
+    let (compact, best_effort) = batch
+        .as_ref()
+        .map_or((false, false), |batch| (batch.compact, batch.best_effort));
+    let treasury = if protocol_fee > 0 {
+        Some(get_treasury(env)?)
+    } else {
+        None
+    };
+
+    let token_client = token::Client::new(env, &remittance.token);
+
+    // Transfer payout to agent (split across hops for routed remittances)
+    let mut failure = routing::pay_out(env, &token_client, &remittance, payout_amount, best_effort)?;
+
+    // Transfer protocol fee to treasury if needed
+    if let Some(treasury) = treasury {
+        let held = token_transfer::pay(
+            env,
+            &token_client,
+            remittance_id,
+            &treasury,
+            protocol_fee,
+            best_effort,
+        )?;
+        failure = failure.or(held);
+    }
+    // Share any expedite fees the sender paid between agent and platform
+    priority::settle(env, &token_client, &remittance, best_effort)?;
+    if let (Some(error), Some(batch)) = (failure, batch) {
+        batch.failures.push_back((remittance_id, error as u32));
+    }
+
+    observers::notify_observers(env, ObserverEvent::Completed, &remittance);
+    performance::record(env, PerformanceEvent::Completed, &remittance);
+    emit_tag_events(env, &remittance, symbol_short!("complete"));
+
+    if !compact && !emit_minimal_lifecycle(env, &remittance) {
+        // Event: Remittance completed - Fires when agent confirms fiat payout and USDC is released
+        // Used by off-chain systems to track successful settlements and update transaction status
+        emit_remittance_completed(
+            env,
+            remittance_id,
+            remittance.sender.clone(),
+            remittance.agent.clone(),
+            remittance.settlement_latency().unwrap_or(0),
+            remittance.processing_latency(),
+        );
+
+        // Event: Settlement completed - Fires with final executed settlement values
+        // Used by off-chain systems for reconciliation and audit trails of completed transactions
+        emit_settlement_completed(
+            env,
+            remittance_id,
+            remittance.sender,
+            remittance.agent,
+            remittance.token,
+            payout_amount,
+        );
+    }
+
+    if let Some(accepted) = accepted_quote {
+        emit_quote_settled(env, remittance_id, accepted.quote_id, accepted.dest_amount);
+    }
+
+    log_confirm_payout(env, remittance_id, payout_amount);
+
+    // Cleanup: remove idempotency record on terminal state (Completed)
+    if let Some(idem_key) = storage::take_remittance_idempotency_key(env, remittance_id) {
+        storage::remove_idempotency_record(env, &idem_key);
+    }
+
+    Ok(payout_amount)
+}
+
+/// Confirmed IDs and `(remittance_id, error code)` failures of a batch payout.
+type BatchOutcome = (Vec<u64>, Vec<(u64, u32)>);
+
+/// Settles a batch for `confirm_batch_payout` and its best-effort variant.
+///
+/// Returns the confirmed IDs and the `(remittance_id, error code)` failures
+/// recorded in best-effort mode.
+fn settle_batch(
+    env: &Env,
+    agent: &Address,
+    remittance_ids: &Vec<u64>,
+    compact: bool,
+    best_effort: bool,
+) -> Result<BatchOutcome, ContractError> {
+    let batch_size = remittance_ids.len();
+    if batch_size == 0 {
+        return Err(ContractError::InvalidBatchSize);
+    }
+    if batch_size > MAX_SAFE_BATCH_SIZE {
+        return Err(ContractError::BatchTooLarge);
+    }
+    if crate::storage::is_migration_in_progress(env) {
+        return Err(ContractError::MigrationInProgress);
+    }
+
+    // Validate every entry before authorizing, so the signed set is the
+    // full list of (id, amount, agent) that will actually settle.
+    let mut remittances: Vec<Remittance> = Vec::new(env);
+    let mut entries: Vec<(u64, i128, Address)> = Vec::new(env);
+    for i in 0..batch_size {
+        let id = remittance_ids.get_unchecked(i);
+        if remittance_ids.first_index_of(id) != Some(i) {
+            return Err(ContractError::DuplicateSettlement);
+        }
+        let remittance = validate_confirm_payout_request(env, id)?;
+        entries.push_back((id, remittance.amount, remittance.agent.clone()));
+        remittances.push_back(remittance);
+    }
+    agent.require_auth_for_args((entries,).into_val(env));
+
+    let mut confirmed = Vec::new(env);
+    let mut batch = BatchPayout {
+        fees: 0,
+        compact,
+        best_effort,
+        failures: Vec::new(env),
+    };
+    let mut committed: Vec<CommittedPayout> = Vec::new(env);
+    for remittance in remittances.iter() {
+        crate::storage::check_agent_or_sub_operator(
+            env,
+            agent,
+            &remittance.agent,
+            remittance.amount,
+        )?;
+        committed.push_back(commit_payout(
+            env,
+            remittance,
+            None,
+            None,
+            None,
+            None,
+            Some(&mut batch),
+        )?);
+    }
+    // The fee total is committed with the rest of the batch's state, before
+    // the first token moves
+    safe_add_accumulated_fee(env, batch.fees)?;
+
+    let mut payouts: Vec<(u64, i128)> = Vec::new(env);
+    let mut agent_totals: Map<Address, i128> = Map::new(env);
+    for entry in committed.iter() {
+        let id = entry.0.id;
+        let payee = entry.0.agent.clone();
+        let payout = release_payout(env, entry, Some(&mut batch))?;
+        if compact {
+            payouts.push_back((id, payout));
+            let total = agent_totals
+                .get(payee.clone())
+                .unwrap_or(0)
+                .checked_add(payout)
+                .ok_or(ContractError::Overflow)?;
+            agent_totals.set(payee, total);
+        }
+        confirmed.push_back(id);
+    }
+    if compact {
+        emit_batch_payout_completed(env, payouts, agent_totals);
+    }
+    emit_batch_paid(env, confirmed.len());
+    Ok((confirmed, batch.failures))
+}
+
+/// Running state for payouts settled within a single batch call.
+struct BatchPayout {
+    /// Fees collected so far, credited to storage before the first transfer
+    fees: i128,
+    /// Replace per-remittance completion events with one batch summary
+    compact: bool,
+    /// Hold payouts whose transfer fails instead of aborting the batch
+    best_effort: bool,
+    /// `(remittance_id, error code)` for each remittance with a held payout
+    failures: Vec<(u64, u32)>,
+}
+
+/// The main SwiftRemit contract for managing cross-border remittances.
+///
+/// This contract handles the complete lifecycle of remittance transactions including:
+/// - Agent registration and management
+/// - Remittance creation with automatic fee calculation
+/// - Settlement confirmation with duplicate protection
+/// - Cancellation and refund processing
+/// - Platform fee collection and withdrawal
+#[contract]
+pub struct SwiftRemitContract;
+
+// ============================================================================
+// Configuration Constants
+// ============================================================================
+//
+// These constants define validation limits and calculation parameters.
+// They are intentionally hardcoded in the contract to ensure consistent
+// on-chain behavior across all deployments.
+//
+// MAX_FEE_BPS: Maximum allowed fee in basis points (100% = 10000 bps)
+// - This limit prevents accidentally setting fees above 100%
+// - Used in initialize() and update_fee() for validation
+// - Value: 10000 (represents 100%)
+//
+// FEE_DIVISOR: Divisor for converting basis points to actual fee amount
+// - Formula: fee_amount = amount * fee_bps / FEE_DIVISOR
+// - Used in create_remittance() for fee calculation
+// - Value: 10000 (basis points scale)
+//
+// Configurable Values at Deployment:
+// - initial fee_bps: Set during initialize(), can be any value 0-10000
+//   This value can be configured via the INITIAL_FEE_BPS environment variable
+//   in deployment scripts (deploy.sh, deploy.ps1)
+//
+// Runtime Configurable Values:
+// - fee_bps: Can be updated by admin via update_fee()
+// ============================================================================
+
+#[contractimpl]
+impl SwiftRemitContract {
+    fn set_blacklist_status(
+        env: &Env,
+        user: Address,
+        blacklisted: bool,
+    ) -> Result<(), ContractError> {
+        let caller = get_admin(env)?;
+        require_admin(env, &caller)?;
+
+        set_user_blacklisted(env, &user, blacklisted);
+
+        if blacklisted {
+            emit_user_blacklisted(env, user, caller);
+        } else {
+            emit_user_removed_from_blacklist(env, user, caller);
+        }
+
+        Ok(())
+    }
+
+    /// Initializes the contract with admin, token, and fee configuration.
+    ///
+    /// This function can only be called once. It sets up the contract's core parameters
+    /// and initializes all counters and accumulators to zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `admin` - Address that will have administrative privileges
+    /// * `usdc_token` - Address of the USDC token contract used for transactions
+    /// * `fee_bps` - Platform fee in basis points (1 bps = 0.01%, max 10000 = 100%)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Contract successfully initialized
+    /// * `Err(ContractError::AlreadyInitialized)` - Contract was already initialized
+    /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds maximum allowed (10000 bps)
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// contract.initialize(env, admin_addr, usdc_addr, 250); // 2.5% fee
+    /// ```
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        usdc_token: Address,
+        fee_bps: u32,
+        rate_limit_cooldown: u64,
+        protocol_fee_bps: u32,
+        treasury: Address,
+    ) -> Result<(), ContractError> {
+        // Centralized validation before business logic
+        validate_initialize_request(&env, &admin, &usdc_token, fee_bps)?;
+
+        // Set legacy admin for backward compatibility
+        set_admin(&env, &admin);
+
+        // Initialize new admin role system
+        set_admin_role(&env, &admin, true);
+        set_admin_count(&env, 1);
+
+        // Assign Admin role to initial admin
+        assign_role(&env, &admin, &Role::Admin);
+
+        set_usdc_token(&env, &usdc_token);
+        set_token_whitelisted(&env, &usdc_token, true);
+        set_platform_fee_bps(&env, fee_bps);
+        set_token_fee_bps(&env, &usdc_token, fee_bps)?;
+        set_fee_strategy(&env, &FeeStrategy::Percentage(fee_bps));
+        set_remittance_counter(&env, 0);
+        set_accumulated_fees(&env, 0);
+        set_rate_limit_cooldown(&env, rate_limit_cooldown);
+        set_escrow_counter(&env, 0);
+        set_escrow_ttl(&env, 0);
+
+        // Initialize protocol fee and treasury
+        set_protocol_fee_bps(&env, protocol_fee_bps)?;
+        set_treasury(&env, &treasury);
+
+        // Initialize rate limiting with default configuration
+        init_rate_limit(&env);
+
+        // Fresh deployments start at the current storage schema
+        migration::set_schema_version(&env, migration::CURRENT_SCHEMA_VERSION);
+
+        log_initialize(&env, &admin, &usdc_token, fee_bps);
+
+        Ok(())
+    }
+
+    /// Registers a new agent authorized to receive remittance payouts.
     ///
     /// Only the contract admin can register agents. Registered agents can confirm
     /// payouts for remittances assigned to them.
@@ -322,6 +1394,7 @@ impl SwiftRemitContract {
     pub fn register_agent(env: Env, agent: Address, kyc_hash: Option<soroban_sdk::BytesN<32>>) -> Result<(), ContractError> {
         let caller = get_admin(&env)?;
         require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "register_agent", (agent.clone(), kyc_hash.clone()));
 
         set_agent_registered(&env, &agent, true);
         assign_role(&env, &agent, &Role::Settler);
@@ -358,6 +1431,7 @@ impl SwiftRemitContract {
     pub fn remove_agent(env: Env, agent: Address) -> Result<(), ContractError> {
         let caller = get_admin(&env)?;
         require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "remove_agent", (agent.clone(),));
 
         set_agent_registered(&env, &agent, false);
         remove_role(&env, &agent, &Role::Settler);
@@ -369,35 +1443,326 @@ impl SwiftRemitContract {
         Ok(())
     }
 
-    /// Updates the platform fee rate.
+    /// Registers several agents in one transaction, as when onboarding a corridor.
     ///
-    /// Only the contract admin can update the fee. The new fee applies to all
-    /// remittances created after the update.
+    /// Each entry is validated on its own; an invalid entry is skipped and
+    /// reported without affecting the others. One aggregated event lists the
+    /// agents registered. Returns `(agent, error code)` for each skipped entry.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `env` - The contract execution environment
-    /// * `fee_bps` - New platform fee in basis points (1 bps = 0.01%, max 10000 = 100%)
+    /// * `ContractError::InvalidBatchSize` - Batch is empty or exceeds `MAX_BATCH_SIZE`
     ///
-    /// # Returns
+    /// Per-entry failures:
     ///
-    /// * `Ok(())` - Fee successfully updated
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
-    /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds maximum allowed (10000 bps)
+    /// * `ContractError::InvalidAddress` - The entry is this contract
+    /// * `ContractError::AgentAlreadyRegistered` - The agent is already registered,
+    ///   including by an earlier entry of the batch
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the contract admin.
+    /// Requires authentication from an admin or an `Operator`.
+    pub fn register_agents(
+        env: Env,
+        caller: Address,
+        agents: Vec<Address>,
+    ) -> Result<Vec<(Address, u32)>, ContractError> {
+        storage::require_admin_or_role(&env, &caller, &Role::Operator)?;
+        admin_log::record(&env, &caller, "register_agents", (agents.clone(),));
+        if agents.is_empty() || agents.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidBatchSize);
+        }
+
+        let mut registered = Vec::new(&env);
+        let mut failures = Vec::new(&env);
+        for agent in agents.iter() {
+            let error = if agent == env.current_contract_address() {
+                Some(ContractError::InvalidAddress)
+            } else if is_agent_registered(&env, &agent) {
+                Some(ContractError::AgentAlreadyRegistered)
+            } else {
+                None
+            };
+            if let Some(error) = error {
+                failures.push_back((agent, error as u32));
+                continue;
+            }
+            set_agent_registered(&env, &agent, true);
+            assign_role(&env, &agent, &Role::Settler);
+            registered.push_back(agent);
+        }
+
+        emit_agents_registered(&env, registered, failures.len(), caller);
+        Ok(failures)
+    }
+
+    /// Removes several agents in one transaction.
+    ///
+    /// Like `register_agents`, each entry is validated on its own and skipped
+    /// entries are returned as `(agent, error code)`. Existing remittances
+    /// assigned to removed agents remain valid.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidBatchSize` - Batch is empty or exceeds `MAX_BATCH_SIZE`
+    ///
+    /// Per-entry failures:
+    ///
+    /// * `ContractError::AgentNotRegistered` - The agent is not registered,
+    ///   including when removed by an earlier entry of the batch
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin or an `Operator`.
+    pub fn remove_agents(
+        env: Env,
+        caller: Address,
+        agents: Vec<Address>,
+    ) -> Result<Vec<(Address, u32)>, ContractError> {
+        storage::require_admin_or_role(&env, &caller, &Role::Operator)?;
+        admin_log::record(&env, &caller, "remove_agents", (agents.clone(),));
+        if agents.is_empty() || agents.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidBatchSize);
+        }
+
+        let mut removed = Vec::new(&env);
+        let mut failures = Vec::new(&env);
+        for agent in agents.iter() {
+            if !is_agent_registered(&env, &agent) {
+                failures.push_back((agent, ContractError::AgentNotRegistered as u32));
+                continue;
+            }
+            set_agent_registered(&env, &agent, false);
+            remove_role(&env, &agent, &Role::Settler);
+            removed.push_back(agent);
+        }
+
+        emit_agents_removed(&env, removed, failures.len(), caller);
+        Ok(failures)
+    }
+
+    /// Authorizes a sub-operator to confirm payouts on the agent's behalf.
+    ///
+    /// Calling again for an existing sub-operator of the same agent updates its
+    /// daily limit without resetting the current usage window.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::AgentNotRegistered` - `agent` is not a registered agent
+    /// * `ContractError::InvalidAmount` - `daily_limit` is not positive
+    /// * `ContractError::Unauthorized` - `operator` already acts for another agent
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn add_sub_operator(
+        env: Env,
+        agent: Address,
+        operator: Address,
+        daily_limit: i128,
+    ) -> Result<(), ContractError> {
+        crate::storage::require_agent_authorized(&env, &agent)?;
+        validate_amount(daily_limit)?;
+        if operator == agent {
+            return Err(ContractError::InvalidAddress);
+        }
+
+        let record = match storage::get_sub_operator(&env, &operator) {
+            Some(existing) if existing.agent != agent => return Err(ContractError::Unauthorized),
+            Some(existing) => SubOperator { daily_limit, ..existing },
+            None => SubOperator {
+                agent: agent.clone(),
+                daily_limit,
+                window_start: env.ledger().timestamp(),
+                used_in_window: 0,
+            },
+        };
+        storage::set_sub_operator(&env, &operator, &record);
+        emit_sub_operator_added(&env, agent, operator, daily_limit);
+
+        Ok(())
+    }
+
+    /// Revokes a sub-operator previously authorized by the agent.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn revoke_sub_operator(env: Env, agent: Address, operator: Address) -> Result<(), ContractError> {
+        agent.require_auth();
+        storage::remove_sub_operator(&env, &agent, &operator)?;
+        emit_sub_operator_revoked(&env, agent, operator);
+
+        Ok(())
+    }
+
+    /// Returns the sub-operators authorized by an agent.
+    pub fn get_sub_operators(env: Env, agent: Address) -> Vec<Address> {
+        storage::get_agent_sub_operators(&env, &agent)
+    }
+
+    /// Returns the sub-operator record for an address, if it is one.
+    pub fn get_sub_operator(env: Env, operator: Address) -> Option<SubOperator> {
+        storage::get_sub_operator(&env, &operator)
+    }
+
+    /// Designates the platform operator that opted-in agents allow to settle
+    /// on their behalf (admin only).
+    ///
+    /// Agents' opt-ins name the operator explicitly, so rotating the operator
+    /// does not carry existing authorizations over to the new address.
+    pub fn set_settlement_operator(env: Env, operator: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_settlement_operator", (operator.clone(),));
+        storage::set_settlement_operator(&env, &operator);
+        emit_settlement_operator_set(&env, operator, caller);
+        Ok(())
+    }
+
+    /// Returns the designated platform settlement operator, if any.
+    pub fn get_settlement_operator(env: Env) -> Option<Address> {
+        storage::get_settlement_operator(&env)
+    }
+
+    /// Opts the agent into managed auto-settlement by the platform operator.
+    ///
+    /// The operator may then call `confirm_payout` for the agent's remittances
+    /// up to `max_per_remittance` each and `daily_limit` per rolling 24 hours.
+    /// Calling again updates the limits without resetting the usage window.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::SettlementOperatorNotSet` - No operator has been designated
+    /// * `ContractError::InvalidAmount` - A limit is not positive
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn enable_auto_settlement(
+        env: Env,
+        agent: Address,
+        max_per_remittance: i128,
+        daily_limit: i128,
+    ) -> Result<(), ContractError> {
+        crate::storage::require_agent_authorized(&env, &agent)?;
+        validate_amount(max_per_remittance)?;
+        validate_amount(daily_limit)?;
+        let operator = storage::get_settlement_operator(&env).ok_or(ContractError::SettlementOperatorNotSet)?;
+
+        let config = match storage::get_auto_settlement(&env, &agent) {
+            Some(existing) if existing.operator == operator => AutoSettlement {
+                max_per_remittance,
+                daily_limit,
+                ..existing
+            },
+            _ => AutoSettlement {
+                operator: operator.clone(),
+                max_per_remittance,
+                daily_limit,
+                window_start: env.ledger().timestamp(),
+                used_in_window: 0,
+            },
+        };
+        storage::set_auto_settlement(&env, &agent, &config);
+        emit_auto_settlement_enabled(&env, agent, operator, max_per_remittance, daily_limit);
+        Ok(())
+    }
+
+    /// Revokes the agent's auto-settlement opt-in.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn disable_auto_settlement(env: Env, agent: Address) -> Result<(), ContractError> {
+        agent.require_auth();
+        if storage::get_auto_settlement(&env, &agent).is_none() {
+            return Err(ContractError::AutoSettlementNotEnabled);
+        }
+        storage::remove_auto_settlement(&env, &agent);
+        emit_auto_settlement_disabled(&env, agent);
+        Ok(())
+    }
+
+    /// Returns an agent's auto-settlement opt-in, if enabled.
+    pub fn get_auto_settlement(env: Env, agent: Address) -> Option<AutoSettlement> {
+        storage::get_auto_settlement(&env, &agent)
+    }
+
+    /// Registers an observer contract for lifecycle notifications (admin only).
+    ///
+    /// Observers are invoked via their `on_remittance(RemittanceNotification)`
+    /// entry point when a remittance is created, completed, or disputed.
+    /// A failing observer never blocks the triggering operation.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::ObserverAlreadyRegistered` - Observer is already registered
+    /// * `ContractError::TooManyObservers` - Registry already holds `MAX_OBSERVERS` entries
+    pub fn register_observer(env: Env, observer: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "register_observer", (observer.clone(),));
+
+        observers::add_observer(&env, &observer)?;
+        emit_observer_added(&env, observer, caller);
+
+        Ok(())
+    }
+
+    /// Removes a previously registered observer contract (admin only).
+    pub fn remove_observer(env: Env, observer: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "remove_observer", (observer.clone(),));
+
+        observers::remove_observer(&env, &observer)?;
+        emit_observer_removed(&env, observer, caller);
+
+        Ok(())
+    }
+
+    /// Returns the registered observer contracts.
+    pub fn get_observers(env: Env) -> Vec<Address> {
+        storage::get_observers(&env)
+    }
+
+    /// Updates the platform fee rate.
+    ///
+    /// Only the contract admin can update the fee. The new fee applies to all
+    /// remittances created after the update. Guardrails cap the fee at
+    /// `MAX_PLATFORM_FEE_BPS` and limit how far it can move within a 24-hour
+    /// window to `MAX_FEE_CHANGE_BPS`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `fee_bps` - New platform fee in basis points (1 bps = 0.01%, max 3000 = 30%)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fee successfully updated
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::InvalidFeeBps)` - Fee exceeds maximum allowed (10000 bps)
+    /// * `Err(ContractError::FeeAboveCeiling)` - Fee exceeds `MAX_PLATFORM_FEE_BPS`
+    /// * `Err(ContractError::FeeChangeTooLarge)` - Fee moves more than `MAX_FEE_CHANGE_BPS` within the window
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
     pub fn update_fee(env: Env, fee_bps: u32) -> Result<(), ContractError> {
         // Centralized validation
         validate_update_fee_request(fee_bps)?;
 
         let caller = get_admin(&env)?;
         require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "update_fee", (fee_bps,));
+        fee_service::enforce_fee_change_guardrails(&env, fee_bps)?;
 
+        let old_fee_bps = get_platform_fee_bps(&env)?;
         set_platform_fee_bps(&env, fee_bps);
         set_fee_strategy(&env, &FeeStrategy::Percentage(fee_bps));
-        emit_fee_updated(&env, fee_bps);
+        emit_fee_updated(&env, old_fee_bps, fee_bps);
 
         log_update_fee(&env, fee_bps);
 
@@ -416,7 +1781,7 @@ impl SwiftRemitContract {
     /// * `sender` - Address initiating the remittance
     /// * `agent` - Address of the registered agent who will receive the payout
     /// * `amount` - Amount to remit in USDC (must be positive)
-    /// * `expiry` - Optional expiry timestamp (seconds since epoch) after which settlement fails
+    /// * `expiry` - Optional deadline (timestamp or ledger sequence) after which settlement fails
     ///
     /// # Returns
     ///
@@ -434,7 +1799,7 @@ impl SwiftRemitContract {
         sender: Address,
         agent: Address,
         amount: i128,
-        expiry: Option<u64>,
+        expiry: Option<Expiry>,
         token: Option<Address>,
         idempotency_key: Option<String>,
         settlement_config: Option<SettlementConfig>,
@@ -460,6 +1825,8 @@ impl SwiftRemitContract {
         if !is_token_whitelisted(&env, &token_address) {
             return Err(ContractError::TokenNotWhitelisted);
         }
+        restrictions::check(&env, &sender, &token_address, None)?;
+        restrictions::check(&env, &agent, &token_address, None)?;
 
         sender.require_auth();
 
@@ -467,16 +1834,6 @@ impl SwiftRemitContract {
         let default_country = String::from_str(&env, DEFAULT_DAILY_LIMIT_COUNTRY);
         enforce_daily_send_limit(&env, &sender, &default_currency, &default_country, amount)?;
 
-        // #839: Check and increment corridor volume against the admin-configured cap.
-        // Both from/to default to "GLOBAL" so the cap covers all traffic when no
-        // corridor-specific routing is provided.
-        storage::check_and_increment_corridor_volume(
-            &env,
-            &default_currency,
-            &default_country,
-            amount,
-        )?;
-
         // Validate settlement config
         if let Some(ref config) = settlement_config {
             if config.require_proof && config.oracle_address.is_none() {
@@ -499,15 +1856,18 @@ impl SwiftRemitContract {
         }
 
         // Use centralized fee service with sender-specific rolling volume discounts.
-        let fee = fee_service::calculate_platform_fee_for_sender(
-            &env,
-            &sender,
-            amount,
-            Some(&token_address),
-        )?;
+        let charged = creation_fee(&env, &sender, amount, expiry, || {
+            fee_service::calculate_platform_fee_for_sender(
+                &env,
+                &sender,
+                amount,
+                Some(&token_address),
+            )
+        })?;
+        let fee = charged.fee;
 
         let token_client = token::Client::new(&env, &token_address);
-        token_client.transfer(&sender, &env.current_contract_address(), &amount);
+        token_transfer::transfer(&token_client, &sender, &env.current_contract_address(), amount)?;
 
         let counter = get_remittance_counter(&env)?;
         let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
@@ -526,13 +1886,17 @@ impl SwiftRemitContract {
             amount,
             fee,
             status: RemittanceStatus::Pending,
-            expiry,
+            expiry: expiry.into(),
             settlement_config: settlement_config.clone().into(),
             token: token_address.clone(),
             created_at,
             failed_at: None,
             dispute_evidence: None.into(),
             expires_at,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         };
 
         let payout_commitment = compute_payout_commitment(&env, &remittance);
@@ -541,6 +1905,7 @@ impl SwiftRemitContract {
         set_payout_commitment(&env, remittance_id, &payout_commitment);
         set_remittance_counter(&env, remittance_id);
         storage::record_sender_volume(&env, &sender, amount, env.ledger().timestamp())?;
+        agent_tiers::track_pending(&env, &remittance)?;
 
         // Store recipient hash if provided (Task 7.1)
         if let Some(ref hash) = recipient_hash {
@@ -553,6 +1918,20 @@ impl SwiftRemitContract {
         // Index this remittance under the sender and agent for paginated queries
         storage::append_sender_remittance(&env, &sender, remittance_id);
         storage::append_agent_remittance(&env, &agent, remittance_id);
+        record_expiry_fee(&env, remittance_id, charged.lock_seconds, charged.expiry_fee);
+        emit_remittance_created(
+            &env,
+            remittance_id,
+            sender.clone(),
+            agent.clone(),
+            amount,
+            fee,
+            0,
+            fee,
+            0,
+            amount - fee,
+            charged.exempt,
+        );
         // Set initial transfer state
         set_transfer_state(&env, remittance_id, RemittanceStatus::Pending)?;
 
@@ -577,2362 +1956,6372 @@ impl SwiftRemitContract {
             storage::set_remittance_idempotency_key(&env, remittance_id, &key);
         }
 
+        observers::notify_observers(&env, ObserverEvent::Created, &remittance);
+        performance::record(&env, PerformanceEvent::Created, &remittance);
+
         Ok(remittance_id)
     }
 
-    /// Creates a remittance using corridor-specific fees when available.
+    /// Previews `create_remittance` without moving tokens or writing state, so
+    /// wallets can show an exact confirmation screen.
     ///
-    /// If a corridor is configured for the given country pair, its fee strategy
-    /// is used instead of the global strategy. Falls back to global if not found.
-    pub fn create_remittance_with_corridor(
+    /// Runs the same checks as `create_remittance` (registration, blacklist,
+    /// maintenance, agent reputation and tier, token whitelist, daily send
+    /// limit) and returns the remittance it would store, with the ID it would
+    /// be given, the agent's payout, and the expiry fee, volume discount, and
+    /// daily limit headroom that applied. The sender's authorization is not
+    /// checked.
+    ///
+    /// # Errors
+    ///
+    /// Any validation error returned by `create_remittance`
+    pub fn dry_run_create(
         env: Env,
         sender: Address,
         agent: Address,
         amount: i128,
-        expiry: Option<u64>,
-        from_country: Option<String>,
-        to_country: Option<String>,
-    ) -> Result<u64, ContractError> {
+        expiry: Option<Expiry>,
+        token: Option<Address>,
+    ) -> Result<RemittancePreview, ContractError> {
+        if crate::storage::is_migration_in_progress(&env) {
+            return Err(ContractError::MigrationInProgress);
+        }
         validate_create_remittance_request(&env, &sender, &agent, amount)?;
 
-        sender.require_auth();
-
-        let limit_currency = String::from_str(&env, DEFAULT_DAILY_LIMIT_CURRENCY);
-        let limit_country = to_country
-            .clone()
-            .unwrap_or_else(|| String::from_str(&env, DEFAULT_DAILY_LIMIT_COUNTRY));
-        enforce_daily_send_limit(&env, &sender, &limit_currency, &limit_country, amount)?;
+        let min_rep = storage::get_min_agent_reputation(&env);
+        if min_rep > 0
+            && storage::compute_agent_reputation(&storage::get_agent_stats(&env, &agent)) < min_rep
+        {
+            return Err(ContractError::BelowMinReputation);
+        }
 
-        let corridor = match (&from_country, &to_country) {
-            (Some(from), Some(to)) => storage::get_fee_corridor(&env, from, to),
-            _ => None,
+        let token_address = match token {
+            Some(token) => token,
+            None => get_usdc_token(&env)?,
         };
-        let fee = fee_service::calculate_fees_with_breakdown_for_sender(
+        if !is_token_whitelisted(&env, &token_address) {
+            return Err(ContractError::TokenNotWhitelisted);
+        }
+        restrictions::check(&env, &sender, &token_address, None)?;
+        restrictions::check(&env, &agent, &token_address, None)?;
+
+        let (_, daily_limit_remaining) = check_daily_send_limit(
             &env,
             &sender,
+            &String::from_str(&env, DEFAULT_DAILY_LIMIT_CURRENCY),
+            &String::from_str(&env, DEFAULT_DAILY_LIMIT_COUNTRY),
             amount,
-            Some(&get_usdc_token(&env)?),
-            corridor.as_ref(),
-        )?
-        .platform_fee;
-
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(&sender, &env.current_contract_address(), &amount);
+        )?;
 
-        let counter = get_remittance_counter(&env)?;
-        let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+        let mut platform_fee = 0;
+        let charged = creation_fee(&env, &sender, amount, expiry, || {
+            platform_fee = fee_service::calculate_platform_fee_for_sender(
+                &env,
+                &sender,
+                amount,
+                Some(&token_address),
+            )?;
+            Ok(platform_fee)
+        })?;
+        let undiscounted_fee = if charged.exempt {
+            0
+        } else {
+            fee_service::calculate_platform_fee_for_volume(&env, amount, Some(&token_address), 0)?
+        };
+        let fee = charged.fee;
+        agent_tiers::check_assignment(&env, &agent, amount)?;
 
-        let corridor_created_at = env.ledger().timestamp();
-        let corridor_expiry_window = storage::get_remittance_expiry_window(&env);
-        let corridor_expires_at = if corridor_expiry_window > 0 {
-            Some(corridor_created_at.saturating_add(corridor_expiry_window))
+        let remittance_id = get_remittance_counter(&env)?
+            .checked_add(1)
+            .ok_or(ContractError::Overflow)?;
+        let created_at = env.ledger().timestamp();
+        let expiry_window = storage::get_remittance_expiry_window(&env);
+        let expires_at = if expiry_window > 0 {
+            Some(created_at.saturating_add(expiry_window))
         } else {
             None
         };
-        let remittance = Remittance {
-            id: remittance_id,
-            sender: sender.clone(),
-            agent: agent.clone(),
-            amount,
-            fee,
-            status: RemittanceStatus::Pending,
-            expiry,
-            settlement_config: crate::MaybeSettlementConfig::None,
-            token: usdc_token.clone(),
-            created_at: corridor_created_at,
-            failed_at: None,
-            dispute_evidence: None.into(),
-            expires_at: corridor_expires_at,
-        };
 
-        let payout_commitment = compute_payout_commitment(&env, &remittance);
+        Ok(RemittancePreview {
+            remittance: Remittance {
+                id: remittance_id,
+                sender,
+                agent,
+                amount,
+                fee,
+                status: RemittanceStatus::Pending,
+                expiry: expiry.into(),
+                settlement_config: MaybeSettlementConfig::None,
+                token: token_address,
+                created_at,
+                failed_at: None,
+                dispute_evidence: None.into(),
+                expires_at,
+                accepted_at: None,
+                completed_at: None,
+                memo: None,
+                corridor: crate::MaybeCorridor::None,
+            },
+            payout_amount: amount - fee,
+            expiry_fee: charged.expiry_fee,
+            volume_discount: undiscounted_fee.saturating_sub(platform_fee),
+            daily_limit_remaining,
+        })
+    }
+
+    /// Creates a remittance carrying sender-defined tags (e.g. `payroll`, `rent`).
+    ///
+    /// Behaves exactly like `create_remittance` without idempotency, settlement
+    /// config, or recipient hash. Up to `MAX_REMITTANCE_TAGS` distinct tags are
+    /// stored with the remittance, and a `remit/tagged/<tag>/created` event is
+    /// emitted per tag (and `.../complete` on payout) so senders can segment
+    /// their flows with topic filters.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidTags` - Too many tags or a duplicate tag
+    /// * Any error returned by `create_remittance`
+    pub fn create_tagged_remittance(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        expiry: Option<Expiry>,
+        token: Option<Address>,
+        tags: Vec<Symbol>,
+    ) -> Result<u64, ContractError> {
+        if tags.len() > MAX_REMITTANCE_TAGS {
+            return Err(ContractError::InvalidTags);
+        }
+        for (i, tag) in tags.iter().enumerate() {
+            if tags.first_index_of(&tag) != Some(i as u32) {
+                return Err(ContractError::InvalidTags);
+            }
+        }
 
-        set_remittance(&env, remittance_id, &remittance);
-        set_payout_commitment(&env, remittance_id, &payout_commitment);
-        set_remittance_counter(&env, remittance_id);
-        set_transfer_state(&env, remittance_id, RemittanceStatus::Pending)?;
-        storage::record_sender_volume(&env, &sender, amount, env.ledger().timestamp())?;
-        storage::append_sender_remittance(&env, &sender, remittance_id);
+        let remittance_id = Self::create_remittance(
+            env.clone(),
+            sender,
+            agent,
+            amount,
+            expiry,
+            token,
+            None,
+            None,
+            None,
+        )?;
+        storage::set_remittance_tags(&env, remittance_id, &tags);
 
+        let remittance = get_remittance(&env, remittance_id)?;
+        emit_tag_events(&env, &remittance, symbol_short!("created"));
         Ok(remittance_id)
     }
 
-    /// Creates multiple remittances in a single atomic batch operation.
-    ///
-    /// This function allows high-volume senders to create multiple remittances
-    /// at once, reducing transaction costs by batching the token transfer.
-    /// All entries are validated before any state changes occur.
+    /// Creates a remittance to an agent given by address or by handle.
     ///
-    /// # Arguments
-    ///
-    /// * `env` - The contract execution environment
-    /// * `sender` - Address of the sender initiating the batch
-    /// * `entries` - Vector of BatchCreateEntry structs containing remittance details
+    /// A handle is resolved in the same call, so the remittance goes to the
+    /// handle's owner at creation time. Otherwise behaves like
+    /// `create_remittance` without idempotency, settlement config, or
+    /// recipient hash.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// * `Ok(Vec<u64>)` - Vector of created remittance IDs
-    /// * `Err(ContractError)` - If any entry fails validation or batch size exceeds limit
+    /// * `ContractError::HandleNotFound` - Handle is unregistered or lapsed
+    /// * `ContractError::HandleFrozen` - Handle is frozen
+    /// * Any error returned by `create_remittance`
+    pub fn create_remittance_to_payee(
+        env: Env,
+        sender: Address,
+        agent: Payee,
+        amount: i128,
+        expiry: Option<Expiry>,
+        token: Option<Address>,
+    ) -> Result<u64, ContractError> {
+        let agent = handles::resolve_payee(&env, agent)?;
+        Self::create_remittance(env, sender, agent, amount, expiry, token, None, None, None)
+    }
+
+    /// Posts a firm quote for a corridor and returns its ID.
+    ///
+    /// `rate` converts source units into destination units, scaled by
+    /// `QUOTE_RATE_SCALE`, after `fee` is deducted from the payout. The quote
+    /// can be accepted once, until `expires_at`.
     ///
     /// # Errors
     ///
-    /// * `ContractError::InvalidBatchSize` - Batch is empty or exceeds MAX_BATCH_SIZE (100)
-    /// * `ContractError::InvalidAmount` - Any entry has zero or negative amount
-    /// * `ContractError::AgentNotRegistered` - Any agent is not registered
-    /// * `ContractError::UserBlacklisted` - Sender is blacklisted
-    /// * `ContractError::DailySendLimitExceeded` - Total amount exceeds daily limit
-    /// * `ContractError::Overflow` - Arithmetic overflow in amount calculation
+    /// * `ContractError::AgentNotRegistered` - `agent` is not a registered agent
+    /// * `ContractError::InvalidQuote` - Non-positive rate, negative fee, or an
+    ///   expiry not within `MAX_QUOTE_TTL_SECONDS` from now
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the sender address.
-    pub fn batch_create_remittances(
+    /// Requires authentication from the agent.
+    pub fn post_quote(
         env: Env,
-        sender: Address,
-        entries: Vec<BatchCreateEntry>,
-    ) -> Result<Vec<u64>, ContractError> {
-        if crate::storage::is_migration_in_progress(&env) {
-            return Err(ContractError::MigrationInProgress);
-        }
-
-        // Validate batch size
-        let batch_size = entries.len();
-        if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
-            return Err(ContractError::InvalidBatchSize);
-        }
-
-        sender.require_auth();
-
-        // Validate all entries and accumulate total before any state changes
-        let mut total_amount: i128 = 0;
-        for i in 0..batch_size {
-            let entry = entries.get_unchecked(i);
-            validate_create_remittance_request(&env, &sender, &entry.agent, entry.amount)?;
-            total_amount = total_amount
-                .checked_add(entry.amount)
-                .ok_or(ContractError::Overflow)?;
-        }
-
-        // Pre-validate the entire batch total against the daily limit atomically (#611)
-        // This ensures no partial batch can sneak past the limit one entry at a time
-        let default_currency = String::from_str(&env, DEFAULT_DAILY_LIMIT_CURRENCY);
-        let default_country = String::from_str(&env, DEFAULT_DAILY_LIMIT_COUNTRY);
-        enforce_daily_send_limit(&env, &sender, &default_currency, &default_country, total_amount)?;
-
-        // Transfer total amount in a single token transfer
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(&sender, &env.current_contract_address(), &total_amount);
-
-        // Create all remittances
-        let mut remittance_ids = Vec::new(&env);
-        let mut counter = get_remittance_counter(&env)?;
-        // #840: Cache timestamp and prior volume once before the loop to avoid
-        // redundant ledger reads on every iteration.
-        let now = env.ledger().timestamp();
-        let prior_volume = storage::get_sender_rolling_volume(&env, &sender, now);
-        let mut cumulative_volume = prior_volume;
+        agent: Address,
+        corridor: RemittanceCorridor,
+        rate: i128,
+        fee: i128,
+        expires_at: u64,
+    ) -> Result<u64, ContractError> {
+        crate::storage::require_agent_authorized(&env, &agent)?;
+        let quote = quotes::post(&env, agent, corridor, rate, fee, expires_at)?;
+        emit_quote_posted(
+            &env,
+            quote.id,
+            quote.agent,
+            quote.corridor,
+            quote.rate,
+            quote.fee,
+            quote.expires_at,
+        );
+        Ok(quote.id)
+    }
 
-        for i in 0..batch_size {
-            let entry = entries.get_unchecked(i);
-            counter = counter.checked_add(1).ok_or(ContractError::Overflow)?;
-            let remittance_id = counter;
+    /// Returns a posted quote.
+    pub fn get_quote(env: Env, quote_id: u64) -> Option<Quote> {
+        storage::get_quote(&env, quote_id)
+    }
 
-            // Calculate fee for this entry using the sender's rolling volume and batch cumulative amount.
-            let total_volume = cumulative_volume
-                .checked_add(entry.amount)
-                .ok_or(ContractError::Overflow)?;
-            let fee = fee_service::calculate_platform_fee_for_volume(
-                &env,
-                entry.amount,
-                Some(&usdc_token),
-                total_volume,
-            )?;
-            cumulative_volume = total_volume;
+    /// Creates a remittance for the agent that posted `quote_id`, under the
+    /// quoted terms.
+    ///
+    /// The remittance is created as by `create_remittance` in the default
+    /// token. Settlement then only goes through if it pays the agent the same
+    /// amount as computed here.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::QuoteNotFound` - Unknown quote ID
+    /// * `ContractError::QuoteAlreadyAccepted` - The quote was already used
+    /// * `ContractError::QuoteExpired` - The quote's deadline has passed
+    /// * `ContractError::InvalidAmount` - The payout does not cover the quoted fee
+    /// * Any error returned by `create_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender.
+    pub fn create_remittance_with_quote(
+        env: Env,
+        sender: Address,
+        quote_id: u64,
+        amount: i128,
+        expiry: Option<Expiry>,
+    ) -> Result<u64, ContractError> {
+        let quote = quotes::open_quote(&env, quote_id)?;
+        let remittance_id = Self::create_remittance(
+            env.clone(),
+            sender,
+            quote.agent.clone(),
+            amount,
+            expiry,
+            None,
+            None,
+            None,
+            None,
+        )?;
 
-            let batch_created_at = env.ledger().timestamp();
-            let batch_expiry_window = storage::get_remittance_expiry_window(&env);
-            let batch_expires_at = if batch_expiry_window > 0 {
-                Some(batch_created_at.saturating_add(batch_expiry_window))
-            } else {
-                None
-            };
-            let remittance = Remittance {
-                id: remittance_id,
-                sender: sender.clone(),
-                agent: entry.agent.clone(),
-                amount: entry.amount,
-                fee,
-                status: RemittanceStatus::Pending,
-                expiry: entry.expiry,
-                settlement_config: crate::MaybeSettlementConfig::None,
-                token: usdc_token.clone(),
-                created_at: batch_created_at,
-                failed_at: None,
-                dispute_evidence: None.into(),
-                expires_at: batch_expires_at,
-            };
+        let remittance = get_remittance(&env, remittance_id)?;
+        let protocol_fee = fee_service::calculate_fees_with_breakdown(&env, amount, None, None)?
+            .protocol_fee;
+        let payout_amount = amount
+            .checked_sub(remittance.fee)
+            .and_then(|v| v.checked_sub(protocol_fee))
+            .ok_or(ContractError::Overflow)?;
+        let accepted = quotes::accept(&env, quote, &remittance, payout_amount)?;
+        emit_quote_accepted(
+            &env,
+            quote_id,
+            remittance_id,
+            accepted.payout_amount,
+            accepted.dest_amount,
+        );
+        Ok(remittance_id)
+    }
 
-            let payout_commitment = compute_payout_commitment(&env, &remittance);
+    /// Returns the quote terms a remittance was created under, if any.
+    pub fn get_accepted_quote(env: Env, remittance_id: u64) -> Option<AcceptedQuote> {
+        storage::get_accepted_quote(&env, remittance_id)
+    }
 
-            set_remittance(&env, remittance_id, &remittance);
-            set_payout_commitment(&env, remittance_id, &payout_commitment);
-            set_transfer_state(&env, remittance_id, RemittanceStatus::Pending)?;
+    /// Creates a remittance routed through intermediate agents to `agent`.
+    ///
+    /// `hops` lists the intermediate agents in travel order, each with its
+    /// share of the net payout in basis points; `agent` is the final cash-out
+    /// agent and receives the remainder. Each hop confirms its leg in order via
+    /// `confirm_route_leg` before `agent` can call `confirm_payout`.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidRoute` - Malformed route
+    /// * Any error returned by `create_remittance`
+    pub fn create_routed_remittance(
+        env: Env,
+        sender: Address,
+        hops: Vec<RouteHop>,
+        agent: Address,
+        amount: i128,
+        expiry: Option<Expiry>,
+        token: Option<Address>,
+    ) -> Result<u64, ContractError> {
+        let legs = routing::build_route(&env, &hops, &agent)?;
 
-            // Persist the sender's volume history for future discount calculations.
-            storage::record_sender_volume(&env, &sender, entry.amount, now)?;
+        let remittance_id = Self::create_remittance(
+            env.clone(),
+            sender,
+            agent,
+            amount,
+            expiry,
+            token,
+            None,
+            None,
+            None,
+        )?;
+        storage::set_remittance_route(&env, remittance_id, &legs);
 
-            // Index this remittance under the sender and agent for paginated queries
-            storage::append_sender_remittance(&env, &sender, remittance_id);
-            storage::append_agent_remittance(&env, &entry.agent, remittance_id);
+        emit_route_created(&env, remittance_id, hops.len());
+        Ok(remittance_id)
+    }
 
-            remittance_ids.push_back(remittance_id);
+    /// Confirms the next intermediate leg of a routed remittance.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::RouteLegOutOfOrder` - `agent` is not the next hop to confirm
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the hop agent.
+    pub fn confirm_route_leg(env: Env, agent: Address, remittance_id: u64) -> Result<u32, ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        if remittance.status != RemittanceStatus::Pending {
+            return Err(ContractError::InvalidStatus);
         }
+        crate::storage::require_agent_authorized(&env, &agent)?;
+        routing::confirm_leg(&env, remittance_id, &agent)
+    }
 
-        // Update counter once at the end
-        set_remittance_counter(&env, counter);
-
-        Ok(remittance_ids)
+    /// Returns the legs of a routed remittance, or `None` for a direct one.
+    pub fn get_remittance_route(env: Env, remittance_id: u64) -> Option<Vec<RouteLeg>> {
+        storage::get_remittance_route(&env, remittance_id)
     }
 
-    /// Confirms a remittance payout to the agent.
-    ///
-    /// Transfers the remittance amount (minus platform fee) to the agent and marks
-    /// the remittance as completed. Includes duplicate settlement protection and
-    /// expiry validation.
-    ///
-    /// # Arguments
+    /// Takes a pending remittance's payout in another asset.
     ///
-    /// * `env` - The contract execution environment
-    /// * `remittance_id` - ID of the remittance to confirm
+    /// At settlement the payout is converted through the path payment adapter
+    /// and `agent` receives at least `path_payout.min_receive` of
+    /// `path_payout.dest_token`, or the settlement fails. Calling again
+    /// replaces the earlier choice.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// * `Ok(())` - Payout successfully confirmed and transferred
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
-    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
-    /// * `Err(ContractError::DuplicateSettlement)` - Settlement already executed
-    /// * `Err(ContractError::SettlementExpired)` - Current time exceeds expiry timestamp
-    /// * `Err(ContractError::InvalidAddress)` - Agent address validation failed
-    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in payout calculation
+    /// * `ContractError::PathPayoutUnavailable` - No path payment adapter is set
+    /// * `ContractError::InvalidStatus` - Remittance is not Pending
+    /// * `ContractError::Unauthorized` - `agent` is not the remittance's agent
+    /// * `ContractError::InvalidRoute` - The remittance is routed through intermediaries
+    /// * `ContractError::InvalidAmount` - `min_receive` is not positive
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the agent address assigned to the remittance.
-    /// Requires Settler role.
-    pub fn confirm_payout(
+    /// Requires authentication from the agent.
+    pub fn set_path_payout(
         env: Env,
         agent: Address,
         remittance_id: u64,
-        proof: Option<soroban_sdk::BytesN<32>>,
-        recipient_details_hash: Option<BytesN<32>>,
+        path_payout: PathPayout,
     ) -> Result<(), ContractError> {
-        if crate::storage::is_migration_in_progress(&env) {
-            return Err(ContractError::MigrationInProgress);
+        if storage::get_path_payment_adapter(&env).is_none() {
+            return Err(ContractError::PathPayoutUnavailable);
         }
-        // Centralized validation before business logic (returns remittance to avoid re-read)
-        let mut remittance = validate_confirm_payout_request(&env, remittance_id)?;
-
-        // Verify the caller is the specific agent assigned to this remittance (#608)
-        if agent != remittance.agent {
+        let remittance = get_remittance(&env, remittance_id)?;
+        if remittance.status != RemittanceStatus::Pending {
+            return Err(ContractError::InvalidStatus);
+        }
+        if remittance.agent != agent {
             return Err(ContractError::Unauthorized);
         }
-
-        // #831: Pre-confirm lifecycle hook — validates sender eligibility and KYC
-        // before any state mutation occurs.
-        transaction_controller::TransactionController::pre_confirm_validation(&env, &remittance)?;
-
-        // Validate proof against settlement config if required
-        if let crate::MaybeSettlementConfig::Some(ref config) = remittance.settlement_config {
-            if config.require_proof {
-                match proof {
-                    None => return Err(ContractError::MissingProof),
-                    Some(ref submitted) => {
-                        let expected = get_payout_commitment(&env, remittance_id);
-                        if let Some(ref expected_hash) = expected {
-                            if !verification::verify_proof_commitment(submitted, expected_hash) {
-                                return Err(ContractError::InvalidProof);
-                            }
-                        }
-                    }
-                }
-            }
+        crate::storage::require_agent_authorized(&env, &agent)?;
+        if storage::get_remittance_route(&env, remittance_id).is_some() {
+            return Err(ContractError::InvalidRoute);
         }
-
-        // Validate that the assigned agent is registered and authenticated before any payout execution.
-        crate::storage::require_agent_authorized(&env, &remittance.agent)?;
-
-        // Require Settler role
-        require_role_settler(&env, &remittance.agent)?;
-
-        // Fix #379: Optimistic lock — atomically claim this settlement before any token
-        // transfer. A second concurrent call will find the hash already set and fail with
-        // DuplicateSettlement, preventing double-payout.
-        if has_settlement_hash(&env, remittance_id) {
-            return Err(ContractError::DuplicateSettlement);
+        if path_payout.min_receive <= 0 {
+            return Err(ContractError::InvalidAmount);
         }
-        set_settlement_hash(&env, remittance_id);
-        
-        // Transition to Processing state
-        crate::transitions::transition_status(&env, &mut remittance, RemittanceStatus::Processing)?;
-        storage::add_processing_volume(&env, remittance.amount)?;
 
-        // Extend the remittance TTL when entering Processing so the escrow
-        // does not expire while the agent is completing the off-chain payout (#624).
-        crate::storage::extend_remittance_ttl(
+        storage::set_path_payout(&env, remittance_id, &path_payout);
+        emit_path_payout_set(
             &env,
             remittance_id,
-            crate::config::PROCESSING_WINDOW_LEDGERS,
+            agent,
+            path_payout.dest_token,
+            path_payout.min_receive,
         );
+        Ok(())
+    }
 
-        // Verify recipient hash before any token transfer (Task 7.2)
-        recipient_verification::verify_recipient_hash(
-            &env,
-            remittance_id,
-            &remittance.agent,
-            recipient_details_hash,
-        )?;
-
-        // Update Agent Stats
-        let mut stats = crate::storage::get_agent_stats(&env, &remittance.agent);
-        stats.total_settlements += 1;
-        stats.total_settlement_time += env
-            .ledger()
-            .timestamp()
-            .saturating_sub(remittance.created_at);
-        stats.last_active_timestamp = env.ledger().timestamp();
-        let successful = stats.total_settlements.saturating_sub(stats.failed_settlements);
-        stats.success_rate_bps = successful
-            .saturating_mul(10000)
-            .checked_div(stats.total_settlements)
-            .unwrap_or(10000);
-        crate::storage::set_agent_stats(&env, &remittance.agent, &stats);
-
-        // Check rate limit for sender
-        check_settlement_rate_limit(&env, &remittance.sender)?;
-
-        // Enforce per-agent daily withdrawal cap
-        storage::check_and_record_agent_withdrawal(&env, &remittance.agent, remittance.amount)?;
-
-        // Use centralized fee service to get complete breakdown
-        let fee_breakdown = fee_service::calculate_fees_with_breakdown(
-            &env,
-            remittance.amount,
-            None, // No corridor specified
-            None, // No corridor config
-        )?;
+    /// Returns the asset a remittance's payout is converted to, if any.
+    pub fn get_path_payout(env: Env, remittance_id: u64) -> Option<PathPayout> {
+        storage::get_path_payout(&env, remittance_id)
+    }
 
-        // Verify stored fee matches calculated platform fee
-        if remittance.fee != fee_breakdown.platform_fee {
-            return Err(ContractError::InvalidAmount);
+    /// Sets the token `agent` prefers its payouts in.
+    ///
+    /// When a direct remittance settles in a different token, the payout is
+    /// converted through the path payment adapter and must come to at least
+    /// the adapter's quote less `max_slippage_bps`. If the adapter is unset,
+    /// cannot quote, or fails, the payout is made in the escrowed token.
+    /// A per-remittance `set_path_payout` takes precedence.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidFeeBps` - `max_slippage_bps` exceeds `MAX_PAYOUT_SLIPPAGE_BPS`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn set_payout_preference(
+        env: Env,
+        agent: Address,
+        token: Address,
+        max_slippage_bps: u32,
+    ) -> Result<(), ContractError> {
+        crate::storage::require_agent_authorized(&env, &agent)?;
+        if max_slippage_bps > MAX_PAYOUT_SLIPPAGE_BPS {
+            return Err(ContractError::InvalidFeeBps);
         }
-
-        let payout_amount = fee_breakdown.net_amount;
-        let protocol_fee = fee_breakdown.protocol_fee;
-
-        let remittance_token = remittance.token.clone();
-        let current_fees = get_accumulated_fees(&env)?;
-        let current_time = env.ledger().timestamp();
-
-        let token_client = token::Client::new(&env, &remittance_token);
-
-        // Transfer payout to agent
-        token_client.transfer(
-            &env.current_contract_address(),
-            &remittance.agent,
-            &payout_amount,
+        storage::set_payout_preference(
+            &env,
+            &agent,
+            &PayoutPreference {
+                token,
+                max_slippage_bps,
+            },
         );
+        Ok(())
+    }
 
-        // Transfer protocol fee to treasury if needed
-        if protocol_fee > 0 {
-            let treasury = get_treasury(&env)?;
-            token_client.transfer(&env.current_contract_address(), &treasury, &protocol_fee);
-        }
-
-        // Update accumulated fees with overflow protection and automatic flush
-        safe_add_accumulated_fee(&env, remittance.fee)?;
-
-        // Update analytics: move volume from in-flight to completed
-        storage::sub_processing_volume(&env, remittance.amount)?;
-        storage::add_completed_volume(&env, remittance.amount)?;
+    /// Removes `agent`'s payout preference, so payouts arrive in the escrowed token.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn clear_payout_preference(env: Env, agent: Address) -> Result<(), ContractError> {
+        crate::storage::require_agent_authorized(&env, &agent)?;
+        storage::remove_payout_preference(&env, &agent);
+        Ok(())
+    }
 
-        // Update remittance status via validated transition
-        crate::transitions::transition_status(&env, &mut remittance, RemittanceStatus::Completed)?;
-        set_remittance(&env, remittance_id, &remittance);
+    /// Returns the token an agent prefers its payouts in, if any.
+    pub fn get_payout_preference(env: Env, agent: Address) -> Option<PayoutPreference> {
+        storage::get_payout_preference(&env, &agent)
+    }
 
-        // Update last settlement time for rate limiting
-        set_last_settlement_time(&env, &remittance.sender, current_time);
+    /// Returns the sender-defined tags attached to a remittance.
+    pub fn get_remittance_tags(env: Env, remittance_id: u64) -> Vec<Symbol> {
+        storage::get_remittance_tags(&env, remittance_id)
+    }
 
-        // Event: Remittance completed - Fires when agent confirms fiat payout and USDC is released
-        // Used by off-chain systems to track successful settlements and update transaction status
-        emit_remittance_completed(
-            &env,
-            remittance_id,
-            remittance.sender.clone(),
-            remittance.agent.clone(),
-        );
+    /// Creates a remittance using corridor-specific fees when available.
+    ///
+    /// If a corridor is configured for the given country pair, its fee strategy
+    /// is used instead of the global strategy. Falls back to global if not found.
+    pub fn create_remittance_with_corridor(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        amount: i128,
+        expiry: Option<Expiry>,
+        from_country: Option<String>,
+        to_country: Option<String>,
+    ) -> Result<u64, ContractError> {
+        validate_create_remittance_request(&env, &sender, &agent, amount)?;
 
-        // Event: Settlement completed - Fires with final executed settlement values
-        // Used by off-chain systems for reconciliation and audit trails of completed transactions
-        emit_settlement_completed(
-            &env,
-            remittance_id,
-            remittance.sender,
-            remittance.agent,
-            remittance_token,
-            payout_amount,
-        );
+        sender.require_auth();
 
-        log_confirm_payout(&env, remittance_id, payout_amount);
+        let limit_currency = String::from_str(&env, DEFAULT_DAILY_LIMIT_CURRENCY);
+        let limit_country = to_country
+            .clone()
+            .unwrap_or_else(|| String::from_str(&env, DEFAULT_DAILY_LIMIT_COUNTRY));
+        enforce_daily_send_limit(&env, &sender, &limit_currency, &limit_country, amount)?;
 
-        // Cleanup: remove idempotency record on terminal state (Completed)
-        if let Some(idem_key) = storage::take_remittance_idempotency_key(&env, remittance_id) {
-            storage::remove_idempotency_record(&env, &idem_key);
+        if let (Some(from), Some(to)) = (&from_country, &to_country) {
+            if storage::is_corridor_disabled(&env, from, to) {
+                return Err(ContractError::CorridorDisabled);
+            }
+            if storage::is_hash_only_corridor(&env, from, to) {
+                return Err(ContractError::HashOnlyCorridor);
+            }
         }
+        let usdc_token = get_usdc_token(&env)?;
+        let restricted_corridor = match (&from_country, &to_country) {
+            (Some(from_country), Some(to_country)) => Some(RemittanceCorridor {
+                from_country: from_country.clone(),
+                to_country: to_country.clone(),
+            }),
+            _ => None,
+        };
+        restrictions::check(&env, &sender, &usdc_token, restricted_corridor.as_ref())?;
+        restrictions::check(&env, &agent, &usdc_token, restricted_corridor.as_ref())?;
 
-        Ok(())
-    }
+        let corridor = match (&from_country, &to_country) {
+            (Some(from), Some(to)) => storage::get_fee_corridor(&env, from, to),
+            _ => None,
+        };
+        let charged = creation_fee(&env, &sender, amount, expiry, || {
+            fee_service::calculate_fees_with_breakdown_for_sender(
+                &env,
+                &sender,
+                amount,
+                Some(&usdc_token),
+                corridor.as_ref(),
+            )
+            .map(|breakdown| breakdown.platform_fee)
+        })?;
+        let fee = charged.fee;
 
-    pub fn mark_failed(env: Env, remittance_id: u64) -> Result<(), ContractError> {
-        let mut remittance = get_remittance(&env, remittance_id)?;
-        crate::storage::require_agent_authorized(&env, &remittance.agent)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        token_transfer::transfer(&token_client, &sender, &env.current_contract_address(), amount)?;
 
-        if remittance.status != RemittanceStatus::Pending
-            && remittance.status != RemittanceStatus::Processing
-        {
-            return Err(ContractError::InvalidStatus);
-        }
+        let counter = get_remittance_counter(&env)?;
+        let remittance_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
 
-        // Auto-refund the escrowed amount to the sender (#621)
-        let token_client = token::Client::new(&env, &remittance.token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &remittance.sender,
-            &remittance.amount,
-        );
+        let corridor_created_at = env.ledger().timestamp();
+        let corridor_expiry_window = storage::get_remittance_expiry_window(&env);
+        let corridor_expires_at = if corridor_expiry_window > 0 {
+            Some(corridor_created_at.saturating_add(corridor_expiry_window))
+        } else {
+            None
+        };
+        let remittance = Remittance {
+            id: remittance_id,
+            sender: sender.clone(),
+            agent: agent.clone(),
+            amount,
+            fee,
+            status: RemittanceStatus::Pending,
+            expiry: expiry.into(),
+            settlement_config: crate::MaybeSettlementConfig::None,
+            token: usdc_token.clone(),
+            created_at: corridor_created_at,
+            failed_at: None,
+            dispute_evidence: None.into(),
+            expires_at: corridor_expires_at,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: match (from_country, to_country) {
+                (Some(from_country), Some(to_country)) => MaybeCorridor::Some(RemittanceCorridor {
+                    from_country,
+                    to_country,
+                }),
+                _ => MaybeCorridor::None,
+            },
+        };
+
+        let payout_commitment = compute_payout_commitment(&env, &remittance);
 
-        let was_processing = remittance.status == RemittanceStatus::Processing;
-        let original_amount = remittance.amount;
-        remittance.status = RemittanceStatus::Cancelled;
-        remittance.amount = 0;
         set_remittance(&env, remittance_id, &remittance);
+        set_payout_commitment(&env, remittance_id, &payout_commitment);
+        set_remittance_counter(&env, remittance_id);
+        set_transfer_state(&env, remittance_id, RemittanceStatus::Pending)?;
+        storage::record_sender_volume(&env, &sender, amount, env.ledger().timestamp())?;
+        storage::increment_remittance_count(&env)?;
+        storage::append_sender_remittance(&env, &sender, remittance_id);
+        storage::append_agent_remittance(&env, &agent, remittance_id);
+        agent_tiers::track_pending(&env, &remittance)?;
+        record_expiry_fee(&env, remittance_id, charged.lock_seconds, charged.expiry_fee);
+        emit_remittance_created(
+            &env,
+            remittance_id,
+            sender.clone(),
+            agent.clone(),
+            amount,
+            fee,
+            0,
+            fee,
+            0,
+            amount - fee,
+            charged.exempt,
+        );
 
-        if was_processing {
-            storage::sub_processing_volume(&env, original_amount)?;
+        if let MaybeCorridor::Some(ref c) = remittance.corridor {
+            corridor_utilization::track_pending(
+                &env,
+                remittance_id,
+                &c.from_country,
+                &c.to_country,
+                amount,
+            )?;
         }
 
-        // Clear idempotency key on Failed so the same key can be reused to retry (#610)
-        if let Some(idem_key) = storage::take_remittance_idempotency_key(&env, remittance_id) {
-            storage::remove_idempotency_record(&env, &idem_key);
-        }
+        observers::notify_observers(&env, ObserverEvent::Created, &remittance);
+        performance::record(&env, PerformanceEvent::Created, &remittance);
 
-        let mut stats = crate::storage::get_agent_stats(&env, &remittance.agent);
-        stats.failed_settlements += 1;
-        stats.last_active_timestamp = env.ledger().timestamp();
-        let successful = stats.total_settlements.saturating_sub(stats.failed_settlements);
-        stats.success_rate_bps = if stats.total_settlements == 0 {
-            10000
-        } else {
-            successful
-                .saturating_mul(10000)
-                .checked_div(stats.total_settlements)
-                .unwrap_or(0)
-        };
-        crate::storage::set_agent_stats(&env, &remittance.agent, &stats);
+        Ok(remittance_id)
+    }
 
-        emit_remittance_failed(&env, remittance_id, remittance.agent);
-        Ok(())
+    /// Saves a named remittance template for repeat transfers.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidTemplate` - Empty name, non-positive amount,
+    ///   memo too long, or the sender already has `MAX_TEMPLATES_PER_SENDER`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender.
+    #[allow(clippy::too_many_arguments)]
+    pub fn save_remittance_template(
+        env: Env,
+        sender: Address,
+        name: String,
+        agent: Address,
+        amount: i128,
+        corridor: Option<RemittanceCorridor>,
+        memo: Option<String>,
+        purpose: Option<Symbol>,
+    ) -> Result<u64, ContractError> {
+        sender.require_auth();
+        let template = templates::save(
+            &env,
+            RemittanceTemplate {
+                id: 0,
+                owner: sender.clone(),
+                name,
+                agent,
+                amount,
+                corridor: match corridor {
+                    Some(corridor) => MaybeCorridor::Some(corridor),
+                    None => MaybeCorridor::None,
+                },
+                memo,
+                purpose,
+            },
+        )?;
+        emit_template_saved(&env, template.id, sender);
+        Ok(template.id)
     }
 
-    pub fn raise_dispute(
+    /// Deletes one of the sender's remittance templates.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender.
+    pub fn delete_remittance_template(
         env: Env,
-        remittance_id: u64,
-        evidence_hash: BytesN<32>,
+        sender: Address,
+        template_id: u64,
     ) -> Result<(), ContractError> {
-        let mut remittance = get_remittance(&env, remittance_id)?;
-        remittance.sender.require_auth();
-
-        if remittance.status != RemittanceStatus::Failed {
-            return Err(ContractError::InvalidStatus);
-        }
-
-        let failed_at = remittance.failed_at.ok_or(ContractError::InvalidStatus)?;
-        let window = get_dispute_window(&env);
-        if env.ledger().timestamp() > failed_at + window {
-            return Err(ContractError::DisputeWindowExpired);
+        sender.require_auth();
+        templates::owned(&env, &sender, template_id)?;
+        let mut ids = storage::get_sender_templates(&env, &sender);
+        if let Some(index) = ids.first_index_of(template_id) {
+            ids.remove(index);
         }
+        storage::set_sender_templates(&env, &sender, &ids);
+        storage::remove_remittance_template(&env, template_id);
+        emit_template_deleted(&env, template_id, sender);
+        Ok(())
+    }
 
-        remittance.status = RemittanceStatus::Disputed;
-        remittance.dispute_evidence = MaybeBytes32::Some(evidence_hash.clone());
-        set_remittance(&env, remittance_id, &remittance);
-
-        let mut stats = crate::storage::get_agent_stats(&env, &remittance.agent);
-        stats.dispute_count += 1;
-        crate::storage::set_agent_stats(&env, &remittance.agent, &stats);
+    /// Returns a saved remittance template.
+    pub fn get_remittance_template(env: Env, template_id: u64) -> Option<RemittanceTemplate> {
+        storage::get_remittance_template(&env, template_id)
+    }
 
-        emit_dispute_raised(&env, remittance_id, remittance.sender, evidence_hash);
-        Ok(())
+    /// Returns the IDs of the templates a sender has saved.
+    pub fn get_sender_templates(env: Env, sender: Address) -> Vec<u64> {
+        storage::get_sender_templates(&env, &sender)
     }
 
-    pub fn resolve_dispute(
+    /// Creates a remittance from a saved template.
+    ///
+    /// Any field set in `overrides` replaces the template's value for this
+    /// transfer only. Templates with a corridor are created through
+    /// `create_remittance_with_corridor`, others through `create_remittance`
+    /// in the default token. The template's purpose becomes the remittance's
+    /// tag and its memo the remittance memo.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::TemplateNotFound` - Template does not exist
+    /// * `ContractError::InvalidTemplate` - Override memo is too long
+    /// * Any error returned by the underlying create function
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the template owner.
+    pub fn create_from_template(
         env: Env,
-        remittance_id: u64,
-        in_favour_of_sender: bool,
-    ) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
+        template_id: u64,
+        overrides: TemplateOverrides,
+    ) -> Result<u64, ContractError> {
+        let template = storage::get_remittance_template(&env, template_id)
+            .ok_or(ContractError::TemplateNotFound)?;
+        let template = templates::apply(template, overrides)?;
+
+        let remittance_id = match template.corridor {
+            MaybeCorridor::Some(corridor) => Self::create_remittance_with_corridor(
+                env.clone(),
+                template.owner,
+                template.agent,
+                template.amount,
+                None,
+                Some(corridor.from_country),
+                Some(corridor.to_country),
+            )?,
+            MaybeCorridor::None => Self::create_remittance(
+                env.clone(),
+                template.owner,
+                template.agent,
+                template.amount,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )?,
+        };
 
         let mut remittance = get_remittance(&env, remittance_id)?;
-        if remittance.status != RemittanceStatus::Disputed {
-            return Err(ContractError::NotDisputed);
+        if template.memo.is_some() {
+            remittance.memo = template.memo;
+            set_remittance(&env, remittance_id, &remittance);
         }
-
-        let token_client = token::Client::new(&env, &remittance.token);
-        if in_favour_of_sender {
-            token_client.transfer(
-                &env.current_contract_address(),
-                &remittance.sender,
-                &remittance.amount,
-            );
-            remittance.status = RemittanceStatus::Cancelled;
-        } else {
-            let fee_breakdown = fee_service::calculate_fees_with_breakdown(
-                &env,
-                remittance.amount,
-                Some(&remittance.token),
-                None,
-            )?;
-            token_client.transfer(
-                &env.current_contract_address(),
-                &remittance.agent,
-                &fee_breakdown.net_amount,
-            );
-            remittance.status = RemittanceStatus::Completed;
+        if let Some(purpose) = template.purpose {
+            storage::set_remittance_tags(&env, remittance_id, &Vec::from_array(&env, [purpose]));
+            emit_tag_events(&env, &remittance, symbol_short!("created"));
         }
-
-        set_remittance(&env, remittance_id, &remittance);
-        emit_dispute_resolved(&env, remittance_id, caller, in_favour_of_sender);
-        Ok(())
+        emit_template_used(&env, template_id, remittance_id);
+        Ok(remittance_id)
     }
 
-    /// Sets the dispute window duration (admin only).
+    /// Creates multiple remittances in a single atomic batch operation.
     ///
-    /// Senders have this many seconds after a payout is marked Failed to raise a dispute.
-    pub fn set_dispute_window(env: Env, seconds: u64) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
-        storage::set_dispute_window(&env, seconds);
-        Ok(())
-    }
-
-    /// Returns the current dispute window in seconds.
-    pub fn get_dispute_window(env: Env) -> u64 {
-        storage::get_dispute_window(&env)
-    }
-
-    /// Confirms a partial payout for a remittance, disbursing `amount` to the agent.
+    /// This function allows high-volume senders to create multiple remittances
+    /// at once, reducing transaction costs by batching the token transfer.
+    /// All entries are validated before any state changes occur.
+    /// Each remittance gets its own `remit`/`created` event, as it would from
+    /// `create_remittance`.
     ///
-    /// Large remittances can be split into multiple disbursements. Each call transfers
-    /// `amount` from escrow to the agent. When the total disbursed equals the net payout
-    /// (amount - fee), the remittance is automatically marked Completed.
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `sender` - Address of the sender initiating the batch
+    /// * `entries` - Vector of BatchCreateEntry structs containing remittance details
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u64>)` - Vector of created remittance IDs
+    /// * `Err(ContractError)` - If any entry fails validation or batch size exceeds limit
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidBatchSize` - Batch is empty or exceeds MAX_BATCH_SIZE (100)
+    /// * `ContractError::InvalidAmount` - Any entry has zero or negative amount
+    /// * `ContractError::AgentNotRegistered` - Any agent is not registered
+    /// * `ContractError::UserBlacklisted` - Sender is blacklisted
+    /// * `ContractError::DailySendLimitExceeded` - Total amount exceeds daily limit
+    /// * `ContractError::Overflow` - Arithmetic overflow in amount calculation
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address.
+    pub fn batch_create_remittances(
+        env: Env,
+        sender: Address,
+        entries: Vec<BatchCreateEntry>,
+    ) -> Result<Vec<u64>, ContractError> {
+        if crate::storage::is_migration_in_progress(&env) {
+            return Err(ContractError::MigrationInProgress);
+        }
+
+        // Validate batch size
+        let batch_size = entries.len();
+        if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidBatchSize);
+        }
+
+        sender.require_auth();
+
+        // Validate all entries and accumulate total before any state changes
+        let mut total_amount: i128 = 0;
+        for i in 0..batch_size {
+            let entry = entries.get_unchecked(i);
+            validate_create_remittance_request(&env, &sender, &entry.agent, entry.amount)?;
+            total_amount = total_amount
+                .checked_add(entry.amount)
+                .ok_or(ContractError::Overflow)?;
+        }
+
+        // Pre-validate the entire batch total against the daily limit atomically (#611)
+        // This ensures no partial batch can sneak past the limit one entry at a time
+        let default_currency = String::from_str(&env, DEFAULT_DAILY_LIMIT_CURRENCY);
+        let default_country = String::from_str(&env, DEFAULT_DAILY_LIMIT_COUNTRY);
+        enforce_daily_send_limit(&env, &sender, &default_currency, &default_country, total_amount)?;
+
+        // Transfer total amount in a single token transfer
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        token_transfer::transfer(
+            &token_client,
+            &sender,
+            &env.current_contract_address(),
+            total_amount,
+        )?;
+
+        // Create all remittances
+        let mut remittance_ids = Vec::new(&env);
+        let mut counter = get_remittance_counter(&env)?;
+        // #840: Cache timestamp and prior volume once before the loop to avoid
+        // redundant ledger reads on every iteration.
+        let now = env.ledger().timestamp();
+        let prior_volume = storage::get_sender_rolling_volume(&env, &sender, now);
+        let mut cumulative_volume = prior_volume;
+        let fee_exempt = storage::is_fee_exempt(&env, &sender);
+
+        for i in 0..batch_size {
+            let entry = entries.get_unchecked(i);
+            counter = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+            let remittance_id = counter;
+
+            // Calculate fee for this entry using the sender's rolling volume and batch cumulative amount.
+            let total_volume = cumulative_volume
+                .checked_add(entry.amount)
+                .ok_or(ContractError::Overflow)?;
+            let fee = if fee_exempt {
+                0
+            } else {
+                fee_service::calculate_platform_fee_for_volume(
+                    &env,
+                    entry.amount,
+                    Some(&usdc_token),
+                    total_volume,
+                )?
+            };
+            cumulative_volume = total_volume;
+
+            let batch_created_at = env.ledger().timestamp();
+            let batch_expiry_window = storage::get_remittance_expiry_window(&env);
+            let batch_expires_at = if batch_expiry_window > 0 {
+                Some(batch_created_at.saturating_add(batch_expiry_window))
+            } else {
+                None
+            };
+            let remittance = Remittance {
+                id: remittance_id,
+                sender: sender.clone(),
+                agent: entry.agent.clone(),
+                amount: entry.amount,
+                fee,
+                status: RemittanceStatus::Pending,
+                expiry: entry.expiry,
+                settlement_config: crate::MaybeSettlementConfig::None,
+                token: usdc_token.clone(),
+                created_at: batch_created_at,
+                failed_at: None,
+                dispute_evidence: None.into(),
+                expires_at: batch_expires_at,
+                accepted_at: None,
+                completed_at: None,
+                memo: None,
+                corridor: crate::MaybeCorridor::None,
+            };
+
+            let payout_commitment = compute_payout_commitment(&env, &remittance);
+
+            set_remittance(&env, remittance_id, &remittance);
+            set_payout_commitment(&env, remittance_id, &payout_commitment);
+            set_transfer_state(&env, remittance_id, RemittanceStatus::Pending)?;
+
+            // Persist the sender's volume history for future discount calculations.
+            storage::record_sender_volume(&env, &sender, entry.amount, now)?;
+            storage::increment_remittance_count(&env)?;
+
+            // Index this remittance under the sender and agent for paginated queries
+            storage::append_sender_remittance(&env, &sender, remittance_id);
+            storage::append_agent_remittance(&env, &entry.agent, remittance_id);
+            agent_tiers::track_pending(&env, &remittance)?;
+
+            emit_remittance_created(
+                &env,
+                remittance_id,
+                sender.clone(),
+                entry.agent.clone(),
+                entry.amount,
+                fee,
+                0,
+                fee,
+                0,
+                entry.amount - fee,
+                fee_exempt,
+            );
+            observers::notify_observers(&env, ObserverEvent::Created, &remittance);
+            performance::record(&env, PerformanceEvent::Created, &remittance);
+
+            remittance_ids.push_back(remittance_id);
+        }
+
+        // Update counter once at the end
+        set_remittance_counter(&env, counter);
+
+        Ok(remittance_ids)
+    }
+
+    /// Confirms a remittance payout to the agent.
+    ///
+    /// Transfers the remittance amount (minus platform fee) to the agent and marks
+    /// the remittance as completed. Includes duplicate settlement protection and
+    /// expiry validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to confirm
+    /// * `pickup_secret` - Recipient's pickup secret, required if a pickup hash is registered
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Payout successfully confirmed and transferred
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    /// * `Err(ContractError::DuplicateSettlement)` - Settlement already executed
+    /// * `Err(ContractError::SettlementExpired)` - Current time exceeds expiry timestamp
+    /// * `Err(ContractError::InvalidAddress)` - Agent address validation failed
+    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in payout calculation
+    /// * `Err(ContractError::PickupSecretRequired)` - Pickup secret missing or not registered for a high-value remittance
+    /// * `Err(ContractError::InvalidPickupSecret)` - Pickup secret does not match the registered hash
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address assigned to the remittance,
+    /// from one of its sub-operators within that sub-operator's daily limit, or
+    /// from the platform settlement operator if the agent enabled auto-settlement.
+    /// Requires Settler role.
+    pub fn confirm_payout(
+        env: Env,
+        agent: Address,
+        remittance_id: u64,
+        proof: Option<soroban_sdk::BytesN<32>>,
+        recipient_details_hash: Option<BytesN<32>>,
+        pickup_secret: Option<Bytes>,
+    ) -> Result<(), ContractError> {
+        if crate::storage::is_migration_in_progress(&env) {
+            return Err(ContractError::MigrationInProgress);
+        }
+        // Centralized validation before business logic (returns remittance to avoid re-read)
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
+
+        // Verify the caller is the specific agent assigned to this remittance (#608)
+        // or one of its sub-operators; payout still goes to the assigned agent.
+        crate::storage::require_agent_or_sub_operator(
+            &env,
+            &agent,
+            &remittance.agent,
+            remittance.amount,
+        )?;
+
+        execute_payout(&env, remittance, proof, recipient_details_hash, pickup_secret, None)?;
+        Ok(())
+    }
+
+    /// Confirms a remittance payout with a delivery-proof hash.
+    ///
+    /// `delivery_proof` is a hash of the agent's evidence that the recipient
+    /// was paid, such as a signed receipt or an ID-check attestation. It is
+    /// stored against the remittance for later disputes. Remittances at or
+    /// above the delivery-proof threshold can only be confirmed this way.
+    ///
+    /// # Errors
+    ///
+    /// * Any error returned by `confirm_payout`
+    ///
+    /// # Authorization
+    ///
+    /// Same as `confirm_payout`.
+    pub fn confirm_with_delivery_proof(
+        env: Env,
+        agent: Address,
+        remittance_id: u64,
+        delivery_proof: BytesN<32>,
+        proof: Option<BytesN<32>>,
+        recipient_details_hash: Option<BytesN<32>>,
+        pickup_secret: Option<Bytes>,
+    ) -> Result<(), ContractError> {
+        if crate::storage::is_migration_in_progress(&env) {
+            return Err(ContractError::MigrationInProgress);
+        }
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
+        crate::storage::require_agent_or_sub_operator(
+            &env,
+            &agent,
+            &remittance.agent,
+            remittance.amount,
+        )?;
+
+        execute_payout(
+            &env,
+            remittance,
+            proof,
+            recipient_details_hash,
+            pickup_secret,
+            Some(delivery_proof),
+        )?;
+        Ok(())
+    }
+
+    /// Returns the delivery-proof hash recorded when a remittance was confirmed, if any.
+    pub fn get_delivery_proof(env: Env, remittance_id: u64) -> Option<BytesN<32>> {
+        storage::get_delivery_proof(&env, remittance_id)
+    }
+
+    /// Registers or rotates the ed25519 public key an agent signs payout vouchers with.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from a registered agent.
+    pub fn set_voucher_key(env: Env, agent: Address, public_key: BytesN<32>) -> Result<(), ContractError> {
+        crate::storage::require_agent_authorized(&env, &agent)?;
+        storage::set_voucher_key(&env, &agent, &public_key);
+        storage::remove_passkey_voucher_key(&env, &agent);
+        emit_voucher_key_set(&env, agent, public_key);
+        Ok(())
+    }
+
+    /// Registers or rotates a secp256r1 passkey an agent signs payout vouchers
+    /// with, replacing any ed25519 voucher key.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidPasskey` - Key is not an uncompressed SEC-1 point
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from a registered agent.
+    pub fn set_passkey_voucher_key(
+        env: Env,
+        agent: Address,
+        public_key: BytesN<65>,
+    ) -> Result<(), ContractError> {
+        crate::storage::require_agent_authorized(&env, &agent)?;
+        voucher::validate_passkey(&public_key)?;
+        storage::set_passkey_voucher_key(&env, &agent, &public_key);
+        storage::remove_voucher_key(&env, &agent);
+        emit_passkey_voucher_key_set(&env, agent, public_key);
+        Ok(())
+    }
+
+    /// Settles a remittance using a payout voucher signed off-chain by its agent.
+    ///
+    /// The voucher is an ed25519 or passkey signature over the payload
+    /// described in the `voucher` module (remittance ID, amount, nonce, and
+    /// the proof, recipient details hash, and pickup secret submitted here),
+    /// depending on which key the agent registered. Anyone may submit
+    /// it; the signature stands in for the agent's transaction-level auth.
+    /// Settlement otherwise follows `confirm_payout` exactly.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidAmount` - `amount` does not match the remittance
+    /// * `ContractError::VoucherKeyNotSet` - Agent has no registered signing key
+    /// * `ContractError::VoucherNonceUsed` - Voucher was already submitted
+    /// * Any error returned by `confirm_payout`
+    ///
+    /// An invalid signature aborts the invocation.
+    #[allow(clippy::too_many_arguments)]
+    pub fn settle_with_voucher(
+        env: Env,
+        remittance_id: u64,
+        amount: i128,
+        nonce: u64,
+        signature: BytesN<64>,
+        proof: Option<BytesN<32>>,
+        recipient_details_hash: Option<BytesN<32>>,
+        pickup_secret: Option<Bytes>,
+    ) -> Result<(), ContractError> {
+        if crate::storage::is_migration_in_progress(&env) {
+            return Err(ContractError::MigrationInProgress);
+        }
+        let remittance = validate_confirm_payout_request(&env, remittance_id)?;
+        if amount != remittance.amount {
+            return Err(ContractError::InvalidAmount);
+        }
+        if !storage::is_agent_registered(&env, &remittance.agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+
+        let evidence = voucher::VoucherEvidence {
+            proof,
+            recipient_details_hash,
+            pickup_secret,
+        };
+        voucher::consume_voucher(
+            &env,
+            &remittance.agent,
+            remittance_id,
+            amount,
+            nonce,
+            &evidence,
+            &signature,
+        )?;
+        let agent = remittance.agent.clone();
+
+        execute_payout(
+            &env,
+            remittance,
+            evidence.proof,
+            evidence.recipient_details_hash,
+            evidence.pickup_secret,
+            None,
+        )?;
+        emit_voucher_settled(&env, remittance_id, agent, nonce);
+        Ok(())
+    }
+
+    /// Creates a remittance whose amount is hidden behind a commitment.
+    ///
+    /// The sender escrows `deposit`, which must cover the committed amount, and
+    /// shares the amount and salt with the agent off-chain. Only the commitment
+    /// is stored and emitted; see the `amount_commitment` module for its layout.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender.
+    pub fn create_private_remittance(
+        env: Env,
+        sender: Address,
+        agent: Address,
+        token: Address,
+        deposit: i128,
+        commitment: BytesN<32>,
+    ) -> Result<u64, ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::ContractPaused);
+        }
+        validate_create_remittance_request(&env, &sender, &agent, deposit)?;
+        if !is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
+        }
+        sender.require_auth();
+
+        token_transfer::transfer(
+            &token::Client::new(&env, &token),
+            &sender,
+            &env.current_contract_address(),
+            deposit,
+        )?;
+        let remittance =
+            amount_commitment::create(&env, &sender, &agent, &token, deposit, commitment.clone())?;
+        emit_private_remittance_created(&env, remittance.id, sender, agent, commitment);
+        Ok(remittance.id)
+    }
+
+    /// Settles a private remittance by revealing its amount.
+    ///
+    /// Verifies `amount` and `salt` against the commitment, pays the agent the
+    /// amount minus fees, and refunds the rest of the deposit to the sender.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::AmountCommitmentMismatch` - Reveal does not match the commitment
+    /// * `ContractError::InvalidAmount` - Revealed amount is not positive or exceeds the deposit
+    /// * `ContractError::InvalidStatus` - Remittance is not Pending
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the remittance's agent.
+    pub fn settle_private_remittance(
+        env: Env,
+        remittance_id: u64,
+        amount: i128,
+        salt: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::ContractPaused);
+        }
+        let mut remittance = amount_commitment::pending(&env, remittance_id)?;
+        crate::storage::require_agent_authorized(&env, &remittance.agent)?;
+        amount_commitment::verify_reveal(&env, &remittance, amount, &salt)?;
+
+        let fees = fee_service::calculate_fees_with_breakdown(&env, amount, Some(&remittance.token), None)?;
+        let payout = fees.net_amount;
+
+        let treasury = if fees.protocol_fee > 0 {
+            Some(get_treasury(&env)?)
+        } else {
+            None
+        };
+        add_token_fee(&env, &remittance.token, fees.platform_fee)?;
+        remittance.status = RemittanceStatus::Completed;
+        remittance.amount = Some(amount);
+        storage::set_private_remittance(&env, &remittance);
+
+        let token_client = token::Client::new(&env, &remittance.token);
+        let contract = env.current_contract_address();
+        token_transfer::transfer_for(
+            &token_client,
+            remittance_id,
+            &contract,
+            &remittance.agent,
+            payout,
+        )?;
+        if let Some(treasury) = treasury {
+            token_transfer::transfer_for(
+                &token_client,
+                remittance_id,
+                &contract,
+                &treasury,
+                fees.protocol_fee,
+            )?;
+        }
+        let refund = remittance.deposit - amount;
+        if refund > 0 {
+            token_transfer::transfer_for(
+                &token_client,
+                remittance_id,
+                &contract,
+                &remittance.sender,
+                refund,
+            )?;
+        }
+        emit_private_remittance_settled(&env, remittance_id, remittance.agent, amount, payout);
+        Ok(())
+    }
+
+    /// Cancels a Pending private remittance and refunds the full deposit.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender.
+    pub fn cancel_private_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let mut remittance = amount_commitment::pending(&env, remittance_id)?;
+        remittance.sender.require_auth();
+
+        remittance.status = RemittanceStatus::Cancelled;
+        storage::set_private_remittance(&env, &remittance);
+        token_transfer::transfer_for(
+            &token::Client::new(&env, &remittance.token),
+            remittance_id,
+            &env.current_contract_address(),
+            &remittance.sender,
+            remittance.deposit,
+        )?;
+        emit_private_remittance_cancelled(&env, remittance_id, remittance.sender);
+        Ok(())
+    }
+
+    /// Returns a private remittance. Its amount is `None` until settlement.
+    pub fn get_private_remittance(env: Env, remittance_id: u64) -> Option<PrivateRemittance> {
+        storage::get_private_remittance(&env, remittance_id)
+    }
+
+    /// Creates a remittance that stores only a hash of its details.
+    ///
+    /// The sender escrows `amount` and keeps the [`RemittanceDetails`] hashed
+    /// into `details_hash` off-chain, sharing them with the agent; see the
+    /// `hashed_records` module for the hash layout. The details must name
+    /// `sender` as the sender, or the deposit cannot be refunded to it.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender.
+    pub fn create_hashed_remittance(
+        env: Env,
+        sender: Address,
+        token: Address,
+        amount: i128,
+        details_hash: BytesN<32>,
+    ) -> Result<u64, ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::ContractPaused);
+        }
+        if amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        if is_user_blacklisted(&env, &sender) {
+            return Err(ContractError::UserBlacklisted);
+        }
+        if !is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
+        }
+        maintenance::ensure_open(&env)?;
+        sender.require_auth();
+
+        token_transfer::transfer(
+            &token::Client::new(&env, &token),
+            &sender,
+            &env.current_contract_address(),
+            amount,
+        )?;
+        let remittance = hashed_records::create(&env, &token, amount, details_hash.clone())?;
+        emit_hashed_remittance_created(&env, remittance.id, amount, details_hash);
+        Ok(remittance.id)
+    }
+
+    /// Settles a hash-only remittance by presenting its details.
+    ///
+    /// Verifies `details` against the stored hash and pays the agent named in
+    /// them the amount minus fees.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::DetailsHashMismatch` - Details do not match the stored hash
+    /// * `ContractError::InvalidStatus` - Remittance is not Pending
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent named in the details, which must
+    /// be registered.
+    pub fn settle_hashed_remittance(
+        env: Env,
+        remittance_id: u64,
+        details: RemittanceDetails,
+    ) -> Result<(), ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::ContractPaused);
+        }
+        let mut remittance = hashed_records::verified_pending(&env, remittance_id, &details)?;
+        crate::storage::require_agent_authorized(&env, &details.agent)?;
+
+        let fees = fee_service::calculate_fees_with_breakdown(
+            &env,
+            remittance.amount,
+            Some(&remittance.token),
+            None,
+        )?;
+        let payout = fees.net_amount;
+
+        let treasury = if fees.protocol_fee > 0 {
+            Some(get_treasury(&env)?)
+        } else {
+            None
+        };
+        add_token_fee(&env, &remittance.token, fees.platform_fee)?;
+        remittance.status = RemittanceStatus::Completed;
+        storage::set_hashed_remittance(&env, &remittance);
+
+        let token_client = token::Client::new(&env, &remittance.token);
+        let contract = env.current_contract_address();
+        token_transfer::transfer_for(
+            &token_client,
+            remittance_id,
+            &contract,
+            &details.agent,
+            payout,
+        )?;
+        if let Some(treasury) = treasury {
+            token_transfer::transfer_for(
+                &token_client,
+                remittance_id,
+                &contract,
+                &treasury,
+                fees.protocol_fee,
+            )?;
+        }
+        emit_hashed_remittance_settled(&env, remittance_id, payout);
+        Ok(())
+    }
+
+    /// Cancels a Pending hash-only remittance and refunds the sender named in
+    /// `details`.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender named in the details.
+    pub fn cancel_hashed_remittance(
+        env: Env,
+        remittance_id: u64,
+        details: RemittanceDetails,
+    ) -> Result<(), ContractError> {
+        let mut remittance = hashed_records::verified_pending(&env, remittance_id, &details)?;
+        details.sender.require_auth();
+
+        remittance.status = RemittanceStatus::Cancelled;
+        storage::set_hashed_remittance(&env, &remittance);
+        token_transfer::transfer_for(
+            &token::Client::new(&env, &remittance.token),
+            remittance_id,
+            &env.current_contract_address(),
+            &details.sender,
+            remittance.amount,
+        )?;
+        emit_hashed_remittance_cancelled(&env, remittance_id);
+        Ok(())
+    }
+
+    /// Returns the on-chain part of a hash-only remittance.
+    pub fn get_hashed_remittance(env: Env, remittance_id: u64) -> Option<HashedRemittance> {
+        storage::get_hashed_remittance(&env, remittance_id)
+    }
+
+    /// Marks a corridor as hash-only, or clears the mark.
+    ///
+    /// While marked, `create_remittance_with_corridor` rejects the corridor
+    /// with `ContractError::HashOnlyCorridor`.
+    ///
+    /// # Authorization
+    ///
+    /// Requires admin authorization.
+    pub fn set_hash_only_corridor(
+        env: Env,
+        caller: Address,
+        from_country: String,
+        to_country: String,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(
+            &env,
+            &caller,
+            "set_hash_only_corridor",
+            (from_country.clone(), to_country.clone(), enabled),
+        );
+        storage::set_hash_only_corridor(&env, &from_country, &to_country, enabled);
+        Ok(())
+    }
+
+    /// Returns whether a corridor only accepts hash-only remittances.
+    pub fn is_hash_only_corridor(env: Env, from_country: String, to_country: String) -> bool {
+        storage::is_hash_only_corridor(&env, &from_country, &to_country)
+    }
+
+    /// Registers the SHA-256 digest of a recipient-generated pickup secret.
+    ///
+    /// Once registered, `confirm_payout` only succeeds if the agent presents the
+    /// secret itself, proving the recipient was present at cash pickup. The hash
+    /// can be set once, while the remittance is Pending.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the remittance sender.
+    pub fn register_pickup_secret(
+        env: Env,
+        sender: Address,
+        remittance_id: u64,
+        secret_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+        recipient_verification::store_pickup_secret_hash(&env, &sender, remittance_id, &secret_hash)
+    }
+
+    /// Attaches an opaque memo, encrypted off-chain to the recipient's key, to a
+    /// Pending remittance.
+    ///
+    /// The ciphertext is stored with the remittance and emitted once, so pickup
+    /// instructions can travel with the transfer without exposing personal
+    /// details. At most `MAX_ENCRYPTED_MEMO_BYTES`; can be set once.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the remittance sender.
+    pub fn attach_encrypted_memo(
+        env: Env,
+        sender: Address,
+        remittance_id: u64,
+        ciphertext: Bytes,
+    ) -> Result<(), ContractError> {
+        sender.require_auth();
+        recipient_verification::store_encrypted_memo(&env, &sender, remittance_id, &ciphertext)
+    }
+
+    /// Returns the encrypted memo envelope attached to a remittance, if any.
+    pub fn get_encrypted_memo(env: Env, remittance_id: u64) -> Option<Bytes> {
+        storage::get_encrypted_memo(&env, remittance_id)
+    }
+
+    /// Sets the amount at or above which remittances can only be confirmed
+    /// with `confirm_with_delivery_proof`. Pass 0 to disable.
+    ///
+    /// # Authorization
+    ///
+    /// Requires admin authentication.
+    pub fn set_delivery_proof_threshold(
+        env: Env,
+        caller: Address,
+        threshold: i128,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_delivery_proof_threshold", (threshold,));
+        if threshold < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        storage::set_delivery_proof_threshold(&env, threshold);
+        Ok(())
+    }
+
+    /// Returns the delivery-proof threshold (0 when disabled).
+    pub fn get_delivery_proof_threshold(env: Env) -> i128 {
+        storage::get_delivery_proof_threshold(&env)
+    }
+
+    /// Sets the amount at or above which remittances require a pickup secret
+    /// to be confirmed. Pass 0 to disable (admin only).
+    pub fn set_pickup_secret_threshold(env: Env, threshold: i128) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_pickup_secret_threshold", (threshold,));
+        if threshold < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        storage::set_pickup_secret_threshold(&env, threshold);
+        Ok(())
+    }
+
+    /// Returns the pickup secret threshold (0 = disabled).
+    pub fn get_pickup_secret_threshold(env: Env) -> i128 {
+        storage::get_pickup_secret_threshold(&env)
+    }
+
+    /// Hands a pending remittance from its current agent to another registered agent.
+    ///
+    /// Lets an agent that cannot fulfil a remittance pass it on instead of
+    /// forcing a cancellation. The payout commitment is recomputed for the new
+    /// agent and, for routed remittances, the cash-out leg is updated. The
+    /// sender is notified through a `remit/reassign` event.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidStatus` - Remittance is not Pending or has partial disbursements
+    /// * `ContractError::InvalidAddress` - `to_agent` is already the assigned agent
+    /// * `ContractError::AgentNotRegistered` - Either agent is not registered
+    /// * `ContractError::AgentTierTooLow` / `AgentPendingLimitExceeded` - `to_agent`'s
+    ///   tier does not allow the remittance
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from both the current agent and `to_agent`.
+    pub fn reassign_remittance(
+        env: Env,
+        remittance_id: u64,
+        to_agent: Address,
+    ) -> Result<(), ContractError> {
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        ensure_reassignable(&env, &remittance, &to_agent)?;
+
+        crate::storage::require_agent_authorized(&env, &remittance.agent)?;
+        crate::storage::require_agent_authorized(&env, &to_agent)?;
+
+        move_to_agent(&env, &mut remittance, to_agent)
+    }
+
+    pub fn mark_failed(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        crate::storage::require_agent_authorized(&env, &remittance.agent)?;
+
+        if remittance.status != RemittanceStatus::Pending
+            && remittance.status != RemittanceStatus::Processing
+        {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        let was_processing = remittance.status == RemittanceStatus::Processing;
+        let original_amount = remittance.amount;
+
+        remittance.status = RemittanceStatus::Cancelled;
+        remittance.amount = 0;
+        set_remittance(&env, remittance_id, &remittance);
+        corridor_utilization::release_pending(&env, &remittance);
+        agent_tiers::release_pending(&env, &remittance);
+
+        if was_processing {
+            storage::sub_processing_volume(&env, original_amount)?;
+        }
+
+        // Auto-refund the escrowed amount to the sender (#621)
+        let token_client = token::Client::new(&env, &remittance.token);
+        token_transfer::transfer_for(
+            &token_client,
+            remittance_id,
+            &env.current_contract_address(),
+            &remittance.sender,
+            original_amount,
+        )?;
+
+        // Agent default: compensate insured senders on top of the refund
+        insurance::compensate(&env, &remittance)?;
+        priority::refund(&env, &remittance)?;
+
+        // Clear idempotency key on Failed so the same key can be reused to retry (#610)
+        if let Some(idem_key) = storage::take_remittance_idempotency_key(&env, remittance_id) {
+            storage::remove_idempotency_record(&env, &idem_key);
+        }
+
+        let mut stats = crate::storage::get_agent_stats(&env, &remittance.agent);
+        stats.failed_settlements += 1;
+        stats.last_active_timestamp = env.ledger().timestamp();
+        let successful = stats.total_settlements.saturating_sub(stats.failed_settlements);
+        stats.success_rate_bps = if stats.total_settlements == 0 {
+            10000
+        } else {
+            successful
+                .saturating_mul(10000)
+                .checked_div(stats.total_settlements)
+                .unwrap_or(0)
+        };
+        crate::storage::set_agent_stats(&env, &remittance.agent, &stats);
+
+        if !emit_minimal_lifecycle(&env, &remittance) {
+            emit_remittance_failed(&env, remittance_id, remittance.agent);
+        }
+        Ok(())
+    }
+
+    /// Insures a Pending remittance against non-delivery.
+    ///
+    /// The sender pays a premium of `INSURANCE_PREMIUM_BPS` of the amount into
+    /// the insurance fund. If the agent later marks the remittance failed, or
+    /// a dispute over it is resolved in the sender's favour, the sender is paid
+    /// `INSURANCE_COVERAGE_BPS` of the amount from the fund on top of the refund.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidStatus` - Remittance is not Pending
+    /// * `ContractError::AlreadyInsured` - Remittance already has a policy
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the remittance sender.
+    pub fn insure_remittance(env: Env, remittance_id: u64) -> Result<InsurancePolicy, ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        remittance.sender.require_auth();
+
+        let policy = insurance::insure(&env, &remittance)?;
+        emit_insurance_purchased(
+            &env,
+            remittance_id,
+            remittance.sender,
+            policy.premium,
+            policy.coverage,
+        );
+        Ok(policy)
+    }
+
+    /// Raises a Pending remittance's priority by one level, halving its SLA.
+    ///
+    /// The sender pays an expedite fee of `EXPEDITE_FEE_BPS` of the amount,
+    /// held until the remittance ends: on completion `EXPEDITE_AGENT_SHARE_BPS`
+    /// of it goes to the agent and the rest to platform fees, otherwise it is
+    /// refunded to the sender.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidStatus` - Remittance is not Pending
+    /// * `ContractError::PriorityAtMaximum` - Already at `MAX_PRIORITY_LEVEL`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the remittance sender.
+    pub fn boost_priority(env: Env, remittance_id: u64) -> Result<PriorityBoost, ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        remittance.sender.require_auth();
+
+        let (boost, fee) = priority::boost(&env, &remittance)?;
+        emit_priority_boosted(
+            &env,
+            remittance_id,
+            remittance.sender,
+            boost.level,
+            fee,
+            boost.sla_deadline,
+        );
+        Ok(boost)
+    }
+
+    /// Returns the priority boost bought for a remittance, if any.
+    pub fn get_priority_boost(env: Env, remittance_id: u64) -> Option<PriorityBoost> {
+        storage::get_priority_boost(&env, remittance_id)
+    }
+
+    /// Returns the time by which a remittance is due to be paid out under its SLA.
+    pub fn get_sla_deadline(env: Env, remittance_id: u64) -> Result<u64, ContractError> {
+        Ok(priority::sla_deadline(&env, &get_remittance(&env, remittance_id)?))
+    }
+
+    /// Returns the insurance policy for a remittance, if it is insured.
+    pub fn get_insurance_policy(env: Env, remittance_id: u64) -> Option<InsurancePolicy> {
+        storage::get_insurance_policy(&env, remittance_id)
+    }
+
+    /// Returns the insurance fund balance held for a token.
+    pub fn get_insurance_fund(env: Env, token: Address) -> i128 {
+        storage::get_insurance_fund(&env, &token)
+    }
+
+    /// Files an insurance claim for a remittance that has left Pending/Processing.
+    ///
+    /// Covers losses the automatic payouts do not, such as a confirmed payout
+    /// that never reached the recipient. Only one claim may be filed per
+    /// remittance, and none once its policy has paid out.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::NotInsured` - Remittance has no policy
+    /// * `ContractError::InvalidStatus` - Remittance is still Pending or Processing
+    /// * `ContractError::ClaimAlreadyFiled` - A claim exists or the policy already paid out
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the remittance sender.
+    pub fn file_claim(
+        env: Env,
+        remittance_id: u64,
+        evidence_hash: BytesN<32>,
+    ) -> Result<InsuranceClaim, ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        remittance.sender.require_auth();
+
+        let claim = insurance::file_claim(&env, &remittance, evidence_hash.clone())?;
+        emit_insurance_claim_filed(&env, remittance_id, remittance.sender, evidence_hash);
+        Ok(claim)
+    }
+
+    /// Approves a pending insurance claim and pays the policy coverage from the fund.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an address holding `Role::ClaimResolver`.
+    pub fn approve_claim(
+        env: Env,
+        resolver: Address,
+        remittance_id: u64,
+    ) -> Result<InsuranceClaim, ContractError> {
+        Self::resolve_claim(env, resolver, remittance_id, true)
+    }
+
+    /// Denies a pending insurance claim.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an address holding `Role::ClaimResolver`.
+    pub fn deny_claim(
+        env: Env,
+        resolver: Address,
+        remittance_id: u64,
+    ) -> Result<InsuranceClaim, ContractError> {
+        Self::resolve_claim(env, resolver, remittance_id, false)
+    }
+
+    fn resolve_claim(
+        env: Env,
+        resolver: Address,
+        remittance_id: u64,
+        approve: bool,
+    ) -> Result<InsuranceClaim, ContractError> {
+        resolver.require_auth();
+        storage::require_role_claim_resolver(&env, &resolver)?;
+
+        let remittance = get_remittance(&env, remittance_id)?;
+        let claim = insurance::resolve_claim(&env, &remittance, &resolver, approve)?;
+        emit_insurance_claim_resolved(&env, remittance_id, resolver, approve, claim.payout);
+        Ok(claim)
+    }
+
+    /// Returns the insurance claim filed for a remittance, if any.
+    pub fn get_insurance_claim(env: Env, remittance_id: u64) -> Option<InsuranceClaim> {
+        storage::get_insurance_claim(&env, remittance_id)
+    }
+
+    /// Returns the remittance IDs a sender has filed insurance claims for, oldest first.
+    pub fn get_sender_claims(env: Env, sender: Address) -> Vec<u64> {
+        storage::get_sender_claims(&env, &sender)
+    }
+
+    /// Links a remittance to the receiving anchor's SEP-31 transaction.
+    ///
+    /// The link starts in `AnchorTxStatus::PendingSender` and can be made in
+    /// any remittance status, so flows that only learn the anchor's
+    /// transaction ID after settlement remain auditable.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::Unauthorized` - `operator` lacks `Role::AnchorOperator`
+    /// * `ContractError::RemittanceNotFound` - Remittance does not exist
+    /// * `ContractError::AnchorTxAlreadyLinked` - The remittance or `tx_id` is already linked
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an address holding `Role::AnchorOperator`.
+    pub fn link_anchor_tx(
+        env: Env,
+        operator: Address,
+        remittance_id: u64,
+        anchor: Address,
+        tx_id: String,
+    ) -> Result<AnchorLink, ContractError> {
+        operator.require_auth();
+        storage::require_role_anchor_operator(&env, &operator)?;
+        get_remittance(&env, remittance_id)?;
+
+        let link = anchor_link::link(&env, remittance_id, anchor, tx_id)?;
+        emit_anchor_tx_linked(&env, remittance_id, link.anchor.clone(), link.tx_id.clone(), operator);
+        Ok(link)
+    }
+
+    /// Records the anchor transaction status reported for a remittance.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::Unauthorized` - `operator` lacks `Role::AnchorOperator`
+    /// * `ContractError::AnchorTxNotLinked` - The remittance has no anchor transaction
+    /// * `ContractError::InvalidStatus` - The anchor transaction already reached a final status
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an address holding `Role::AnchorOperator`.
+    pub fn update_anchor_status(
+        env: Env,
+        operator: Address,
+        remittance_id: u64,
+        status: AnchorTxStatus,
+    ) -> Result<AnchorLink, ContractError> {
+        operator.require_auth();
+        storage::require_role_anchor_operator(&env, &operator)?;
+
+        let link = anchor_link::update_status(&env, remittance_id, status)?;
+        emit_anchor_status_updated(&env, remittance_id, link.tx_id.clone(), status, operator);
+        Ok(link)
+    }
+
+    /// Returns the anchor transaction linked to a remittance, if any.
+    pub fn get_anchor_link(env: Env, remittance_id: u64) -> Option<AnchorLink> {
+        storage::get_anchor_link(&env, remittance_id)
+    }
+
+    /// Returns the remittance linked to an anchor transaction ID, if any.
+    pub fn get_remittance_by_anchor_tx(env: Env, tx_id: String) -> Option<u64> {
+        storage::get_anchor_tx_remittance(&env, &tx_id)
+    }
+
+    /// Registers a verified cause, or renames and reactivates an existing one (Admin only).
+    pub fn register_cause(
+        env: Env,
+        caller: Address,
+        cause: Address,
+        name: String,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "register_cause", (cause.clone(), name.clone()));
+
+        storage::set_cause(
+            &env,
+            &cause,
+            &Cause {
+                name: name.clone(),
+                active: true,
+            },
+        );
+        emit_cause_registered(&env, cause, name, caller);
+        Ok(())
+    }
+
+    /// Deactivates a cause so it accepts no new pledges (Admin only).
+    ///
+    /// Pledges already made still settle, and the cause can still withdraw
+    /// its accumulated balance.
+    pub fn deactivate_cause(env: Env, caller: Address, cause: Address) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "deactivate_cause", (cause.clone(),));
+
+        let mut info = storage::get_cause(&env, &cause).ok_or(ContractError::CauseNotFound)?;
+        info.active = false;
+        storage::set_cause(&env, &cause, &info);
+        emit_cause_deactivated(&env, cause, caller);
+        Ok(())
+    }
+
+    /// Returns a registered cause.
+    pub fn get_cause(env: Env, cause: Address) -> Option<Cause> {
+        storage::get_cause(&env, &cause)
+    }
+
+    /// Pledges a percentage of a Pending remittance to a registered cause.
+    ///
+    /// With `DonationSource::Amount` the donation reduces the agent payout;
+    /// with `DonationSource::Fee` it is taken from the platform fee. The
+    /// donation is credited to the cause when the payout settles. Pledging
+    /// again replaces the previous pledge.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidStatus` - Remittance is not Pending
+    /// * `ContractError::InvalidDonation` - `bps` is 0 or above `MAX_DONATION_BPS`
+    /// * `ContractError::CauseNotFound` - Cause is not registered or inactive
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the remittance sender.
+    pub fn pledge_donation(
+        env: Env,
+        remittance_id: u64,
+        cause: Address,
+        bps: u32,
+        source: DonationSource,
+    ) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        remittance.sender.require_auth();
+
+        causes::pledge(
+            &env,
+            &remittance,
+            &DonationPledge {
+                cause: cause.clone(),
+                bps,
+                source,
+            },
+        )?;
+        emit_donation_pledged(&env, remittance_id, cause, bps, source);
+        Ok(())
+    }
+
+    /// Returns the donation pledged against a remittance, if any.
+    pub fn get_donation_pledge(env: Env, remittance_id: u64) -> Option<DonationPledge> {
+        storage::get_donation_pledge(&env, remittance_id)
+    }
+
+    /// Returns the donations a cause has accumulated in a token.
+    pub fn get_cause_balance(env: Env, cause: Address, token: Address) -> CauseBalance {
+        storage::get_cause_balance(&env, &cause, &token)
+    }
+
+    /// Transfers a cause's available donations in `token` to the cause.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the cause address.
+    pub fn withdraw_cause_donations(
+        env: Env,
+        cause: Address,
+        token: Address,
+    ) -> Result<i128, ContractError> {
+        cause.require_auth();
+
+        let amount = causes::withdraw(&env, &cause, &token)?;
+        emit_cause_withdrawal(&env, cause, token, amount);
+        Ok(amount)
+    }
+
+    /// Tops up the insurance fund for a whitelisted token (Admin only).
+    pub fn fund_insurance(
+        env: Env,
+        caller: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "fund_insurance", (token.clone(), amount));
+        if amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        if !is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
+        }
+        token_transfer::transfer(
+            &token::Client::new(&env, &token),
+            &caller,
+            &env.current_contract_address(),
+            amount,
+        )?;
+        let balance = storage::get_insurance_fund(&env, &token)
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        storage::set_insurance_fund(&env, &token, balance);
+        emit_insurance_funded(&env, token, amount, caller);
+        Ok(())
+    }
+
+    pub fn raise_dispute(
+        env: Env,
+        remittance_id: u64,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        remittance.sender.require_auth();
+
+        if remittance.status != RemittanceStatus::Failed {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        let failed_at = remittance.failed_at.ok_or(ContractError::InvalidStatus)?;
+        let window = get_dispute_window(&env);
+        if env.ledger().timestamp() > failed_at + window {
+            return Err(ContractError::DisputeWindowExpired);
+        }
+
+        remittance.status = RemittanceStatus::Disputed;
+        remittance.dispute_evidence = MaybeBytes32::Some(evidence_hash.clone());
+        set_remittance(&env, remittance_id, &remittance);
+
+        let mut stats = crate::storage::get_agent_stats(&env, &remittance.agent);
+        stats.dispute_count += 1;
+        crate::storage::set_agent_stats(&env, &remittance.agent, &stats);
+
+        observers::notify_observers(&env, ObserverEvent::Disputed, &remittance);
+        performance::record(&env, PerformanceEvent::Disputed, &remittance);
+        if !emit_minimal_lifecycle(&env, &remittance) {
+            emit_dispute_raised(&env, remittance_id, remittance.sender, evidence_hash);
+        }
+        Ok(())
+    }
+
+    pub fn resolve_dispute(
+        env: Env,
+        remittance_id: u64,
+        in_favour_of_sender: bool,
+    ) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "resolve_dispute", (remittance_id, in_favour_of_sender));
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        if remittance.status != RemittanceStatus::Disputed {
+            return Err(ContractError::NotDisputed);
+        }
+
+        let (recipient, payout) = if in_favour_of_sender {
+            remittance.status = RemittanceStatus::Cancelled;
+            (remittance.sender.clone(), remittance.amount)
+        } else {
+            let fee_breakdown = fee_service::calculate_fees_with_breakdown(
+                &env,
+                remittance.amount,
+                Some(&remittance.token),
+                None,
+            )?;
+            remittance.status = RemittanceStatus::Completed;
+            remittance.completed_at = Some(env.ledger().timestamp());
+            (remittance.agent.clone(), fee_breakdown.net_amount)
+        };
+        set_remittance(&env, remittance_id, &remittance);
+
+        let token_client = token::Client::new(&env, &remittance.token);
+        token_transfer::transfer_for(
+            &token_client,
+            remittance_id,
+            &env.current_contract_address(),
+            &recipient,
+            payout,
+        )?;
+        if in_favour_of_sender {
+            insurance::compensate(&env, &remittance)?;
+            priority::refund(&env, &remittance)?;
+        } else {
+            priority::settle(&env, &token_client, &remittance, false)?;
+        }
+        emit_dispute_resolved(&env, remittance_id, caller, in_favour_of_sender);
+        Ok(())
+    }
+
+    /// Sets the dispute window duration (admin only).
+    ///
+    /// Senders have this many seconds after a payout is marked Failed to raise a dispute.
+    pub fn set_dispute_window(env: Env, seconds: u64) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_dispute_window", (seconds,));
+        storage::set_dispute_window(&env, seconds);
+        Ok(())
+    }
+
+    /// Returns the current dispute window in seconds.
+    pub fn get_dispute_window(env: Env) -> u64 {
+        storage::get_dispute_window(&env)
+    }
+
+    /// Reports delivery progress on a remittance before its final confirmation.
+    ///
+    /// Only the latest status is stored; each report is also emitted as an
+    /// event so senders can follow the whole sequence. Reporting the current
+    /// status again only refreshes its timestamp.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::Unauthorized` - `agent` is not the remittance's agent
+    /// * `ContractError::InvalidStatus` - Remittance is not Pending or Processing
+    /// * `ContractError::InvalidStateTransition` - `status` is behind the current one
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn update_delivery_status(
+        env: Env,
+        agent: Address,
+        remittance_id: u64,
+        status: DeliveryStatus,
+    ) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        if remittance.agent != agent {
+            return Err(ContractError::Unauthorized);
+        }
+        crate::storage::require_agent_authorized(&env, &agent)?;
+        if remittance.status != RemittanceStatus::Pending
+            && remittance.status != RemittanceStatus::Processing
+        {
+            return Err(ContractError::InvalidStatus);
+        }
+        if storage::get_delivery_update(&env, remittance_id)
+            .is_some_and(|current| status < current.status)
+        {
+            return Err(ContractError::InvalidStateTransition);
+        }
+
+        storage::set_delivery_update(
+            &env,
+            remittance_id,
+            &DeliveryUpdate {
+                status,
+                updated_at: env.ledger().timestamp(),
+            },
+        );
+        emit_delivery_status(&env, remittance_id, agent, status);
+        Ok(())
+    }
+
+    /// Returns the latest delivery status reported for a remittance, if any.
+    pub fn get_delivery_status(env: Env, remittance_id: u64) -> Option<DeliveryUpdate> {
+        storage::get_delivery_update(&env, remittance_id)
+    }
+
+    /// Confirms a partial payout for a remittance, disbursing `amount` to the agent.
+    ///
+    /// Large remittances can be split into multiple disbursements. Each call transfers
+    /// `amount` from escrow to the agent. When the total disbursed equals the net payout
+    /// (amount - fee), the remittance is automatically marked Completed.
+    ///
+    /// # Authorization
+    /// Requires authentication from the agent assigned to the remittance.
+    pub fn confirm_partial_payout(
+        env: Env,
+        remittance_id: u64,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        if amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        crate::storage::require_agent_authorized(&env, &remittance.agent)?;
+
+        if remittance.status != RemittanceStatus::Pending
+            && remittance.status != RemittanceStatus::Processing
+        {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        // Partial disbursement pays only the cash-out agent, so routes are excluded
+        if storage::get_remittance_route(&env, remittance_id).is_some() {
+            return Err(ContractError::InvalidRoute);
+        }
+        causes::require_no_pledge(&env, remittance_id)?;
+
+        // Enforce per-agent daily cap
+        storage::check_and_record_agent_withdrawal(&env, &remittance.agent, amount)?;
+
+        let fee_breakdown = fee_service::calculate_fees_with_breakdown(&env, remittance.amount, None, None)?;
+        let net_payout = fee_breakdown.net_amount;
+
+        let already_disbursed = storage::get_disbursed_amount(&env, remittance_id);
+        let remaining = net_payout
+            .checked_sub(already_disbursed)
+            .ok_or(ContractError::Underflow)?;
+
+        if amount > remaining {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        // Move to Processing on first partial disbursement
+        if remittance.status == RemittanceStatus::Pending {
+            crate::transitions::transition_status(&env, &mut remittance, RemittanceStatus::Processing)?;
+            storage::add_processing_volume(&env, remittance.amount)?;
+            corridor_utilization::release_pending(&env, &remittance);
+            agent_tiers::release_pending(&env, &remittance);
+        }
+
+        storage::add_disbursed_amount(&env, remittance_id, amount)?;
+        let new_total = already_disbursed.checked_add(amount).ok_or(ContractError::Overflow)?;
+        let remaining_amount = net_payout.saturating_sub(new_total);
+
+        storage::append_partial_payout_record(&env, remittance_id, crate::PartialPayoutRecord {
+            amount,
+            total_disbursed: new_total,
+            remaining_amount,
+            timestamp: env.ledger().timestamp(),
+            ledger_sequence: env.ledger().sequence(),
+        });
+
+        // If fully disbursed, collect fee and complete
+        let completed = new_total >= net_payout;
+        if completed {
+            // Update accumulated fees with overflow protection and automatic flush
+            let commission =
+                credit_commission(&env, &remittance.agent, &remittance.token, remittance.fee)?;
+            add_token_fee(&env, &remittance.token, remittance.fee - commission)?;
+
+            // Move volume from in-flight to completed
+            storage::sub_processing_volume(&env, remittance.amount)?;
+            storage::add_completed_volume(&env, remittance.amount)?;
+
+            crate::transitions::transition_status(&env, &mut remittance, RemittanceStatus::Completed)?;
+        }
+        set_remittance(&env, remittance_id, &remittance);
+
+        let token_client = token::Client::new(&env, &remittance.token);
+        token_transfer::transfer_for(
+            &token_client,
+            remittance_id,
+            &env.current_contract_address(),
+            &remittance.agent,
+            amount,
+        )?;
+
+        if !emit_minimal_lifecycle(&env, &remittance) {
+            emit_partial_payout(&env, remittance_id, remittance.agent.clone(), amount, new_total, remaining_amount);
+        }
+        if completed {
+            priority::settle(&env, &token_client, &remittance, false)?;
+            observers::notify_observers(&env, ObserverEvent::Completed, &remittance);
+            performance::record(&env, PerformanceEvent::Completed, &remittance);
+            emit_tag_events(&env, &remittance, symbol_short!("complete"));
+            if !emit_minimal_lifecycle(&env, &remittance) {
+                emit_remittance_completed(
+                    &env,
+                    remittance_id,
+                    remittance.sender.clone(),
+                    remittance.agent.clone(),
+                    remittance.settlement_latency().unwrap_or(0),
+                    remittance.processing_latency(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets a per-agent daily withdrawal cap (admin only).
+    ///
+    /// The agent may not withdraw more than `cap` USDC in any rolling 24-hour window.
+    /// Set `cap` to 0 to remove the cap.
+    pub fn set_agent_daily_cap(env: Env, agent: Address, cap: i128) -> Result<(), ContractError> {
+        if cap < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_agent_daily_cap", (agent.clone(), cap));
+        storage::set_agent_daily_cap(&env, &agent, cap);
+        emit_agent_cap_set(&env, agent, cap, caller);
+        Ok(())
+    }
+
+    /// Returns the per-agent daily withdrawal cap (0 = no cap).
+    pub fn get_agent_daily_cap(env: Env, agent: Address) -> i128 {
+        storage::get_agent_daily_cap(&env, &agent)
+    }
+
+    /// Sets an agent's tier, which bounds its pending value and decides access
+    /// to large remittances and open-pool claims (Admin or Operator).
+    ///
+    /// Lowering a tier does not affect remittances already assigned; the new
+    /// limits apply to subsequent assignments.
+    pub fn set_agent_tier(
+        env: Env,
+        caller: Address,
+        agent: Address,
+        tier: AgentTier,
+    ) -> Result<(), ContractError> {
+        storage::require_admin_or_role(&env, &caller, &Role::Operator)?;
+        admin_log::record(&env, &caller, "set_agent_tier", (agent.clone(), tier));
+        if !storage::is_agent_registered(&env, &agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+        storage::set_agent_tier(&env, &agent, tier);
+        emit_agent_tier_updated(&env, agent, tier, caller);
+        Ok(())
+    }
+
+    /// Caps the total value of Pending remittances assignable to an agent
+    /// (Admin only). Set `cap` to 0 to remove the cap.
+    ///
+    /// The cap applies alongside the agent tier's pending bound, and only to
+    /// subsequent assignments.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidAmount` - Negative cap
+    /// * `ContractError::AgentNotRegistered` - Agent is not registered
+    pub fn set_agent_capacity(
+        env: Env,
+        caller: Address,
+        agent: Address,
+        cap: i128,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_agent_capacity", (agent.clone(), cap));
+        if cap < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        if !storage::is_agent_registered(&env, &agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+        storage::set_agent_capacity(&env, &agent, cap);
+        emit_agent_capacity_set(&env, agent, cap, caller);
+        Ok(())
+    }
+
+    /// Returns an agent's pending-value cap, if one is set.
+    pub fn get_agent_capacity(env: Env, agent: Address) -> Option<i128> {
+        storage::get_agent_capacity(&env, &agent)
+    }
+
+    /// Records whether an agent's business verification (KYB) is complete (Admin or Compliance).
+    ///
+    /// Unverified agents are capped at `UNVERIFIED_AGENT_MAX_REMITTANCE` per
+    /// remittance and `UNVERIFIED_AGENT_DAILY_LIMIT` per day, and are left out
+    /// of `get_corridor_agents`.
+    pub fn set_agent_kyb_verified(
+        env: Env,
+        caller: Address,
+        agent: Address,
+        verified: bool,
+    ) -> Result<(), ContractError> {
+        storage::require_admin_or_role(&env, &caller, &Role::Compliance)?;
+        admin_log::record(&env, &caller, "set_agent_kyb_verified", (agent.clone(), verified));
+        if !storage::is_agent_registered(&env, &agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+        storage::set_agent_kyb_verified(&env, &agent, verified);
+        emit_agent_kyb_updated(&env, agent, verified, caller);
+        Ok(())
+    }
+
+    /// Returns whether an agent's KYB verification is complete.
+    pub fn is_agent_kyb_verified(env: Env, agent: Address) -> bool {
+        storage::is_agent_kyb_verified(&env, &agent)
+    }
+
+    /// Returns an agent's tier. Agents never assigned one are Basic.
+    pub fn get_agent_tier(env: Env, agent: Address) -> AgentTier {
+        storage::get_agent_tier(&env, &agent)
+    }
+
+    /// Returns the privileges granted by an agent's current tier.
+    pub fn get_agent_tier_privileges(env: Env, agent: Address) -> AgentTierPrivileges {
+        agent_tiers::privileges(&env, &agent)
+    }
+
+    /// Returns the total value of Pending remittances assigned to an agent.
+    pub fn get_agent_pending_value(env: Env, agent: Address) -> i128 {
+        storage::get_agent_pending_value(&env, &agent)
+    }
+
+    /// Locks `amount` of a token as the agent's stake, making it eligible for
+    /// working-capital advances in that token. Returns the new stake.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the registered agent.
+    pub fn stake_agent(
+        env: Env,
+        agent: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, ContractError> {
+        crate::storage::require_agent_authorized(&env, &agent)?;
+        if !is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
+        }
+
+        let staked = advances::stake(&env, &agent, &token, amount)?;
+        emit_agent_stake_updated(&env, agent, token, amount, staked);
+        Ok(staked)
+    }
+
+    /// Returns `amount` of the agent's stake. Returns the remaining stake.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::AdvanceOutstanding` - Agent has an outstanding advance in the token
+    /// * `ContractError::InvalidAmount` - Amount is not positive or exceeds the stake
+    /// * `ContractError::AgentStakeRequired` - The agent has pending remittances
+    ///   and the stake would fall below the collateral requirement
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn unstake_agent(
+        env: Env,
+        agent: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, ContractError> {
+        agent.require_auth();
+        let remaining = storage::get_agent_stake(&env, &agent, &token).saturating_sub(amount);
+        collateral::check_unstake(&env, &agent, &token, remaining)?;
+
+        let staked = advances::unstake(&env, &agent, &token, amount)?;
+        emit_agent_stake_updated(&env, agent, token, -amount, staked);
+        Ok(staked)
+    }
+
+    /// Returns the stake an agent has locked in a token.
+    pub fn get_agent_stake(env: Env, agent: Address, token: Address) -> i128 {
+        storage::get_agent_stake(&env, &agent, &token)
+    }
+
+    /// Requires agents to stake at least `min_stake` of the default token
+    /// before they are assigned remittances above `threshold` (Admin only).
+    /// A `min_stake` of zero removes the requirement.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidAmount` - Negative threshold or minimum stake
+    pub fn set_collateral_requirement(
+        env: Env,
+        caller: Address,
+        threshold: i128,
+        min_stake: i128,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_collateral_requirement", (threshold, min_stake));
+        if threshold < 0 || min_stake < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        if min_stake == 0 {
+            storage::remove_collateral_requirement(&env);
+        } else {
+            storage::set_collateral_requirement(
+                &env,
+                &CollateralRequirement {
+                    threshold,
+                    min_stake,
+                },
+            );
+        }
+        emit_collateral_requirement_updated(&env, threshold, min_stake);
+        Ok(())
+    }
+
+    /// Returns the collateral requirement in force, if any.
+    pub fn get_collateral_requirement(env: Env) -> Option<CollateralRequirement> {
+        storage::get_collateral_requirement(&env)
+    }
+
+    /// Slashes `amount` of the stake of a remittance's agent, in the
+    /// remittance's token, and pays it to the remittance's sender as
+    /// compensation. Returns the agent's remaining stake.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::RemittanceNotFound` - Remittance ID does not exist
+    /// * `ContractError::InvalidAmount` - Amount is not positive or exceeds the stake
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin or a `ClaimResolver`.
+    pub fn slash_agent(
+        env: Env,
+        caller: Address,
+        remittance_id: u64,
+        amount: i128,
+    ) -> Result<i128, ContractError> {
+        storage::require_admin_or_role(&env, &caller, &Role::ClaimResolver)?;
+        admin_log::record(&env, &caller, "slash_agent", (remittance_id, amount));
+
+        let remittance = get_remittance(&env, remittance_id)?;
+        let staked = collateral::slash(&env, &remittance, amount)?;
+        emit_agent_slashed(
+            &env,
+            remittance_id,
+            remittance.agent,
+            remittance.sender,
+            remittance.token,
+            amount,
+            staked,
+        );
+        Ok(staked)
+    }
+
+    /// Tops up the advance pool for a whitelisted token (Admin only).
+    pub fn fund_advance_pool(
+        env: Env,
+        caller: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "fund_advance_pool", (token.clone(), amount));
+        if amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        if !is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
+        }
+        token_transfer::transfer(
+            &token::Client::new(&env, &token),
+            &caller,
+            &env.current_contract_address(),
+            amount,
+        )?;
+        let balance = storage::get_advance_pool(&env, &token)
+            .checked_add(amount)
+            .ok_or(ContractError::Overflow)?;
+        storage::set_advance_pool(&env, &token, balance);
+        emit_advance_pool_funded(&env, token, amount, caller);
+        Ok(())
+    }
+
+    /// Returns the advance pool balance held for a token.
+    pub fn get_advance_pool(env: Env, token: Address) -> i128 {
+        storage::get_advance_pool(&env, &token)
+    }
+
+    /// Draws a working-capital advance against the agent's unsettled remittances.
+    ///
+    /// `collateral` lists the agent's Pending or Processing remittances in
+    /// `token` backing the advance. The advance is repaid automatically from
+    /// the agent's next direct payouts in the token and must be cleared within
+    /// `ADVANCE_TERM_SECONDS`, after which it can be liquidated against the stake.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::AdvanceNotEligible` - Reputation too low or no stake in the token
+    /// * `ContractError::AdvanceOutstanding` - Agent already has an advance in the token
+    /// * `ContractError::AdvanceLimitExceeded` - Amount exceeds the collateral or stake limit
+    /// * `ContractError::InsufficientAdvanceLiquidity` - Pool cannot cover the amount
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the registered agent.
+    pub fn draw_advance(
+        env: Env,
+        agent: Address,
+        token: Address,
+        collateral: Vec<u64>,
+        amount: i128,
+    ) -> Result<AgentAdvance, ContractError> {
+        crate::storage::require_agent_authorized(&env, &agent)?;
+
+        let advance = advances::draw(&env, &agent, &token, collateral, amount)?;
+        emit_advance_drawn(&env, agent, token, amount, advance.due_at);
+        Ok(advance)
+    }
+
+    /// Returns the maximum advance an agent could draw against `collateral`.
+    pub fn get_advance_limit(
+        env: Env,
+        agent: Address,
+        token: Address,
+        collateral: Vec<u64>,
+    ) -> Result<i128, ContractError> {
+        advances::limit(&env, &agent, &token, &collateral)
+    }
+
+    /// Returns an agent's outstanding advance in a token, if any.
+    pub fn get_agent_advance(env: Env, agent: Address, token: Address) -> Option<AgentAdvance> {
+        storage::get_agent_advance(&env, &agent, &token)
+    }
+
+    /// Liquidates a past-due advance against the agent's stake (Admin only).
+    ///
+    /// Returns the amount of stake seized into the advance pool. Any part of
+    /// the advance the stake does not cover is written off.
+    pub fn liquidate_advance(
+        env: Env,
+        caller: Address,
+        agent: Address,
+        token: Address,
+    ) -> Result<i128, ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "liquidate_advance", (agent.clone(), token.clone()));
+
+        let outstanding = storage::get_agent_advance(&env, &agent, &token)
+            .map(|advance| advance.outstanding)
+            .unwrap_or(0);
+        let seized = advances::liquidate(&env, &agent, &token)?;
+        emit_advance_liquidated(&env, agent, token, seized, outstanding - seized);
+        Ok(seized)
+    }
+
+    pub fn get_agent_stats(env: Env, agent: Address) -> AgentStats {
+        crate::storage::get_agent_stats(&env, &agent)
+    }
+
+    pub fn get_agent_reputation(env: Env, agent: Address) -> u32 {
+        let stats = crate::storage::get_agent_stats(&env, &agent);
+        crate::storage::compute_agent_reputation(&stats)
+    }
+
+    /// Returns settlement performance metrics for an agent, or platform-wide
+    /// when `agent` is `None`.
+    ///
+    /// Includes average and p95 time-to-settlement plus completion, expiry,
+    /// and dispute rates in basis points of created remittances.
+    pub fn get_performance_stats(env: Env, agent: Option<Address>) -> PerformanceStats {
+        performance::stats(&env, agent.as_ref())
+    }
+
+    /// Returns how many remittances are in each status.
+    ///
+    /// The counts are maintained on every status change, so remittances last
+    /// written before counting was introduced are not included.
+    pub fn get_counts(env: Env) -> RemittanceCounts {
+        health::remittance_counts(&env)
+    }
+
+    /// Returns created volume, settled volume, and fee revenue for each of the
+    /// last `days` days, oldest first and ending with today.
+    ///
+    /// At most `VOLUME_HISTORY_BUCKETS` days are kept; longer requests are
+    /// truncated to that window.
+    pub fn get_volume_history(env: Env, days: u32) -> Vec<VolumeBucket> {
+        volume_history::history(&env, days)
+    }
+
+    /// Returns the credit-signal summary a sender or agent is accumulating,
+    /// which may belong to a period that has already closed.
+    pub fn get_activity_summary(
+        env: Env,
+        party: Address,
+        role: CreditParty,
+    ) -> Option<ActivitySummary> {
+        storage::get_activity_summary(&env, &party, role)
+    }
+
+    /// Emits a party's credit-signal summary once its period has closed.
+    ///
+    /// Summaries are normally emitted on the party's next activity; this lets
+    /// anyone publish the last period of a party that has gone quiet. Returns
+    /// the published summary, or `None` if there was nothing to publish.
+    pub fn publish_credit_signal(
+        env: Env,
+        party: Address,
+        role: CreditParty,
+    ) -> Option<ActivitySummary> {
+        credit_signals::publish(&env, &party, role)
+    }
+
+    pub fn finalize_remittance(
+        env: Env,
+        caller: Address,
+        remittance_id: u64,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "finalize_remittance", (remittance_id,));
+        let remittance = get_remittance(&env, remittance_id)?;
+
+        // Verify remittance is in a valid state (Completed)
+        if remittance.status != RemittanceStatus::Completed {
+            return Err(ContractError::InvalidStateTransition);
+        }
+
+        // Remittance is already completed, no further action needed
+        Ok(())
+    }
+
+    /// Cancels a pending remittance and refunds the sender.
+    ///
+    /// Returns the full remittance amount to the sender and marks the remittance
+    /// as cancelled. Can only be called by the original sender.
+    ///
+    /// The sender must wait out the cancel cooldown after creation. Once the
+    /// agent has accepted the remittance by reporting a delivery status, the
+    /// sender can only cancel alone after its SLA deadline has passed. Until
+    /// then, cancel with the agent through `mutual_cancel_remittance`, or wait
+    /// for the agent to mark the payout failed and dispute it. Neither
+    /// restriction applies once the agent has rejected the remittance.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to cancel
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Remittance successfully cancelled and refunded
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
+    /// * `Err(ContractError::CancelCooldownActive)` - The cancel cooldown has not elapsed
+    /// * `Err(ContractError::CancelWindowClosed)` - The agent has accepted and the SLA
+    ///   has not been breached
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender address who created the remittance.
+    pub fn cancel_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        // Centralized validation before business logic (returns remittance to avoid re-read)
+        let remittance = validate_cancel_remittance_request(&env, remittance_id)?;
+
+        remittance.sender.require_auth();
+        check_sender_may_cancel(&env, &remittance)?;
+
+        cancel_and_refund(&env, remittance)
+    }
+
+    /// Cancels several pending remittances of one sender and refunds them.
+    ///
+    /// Each remittance is cancelled exactly as `cancel_remittance` would, with
+    /// its own `remit`/`cancel` event, followed by one `batch`/`cancelled`
+    /// event listing the IDs. If any remittance cannot be cancelled, none are.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidBatchSize` - Batch is empty or exceeds `MAX_BATCH_SIZE`
+    /// * `ContractError::Unauthorized` - A remittance belongs to another sender
+    /// * `ContractError::InvalidStatus` - A remittance is not Pending, or an ID
+    ///   appears more than once
+    /// * Any other error returned by `cancel_remittance`
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `sender`.
+    pub fn batch_cancel(
+        env: Env,
+        sender: Address,
+        remittance_ids: Vec<u64>,
+    ) -> Result<(), ContractError> {
+        if remittance_ids.is_empty() || remittance_ids.len() > MAX_BATCH_SIZE {
+            return Err(ContractError::InvalidBatchSize);
+        }
+        sender.require_auth();
+
+        for remittance_id in remittance_ids.iter() {
+            let remittance = validate_cancel_remittance_request(&env, remittance_id)?;
+            if remittance.sender != sender {
+                return Err(ContractError::Unauthorized);
+            }
+            check_sender_may_cancel(&env, &remittance)?;
+            cancel_and_refund(&env, remittance)?;
+        }
+
+        emit_batch_cancelled(&env, sender, remittance_ids);
+        Ok(())
+    }
+
+    /// Cancels a pending remittance with the agent's agreement and refunds
+    /// the sender.
+    ///
+    /// Unlike `cancel_remittance`, this is available after the agent has
+    /// accepted the remittance.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::RemittanceNotFound` - Remittance ID does not exist
+    /// * `ContractError::InvalidStatus` - Remittance is not in Pending status
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from both the sender and the agent.
+    pub fn mutual_cancel_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let remittance = validate_cancel_remittance_request(&env, remittance_id)?;
+
+        remittance.sender.require_auth();
+        remittance.agent.require_auth();
+
+        cancel_and_refund(&env, remittance)
+    }
+
+    /// Rejects a pending remittance assigned to `agent`.
+    ///
+    /// The remittance stays Pending, but its sender may cancel it at once,
+    /// regardless of the cancel cooldown, or reassign it. Reassignment clears
+    /// the rejection.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::Unauthorized` - `agent` is not the remittance's agent
+    /// * `ContractError::InvalidStatus` - Remittance is not Pending
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn reject_remittance(
+        env: Env,
+        agent: Address,
+        remittance_id: u64,
+    ) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        if remittance.agent != agent {
+            return Err(ContractError::Unauthorized);
+        }
+        crate::storage::require_agent_authorized(&env, &agent)?;
+        if remittance.status != RemittanceStatus::Pending {
+            return Err(ContractError::InvalidStatus);
+        }
+
+        storage::set_agent_rejection(&env, remittance_id, env.ledger().timestamp());
+        emit_remittance_rejected(&env, remittance_id, remittance.sender, agent);
+        Ok(())
+    }
+
+    /// Returns when the agent rejected a remittance, if it has.
+    pub fn get_agent_rejection(env: Env, remittance_id: u64) -> Option<u64> {
+        storage::get_agent_rejection(&env, remittance_id)
+    }
+
+    /// Sets how many seconds after creation a sender must wait before
+    /// cancelling a remittance. Pass 0 to disable.
+    ///
+    /// # Authorization
+    ///
+    /// Requires admin authentication.
+    pub fn set_cancel_cooldown(env: Env, caller: Address, seconds: u64) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_cancel_cooldown", (seconds,));
+        storage::set_cancel_cooldown(&env, seconds);
+        Ok(())
+    }
+
+    /// Returns the cancel cooldown in seconds (0 when disabled).
+    pub fn get_cancel_cooldown(env: Env) -> u64 {
+        storage::get_cancel_cooldown(&env)
+    }
+
+    /// Moves a pending remittance to another SwiftRemit instance.
+    ///
+    /// Cancels the remittance here, transfers its escrow to `target`, and
+    /// recreates it there for `agent` with the same amount, fee, and expiry,
+    /// all in one transaction. Use this when the sender picked the wrong
+    /// corridor instance. Both instances must trust each other; see the
+    /// `forwarding` module. Returns the remittance ID on `target`.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the remittance sender.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidStatus` - The remittance is not Pending
+    /// * `ContractError::UntrustedInstance` - Either instance does not trust the other
+    /// * `ContractError::InvalidRoute` - The remittance is routed through intermediaries
+    /// * Any error `target` returns while recreating the remittance
+    pub fn forward_remittance(
+        env: Env,
+        remittance_id: u64,
+        target: Address,
+        agent: Address,
+    ) -> Result<u64, ContractError> {
+        let remittance = validate_cancel_remittance_request(&env, remittance_id)?;
+        remittance.sender.require_auth();
+        let sender = remittance.sender.clone();
+
+        let target_id = forwarding::forward(&env, remittance, &target, agent)?;
+        emit_remittance_forwarded(&env, remittance_id, sender, target, target_id);
+
+        if let Some(idem_key) = storage::take_remittance_idempotency_key(&env, remittance_id) {
+            storage::remove_idempotency_record(&env, &idem_key);
+        }
+        transaction_controller::TransactionController::post_cancel_cleanup(&env, remittance_id)?;
+        Ok(target_id)
+    }
+
+    /// Recreates a remittance forwarded by the trusted instance `source`.
+    ///
+    /// Called by `source` from its `forward_remittance` after transferring the
+    /// escrow here. Returns the new remittance ID.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `source`, which must be a trusted instance.
+    pub fn accept_forwarded(
+        env: Env,
+        source: Address,
+        remittance: ForwardedRemittance,
+    ) -> Result<u64, ContractError> {
+        let source_id = remittance.source_id;
+        let created = forwarding::accept(&env, &source, remittance)?;
+        emit_remittance_received(&env, created.id, created.sender, source, source_id);
+        Ok(created.id)
+    }
+
+    /// Returns the instance and remittance ID a remittance was forwarded to.
+    pub fn get_forwarded_to(env: Env, remittance_id: u64) -> Option<(Address, u64)> {
+        storage::get_forwarded_to(&env, remittance_id)
+    }
+
+    /// Returns the instance and remittance ID a remittance was forwarded from.
+    pub fn get_forwarded_from(env: Env, remittance_id: u64) -> Option<(Address, u64)> {
+        storage::get_forwarded_from(&env, remittance_id)
+    }
+
+    /// Expires a pending remittance past its expiry and refunds the sender.
+    ///
+    /// Callable by anyone, so funds never stay locked waiting for the sender.
+    /// The remittance moves to the terminal `Expired` status, so it can no
+    /// longer be paid out. Any expedite fees are refunded with it.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::RemittanceNotFound` - Remittance ID does not exist
+    /// * `ContractError::InvalidStatus` - Remittance is not Pending
+    /// * `ContractError::RemittanceNotExpired` - No expiry, or not yet reached
+    pub fn expire_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        if remittance.status != RemittanceStatus::Pending {
+            return Err(ContractError::InvalidStatus);
+        }
+        let expiry = remittance
+            .reached_expiry(&env)
+            .ok_or(ContractError::RemittanceNotExpired)?;
+        expire_pending(&env, remittance, expiry)
+    }
+
+    /// Expires pending remittances in batch and refunds their senders.
+    ///
+    /// Callable by anyone. Each provided remittance ID is processed independently
+    /// with the same rules as `expire_remittance`, ending in the `Expired`
+    /// status. Non-existent, non-pending, or non-expired remittances are skipped.
+    pub fn process_expired_remittances(
+        env: Env,
+        remittance_ids: Vec<u64>,
+    ) -> Result<Vec<u64>, ContractError> {
+        if remittance_ids.len() > get_max_expired_batch_size(&env) {
+            return Err(ContractError::InvalidBatchSize);
+        }
+
+        let mut processed_ids = Vec::new(&env);
+
+        for i in 0..remittance_ids.len() {
+            let remittance_id = remittance_ids.get_unchecked(i);
+            let remittance = match get_remittance(&env, remittance_id) {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+
+            if remittance.status != RemittanceStatus::Pending {
+                continue;
+            }
+            let Some(expiry) = remittance.reached_expiry(&env) else {
+                continue;
+            };
+
+            expire_pending(&env, remittance, expiry)?;
+            processed_ids.push_back(remittance_id);
+        }
+
+        Ok(processed_ids)
+    }
+
+    /// Withdraws accumulated platform fees to a specified address.
+    ///
+    /// Transfers all accumulated fees to the recipient address and resets the
+    /// fee counter to zero. Only the contract admin can withdraw fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `to` - Address to receive the withdrawn fees
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fees successfully withdrawn
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `Err(ContractError::NoFeesToWithdraw)` - No fees available (balance is zero or negative)
+    /// * `Err(ContractError::InvalidAddress)` - Recipient address validation failed
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the contract admin.
+    pub fn withdraw_fees(env: Env, to: Address) -> Result<(), ContractError> {
+        // Centralized validation before business logic (returns fees to avoid re-read)
+        let fees = validate_withdraw_fees_request(&env, &to)?;
+
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "withdraw_fees", (to.clone(),));
+
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        set_accumulated_fees(&env, 0);
+        token_transfer::transfer(&token_client, &env.current_contract_address(), &to, fees)?;
+
+        emit_fees_withdrawn(&env, caller, to.clone(), usdc_token, fees);
+
+        log_withdraw_fees(&env, &to, fees);
+
+        Ok(())
+    }
+
+    /// Withdraws the platform fees accumulated in `token` to `to` (admin only).
+    ///
+    /// Remittances in whitelisted tokens other than the default one collect
+    /// their fees per token; this withdraws them in the asset they were paid in.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidAddress` - `to` is this contract
+    /// * `ContractError::NoFeesToWithdraw` - No fees accumulated in `token`
+    pub fn withdraw_token_fees(
+        env: Env,
+        caller: Address,
+        token: Address,
+        to: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "withdraw_token_fees", (token.clone(), to.clone()));
+        if to == env.current_contract_address() {
+            return Err(ContractError::InvalidAddress);
+        }
+        validate_fees_available(token_fees(&env, &token)?)?;
+
+        let fees = take_token_fees(&env, &token)?;
+        token_transfer::transfer(
+            &token::Client::new(&env, &token),
+            &env.current_contract_address(),
+            &to,
+            fees,
+        )?;
+        emit_fees_withdrawn(&env, caller, to, token, fees);
+        Ok(())
+    }
+
+    /// Sets the share of each platform fee credited to the settling agent as
+    /// commission, in basis points (Admin or FeeManager). Defaults to 0.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidFeeBps` - `bps` exceeds 10000
+    pub fn set_agent_commission_bps(
+        env: Env,
+        caller: Address,
+        bps: u32,
+    ) -> Result<(), ContractError> {
+        storage::require_admin_or_role(&env, &caller, &Role::FeeManager)?;
+        admin_log::record(&env, &caller, "set_agent_commission_bps", (bps,));
+        if bps > MAX_FEE_BPS {
+            return Err(ContractError::InvalidFeeBps);
+        }
+        let old_bps = storage::get_agent_commission_bps(&env);
+        storage::set_agent_commission_bps(&env, bps);
+        emit_commission_rate_updated(&env, caller, old_bps, bps);
+        Ok(())
+    }
+
+    /// Returns the agent commission rate, in basis points of the platform fee.
+    pub fn get_agent_commission_bps(env: Env) -> u32 {
+        storage::get_agent_commission_bps(&env)
+    }
+
+    /// Returns an agent's unwithdrawn commission in `token`.
+    pub fn get_agent_commission(env: Env, agent: Address, token: Address) -> i128 {
+        storage::get_agent_commission(&env, &agent, &token)
+    }
+
+    /// Withdraws the agent's commission in `token` to the agent. Returns the
+    /// amount withdrawn.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::NoFeesToWithdraw` - No commission to withdraw
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent.
+    pub fn withdraw_commission(
+        env: Env,
+        agent: Address,
+        token: Address,
+    ) -> Result<i128, ContractError> {
+        agent.require_auth();
+        let amount = storage::get_agent_commission(&env, &agent, &token);
+        if amount <= 0 {
+            return Err(ContractError::NoFeesToWithdraw);
+        }
+
+        storage::set_agent_commission(&env, &agent, &token, 0);
+        token_transfer::transfer(
+            &token::Client::new(&env, &token),
+            &env.current_contract_address(),
+            &agent,
+            amount,
+        )?;
+        emit_commission_withdrawn(&env, agent, token, amount);
+        Ok(amount)
+    }
+
+    /// Withdraws accumulated integrator fees to a specified address.
+    ///
+    /// Transfers all accumulated integrator fees to the recipient and resets the
+    /// counter to zero. Only the designated integrator can withdraw their own fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `integrator` - Address of the integrator requesting withdrawal (must authenticate)
+    /// * `to` - Address to receive the withdrawn fees
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Fees successfully withdrawn
+    /// * `Err(ContractError::NoFeesToWithdraw)` - No integrator fees available
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the integrator address.
+    pub fn withdraw_integrator_fees(
+        env: Env,
+        integrator: Address,
+        to: Address,
+    ) -> Result<(), ContractError> {
+        let fees = storage::get_accumulated_integrator_fees(&env);
+        if fees <= 0 {
+            return Err(ContractError::NoFeesToWithdraw);
+        }
+
+        integrator.require_auth();
+
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        storage::set_accumulated_integrator_fees(&env, 0);
+        token_transfer::transfer(&token_client, &env.current_contract_address(), &to, fees)?;
+
+        emit_integrator_fees_withdrawn(&env, integrator, to, usdc_token, fees);
+
+        Ok(())
+    }
+
+    /// Retrieves a remittance record by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - ID of the remittance to retrieve
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Remittance)` - The remittance record
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    pub fn get_remittance(env: Env, remittance_id: u64) -> Result<Remittance, ContractError> {
+        if storage::is_minimal_disclosure(&env, remittance_id) {
+            return Err(ContractError::Unauthorized);
+        }
+        get_remittance(&env, remittance_id)
+    }
+
+    /// Marks a Pending remittance as minimal disclosure.
+    ///
+    /// Its later lifecycle events carry only the remittance ID and status,
+    /// and `get_remittance` no longer returns it; the sender, agent, and admin
+    /// read it through `get_remittance_as`. Events emitted before the flag was
+    /// set are unaffected, and the flag cannot be cleared.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the remittance sender.
+    pub fn set_minimal_disclosure(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        remittance.sender.require_auth();
+        if remittance.status != RemittanceStatus::Pending {
+            return Err(ContractError::InvalidStatus);
+        }
+        storage::set_minimal_disclosure(&env, remittance_id);
+        Ok(())
+    }
+
+    /// Returns whether a remittance is marked as minimal disclosure.
+    pub fn is_minimal_disclosure(env: Env, remittance_id: u64) -> bool {
+        storage::is_minimal_disclosure(&env, remittance_id)
+    }
+
+    /// Returns a remittance to one of its parties, including minimal-disclosure ones.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `caller`, who must be the remittance's
+    /// sender or agent, or the admin.
+    pub fn get_remittance_as(
+        env: Env,
+        caller: Address,
+        remittance_id: u64,
+    ) -> Result<Remittance, ContractError> {
+        caller.require_auth();
+        let remittance = get_remittance(&env, remittance_id)?;
+        if caller != remittance.sender
+            && caller != remittance.agent
+            && !crate::storage::is_admin(&env, &caller)
+        {
+            return Err(ContractError::Unauthorized);
+        }
+        Ok(remittance)
+    }
+
+    /// Retrieves a remittance in whichever schema version it is stored in.
+    ///
+    /// Records written before the V2 upgrade are returned as
+    /// `VersionedRemittance::V1` without being migrated.
+    pub fn get_remittance_any(
+        env: Env,
+        remittance_id: u64,
+    ) -> Result<VersionedRemittance, ContractError> {
+        storage::get_remittance_any(&env, remittance_id)
+    }
+
+    /// Returns a page of a sender's remittance records, oldest first.
+    ///
+    /// Backed by a per-sender index written at creation, so wallets can list
+    /// a sender's history without an off-chain indexer. Uses the same cursor
+    /// convention as `get_remittances_by_status`.
+    pub fn get_remittances_by_sender(
+        env: Env,
+        sender: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<RemittancePage, ContractError> {
+        let ids = storage::get_sender_remittances(&env, &sender, cursor, limit);
+        Ok(storage::load_remittances(&env, ids)?.into())
+    }
+
+    /// Returns a page of the Pending remittances assigned to `agent`, oldest
+    /// first, so agent apps can poll for work without an off-chain indexer.
+    ///
+    /// Uses the same cursor convention as `get_remittances_by_status`, and
+    /// like it stores one ID per ledger entry, so a busy agent's queue never
+    /// outgrows an entry. A remittance leaves the queue once it is no longer
+    /// Pending or is reassigned to another agent.
+    pub fn get_pending_for_agent(
+        env: Env,
+        agent: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<RemittancePage, ContractError> {
+        let ids = storage::get_agent_pending_queue(&env, &agent, cursor, limit);
+        Ok(storage::load_remittances(&env, ids)?.into())
+    }
+
+    /// Returns a page of remittance IDs currently in the given status.
+    ///
+    /// Backed by per-status indexes maintained on every status change, so
+    /// operational queries such as "all pending remittances" do not need to
+    /// scan the full ID range. IDs are returned in the order they entered the
+    /// status, and entries never move, so a remittance that stays in the
+    /// status while a client pages is returned exactly once. A page may hold
+    /// fewer than `limit` IDs while `has_more` is set. Pass `0` as the first
+    /// cursor and the returned `next_cursor` for subsequent pages.
+    pub fn get_remittances_by_status(
+        env: Env,
+        status: RemittanceStatus,
+        cursor: u32,
+        limit: u32,
+    ) -> IdPage {
+        storage::get_status_index(&env, &status, cursor, limit).into()
+    }
+
+    /// Returns a page of currently registered agent addresses.
+    ///
+    /// Uses the same cursor convention as `get_remittances_by_status`.
+    pub fn list_agents(env: Env, cursor: u32, limit: u32) -> AddressPage {
+        let agents = storage::get_agent_list(&env);
+        storage::paginate(&env, &agents, cursor, limit).into()
+    }
+
+    /// Returns a page of the IDs of every remittance assigned to `agent`,
+    /// oldest first, including ones later reassigned away.
+    ///
+    /// Uses the same cursor convention as `get_remittances_by_status`.
+    pub fn get_remittances_by_agent(env: Env, agent: Address, cursor: u32, limit: u32) -> IdPage {
+        storage::get_agent_remittances(&env, &agent, cursor, limit).into()
+    }
+
+    pub fn get_accumulated_fees(env: Env) -> Result<i128, ContractError> {
+        get_accumulated_fees(&env)
+    }
+
+    /// Returns the platform fees accumulated in `token`.
+    ///
+    /// For the default token this is the same as `get_accumulated_fees`.
+    pub fn get_token_fees(env: Env, token: Address) -> Result<i128, ContractError> {
+        token_fees(&env, &token)
+    }
+
+    pub fn get_accumulated_integrator_fees(env: Env) -> i128 {
+        storage::get_accumulated_integrator_fees(&env)
+    }
+
+    /// Returns the number of registered admins.
+    pub fn get_admin_count(env: Env) -> u32 {
+        storage::get_admin_count(&env)
+    }
+
+    /// Returns a page of the admin audit log, oldest entry first.
+    ///
+    /// `cursor` is an entry sequence number: pass `0` for the oldest entry
+    /// retained and the returned `next_cursor` for subsequent pages. Only the
+    /// latest `ADMIN_LOG_CAPACITY` privileged calls are kept.
+    pub fn get_admin_log(env: Env, cursor: u32, limit: u32) -> AdminLogPage {
+        admin_log::page(&env, cursor, limit).into()
+    }
+
+    /// Returns the total number of remittances ever created.
+    pub fn get_remittance_count(env: Env) -> u64 {
+        storage::get_total_remittance_count(&env)
+    }
+
+    /// Returns the cumulative volume of all completed remittances (original amounts).
+    pub fn get_total_volume(env: Env) -> i128 {
+        storage::get_total_completed_volume(&env)
+    }
+
+    /// Returns the total amount currently held in Processing (in-flight) remittances.
+    ///
+    /// This complements `get_total_volume` (which only counts completed remittances)
+    /// by exposing the volume of remittances that have been claimed by an agent but
+    /// not yet confirmed as paid out to the recipient.
+    pub fn get_in_flight_volume(env: Env) -> i128 {
+        storage::get_total_processing_volume(&env)
+    }
+
+    /// Checks whether an address currently has admin privileges.
+    pub fn is_admin(env: Env, address: Address) -> bool {
+        crate::storage::is_admin(&env, &address)
+    }
+
+    /// Adds a new admin. Caller must already be an admin.
+    pub fn add_admin(env: Env, caller: Address, new_admin: Address) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "add_admin", (new_admin.clone(),));
+
+        if crate::storage::is_admin(&env, &new_admin) {
+            return Err(ContractError::AdminAlreadyExists);
+        }
+
+        crate::storage::set_admin_role(&env, &new_admin, true);
+        assign_role(&env, &new_admin, &Role::Admin);
+
+        let count = storage::get_admin_count(&env);
+        let next = count.checked_add(1).ok_or(ContractError::Overflow)?;
+        storage::set_admin_count(&env, next);
+
+        emit_admin_added(&env, caller.clone(), new_admin.clone());
+        log_add_admin(&env, &caller, &new_admin);
+
+        Ok(())
+    }
+
+    /// Removes an admin. Caller must be an admin and at least one admin must remain.
+    pub fn remove_admin(
+        env: Env,
+        caller: Address,
+        admin_to_remove: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "remove_admin", (admin_to_remove.clone(),));
+
+        if !crate::storage::is_admin(&env, &admin_to_remove) {
+            return Err(ContractError::AdminNotFound);
+        }
+
+        let count = storage::get_admin_count(&env);
+        if count <= 1 {
+            return Err(ContractError::CannotRemoveLastAdmin);
+        }
+
+        crate::storage::set_admin_role(&env, &admin_to_remove, false);
+        remove_role(&env, &admin_to_remove, &Role::Admin);
+        storage::set_admin_count(&env, count - 1);
+
+        // Keep legacy single-admin storage aligned so legacy admin-gated paths remain operable.
+        if get_admin(&env)? == admin_to_remove {
+            set_admin(&env, &caller);
+        }
+
+        emit_admin_removed(&env, caller.clone(), admin_to_remove.clone());
+        log_remove_admin(&env, &caller, &admin_to_remove);
+
+        Ok(())
+    }
+
+    /// Checks if an address is registered as an agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `agent` - Address to check
+    ///
+    /// # Returns
+    ///
+    /// * `true` - Address is a registered agent
+    /// * `false` - Address is not registered
+    pub fn is_agent_registered(env: Env, agent: Address) -> bool {
+        is_agent_registered(&env, &agent)
+    }
+
+    /// Returns the KYC metadata hash stored for an agent, or `None` if not set.
+    pub fn get_agent_kyc_hash(env: Env, agent: Address) -> Option<soroban_sdk::BytesN<32>> {
+        get_agent_kyc_hash(&env, &agent)
+    }
+
+    /// Retrieves the current platform fee rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u32)` - Platform fee in basis points (1 bps = 0.01%)
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    pub fn get_platform_fee_bps(env: Env) -> Result<u32, ContractError> {
+        get_platform_fee_bps(&env)
+    }
+
+    /// Returns a detailed fee breakdown for a given amount and optional corridor.
+    ///
+    /// This function allows callers to preview the exact fee split before creating
+    /// a remittance. It supports both global fees and country-specific corridor fees.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `amount` - Transaction amount to calculate fees for (must be positive)
+    /// * `from_country` - Optional source country code (ISO 3166-1 alpha-2)
+    /// * `to_country` - Optional destination country code (ISO 3166-1 alpha-2)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FeeBreakdown)` - Complete fee breakdown with:
+    ///   - `amount`: Original transaction amount
+    ///   - `platform_fee`: Platform fee deducted
+    ///   - `protocol_fee`: Treasury/protocol fee deducted
+    ///   - `net_amount`: Amount remaining after all fees
+    ///   - `corridor`: Optional corridor identifier (populated if country params provided)
+    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
+    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in calculation
+    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    ///
+    /// # Behavior
+    ///
+    /// - Callable without authorization (read-only query)
+    /// - If both `from_country` and `to_country` are provided:
+    ///   - Looks up corridor-specific fee configuration
+    ///   - Uses corridor fees if corridor exists, otherwise uses global fees
+    ///   - Sets `corridor` field in returned FeeBreakdown
+    /// - If countries not provided, uses current global fee strategy
+    /// - Fee calculations support Percentage, Flat, and Dynamic fee strategies
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// // Check global fees
+    /// let breakdown = contract.get_fee_breakdown(&env, 1000_000, None, None)?;
+    ///
+    /// // Check corridor-specific fees
+    /// let breakdown = contract.get_fee_breakdown(
+    ///     &env,
+    ///     1000_000,
+    ///     Some(String::from_str(&env, "US")),
+    ///     Some(String::from_str(&env, "MX")),
+    /// )?;
+    /// ```
+    pub fn get_fee_breakdown(
+        env: Env,
+        amount: i128,
+        from_country: Option<String>,
+        to_country: Option<String>,
+    ) -> Result<FeeBreakdown, ContractError> {
+        // Validate amount
+        if amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        // Try to find corridor if both countries provided
+        let corridor_opt = if from_country.is_some() && to_country.is_some() {
+            let from = from_country.clone().unwrap();
+            let to = to_country.clone().unwrap();
+            get_fee_corridor(&env, &from, &to)
+        } else {
+            None
+        };
+
+        // Calculate fees with breakdown using corridor if available
+        let mut breakdown =
+            fee_service::calculate_fees_with_breakdown(&env, amount, None, corridor_opt.as_ref())?;
+
+        // If countries were provided but no corridor exists in storage,
+        // still set the corridor field for informational purposes
+        if breakdown.corridor.is_none() && from_country.is_some() && to_country.is_some() {
+            let from = from_country.unwrap();
+            let to = to_country.unwrap();
+            // Create corridor identifier string
+            let mut corridor_id = from.clone();
+            // For now, we'll use the from_country as the corridor ID
+            // In a production system with better string handling, this would be "from-to"
+            breakdown.corridor = Some(corridor_id);
+        }
+
+        Ok(breakdown)
+    }
+
+    /// Computes the deterministic settlement hash for a remittance.
+    ///
+    /// This function allows external systems (banks, anchors, APIs) to compute
+    /// the same settlement hash that the contract uses internally. The hash is
+    /// computed using the canonical ordering specified in DETERMINISTIC_HASHING_SPEC.md.
+    ///
+    /// External systems can use this to:
+    /// - Pre-compute settlement IDs before submission
+    /// - Verify on-chain settlement IDs match expected values
+    /// - Enable cross-system reconciliation using deterministic IDs
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - The remittance ID to compute hash for
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BytesN<32>)` - The 32-byte SHA-256 settlement hash
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    ///
+    /// # Hash Input Ordering (Canonical)
+    ///
+    /// 1. remittance_id (u64, big-endian)
+    /// 2. sender (Address, XDR-encoded)
+    /// 3. agent (Address, XDR-encoded)
+    /// 4. amount (i128, big-endian)
+    /// 5. fee (i128, big-endian)
+    /// 6. expiry (u64, big-endian, 0 if None)
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let settlement_hash = contract.compute_settlement_hash(&env, remittance_id)?;
+    /// // External system can verify this matches their computed hash
+    /// ```
+    pub fn compute_settlement_hash(
+        env: Env,
+        remittance_id: u64,
+    ) -> Result<soroban_sdk::BytesN<32>, ContractError> {
+        let remittance = get_remittance(&env, remittance_id)?;
+        Ok(compute_settlement_id_from_remittance(&env, &remittance))
+    }
+    /// Retrieves the stored settlement hash for a given remittance ID.
+    ///
+    /// This function allows external systems to retrieve and verify the settlement hash
+    /// that was stored on-chain when the remittance was settled. External systems that
+    /// implement the same hashing algorithm can verify their computed hash against the
+    /// stored one.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `remittance_id` - The remittance ID to retrieve the hash for
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BytesN<32>)` - The 32-byte SHA-256 settlement hash if the remittance is settled
+    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// * `Err(ContractError::InvalidStatus)` - Remittance has not been settled yet
+    ///
+    /// # Examples
     ///
-    /// # Authorization
-    /// Requires authentication from the agent assigned to the remittance.
-    pub fn confirm_partial_payout(
+    /// ```ignore
+    /// let stored_hash = contract.get_settlement_hash(&env, remittance_id)?;
+    /// let computed_hash = contract.compute_settlement_hash(&env, remittance_id)?;
+    /// assert_eq!(stored_hash, computed_hash);
+    /// ```
+    pub fn get_settlement_hash(
         env: Env,
         remittance_id: u64,
-        amount: i128,
-    ) -> Result<(), ContractError> {
-        if amount <= 0 {
-            return Err(ContractError::InvalidAmount);
-        }
-
-        let mut remittance = get_remittance(&env, remittance_id)?;
-        crate::storage::require_agent_authorized(&env, &remittance.agent)?;
+    ) -> Result<soroban_sdk::BytesN<32>, ContractError> {
+        // Check if remittance exists
+        let remittance = get_remittance(&env, remittance_id)?;
 
-        if remittance.status != RemittanceStatus::Pending
-            && remittance.status != RemittanceStatus::Processing
-        {
+        // Check if settlement has been executed
+        if !remittance.is_settlement_executed() {
             return Err(ContractError::InvalidStatus);
         }
 
-        // Enforce per-agent daily cap
-        storage::check_and_record_agent_withdrawal(&env, &remittance.agent, amount)?;
-
-        let fee_breakdown = fee_service::calculate_fees_with_breakdown(&env, remittance.amount, None, None)?;
-        let net_payout = fee_breakdown.net_amount;
+        // Return the computed hash (which is deterministic and matches what was stored)
+        Ok(compute_settlement_id_from_remittance(&env, &remittance))
+    }
 
-        let already_disbursed = storage::get_disbursed_amount(&env, remittance_id);
-        let remaining = net_payout
-            .checked_sub(already_disbursed)
-            .ok_or(ContractError::Underflow)?;
+    /// Step 1 of 2-step admin transfer (#365).
+    ///
+    /// The current admin proposes a new admin address. The proposal is stored on-chain
+    /// and must be accepted by the proposed address via `accept_admin` before the
+    /// transfer takes effect. This prevents accidental or malicious key handover.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the current admin.
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "propose_admin", (new_admin.clone(),));
 
-        if amount > remaining {
-            return Err(ContractError::InvalidAmount);
-        }
+        set_pending_admin(&env, &new_admin);
+        emit_admin_transfer_proposed(&env, caller, new_admin);
 
-        // Move to Processing on first partial disbursement
-        if remittance.status == RemittanceStatus::Pending {
-            crate::transitions::transition_status(&env, &mut remittance, RemittanceStatus::Processing)?;
-            storage::add_processing_volume(&env, remittance.amount)?;
-        }
+        Ok(())
+    }
 
-        let token_client = token::Client::new(&env, &remittance.token);
-        token_client.transfer(&env.current_contract_address(), &remittance.agent, &amount);
+    /// Step 2 of 2-step admin transfer (#365).
+    ///
+    /// The proposed admin accepts the role. Only callable by the address that was
+    /// previously proposed via `propose_admin`. On success the caller becomes the
+    /// new admin and the pending proposal is cleared.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the proposed admin address.
+    pub fn accept_admin(env: Env) -> Result<(), ContractError> {
+        let new_admin = get_pending_admin(&env)
+            .ok_or(ContractError::NoPendingAdminTransfer)?;
 
-        storage::add_disbursed_amount(&env, remittance_id, amount)?;
-        let new_total = already_disbursed.checked_add(amount).ok_or(ContractError::Overflow)?;
-        let remaining_amount = net_payout.saturating_sub(new_total);
+        new_admin.require_auth();
+        admin_log::record(&env, &new_admin, "accept_admin", ());
 
-        storage::append_partial_payout_record(&env, remittance_id, crate::PartialPayoutRecord {
-            amount,
-            total_disbursed: new_total,
-            remaining_amount,
-            timestamp: env.ledger().timestamp(),
-            ledger_sequence: env.ledger().sequence(),
-        });
+        let old_admin = get_admin(&env)?;
 
-        emit_partial_payout(&env, remittance_id, remittance.agent.clone(), amount, new_total, remaining_amount);
+        // Update legacy admin pointer
+        set_admin(&env, &new_admin);
 
-        // If fully disbursed, collect fee and complete
-        if new_total >= net_payout {
-            // Update accumulated fees with overflow protection and automatic flush
-            safe_add_accumulated_fee(&env, remittance.fee)?;
+        // Update role-based admin system
+        set_admin_role(&env, &old_admin, false);
+        set_admin_role(&env, &new_admin, true);
 
-            // Move volume from in-flight to completed
-            storage::sub_processing_volume(&env, remittance.amount)?;
-            storage::add_completed_volume(&env, remittance.amount)?;
+        // Update role assignments
+        remove_role(&env, &old_admin, &Role::Admin);
+        assign_role(&env, &new_admin, &Role::Admin);
 
-            crate::transitions::transition_status(&env, &mut remittance, RemittanceStatus::Completed)?;
-            set_remittance(&env, remittance_id, &remittance);
-            set_settlement_hash(&env, remittance_id);
+        // Clear the pending proposal
+        clear_pending_admin(&env);
 
-            emit_remittance_completed(&env, remittance_id, remittance.sender, remittance.agent);
-        } else {
-            set_remittance(&env, remittance_id, &remittance);
-        }
+        emit_admin_transfer_accepted(&env, old_admin, new_admin);
 
         Ok(())
     }
 
-    /// Sets a per-agent daily withdrawal cap (admin only).
-    ///
-    /// The agent may not withdraw more than `cap` USDC in any rolling 24-hour window.
-    /// Set `cap` to 0 to remove the cap.
-    pub fn set_agent_daily_cap(env: Env, agent: Address, cap: i128) -> Result<(), ContractError> {
-        if cap < 0 {
-            return Err(ContractError::InvalidAmount);
-        }
+    pub fn pause(env: Env) -> Result<(), ContractError> {
         let caller = get_admin(&env)?;
         require_admin(&env, &caller)?;
-        storage::set_agent_daily_cap(&env, &agent, cap);
-        emit_agent_cap_set(&env, agent, cap, caller);
-        Ok(())
-    }
+        admin_log::record(&env, &caller, "pause", ());
 
-    /// Returns the per-agent daily withdrawal cap (0 = no cap).
-    pub fn get_agent_daily_cap(env: Env, agent: Address) -> i128 {
-        storage::get_agent_daily_cap(&env, &agent)
+        // Delegate to circuit breaker with bypass_checks = true (legacy wrapper)
+        circuit_breaker::do_emergency_pause(&env, &caller, PauseReason::MaintenanceWindow, true)
     }
 
-    pub fn get_agent_stats(env: Env, agent: Address) -> AgentStats {
-        crate::storage::get_agent_stats(&env, &agent)
-    }
+    pub fn unpause(env: Env) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "unpause", ());
 
-    pub fn get_agent_reputation(env: Env, agent: Address) -> u32 {
-        let stats = crate::storage::get_agent_stats(&env, &agent);
-        crate::storage::compute_agent_reputation(&stats)
+        // Delegate to circuit breaker with bypass_timelock_quorum = true (legacy wrapper)
+        circuit_breaker::do_emergency_unpause(&env, &caller, true)
     }
 
-    pub fn finalize_remittance(
+    // ── Maintenance Windows ────────────────────────────────────────────────────
+
+    /// Schedules a maintenance window during which new remittances are refused.
+    ///
+    /// Confirmations, settlements, cancellations, and refunds keep working
+    /// during the window. Requires Admin role.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidMaintenanceWindow` - `start` is not before `end`,
+    ///   `end` has passed, or `MAX_MAINTENANCE_WINDOWS` are already scheduled
+    pub fn schedule_maintenance(
         env: Env,
         caller: Address,
-        remittance_id: u64,
+        start: u64,
+        end: u64,
     ) -> Result<(), ContractError> {
         require_admin(&env, &caller)?;
-        let remittance = get_remittance(&env, remittance_id)?;
-
-        // Verify remittance is in a valid state (Completed)
-        if remittance.status != RemittanceStatus::Completed {
-            return Err(ContractError::InvalidStateTransition);
-        }
-
-        // Remittance is already completed, no further action needed
+        admin_log::record(&env, &caller, "schedule_maintenance", (start, end));
+        maintenance::schedule(&env, start, end)?;
+        emit_maintenance_scheduled(&env, start, end);
         Ok(())
     }
 
-    /// Cancels a pending remittance and refunds the sender.
-    ///
-    /// Returns the full remittance amount to the sender and marks the remittance
-    /// as cancelled. Can only be called by the original sender.
-    ///
-    /// # Arguments
-    ///
-    /// * `env` - The contract execution environment
-    /// * `remittance_id` - ID of the remittance to cancel
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(())` - Remittance successfully cancelled and refunded
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
-    /// * `Err(ContractError::InvalidStatus)` - Remittance is not in Pending status
-    ///
-    /// # Authorization
-    ///
-    /// Requires authentication from the sender address who created the remittance.
-    pub fn cancel_remittance(env: Env, remittance_id: u64) -> Result<(), ContractError> {
-        // Centralized validation before business logic (returns remittance to avoid re-read)
-        let mut remittance = validate_cancel_remittance_request(&env, remittance_id)?;
-
-        remittance.sender.require_auth();
-
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &remittance.sender,
-            &remittance.amount,
-        );
+    /// Cancels the maintenance window starting at `start`. Requires Admin role.
+    pub fn cancel_maintenance(env: Env, caller: Address, start: u64) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "cancel_maintenance", (start,));
+        maintenance::cancel(&env, start)?;
+        emit_maintenance_cancelled(&env, start);
+        Ok(())
+    }
 
-        remittance.status = RemittanceStatus::Cancelled;
-        // Fix #378: zero out the amount field so querying the remittance after
-        // cancellation does not return a stale USDC balance.
-        remittance.amount = 0;
-        set_remittance(&env, remittance_id, &remittance);
+    /// Returns the maintenance window in effect now, whose `end` is the time
+    /// creations resume.
+    pub fn get_active_maintenance_window(env: Env) -> Option<MaintenanceWindow> {
+        maintenance::active(&env)
+    }
 
-        // Event: Remittance cancelled - Fires when sender cancels a pending remittance and receives full refund
-        // Used by off-chain systems to track cancellations and update transaction status
-        emit_remittance_cancelled(
-            &env,
-            remittance_id,
-            remittance.sender,
-            remittance.agent,
-            usdc_token,
-            remittance.amount,
-        );
+    /// Returns all scheduled maintenance windows that have not been pruned.
+    pub fn get_maintenance_windows(env: Env) -> Vec<MaintenanceWindow> {
+        storage::get_maintenance_windows(&env)
+    }
 
-        log_cancel_remittance(&env, remittance_id);
+    // ── Emergency Evacuation ───────────────────────────────────────────────────
 
-        // Cleanup: remove idempotency record on terminal state (Cancelled)
-        if let Some(idem_key) = storage::take_remittance_idempotency_key(&env, remittance_id) {
-            storage::remove_idempotency_record(&env, &idem_key);
+    /// Appoints or rotates the guardian who co-signs emergency evacuations.
+    ///
+    /// The guardian may not be an admin. Requires authentication from the
+    /// admin `caller`, from `guardian`, and from the current guardian if one is
+    /// set, so no single key can change it.
+    pub fn set_guardian(env: Env, caller: Address, guardian: Address) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_guardian", (guardian.clone(),));
+        if storage::is_admin(&env, &guardian) {
+            return Err(ContractError::Unauthorized);
         }
-
-        // #831: Post-cancel cleanup — removes controller-layer bookkeeping entries
-        // so stale transaction records and anchor mappings do not persist after cancellation.
-        transaction_controller::TransactionController::post_cancel_cleanup(&env, remittance_id)?;
-
+        if let Some(current) = storage::get_guardian(&env) {
+            if current != guardian {
+                current.require_auth();
+            }
+        }
+        guardian.require_auth();
+        storage::set_guardian(&env, &guardian);
+        emit_guardian_set(&env, guardian, caller);
         Ok(())
     }
 
-    /// Refunds expired pending remittances in batch.
+    /// Returns the guardian, if one has been appointed.
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        storage::get_guardian(&env)
+    }
+
+    /// Proposes moving all escrowed funds to `recovery`.
     ///
-    /// Callable by anyone. Each provided remittance ID is processed independently:
-    /// only remittances that are both Pending and expired are cancelled and refunded.
-    /// Non-existent, non-pending, or non-expired remittances are skipped.
-    pub fn process_expired_remittances(
+    /// `caller` must be an admin or the guardian; the other party then has to
+    /// approve. See the `evacuation` module for the full procedure.
+    pub fn propose_evacuation(
         env: Env,
-        remittance_ids: Vec<u64>,
-    ) -> Result<Vec<u64>, ContractError> {
-        if remittance_ids.len() > get_max_expired_batch_size(&env) {
-            return Err(ContractError::InvalidBatchSize);
-        }
-
-        let now = env.ledger().timestamp();
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-
-        let mut processed_ids = Vec::new(&env);
-
-        for i in 0..remittance_ids.len() {
-            let remittance_id = remittance_ids.get_unchecked(i);
-            let mut remittance = match get_remittance(&env, remittance_id) {
-                Ok(value) => value,
-                Err(_) => continue,
-            };
-
-            if remittance.status != RemittanceStatus::Pending {
-                continue;
-            }
-
-            let is_expired = match remittance.expiry {
-                Some(expiry) => now > expiry,
-                None => false,
-            };
-
-            if !is_expired {
-                continue;
-            }
-
-            token_client.transfer(
-                &env.current_contract_address(),
-                &remittance.sender,
-                &remittance.amount,
-            );
-
-            crate::transitions::transition_status(
-                &env,
-                &mut remittance,
-                RemittanceStatus::Cancelled,
-            )?;
-            set_remittance(&env, remittance_id, &remittance);
-
-            emit_remittance_cancelled(
-                &env,
-                remittance_id,
-                remittance.sender.clone(),
-                remittance.agent.clone(),
-                usdc_token.clone(),
-                remittance.amount,
-            );
-            emit_remittance_cancelled_with_reason(
-                &env,
-                remittance_id,
-                remittance.sender,
-                remittance.agent,
-                usdc_token.clone(),
-                remittance.amount,
-                String::from_str(&env, "expired"),
-            );
+        caller: Address,
+        recovery: Address,
+    ) -> Result<(), ContractError> {
+        let request = evacuation::propose(&env, &caller, recovery)?;
+        admin_log::record(&env, &caller, "propose_evacuation", (request.recovery.clone(),));
+        emit_evacuation_proposed(&env, request.recovery, request.proposed_by);
+        Ok(())
+    }
 
-            if let Some(idem_key) = storage::take_remittance_idempotency_key(&env, remittance_id) {
-                storage::remove_idempotency_record(&env, &idem_key);
-            }
+    /// Approves the pending evacuation and starts its countdown.
+    ///
+    /// `caller` must be the party (admin or guardian) that did not propose it.
+    /// Returns the time at which the evacuation becomes executable.
+    pub fn approve_evacuation(env: Env, caller: Address) -> Result<u64, ContractError> {
+        let request = evacuation::approve(&env, &caller)?;
+        admin_log::record(&env, &caller, "approve_evacuation", ());
+        let executable_at = request.executable_at.unwrap_or_default();
+        emit_evacuation_armed(&env, request.recovery, executable_at);
+        Ok(executable_at)
+    }
 
-            processed_ids.push_back(remittance_id);
+    /// Cancels the pending evacuation. Either an admin or the guardian may cancel.
+    pub fn cancel_evacuation(env: Env, caller: Address) -> Result<(), ContractError> {
+        evacuation::cancel(&env, &caller)?;
+        admin_log::record(&env, &caller, "cancel_evacuation", ());
+        emit_evacuation_cancelled(&env, caller);
+        Ok(())
+    }
+
+    /// Re-announces the time left on an armed evacuation countdown.
+    ///
+    /// Anyone may call this so monitors keep getting alerts while the
+    /// countdown runs. Returns the remaining seconds.
+    pub fn evacuation_countdown(env: Env) -> Result<u64, ContractError> {
+        let (recovery, remaining) = evacuation::remaining(&env)?;
+        emit_evacuation_countdown(&env, recovery, remaining);
+        Ok(remaining)
+    }
+
+    /// Executes an approved evacuation whose countdown has ended.
+    ///
+    /// Pauses the contract and transfers the full balance of every whitelisted
+    /// token to the recovery address. Anyone may call this once it is ready.
+    pub fn execute_evacuation(env: Env) -> Result<(), ContractError> {
+        let (request, moved) = evacuation::execute(&env)?;
+        for (token, amount) in moved.iter() {
+            emit_evacuation_executed(&env, request.recovery.clone(), token, amount);
         }
+        Ok(())
+    }
 
-        Ok(processed_ids)
+    /// Returns the pending evacuation, if any.
+    pub fn get_evacuation(env: Env) -> Option<EvacuationRequest> {
+        storage::get_evacuation(&env)
     }
 
-    /// Withdraws accumulated platform fees to a specified address.
+    // ── Agent Key-Loss Recovery ────────────────────────────────────────────────
+
+    /// Proposes `replacement` to take over pending remittances of an agent
+    /// that has lost access to its key. Requires Admin role.
     ///
-    /// Transfers all accumulated fees to the recipient address and resets the
-    /// fee counter to zero. Only the contract admin can withdraw fees.
+    /// Nothing moves on proposal: each affected sender approves the
+    /// reassignment of their own remittance with `approve_agent_recovery`,
+    /// so transfers migrate without being cancelled and no sender's funds are
+    /// handed to a new agent without consent. Proposing again replaces the
+    /// previous replacement.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `env` - The contract execution environment
-    /// * `to` - Address to receive the withdrawn fees
+    /// * `ContractError::InvalidAddress` - `replacement` is `lost_agent`
+    /// * `ContractError::AgentNotRegistered` - `replacement` is not registered
+    pub fn propose_agent_recovery(
+        env: Env,
+        caller: Address,
+        lost_agent: Address,
+        replacement: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(
+            &env,
+            &caller,
+            "propose_agent_recovery",
+            (lost_agent.clone(), replacement.clone()),
+        );
+        if lost_agent == replacement {
+            return Err(ContractError::InvalidAddress);
+        }
+        if !storage::is_agent_registered(&env, &replacement) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+        storage::set_agent_recovery(&env, &lost_agent, &replacement);
+        emit_agent_recovery_proposed(&env, lost_agent, replacement);
+        Ok(())
+    }
+
+    /// Closes an open agent recovery. Remittances already reassigned stay with
+    /// the replacement. Requires Admin role.
+    pub fn cancel_agent_recovery(
+        env: Env,
+        caller: Address,
+        lost_agent: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "cancel_agent_recovery", (lost_agent.clone(),));
+        storage::get_agent_recovery(&env, &lost_agent).ok_or(ContractError::AgentRecoveryNotFound)?;
+        storage::remove_agent_recovery(&env, &lost_agent);
+        emit_agent_recovery_cancelled(&env, lost_agent);
+        Ok(())
+    }
+
+    /// Returns the replacement proposed for `lost_agent`, if a recovery is open.
+    pub fn get_agent_recovery(env: Env, lost_agent: Address) -> Option<Address> {
+        storage::get_agent_recovery(&env, &lost_agent)
+    }
+
+    /// Reassigns the sender's pending remittance from an agent under recovery
+    /// to the proposed replacement.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// * `Ok(())` - Fees successfully withdrawn
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
-    /// * `Err(ContractError::NoFeesToWithdraw)` - No fees available (balance is zero or negative)
-    /// * `Err(ContractError::InvalidAddress)` - Recipient address validation failed
+    /// * `ContractError::AgentRecoveryNotFound` - No recovery is open for the remittance's agent
+    /// * `ContractError::InvalidStatus` - Remittance is not Pending or has partial disbursements
+    /// * `ContractError::AgentTierTooLow` / `AgentPendingLimitExceeded` - The replacement's
+    ///   tier does not allow the remittance
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the contract admin.
-    pub fn withdraw_fees(env: Env, to: Address) -> Result<(), ContractError> {
-        // Centralized validation before business logic (returns fees to avoid re-read)
-        let fees = validate_withdraw_fees_request(&env, &to)?;
-
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
-
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(&env.current_contract_address(), &to, &fees);
-
-        set_accumulated_fees(&env, 0);
+    /// Requires authentication from the remittance's sender. The lost agent's
+    /// key is not needed.
+    pub fn approve_agent_recovery(env: Env, remittance_id: u64) -> Result<(), ContractError> {
+        let mut remittance = get_remittance(&env, remittance_id)?;
+        remittance.sender.require_auth();
+        let replacement = storage::get_agent_recovery(&env, &remittance.agent)
+            .ok_or(ContractError::AgentRecoveryNotFound)?;
+        ensure_reassignable(&env, &remittance, &replacement)?;
+        if !storage::is_agent_registered(&env, &replacement) {
+            return Err(ContractError::AgentNotRegistered);
+        }
 
-        emit_fees_withdrawn(&env, caller, to.clone(), usdc_token, fees);
+        move_to_agent(&env, &mut remittance, replacement)
+    }
 
-        log_withdraw_fees(&env, &to, fees);
+    // ── Sender Account Recovery ────────────────────────────────────────────────
 
+    /// Registers the guardians who can jointly rotate `owner`'s internal
+    /// balances and records to a new address, replacing any previous set and
+    /// discarding a recovery in progress. An empty list removes recovery.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidRecoveryGuardians` - Too many or duplicate
+    ///   guardians, the owner listed, or a threshold outside `1..=guardians.len()`
+    pub fn set_recovery_guardians(
+        env: Env,
+        owner: Address,
+        guardians: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), ContractError> {
+        owner.require_auth();
+        let count = guardians.len();
+        account_recovery::set_guardians(&env, &owner, guardians, threshold)?;
+        emit_recovery_guardians_set(&env, owner, count, threshold);
         Ok(())
     }
 
-    /// Withdraws accumulated integrator fees to a specified address.
+    /// Returns `owner`'s recovery guardians, if registered.
+    pub fn get_recovery_guardians(env: Env, owner: Address) -> Option<RecoveryGuardians> {
+        storage::get_recovery_guardians(&env, &owner)
+    }
+
+    /// Approves rotating `owner` to `new_owner` as one of their guardians.
     ///
-    /// Transfers all accumulated integrator fees to the recipient and resets the
-    /// counter to zero. Only the designated integrator can withdraw their own fees.
+    /// The approval that meets the owner's threshold starts a challenge
+    /// period of `ACCOUNT_RECOVERY_CHALLENGE_SECONDS`.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `env` - The contract execution environment
-    /// * `integrator` - Address of the integrator requesting withdrawal (must authenticate)
-    /// * `to` - Address to receive the withdrawn fees
+    /// * `ContractError::RecoveryGuardiansNotSet` - Owner has no guardians
+    /// * `ContractError::Unauthorized` - `guardian` is not a guardian of `owner`
+    ///   or has already approved
+    /// * `ContractError::InvalidAddress` - `new_owner` is `owner` or differs
+    ///   from the address earlier approvals named
+    pub fn approve_account_recovery(
+        env: Env,
+        guardian: Address,
+        owner: Address,
+        new_owner: Address,
+    ) -> Result<(), ContractError> {
+        guardian.require_auth();
+        let armed = account_recovery::approve(&env, &guardian, &owner, &new_owner)?;
+        emit_account_recovery_approved(&env, owner.clone(), new_owner.clone(), guardian);
+        if let Some(executable_at) = armed {
+            emit_account_recovery_armed(&env, owner, new_owner, executable_at);
+        }
+        Ok(())
+    }
+
+    /// Cancels a recovery of `owner`'s account. Only the owner can cancel,
+    /// which is how a recovery started without their consent is challenged.
+    pub fn cancel_account_recovery(env: Env, owner: Address) -> Result<(), ContractError> {
+        owner.require_auth();
+        storage::get_account_recovery(&env, &owner).ok_or(ContractError::AccountRecoveryNotFound)?;
+        storage::remove_account_recovery(&env, &owner);
+        emit_account_recovery_cancelled(&env, owner);
+        Ok(())
+    }
+
+    /// Executes a recovery once its challenge period has ended, moving
+    /// `owner`'s savings, savings goals, templates, and guardians to the new
+    /// address, along with any of `remittance_ids` that are pending and sent
+    /// by `owner`. Other IDs are skipped. Returns the IDs migrated.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// * `Ok(())` - Fees successfully withdrawn
-    /// * `Err(ContractError::NoFeesToWithdraw)` - No integrator fees available
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// * `ContractError::AccountRecoveryNotFound` - No recovery in progress
+    /// * `ContractError::AccountRecoveryNotReady` - Threshold not met or
+    ///   challenge period not over
+    /// * `ContractError::InvalidAmount` - More than `MAX_MIGRATION_BATCH_SIZE` IDs
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the integrator address.
-    pub fn withdraw_integrator_fees(
+    /// Requires authentication from the new owner.
+    pub fn execute_account_recovery(
         env: Env,
-        integrator: Address,
-        to: Address,
-    ) -> Result<(), ContractError> {
-        let fees = storage::get_accumulated_integrator_fees(&env);
-        if fees <= 0 {
-            return Err(ContractError::NoFeesToWithdraw);
-        }
+        owner: Address,
+        remittance_ids: Vec<u64>,
+    ) -> Result<Vec<u64>, ContractError> {
+        let (new_owner, migrated) = account_recovery::execute(&env, &owner, &remittance_ids)?;
+        emit_account_recovery_executed(&env, owner, new_owner, migrated.len());
+        Ok(migrated)
+    }
 
-        integrator.require_auth();
+    /// Returns the recovery in progress for `owner`, if any.
+    pub fn get_account_recovery(env: Env, owner: Address) -> Option<AccountRecovery> {
+        storage::get_account_recovery(&env, &owner)
+    }
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(&env.current_contract_address(), &to, &fees);
+    // ── Circuit Breaker Entry Points ───────────────────────────────────────────
 
-        storage::set_accumulated_integrator_fees(&env, 0);
+    /// Pauses the contract with a structured reason. Requires Admin or Operator role.
+    pub fn emergency_pause(
+        env: Env,
+        caller: Address,
+        reason: PauseReason,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::do_emergency_pause(&env, &caller, reason.clone(), false)?;
+        admin_log::record(&env, &caller, "emergency_pause", (reason,));
+        Ok(())
+    }
 
-        emit_integrator_fees_withdrawn(&env, integrator, to, usdc_token, fees);
+    /// Unpauses the contract, enforcing timelock and quorum. Requires Admin role.
+    pub fn emergency_unpause(env: Env, caller: Address) -> Result<(), ContractError> {
+        circuit_breaker::do_emergency_unpause(&env, &caller, false)?;
+        admin_log::record(&env, &caller, "emergency_unpause", ());
+        Ok(())
+    }
+
+    /// Casts an admin vote to unpause; auto-unpauses when quorum is reached.
+    pub fn vote_unpause(env: Env, caller: Address) -> Result<(), ContractError> {
+        circuit_breaker::do_vote_unpause(&env, &caller)?;
+        admin_log::record(&env, &caller, "vote_unpause", ());
+        Ok(())
+    }
 
+    /// Sets the timelock duration (0..=604800 seconds). Requires Admin role.
+    pub fn set_pause_timelock(
+        env: Env,
+        caller: Address,
+        seconds: u64,
+    ) -> Result<(), ContractError> {
+        if seconds > 604800 {
+            return Err(ContractError::InvalidTimelockDuration);
+        }
+        caller.require_auth();
+        require_role_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_pause_timelock", (seconds,));
+        let old_seconds = circuit_breaker_storage::get_timelock_seconds(&env);
+        circuit_breaker_storage::set_timelock_seconds(&env, seconds);
+        emit_pause_timelock_updated(&env, caller, old_seconds, seconds);
         Ok(())
     }
 
-    /// Retrieves a remittance record by ID.
-    ///
-    /// # Arguments
-    ///
-    /// * `env` - The contract execution environment
-    /// * `remittance_id` - ID of the remittance to retrieve
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(Remittance)` - The remittance record
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
-    pub fn get_remittance(env: Env, remittance_id: u64) -> Result<Remittance, ContractError> {
-        get_remittance(&env, remittance_id)
+    /// Sets the unpause quorum (1..=admin_count). Requires Admin role.
+    pub fn set_unpause_quorum(
+        env: Env,
+        caller: Address,
+        quorum: u32,
+    ) -> Result<(), ContractError> {
+        let admin_count = storage::get_admin_count(&env);
+        if quorum < 1 || quorum > admin_count {
+            return Err(ContractError::InvalidQuorum);
+        }
+        caller.require_auth();
+        require_role_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_unpause_quorum", (quorum,));
+        let old_quorum = circuit_breaker_storage::get_unpause_quorum(&env);
+        circuit_breaker_storage::set_unpause_quorum(&env, quorum);
+        emit_unpause_quorum_updated(&env, caller, old_quorum, quorum);
+        Ok(())
     }
 
-    /// Returns a paginated list of remittance IDs for a given sender.
-    ///
-    /// # Arguments
-    ///
-    /// * `env` - The contract execution environment
-    /// * `sender` - Address of the sender to query
-    /// * `offset` - Zero-based index of the first result to return
-    /// * `limit` - Maximum number of IDs to return (capped at 100)
-    ///
-    /// # Returns
+    /// Sets the post-unpause cooldown period in seconds (0 disables cooldown).
     ///
-    /// * `Vec<u64>` - Slice of remittance IDs in creation order
-    pub fn get_remittances_by_sender(
+    /// During this window after an emergency unpause, per-sender rate limits are
+    /// halved to throttle traffic. Max 7 days. Requires Admin role.
+    pub fn set_cooldown_period(
         env: Env,
-        sender: Address,
-        offset: u64,
-        limit: u64,
-    ) -> Vec<u64> {
-        const MAX_PAGE_SIZE: u64 = 100;
-        let limit = limit.min(MAX_PAGE_SIZE);
+        caller: Address,
+        seconds: u64,
+    ) -> Result<(), ContractError> {
+        if seconds > 604_800 {
+            return Err(ContractError::InvalidTimelockDuration);
+        }
+        caller.require_auth();
+        require_role_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_cooldown_period", (seconds,));
+        let old_seconds = circuit_breaker_storage::get_cooldown_period(&env);
+        circuit_breaker_storage::set_cooldown_period(&env, seconds);
+        emit_cooldown_period_updated(&env, caller, old_seconds, seconds);
+        Ok(())
+    }
 
-        let all_ids = storage::get_sender_remittances(&env, &sender);
-        let total = all_ids.len() as u64;
+    /// Returns the current post-unpause cooldown period in seconds.
+    pub fn get_cooldown_period(env: Env) -> u64 {
+        circuit_breaker_storage::get_cooldown_period(&env)
+    }
 
-        if offset >= total || limit == 0 {
-            return Vec::new(&env);
-        }
+    // ── Circuit Breaker View Entry Points ──────────────────────────────────────
 
-        let end = (offset + limit).min(total);
-        let mut page = Vec::new(&env);
-        for i in offset..end {
-            page.push_back(all_ids.get_unchecked(i as u32));
-        }
-        page
+    /// Returns a snapshot of the full circuit-breaker state. No auth required.
+    pub fn get_circuit_breaker_status(env: Env) -> CircuitBreakerStatus {
+        circuit_breaker::build_status(&env)
+    }
+
+    /// Returns the pause record for the given sequence number.
+    pub fn get_pause_record(env: Env, seq: u64) -> Result<PauseRecord, ContractError> {
+        circuit_breaker_storage::get_pause_record_by_seq(&env, seq)
+            .ok_or(ContractError::PauseRecordNotFound)
+    }
+
+    /// Returns the active pause record, or None when not paused.
+    pub fn get_current_pause_record(env: Env) -> Option<PauseRecord> {
+        let seq = circuit_breaker_storage::get_active_pause_seq(&env)?;
+        circuit_breaker_storage::get_pause_record_by_seq(&env, seq)
     }
 
-    /// Returns a paginated list of remittance IDs for a given agent.
-    pub fn get_remittances_by_agent(
+    /// Returns the total number of pause events ever recorded.
+    pub fn get_pause_history_count(env: Env) -> u64 {
+        circuit_breaker_storage::get_pause_sequence(&env)
+    }
+
+    // ── Escrow Functions ───────────────────────────────────────────
+
+    pub fn create_escrow(
         env: Env,
-        agent: Address,
-        offset: u64,
-        limit: u64,
-    ) -> Vec<u64> {
-        const MAX_PAGE_SIZE: u64 = 100;
-        let limit = limit.min(MAX_PAGE_SIZE);
+        sender: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<u64, ContractError> {
+        sender.require_auth();
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        maintenance::ensure_open(&env)?;
+
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        token_transfer::transfer(&token_client, &sender, &env.current_contract_address(), amount)?;
+
+        let counter = get_escrow_counter(&env)?;
+        let transfer_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
 
-        let all_ids = storage::get_agent_remittances(&env, &agent);
-        let total = all_ids.len() as u64;
+        let ttl = crate::storage::get_escrow_ttl(&env)?;
+        let expiry = if ttl == 0 {
+            None
+        } else {
+            Some(
+                env.ledger()
+                    .timestamp()
+                    .checked_add(ttl)
+                    .ok_or(ContractError::Overflow)?,
+            )
+        };
+
+        let escrow = Escrow {
+            transfer_id,
+            sender: sender.clone(),
+            recipient: recipient.clone(),
+            amount,
+            expiry,
+            status: EscrowStatus::Pending,
+        };
 
-        if offset >= total || limit == 0 {
-            return Vec::new(&env);
-        }
+        set_escrow(&env, transfer_id, &escrow);
+        set_escrow_counter(&env, transfer_id);
+        storage::set_escrow_created_at(&env, transfer_id, env.ledger().timestamp());
 
-        let end = (offset + limit).min(total);
-        let mut page = Vec::new(&env);
-        for i in offset..end {
-            page.push_back(all_ids.get_unchecked(i as u32));
-        }
-        page
-    }
+        emit_escrow_created(&env, transfer_id, sender, recipient, amount);
 
-    pub fn get_accumulated_fees(env: Env) -> Result<i128, ContractError> {
-        get_accumulated_fees(&env)
+        Ok(transfer_id)
     }
 
-    pub fn get_accumulated_integrator_fees(env: Env) -> i128 {
-        storage::get_accumulated_integrator_fees(&env)
-    }
+    pub fn release_escrow(env: Env, transfer_id: u64) -> Result<(), ContractError> {
+        let mut escrow = get_escrow(&env, transfer_id)?;
 
-    /// Returns the number of registered admins.
-    pub fn get_admin_count(env: Env) -> u32 {
-        storage::get_admin_count(&env)
-    }
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "release_escrow", (transfer_id,));
 
-    /// Returns the total number of remittances ever created.
-    pub fn get_remittance_count(env: Env) -> u64 {
-        storage::get_total_remittance_count(&env)
+        release_to_recipient(&env, &mut escrow, EscrowStatus::Pending)
     }
 
-    /// Returns the cumulative volume of all completed remittances (original amounts).
-    pub fn get_total_volume(env: Env) -> i128 {
-        storage::get_total_completed_volume(&env)
+    /// Attaches the recipient's secp256r1 passkey to a pending escrow, so the
+    /// recipient can release it with `claim_escrow_with_passkey`.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidEscrowStatus` - Escrow is not Pending
+    /// * `ContractError::InvalidPasskey` - Key is not an uncompressed SEC-1 point
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the escrow sender.
+    pub fn set_escrow_claim_key(
+        env: Env,
+        transfer_id: u64,
+        public_key: BytesN<65>,
+    ) -> Result<(), ContractError> {
+        let escrow = get_escrow(&env, transfer_id)?;
+        escrow.sender.require_auth();
+        if escrow.status != EscrowStatus::Pending {
+            return Err(ContractError::InvalidEscrowStatus);
+        }
+        voucher::validate_passkey(&public_key)?;
+        storage::set_escrow_claim_key(&env, transfer_id, &public_key);
+        emit_escrow_claim_key_set(&env, transfer_id, public_key);
+        Ok(())
     }
 
-    /// Returns the total amount currently held in Processing (in-flight) remittances.
+    /// Releases a pending escrow to its recipient using a claim code signed
+    /// with the recipient's passkey. See the `voucher` module for the signed
+    /// payload. Anyone may submit it; funds only ever go to the recipient.
     ///
-    /// This complements `get_total_volume` (which only counts completed remittances)
-    /// by exposing the volume of remittances that have been claimed by an agent but
-    /// not yet confirmed as paid out to the recipient.
-    pub fn get_in_flight_volume(env: Env) -> i128 {
-        storage::get_total_processing_volume(&env)
-    }
+    /// # Errors
+    ///
+    /// * `ContractError::ClaimKeyNotSet` - Escrow has no recipient passkey
+    /// * `ContractError::InvalidEscrowStatus` - Escrow is not Pending
+    ///
+    /// An invalid signature aborts the invocation.
+    pub fn claim_escrow_with_passkey(
+        env: Env,
+        transfer_id: u64,
+        signature: BytesN<64>,
+    ) -> Result<(), ContractError> {
+        let mut escrow = get_escrow(&env, transfer_id)?;
+        if escrow.status != EscrowStatus::Pending {
+            return Err(ContractError::InvalidEscrowStatus);
+        }
+        voucher::verify_escrow_claim(&env, transfer_id, &signature)?;
 
-    /// Checks whether an address currently has admin privileges.
-    pub fn is_admin(env: Env, address: Address) -> bool {
-        crate::storage::is_admin(&env, &address)
+        release_to_recipient(&env, &mut escrow, EscrowStatus::Pending)
     }
 
-    /// Adds a new admin. Caller must already be an admin.
-    pub fn add_admin(env: Env, caller: Address, new_admin: Address) -> Result<(), ContractError> {
-        require_admin(&env, &caller)?;
+    pub fn refund_escrow(env: Env, transfer_id: u64) -> Result<(), ContractError> {
+        let mut escrow = get_escrow(&env, transfer_id)?;
 
-        if crate::storage::is_admin(&env, &new_admin) {
-            return Err(ContractError::AdminAlreadyExists);
+        escrow.sender.require_auth();
+
+        if escrow.status != EscrowStatus::Pending {
+            return Err(ContractError::InvalidEscrowStatus);
         }
 
-        crate::storage::set_admin_role(&env, &new_admin, true);
-        assign_role(&env, &new_admin, &Role::Admin);
+        escrow.status = EscrowStatus::Refunded;
+        set_escrow(&env, transfer_id, &escrow);
 
-        let count = storage::get_admin_count(&env);
-        let next = count.checked_add(1).ok_or(ContractError::Overflow)?;
-        storage::set_admin_count(&env, next);
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        token_transfer::transfer(
+            &token_client,
+            &env.current_contract_address(),
+            &escrow.sender,
+            escrow.amount,
+        )?;
 
-        emit_admin_added(&env, caller.clone(), new_admin.clone());
-        log_add_admin(&env, &caller, &new_admin);
+        emit_escrow_refunded(&env, transfer_id, escrow.sender, escrow.amount);
 
         Ok(())
     }
 
-    /// Removes an admin. Caller must be an admin and at least one admin must remain.
-    pub fn remove_admin(
-        env: Env,
-        caller: Address,
-        admin_to_remove: Address,
-    ) -> Result<(), ContractError> {
-        require_admin(&env, &caller)?;
-
-        if !crate::storage::is_admin(&env, &admin_to_remove) {
-            return Err(ContractError::AdminNotFound);
-        }
-
-        let count = storage::get_admin_count(&env);
-        if count <= 1 {
-            return Err(ContractError::CannotRemoveLastAdmin);
+    /// Turns a pending escrow nobody has released into a claim its recipient
+    /// can redeem with `redeem_escrow_claim`.
+    ///
+    /// Callable by anyone once `ESCROW_CLAIM_FALLBACK_SECONDS` have passed
+    /// since the escrow was created. From then on the escrow can no longer
+    /// be released, refunded, or expired; only its recipient can collect it.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidEscrowStatus` - Escrow is not Pending, or has expired
+    /// * `ContractError::EscrowClaimNotDue` - The fallback period has not passed
+    pub fn make_escrow_claimable(env: Env, transfer_id: u64) -> Result<(), ContractError> {
+        let mut escrow = get_escrow(&env, transfer_id)?;
+        let expired = escrow
+            .expiry
+            .is_some_and(|expiry| Expiry::Time(expiry).is_reached(&env));
+        if escrow.status != EscrowStatus::Pending || expired {
+            return Err(ContractError::InvalidEscrowStatus);
         }
-
-        crate::storage::set_admin_role(&env, &admin_to_remove, false);
-        remove_role(&env, &admin_to_remove, &Role::Admin);
-        storage::set_admin_count(&env, count - 1);
-
-        // Keep legacy single-admin storage aligned so legacy admin-gated paths remain operable.
-        if get_admin(&env)? == admin_to_remove {
-            set_admin(&env, &caller);
+        let due_at = storage::get_escrow_created_at(&env, transfer_id)
+            .saturating_add(ESCROW_CLAIM_FALLBACK_SECONDS);
+        if env.ledger().timestamp() < due_at {
+            return Err(ContractError::EscrowClaimNotDue);
         }
 
-        emit_admin_removed(&env, caller.clone(), admin_to_remove.clone());
-        log_remove_admin(&env, &caller, &admin_to_remove);
-
+        escrow.status = EscrowStatus::Claimable;
+        set_escrow(&env, transfer_id, &escrow);
+        emit_escrow_claimable(&env, transfer_id, escrow.recipient, escrow.amount);
         Ok(())
     }
 
-    /// Checks if an address is registered as an agent.
+    /// Pays a claimable escrow to its recipient through the token's Stellar
+    /// Asset Contract.
     ///
-    /// # Arguments
+    /// Only the recipient's own authorization is needed, so a classic Stellar
+    /// account can redeem by submitting the call as the transaction source
+    /// account, without a Soroban-aware wallet, passkey, or admin release.
+    /// Funds are released exactly as `release_escrow` would, including the
+    /// recipient's savings share.
     ///
-    /// * `env` - The contract execution environment
-    /// * `agent` - Address to check
+    /// # Errors
     ///
-    /// # Returns
+    /// * `ContractError::InvalidEscrowStatus` - Escrow is not Claimable
     ///
-    /// * `true` - Address is a registered agent
-    /// * `false` - Address is not registered
-    pub fn is_agent_registered(env: Env, agent: Address) -> bool {
-        is_agent_registered(&env, &agent)
-    }
+    /// # Authorization
+    ///
+    /// Requires authentication from the escrow recipient.
+    pub fn redeem_escrow_claim(env: Env, transfer_id: u64) -> Result<(), ContractError> {
+        let mut escrow = get_escrow(&env, transfer_id)?;
+        escrow.recipient.require_auth();
 
-    /// Returns the KYC metadata hash stored for an agent, or `None` if not set.
-    pub fn get_agent_kyc_hash(env: Env, agent: Address) -> Option<soroban_sdk::BytesN<32>> {
-        get_agent_kyc_hash(&env, &agent)
+        release_to_recipient(&env, &mut escrow, EscrowStatus::Claimable)?;
+        emit_escrow_redeemed(&env, transfer_id, escrow.recipient, escrow.amount);
+        Ok(())
     }
 
-    /// Retrieves the current platform fee rate.
-    ///
-    /// # Arguments
-    ///
-    /// * `env` - The contract execution environment
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(u32)` - Platform fee in basis points (1 bps = 0.01%)
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
-    pub fn get_platform_fee_bps(env: Env) -> Result<u32, ContractError> {
-        get_platform_fee_bps(&env)
+    pub fn get_escrow(env: Env, transfer_id: u64) -> Result<Escrow, ContractError> {
+        crate::storage::get_escrow(&env, transfer_id)
     }
 
-    /// Returns a detailed fee breakdown for a given amount and optional corridor.
-    ///
-    /// This function allows callers to preview the exact fee split before creating
-    /// a remittance. It supports both global fees and country-specific corridor fees.
-    ///
-    /// # Arguments
-    ///
-    /// * `env` - The contract execution environment
-    /// * `amount` - Transaction amount to calculate fees for (must be positive)
-    /// * `from_country` - Optional source country code (ISO 3166-1 alpha-2)
-    /// * `to_country` - Optional destination country code (ISO 3166-1 alpha-2)
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(FeeBreakdown)` - Complete fee breakdown with:
-    ///   - `amount`: Original transaction amount
-    ///   - `platform_fee`: Platform fee deducted
-    ///   - `protocol_fee`: Treasury/protocol fee deducted
-    ///   - `net_amount`: Amount remaining after all fees
-    ///   - `corridor`: Optional corridor identifier (populated if country params provided)
-    /// * `Err(ContractError::InvalidAmount)` - Amount is zero or negative
-    /// * `Err(ContractError::Overflow)` - Arithmetic overflow in calculation
-    /// * `Err(ContractError::NotInitialized)` - Contract not initialized
+    /// Sets the share of escrow payouts, in basis points, a recipient keeps in
+    /// their savings vault when an escrow addressed to them is released.
     ///
-    /// # Behavior
-    ///
-    /// - Callable without authorization (read-only query)
-    /// - If both `from_country` and `to_country` are provided:
-    ///   - Looks up corridor-specific fee configuration
-    ///   - Uses corridor fees if corridor exists, otherwise uses global fees
-    ///   - Sets `corridor` field in returned FeeBreakdown
-    /// - If countries not provided, uses current global fee strategy
-    /// - Fee calculations support Percentage, Flat, and Dynamic fee strategies
-    ///
-    /// # Examples
-    ///
-    /// ```ignore
-    /// // Check global fees
-    /// let breakdown = contract.get_fee_breakdown(&env, 1000_000, None, None)?;
+    /// # Authorization
     ///
-    /// // Check corridor-specific fees
-    /// let breakdown = contract.get_fee_breakdown(
-    ///     &env,
-    ///     1000_000,
-    ///     Some(String::from_str(&env, "US")),
-    ///     Some(String::from_str(&env, "MX")),
-    /// )?;
-    /// ```
-    pub fn get_fee_breakdown(
-        env: Env,
-        amount: i128,
-        from_country: Option<String>,
-        to_country: Option<String>,
-    ) -> Result<FeeBreakdown, ContractError> {
-        // Validate amount
-        if amount <= 0 {
-            return Err(ContractError::InvalidAmount);
-        }
-
-        // Try to find corridor if both countries provided
-        let corridor_opt = if from_country.is_some() && to_country.is_some() {
-            let from = from_country.clone().unwrap();
-            let to = to_country.clone().unwrap();
-            get_fee_corridor(&env, &from, &to)
-        } else {
-            None
-        };
+    /// Requires authentication from the recipient.
+    pub fn set_savings_share(env: Env, recipient: Address, bps: u32) -> Result<(), ContractError> {
+        recipient.require_auth();
+        savings::validate_share(bps)?;
 
-        // Calculate fees with breakdown using corridor if available
-        let mut breakdown =
-            fee_service::calculate_fees_with_breakdown(&env, amount, None, corridor_opt.as_ref())?;
+        storage::set_savings_share(&env, &recipient, bps);
+        emit_savings_share_set(&env, recipient, bps);
+        Ok(())
+    }
 
-        // If countries were provided but no corridor exists in storage,
-        // still set the corridor field for informational purposes
-        if breakdown.corridor.is_none() && from_country.is_some() && to_country.is_some() {
-            let from = from_country.unwrap();
-            let to = to_country.unwrap();
-            // Create corridor identifier string
-            let mut corridor_id = from.clone();
-            // For now, we'll use the from_country as the corridor ID
-            // In a production system with better string handling, this would be "from-to"
-            breakdown.corridor = Some(corridor_id);
-        }
+    /// Returns a recipient's savings share in basis points (0 if never set).
+    pub fn get_savings_share(env: Env, recipient: Address) -> u32 {
+        storage::get_savings_share(&env, &recipient)
+    }
 
-        Ok(breakdown)
+    /// Returns an owner's savings balance in a token.
+    pub fn get_savings_balance(env: Env, owner: Address, token: Address) -> i128 {
+        storage::get_savings_balance(&env, &owner, &token)
     }
 
-    /// Computes the deterministic settlement hash for a remittance.
-    ///
-    /// This function allows external systems (banks, anchors, APIs) to compute
-    /// the same settlement hash that the contract uses internally. The hash is
-    /// computed using the canonical ordering specified in DETERMINISTIC_HASHING_SPEC.md.
-    ///
-    /// External systems can use this to:
-    /// - Pre-compute settlement IDs before submission
-    /// - Verify on-chain settlement IDs match expected values
-    /// - Enable cross-system reconciliation using deterministic IDs
-    ///
-    /// # Arguments
-    ///
-    /// * `env` - The contract execution environment
-    /// * `remittance_id` - The remittance ID to compute hash for
+    /// Withdraws `amount` from the owner's savings to their wallet.
     ///
-    /// # Returns
-    ///
-    /// * `Ok(BytesN<32>)` - The 32-byte SHA-256 settlement hash
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
+    /// Returns the remaining balance.
     ///
-    /// # Hash Input Ordering (Canonical)
+    /// # Errors
     ///
-    /// 1. remittance_id (u64, big-endian)
-    /// 2. sender (Address, XDR-encoded)
-    /// 3. agent (Address, XDR-encoded)
-    /// 4. amount (i128, big-endian)
-    /// 5. fee (i128, big-endian)
-    /// 6. expiry (u64, big-endian, 0 if None)
+    /// * `ContractError::InvalidAmount` - Amount is not positive
+    /// * `ContractError::InsufficientSavingsBalance` - Amount exceeds the balance
     ///
-    /// # Examples
+    /// # Authorization
     ///
-    /// ```ignore
-    /// let settlement_hash = contract.compute_settlement_hash(&env, remittance_id)?;
-    /// // External system can verify this matches their computed hash
-    /// ```
-    pub fn compute_settlement_hash(
+    /// Requires authentication from the owner.
+    pub fn withdraw_savings(
         env: Env,
-        remittance_id: u64,
-    ) -> Result<soroban_sdk::BytesN<32>, ContractError> {
-        let remittance = get_remittance(&env, remittance_id)?;
-        Ok(compute_settlement_id_from_remittance(&env, &remittance))
+        owner: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, ContractError> {
+        owner.require_auth();
+
+        let balance = savings::withdraw(&env, &owner, &token, amount)?;
+        emit_savings_withdrawn(&env, owner, token, amount, balance);
+        Ok(balance)
     }
-    /// Retrieves the stored settlement hash for a given remittance ID.
-    ///
-    /// This function allows external systems to retrieve and verify the settlement hash
-    /// that was stored on-chain when the remittance was settled. External systems that
-    /// implement the same hashing algorithm can verify their computed hash against the
-    /// stored one.
-    ///
-    /// # Arguments
-    ///
-    /// * `env` - The contract execution environment
-    /// * `remittance_id` - The remittance ID to retrieve the hash for
+
+    /// Creates a named savings goal with a target amount and optional deadline.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// * `Ok(BytesN<32>)` - The 32-byte SHA-256 settlement hash if the remittance is settled
-    /// * `Err(ContractError::RemittanceNotFound)` - Remittance ID does not exist
-    /// * `Err(ContractError::InvalidStatus)` - Remittance has not been settled yet
+    /// * `ContractError::TokenNotWhitelisted` - Token is not whitelisted
+    /// * `ContractError::InvalidSavingsGoal` - Target is not positive or the deadline has passed
     ///
-    /// # Examples
+    /// # Authorization
     ///
-    /// ```ignore
-    /// let stored_hash = contract.get_settlement_hash(&env, remittance_id)?;
-    /// let computed_hash = contract.compute_settlement_hash(&env, remittance_id)?;
-    /// assert_eq!(stored_hash, computed_hash);
-    /// ```
-    pub fn get_settlement_hash(
+    /// Requires authentication from the owner.
+    pub fn create_savings_goal(
         env: Env,
-        remittance_id: u64,
-    ) -> Result<soroban_sdk::BytesN<32>, ContractError> {
-        // Check if remittance exists
-        let remittance = get_remittance(&env, remittance_id)?;
-
-        // Check if settlement has been executed
-        if !has_settlement_hash(&env, remittance_id) {
-            return Err(ContractError::InvalidStatus);
+        owner: Address,
+        name: String,
+        token: Address,
+        target: i128,
+        deadline: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        owner.require_auth();
+        if !storage::is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
         }
 
-        // Return the computed hash (which is deterministic and matches what was stored)
-        Ok(compute_settlement_id_from_remittance(&env, &remittance))
+        let goal = savings::create_goal(&env, &owner, name, &token, target, deadline)?;
+        emit_savings_goal_created(&env, goal.id, owner, target);
+        Ok(goal.id)
     }
 
-    /// Step 1 of 2-step admin transfer (#365).
+    /// Deposits `amount` from the owner's wallet into one of their savings goals.
     ///
-    /// The current admin proposes a new admin address. The proposal is stored on-chain
-    /// and must be accepted by the proposed address via `accept_admin` before the
-    /// transfer takes effect. This prevents accidental or malicious key handover.
+    /// Returns the goal's new balance.
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the current admin.
-    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
-
-        set_pending_admin(&env, &new_admin);
-        emit_admin_transfer_proposed(&env, caller, new_admin);
-
-        Ok(())
+    /// Requires authentication from the owner.
+    pub fn deposit_to_goal(
+        env: Env,
+        owner: Address,
+        goal_id: u64,
+        amount: i128,
+    ) -> Result<i128, ContractError> {
+        Self::fund_savings_goal(env, owner, goal_id, amount, false)
     }
 
-    /// Step 2 of 2-step admin transfer (#365).
+    /// Moves `amount` of the owner's vault savings, such as payouts kept from
+    /// released escrows, into one of their savings goals.
     ///
-    /// The proposed admin accepts the role. Only callable by the address that was
-    /// previously proposed via `propose_admin`. On success the caller becomes the
-    /// new admin and the pending proposal is cleared.
+    /// Returns the goal's new balance.
     ///
     /// # Authorization
     ///
-    /// Requires authentication from the proposed admin address.
-    pub fn accept_admin(env: Env) -> Result<(), ContractError> {
-        let new_admin = get_pending_admin(&env)
-            .ok_or(ContractError::NoPendingAdminTransfer)?;
+    /// Requires authentication from the owner.
+    pub fn move_savings_to_goal(
+        env: Env,
+        owner: Address,
+        goal_id: u64,
+        amount: i128,
+    ) -> Result<i128, ContractError> {
+        Self::fund_savings_goal(env, owner, goal_id, amount, true)
+    }
 
-        new_admin.require_auth();
+    fn fund_savings_goal(
+        env: Env,
+        owner: Address,
+        goal_id: u64,
+        amount: i128,
+        from_vault: bool,
+    ) -> Result<i128, ContractError> {
+        owner.require_auth();
 
-        let old_admin = get_admin(&env)?;
+        let mut goal = savings::owned_goal(&env, &owner, goal_id)?;
+        let balance = savings::fund_goal(&env, &mut goal, amount, from_vault)?;
+        emit_savings_goal_funded(&env, goal_id, amount, from_vault, balance);
+        Ok(balance)
+    }
 
-        // Update legacy admin pointer
-        set_admin(&env, &new_admin);
+    /// Withdraws `amount` from a savings goal to the owner's wallet.
+    ///
+    /// Free once the goal has reached its target or deadline; otherwise
+    /// `GOAL_EARLY_WITHDRAWAL_FEE_BPS` of the amount is paid into the
+    /// insurance fund. Returns the fee charged.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::SavingsGoalNotFound` - Goal does not exist or is not the owner's
+    /// * `ContractError::InsufficientSavingsBalance` - Amount exceeds the goal balance
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the owner.
+    pub fn withdraw_from_goal(
+        env: Env,
+        owner: Address,
+        goal_id: u64,
+        amount: i128,
+    ) -> Result<i128, ContractError> {
+        owner.require_auth();
 
-        // Update role-based admin system
-        set_admin_role(&env, &old_admin, false);
-        set_admin_role(&env, &new_admin, true);
+        let mut goal = savings::owned_goal(&env, &owner, goal_id)?;
+        let fee = savings::withdraw_goal(&env, &mut goal, amount)?;
+        emit_savings_goal_withdrawn(&env, goal_id, amount, fee, goal.balance);
+        Ok(fee)
+    }
 
-        // Update role assignments
-        remove_role(&env, &old_admin, &Role::Admin);
-        assign_role(&env, &new_admin, &Role::Admin);
+    /// Returns a savings goal.
+    pub fn get_savings_goal(env: Env, goal_id: u64) -> Option<SavingsGoal> {
+        storage::get_savings_goal(&env, goal_id)
+    }
 
-        // Clear the pending proposal
-        clear_pending_admin(&env);
+    /// Returns a savings goal's progress towards its target in basis points.
+    pub fn get_savings_goal_progress(env: Env, goal_id: u64) -> Result<u32, ContractError> {
+        storage::get_savings_goal(&env, goal_id)
+            .map(|goal| goal.progress_bps())
+            .ok_or(ContractError::SavingsGoalNotFound)
+    }
 
-        emit_admin_transfer_accepted(&env, old_admin, new_admin);
+    /// Returns the IDs of the savings goals an owner has created, oldest first.
+    pub fn get_owner_savings_goals(env: Env, owner: Address) -> Vec<u64> {
+        storage::get_owner_savings_goals(&env, &owner)
+    }
 
-        Ok(())
+    pub fn get_escrow_ttl(env: Env) -> Result<u64, ContractError> {
+        crate::storage::get_escrow_ttl(&env)
     }
 
-    pub fn pause(env: Env) -> Result<(), ContractError> {
+    pub fn update_escrow_ttl(env: Env, ttl: u64) -> Result<(), ContractError> {
         let caller = get_admin(&env)?;
         require_admin(&env, &caller)?;
-
-        // Delegate to circuit breaker with bypass_checks = true (legacy wrapper)
-        circuit_breaker::do_emergency_pause(&env, &caller, PauseReason::MaintenanceWindow, true)
+        admin_log::record(&env, &caller, "update_escrow_ttl", (ttl,));
+        validate_escrow_ttl(ttl)?;
+        set_escrow_ttl(&env, ttl);
+        Ok(())
     }
 
-    pub fn unpause(env: Env) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
+    pub fn process_expired_escrows(
+        env: Env,
+        transfer_ids: Vec<u64>,
+    ) -> Result<Vec<u64>, ContractError> {
+        if transfer_ids.len() > get_max_expired_batch_size(&env) {
+            return Err(ContractError::InvalidBatchSize);
+        }
 
-        // Delegate to circuit breaker with bypass_timelock_quorum = true (legacy wrapper)
-        circuit_breaker::do_emergency_unpause(&env, &caller, true)
-    }
+        let usdc_token = get_usdc_token(&env)?;
+        let token_client = token::Client::new(&env, &usdc_token);
+        let mut processed_ids = Vec::new(&env);
 
-    // ── Circuit Breaker Entry Points ───────────────────────────────────────────
+        for i in 0..transfer_ids.len() {
+            let transfer_id = transfer_ids.get_unchecked(i);
+            let mut escrow = match crate::storage::get_escrow(&env, transfer_id) {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
 
-    /// Pauses the contract with a structured reason. Requires Admin role.
-    pub fn emergency_pause(
-        env: Env,
-        caller: Address,
-        reason: PauseReason,
-    ) -> Result<(), ContractError> {
-        circuit_breaker::do_emergency_pause(&env, &caller, reason, false)
-    }
+            if escrow.status != EscrowStatus::Pending {
+                continue;
+            }
 
-    /// Unpauses the contract, enforcing timelock and quorum. Requires Admin role.
-    pub fn emergency_unpause(env: Env, caller: Address) -> Result<(), ContractError> {
-        circuit_breaker::do_emergency_unpause(&env, &caller, false)
+            let is_expired = match escrow.expiry {
+                Some(expiry) => Expiry::Time(expiry).is_reached(&env),
+                None => false,
+            };
+
+            if !is_expired {
+                continue;
+            }
+
+            escrow.status = EscrowStatus::Refunded;
+            set_escrow(&env, transfer_id, &escrow);
+            token_transfer::transfer(
+                &token_client,
+                &env.current_contract_address(),
+                &escrow.sender,
+                escrow.amount,
+            )?;
+
+            emit_escrow_refunded(&env, transfer_id, escrow.sender, escrow.amount);
+            processed_ids.push_back(transfer_id);
+        }
+
+        Ok(processed_ids)
     }
 
-    /// Casts an admin vote to unpause; auto-unpauses when quorum is reached.
-    pub fn vote_unpause(env: Env, caller: Address) -> Result<(), ContractError> {
-        circuit_breaker::do_vote_unpause(&env, &caller)
+    pub fn is_paused(env: Env) -> bool {
+        crate::storage::is_paused(&env)
     }
 
-    /// Sets the timelock duration (0..=604800 seconds). Requires Admin role.
-    pub fn set_pause_timelock(
-        env: Env,
-        caller: Address,
-        seconds: u64,
-    ) -> Result<(), ContractError> {
-        if seconds > 604800 {
-            return Err(ContractError::InvalidTimelockDuration);
-        }
-        caller.require_auth();
-        require_role_admin(&env, &caller)?;
-        circuit_breaker_storage::set_timelock_seconds(&env, seconds);
+    pub fn update_rate_limit(env: Env, cooldown_seconds: u64) -> Result<(), ContractError> {
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+        admin_log::record(&env, &admin, "update_rate_limit", (cooldown_seconds,));
+
+        let old_seconds = get_rate_limit_cooldown(&env).unwrap_or(0);
+        set_rate_limit_cooldown(&env, cooldown_seconds);
+        emit_rate_limit_cooldown_updated(&env, admin, old_seconds, cooldown_seconds);
+
         Ok(())
     }
 
-    /// Sets the unpause quorum (1..=admin_count). Requires Admin role.
-    pub fn set_unpause_quorum(
+    pub fn get_rate_limit_cooldown(env: Env) -> Result<u64, ContractError> {
+        get_rate_limit_cooldown(&env)
+    }
+
+    pub fn get_last_settlement_time(env: Env, sender: Address) -> Option<u64> {
+        get_last_settlement_time(&env, &sender)
+    }
+
+    /// Set daily send limit for a currency/country pair (admin only).
+    ///
+    /// `limit` must be greater than zero. Zero is rejected to prevent
+    /// silently blocking all corridor transfers via an ambiguous no-op limit.
+    pub fn set_daily_limit(
         env: Env,
-        caller: Address,
-        quorum: u32,
+        currency: String,
+        country: String,
+        limit: i128,
     ) -> Result<(), ContractError> {
-        let admin_count = storage::get_admin_count(&env);
-        if quorum < 1 || quorum > admin_count {
-            return Err(ContractError::InvalidQuorum);
+        if limit <= 0 {
+            return Err(ContractError::InvalidAmount);
         }
-        caller.require_auth();
-        require_role_admin(&env, &caller)?;
-        circuit_breaker_storage::set_unpause_quorum(&env, quorum);
+
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+        admin_log::record(
+            &env,
+            &admin,
+            "set_daily_limit",
+            (currency.clone(), country.clone(), limit),
+        );
+
+        let old_limit = crate::storage::get_daily_limit(&env, &currency, &country)
+            .map(|cfg| cfg.limit);
+        crate::storage::set_daily_limit(&env, &currency, &country, limit);
+        crate::events::emit_daily_limit_updated(&env, currency, country, old_limit, limit, admin);
         Ok(())
     }
 
-    /// Sets the post-unpause cooldown period in seconds (0 disables cooldown).
+    /// Schedules a platform fee or daily limit change for a future time (admin only).
     ///
-    /// During this window after an emergency unpause, per-sender rate limits are
-    /// halved to throttle traffic. Max 7 days. Requires Admin role.
-    pub fn set_cooldown_period(
+    /// `effective_at` must be at least the governance timelock (and at least
+    /// one second) in the future. From then on the fee and limit getters
+    /// return the scheduled value until a later change replaces it.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidSchedule` - Too little notice, or too many changes pending
+    /// * `ContractError::FeeAboveCeiling` - Fee exceeds `MAX_PLATFORM_FEE_BPS`
+    /// * `ContractError::InvalidAmount` - Limit is not positive
+    pub fn schedule_config_change(
         env: Env,
         caller: Address,
-        seconds: u64,
-    ) -> Result<(), ContractError> {
-        if seconds > 604_800 {
-            return Err(ContractError::InvalidTimelockDuration);
-        }
-        caller.require_auth();
-        require_role_admin(&env, &caller)?;
-        circuit_breaker_storage::set_cooldown_period(&env, seconds);
-        Ok(())
+        change: ConfigChange,
+        effective_at: u64,
+    ) -> Result<u64, ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "schedule_config_change", (change.clone(), effective_at));
+        let scheduled = scheduled_config::schedule(&env, change, effective_at)?;
+        emit_config_change_scheduled(&env, scheduled.id, scheduled.change, effective_at);
+        Ok(scheduled.id)
     }
 
-    /// Returns the current post-unpause cooldown period in seconds.
-    pub fn get_cooldown_period(env: Env) -> u64 {
-        circuit_breaker_storage::get_cooldown_period(&env)
+    /// Withdraws a scheduled change before it takes effect (admin only).
+    pub fn cancel_config_change(env: Env, caller: Address, id: u64) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "cancel_config_change", (id,));
+        scheduled_config::cancel(&env, id)?;
+        emit_config_change_cancelled(&env, id);
+        Ok(())
     }
 
-    // ── Circuit Breaker View Entry Points ──────────────────────────────────────
-
-    /// Returns a snapshot of the full circuit-breaker state. No auth required.
-    pub fn get_circuit_breaker_status(env: Env) -> CircuitBreakerStatus {
-        circuit_breaker::build_status(&env)
+    /// Returns scheduled configuration changes ordered by effective time,
+    /// including due ones still shadowing the stored settings.
+    pub fn get_scheduled_config_changes(env: Env) -> Vec<ScheduledConfigChange> {
+        storage::get_scheduled_config_changes(&env)
     }
 
-    /// Returns the pause record for the given sequence number.
-    pub fn get_pause_record(env: Env, seq: u64) -> Result<PauseRecord, ContractError> {
-        circuit_breaker_storage::get_pause_record_by_seq(&env, seq)
-            .ok_or(ContractError::PauseRecordNotFound)
+    /// Sets the grace period applied to timestamp expiries (admin only).
+    ///
+    /// A timestamp expiry is only treated as reached once the ledger time is
+    /// more than `seconds` past it. The tolerance is applied by every expiry
+    /// check, so confirmation, batch settlement, and expiry sweeps agree on
+    /// whether a remittance has expired. Ledger-sequence expiries are exact.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::ExpiryToleranceTooLarge` - `seconds` exceeds `MAX_EXPIRY_TOLERANCE_SECONDS`
+    pub fn set_expiry_tolerance(env: Env, seconds: u64) -> Result<(), ContractError> {
+        if seconds > MAX_EXPIRY_TOLERANCE_SECONDS {
+            return Err(ContractError::ExpiryToleranceTooLarge);
+        }
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_expiry_tolerance", (seconds,));
+        let old_seconds = storage::get_expiry_tolerance(&env);
+        storage::set_expiry_tolerance(&env, seconds);
+        emit_expiry_tolerance_updated(&env, caller, old_seconds, seconds);
+        Ok(())
     }
 
-    /// Returns the active pause record, or None when not paused.
-    pub fn get_current_pause_record(env: Env) -> Option<PauseRecord> {
-        let seq = circuit_breaker_storage::get_active_pause_seq(&env)?;
-        circuit_breaker_storage::get_pause_record_by_seq(&env, seq)
+    /// Returns the expiry tolerance in seconds.
+    pub fn get_expiry_tolerance(env: Env) -> u64 {
+        storage::get_expiry_tolerance(&env)
     }
 
-    /// Returns the total number of pause events ever recorded.
-    pub fn get_pause_history_count(env: Env) -> u64 {
-        circuit_breaker_storage::get_pause_sequence(&env)
+    /// Set the maximum batch size for process_expired_remittances (admin only).
+    ///
+    /// # Arguments
+    /// * `size` - New batch size limit. Must be between 1 and 200.
+    ///
+    /// # Tradeoffs
+    /// Larger batches process more remittances per call but consume more ledger
+    /// resources (CPU instructions and memory). Keep below 200 to avoid hitting
+    /// Soroban resource limits. The default (50) is a safe starting point.
+    pub fn set_max_expired_batch_size(
+        env: Env,
+        size: u32,
+    ) -> Result<(), ContractError> {
+        if size < 1 || size > 200 {
+            return Err(ContractError::InvalidBatchSize);
+        }
+        let admin = get_admin(&env)?;
+        admin.require_auth();
+        admin_log::record(&env, &admin, "set_max_expired_batch_size", (size,));
+        crate::storage::set_max_expired_batch_size(&env, size);
+        Ok(())
     }
 
-    // ── Escrow Functions ───────────────────────────────────────────
+    /// Get daily send limit for a currency/country pair.
+    pub fn get_daily_limit(env: Env, currency: String, country: String) -> Option<i128> {
+        crate::storage::get_daily_limit(&env, &currency, &country).map(|cfg| cfg.limit)
+    }
 
-    pub fn create_escrow(
+    /// Get a sender's daily limit status for a currency/country corridor.
+    ///
+    /// Returns `(limit, used, remaining, resets_at)` where:
+    /// - `limit` is the configured corridor limit in stroops (0 = no limit set)
+    /// - `used` is the rolling 24-hour volume already sent by this sender
+    /// - `remaining` is `limit - used` (0 when no limit is configured)
+    /// - `resets_at` is the Unix timestamp when the oldest in-window transfer ages out
+    pub fn get_daily_limit_status(
         env: Env,
         sender: Address,
-        recipient: Address,
-        amount: i128,
-    ) -> Result<u64, ContractError> {
-        sender.require_auth();
+        currency: String,
+        country: String,
+    ) -> (i128, i128, i128, u64) {
+        use crate::config::DAILY_LIMIT_WINDOW_SECONDS;
+        use crate::storage::get_user_transfers;
 
-        if amount <= 0 {
-            return Err(ContractError::InvalidAmount);
-        }
+        let now = env.ledger().timestamp();
+        let window_start = now.saturating_sub(DAILY_LIMIT_WINDOW_SECONDS);
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(&sender, &env.current_contract_address(), &amount);
+        let transfers = get_user_transfers(&env, &sender);
+        let mut used: i128 = 0;
+        let mut oldest_in_window: u64 = now;
 
-        let counter = get_escrow_counter(&env)?;
-        let transfer_id = counter.checked_add(1).ok_or(ContractError::Overflow)?;
+        for i in 0..transfers.len() {
+            let record = transfers.get_unchecked(i);
+            if record.timestamp > window_start
+                && record.currency == currency
+                && record.country == country
+            {
+                used = used.saturating_add(record.amount);
+                if record.timestamp < oldest_in_window {
+                    oldest_in_window = record.timestamp;
+                }
+            }
+        }
 
-        let ttl = crate::storage::get_escrow_ttl(&env)?;
-        let expiry = if ttl == 0 {
-            None
-        } else {
-            Some(
-                env.ledger()
-                    .timestamp()
-                    .checked_add(ttl)
-                    .ok_or(ContractError::Overflow)?,
-            )
-        };
+        let limit = crate::storage::get_daily_limit(&env, &currency, &country)
+            .map(|cfg| cfg.limit)
+            .unwrap_or(0);
 
-        let escrow = Escrow {
-            transfer_id,
-            sender: sender.clone(),
-            recipient: recipient.clone(),
-            amount,
-            expiry,
-            status: EscrowStatus::Pending,
+        let remaining = if limit > 0 {
+            limit.saturating_sub(used).max(0)
+        } else {
+            0
         };
 
-        set_escrow(&env, transfer_id, &escrow);
-        set_escrow_counter(&env, transfer_id);
-
-        emit_escrow_created(&env, transfer_id, sender, recipient, amount);
+        // resets_at: when the oldest in-window transfer exits the 24h window
+        let resets_at = oldest_in_window.saturating_add(DAILY_LIMIT_WINDOW_SECONDS);
 
-        Ok(transfer_id)
+        (limit, used, remaining, resets_at)
     }
 
-    pub fn release_escrow(env: Env, transfer_id: u64) -> Result<(), ContractError> {
-        let mut escrow = get_escrow(&env, transfer_id)?;
-
-        let caller = get_admin(&env)?;
+    /// Extend TTLs for critical persistent and instance storage keys (admin only).
+    ///
+    /// Bumps the TTL of the contract instance and all persistent remittance/agent
+    /// records so they do not expire between backend scheduler runs.
+    ///
+    /// # Parameters
+    /// - `caller`: Admin address (must be authorised)
+    /// - `extend_by_ledgers`: Number of ledgers to extend TTL by (max 3_110_400 ≈ 1 year)
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or a `ContractError` if the caller is not an admin.
+    pub fn extend_storage_ttl(
+        env: Env,
+        caller: Address,
+        extend_by_ledgers: u32,
+    ) -> Result<(), ContractError> {
         require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "extend_storage_ttl", (extend_by_ledgers,));
+        crate::storage::extend_critical_ttls(&env, extend_by_ledgers);
+        Ok(())
+    }
 
-        if escrow.status != EscrowStatus::Pending {
-            return Err(ContractError::InvalidEscrowStatus);
-        }
-
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &escrow.recipient,
-            &escrow.amount,
-        );
+    pub fn get_version(env: Env) -> soroban_sdk::String {
+        soroban_sdk::String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    }
 
-        escrow.status = EscrowStatus::Released;
-        set_escrow(&env, transfer_id, &escrow);
+    /// Returns the current health status of the contract.
+    ///
+    /// Reports initialization state, pause status, admin count, total remittances
+    /// created, and accumulated platform fees. Safe to call at any time.
+    pub fn health(env: Env) -> health::HealthStatus {
+        health::health(&env)
+    }
 
-        emit_escrow_released(&env, transfer_id, escrow.recipient, escrow.amount);
+    /// Returns a single snapshot of pause states, remittance counts, volume,
+    /// TVL, fee totals, and agent count, so monitors can scrape the contract's
+    /// health with one simulated call.
+    ///
+    /// Principal escrowed in open remittances is not tracked as a running
+    /// total, so `fees_covered` only checks that the settlement token balance
+    /// covers the fees owed.
+    pub fn get_metrics(env: Env) -> health::ContractMetrics {
+        health::metrics(&env)
+    }
 
-        Ok(())
+    /// Checks the contract's core invariants and returns every violation
+    /// found; an empty list means they all hold (Admin only).
+    ///
+    /// Reads every indexed remittance, so it is intended for simulation, the
+    /// test and fuzz suites, and testnet runbooks.
+    pub fn assert_invariants(
+        env: Env,
+        caller: Address,
+    ) -> Result<Vec<InvariantViolation>, ContractError> {
+        require_admin(&env, &caller)?;
+        Ok(invariants::check(&env))
     }
 
-    pub fn refund_escrow(env: Env, transfer_id: u64) -> Result<(), ContractError> {
-        let mut escrow = get_escrow(&env, transfer_id)?;
+    /// Batch settle multiple remittances with net settlement optimization.
+    ///
+    /// This function processes multiple remittances in a single transaction and applies
+    /// net settlement logic to offset opposing transfers between the same parties.
+    /// Only the net difference is executed on-chain, reducing total token transfers.
+    ///
+    /// # Benefits
+    /// - Reduces on-chain transfer count by offsetting opposing flows
+    /// - Preserves all fees and accounting integrity
+    /// - Deterministic and order-independent results
+    /// - Gas-efficient batch processing
+    ///
+    /// # Example
+    /// If batch contains:
+    /// - Remittance 1: A -> B: 100 USDC (fee: 2)
+    /// - Remittance 2: B -> A: 90 USDC (fee: 1.8)
+    ///
+    /// Result: Single transfer of 10 USDC from A to B, total fees: 3.8
+    ///
+    /// Remittances in different tokens are grouped and netted separately, and
+    /// each agent receives a single transfer per token.
+    ///
+    /// # Parameters
+    /// - `entries`: Vector of BatchSettlementEntry containing remittance IDs to settle
+    ///
+    /// # Returns
+    /// BatchSettlementResult with list of successfully settled remittance IDs
+    ///
+    /// # Authorization
+    /// Every agent with a remittance in the batch must authorize it. Each agent
+    /// signs only the IDs of its own remittances (via `require_auth_for_args`),
+    /// so a submitter cannot settle anything an agent has not approved.
+    ///
+    /// # Errors
+    /// - ContractPaused: Contract is in paused state
+    /// - InvalidAmount: Batch size exceeds MAX_BATCH_SIZE or is empty
+    /// - RemittanceNotFound: One or more remittance IDs don't exist
+    /// - InvalidStatus: One or more remittances are not in Pending status
+    /// - DuplicateSettlement: Duplicate remittance IDs in batch
+    /// - TokenBatchLimitExceeded: The batch moves more of a token than its limit
+    /// - Overflow: Arithmetic overflow in calculations
+    pub fn batch_settle_with_netting(
+        env: Env,
+        entries: Vec<BatchSettlementEntry>,
+    ) -> Result<BatchSettlementResult, ContractError> {
+        validate_netting_batch(&env, &entries)?;
+        let batch_size = entries.len();
 
-        escrow.sender.require_auth();
+        // Load all remittances and validate
+        let mut remittances = Vec::new(&env);
+        let mut seen_ids = Vec::new(&env);
 
-        if escrow.status != EscrowStatus::Pending {
-            return Err(ContractError::InvalidEscrowStatus);
-        }
+        for i in 0..batch_size {
+            let entry = entries.get_unchecked(i);
+            let remittance_id = entry.remittance_id;
 
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &escrow.sender,
-            &escrow.amount,
-        );
+            // Check for duplicate IDs in batch
+            for j in 0..seen_ids.len() {
+                if seen_ids.get_unchecked(j) == remittance_id {
+                    return Err(ContractError::DuplicateSettlement);
+                }
+            }
+            seen_ids.push_back(remittance_id);
 
-        escrow.status = EscrowStatus::Refunded;
-        set_escrow(&env, transfer_id, &escrow);
+            remittances.push_back(load_netting_entry(&env, remittance_id)?);
+        }
 
-        emit_escrow_refunded(&env, transfer_id, escrow.sender, escrow.amount);
+        // Each affected agent must authorize the settlement of its own remittances.
+        let mut agent_ids: Map<Address, Vec<u64>> = Map::new(&env);
+        for remittance in remittances.iter() {
+            let mut ids = agent_ids
+                .get(remittance.agent.clone())
+                .unwrap_or(Vec::new(&env));
+            ids.push_back(remittance.id);
+            agent_ids.set(remittance.agent.clone(), ids);
+        }
+        for (agent, ids) in agent_ids.iter() {
+            agent.require_auth_for_args((ids,).into_val(&env));
+        }
 
-        Ok(())
-    }
+        // Group by token: flows in different tokens cannot offset each other,
+        // and each group needs its own token client.
+        let token_batches = batch_grouping::group_by_token(&env, &remittances)?;
+
+        // Checks-effects-interactions: compute every transfer and commit all
+        // state before any token is moved, so a token contract that calls
+        // back into this contract sees the remittances already Completed.
+        let mut transfers: Vec<(batch_grouping::TokenBatch, Map<Address, i128>)> = Vec::new(&env);
+        for token_batch in token_batches.iter() {
+            // Compute net settlements within the token group.
+            // Gas note: netting offsets opposing flows so fewer token transfer calls are executed.
+            let netting_result = compute_net_settlements(&env, &token_batch.remittances)?;
+            let net_transfers = netting_result.net_transfers;
+
+            // Validate net settlement calculations
+            validate_net_settlement(&token_batch.remittances, &net_transfers)?;
+
+            // One transfer per (token, recipient), however many remittances it covers
+            let plan = batch_grouping::aggregate_payouts(&env, &net_transfers)?;
+
+            // Each token's fees are credited once, in the token they were paid in
+            add_token_fee(&env, &token_batch.token, plan.fees)?;
+            transfers.push_back((token_batch, plan.payouts));
+        }
+
+        // Mark all remittances as completed before paying out
+        let mut settled_ids = Vec::new(&env);
+        for i in 0..remittances.len() {
+            let mut remittance = remittances.get_unchecked(i);
+            remittance.status = RemittanceStatus::Completed;
+            remittance.completed_at = Some(env.ledger().timestamp());
+            set_remittance(&env, remittance.id, &remittance);
+            corridor_utilization::release_pending(&env, &remittance);
+            agent_tiers::release_pending(&env, &remittance);
+            remittances.set(i, remittance.clone());
+            settled_ids.push_back(remittance.id);
+        }
 
-    pub fn get_escrow(env: Env, transfer_id: u64) -> Result<Escrow, ContractError> {
-        crate::storage::get_escrow(&env, transfer_id)
-    }
+        for (token_batch, payouts) in transfers.iter() {
+            let token_client = token::Client::new(&env, &token_batch.token);
+            for (to, payout_amount) in payouts.iter() {
+                token_transfer::transfer(
+                    &token_client,
+                    &env.current_contract_address(),
+                    &to,
+                    payout_amount,
+                )?;
+
+                // Reference the first remittance in the group paid to this recipient
+                let mut reference = None;
+                for remittance in token_batch.remittances.iter() {
+                    if remittance.agent == to {
+                        reference = Some(remittance);
+                        break;
+                    }
+                }
+                let (remittance_id, from) = match reference {
+                    Some(remittance) => (remittance.id, remittance.sender),
+                    None => (0, env.current_contract_address()),
+                };
+                emit_settlement_completed(
+                    &env,
+                    remittance_id,
+                    from,
+                    to,
+                    token_batch.token.clone(),
+                    payout_amount,
+                );
+            }
+        }
 
-    pub fn get_escrow_ttl(env: Env) -> Result<u64, ContractError> {
-        crate::storage::get_escrow_ttl(&env)
-    }
+        for remittance in remittances.iter() {
+            // Emit individual remittance completion event
+            observers::notify_observers(&env, ObserverEvent::Completed, &remittance);
+            performance::record(&env, PerformanceEvent::Completed, &remittance);
+            emit_tag_events(&env, &remittance, symbol_short!("complete"));
+            if !emit_minimal_lifecycle(&env, &remittance) {
+                emit_remittance_completed(
+                    &env,
+                    remittance.id,
+                    remittance.sender.clone(),
+                    remittance.agent.clone(),
+                    remittance.settlement_latency().unwrap_or(0),
+                    remittance.processing_latency(),
+                );
+            }
+        }
 
-    pub fn update_escrow_ttl(env: Env, ttl: u64) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
-        validate_escrow_ttl(ttl)?;
-        set_escrow_ttl(&env, ttl);
-        Ok(())
+        Ok(BatchSettlementResult { settled_ids })
     }
 
-    pub fn process_expired_escrows(
+    /// Previews `batch_settle_with_netting` without moving tokens or writing
+    /// state, so operators can preflight a large batch.
+    ///
+    /// Every entry goes through the same validation as the real settlement
+    /// and reports its own outcome, so a single call lists all entries that
+    /// would be rejected. When every entry passes, the batch is grouped by
+    /// token and netted, and the preview lists the net transfers and fees
+    /// the settlement would make. Agent authorization is not checked.
+    ///
+    /// # Errors
+    /// - ContractPaused: Contract is in paused state
+    /// - InvalidAmount: Batch size exceeds MAX_BATCH_SIZE or is empty
+    pub fn dry_run_batch_settle(
         env: Env,
-        transfer_ids: Vec<u64>,
-    ) -> Result<Vec<u64>, ContractError> {
-        if transfer_ids.len() > get_max_expired_batch_size(&env) {
-            return Err(ContractError::InvalidBatchSize);
-        }
+        entries: Vec<BatchSettlementEntry>,
+    ) -> Result<BatchSettlementPreview, ContractError> {
+        validate_netting_batch(&env, &entries)?;
 
-        let now = env.ledger().timestamp();
-        let usdc_token = get_usdc_token(&env)?;
-        let token_client = token::Client::new(&env, &usdc_token);
-        let mut processed_ids = Vec::new(&env);
+        let mut previews = Vec::new(&env);
+        let mut remittances = Vec::new(&env);
+        let mut seen_ids = Vec::new(&env);
+        let mut first_error: Option<ContractError> = None;
 
-        for i in 0..transfer_ids.len() {
-            let transfer_id = transfer_ids.get_unchecked(i);
-            let mut escrow = match crate::storage::get_escrow(&env, transfer_id) {
-                Ok(value) => value,
-                Err(_) => continue,
+        for entry in entries.iter() {
+            let remittance_id = entry.remittance_id;
+            let result = if seen_ids.contains(remittance_id) {
+                Err(ContractError::DuplicateSettlement)
+            } else {
+                load_netting_entry(&env, remittance_id)
             };
+            seen_ids.push_back(remittance_id);
 
-            if escrow.status != EscrowStatus::Pending {
-                continue;
-            }
-
-            let is_expired = match escrow.expiry {
-                Some(expiry) => now > expiry,
-                None => false,
+            let preview = match result {
+                Ok(remittance) => {
+                    let preview = BatchEntryPreview {
+                        remittance_id,
+                        would_succeed: true,
+                        payout_amount: remittance
+                            .amount
+                            .checked_sub(remittance.fee)
+                            .ok_or(ContractError::Overflow)?,
+                        fee: remittance.fee,
+                        error_code: None,
+                    };
+                    remittances.push_back(remittance);
+                    preview
+                }
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                    BatchEntryPreview {
+                        remittance_id,
+                        would_succeed: false,
+                        payout_amount: 0,
+                        fee: 0,
+                        error_code: Some(error as u32),
+                    }
+                }
             };
+            previews.push_back(preview);
+        }
 
-            if !is_expired {
-                continue;
+        let mut transfers = Vec::new(&env);
+        let mut fees = Vec::new(&env);
+        if first_error.is_none() {
+            match plan_netting_transfers(&env, &remittances) {
+                Ok((planned, planned_fees)) => {
+                    transfers = planned;
+                    fees = planned_fees;
+                }
+                Err(error) => first_error = Some(error),
             }
+        }
 
-            token_client.transfer(
-                &env.current_contract_address(),
-                &escrow.sender,
-                &escrow.amount,
-            );
-
-            escrow.status = EscrowStatus::Refunded;
-            set_escrow(&env, transfer_id, &escrow);
+        Ok(BatchSettlementPreview {
+            entries: previews,
+            would_succeed: first_error.is_none(),
+            error_code: first_error.map(|error| error as u32),
+            transfers,
+            fees,
+        })
+    }
 
-            emit_escrow_refunded(&env, transfer_id, escrow.sender, escrow.amount);
-            processed_ids.push_back(transfer_id);
+    /// Registers a bilateral netting pair between two agents.
+    ///
+    /// Obligations between the pair are then recorded with
+    /// `record_netting_obligation` and settled by a single net transfer at
+    /// `settle_netting_checkpoint`.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from both agents.
+    pub fn register_netting_pair(
+        env: Env,
+        agent_a: Address,
+        agent_b: Address,
+        token: Address,
+    ) -> Result<(), ContractError> {
+        if agent_a == agent_b {
+            return Err(ContractError::InvalidAddress);
+        }
+        crate::storage::require_agent_authorized(&env, &agent_a)?;
+        crate::storage::require_agent_authorized(&env, &agent_b)?;
+        if !is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
         }
 
-        Ok(processed_ids)
+        let pair = netting_pairs::register(&env, &agent_a, &agent_b, &token)?;
+        emit_netting_pair_registered(&env, pair.agent_a, pair.agent_b, token);
+        Ok(())
     }
 
-    pub fn is_paused(env: Env) -> bool {
-        crate::storage::is_paused(&env)
+    /// Removes a netting pair. Either agent may remove it once the position is settled.
+    pub fn remove_netting_pair(env: Env, caller: Address, counterparty: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        netting_pairs::remove(&env, &caller, &counterparty)?;
+        emit_netting_pair_removed(&env, caller, counterparty);
+        Ok(())
     }
 
-    pub fn update_rate_limit(env: Env, cooldown_seconds: u64) -> Result<(), ContractError> {
-        let admin = get_admin(&env)?;
-        admin.require_auth();
+    /// Records that `debtor` owes `creditor` `amount` under their netting pair.
+    ///
+    /// No tokens move; the pair's net position is updated.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `debtor`.
+    pub fn record_netting_obligation(
+        env: Env,
+        debtor: Address,
+        creditor: Address,
+        amount: i128,
+    ) -> Result<i128, ContractError> {
+        validate_amount(amount)?;
+        crate::storage::require_agent_authorized(&env, &debtor)?;
 
-        set_rate_limit_cooldown(&env, cooldown_seconds);
+        let pair = netting_pairs::record_obligation(&env, &debtor, &creditor, amount)?;
+        emit_netting_obligation(&env, debtor, creditor, amount, pair.net_position);
+        Ok(pair.net_position)
+    }
 
-        Ok(())
+    /// Settles a netting pair's net position with a single transfer.
+    ///
+    /// Anyone may trigger the checkpoint, but the net debtor must authorize the
+    /// token transfer. A zero position simply resets the checkpoint time.
+    pub fn settle_netting_checkpoint(
+        env: Env,
+        agent_a: Address,
+        agent_b: Address,
+    ) -> Result<NettingCheckpoint, ContractError> {
+        let result = netting_pairs::checkpoint(&env, &agent_a, &agent_b)?;
+        emit_netting_checkpoint(
+            &env,
+            result.debtor.clone(),
+            result.creditor.clone(),
+            result.amount,
+            result.obligations,
+        );
+        Ok(result)
     }
 
-    pub fn get_rate_limit_cooldown(env: Env) -> Result<u64, ContractError> {
-        get_rate_limit_cooldown(&env)
+    /// Returns the netting pair between two agents, in either order.
+    pub fn get_netting_pair(env: Env, agent_a: Address, agent_b: Address) -> Option<NettingPair> {
+        netting_pairs::load(&env, &agent_a, &agent_b).ok()
     }
 
-    pub fn get_last_settlement_time(env: Env, sender: Address) -> Option<u64> {
-        get_last_settlement_time(&env, &sender)
+    /// Creates multiple remittances in one transaction (#590).
+    pub fn create_batch_remittance(
+        env: Env,
+        sender: Address,
+        entries: Vec<BatchCreateEntry>,
+    ) -> Result<Vec<u64>, ContractError> {
+        let ids = Self::batch_create_remittances(env.clone(), sender.clone(), entries)?;
+        env.events().publish(
+            (soroban_sdk::symbol_short!("batch"), soroban_sdk::symbol_short!("created")),
+            (sender, ids.len()),
+        );
+        Ok(ids)
     }
 
-    /// Set daily send limit for a currency/country pair (admin only).
+    /// Confirms payouts for multiple remittances in one transaction (#590).
     ///
-    /// `limit` must be greater than zero. Zero is rejected to prevent
-    /// silently blocking all corridor transfers via an ambiguous no-op limit.
-    pub fn set_daily_limit(
+    /// Each entry is settled exactly as `confirm_payout` would, except that
+    /// fees are summed in memory and added to the accumulated fee balance
+    /// with a single read and write once every entry has settled.
+    ///
+    /// With `compact` set, the per-remittance completion events are replaced
+    /// by a single `batch`/`complete` event carrying `(id, payout)` pairs and
+    /// per-agent payout totals, keeping event cost flat for large batches.
+    ///
+    /// # Authorization
+    ///
+    /// `agent` (the agent itself, its sub-operator, or the settlement
+    /// operator) authorizes the exact settlement set rather than the call:
+    /// `require_auth_for_args` is given a single argument, the list of
+    /// `(remittance_id, amount, agent)` entries in submission order. A relayer
+    /// therefore cannot add, drop, reorder, or swap entries after signing.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidBatchSize` - Batch is empty
+    /// * `ContractError::BatchTooLarge` - Batch exceeds `MAX_SAFE_BATCH_SIZE`;
+    ///   see `estimate_batch_cost`
+    /// * `ContractError::DuplicateSettlement` - An ID appears more than once
+    /// * Any error returned by `confirm_payout`
+    pub fn confirm_batch_payout(
         env: Env,
-        currency: String,
-        country: String,
-        limit: i128,
-    ) -> Result<(), ContractError> {
-        if limit <= 0 {
-            return Err(ContractError::InvalidAmount);
-        }
-
-        let admin = get_admin(&env)?;
-        admin.require_auth();
+        agent: Address,
+        remittance_ids: Vec<u64>,
+        compact: bool,
+    ) -> Result<Vec<u64>, ContractError> {
+        let (confirmed, _) = settle_batch(&env, &agent, &remittance_ids, compact, false)?;
+        Ok(confirmed)
+    }
 
-        let old_limit = crate::storage::get_daily_limit(&env, &currency, &country)
-            .map(|cfg| cfg.limit);
-        crate::storage::set_daily_limit(&env, &currency, &country, limit);
-        crate::events::emit_daily_limit_updated(&env, currency, country, old_limit, limit, admin);
-        Ok(())
+    /// Confirms payouts for multiple remittances like `confirm_batch_payout`,
+    /// without letting one undeliverable payout abort the batch.
+    ///
+    /// Every entry settles. When a token transfer fails, for example because
+    /// the payee's trustline is frozen, the amount is held as an undelivered
+    /// payout the payee withdraws with `claim_undelivered_payout`, and a
+    /// `payout`/`held` event is emitted.
+    ///
+    /// Returns `(remittance_id, error code)` for each entry with a held
+    /// payout, where the code is the `ContractError` of the failed transfer.
+    ///
+    /// # Authorization
+    ///
+    /// Same as `confirm_batch_payout`.
+    ///
+    /// # Errors
+    ///
+    /// Same as `confirm_batch_payout`, except that token transfer errors are
+    /// recorded rather than returned.
+    pub fn confirm_batch_payout_best_effort(
+        env: Env,
+        agent: Address,
+        remittance_ids: Vec<u64>,
+        compact: bool,
+    ) -> Result<Vec<(u64, u32)>, ContractError> {
+        let (_, failures) = settle_batch(&env, &agent, &remittance_ids, compact, true)?;
+        Ok(failures)
     }
 
-    /// Set the maximum batch size for process_expired_remittances (admin only).
+    /// Withdraws payouts held for `payee` in `token` after failed transfers.
     ///
-    /// # Arguments
-    /// * `size` - New batch size limit. Must be between 1 and 200.
+    /// # Authorization
     ///
-    /// # Tradeoffs
-    /// Larger batches process more remittances per call but consume more ledger
-    /// resources (CPU instructions and memory). Keep below 200 to avoid hitting
-    /// Soroban resource limits. The default (50) is a safe starting point.
-    pub fn set_max_expired_batch_size(
+    /// Requires authentication from the payee.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::NoUndeliveredPayout` - Nothing is held for the payee
+    /// * Token transfer errors if the payout still cannot be delivered
+    pub fn claim_undelivered_payout(
         env: Env,
-        size: u32,
-    ) -> Result<(), ContractError> {
-        if size < 1 || size > 200 {
-            return Err(ContractError::InvalidBatchSize);
+        payee: Address,
+        token: Address,
+    ) -> Result<i128, ContractError> {
+        payee.require_auth();
+        let amount = storage::get_undelivered_payout(&env, &payee, &token);
+        if amount <= 0 {
+            return Err(ContractError::NoUndeliveredPayout);
         }
-        let admin = get_admin(&env)?;
-        admin.require_auth();
-        crate::storage::set_max_expired_batch_size(&env, size);
+        storage::set_undelivered_payout(&env, &payee, &token, 0);
+        token_transfer::transfer(
+            &token::Client::new(&env, &token),
+            &env.current_contract_address(),
+            &payee,
+            amount,
+        )?;
+        emit_undelivered_payout_claimed(&env, payee, token, amount);
+        Ok(amount)
+    }
+
+    /// Returns the payout held for `payee` in `token` after failed transfers.
+    pub fn get_undelivered_payout(env: Env, payee: Address, token: Address) -> i128 {
+        storage::get_undelivered_payout(&env, &payee, &token)
+    }
+
+    /// Estimates the CPU instructions `confirm_batch_payout` needs for
+    /// `entries` remittances, so callers can size batches before submitting.
+    pub fn estimate_batch_cost(entries: u32) -> u64 {
+        crate::config::estimate_batch_cost(entries)
+    }
+
+    /// Returns the largest payout batch accepted, `MAX_SAFE_BATCH_SIZE`.
+    pub fn get_max_safe_batch_size() -> u32 {
+        MAX_SAFE_BATCH_SIZE
+    }
+
+    /// Sets the minimum agent reputation threshold (#591). Admin only.
+    pub fn set_min_agent_reputation(env: Env, threshold: u32) -> Result<(), ContractError> {
+        if threshold > 100 { return Err(ContractError::InvalidReputationScore); }
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_min_agent_reputation", (threshold,));
+        storage::set_min_agent_reputation(&env, threshold);
         Ok(())
     }
 
-    /// Get daily send limit for a currency/country pair.
-    pub fn get_daily_limit(env: Env, currency: String, country: String) -> Option<i128> {
-        crate::storage::get_daily_limit(&env, &currency, &country).map(|cfg| cfg.limit)
+    /// Returns the current minimum agent reputation threshold.
+    pub fn get_min_agent_reputation(env: Env) -> u32 {
+        storage::get_min_agent_reputation(&env)
     }
 
-    /// Get a sender's daily limit status for a currency/country corridor.
-    ///
-    /// Returns `(limit, used, remaining, resets_at)` where:
-    /// - `limit` is the configured corridor limit in stroops (0 = no limit set)
-    /// - `used` is the rolling 24-hour volume already sent by this sender
-    /// - `remaining` is `limit - used` (0 when no limit is configured)
-    /// - `resets_at` is the Unix timestamp when the oldest in-window transfer ages out
-    pub fn get_daily_limit_status(
-        env: Env,
-        sender: Address,
-        currency: String,
-        country: String,
-    ) -> (i128, i128, i128, u64) {
-        use crate::config::DAILY_LIMIT_WINDOW_SECONDS;
-        use crate::storage::get_user_transfers;
+    /// Add a token to the whitelist. Only admins can call this.
+    pub fn add_whitelisted_token(env: Env, token: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "add_whitelisted_token", (token.clone(),));
 
-        let now = env.ledger().timestamp();
-        let window_start = now.saturating_sub(DAILY_LIMIT_WINDOW_SECONDS);
+        if is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenAlreadyWhitelisted);
+        }
 
-        let transfers = get_user_transfers(&env, &sender);
-        let mut used: i128 = 0;
-        let mut oldest_in_window: u64 = now;
+        set_token_whitelisted(&env, &token, true);
 
-        for i in 0..transfers.len() {
-            let record = transfers.get_unchecked(i);
-            if record.timestamp > window_start
-                && record.currency == currency
-                && record.country == country
-            {
-                used = used.saturating_add(record.amount);
-                if record.timestamp < oldest_in_window {
-                    oldest_in_window = record.timestamp;
-                }
-            }
+        emit_token_whitelisted(&env, token.clone(), caller);
+        log_whitelist_token(&env, &token);
+
+        Ok(())
+    }
+
+    /// Remove a token from the whitelist. Only admins can call this.
+    pub fn remove_whitelisted_token(env: Env, token: Address) -> Result<(), ContractError> {
+        let caller = get_admin(&env)?;
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "remove_whitelisted_token", (token.clone(),));
+
+        if !is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
         }
 
-        let limit = crate::storage::get_daily_limit(&env, &currency, &country)
-            .map(|cfg| cfg.limit)
-            .unwrap_or(0);
+        set_token_whitelisted(&env, &token, false);
 
-        let remaining = if limit > 0 {
-            limit.saturating_sub(used).max(0)
-        } else {
-            0
-        };
+        emit_token_removed_from_whitelist(&env, token.clone(), caller);
+        log_remove_whitelisted_token(&env, &token);
 
-        // resets_at: when the oldest in-window transfer exits the 24h window
-        let resets_at = oldest_in_window.saturating_add(DAILY_LIMIT_WINDOW_SECONDS);
+        Ok(())
+    }
 
-        (limit, used, remaining, resets_at)
+    /// Check if a token is whitelisted.
+    pub fn is_token_whitelisted(env: Env, token: Address) -> bool {
+        crate::storage::is_token_whitelisted(&env, &token)
     }
 
-    /// Extend TTLs for critical persistent and instance storage keys (admin only).
+    /// Get all whitelisted tokens.
     ///
-    /// Bumps the TTL of the contract instance and all persistent remittance/agent
-    /// records so they do not expire between backend scheduler runs.
+    /// Returns a vector of all token addresses that are currently whitelisted.
+    /// This is a public view function that can be called by anyone.
+    pub fn get_whitelisted_tokens(env: Env) -> Vec<Address> {
+        crate::storage::get_all_whitelisted_tokens(&env)
+    }
+
+    /// Update rate limit configuration. Only admins can call this.
     ///
     /// # Parameters
-    /// - `caller`: Admin address (must be authorised)
-    /// - `extend_by_ledgers`: Number of ledgers to extend TTL by (max 3_110_400 ≈ 1 year)
+    /// - `caller`: Admin address (must be authorized)
+    /// - `max_requests`: Maximum number of requests allowed per window
+    /// - `window_seconds`: Time window in seconds
+    /// - `enabled`: Whether rate limiting is enabled
     ///
-    /// # Returns
-    /// `Ok(())` on success, or a `ContractError` if the caller is not an admin.
-    pub fn extend_storage_ttl(
+    /// # Example
+    /// ```ignore
+    /// // Set rate limit to 50 requests per 30 seconds
+    /// contract.update_rate_limit_config(&admin, 50, 30, true)?;
+    /// ```
+    pub fn update_rate_limit_config(
         env: Env,
         caller: Address,
-        extend_by_ledgers: u32,
+        max_requests: u32,
+        window_seconds: u64,
+        enabled: bool,
     ) -> Result<(), ContractError> {
         require_admin(&env, &caller)?;
-        caller.require_auth();
-        crate::storage::extend_critical_ttls(&env, extend_by_ledgers);
+        admin_log::record(
+            &env,
+            &caller,
+            "update_rate_limit_config",
+            (max_requests, window_seconds, enabled),
+        );
+
+        let config = RateLimitConfig {
+            max_requests,
+            window_seconds,
+            enabled,
+        };
+
+        let old_config = crate::rate_limit::get_rate_limit_config(&env).ok();
+        set_rate_limit_config(&env, config.clone());
+        emit_rate_limit_updated(&env, caller, old_config, config);
+
         Ok(())
     }
 
-    pub fn get_version(env: Env) -> soroban_sdk::String {
-        soroban_sdk::String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    /// Get current rate limit configuration
+    ///
+    /// # Returns
+    /// Tuple of (max_requests, window_seconds, enabled)
+    pub fn get_rate_limit_config(env: Env) -> Result<(u32, u64, bool), ContractError> {
+        let config = crate::rate_limit::get_rate_limit_config(&env)?;
+        Ok((config.max_requests, config.window_seconds, config.enabled))
     }
 
-    /// Returns the current health status of the contract.
-    ///
-    /// Reports initialization state, pause status, admin count, total remittances
-    /// created, and accumulated platform fees. Safe to call at any time.
-    pub fn health(env: Env) -> health::HealthStatus {
-        health::health(&env)
+    /// Returns the platform-wide limits in force.
+    pub fn get_limits(env: Env) -> Result<Limits, ContractError> {
+        limits::current(&env)
     }
 
-    /// Batch settle multiple remittances with net settlement optimization.
+    /// Returns the limits change waiting for the governance timelock, if any.
+    pub fn get_pending_limits(env: Env) -> Option<PendingLimits> {
+        storage::get_pending_limits(&env)
+    }
+
+    /// Changes all platform-wide limits at once (admin only).
     ///
-    /// This function processes multiple remittances in a single transaction and applies
-    /// net settlement logic to offset opposing transfers between the same parties.
-    /// Only the net difference is executed on-chain, reducing total token transfers.
+    /// The change takes effect after the governance timelock, once applied
+    /// with `apply_limits`; without a timelock it is applied immediately.
+    /// Scheduling again replaces a change still pending. Returns when the
+    /// change takes effect.
     ///
-    /// # Benefits
-    /// - Reduces on-chain transfer count by offsetting opposing flows
-    /// - Preserves all fees and accounting integrity
-    /// - Deterministic and order-independent results
-    /// - Gas-efficient batch processing
+    /// # Errors
     ///
-    /// # Example
-    /// If batch contains:
-    /// - Remittance 1: A -> B: 100 USDC (fee: 2)
-    /// - Remittance 2: B -> A: 90 USDC (fee: 1.8)
+    /// * `ContractError::InvalidAmount` - Negative amount bounds, or a maximum below the minimum
+    /// * `ContractError::InvalidBatchSize` - Batch size not between 1 and 200
+    pub fn set_limits(env: Env, caller: Address, new_limits: Limits) -> Result<u64, ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_limits", (new_limits.clone(),));
+        let pending = limits::schedule(&env, new_limits)?;
+        emit_limits_scheduled(&env, caller, pending.limits, pending.effective_at);
+        if pending.effective_at <= env.ledger().timestamp() {
+            Self::apply_limits(env)?;
+        }
+        Ok(pending.effective_at)
+    }
+
+    /// Applies the pending limits change once its timelock has passed.
+    /// Anyone may call this.
     ///
-    /// Result: Single transfer of 10 USDC from A to B, total fees: 3.8
+    /// # Errors
+    ///
+    /// * `ContractError::ScheduledChangeNotFound` - No change is pending
+    /// * `ContractError::TimelockActive` - The timelock has not passed yet
+    pub fn apply_limits(env: Env) -> Result<(), ContractError> {
+        let (old, new) = limits::apply(&env)?;
+        emit_limits_updated(&env, old, new);
+        Ok(())
+    }
+
+    /// Get rate limit status for a specific address
     ///
     /// # Parameters
-    /// - `entries`: Vector of BatchSettlementEntry containing remittance IDs to settle
+    /// - `address`: Address to check
     ///
     /// # Returns
-    /// BatchSettlementResult with list of successfully settled remittance IDs
-    ///
-    /// # Errors
-    /// - ContractPaused: Contract is in paused state
-    /// - InvalidAmount: Batch size exceeds MAX_BATCH_SIZE or is empty
-    /// - RemittanceNotFound: One or more remittance IDs don't exist
-    /// - InvalidStatus: One or more remittances are not in Pending status
-    /// - DuplicateSettlement: Duplicate remittance IDs in batch
-    /// - Overflow: Arithmetic overflow in calculations
-    pub fn batch_settle_with_netting(
+    /// Tuple of (current_requests, max_requests, window_seconds)
+    pub fn get_rate_limit_status(
         env: Env,
-        entries: Vec<BatchSettlementEntry>,
-    ) -> Result<BatchSettlementResult, ContractError> {
-        if is_paused(&env) {
-            return Err(ContractError::ContractPaused);
-        }
-
-        // Validate batch size
-        let batch_size = entries.len();
-        if batch_size == 0 {
-            return Err(ContractError::InvalidAmount);
-        }
-        if batch_size > MAX_BATCH_SIZE {
-            return Err(ContractError::InvalidAmount);
-        }
-
-        // Load all remittances and validate
-        let mut remittances = Vec::new(&env);
-        let mut seen_ids = Vec::new(&env);
-
-        for i in 0..batch_size {
-            let entry = entries.get_unchecked(i);
-            let remittance_id = entry.remittance_id;
-
-            // Check for duplicate IDs in batch
-            for j in 0..seen_ids.len() {
-                if seen_ids.get_unchecked(j) == remittance_id {
-                    return Err(ContractError::DuplicateSettlement);
-                }
-            }
-            seen_ids.push_back(remittance_id);
-
-            // Load and validate remittance
-            let remittance = get_remittance(&env, remittance_id)?;
-
-            // Verify remittance is pending
-            if remittance.status != RemittanceStatus::Pending {
-                return Err(ContractError::InvalidStatus);
-            }
-
-            // Check for duplicate settlement execution
-            if has_settlement_hash(&env, remittance_id) {
-                return Err(ContractError::DuplicateSettlement);
-            }
+        address: Address,
+    ) -> Result<(u32, u32, u64), ContractError> {
+        crate::rate_limit::get_rate_limit_status(&env, &address)
+    }
 
-            // Check expiry
-            if let Some(expiry_time) = remittance.expiry {
-                let current_time = env.ledger().timestamp();
-                if current_time > expiry_time {
-                    return Err(ContractError::SettlementExpired);
-                }
-            }
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Protocol Fee Management
+    // ═══════════════════════════════════════════════════════════════════════════
 
-            // Address type is guaranteed valid by the Soroban SDK runtime; no further
-            // address validation is required or possible at the contract level.
+    /// Updates the protocol fee (Admin or FeeManager, max 200 bps)
+    pub fn update_protocol_fee(
+        env: Env,
+        caller: Address,
+        fee_bps: u32,
+    ) -> Result<(), ContractError> {
+        storage::require_admin_or_role(&env, &caller, &Role::FeeManager)?;
+        admin_log::record(&env, &caller, "update_protocol_fee", (fee_bps,));
+        let old_fee_bps = storage::get_protocol_fee_bps(&env);
+        set_protocol_fee_bps(&env, fee_bps)?;
+        emit_protocol_fee_updated(&env, caller, old_fee_bps, fee_bps);
+        Ok(())
+    }
 
-            remittances.push_back(remittance);
+    /// Updates the platform fee for a whitelisted token (Admin or FeeManager).
+    pub fn update_token_fee(
+        env: Env,
+        caller: Address,
+        token: Address,
+        fee_bps: u32,
+    ) -> Result<(), ContractError> {
+        storage::require_admin_or_role(&env, &caller, &Role::FeeManager)?;
+        admin_log::record(&env, &caller, "update_token_fee", (token.clone(), fee_bps));
+        if !is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
         }
+        let old_fee_bps = storage::get_token_fee_bps(&env, &token);
+        set_token_fee_bps(&env, &token, fee_bps)?;
+        emit_token_fee_updated(&env, caller, token.clone(), old_fee_bps, fee_bps);
+        Ok(())
+    }
 
-        // Compute net settlements.
-        // Gas note: netting offsets opposing flows so fewer token transfer calls are executed.
-        let netting_result = compute_net_settlements(&env, &remittances)?;
-        let net_transfers = netting_result.net_transfers;
-
-        // Validate net settlement calculations
-        validate_net_settlement(&remittances, &net_transfers)?;
-
-        // Batch read storage values once
-        let usdc_token = get_usdc_token(&env)?;
-        let mut current_fees = get_accumulated_fees(&env)?;
+    /// Gets the configured platform fee for a whitelisted token.
+    pub fn get_token_fee_bps(env: Env, token: Address) -> Option<u32> {
+        get_token_fee_bps(&env, &token)
+    }
 
-        let token_client = token::Client::new(&env, &usdc_token);
+    /// Sets the maximum volume a single `batch_settle_with_netting` call may
+    /// move in a token, or clears it with `None` (Admin only).
+    pub fn set_token_batch_limit(
+        env: Env,
+        caller: Address,
+        token: Address,
+        limit: Option<i128>,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_token_batch_limit", (token.clone(), limit));
+        if !is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
+        }
+        if limit.is_some_and(|limit| limit <= 0) {
+            return Err(ContractError::InvalidAmount);
+        }
+        let old_limit = storage::get_token_batch_limit(&env, &token);
+        storage::set_token_batch_limit(&env, &token, limit);
+        emit_token_batch_limit_updated(&env, token, old_limit, limit, caller);
+        Ok(())
+    }
 
-        // Execute net transfers
-        for i in 0..net_transfers.len() {
-            let transfer = net_transfers.get_unchecked(i);
-
-            // Determine actual sender and recipient based on net_amount sign
-            let (from, to, amount) = if transfer.net_amount > 0 {
-                // Positive: party_a -> party_b
-                (
-                    transfer.party_a.clone(),
-                    transfer.party_b.clone(),
-                    transfer.net_amount,
-                )
-            } else if transfer.net_amount < 0 {
-                // Negative: party_b -> party_a
-                (
-                    transfer.party_b.clone(),
-                    transfer.party_a.clone(),
-                    -transfer.net_amount,
-                )
-            } else {
-                // Zero: complete offset, no transfer needed
-                continue;
-            };
+    /// Gets the per-batch settlement limit for a token, if one is set.
+    pub fn get_token_batch_limit(env: Env, token: Address) -> Option<i128> {
+        storage::get_token_batch_limit(&env, &token)
+    }
 
-            // Calculate payout amount (net amount minus fees)
-            let payout_amount = amount
-                .checked_sub(transfer.total_fees)
-                .ok_or(ContractError::Overflow)?;
+    /// Updates the treasury address (Admin only)
+    pub fn update_treasury(
+        env: Env,
+        caller: Address,
+        treasury: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "update_treasury", (treasury.clone(),));
+        let old_treasury = get_treasury(&env).ok();
+        set_treasury(&env, &treasury);
+        emit_treasury_updated(&env, caller, old_treasury, treasury);
+        Ok(())
+    }
 
-            // Execute the net transfer from contract to recipient
-            token_client.transfer(&env.current_contract_address(), &to, &payout_amount);
+    /// Gets the current protocol fee in basis points
+    pub fn get_protocol_fee_bps(env: Env) -> u32 {
+        get_protocol_fee_bps(&env)
+    }
 
-            // Accumulate fees in memory with overflow check
-            current_fees = current_fees
-                .checked_add(transfer.total_fees)
-                .ok_or(ContractError::Overflow)?;
+    /// Gets the treasury address
+    pub fn get_treasury(env: Env) -> Result<Address, ContractError> {
+        get_treasury(&env)
+    }
 
-            // Emit settlement event (using remittance ID from the transfer)
-            // Note: In batch processing, we use the first remittance ID as reference
-            let remittance_id = if i < remittances.len() {
-                remittances.get_unchecked(i).id
-            } else {
-                0
-            };
-            emit_settlement_completed(
-                &env,
-                remittance_id,
-                from,
-                to,
-                usdc_token.clone(),
-                payout_amount,
-            );
-        }
+    /// Deploys and initializes a SwiftRemit instance for a corridor.
+    ///
+    /// `wasm_hash` must name SwiftRemit WASM already uploaded to the network.
+    /// The instance is deployed at an address derived from this contract and
+    /// `salt`, initialized with `caller` as admin and this contract's
+    /// treasury, given this contract's other admins and the role grants in
+    /// `config`, and recorded in the corridor registry. See the `factory`
+    /// module for details.
+    ///
+    /// # Authorization
+    ///
+    /// Requires admin authentication. `caller` also authorizes the admin and
+    /// role calls made on the new instance.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::CorridorInstanceExists` - The corridor already has an instance
+    pub fn deploy_corridor_instance(
+        env: Env,
+        caller: Address,
+        wasm_hash: BytesN<32>,
+        salt: BytesN<32>,
+        config: InstanceConfig,
+    ) -> Result<Address, ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(
+            &env,
+            &caller,
+            "deploy_corridor_instance",
+            (wasm_hash.clone(), salt.clone(), config.clone()),
+        );
+        let instance = factory::deploy(&env, &caller, wasm_hash, salt, config)?;
+        emit_instance_deployed(
+            &env,
+            instance.corridor,
+            instance.address.clone(),
+            instance.token,
+            caller,
+        );
+        Ok(instance.address)
+    }
 
-        // Write accumulated fees once at the end
-        // For batch settlement, check if accumulation would exceed MAX_FEES
-        if current_fees > MAX_FEES {
-            // Flush current accumulated fees and write new total
-            trigger_flush(&env, current_fees)?;
-            set_accumulated_fees(&env, 0);
-        } else {
-            set_accumulated_fees(&env, current_fees);
-        }
+    /// Returns the factory-deployed instance for a corridor, if any.
+    pub fn get_corridor_instance(
+        env: Env,
+        corridor: RemittanceCorridor,
+    ) -> Option<CorridorInstance> {
+        storage::get_corridor_instance(&env, &corridor)
+    }
 
-        // Mark all remittances as completed and set settlement hashes
-        let mut settled_ids = Vec::new(&env);
+    /// Returns every factory-deployed instance, in deployment order.
+    pub fn get_corridor_instances(env: Env) -> Vec<CorridorInstance> {
+        factory::instances(&env)
+    }
 
-        for i in 0..remittances.len() {
-            let mut remittance = remittances.get_unchecked(i);
-            remittance.status = RemittanceStatus::Completed;
-            set_remittance(&env, remittance.id, &remittance);
-            set_settlement_hash(&env, remittance.id);
-            settled_ids.push_back(remittance.id);
+    /// Sets the contract that converts payouts for `set_path_payout`.
+    ///
+    /// The adapter must implement `PATH_PAYMENT_ENTRY_POINT`. It is approved
+    /// for exactly one payout at a time, so it never holds a standing
+    /// allowance over escrowed funds.
+    ///
+    /// # Authorization
+    ///
+    /// Requires admin authentication.
+    pub fn set_path_payment_adapter(
+        env: Env,
+        caller: Address,
+        adapter: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_path_payment_adapter", (adapter.clone(),));
+        storage::set_path_payment_adapter(&env, &adapter);
+        Ok(())
+    }
 
-            // Emit individual remittance completion event
-            let payout_amount = remittance
-                .amount
-                .checked_sub(remittance.fee)
-                .ok_or(ContractError::Overflow)?;
-            emit_remittance_completed(&env, remittance.id, remittance.sender, remittance.agent);
-        }
+    /// Returns the path payment adapter, if one is set.
+    pub fn get_path_payment_adapter(env: Env) -> Option<Address> {
+        storage::get_path_payment_adapter(&env)
+    }
 
-        Ok(BatchSettlementResult { settled_ids })
+    /// Trusts or distrusts another SwiftRemit instance for remittance
+    /// forwarding, in both directions.
+    ///
+    /// # Authorization
+    ///
+    /// Requires admin authentication.
+    pub fn set_trusted_instance(
+        env: Env,
+        caller: Address,
+        instance: Address,
+        trusted: bool,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "set_trusted_instance", (instance.clone(), trusted));
+        storage::set_trusted_instance(&env, &instance, trusted);
+        emit_instance_trusted(&env, instance, trusted, caller);
+        Ok(())
     }
 
-    /// Creates multiple remittances in one transaction (#590).
-    pub fn create_batch_remittance(
+    /// Returns whether another instance is trusted for remittance forwarding.
+    pub fn is_trusted_instance(env: Env, instance: Address) -> bool {
+        storage::is_trusted_instance(&env, &instance)
+    }
+
+    /// Registers this instance for `corridor` with the hub registry `hub`.
+    ///
+    /// The hub's `register_instance` entry point receives a `RegistryEntry`
+    /// with this instance's address, corridor, settlement token, and version.
+    /// Call again after an upgrade to refresh the hub's entry.
+    ///
+    /// # Authorization
+    ///
+    /// Requires admin authentication.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::HubRegistrationFailed` - The hub rejected the entry
+    pub fn register_with_hub(
         env: Env,
-        sender: Address,
-        entries: Vec<BatchCreateEntry>,
-    ) -> Result<Vec<u64>, ContractError> {
-        let ids = Self::batch_create_remittances(env.clone(), sender.clone(), entries)?;
-        env.events().publish(
-            (soroban_sdk::symbol_short!("batch"), soroban_sdk::symbol_short!("created")),
-            (sender, ids.len()),
-        );
-        Ok(ids)
+        caller: Address,
+        hub: Address,
+        corridor: RemittanceCorridor,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "register_with_hub", (hub.clone(), corridor.clone()));
+        let entry = hub_registry::register(&env, hub, corridor)?;
+        emit_hub_registered(&env, entry.hub, entry.corridor, entry.token, entry.version);
+        Ok(())
     }
 
-    /// Confirms payouts for multiple remittances in one transaction (#590).
-    pub fn confirm_batch_payout(
+    /// Returns the entry this instance last registered with a hub, if any.
+    pub fn get_registry_entry(env: Env) -> Option<RegistryEntry> {
+        storage::get_registry_entry(&env)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Role-Based Authorization Functions
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Assigns a role to an address (Admin only)
+    pub fn assign_role(
         env: Env,
-        agent: Address,
-        remittance_ids: Vec<u64>,
-    ) -> Result<Vec<u64>, ContractError> {
-        let batch_size = remittance_ids.len();
-        if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
-            return Err(ContractError::InvalidBatchSize);
-        }
-        let mut confirmed = Vec::new(&env);
-        for i in 0..batch_size {
-            let id = remittance_ids.get_unchecked(i);
-            Self::confirm_payout(env.clone(), agent.clone(), id, None, None)?;
-            confirmed.push_back(id);
-        }
-        env.events().publish(
-            (soroban_sdk::symbol_short!("batch"), soroban_sdk::symbol_short!("paid")),
-            confirmed.len(),
-        );
-        Ok(confirmed)
+        caller: Address,
+        address: Address,
+        role: Role,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_role_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "assign_role", (address.clone(), role.clone()));
+        let old_held = storage::has_role(&env, &address, &role);
+        assign_role(&env, &address, &role);
+        emit_role_updated(&env, caller, address, role, old_held, true);
+        Ok(())
     }
 
-    /// Sets the minimum agent reputation threshold (#591). Admin only.
-    pub fn set_min_agent_reputation(env: Env, threshold: u32) -> Result<(), ContractError> {
-        if threshold > 100 { return Err(ContractError::InvalidReputationScore); }
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
-        storage::set_min_agent_reputation(&env, threshold);
+    /// Removes a role from an address (Admin only)
+    pub fn remove_role(
+        env: Env,
+        caller: Address,
+        address: Address,
+        role: Role,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        require_role_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "remove_role", (address.clone(), role.clone()));
+        let old_held = storage::has_role(&env, &address, &role);
+        remove_role(&env, &address, &role);
+        emit_role_updated(&env, caller, address, role, old_held, false);
         Ok(())
     }
 
-    /// Returns the current minimum agent reputation threshold.
-    pub fn get_min_agent_reputation(env: Env) -> u32 {
-        storage::get_min_agent_reputation(&env)
+    /// Checks if an address has a specific role
+    pub fn has_role(env: Env, address: Address, role: Role) -> bool {
+        has_role(&env, &address, &role)
     }
 
-    /// Add a token to the whitelist. Only admins can call this.
-    pub fn add_whitelisted_token(env: Env, token: Address) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
-        require_admin(&env, &caller)?;
+    // ═══════════════════════════════════════════════════════════════════════════
+    // Fee Strategy Management
+    // ═══════════════════════════════════════════════════════════════════════════
 
-        if is_token_whitelisted(&env, &token) {
-            return Err(ContractError::TokenAlreadyWhitelisted);
+    /// Updates the fee strategy (Admin or FeeManager)
+    ///
+    /// Allows switching between different fee calculation methods:
+    /// - Percentage: Fee based on basis points (e.g., 250 = 2.5%)
+    /// - Flat: Fixed fee amount regardless of transaction size
+    /// - Dynamic: Tiered fee that decreases for larger amounts
+    /// - Tiered: Rate from an admin-defined schedule; see `set_fee_tiers`
+    ///
+    /// # Arguments
+    /// * `caller` - Admin or FeeManager address (must be authorized)
+    /// * `strategy` - New fee strategy to apply
+    ///
+    /// # Examples
+    /// ```ignore
+    /// // Set 2.5% percentage fee
+    /// contract.update_fee_strategy(&admin, FeeStrategy::Percentage(250))?;
+    ///
+    /// // Set flat 100 USDC fee
+    /// contract.update_fee_strategy(&admin, FeeStrategy::Flat(100_0000000))?;
+    ///
+    /// // Set dynamic tiered fee starting at 4%
+    /// contract.update_fee_strategy(&admin, FeeStrategy::Dynamic(400))?;
+    /// ```
+    pub fn update_fee_strategy(
+        env: Env,
+        caller: Address,
+        strategy: FeeStrategy,
+    ) -> Result<(), ContractError> {
+        storage::require_admin_or_role(&env, &caller, &Role::FeeManager)?;
+        admin_log::record(&env, &caller, "update_fee_strategy", (strategy.clone(),));
+        if let FeeStrategy::Tiered(tiers) = &strategy {
+            fee_strategy::validate_tiers(tiers)?;
         }
+        let old_strategy = storage::get_fee_strategy(&env);
+        set_fee_strategy(&env, &strategy);
+        emit_fee_strategy_updated(&env, caller, old_strategy, strategy);
+        Ok(())
+    }
 
-        set_token_whitelisted(&env, &token, true);
+    /// Switches to a tiered fee schedule (Admin or FeeManager)
+    ///
+    /// Each remittance is charged the rate of the tier its amount falls in.
+    /// Tiers are given in ascending order of `min_amount`, the first starting
+    /// at 0; for example `[(0, 150), (100 USDC, 100), (1,000 USDC, 50)]`.
+    /// Equivalent to `update_fee_strategy` with `FeeStrategy::Tiered`, after
+    /// validating the schedule.
+    ///
+    /// # Errors
+    /// * `ContractError::InvalidAmount` - Empty or oversized schedule, or
+    ///   bounds not starting at 0 and strictly ascending
+    /// * `ContractError::InvalidFeeBps` - A rate exceeds 10000
+    pub fn set_fee_tiers(
+        env: Env,
+        caller: Address,
+        tiers: Vec<FeeTier>,
+    ) -> Result<(), ContractError> {
+        storage::require_admin_or_role(&env, &caller, &Role::FeeManager)?;
+        admin_log::record(&env, &caller, "set_fee_tiers", (tiers.clone(),));
+        fee_strategy::validate_tiers(&tiers)?;
+        let old_strategy = storage::get_fee_strategy(&env);
+        let strategy = FeeStrategy::Tiered(tiers);
+        set_fee_strategy(&env, &strategy);
+        emit_fee_strategy_updated(&env, caller, old_strategy, strategy);
+        Ok(())
+    }
 
-        emit_token_whitelisted(&env, token.clone(), caller);
-        log_whitelist_token(&env, &token);
+    /// Gets the current fee strategy
+    pub fn get_fee_strategy(env: Env) -> FeeStrategy {
+        get_fee_strategy(&env)
+    }
 
+    /// Sets a flat amount, in token stroops, added to the platform fee of
+    /// every remittance (Admin or FeeManager)
+    ///
+    /// Applies alongside rate-based strategies, so small remittances pay a
+    /// floor and large ones the rate; it is not added under
+    /// `FeeStrategy::Flat`. Remittances smaller than the resulting fee are
+    /// refused. Set to 0 to remove.
+    ///
+    /// # Errors
+    /// * `ContractError::InvalidAmount` - Negative amount
+    pub fn set_flat_fee(env: Env, caller: Address, flat_fee: i128) -> Result<(), ContractError> {
+        storage::require_admin_or_role(&env, &caller, &Role::FeeManager)?;
+        admin_log::record(&env, &caller, "set_flat_fee", (flat_fee,));
+        if flat_fee < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        let old_flat_fee = storage::get_flat_fee(&env);
+        storage::set_flat_fee(&env, flat_fee);
+        emit_flat_fee_updated(&env, caller, old_flat_fee, flat_fee);
         Ok(())
     }
 
-    /// Remove a token from the whitelist. Only admins can call this.
-    pub fn remove_whitelisted_token(env: Env, token: Address) -> Result<(), ContractError> {
-        let caller = get_admin(&env)?;
+    /// Adds `sender` to the fee exemption allowlist (admin only)
+    ///
+    /// Remittances the sender creates from then on are charged no platform or
+    /// expiry fee, and their creation emits a `fee/exempt` event. Remittances
+    /// already created keep their fee.
+    pub fn add_fee_exempt(env: Env, caller: Address, sender: Address) -> Result<(), ContractError> {
         require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "add_fee_exempt", (sender.clone(),));
+        storage::set_fee_exempt(&env, &sender, true);
+        emit_fee_exemption_updated(&env, caller, sender, true);
+        Ok(())
+    }
 
-        if !is_token_whitelisted(&env, &token) {
-            return Err(ContractError::TokenNotWhitelisted);
-        }
+    /// Removes `sender` from the fee exemption allowlist (admin only)
+    pub fn remove_fee_exempt(
+        env: Env,
+        caller: Address,
+        sender: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "remove_fee_exempt", (sender.clone(),));
+        storage::set_fee_exempt(&env, &sender, false);
+        emit_fee_exemption_updated(&env, caller, sender, false);
+        Ok(())
+    }
 
-        set_token_whitelisted(&env, &token, false);
+    /// Returns whether `sender` is on the fee exemption allowlist.
+    pub fn is_fee_exempt(env: Env, sender: Address) -> bool {
+        storage::is_fee_exempt(&env, &sender)
+    }
 
-        emit_token_removed_from_whitelist(&env, token.clone(), caller);
-        log_remove_whitelisted_token(&env, &token);
+    /// Returns the platform fee settings in force: strategy, default rate,
+    /// flat component, and protocol fee.
+    pub fn get_fee_config(env: Env) -> Result<FeeConfig, ContractError> {
+        fee_service::fee_config(&env)
+    }
 
+    /// Sets the expiry-weighted fee slope (Admin or FeeManager)
+    ///
+    /// New remittances are charged `bps_per_day` basis points for each day
+    /// their funds can stay locked in escrow, capped at `max_bps`. Remittances
+    /// without an expiry are charged the cap. A zero slope disables the component.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidFeeBps` - `max_bps` exceeds 10000 or is below `bps_per_day`
+    pub fn set_expiry_fee_config(
+        env: Env,
+        caller: Address,
+        bps_per_day: u32,
+        max_bps: u32,
+    ) -> Result<(), ContractError> {
+        storage::require_admin_or_role(&env, &caller, &Role::FeeManager)?;
+        admin_log::record(&env, &caller, "set_expiry_fee_config", (bps_per_day, max_bps));
+        let config = ExpiryFeeConfig { bps_per_day, max_bps };
+        fee_service::validate_expiry_fee_config(&config)?;
+        let old_config = storage::get_expiry_fee_config(&env);
+        storage::set_expiry_fee_config(&env, &config);
+        events::emit_expiry_fee_config_updated(&env, caller, old_config, config);
         Ok(())
     }
 
-    /// Check if a token is whitelisted.
-    pub fn is_token_whitelisted(env: Env, token: Address) -> bool {
-        crate::storage::is_token_whitelisted(&env, &token)
+    /// Gets the expiry-weighted fee slope, if configured.
+    pub fn get_expiry_fee_config(env: Env) -> Option<ExpiryFeeConfig> {
+        storage::get_expiry_fee_config(&env)
     }
 
-    /// Get all whitelisted tokens.
+    /// Gets the expiry-weighted fee charged on a remittance at creation.
+    pub fn get_remittance_expiry_fee(env: Env, remittance_id: u64) -> i128 {
+        storage::get_remittance_expiry_fee(&env, remittance_id)
+    }
+
+    /// Calculates fee breakdown for a given amount
     ///
-    /// Returns a vector of all token addresses that are currently whitelisted.
-    /// This is a public view function that can be called by anyone.
-    pub fn get_whitelisted_tokens(env: Env) -> Vec<Address> {
-        crate::storage::get_all_whitelisted_tokens(&env)
+    /// Returns detailed breakdown of all fees that would be applied to a transaction.
+    /// Useful for displaying fee information to users before they commit to a transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `amount` - Transaction amount to calculate fees for
+    ///
+    /// # Returns
+    ///
+    /// Complete fee breakdown including platform fee, protocol fee, and net amount
+    pub fn calculate_fee_breakdown(env: Env, amount: i128) -> Result<FeeBreakdown, ContractError> {
+        fee_service::calculate_fees_with_breakdown(&env, amount, None, None)
     }
 
-    /// Update rate limit configuration. Only admins can call this.
+    /// Quotes the total fee for a remittance with the given expiry
+    ///
+    /// Extends `calculate_fee_breakdown` with the expiry-weighted component,
+    /// so senders can see how the chosen escrow duration affects the fee.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `amount` - Transaction amount
+    /// * `expiry` - Expiry the remittance would be created with
+    /// * `token` - Token the remittance would use (for token-specific fees)
+    pub fn quote_fee(
+        env: Env,
+        amount: i128,
+        expiry: Option<Expiry>,
+        token: Option<Address>,
+    ) -> Result<FeeQuote, ContractError> {
+        fee_service::quote_fees(&env, amount, expiry, token.as_ref())
+    }
+
+    /// Calculates fee breakdown with corridor-specific configuration
+    ///
+    /// Applies country-to-country fee rules for cross-border transactions.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `amount` - Transaction amount
+    /// * `corridor` - Corridor configuration with country codes and fee rules
+    ///
+    /// # Returns
+    ///
+    /// Fee breakdown using corridor-specific rates
+    pub fn fee_breakdown_corridor(
+        env: Env,
+        amount: i128,
+        corridor: FeeCorridor,
+    ) -> Result<FeeBreakdown, ContractError> {
+        fee_service::calculate_fees_with_breakdown(&env, amount, None, Some(&corridor))
+    }
+
+    /// Sets a fee corridor configuration for a country pair
+    ///
+    /// Allows admin to configure specific fee rules for cross-border corridors.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `corridor` - Corridor configuration with country codes and fee rules
     ///
-    /// # Parameters
-    /// - `caller`: Admin address (must be authorized)
-    /// - `max_requests`: Maximum number of requests allowed per window
-    /// - `window_seconds`: Time window in seconds
-    /// - `enabled`: Whether rate limiting is enabled
+    /// # Authorization
     ///
-    /// # Example
-    /// ```ignore
-    /// // Set rate limit to 50 requests per 30 seconds
-    /// contract.update_rate_limit_config(&admin, 50, 30, true)?;
-    /// ```
-    pub fn update_rate_limit_config(
+    /// Requires authentication from an admin or a `FeeManager`
+    pub fn set_fee_corridor(
         env: Env,
         caller: Address,
-        max_requests: u32,
-        window_seconds: u64,
-        enabled: bool,
+        corridor: FeeCorridor,
     ) -> Result<(), ContractError> {
-        require_admin(&env, &caller)?;
-
-        let config = RateLimitConfig {
-            max_requests,
-            window_seconds,
-            enabled,
-        };
-
-        set_rate_limit_config(&env, config);
-
+        storage::require_admin_or_role(&env, &caller, &Role::FeeManager)?;
+        admin_log::record(&env, &caller, "set_fee_corridor", (corridor.clone(),));
+        storage::set_fee_corridor(&env, &corridor);
         Ok(())
     }
 
-    /// Get current rate limit configuration
+    /// Gets a fee corridor configuration for a country pair
     ///
-    /// # Returns
-    /// Tuple of (max_requests, window_seconds, enabled)
-    pub fn get_rate_limit_config(env: Env) -> Result<(u32, u64, bool), ContractError> {
-        let config = crate::rate_limit::get_rate_limit_config(&env)?;
-        Ok((config.max_requests, config.window_seconds, config.enabled))
-    }
-
-    /// Get rate limit status for a specific address
+    /// # Arguments
     ///
-    /// # Parameters
-    /// - `address`: Address to check
+    /// * `env` - The contract execution environment
+    /// * `from_country` - Source country code (ISO 3166-1 alpha-2)
+    /// * `to_country` - Destination country code (ISO 3166-1 alpha-2)
     ///
     /// # Returns
-    /// Tuple of (current_requests, max_requests, window_seconds)
-    pub fn get_rate_limit_status(
+    ///
+    /// Corridor configuration if exists, None otherwise
+    pub fn get_fee_corridor(
         env: Env,
-        address: Address,
-    ) -> Result<(u32, u32, u64), ContractError> {
-        crate::rate_limit::get_rate_limit_status(&env, &address)
+        from_country: String,
+        to_country: String,
+    ) -> Option<FeeCorridor> {
+        storage::get_fee_corridor(&env, &from_country, &to_country)
     }
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    // Protocol Fee Management
-    // ═══════════════════════════════════════════════════════════════════════════
-
-    /// Updates the protocol fee (Admin only, max 200 bps)
-    pub fn update_protocol_fee(
+    /// Removes a fee corridor configuration
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract execution environment
+    /// * `from_country` - Source country code
+    /// * `to_country` - Destination country code
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an admin or a `FeeManager`
+    pub fn remove_fee_corridor(
         env: Env,
         caller: Address,
-        fee_bps: u32,
+        from_country: String,
+        to_country: String,
     ) -> Result<(), ContractError> {
-        require_admin(&env, &caller)?;
-        set_protocol_fee_bps(&env, fee_bps)?;
-        emit_protocol_fee_updated(&env, caller, fee_bps);
+        storage::require_admin_or_role(&env, &caller, &Role::FeeManager)?;
+        admin_log::record(
+            &env,
+            &caller,
+            "remove_fee_corridor",
+            (from_country.clone(), to_country.clone()),
+        );
+        storage::remove_fee_corridor(&env, &from_country, &to_country);
         Ok(())
     }
 
-    /// Updates the platform fee for a whitelisted token (Admin only).
-    pub fn update_token_fee(
+    /// Applies fee, daily-limit, and enabled-flag changes to several corridors
+    /// at once (Admin only)
+    ///
+    /// Every update is validated before any is written, so the batch applies
+    /// completely or not at all. Disabled corridors reject
+    /// `create_remittance_with_corridor` with `ContractError::CorridorDisabled`.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::InvalidCorridorUpdate` - Batch is empty, too large,
+    ///   repeats a corridor, or contains a malformed update
+    /// * `ContractError::InvalidFeeBps` - A fee or protocol fee exceeds its maximum
+    /// * `ContractError::InvalidAmount` - A flat fee is negative or a daily limit is not positive
+    pub fn update_corridors(
         env: Env,
         caller: Address,
-        token: Address,
-        fee_bps: u32,
+        updates: Vec<CorridorConfigUpdate>,
     ) -> Result<(), ContractError> {
         require_admin(&env, &caller)?;
-        if !is_token_whitelisted(&env, &token) {
-            return Err(ContractError::TokenNotWhitelisted);
+        admin_log::record(&env, &caller, "update_corridors", (updates.clone(),));
+        corridor_config::validate(&updates)?;
+        for update in updates.iter() {
+            corridor_config::apply(&env, &update);
+            emit_corridor_config_updated(&env, update.from_country, update.to_country);
         }
-        set_token_fee_bps(&env, &token, fee_bps)?;
-        emit_token_fee_updated(&env, caller, token.clone(), fee_bps);
         Ok(())
     }
 
-    /// Gets the configured platform fee for a whitelisted token.
-    pub fn get_token_fee_bps(env: Env, token: Address) -> Option<u32> {
-        get_token_fee_bps(&env, &token)
+    /// Returns whether a corridor accepts new remittances.
+    pub fn is_corridor_enabled(env: Env, from_country: String, to_country: String) -> bool {
+        !storage::is_corridor_disabled(&env, &from_country, &to_country)
     }
 
-    /// Updates the treasury address (Admin only)
-    pub fn update_treasury(
+    /// Enables utilization-based pricing on a fee corridor (Admin or FeeManager)
+    ///
+    /// The corridor's percentage fee then floats between `min_bps` and
+    /// `max_bps` in proportion to pending corridor volume relative to the
+    /// capacity registered by agents, replacing the corridor's fixed strategy.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::FeeCorridorNotFound` - No fee corridor is configured for the pair
+    /// * `ContractError::InvalidUtilizationBounds` - Bounds are inverted or exceed 10000
+    pub fn set_utilization_fee_bounds(
         env: Env,
         caller: Address,
-        treasury: Address,
+        from_country: String,
+        to_country: String,
+        min_bps: u32,
+        max_bps: u32,
     ) -> Result<(), ContractError> {
-        require_admin(&env, &caller)?;
-        let old_treasury = get_treasury(&env).ok();
-        set_treasury(&env, &treasury);
-        emit_treasury_updated(&env, caller, old_treasury, treasury);
+        storage::require_admin_or_role(&env, &caller, &Role::FeeManager)?;
+        admin_log::record(
+            &env,
+            &caller,
+            "set_utilization_fee_bounds",
+            (from_country.clone(), to_country.clone(), min_bps, max_bps),
+        );
+        if storage::get_fee_corridor(&env, &from_country, &to_country).is_none() {
+            return Err(ContractError::FeeCorridorNotFound);
+        }
+        let bounds = UtilizationFeeBounds { min_bps, max_bps };
+        corridor_utilization::validate_bounds(&bounds)?;
+        storage::set_utilization_fee_bounds(&env, &from_country, &to_country, &bounds);
+        events::emit_utilization_bounds_set(&env, from_country, to_country, min_bps, max_bps);
         Ok(())
     }
 
-    /// Gets the current protocol fee in basis points
-    pub fn get_protocol_fee_bps(env: Env) -> u32 {
-        get_protocol_fee_bps(&env)
+    /// Reverts a corridor to its configured fee strategy (Admin or FeeManager)
+    pub fn remove_utilization_fee_bounds(
+        env: Env,
+        caller: Address,
+        from_country: String,
+        to_country: String,
+    ) -> Result<(), ContractError> {
+        storage::require_admin_or_role(&env, &caller, &Role::FeeManager)?;
+        admin_log::record(
+            &env,
+            &caller,
+            "remove_utilization_fee_bounds",
+            (from_country.clone(), to_country.clone()),
+        );
+        storage::remove_utilization_fee_bounds(&env, &from_country, &to_country);
+        events::emit_utilization_bounds_removed(&env, from_country, to_country);
+        Ok(())
     }
 
-    /// Gets the treasury address
-    pub fn get_treasury(env: Env) -> Result<Address, ContractError> {
-        get_treasury(&env)
+    /// Gets the utilization fee bounds for a corridor, if it is utilization-priced.
+    pub fn get_utilization_fee_bounds(
+        env: Env,
+        from_country: String,
+        to_country: String,
+    ) -> Option<UtilizationFeeBounds> {
+        storage::get_utilization_fee_bounds(&env, &from_country, &to_country)
     }
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    // Role-Based Authorization Functions
-    // ═══════════════════════════════════════════════════════════════════════════
-
-    /// Assigns a role to an address (Admin only)
-    pub fn assign_role(
+    /// Registers the payout capacity an agent provides on a corridor
+    ///
+    /// Replaces the agent's previous capacity for the corridor; zero withdraws it.
+    /// Returns the corridor's new total capacity.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the agent address.
+    pub fn set_agent_corridor_capacity(
         env: Env,
-        caller: Address,
-        address: Address,
-        role: Role,
-    ) -> Result<(), ContractError> {
-        caller.require_auth();
-        require_role_admin(&env, &caller)?;
-        assign_role(&env, &address, &role);
-        Ok(())
+        agent: Address,
+        from_country: String,
+        to_country: String,
+        capacity: i128,
+    ) -> Result<i128, ContractError> {
+        crate::storage::require_agent_authorized(&env, &agent)?;
+        let corridor_capacity = corridor_utilization::set_agent_capacity(
+            &env,
+            &agent,
+            &from_country,
+            &to_country,
+            capacity,
+        )?;
+        events::emit_agent_capacity_updated(
+            &env,
+            agent,
+            from_country,
+            to_country,
+            capacity,
+            corridor_capacity,
+        );
+        Ok(corridor_capacity)
     }
 
-    /// Removes a role from an address (Admin only)
-    pub fn remove_role(
+    /// Gets a corridor's registered agent capacity and pending volume.
+    pub fn get_corridor_liquidity(
         env: Env,
-        caller: Address,
-        address: Address,
-        role: Role,
-    ) -> Result<(), ContractError> {
-        caller.require_auth();
-        require_role_admin(&env, &caller)?;
-        remove_role(&env, &address, &role);
-        Ok(())
+        from_country: String,
+        to_country: String,
+    ) -> CorridorLiquidity {
+        storage::get_corridor_liquidity(&env, &from_country, &to_country)
     }
 
-    /// Checks if an address has a specific role
-    pub fn has_role(env: Env, address: Address, role: Role) -> bool {
-        has_role(&env, &address, &role)
+    /// Gets the fee currently charged on a utilization-priced corridor, in basis points.
+    pub fn get_corridor_dynamic_fee_bps(
+        env: Env,
+        from_country: String,
+        to_country: String,
+    ) -> Option<u32> {
+        corridor_utilization::dynamic_fee_bps(&env, &from_country, &to_country)
     }
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    // Fee Strategy Management
-    // ═══════════════════════════════════════════════════════════════════════════
+    /// Lists the agents available to pay out on a corridor.
+    ///
+    /// Only registered, KYB-verified agents with non-zero capacity on the
+    /// corridor are returned.
+    pub fn get_corridor_agents(env: Env, from_country: String, to_country: String) -> Vec<Address> {
+        let mut agents = Vec::new(&env);
+        for agent in storage::get_corridor_agents(&env, &from_country, &to_country).iter() {
+            if storage::is_agent_registered(&env, &agent)
+                && storage::is_agent_kyb_verified(&env, &agent)
+            {
+                agents.push_back(agent);
+            }
+        }
+        agents
+    }
 
-    /// Updates the fee strategy (Admin only)
+    /// Lists the agents available on a corridor with the sender's favorites first.
     ///
-    /// Allows switching between different fee calculation methods:
-    /// - Percentage: Fee based on basis points (e.g., 250 = 2.5%)
-    /// - Flat: Fixed fee amount regardless of transaction size
-    /// - Dynamic: Tiered fee that decreases for larger amounts
+    /// Favorites keep the order they were added in; the remaining agents follow
+    /// in the order of `get_corridor_agents`. Wallets use this to pick an agent
+    /// for quick-send flows.
+    pub fn get_preferred_corridor_agents(
+        env: Env,
+        sender: Address,
+        from_country: String,
+        to_country: String,
+    ) -> Vec<Address> {
+        let available = Self::get_corridor_agents(env.clone(), from_country, to_country);
+        let mut preferred = Vec::new(&env);
+        for agent in storage::get_favorite_agents(&env, &sender).iter() {
+            if available.contains(&agent) {
+                preferred.push_back(agent);
+            }
+        }
+        for agent in available.iter() {
+            if !preferred.contains(&agent) {
+                preferred.push_back(agent);
+            }
+        }
+        preferred
+    }
+
+    /// Adds a registered agent to the sender's favorites. Adding an existing
+    /// favorite is a no-op.
     ///
-    /// # Arguments
-    /// * `caller` - Admin address (must be authorized)
-    /// * `strategy` - New fee strategy to apply
+    /// # Errors
     ///
-    /// # Examples
-    /// ```ignore
-    /// // Set 2.5% percentage fee
-    /// contract.update_fee_strategy(&admin, FeeStrategy::Percentage(250))?;
+    /// * `ContractError::AgentNotRegistered` - Agent is not registered
+    /// * `ContractError::FavoriteAgentLimit` - Sender already has `MAX_FAVORITE_AGENTS`
     ///
-    /// // Set flat 100 USDC fee
-    /// contract.update_fee_strategy(&admin, FeeStrategy::Flat(100_0000000))?;
+    /// # Authorization
     ///
-    /// // Set dynamic tiered fee starting at 4%
-    /// contract.update_fee_strategy(&admin, FeeStrategy::Dynamic(400))?;
-    /// ```
-    pub fn update_fee_strategy(
-        env: Env,
-        caller: Address,
-        strategy: FeeStrategy,
-    ) -> Result<(), ContractError> {
-        require_admin(&env, &caller)?;
-        set_fee_strategy(&env, &strategy);
+    /// Requires authentication from the sender.
+    pub fn add_favorite_agent(env: Env, sender: Address, agent: Address) -> Result<(), ContractError> {
+        sender.require_auth();
+        if !storage::is_agent_registered(&env, &agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+        let mut favorites = storage::get_favorite_agents(&env, &sender);
+        if favorites.contains(&agent) {
+            return Ok(());
+        }
+        if favorites.len() >= MAX_FAVORITE_AGENTS {
+            return Err(ContractError::FavoriteAgentLimit);
+        }
+        favorites.push_back(agent.clone());
+        storage::set_favorite_agents(&env, &sender, &favorites);
+        emit_favorite_agent_added(&env, sender, agent);
         Ok(())
     }
 
-    /// Gets the current fee strategy
-    pub fn get_fee_strategy(env: Env) -> FeeStrategy {
-        get_fee_strategy(&env)
+    /// Removes an agent from the sender's favorites. Removing an agent that is
+    /// not a favorite is a no-op.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from the sender.
+    pub fn remove_favorite_agent(env: Env, sender: Address, agent: Address) {
+        sender.require_auth();
+        let mut favorites = storage::get_favorite_agents(&env, &sender);
+        if let Some(index) = favorites.first_index_of(&agent) {
+            favorites.remove(index);
+            storage::set_favorite_agents(&env, &sender, &favorites);
+            emit_favorite_agent_removed(&env, sender, agent);
+        }
     }
 
-    /// Calculates fee breakdown for a given amount
-    ///
-    /// Returns detailed breakdown of all fees that would be applied to a transaction.
-    /// Useful for displaying fee information to users before they commit to a transaction.
+    /// Returns the sender's favorite agents in the order they were added.
+    pub fn get_favorite_agents(env: Env, sender: Address) -> Vec<Address> {
+        storage::get_favorite_agents(&env, &sender)
+    }
+
+    /// Saves a contact in the owner's address book under an alias hash,
+    /// replacing any entry already stored under it.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `env` - The contract execution environment
-    /// * `amount` - Transaction amount to calculate fees for
+    /// * `ContractError::AddressBookFull` - Book already holds `MAX_ADDRESS_BOOK_ENTRIES`
     ///
-    /// # Returns
+    /// # Authorization
     ///
-    /// Complete fee breakdown including platform fee, protocol fee, and net amount
-    pub fn calculate_fee_breakdown(env: Env, amount: i128) -> Result<FeeBreakdown, ContractError> {
-        fee_service::calculate_fees_with_breakdown(&env, amount, None, None)
+    /// Requires authentication from the owner.
+    pub fn set_address_book_entry(
+        env: Env,
+        owner: Address,
+        alias_hash: BytesN<32>,
+        address: Address,
+        kind: ContactKind,
+    ) -> Result<(), ContractError> {
+        owner.require_auth();
+        address_book::put(
+            &env,
+            &owner,
+            &AddressBookEntry {
+                alias_hash,
+                address,
+                kind,
+            },
+        )
     }
 
-    /// Calculates fee breakdown with corridor-specific configuration
+    /// Removes a contact from the owner's address book.
     ///
-    /// Applies country-to-country fee rules for cross-border transactions.
+    /// # Authorization
     ///
-    /// # Arguments
+    /// Requires authentication from the owner.
+    pub fn remove_address_book_entry(
+        env: Env,
+        owner: Address,
+        alias_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        owner.require_auth();
+        address_book::remove(&env, &owner, &alias_hash)
+    }
+
+    /// Looks up a contact in the owner's address book.
     ///
-    /// * `env` - The contract execution environment
-    /// * `amount` - Transaction amount
-    /// * `corridor` - Corridor configuration with country codes and fee rules
+    /// # Authorization
+    ///
+    /// Requires authentication from the owner.
+    pub fn get_address_book_entry(
+        env: Env,
+        owner: Address,
+        alias_hash: BytesN<32>,
+    ) -> Result<AddressBookEntry, ContractError> {
+        owner.require_auth();
+        storage::get_address_book_entry(&env, &owner, &alias_hash)
+            .ok_or(ContractError::AddressBookEntryNotFound)
+    }
+
+    /// Returns every contact in the owner's address book.
     ///
-    /// # Returns
+    /// # Authorization
     ///
-    /// Fee breakdown using corridor-specific rates
-    pub fn fee_breakdown_corridor(
-        env: Env,
-        amount: i128,
-        corridor: FeeCorridor,
-    ) -> Result<FeeBreakdown, ContractError> {
-        fee_service::calculate_fees_with_breakdown(&env, amount, None, Some(&corridor))
+    /// Requires authentication from the owner.
+    pub fn get_address_book(env: Env, owner: Address) -> Vec<AddressBookEntry> {
+        owner.require_auth();
+        address_book::list(&env, &owner)
     }
 
-    /// Sets a fee corridor configuration for a country pair
+    /// Registers a handle to `owner` for `HANDLE_TERM_SECONDS`.
     ///
-    /// Allows admin to configure specific fee rules for cross-border corridors.
+    /// Charges `HANDLE_REGISTRATION_FEE` in the default token, paid to the
+    /// treasury. Lapsed handles can be registered by anyone.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `env` - The contract execution environment
-    /// * `corridor` - Corridor configuration with country codes and fee rules
+    /// * `ContractError::InvalidHandle` - Handle fails the format rules
+    /// * `ContractError::HandleTaken` - Handle is currently registered
+    /// * `ContractError::HandleFrozen` - Handle is frozen
     ///
     /// # Authorization
     ///
-    /// Requires admin authentication
-    pub fn set_fee_corridor(
-        env: Env,
-        caller: Address,
-        corridor: FeeCorridor,
-    ) -> Result<(), ContractError> {
-        require_admin(&env, &caller)?;
-        storage::set_fee_corridor(&env, &corridor);
-        Ok(())
+    /// Requires authentication from the owner.
+    pub fn register_handle(env: Env, owner: Address, handle: String) -> Result<u64, ContractError> {
+        owner.require_auth();
+        let record = handles::register(&env, &owner, handle)?;
+        emit_handle_registered(&env, record.handle, owner, record.expires_at);
+        Ok(record.expires_at)
     }
 
-    /// Gets a fee corridor configuration for a country pair
+    /// Extends the owner's handle by another `HANDLE_TERM_SECONDS` for the
+    /// registration fee. Returns the new expiry.
     ///
-    /// # Arguments
+    /// # Authorization
     ///
-    /// * `env` - The contract execution environment
-    /// * `from_country` - Source country code (ISO 3166-1 alpha-2)
-    /// * `to_country` - Destination country code (ISO 3166-1 alpha-2)
+    /// Requires authentication from the handle owner.
+    pub fn renew_handle(env: Env, owner: Address, handle: String) -> Result<u64, ContractError> {
+        owner.require_auth();
+        let record = handles::renew(&env, &owner, &handle)?;
+        emit_handle_renewed(&env, handle, owner, record.expires_at);
+        Ok(record.expires_at)
+    }
+
+    /// Transfers a handle to a new owner, keeping its expiry.
     ///
-    /// # Returns
+    /// # Authorization
     ///
-    /// Corridor configuration if exists, None otherwise
-    pub fn get_fee_corridor(
+    /// Requires authentication from the current handle owner.
+    pub fn transfer_handle(
         env: Env,
-        from_country: String,
-        to_country: String,
-    ) -> Option<FeeCorridor> {
-        storage::get_fee_corridor(&env, &from_country, &to_country)
+        owner: Address,
+        handle: String,
+        new_owner: Address,
+    ) -> Result<(), ContractError> {
+        owner.require_auth();
+        let mut record = handles::owned(&env, &owner, &handle)?;
+        record.owner = new_owner.clone();
+        storage::set_handle(&env, &record);
+        emit_handle_transferred(&env, handle, owner, new_owner);
+        Ok(())
     }
 
-    /// Removes a fee corridor configuration
+    /// Resolves a handle to its owner's address.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `env` - The contract execution environment
-    /// * `from_country` - Source country code
-    /// * `to_country` - Destination country code
+    /// * `ContractError::HandleNotFound` - Handle is unregistered or lapsed
+    /// * `ContractError::HandleFrozen` - Handle is frozen
+    pub fn resolve_handle(env: Env, handle: String) -> Result<Address, ContractError> {
+        handles::resolve(&env, &handle)
+    }
+
+    /// Returns a handle's registration record, including lapsed ones.
+    pub fn get_handle(env: Env, handle: String) -> Option<HandleRecord> {
+        storage::get_handle(&env, &handle)
+    }
+
+    /// Freezes or unfreezes a registered handle.
     ///
     /// # Authorization
     ///
-    /// Requires admin authentication
-    pub fn remove_fee_corridor(
+    /// Requires admin authorization.
+    pub fn set_handle_frozen(
         env: Env,
         caller: Address,
-        from_country: String,
-        to_country: String,
+        handle: String,
+        frozen: bool,
     ) -> Result<(), ContractError> {
         require_admin(&env, &caller)?;
-        storage::remove_fee_corridor(&env, &from_country, &to_country);
+        admin_log::record(&env, &caller, "set_handle_frozen", (handle.clone(), frozen));
+        let mut record = storage::get_handle(&env, &handle).ok_or(ContractError::HandleNotFound)?;
+        record.frozen = frozen;
+        storage::set_handle(&env, &record);
+        emit_handle_frozen(&env, handle, frozen);
         Ok(())
     }
 
@@ -2983,6 +8372,19 @@ impl SwiftRemitContract {
     ) -> Result<(), ContractError> {
         let admin = get_admin(&env)?;
         admin.require_auth();
+        admin_log::record(
+            &env,
+            &admin,
+            "set_asset_verification",
+            (
+                asset_code.clone(),
+                issuer.clone(),
+                status.clone(),
+                reputation_score,
+                trustline_count,
+                has_toml,
+            ),
+        );
 
         if reputation_score > 100 {
             return Err(ContractError::InvalidReputationScore);
@@ -3077,7 +8479,7 @@ impl SwiftRemitContract {
         user: Address,
         agent: Address,
         amount: i128,
-        expiry: Option<u64>,
+        expiry: Option<Expiry>,
     ) -> Result<TransactionRecord, ContractError> {
         TransactionController::execute_transaction(&env, user, agent, amount, expiry)
     }
@@ -3137,6 +8539,7 @@ impl SwiftRemitContract {
     ) -> Result<(), ContractError> {
         let admin = get_admin(&env)?;
         admin.require_auth();
+        admin_log::record(&env, &admin, "set_kyc_approved", (user.clone(), approved, expiry));
 
         set_kyc_approved(&env, &user, approved);
         if approved {
@@ -3150,6 +8553,79 @@ impl SwiftRemitContract {
         is_kyc_approved(&env, &user) && !is_kyc_expired(&env, &user)
     }
 
+    /// Assigns a KYC tier to `user`. The tier only counts while the user's
+    /// KYC approval is current.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an address holding `Role::Compliance`.
+    pub fn set_kyc_tier(
+        env: Env,
+        officer: Address,
+        user: Address,
+        tier: u32,
+    ) -> Result<(), ContractError> {
+        officer.require_auth();
+        storage::require_role_compliance(&env, &officer)?;
+        storage::set_kyc_tier(&env, &user, tier);
+        emit_kyc_tier_set(&env, user, tier, officer);
+        Ok(())
+    }
+
+    /// Returns the KYC tier `user` currently holds (0 without current KYC approval).
+    pub fn get_kyc_tier(env: Env, user: Address) -> u32 {
+        restrictions::kyc_tier(&env, &user)
+    }
+
+    /// Restricts a token or corridor for new remittances and agent
+    /// assignments, replacing any earlier restriction on it.
+    ///
+    /// Remittances created before the restriction are not affected.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an address holding `Role::Compliance`.
+    pub fn set_restriction(
+        env: Env,
+        officer: Address,
+        target: RestrictionTarget,
+        restriction: Restriction,
+    ) -> Result<(), ContractError> {
+        officer.require_auth();
+        storage::require_role_compliance(&env, &officer)?;
+        let record = restrictions::restrict(&env, target, restriction);
+        emit_restriction_set(&env, record.target, restriction, officer);
+        Ok(())
+    }
+
+    /// Lifts the restriction on a token or corridor, if any.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from an address holding `Role::Compliance`.
+    pub fn lift_restriction(
+        env: Env,
+        officer: Address,
+        target: RestrictionTarget,
+    ) -> Result<(), ContractError> {
+        officer.require_auth();
+        storage::require_role_compliance(&env, &officer)?;
+        if restrictions::lift(&env, &target) {
+            emit_restriction_lifted(&env, target, officer);
+        }
+        Ok(())
+    }
+
+    /// Returns the restriction on a token or corridor, if any.
+    pub fn get_restriction(env: Env, target: RestrictionTarget) -> Option<RestrictionRecord> {
+        storage::get_restriction(&env, &target)
+    }
+
+    /// Returns every restriction in force.
+    pub fn get_restrictions(env: Env) -> Vec<RestrictionRecord> {
+        restrictions::all(&env)
+    }
+
     // ═══════════════════════════════════════════════════════════════════════════
     // Migration Functions
     // ═══════════════════════════════════════════════════════════════════════════
@@ -3180,6 +8656,7 @@ impl SwiftRemitContract {
 
         // Admin auth
         require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "export_migration_snapshot", ());
 
         // Prevent double-export
         if crate::storage::is_migration_in_progress(&env) {
@@ -3220,6 +8697,7 @@ impl SwiftRemitContract {
 
         // Admin auth
         require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "import_migration_batch", (batch.clone(),));
 
         // Validate batch metadata
         if batch.batch_number >= batch.total_batches {
@@ -3241,6 +8719,134 @@ impl SwiftRemitContract {
         Ok(())
     }
 
+    /// Rehearses the V1 → V2 remittance migration without writing anything.
+    ///
+    /// Examines up to `limit` remittances from `start_id`, emitting a
+    /// `mig`/`rehearse` comparison event for each record still in the V1
+    /// layout. Call again with the returned `next_id` until `done` to cover
+    /// every remittance. Unlike other admin calls it is not recorded in the
+    /// admin log, so the rehearsal leaves state untouched.
+    ///
+    /// # Authorization
+    /// Admin only — caller must authenticate.
+    ///
+    /// # Errors
+    /// - `Unauthorized` — caller is not an admin
+    pub fn rehearse_migration(
+        env: Env,
+        caller: Address,
+        start_id: u64,
+        limit: u32,
+    ) -> Result<MigrationRehearsal, ContractError> {
+        require_admin(&env, &caller)?;
+        migration::rehearse(&env, start_id, limit)
+    }
+
+    /// Replaces the contract's code with the already uploaded WASM
+    /// `new_wasm_hash`, keeping all storage.
+    ///
+    /// The new code runs from the next invocation. If it raises
+    /// `CURRENT_SCHEMA_VERSION`, call `migrate` next to bring stored state up
+    /// to date.
+    ///
+    /// # Authorization
+    /// Admin only — caller must authenticate.
+    ///
+    /// # Errors
+    /// - `Unauthorized` — caller is not an admin
+    pub fn upgrade(
+        env: Env,
+        caller: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "upgrade", (new_wasm_hash.clone(),));
+        emit_contract_upgraded(
+            &env,
+            caller,
+            new_wasm_hash.clone(),
+            migration::get_schema_version(&env),
+        );
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    /// Brings stored state up to the schema version of the running code after
+    /// an `upgrade`. Does nothing if it is already current, so it is safe to
+    /// call after every upgrade.
+    ///
+    /// # Authorization
+    /// Admin only — caller must authenticate.
+    ///
+    /// # Returns
+    /// The schema version stored state is now at.
+    ///
+    /// # Errors
+    /// - `Unauthorized` — caller is not an admin
+    /// - `MigrationValidationFailed` — agents could not be read back after
+    ///   migrating; the schema version is left unchanged and
+    ///   `rollback_migration` restores the earlier state
+    pub fn migrate(env: Env, caller: Address) -> Result<u32, ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "migrate", ());
+        migration::migrate(&env)?;
+        Ok(migration::get_schema_version(&env))
+    }
+
+    /// Restores agent state saved before a failed `migrate` and returns the
+    /// schema version to what it was.
+    ///
+    /// # Authorization
+    /// Admin only — caller must authenticate.
+    ///
+    /// # Errors
+    /// - `Unauthorized` — caller is not an admin
+    /// - `NotFound` — no failed migration is waiting to be rolled back
+    pub fn rollback_migration(env: Env, caller: Address) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "rollback_migration", ());
+        migration::rollback_migration(&env)
+    }
+
+    /// Returns the storage schema version stored state is at.
+    pub fn get_schema_version(env: Env) -> u32 {
+        migration::get_schema_version(&env)
+    }
+
+    /// Removes leftover settlement flag entries for the given remittances.
+    ///
+    /// Duplicate settlement is detected from remittance state, so the
+    /// per-remittance entries written by the former hash-based tracking are
+    /// dead weight. Run this over historical IDs to stop paying rent on them.
+    ///
+    /// # Authorization
+    /// Admin only — caller must authenticate.
+    ///
+    /// # Returns
+    /// Number of remittances that had entries removed.
+    ///
+    /// # Errors
+    /// - `InvalidBatchSize` — more than `MAX_MIGRATION_BATCH_SIZE` IDs supplied
+    pub fn cleanup_settlement_entries(
+        env: Env,
+        caller: Address,
+        remittance_ids: Vec<u64>,
+    ) -> Result<u32, ContractError> {
+        require_admin(&env, &caller)?;
+        admin_log::record(&env, &caller, "cleanup_settlement_entries", (remittance_ids.clone(),));
+        if remittance_ids.len() > MAX_MIGRATION_BATCH_SIZE {
+            return Err(ContractError::InvalidBatchSize);
+        }
+
+        let mut cleaned = 0u32;
+        for remittance_id in remittance_ids.iter() {
+            if storage::remove_settlement_entries(&env, remittance_id) {
+                cleaned += 1;
+            }
+        }
+        Ok(cleaned)
+    }
+
     // ═══════════════════════════════════════════════════════════════════════════
     // Multi-Signature Admin Operations  (#253)
     // ═══════════════════════════════════════════════════════════════════════════
@@ -3257,6 +8863,7 @@ impl SwiftRemitContract {
         threshold: u32,
         ttl_seconds: u64,
     ) -> Result<(), ContractError> {
+        admin_log::record(&env, &caller, "set_multisig_config", (threshold, ttl_seconds));
         multisig::set_multisig_config(&env, caller, threshold, ttl_seconds)
     }
 
@@ -3279,6 +8886,12 @@ impl SwiftRemitContract {
         fee_bps: u32,
         withdraw_to: Option<Address>,
     ) -> Result<u64, ContractError> {
+        admin_log::record(
+            &env,
+            &proposer,
+            "propose_operation",
+            (operation_type.clone(), fee_bps, withdraw_to.clone()),
+        );
         multisig::propose_operation(&env, proposer, operation_type, fee_bps, withdraw_to)
     }
 
@@ -3293,6 +8906,7 @@ impl SwiftRemitContract {
         approver: Address,
         operation_id: u64,
     ) -> Result<(), ContractError> {
+        admin_log::record(&env, &approver, "approve_operation", (operation_id,));
         multisig::approve_operation(&env, approver, operation_id)
     }
 
@@ -3325,7 +8939,14 @@ impl SwiftRemitContract {
         proposal_ttl_seconds: u64,
     ) -> Result<(), ContractError> {
         caller.require_auth();
-        governance::do_migrate(&env, &caller, quorum, timelock_seconds, proposal_ttl_seconds)
+        governance::do_migrate(&env, &caller, quorum, timelock_seconds, proposal_ttl_seconds)?;
+        admin_log::record(
+            &env,
+            &caller,
+            "migrate_to_governance",
+            (quorum, timelock_seconds, proposal_ttl_seconds),
+        );
+        Ok(())
     }
 
     /// Creates a new governance proposal.
@@ -3401,4 +9022,219 @@ impl SwiftRemitContract {
             proposal_ttl_seconds: storage::get_proposal_ttl(&env),
         }
     }
+
+    // === Organization Functions ===
+
+    /// Creates an organization account owned by `owner`.
+    ///
+    /// The organization's shared balance is denominated in `token`, which must
+    /// be whitelisted. Returns the new organization ID.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `owner`.
+    pub fn create_organization(env: Env, owner: Address, token: Address) -> Result<u64, ContractError> {
+        owner.require_auth();
+        if !storage::is_token_whitelisted(&env, &token) {
+            return Err(ContractError::TokenNotWhitelisted);
+        }
+
+        let org_id = organization::create(&env, &owner, &token)?;
+        emit_org_created(&env, org_id, owner, token);
+        Ok(org_id)
+    }
+
+    /// Deposits funds into an organization's shared balance.
+    ///
+    /// Anyone may fund an organization; only the owner can withdraw.
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `from`.
+    pub fn deposit_to_organization(
+        env: Env,
+        org_id: u64,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        validate_amount(amount)?;
+        from.require_auth();
+
+        let balance = organization::deposit(&env, org_id, &from, amount)?;
+        emit_org_deposit(&env, org_id, from, amount, balance);
+        Ok(())
+    }
+
+    /// Withdraws funds from an organization's shared balance (owner only).
+    pub fn withdraw_from_organization(
+        env: Env,
+        org_id: u64,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        validate_amount(amount)?;
+        let mut org = organization::require_org_owner(&env, org_id)?;
+
+        organization::debit(&env, &mut org, amount)?;
+        token_transfer::transfer(
+            &token::Client::new(&env, &org.token),
+            &env.current_contract_address(),
+            &to,
+            amount,
+        )?;
+        emit_org_withdraw(&env, org_id, to, amount, org.balance);
+        Ok(())
+    }
+
+    /// Adds a member to an organization, or updates an existing member's
+    /// rolling 24-hour spending limit (owner only).
+    pub fn set_org_member(
+        env: Env,
+        org_id: u64,
+        member: Address,
+        spending_limit: i128,
+    ) -> Result<(), ContractError> {
+        validate_amount(spending_limit)?;
+        let mut org = organization::require_org_owner(&env, org_id)?;
+
+        organization::upsert_member(&env, &mut org, &member, spending_limit);
+        emit_org_member_set(&env, org_id, member, spending_limit);
+        Ok(())
+    }
+
+    /// Removes a member from an organization (owner only).
+    pub fn remove_org_member(env: Env, org_id: u64, member: Address) -> Result<(), ContractError> {
+        let mut org = organization::require_org_owner(&env, org_id)?;
+
+        organization::remove_member(&env, &mut org, &member)?;
+        emit_org_member_removed(&env, org_id, member);
+        Ok(())
+    }
+
+    /// Creates a remittance funded from an organization's shared balance.
+    ///
+    /// The remittance is recorded with the organization owner as sender, so any
+    /// refund returns to the owner. `amount` is charged against the member's
+    /// rolling spending limit and debited from the shared balance.
+    ///
+    /// If `amount` would exceed the member's remaining limit, nothing is
+    /// debited; the remittance is held as a request in `PendingApproval` state
+    /// until the owner calls `approve_org_remittance` or `reject_org_remittance`.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::NotOrgMember` - `member` does not belong to the organization
+    /// * `ContractError::InsufficientOrgBalance` - Shared balance is too low
+    /// * `ContractError::OrgPendingRequestsFull` - Too many requests already await approval
+    ///
+    /// # Authorization
+    ///
+    /// Requires authentication from `member`.
+    pub fn create_org_remittance(
+        env: Env,
+        org_id: u64,
+        member: Address,
+        agent: Address,
+        amount: i128,
+        expiry: Option<Expiry>,
+    ) -> Result<OrgRemittanceOutcome, ContractError> {
+        if crate::storage::is_migration_in_progress(&env) {
+            return Err(ContractError::MigrationInProgress);
+        }
+        let mut org = storage::get_organization(&env, org_id).ok_or(ContractError::OrganizationNotFound)?;
+        member.require_auth();
+        validate_create_remittance_request(&env, &org.owner, &agent, amount)?;
+        if is_user_blacklisted(&env, &member) {
+            return Err(ContractError::UserBlacklisted);
+        }
+
+        let mut record = organization::current_member(&env, org_id, &member)?;
+        if !organization::within_limit(&record, amount) {
+            let request_id = organization::open_request(&env, org_id, &member, &agent, amount, expiry.into())?;
+            emit_org_approval_requested(&env, org_id, request_id, member, amount);
+            return Ok(OrgRemittanceOutcome::PendingApproval(request_id));
+        }
+        organization::charge_member(&env, &member, &mut record, amount)?;
+        organization::debit(&env, &mut org, amount)?;
+
+        let fee = fee_service::calculate_platform_fee_for_sender(&env, &org.owner, amount, Some(&org.token))?;
+        let remittance = record_new_remittance(&env, &org.owner, &agent, amount, fee, &org.token, expiry)?;
+        storage::append_org_remittance(&env, org_id, remittance.id);
+
+        emit_org_remittance(&env, org_id, member, remittance.id, amount);
+        Ok(OrgRemittanceOutcome::Created(remittance.id))
+    }
+
+    /// Approves an over-limit member request and commits its remittance (owner only).
+    ///
+    /// The amount is debited from the shared balance at approval time. An
+    /// approved request does not count against the member's spending limit.
+    ///
+    /// # Errors
+    ///
+    /// * `ContractError::OrgRequestNotFound` - No such request for this organization
+    /// * `ContractError::OrgRequestNotPending` - Request was already approved or rejected
+    /// * `ContractError::InsufficientOrgBalance` - Shared balance is too low
+    pub fn approve_org_remittance(env: Env, org_id: u64, request_id: u64) -> Result<u64, ContractError> {
+        if crate::storage::is_migration_in_progress(&env) {
+            return Err(ContractError::MigrationInProgress);
+        }
+        let mut org = organization::require_org_owner(&env, org_id)?;
+        let mut request = organization::pending_request(&env, org_id, request_id)?;
+        validate_create_remittance_request(&env, &org.owner, &request.agent, request.amount)?;
+
+        organization::debit(&env, &mut org, request.amount)?;
+        let fee = fee_service::calculate_platform_fee_for_sender(&env, &org.owner, request.amount, Some(&org.token))?;
+        let remittance = record_new_remittance(
+            &env,
+            &org.owner,
+            &request.agent,
+            request.amount,
+            fee,
+            &org.token,
+            request.expiry.into(),
+        )?;
+        storage::append_org_remittance(&env, org_id, remittance.id);
+        organization::close_request(&env, &mut request, OrgRequestStatus::Approved, Some(remittance.id));
+
+        emit_org_request_approved(&env, org_id, request_id, remittance.id);
+        emit_org_remittance(&env, org_id, request.member, remittance.id, request.amount);
+        Ok(remittance.id)
+    }
+
+    /// Rejects an over-limit member request (owner only). No funds move.
+    pub fn reject_org_remittance(env: Env, org_id: u64, request_id: u64) -> Result<(), ContractError> {
+        organization::require_org_owner(&env, org_id)?;
+        let mut request = organization::pending_request(&env, org_id, request_id)?;
+
+        organization::close_request(&env, &mut request, OrgRequestStatus::Rejected, None);
+        emit_org_request_rejected(&env, org_id, request_id);
+        Ok(())
+    }
+
+    /// Returns an over-limit member request by ID.
+    pub fn get_org_request(env: Env, request_id: u64) -> Result<OrgRemittanceRequest, ContractError> {
+        storage::get_org_request(&env, request_id).ok_or(ContractError::OrgRequestNotFound)
+    }
+
+    /// Returns the IDs of an organization's requests awaiting owner approval.
+    pub fn get_org_pending_requests(env: Env, org_id: u64) -> Vec<u64> {
+        storage::get_org_pending_requests(&env, org_id)
+    }
+
+    /// Returns an organization record.
+    pub fn get_organization(env: Env, org_id: u64) -> Result<Organization, ContractError> {
+        storage::get_organization(&env, org_id).ok_or(ContractError::OrganizationNotFound)
+    }
+
+    /// Returns a member's record, including their usage in the current window.
+    pub fn get_org_member(env: Env, org_id: u64, member: Address) -> Result<OrgMember, ContractError> {
+        organization::current_member(&env, org_id, &member)
+    }
+
+    /// Returns a page of remittance IDs initiated through an organization.
+    pub fn get_org_remittances(env: Env, org_id: u64, cursor: u32, limit: u32) -> IdPage {
+        let ids = storage::get_org_remittances(&env, org_id);
+        storage::paginate(&env, &ids, cursor, limit).into()
+    }
 }
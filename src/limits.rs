@@ -0,0 +1,126 @@
+//! Consolidated configuration of the platform-wide limits.
+//!
+//! The global limit knobs — remittance amount bounds, the settlement
+//! cooldown, the per-address rate limit, and the expiry sweep batch size —
+//! are read together as one [`Limits`] value with `get_limits` and changed
+//! together with `set_limits`.
+//!
+//! Changes are timelocked like other governance actions: `set_limits` records
+//! them as [`PendingLimits`] taking effect after the governance timelock, and
+//! anyone can then apply them with `apply_limits`. Without a timelock they
+//! apply at once. Applying emits a single event carrying both the old and new
+//! limits.
+//!
+//! Limits keyed by corridor, agent, or token (daily send limits, agent caps
+//! and corridor capacity, token batch limits) keep their own setters.
+
+use soroban_sdk::{contracttype, Env};
+
+use crate::rate_limit::{get_rate_limit_config, set_rate_limit_config, RateLimitConfig};
+use crate::storage::{
+    get_governance_timelock, get_max_amount, get_max_expired_batch_size, get_min_amount,
+    get_pending_limits, get_rate_limit_cooldown, remove_pending_limits, set_max_amount,
+    set_max_expired_batch_size, set_min_amount, set_pending_limits, set_rate_limit_cooldown,
+};
+use crate::ContractError;
+
+/// Platform-wide limits.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Limits {
+    /// Smallest remittance amount accepted; 0 for no minimum
+    pub min_amount: i128,
+    /// Largest remittance amount accepted; 0 for no maximum
+    pub max_amount: i128,
+    /// Seconds a sender must wait between settlements
+    pub settlement_cooldown_seconds: u64,
+    /// Per-address request rate limit
+    pub rate_limit: RateLimitConfig,
+    /// Most remittances a single expiry sweep processes
+    pub max_expired_batch_size: u32,
+}
+
+/// Limits waiting for the governance timelock to pass.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingLimits {
+    pub limits: Limits,
+    pub effective_at: u64,
+}
+
+/// Returns the limits in force.
+pub fn current(env: &Env) -> Result<Limits, ContractError> {
+    Ok(Limits {
+        min_amount: get_min_amount(env),
+        max_amount: get_max_amount(env),
+        settlement_cooldown_seconds: get_rate_limit_cooldown(env)?,
+        rate_limit: get_rate_limit_config(env)?,
+        max_expired_batch_size: get_max_expired_batch_size(env),
+    })
+}
+
+/// Records `limits` to take effect after the governance timelock, replacing
+/// any change still pending.
+///
+/// # Errors
+///
+/// * `ContractError::InvalidAmount` - Negative amount bounds, or a maximum below the minimum
+/// * `ContractError::InvalidBatchSize` - Batch size not between 1 and 200
+pub fn schedule(env: &Env, limits: Limits) -> Result<PendingLimits, ContractError> {
+    if limits.min_amount < 0
+        || limits.max_amount < 0
+        || (limits.max_amount > 0 && limits.max_amount < limits.min_amount)
+    {
+        return Err(ContractError::InvalidAmount);
+    }
+    if limits.max_expired_batch_size < 1 || limits.max_expired_batch_size > 200 {
+        return Err(ContractError::InvalidBatchSize);
+    }
+
+    let pending = PendingLimits {
+        limits,
+        effective_at: env
+            .ledger()
+            .timestamp()
+            .saturating_add(get_governance_timelock(env)),
+    };
+    set_pending_limits(env, &pending);
+    Ok(pending)
+}
+
+/// Applies the pending limits once their timelock has passed. Returns the
+/// old and new limits.
+///
+/// # Errors
+///
+/// * `ContractError::ScheduledChangeNotFound` - No change is pending
+/// * `ContractError::TimelockActive` - The timelock has not passed yet
+pub fn apply(env: &Env) -> Result<(Limits, Limits), ContractError> {
+    let pending = get_pending_limits(env).ok_or(ContractError::ScheduledChangeNotFound)?;
+    if env.ledger().timestamp() < pending.effective_at {
+        return Err(ContractError::TimelockActive);
+    }
+
+    let old = current(env)?;
+    let limits = pending.limits;
+    set_min_amount(env, limits.min_amount);
+    set_max_amount(env, limits.max_amount);
+    set_rate_limit_cooldown(env, limits.settlement_cooldown_seconds);
+    set_rate_limit_config(env, limits.rate_limit.clone());
+    set_max_expired_batch_size(env, limits.max_expired_batch_size);
+    remove_pending_limits(env);
+    Ok((old, limits))
+}
+
+/// Checks a remittance amount against the amount bounds.
+///
+/// # Errors
+///
+/// * `ContractError::AmountOutsideLimits` - Below the minimum or above the maximum
+pub fn check_amount(env: &Env, amount: i128) -> Result<(), ContractError> {
+    let max_amount = get_max_amount(env);
+    if amount < get_min_amount(env) || (max_amount > 0 && amount > max_amount) {
+        return Err(ContractError::AmountOutsideLimits);
+    }
+    Ok(())
+}
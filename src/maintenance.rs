@@ -0,0 +1,85 @@
+//! Planned maintenance windows.
+//!
+//! The admin schedules windows during which new remittances are refused, for
+//! example ahead of a migration. Unlike a pause, a window only blocks
+//! creation: confirmations, settlements, cancellations, and refunds keep
+//! working so transfers already in flight can finish. Creation attempts fail
+//! with `ContractError::MaintenanceWindow`; clients read the window's `end`
+//! through `get_active_maintenance_window` to tell users when to retry.
+//!
+//! Windows that have ended are pruned whenever a new one is scheduled, and at
+//! most [`MAX_MAINTENANCE_WINDOWS`] are kept.
+
+use soroban_sdk::{contracttype, Env, Vec};
+
+use crate::config::MAX_MAINTENANCE_WINDOWS;
+use crate::storage::{get_maintenance_windows, set_maintenance_windows};
+use crate::ContractError;
+
+/// A period during which new remittances are refused.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintenanceWindow {
+    pub start: u64,
+    /// Timestamp at which creations resume
+    pub end: u64,
+}
+
+impl MaintenanceWindow {
+    fn contains(&self, now: u64) -> bool {
+        self.start <= now && now < self.end
+    }
+}
+
+/// Adds a window, dropping any that have already ended.
+pub fn schedule(env: &Env, start: u64, end: u64) -> Result<(), ContractError> {
+    let now = env.ledger().timestamp();
+    if start >= end || end <= now {
+        return Err(ContractError::InvalidMaintenanceWindow);
+    }
+    let mut windows = Vec::new(env);
+    for window in get_maintenance_windows(env).iter() {
+        if window.end > now {
+            windows.push_back(window);
+        }
+    }
+    if windows.len() >= MAX_MAINTENANCE_WINDOWS {
+        return Err(ContractError::InvalidMaintenanceWindow);
+    }
+    windows.push_back(MaintenanceWindow { start, end });
+    set_maintenance_windows(env, &windows);
+    Ok(())
+}
+
+/// Removes the window starting at `start`.
+pub fn cancel(env: &Env, start: u64) -> Result<(), ContractError> {
+    let mut windows = get_maintenance_windows(env);
+    let index = windows
+        .iter()
+        .position(|window| window.start == start)
+        .ok_or(ContractError::InvalidMaintenanceWindow)?;
+    windows.remove(index as u32);
+    set_maintenance_windows(env, &windows);
+    Ok(())
+}
+
+/// Returns the window in effect now, if any. When windows overlap, the one
+/// ending last is returned so its `end` is the true resume time.
+pub fn active(env: &Env) -> Option<MaintenanceWindow> {
+    let now = env.ledger().timestamp();
+    let mut active: Option<MaintenanceWindow> = None;
+    for window in get_maintenance_windows(env).iter() {
+        if window.contains(now) && !matches!(&active, Some(a) if a.end >= window.end) {
+            active = Some(window);
+        }
+    }
+    active
+}
+
+/// Fails with `MaintenanceWindow` while a window is in effect.
+pub fn ensure_open(env: &Env) -> Result<(), ContractError> {
+    if active(env).is_some() {
+        return Err(ContractError::MaintenanceWindow);
+    }
+    Ok(())
+}
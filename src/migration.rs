@@ -37,7 +37,11 @@
 
 use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Vec, xdr::ToXdr};
 
-use crate::{config::MAX_MIGRATION_BATCH_SIZE, AgentStats, ContractError, Remittance, RemittanceStatus};
+use crate::{
+    config::MAX_MIGRATION_BATCH_SIZE, events::emit_migration_rehearsed, AgentStats,
+    ContractError, Expiry, MaybeExpiry, Remittance, RemittanceStatus, RemittanceV1,
+    VersionedRemittance,
+};
 
 // ─── Schema version ──────────────────────────────────────────────────────────
 
@@ -100,7 +104,7 @@ pub struct PersistentData {
     pub agents: Vec<AgentRecord>,
     /// Admin role addresses.
     pub admin_roles: Vec<Address>,
-    /// Remittance IDs that have been settled (for dedup).
+    /// Remittance IDs that have been settled (informational; derived from remittance state).
     pub settlement_hashes: Vec<u64>,
     /// Whitelisted token addresses.
     pub whitelisted_tokens: Vec<Address>,
@@ -168,14 +172,14 @@ enum MigrationKey {
     MigrationInProgress,
 }
 
-fn get_schema_version(env: &Env) -> u32 {
+pub(crate) fn get_schema_version(env: &Env) -> u32 {
     env.storage()
         .instance()
         .get(&MigrationKey::SchemaVersion)
         .unwrap_or(1) // contracts deployed before versioning default to v1
 }
 
-fn set_schema_version(env: &Env, version: u32) {
+pub(crate) fn set_schema_version(env: &Env, version: u32) {
     env.storage()
         .instance()
         .set(&MigrationKey::SchemaVersion, &version);
@@ -251,6 +255,7 @@ fn status_to_byte(status: &RemittanceStatus) -> u8 {
         RemittanceStatus::Cancelled => 3,
         RemittanceStatus::Failed => 4,
         RemittanceStatus::Disputed => 5,
+        RemittanceStatus::Expired => 6,
     }
 }
 
@@ -478,6 +483,97 @@ pub fn abort_migration(env: &Env, caller: &Address) -> Result<(), ContractError>
     Ok(())
 }
 
+// ─── Migration rehearsal ─────────────────────────────────────────────────────
+
+/// Result of one `rehearse` pass over a range of remittance IDs.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationRehearsal {
+    /// Number of IDs examined.
+    pub scanned: u32,
+    /// Records still stored in the V1 layout.
+    pub legacy: u32,
+    /// Records already stored in the current layout.
+    pub current: u32,
+    /// IDs with no stored record.
+    pub missing: u32,
+    /// IDs whose record is unreadable or would not survive conversion intact.
+    pub mismatched: Vec<u64>,
+    /// ID to pass as `start_id` to continue the rehearsal.
+    pub next_id: u64,
+    /// Whether the pass reached the remittance counter.
+    pub done: bool,
+}
+
+/// Dry-runs the V1 → V2 remittance migration over IDs from `start_id`.
+///
+/// Reads up to `limit` (capped at `MAX_MIGRATION_BATCH_SIZE`) records without
+/// migrating them. Every V1 record is converted in memory and compared with
+/// its source, and a `mig`/`rehearse` event reports the hashes of both
+/// representations so the conversion can be checked off-chain against real
+/// data. Nothing is written.
+pub fn rehearse(env: &Env, start_id: u64, limit: u32) -> Result<MigrationRehearsal, ContractError> {
+    let counter = crate::storage::get_remittance_counter(env)?;
+    let start = start_id.max(1);
+    let end = start
+        .saturating_add(limit.min(MAX_MIGRATION_BATCH_SIZE) as u64)
+        .min(counter.saturating_add(1))
+        .max(start);
+
+    let mut report = MigrationRehearsal {
+        scanned: 0,
+        legacy: 0,
+        current: 0,
+        missing: 0,
+        mismatched: Vec::new(env),
+        next_id: end,
+        done: end > counter,
+    };
+    for id in start..end {
+        report.scanned += 1;
+        match crate::storage::get_remittance_any(env, id) {
+            Ok(VersionedRemittance::V2(_)) => report.current += 1,
+            Ok(VersionedRemittance::V1(legacy)) => {
+                report.legacy += 1;
+                let upgraded: Remittance = legacy.clone().into();
+                let preserved = preserves_v1_fields(id, &legacy, &upgraded);
+                if !preserved {
+                    report.mismatched.push_back(id);
+                }
+                emit_migration_rehearsed(
+                    env,
+                    id,
+                    env.crypto().sha256(&legacy.to_xdr(env)).to_bytes(),
+                    env.crypto().sha256(&upgraded.to_xdr(env)).to_bytes(),
+                    preserved,
+                );
+            }
+            Err(ContractError::RemittanceNotFound) => report.missing += 1,
+            Err(_) => report.mismatched.push_back(id),
+        }
+    }
+    Ok(report)
+}
+
+/// Whether `upgraded` carries every field of the V1 record stored under `id`.
+fn preserves_v1_fields(id: u64, legacy: &RemittanceV1, upgraded: &Remittance) -> bool {
+    let expiry: Option<Expiry> = upgraded.expiry.into();
+    legacy.id == id
+        && upgraded.id == legacy.id
+        && upgraded.sender == legacy.sender
+        && upgraded.agent == legacy.agent
+        && upgraded.amount == legacy.amount
+        && upgraded.fee == legacy.fee
+        && upgraded.status == legacy.status
+        && expiry == legacy.expiry.map(Expiry::Time)
+        && upgraded.settlement_config == legacy.settlement_config
+        && upgraded.token == legacy.token
+        && upgraded.created_at == legacy.created_at
+        && upgraded.failed_at == legacy.failed_at
+        && upgraded.dispute_evidence == legacy.dispute_evidence
+        && upgraded.expires_at == legacy.expires_at
+}
+
 // ─── Cross-contract migration (export / import) ───────────────────────────────
 
 /// Export complete contract state for cross-contract migration.
@@ -520,9 +616,9 @@ pub fn export_state(env: &Env) -> Result<MigrationSnapshot, ContractError> {
 
     // Collect settled remittance IDs.
     let mut settlement_hashes = Vec::new(env);
-    for id in 1..=counter {
-        if crate::storage::has_settlement_hash(env, id) {
-            settlement_hashes.push_back(id);
+    for remittance in remittances.iter() {
+        if remittance.is_settlement_executed() {
+            settlement_hashes.push_back(remittance.id);
         }
     }
 
@@ -606,11 +702,8 @@ pub fn import_state(env: &Env, snapshot: MigrationSnapshot) -> Result<(), Contra
         crate::storage::set_admin_role(env, &admin, true);
     }
 
-    // Import settlement hashes.
-    for i in 0..snapshot.persistent_data.settlement_hashes.len() {
-        let id = snapshot.persistent_data.settlement_hashes.get_unchecked(i);
-        crate::storage::set_settlement_hash(env, id);
-    }
+    // Settlement state travels with the imported remittance records, so the
+    // settled ID list needs no separate entries.
 
     // Import whitelisted tokens.
     for i in 0..snapshot.persistent_data.whitelisted_tokens.len() {
@@ -728,8 +821,8 @@ fn compute_snapshot_hash(
         data.append(&Bytes::from_array(env, &r.amount.to_be_bytes()));
         data.append(&Bytes::from_array(env, &r.fee.to_be_bytes()));
         data.append(&Bytes::from_array(env, &[status_to_byte(&r.status)]));
-        if let Some(expiry) = r.expiry {
-            data.append(&Bytes::from_array(env, &expiry.to_be_bytes()));
+        if let MaybeExpiry::Some(expiry) = r.expiry {
+            data.append(&Bytes::from_array(env, &expiry.to_hash_value().to_be_bytes()));
         }
     }
 
@@ -772,8 +865,8 @@ fn compute_batch_hash(env: &Env, remittances: &Vec<Remittance>, batch_number: u3
         data.append(&Bytes::from_array(env, &r.amount.to_be_bytes()));
         data.append(&Bytes::from_array(env, &r.fee.to_be_bytes()));
         data.append(&Bytes::from_array(env, &[status_to_byte(&r.status)]));
-        if let Some(expiry) = r.expiry {
-            data.append(&Bytes::from_array(env, &expiry.to_be_bytes()));
+        if let MaybeExpiry::Some(expiry) = r.expiry {
+            data.append(&Bytes::from_array(env, &expiry.to_hash_value().to_be_bytes()));
         }
     }
 
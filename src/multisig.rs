@@ -14,14 +14,16 @@
 
 use soroban_sdk::{token, Address, Env, Vec};
 
+use crate::token_transfer;
 use crate::{
     events::{
-        emit_operation_approved, emit_operation_executed, emit_operation_expired,
-        emit_operation_proposed,
+        emit_fee_updated, emit_operation_approved, emit_operation_executed,
+        emit_operation_expired, emit_operation_proposed,
     },
     storage::{
         get_accumulated_fees, get_multisig_threshold, get_multisig_ttl_seconds,
-        get_pending_operation, get_usdc_token, next_operation_id, remove_pending_operation,
+        get_pending_operation, get_platform_fee_bps, get_usdc_token, next_operation_id,
+        remove_pending_operation,
         require_admin, set_accumulated_fees, set_fee_strategy, set_multisig_threshold,
         set_multisig_ttl_seconds, set_paused, set_pending_operation, set_platform_fee_bps,
     },
@@ -181,8 +183,10 @@ pub fn get_operation(env: &Env, operation_id: u64) -> Result<PendingOperation, C
 fn execute_operation(env: &Env, op: &PendingOperation) -> Result<(), ContractError> {
     match &op.operation_type {
         AdminOperationType::UpdateFee => {
+            let old_fee_bps = get_platform_fee_bps(env)?;
             set_platform_fee_bps(env, op.fee_bps);
             set_fee_strategy(env, &FeeStrategy::Percentage(op.fee_bps));
+            emit_fee_updated(env, old_fee_bps, op.fee_bps);
         }
         AdminOperationType::WithdrawFees => {
             let fees = get_accumulated_fees(env)?;
@@ -192,8 +196,8 @@ fn execute_operation(env: &Env, op: &PendingOperation) -> Result<(), ContractErr
             let to = op.withdraw_to.clone().ok_or(ContractError::InvalidAddress)?;
             let usdc_token = get_usdc_token(env)?;
             let token_client = token::Client::new(env, &usdc_token);
-            token_client.transfer(&env.current_contract_address(), &to, &fees);
             set_accumulated_fees(env, 0);
+            token_transfer::transfer(&token_client, &env.current_contract_address(), &to, fees)?;
         }
         AdminOperationType::Pause => {
             set_paused(env, true);
@@ -147,7 +147,7 @@ pub fn compute_net_settlements(env: &Env, remittances: &Vec<Remittance>) -> Resu
 /// Normalizes a pair of addresses to ensure deterministic ordering.
 /// Returns (smaller_address, larger_address, direction_multiplier)
 /// where direction_multiplier is 1 if from < to, else -1.
-fn normalize_pair(from: &Address, to: &Address) -> (Address, Address, i128) {
+pub(crate) fn normalize_pair(from: &Address, to: &Address) -> (Address, Address, i128) {
     // Compare addresses lexicographically
     if compare_addresses(from, to) < 0 {
         // from < to, so from is party_a, to is party_b
@@ -264,13 +264,17 @@ mod tests {
             amount: 100,
             fee: 2,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: addr_a.clone(),
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         });
 
         // B -> A: 90
@@ -281,13 +285,17 @@ mod tests {
             amount: 90,
             fee: 1,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: addr_a.clone(),
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         });
 
         let result = compute_net_settlements(&env, &remittances).unwrap();
@@ -323,13 +331,17 @@ mod tests {
             amount: 100,
             fee: 2,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: addr_a.clone(),
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         });
 
         // B -> A: 100
@@ -340,13 +352,17 @@ mod tests {
             amount: 100,
             fee: 2,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: addr_a.clone(),
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         });
 
         let result = compute_net_settlements(&env, &remittances).unwrap();
@@ -373,13 +389,17 @@ mod tests {
             amount: 100,
             fee: 2,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: addr_a.clone(),
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         });
 
         // B -> C: 50
@@ -390,13 +410,17 @@ mod tests {
             amount: 50,
             fee: 1,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: addr_a.clone(),
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         });
 
         // C -> A: 30
@@ -407,13 +431,17 @@ mod tests {
             amount: 30,
             fee: 1,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: addr_a.clone(),
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         });
 
         let result = compute_net_settlements(&env, &remittances).unwrap();
@@ -445,13 +473,17 @@ mod tests {
             amount: 100,
             fee: 2,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: addr_a.clone(),
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         });
 
         remittances.push_back(Remittance {
@@ -461,13 +493,17 @@ mod tests {
             amount: 90,
             fee: 1,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: addr_a.clone(),
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         });
 
         let result = compute_net_settlements(&env, &remittances).unwrap();
@@ -491,13 +527,17 @@ mod tests {
             amount: 100,
             fee: 2,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: addr_a.clone(),
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         });
         remittances1.push_back(Remittance {
             id: 2,
@@ -506,13 +546,17 @@ mod tests {
             amount: 90,
             fee: 1,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: addr_a.clone(),
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         });
 
         // Second ordering (reversed)
@@ -524,13 +568,17 @@ mod tests {
             amount: 90,
             fee: 1,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: addr_a.clone(),
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         });
         remittances2.push_back(Remittance {
             id: 1,
@@ -539,13 +587,17 @@ mod tests {
             amount: 100,
             fee: 2,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: addr_a.clone(),
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         });
 
         let net1 = compute_net_settlements(&env, &remittances1).unwrap().net_transfers;
@@ -580,13 +632,17 @@ mod tests {
             amount,
             fee,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: soroban_sdk::Address::generate(env),
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         }
     }
 
@@ -643,7 +699,9 @@ mod tests {
             prop_assert_eq!(
                 actual_fees,
                 expected_fees,
-                "fee sum mismatch: expected {expected_fees}, got {actual_fees}"
+                "fee sum mismatch: expected {}, got {}",
+                expected_fees,
+                actual_fees
             );
         }
 
@@ -691,7 +749,8 @@ mod tests {
                 prop_assert_eq!(
                     transfer.net_amount.abs(),
                     expected_net,
-                    "net amount mismatch: expected {expected_net}"
+                    "net amount mismatch: expected {}",
+                    expected_net
                 );
             }
         }
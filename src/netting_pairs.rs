@@ -0,0 +1,136 @@
+//! Bilateral netting between agents.
+//!
+//! Two agents that exchange flows in both directions can register a netting
+//! pair. Instead of moving tokens for every obligation, each obligation is
+//! recorded against the pair's running net position, and a single transfer of
+//! the net amount is made from the net debtor to the net creditor at a netting
+//! checkpoint.
+//!
+//! Pairs are stored under a canonical ordering (`agent_a` < `agent_b`, using
+//! the same ordering as [`crate::netting`]); a positive `net_position` means
+//! `agent_a` owes `agent_b`.
+
+use soroban_sdk::{contracttype, token, Address, Env};
+
+use crate::netting::normalize_pair;
+use crate::storage::{get_netting_pair, remove_netting_pair, set_netting_pair};
+use crate::token_transfer;
+use crate::ContractError;
+
+/// Registered netting relationship between two agents.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NettingPair {
+    pub agent_a: Address,
+    pub agent_b: Address,
+    /// Token obligations are denominated and settled in
+    pub token: Address,
+    /// Running net obligation; positive means `agent_a` owes `agent_b`
+    pub net_position: i128,
+    /// Obligations recorded since the last checkpoint
+    pub pending_obligations: u32,
+    /// Ledger timestamp of the last checkpoint (0 if never settled)
+    pub last_checkpoint: u64,
+}
+
+/// Outcome of a netting checkpoint.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NettingCheckpoint {
+    /// Party that paid the net amount
+    pub debtor: Address,
+    pub creditor: Address,
+    pub amount: i128,
+    /// Number of obligations settled by this transfer
+    pub obligations: u32,
+}
+
+/// Loads the pair for two agents in either order.
+pub fn load(env: &Env, x: &Address, y: &Address) -> Result<NettingPair, ContractError> {
+    let (a, b, _) = normalize_pair(x, y);
+    get_netting_pair(env, &a, &b).ok_or(ContractError::NettingPairNotFound)
+}
+
+/// Registers a new netting pair with a zero position.
+pub fn register(env: &Env, x: &Address, y: &Address, token: &Address) -> Result<NettingPair, ContractError> {
+    if x == y {
+        return Err(ContractError::InvalidAddress);
+    }
+    let (a, b, _) = normalize_pair(x, y);
+    if get_netting_pair(env, &a, &b).is_some() {
+        return Err(ContractError::NettingPairExists);
+    }
+    let pair = NettingPair {
+        agent_a: a,
+        agent_b: b,
+        token: token.clone(),
+        net_position: 0,
+        pending_obligations: 0,
+        last_checkpoint: 0,
+    };
+    set_netting_pair(env, &pair);
+    Ok(pair)
+}
+
+/// Removes a pair; only allowed when no net obligation is outstanding.
+pub fn remove(env: &Env, x: &Address, y: &Address) -> Result<(), ContractError> {
+    let pair = load(env, x, y)?;
+    if pair.net_position != 0 {
+        return Err(ContractError::NettingPositionOpen);
+    }
+    remove_netting_pair(env, &pair.agent_a, &pair.agent_b);
+    Ok(())
+}
+
+/// Records that `debtor` owes `creditor` `amount`, updating the net position.
+pub fn record_obligation(
+    env: &Env,
+    debtor: &Address,
+    creditor: &Address,
+    amount: i128,
+) -> Result<NettingPair, ContractError> {
+    let mut pair = load(env, debtor, creditor)?;
+    let (_, _, direction) = normalize_pair(debtor, creditor);
+    let delta = amount.checked_mul(direction).ok_or(ContractError::Overflow)?;
+    pair.net_position = pair
+        .net_position
+        .checked_add(delta)
+        .ok_or(ContractError::Overflow)?;
+    pair.pending_obligations = pair.pending_obligations.saturating_add(1);
+    set_netting_pair(env, &pair);
+    Ok(pair)
+}
+
+/// Settles the pair's net position with a single transfer from the net debtor.
+///
+/// The debtor's authorization is required by the token transfer itself.
+pub fn checkpoint(env: &Env, x: &Address, y: &Address) -> Result<NettingCheckpoint, ContractError> {
+    let mut pair = load(env, x, y)?;
+    let (debtor, creditor, amount) = if pair.net_position >= 0 {
+        (pair.agent_a.clone(), pair.agent_b.clone(), pair.net_position)
+    } else {
+        (pair.agent_b.clone(), pair.agent_a.clone(), -pair.net_position)
+    };
+
+    if amount > 0 {
+        debtor.require_auth();
+        token_transfer::transfer(
+            &token::Client::new(env, &pair.token),
+            &debtor,
+            &creditor,
+            amount,
+        )?;
+    }
+
+    let result = NettingCheckpoint {
+        debtor,
+        creditor,
+        amount,
+        obligations: pair.pending_obligations,
+    };
+    pair.net_position = 0;
+    pair.pending_obligations = 0;
+    pair.last_checkpoint = env.ledger().timestamp();
+    set_netting_pair(env, &pair);
+    Ok(result)
+}
@@ -0,0 +1,112 @@
+//! Observer registry for on-chain lifecycle notifications.
+//!
+//! The admin can register observer contracts (for example an accounting
+//! contract) that are invoked whenever a remittance is created, completed, or
+//! disputed. Observers receive a compact [`RemittanceNotification`] through
+//! their `on_remittance` entry point instead of having to poll events.
+//!
+//! Observer calls are failure-isolated: each call goes through
+//! `try_invoke_contract`, so a reverting or missing observer never aborts the
+//! remittance operation that triggered it; `observer/failed` and
+//! `extcall/failed` events are emitted instead. Cost is bounded by capping the registry at
+//! `MAX_OBSERVERS` entries, since Soroban does not support per-call budgets.
+
+use soroban_sdk::{contracttype, Address, Env, IntoVal, InvokeError, Symbol, Val, Vec};
+
+use crate::config::MAX_OBSERVERS;
+use crate::events::{emit_external_call_failed, emit_observer_failed};
+use crate::storage::{get_observers, set_observers};
+use crate::{ContractError, Remittance};
+
+/// Entry point invoked on every registered observer contract.
+pub const OBSERVER_ENTRY_POINT: &str = "on_remittance";
+
+/// Lifecycle event an observer is being notified about.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ObserverEvent {
+    Created,
+    Completed,
+    Disputed,
+}
+
+/// Compact notification payload passed to observer contracts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittanceNotification {
+    pub event: ObserverEvent,
+    pub remittance_id: u64,
+    pub sender: Address,
+    pub agent: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub timestamp: u64,
+}
+
+/// Adds an observer to the registry.
+///
+/// # Errors
+///
+/// * `ContractError::ObserverAlreadyRegistered` - Observer is already registered
+/// * `ContractError::TooManyObservers` - Registry is at `MAX_OBSERVERS`
+pub fn add_observer(env: &Env, observer: &Address) -> Result<(), ContractError> {
+    let mut observers = get_observers(env);
+    if observers.contains(observer) {
+        return Err(ContractError::ObserverAlreadyRegistered);
+    }
+    if observers.len() >= MAX_OBSERVERS {
+        return Err(ContractError::TooManyObservers);
+    }
+    observers.push_back(observer.clone());
+    set_observers(env, &observers);
+    Ok(())
+}
+
+/// Removes an observer from the registry.
+///
+/// # Errors
+///
+/// * `ContractError::ObserverNotFound` - Observer is not registered
+pub fn remove_observer(env: &Env, observer: &Address) -> Result<(), ContractError> {
+    let mut observers = get_observers(env);
+    let pos = observers
+        .first_index_of(observer)
+        .ok_or(ContractError::ObserverNotFound)?;
+    observers.remove(pos);
+    set_observers(env, &observers);
+    Ok(())
+}
+
+/// Notifies every registered observer about a lifecycle event.
+///
+/// Failures are isolated per observer and reported via `emit_observer_failed`.
+pub fn notify_observers(env: &Env, event: ObserverEvent, remittance: &Remittance) {
+    let observers = get_observers(env);
+    if observers.is_empty() {
+        return;
+    }
+
+    let notification = RemittanceNotification {
+        event,
+        remittance_id: remittance.id,
+        sender: remittance.sender.clone(),
+        agent: remittance.agent.clone(),
+        token: remittance.token.clone(),
+        amount: remittance.amount,
+        fee: remittance.fee,
+        timestamp: env.ledger().timestamp(),
+    };
+    let func = Symbol::new(env, OBSERVER_ENTRY_POINT);
+
+    for observer in observers.iter() {
+        let args: Vec<Val> = (notification.clone(),).into_val(env);
+        let code = match env.try_invoke_contract::<Val, InvokeError>(&observer, &func, args) {
+            Ok(Ok(_)) => continue,
+            Err(Ok(InvokeError::Contract(code))) => code,
+            _ => 0,
+        };
+        emit_external_call_failed(env, observer.clone(), func.clone(), remittance.id, code);
+        emit_observer_failed(env, observer, remittance.id, event);
+    }
+}
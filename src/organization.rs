@@ -0,0 +1,289 @@
+//! Organization accounts for enterprise senders.
+//!
+//! An organization pools funds in a shared internal balance controlled by its
+//! owner. The owner adds member addresses (employees) with individual rolling
+//! 24-hour spending limits; members can then initiate remittances that are
+//! funded from the shared balance instead of their own wallets.
+//!
+//! Organization remittances are recorded with the owner as `sender`, so
+//! cancellations and expiry refunds always return to the owner, who keeps
+//! full control. The initiating member is tracked separately and reported in
+//! events and queries.
+//!
+//! A member remittance that would exceed the member's limit is not rejected;
+//! it is held as an [`OrgRemittanceRequest`] in `PendingApproval` state and no
+//! funds leave the shared balance until the owner approves it. Rejection
+//! simply closes the request.
+
+use soroban_sdk::{contracttype, token, Address, Env, Vec};
+
+use crate::config::{DAILY_LIMIT_WINDOW_SECONDS, MAX_ORG_PENDING_REQUESTS};
+use crate::storage::{
+    get_org_counter, get_org_member, get_org_pending_requests, get_org_request,
+    get_org_request_counter, get_organization, remove_org_member, set_org_counter,
+    set_org_member, set_org_pending_requests, set_org_request, set_org_request_counter,
+    set_organization,
+};
+use crate::token_transfer;
+use crate::{ContractError, MaybeExpiry};
+
+/// Shared-balance account owned by a business sender.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Organization {
+    pub id: u64,
+    /// Address that controls membership, limits, and withdrawals
+    pub owner: Address,
+    /// Token held in the shared balance and used for member remittances
+    pub token: Address,
+    /// Funds available for member remittances
+    pub balance: i128,
+    /// Current member addresses
+    pub members: Vec<Address>,
+}
+
+/// Membership record with the member's rolling spending usage.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgMember {
+    pub org_id: u64,
+    /// Maximum total amount the member may spend per 24-hour window
+    pub spending_limit: i128,
+    /// Ledger timestamp at which the current usage window started
+    pub window_start: u64,
+    /// Amount spent within the current window
+    pub spent_in_window: i128,
+}
+
+/// Lifecycle state of an over-limit member remittance request.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OrgRequestStatus {
+    PendingApproval,
+    Approved,
+    Rejected,
+}
+
+/// Member remittance held for owner approval because it exceeds the member's limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgRemittanceRequest {
+    pub id: u64,
+    pub org_id: u64,
+    /// Member who initiated the remittance
+    pub member: Address,
+    pub agent: Address,
+    pub amount: i128,
+    pub expiry: MaybeExpiry,
+    pub status: OrgRequestStatus,
+    /// Remittance created on approval
+    pub remittance_id: Option<u64>,
+    pub created_at: u64,
+}
+
+/// Outcome of a member remittance: created immediately or held for approval.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OrgRemittanceOutcome {
+    /// Remittance was created; carries the remittance ID
+    Created(u64),
+    /// Remittance exceeds the member's limit; carries the approval request ID
+    PendingApproval(u64),
+}
+
+/// Loads an organization and requires its owner's authorization.
+pub fn require_org_owner(env: &Env, org_id: u64) -> Result<Organization, ContractError> {
+    let org = get_organization(env, org_id).ok_or(ContractError::OrganizationNotFound)?;
+    org.owner.require_auth();
+    Ok(org)
+}
+
+/// Creates a new organization with an empty balance and no members.
+pub fn create(env: &Env, owner: &Address, token: &Address) -> Result<u64, ContractError> {
+    let id = get_org_counter(env)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    set_organization(
+        env,
+        &Organization {
+            id,
+            owner: owner.clone(),
+            token: token.clone(),
+            balance: 0,
+            members: Vec::new(env),
+        },
+    );
+    set_org_counter(env, id);
+    Ok(id)
+}
+
+/// Moves `amount` from `from` into the organization's shared balance.
+pub fn deposit(env: &Env, org_id: u64, from: &Address, amount: i128) -> Result<i128, ContractError> {
+    let mut org = get_organization(env, org_id).ok_or(ContractError::OrganizationNotFound)?;
+    token_transfer::transfer(
+        &token::Client::new(env, &org.token),
+        from,
+        &env.current_contract_address(),
+        amount,
+    )?;
+    org.balance = org.balance.checked_add(amount).ok_or(ContractError::Overflow)?;
+    set_organization(env, &org);
+    Ok(org.balance)
+}
+
+/// Debits `amount` from the shared balance, failing if funds are insufficient.
+pub fn debit(env: &Env, org: &mut Organization, amount: i128) -> Result<(), ContractError> {
+    if amount > org.balance {
+        return Err(ContractError::InsufficientOrgBalance);
+    }
+    org.balance -= amount;
+    set_organization(env, org);
+    Ok(())
+}
+
+/// Adds a member or updates an existing member's spending limit.
+pub fn upsert_member(
+    env: &Env,
+    org: &mut Organization,
+    member: &Address,
+    spending_limit: i128,
+) {
+    let record = match get_org_member(env, org.id, member) {
+        Some(existing) => OrgMember { spending_limit, ..existing },
+        None => {
+            org.members.push_back(member.clone());
+            set_organization(env, org);
+            OrgMember {
+                org_id: org.id,
+                spending_limit,
+                window_start: env.ledger().timestamp(),
+                spent_in_window: 0,
+            }
+        }
+    };
+    set_org_member(env, org.id, member, &record);
+}
+
+/// Removes a member from the organization.
+pub fn remove_member(env: &Env, org: &mut Organization, member: &Address) -> Result<(), ContractError> {
+    let pos = org
+        .members
+        .first_index_of(member)
+        .ok_or(ContractError::NotOrgMember)?;
+    org.members.remove(pos);
+    set_organization(env, org);
+    remove_org_member(env, org.id, member);
+    Ok(())
+}
+
+/// Returns the member record with its usage window rolled forward if it has elapsed.
+pub fn current_member(env: &Env, org_id: u64, member: &Address) -> Result<OrgMember, ContractError> {
+    let mut record = get_org_member(env, org_id, member).ok_or(ContractError::NotOrgMember)?;
+    let now = env.ledger().timestamp();
+    if now >= record.window_start.saturating_add(DAILY_LIMIT_WINDOW_SECONDS) {
+        record.window_start = now;
+        record.spent_in_window = 0;
+    }
+    Ok(record)
+}
+
+/// Returns whether `amount` fits within the member's remaining limit for the window.
+pub fn within_limit(record: &OrgMember, amount: i128) -> bool {
+    record
+        .spent_in_window
+        .checked_add(amount)
+        .is_some_and(|spent| spent <= record.spending_limit)
+}
+
+/// Charges `amount` against a member's rolling limit.
+pub fn charge_member(
+    env: &Env,
+    member: &Address,
+    record: &mut OrgMember,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let spent = record
+        .spent_in_window
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    if spent > record.spending_limit {
+        return Err(ContractError::OrgMemberLimitExceeded);
+    }
+    record.spent_in_window = spent;
+    set_org_member(env, record.org_id, member, record);
+    Ok(())
+}
+
+/// Records an over-limit remittance as a request pending owner approval.
+///
+/// Fails with `OrgPendingRequestsFull` once `MAX_ORG_PENDING_REQUESTS` requests
+/// are waiting on the owner.
+pub fn open_request(
+    env: &Env,
+    org_id: u64,
+    member: &Address,
+    agent: &Address,
+    amount: i128,
+    expiry: MaybeExpiry,
+) -> Result<u64, ContractError> {
+    let mut pending = get_org_pending_requests(env, org_id);
+    if pending.len() >= MAX_ORG_PENDING_REQUESTS {
+        return Err(ContractError::OrgPendingRequestsFull);
+    }
+
+    let id = get_org_request_counter(env)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    set_org_request(
+        env,
+        &OrgRemittanceRequest {
+            id,
+            org_id,
+            member: member.clone(),
+            agent: agent.clone(),
+            amount,
+            expiry,
+            status: OrgRequestStatus::PendingApproval,
+            remittance_id: None,
+            created_at: env.ledger().timestamp(),
+        },
+    );
+    set_org_request_counter(env, id);
+
+    pending.push_back(id);
+    set_org_pending_requests(env, org_id, &pending);
+    Ok(id)
+}
+
+/// Loads a request belonging to `org_id` that is still pending approval.
+pub fn pending_request(
+    env: &Env,
+    org_id: u64,
+    request_id: u64,
+) -> Result<OrgRemittanceRequest, ContractError> {
+    let request = get_org_request(env, request_id)
+        .filter(|r| r.org_id == org_id)
+        .ok_or(ContractError::OrgRequestNotFound)?;
+    if request.status != OrgRequestStatus::PendingApproval {
+        return Err(ContractError::OrgRequestNotPending);
+    }
+    Ok(request)
+}
+
+/// Moves a pending request to its final status and drops it from the pending list.
+pub fn close_request(
+    env: &Env,
+    request: &mut OrgRemittanceRequest,
+    status: OrgRequestStatus,
+    remittance_id: Option<u64>,
+) {
+    request.status = status;
+    request.remittance_id = remittance_id;
+    set_org_request(env, request);
+
+    let mut pending = get_org_pending_requests(env, request.org_id);
+    if let Some(pos) = pending.first_index_of(request.id) {
+        pending.remove(pos);
+        set_org_pending_requests(env, request.org_id, &pending);
+    }
+}
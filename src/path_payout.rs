@@ -0,0 +1,219 @@
+//! Payouts converted to another asset through a path payment.
+//!
+//! An agent that settles in a local-currency anchor asset rather than the
+//! escrowed token can take payouts in that asset in two ways:
+//!
+//! - per remittance, with `set_path_payout`, naming the destination token,
+//!   the least it will accept, and an optional path of intermediate assets;
+//! - by default, with `set_payout_preference`, naming a preferred token and a
+//!   slippage tolerance. The minimum is then derived at settlement from the
+//!   adapter's quote, and a failed conversion falls back to paying the
+//!   escrowed token.
+//!
+//! All assets are classic Stellar assets addressed through their Stellar
+//! Asset Contracts. Soroban contracts cannot submit classic path payment
+//! operations, so the conversion goes through an admin-configured adapter
+//! contract exposing [`PATH_PAYMENT_ENTRY_POINT`] and [`QUOTE_ENTRY_POINT`]:
+//!
+//! ```text
+//! path_payment_strict_send(from, send_asset, send_amount, dest_asset,
+//!                          dest_min, destination, path)
+//! quote_strict_send(send_asset, send_amount, dest_asset) -> i128
+//! ```
+//!
+//! At settlement this contract approves the adapter for exactly the payout and
+//! calls it; the adapter pulls the payout with `transfer_from` and delivers at
+//! least `dest_min` of `dest_asset` to the agent, or fails. Both bounds are
+//! checked against actual balances afterwards rather than trusting the
+//! adapter's return value, and the allowance is always cleared.
+//!
+//! If the adapter fails on a per-remittance path payout, the payout is held
+//! for the agent in the escrowed token in best-effort batches and the
+//! settlement fails otherwise. An adapter that returns without honouring the
+//! bounds always fails the settlement, so its effects are rolled back.
+
+use soroban_sdk::{contracttype, token, Address, Env, IntoVal, InvokeError, Symbol, Val, Vec};
+
+use crate::config::FEE_DIVISOR;
+use crate::events::{emit_external_call_failed, emit_path_payout_fallback, emit_path_payout_paid};
+use crate::storage::get_path_payment_adapter;
+use crate::{token_transfer, ContractError, Remittance};
+
+/// Entry point invoked on the path payment adapter.
+pub const PATH_PAYMENT_ENTRY_POINT: &str = "path_payment_strict_send";
+
+/// Entry point the adapter quotes a conversion through.
+pub const QUOTE_ENTRY_POINT: &str = "quote_strict_send";
+
+/// How the agent takes a remittance's payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PathPayout {
+    /// Asset the agent receives
+    pub dest_token: Address,
+    /// Least amount of `dest_token` the agent accepts for the whole payout
+    pub min_receive: i128,
+    /// Intermediate assets, in order; empty lets the adapter pick the path
+    pub path: Vec<Address>,
+}
+
+/// Token an agent prefers its payouts in.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutPreference {
+    pub token: Address,
+    /// Largest shortfall from the adapter's quote the agent accepts, in basis points
+    pub max_slippage_bps: u32,
+}
+
+/// Pays `amount` of the remittance's token to its agent as `path_payout.dest_token`.
+///
+/// With `best_effort` set, a failed adapter call holds `amount` for the
+/// agent in the escrowed token and returns `Ok(Some(error))`.
+///
+/// # Errors
+///
+/// * `ContractError::PathPayoutUnavailable` - No adapter is configured
+/// * `ContractError::PathPaymentFailed` - The adapter failed, or moved the
+///   wrong amount or delivered less than `min_receive`
+pub fn pay(
+    env: &Env,
+    client: &token::Client,
+    remittance: &Remittance,
+    path_payout: PathPayout,
+    amount: i128,
+    best_effort: bool,
+) -> Result<Option<ContractError>, ContractError> {
+    let adapter = get_path_payment_adapter(env).ok_or(ContractError::PathPayoutUnavailable)?;
+    if swap(env, &adapter, client, remittance, amount, path_payout)? {
+        return Ok(None);
+    }
+    if !best_effort {
+        return Err(ContractError::PathPaymentFailed);
+    }
+    let error = ContractError::PathPaymentFailed;
+    token_transfer::hold(env, client, remittance.id, &remittance.agent, amount, error)?;
+    Ok(Some(error))
+}
+
+/// Pays `amount` of the remittance's token to its agent in the agent's
+/// preferred token, or in the escrowed token if the conversion cannot be
+/// quoted or does not go through within the agent's slippage tolerance.
+///
+/// # Errors
+///
+/// * `ContractError::PathPaymentFailed` - The adapter moved the wrong amount
+///   or delivered less than the minimum it was given
+/// * Any error returned by `token_transfer::pay` for the fallback payment
+pub fn pay_preferred(
+    env: &Env,
+    client: &token::Client,
+    remittance: &Remittance,
+    preference: PayoutPreference,
+    amount: i128,
+    best_effort: bool,
+) -> Result<Option<ContractError>, ContractError> {
+    if let Some(adapter) = get_path_payment_adapter(env) {
+        if let Some(quoted) = quote(env, &adapter, client, remittance.id, amount, &preference.token) {
+            let min_receive = quoted
+                .checked_mul(FEE_DIVISOR - preference.max_slippage_bps as i128)
+                .ok_or(ContractError::Overflow)?
+                / FEE_DIVISOR;
+            let path_payout = PathPayout {
+                dest_token: preference.token.clone(),
+                min_receive,
+                path: Vec::new(env),
+            };
+            if min_receive > 0 && swap(env, &adapter, client, remittance, amount, path_payout)? {
+                return Ok(None);
+            }
+        }
+    }
+    emit_path_payout_fallback(env, remittance.id, remittance.agent.clone(), preference.token);
+    token_transfer::pay(env, client, remittance.id, &remittance.agent, amount, best_effort)
+}
+
+/// Asks the adapter how much of `dest_token` `amount` converts into.
+fn quote(
+    env: &Env,
+    adapter: &Address,
+    client: &token::Client,
+    remittance_id: u64,
+    amount: i128,
+    dest_token: &Address,
+) -> Option<i128> {
+    let func = Symbol::new(env, QUOTE_ENTRY_POINT);
+    let args: Vec<Val> = (client.address.clone(), amount, dest_token.clone()).into_val(env);
+    match env.try_invoke_contract::<i128, InvokeError>(adapter, &func, args) {
+        Ok(Ok(quoted)) => Some(quoted),
+        Err(Ok(InvokeError::Contract(code))) => {
+            emit_external_call_failed(env, adapter.clone(), func, remittance_id, code);
+            None
+        }
+        _ => {
+            emit_external_call_failed(env, adapter.clone(), func, remittance_id, 0);
+            None
+        }
+    }
+}
+
+/// Converts `amount` through the adapter. Returns false, with the adapter's
+/// effects rolled back, if the adapter call failed.
+///
+/// # Errors
+///
+/// * `ContractError::PathPaymentFailed` - The adapter returned but moved the
+///   wrong amount or delivered less than `min_receive`
+fn swap(
+    env: &Env,
+    adapter: &Address,
+    client: &token::Client,
+    remittance: &Remittance,
+    amount: i128,
+    path_payout: PathPayout,
+) -> Result<bool, ContractError> {
+    let this = env.current_contract_address();
+    let dest = token::Client::new(env, &path_payout.dest_token);
+    let sent_before = client.balance(&this);
+    let received_before = dest.balance(&remittance.agent);
+
+    client.approve(&this, adapter, &amount, &env.ledger().sequence());
+    let func = Symbol::new(env, PATH_PAYMENT_ENTRY_POINT);
+    let args: Vec<Val> = (
+        this.clone(),
+        client.address.clone(),
+        amount,
+        path_payout.dest_token.clone(),
+        path_payout.min_receive,
+        remittance.agent.clone(),
+        path_payout.path,
+    )
+        .into_val(env);
+    let result = env.try_invoke_contract::<Val, InvokeError>(adapter, &func, args);
+    client.approve(&this, adapter, &0, &env.ledger().sequence());
+
+    let code = match result {
+        Ok(Ok(_)) => None,
+        Err(Ok(InvokeError::Contract(code))) => Some(code),
+        _ => Some(0),
+    };
+    if let Some(code) = code {
+        emit_external_call_failed(env, adapter.clone(), func, remittance.id, code);
+        return Ok(false);
+    }
+
+    let sent = sent_before - client.balance(&this);
+    let received = dest.balance(&remittance.agent) - received_before;
+    if sent != amount || received < path_payout.min_receive {
+        return Err(ContractError::PathPaymentFailed);
+    }
+    emit_path_payout_paid(
+        env,
+        remittance.id,
+        remittance.agent.clone(),
+        sent,
+        path_payout.dest_token,
+        received,
+    );
+    Ok(true)
+}
@@ -0,0 +1,225 @@
+//! Settlement performance metrics for the platform and individual agents.
+//!
+//! Counters are maintained incrementally as remittances are created,
+//! completed, expired, or disputed, so [`PerformanceStats`] can be derived in
+//! constant time. Time-to-settlement (`completed_at - created_at`) feeds a
+//! running total for the average and a fixed-bucket histogram
+//! ([`SETTLEMENT_LATENCY_BUCKETS`]) for the p95, which is reported as the
+//! upper bound of the bucket containing the 95th percentile.
+//!
+//! Every event is recorded twice: once platform-wide and once against the
+//! remittance's current agent. It is also forwarded to the per-party
+//! credit-signal summaries in [`crate::credit_signals`] and the daily volume
+//! history in [`crate::volume_history`].
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::config::SETTLEMENT_LATENCY_BUCKETS;
+use crate::storage::{get_performance_counters, set_performance_counters};
+use crate::Remittance;
+
+/// Raw counters backing the performance metrics.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PerformanceCounters {
+    pub created: u64,
+    pub completed: u64,
+    pub expired: u64,
+    pub disputed: u64,
+    /// Sum of time-to-settlement over all completed remittances
+    pub total_settlement_secs: u64,
+    /// Slowest observed time-to-settlement
+    pub max_settlement_secs: u64,
+    /// Completed remittances per latency bucket, plus a final overflow bucket
+    pub latency_histogram: Vec<u64>,
+}
+
+impl PerformanceCounters {
+    pub fn new(env: &Env) -> Self {
+        let mut latency_histogram = Vec::new(env);
+        for _ in 0..=SETTLEMENT_LATENCY_BUCKETS.len() {
+            latency_histogram.push_back(0);
+        }
+        PerformanceCounters {
+            created: 0,
+            completed: 0,
+            expired: 0,
+            disputed: 0,
+            total_settlement_secs: 0,
+            max_settlement_secs: 0,
+            latency_histogram,
+        }
+    }
+}
+
+/// Settlement performance summary returned by `get_performance_stats`.
+///
+/// Rates are expressed in basis points of created remittances.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PerformanceStats {
+    pub created: u64,
+    pub completed: u64,
+    pub expired: u64,
+    pub disputed: u64,
+    pub avg_settlement_secs: u64,
+    pub p95_settlement_secs: u64,
+    pub completion_rate_bps: u32,
+    pub expiry_rate_bps: u32,
+    pub dispute_rate_bps: u32,
+}
+
+/// Lifecycle milestone counted by the performance metrics.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PerformanceEvent {
+    Created,
+    Completed,
+    Expired,
+    Disputed,
+}
+
+/// Records a lifecycle milestone platform-wide and for the remittance's agent.
+pub fn record(env: &Env, event: PerformanceEvent, remittance: &Remittance) {
+    let settlement_secs = remittance.settlement_latency().unwrap_or(0);
+
+    for scope in [None, Some(remittance.agent.clone())] {
+        let mut counters = get_performance_counters(env, scope.as_ref());
+        apply(&mut counters, event, settlement_secs);
+        set_performance_counters(env, scope.as_ref(), &counters);
+    }
+    crate::credit_signals::record(env, event, remittance);
+    crate::volume_history::record(env, event, remittance);
+}
+
+fn apply(counters: &mut PerformanceCounters, event: PerformanceEvent, settlement_secs: u64) {
+    match event {
+        PerformanceEvent::Created => counters.created = counters.created.saturating_add(1),
+        PerformanceEvent::Expired => counters.expired = counters.expired.saturating_add(1),
+        PerformanceEvent::Disputed => counters.disputed = counters.disputed.saturating_add(1),
+        PerformanceEvent::Completed => {
+            counters.completed = counters.completed.saturating_add(1);
+            counters.total_settlement_secs =
+                counters.total_settlement_secs.saturating_add(settlement_secs);
+            counters.max_settlement_secs = counters.max_settlement_secs.max(settlement_secs);
+
+            let bucket = SETTLEMENT_LATENCY_BUCKETS
+                .iter()
+                .position(|bound| settlement_secs <= *bound)
+                .unwrap_or(SETTLEMENT_LATENCY_BUCKETS.len()) as u32;
+            let count = counters.latency_histogram.get(bucket).unwrap_or(0);
+            counters.latency_histogram.set(bucket, count.saturating_add(1));
+        }
+    }
+}
+
+/// Derives the performance summary from raw counters.
+pub fn summarize(counters: &PerformanceCounters) -> PerformanceStats {
+    let avg_settlement_secs = counters
+        .total_settlement_secs
+        .checked_div(counters.completed)
+        .unwrap_or(0);
+
+    PerformanceStats {
+        created: counters.created,
+        completed: counters.completed,
+        expired: counters.expired,
+        disputed: counters.disputed,
+        avg_settlement_secs,
+        p95_settlement_secs: p95(counters),
+        completion_rate_bps: rate_bps(counters.completed, counters.created),
+        expiry_rate_bps: rate_bps(counters.expired, counters.created),
+        dispute_rate_bps: rate_bps(counters.disputed, counters.created),
+    }
+}
+
+/// Returns the upper bound of the histogram bucket holding the 95th percentile.
+///
+/// The overflow bucket reports the slowest observed settlement instead.
+fn p95(counters: &PerformanceCounters) -> u64 {
+    if counters.completed == 0 {
+        return 0;
+    }
+    // ceil(completed * 0.95)
+    let target = (counters.completed.saturating_mul(95)).div_ceil(100);
+    let mut seen = 0u64;
+    for (i, count) in counters.latency_histogram.iter().enumerate() {
+        seen = seen.saturating_add(count);
+        if seen >= target {
+            return SETTLEMENT_LATENCY_BUCKETS
+                .get(i)
+                .copied()
+                .unwrap_or(counters.max_settlement_secs)
+                .min(counters.max_settlement_secs);
+        }
+    }
+    counters.max_settlement_secs
+}
+
+/// Ratio in basis points, capped at 10000 (counters can predate `created` tracking).
+fn rate_bps(count: u64, total: u64) -> u32 {
+    if total == 0 {
+        return 0;
+    }
+    (count.saturating_mul(10_000) / total).min(10_000) as u32
+}
+
+/// Returns the current stats for the platform (`None`) or a single agent.
+pub fn stats(env: &Env, agent: Option<&Address>) -> PerformanceStats {
+    summarize(&get_performance_counters(env, agent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_empty() {
+        let env = Env::default();
+        let stats = summarize(&PerformanceCounters::new(&env));
+        assert_eq!(stats.avg_settlement_secs, 0);
+        assert_eq!(stats.p95_settlement_secs, 0);
+        assert_eq!(stats.completion_rate_bps, 0);
+    }
+
+    #[test]
+    fn test_rates_and_average() {
+        let env = Env::default();
+        let mut counters = PerformanceCounters::new(&env);
+        for _ in 0..4 {
+            apply(&mut counters, PerformanceEvent::Created, 0);
+        }
+        apply(&mut counters, PerformanceEvent::Completed, 100);
+        apply(&mut counters, PerformanceEvent::Completed, 300);
+        apply(&mut counters, PerformanceEvent::Expired, 0);
+        apply(&mut counters, PerformanceEvent::Disputed, 0);
+
+        let stats = summarize(&counters);
+        assert_eq!(stats.avg_settlement_secs, 200);
+        assert_eq!(stats.completion_rate_bps, 5_000);
+        assert_eq!(stats.expiry_rate_bps, 2_500);
+        assert_eq!(stats.dispute_rate_bps, 2_500);
+    }
+
+    #[test]
+    fn test_p95_uses_bucket_bounds() {
+        let env = Env::default();
+        let mut counters = PerformanceCounters::new(&env);
+        for _ in 0..19 {
+            apply(&mut counters, PerformanceEvent::Completed, 60);
+        }
+        apply(&mut counters, PerformanceEvent::Completed, 5_000);
+        // 19 of 20 (95%) settled within the first bucket
+        assert_eq!(summarize(&counters).p95_settlement_secs, 300);
+
+        apply(&mut counters, PerformanceEvent::Completed, 5_000);
+        assert_eq!(summarize(&counters).p95_settlement_secs, 5_000);
+    }
+
+    #[test]
+    fn test_p95_overflow_bucket_reports_max() {
+        let env = Env::default();
+        let mut counters = PerformanceCounters::new(&env);
+        apply(&mut counters, PerformanceEvent::Completed, 1_000_000);
+        assert_eq!(summarize(&counters).p95_settlement_secs, 1_000_000);
+    }
+}
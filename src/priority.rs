@@ -0,0 +1,144 @@
+//! Sender-funded priority boosts.
+//!
+//! Every remittance has a priority level, starting at 0, and a service-level
+//! deadline of [`BASE_SLA_SECONDS`] after creation. While it is `Pending`,
+//! its sender can pay an expedite fee of [`EXPEDITE_FEE_BPS`] of the amount to
+//! raise the level by one, up to [`MAX_PRIORITY_LEVEL`]. Each level halves
+//! the SLA, still counted from creation.
+//!
+//! Expedite fees are held by the contract until the remittance ends. On
+//! completion the agent receives [`EXPEDITE_AGENT_SHARE_BPS`] of them and the
+//! rest joins the platform fees; if the remittance is cancelled, fails, or
+//! expires instead, they are refunded to the sender.
+
+use soroban_sdk::{contracttype, token, Env};
+
+use crate::config::{
+    BASE_SLA_SECONDS, EXPEDITE_AGENT_SHARE_BPS, EXPEDITE_FEE_BPS, FEE_DIVISOR, MAX_PRIORITY_LEVEL,
+};
+use crate::storage::{get_priority_boost, set_priority_boost};
+use crate::{add_token_fee, token_transfer, ContractError, Remittance, RemittanceStatus};
+
+/// Priority bought for a remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriorityBoost {
+    pub level: u32,
+    /// Expedite fees paid and not yet settled or refunded
+    pub fees_held: i128,
+    pub sla_deadline: u64,
+}
+
+/// SLA deadline of a remittance created at `created_at` with priority `level`.
+pub fn sla_deadline_for(created_at: u64, level: u32) -> u64 {
+    created_at.saturating_add(BASE_SLA_SECONDS >> level)
+}
+
+/// Current SLA deadline of a remittance.
+pub fn sla_deadline(env: &Env, remittance: &Remittance) -> u64 {
+    match get_priority_boost(env, remittance.id) {
+        Some(boost) => boost.sla_deadline,
+        None => sla_deadline_for(remittance.created_at, 0),
+    }
+}
+
+/// Whether a remittance's SLA deadline has passed.
+pub fn sla_breached(env: &Env, remittance: &Remittance) -> bool {
+    env.ledger().timestamp() > sla_deadline(env, remittance)
+}
+
+/// Collects an expedite fee from the sender and raises the priority by one level.
+///
+/// # Errors
+///
+/// * `ContractError::InvalidStatus` - Remittance is not Pending
+/// * `ContractError::PriorityAtMaximum` - Already at `MAX_PRIORITY_LEVEL`
+/// * `ContractError::InvalidAmount` - The fee rounds down to zero
+pub fn boost(env: &Env, remittance: &Remittance) -> Result<(PriorityBoost, i128), ContractError> {
+    if remittance.status != RemittanceStatus::Pending {
+        return Err(ContractError::InvalidStatus);
+    }
+    let mut boost = get_priority_boost(env, remittance.id).unwrap_or(PriorityBoost {
+        level: 0,
+        fees_held: 0,
+        sla_deadline: sla_deadline_for(remittance.created_at, 0),
+    });
+    if boost.level >= MAX_PRIORITY_LEVEL {
+        return Err(ContractError::PriorityAtMaximum);
+    }
+
+    let fee = remittance
+        .amount
+        .checked_mul(EXPEDITE_FEE_BPS as i128)
+        .ok_or(ContractError::Overflow)?
+        / FEE_DIVISOR;
+    if fee <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    token_transfer::transfer(
+        &token::Client::new(env, &remittance.token),
+        &remittance.sender,
+        &env.current_contract_address(),
+        fee,
+    )?;
+
+    boost.level += 1;
+    boost.fees_held = boost.fees_held.checked_add(fee).ok_or(ContractError::Overflow)?;
+    boost.sla_deadline = sla_deadline_for(remittance.created_at, boost.level);
+    set_priority_boost(env, remittance.id, &boost);
+    Ok((boost, fee))
+}
+
+/// Shares the held expedite fees of a completed remittance between its agent
+/// and the platform. Returns the agent's share.
+///
+/// With `best_effort` set, a failed payment to the agent is held as an
+/// undelivered balance instead of failing.
+pub fn settle(
+    env: &Env,
+    client: &token::Client,
+    remittance: &Remittance,
+    best_effort: bool,
+) -> Result<i128, ContractError> {
+    let Some(mut boost) = get_priority_boost(env, remittance.id) else {
+        return Ok(0);
+    };
+    if boost.fees_held == 0 {
+        return Ok(0);
+    }
+
+    let agent_share = boost
+        .fees_held
+        .checked_mul(EXPEDITE_AGENT_SHARE_BPS as i128)
+        .ok_or(ContractError::Overflow)?
+        / FEE_DIVISOR;
+    add_token_fee(env, &remittance.token, boost.fees_held - agent_share)?;
+    boost.fees_held = 0;
+    set_priority_boost(env, remittance.id, &boost);
+    if agent_share > 0 {
+        token_transfer::pay(env, client, remittance.id, &remittance.agent, agent_share, best_effort)?;
+    }
+    Ok(agent_share)
+}
+
+/// Refunds the held expedite fees of a remittance that did not complete.
+/// Returns the amount refunded.
+pub fn refund(env: &Env, remittance: &Remittance) -> Result<i128, ContractError> {
+    let Some(mut boost) = get_priority_boost(env, remittance.id) else {
+        return Ok(0);
+    };
+    let refund = boost.fees_held;
+    if refund == 0 {
+        return Ok(0);
+    }
+
+    boost.fees_held = 0;
+    set_priority_boost(env, remittance.id, &boost);
+    token_transfer::transfer(
+        &token::Client::new(env, &remittance.token),
+        &env.current_contract_address(),
+        &remittance.sender,
+        refund,
+    )?;
+    Ok(refund)
+}
@@ -0,0 +1,154 @@
+//! Firm quotes from receiving agents, in the style of SEP-38.
+//!
+//! A receiving agent posts a [`Quote`] for a corridor with `post_quote`: an
+//! exchange rate into the destination currency, a flat fee it deducts from
+//! the payout before conversion, and a deadline for acceptance. A sender
+//! accepts it with `create_remittance_with_quote`, which creates the
+//! remittance for the quoting agent and records an [`AcceptedQuote`] with the
+//! payout the agent will receive and the destination amount it owes the
+//! recipient under the quoted terms.
+//!
+//! Quotes are single-use. At settlement the contract recomputes the payout
+//! and refuses to settle if it differs from the accepted one, so neither side
+//! can be held to a quote whose basis changed after acceptance.
+
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::config::{MAX_QUOTE_TTL_SECONDS, QUOTE_RATE_SCALE};
+use crate::storage::{
+    get_accepted_quote, get_quote, get_quote_counter, set_accepted_quote, set_quote,
+    set_quote_counter,
+};
+use crate::{ContractError, Remittance, RemittanceCorridor};
+
+/// A firm quote posted by a receiving agent.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Quote {
+    pub id: u64,
+    pub agent: Address,
+    pub corridor: RemittanceCorridor,
+    /// Destination units per source unit, scaled by `QUOTE_RATE_SCALE`
+    pub rate: i128,
+    /// Flat fee in the source token deducted from the payout before conversion
+    pub fee: i128,
+    /// Last moment the quote can be accepted
+    pub expires_at: u64,
+    /// Remittance the quote was accepted for
+    pub remittance_id: Option<u64>,
+}
+
+/// Terms a remittance was created under.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcceptedQuote {
+    pub quote_id: u64,
+    pub rate: i128,
+    pub fee: i128,
+    /// Source-token payout the agent receives at settlement
+    pub payout_amount: i128,
+    /// Destination amount the agent delivers to the recipient
+    pub dest_amount: i128,
+}
+
+/// Posts a quote for `agent`, who must already be authenticated.
+///
+/// # Errors
+///
+/// * `ContractError::InvalidQuote` - Non-positive rate, negative fee, or an
+///   expiry not within `MAX_QUOTE_TTL_SECONDS` from now
+pub fn post(
+    env: &Env,
+    agent: Address,
+    corridor: RemittanceCorridor,
+    rate: i128,
+    fee: i128,
+    expires_at: u64,
+) -> Result<Quote, ContractError> {
+    let now = env.ledger().timestamp();
+    if rate <= 0 || fee < 0 || expires_at <= now || expires_at - now > MAX_QUOTE_TTL_SECONDS {
+        return Err(ContractError::InvalidQuote);
+    }
+
+    let quote = Quote {
+        id: get_quote_counter(env) + 1,
+        agent,
+        corridor,
+        rate,
+        fee,
+        expires_at,
+        remittance_id: None,
+    };
+    set_quote_counter(env, quote.id);
+    set_quote(env, &quote);
+    Ok(quote)
+}
+
+/// Returns a quote that can still be accepted.
+///
+/// # Errors
+///
+/// * `ContractError::QuoteNotFound` - Unknown quote ID
+/// * `ContractError::QuoteAlreadyAccepted` - The quote was already used
+/// * `ContractError::QuoteExpired` - The quote's deadline has passed
+pub fn open_quote(env: &Env, quote_id: u64) -> Result<Quote, ContractError> {
+    let quote = get_quote(env, quote_id).ok_or(ContractError::QuoteNotFound)?;
+    if quote.remittance_id.is_some() {
+        return Err(ContractError::QuoteAlreadyAccepted);
+    }
+    if env.ledger().timestamp() > quote.expires_at {
+        return Err(ContractError::QuoteExpired);
+    }
+    Ok(quote)
+}
+
+/// Binds an open quote to `remittance`, whose agent pays out
+/// `payout_amount` of the source token at settlement.
+///
+/// # Errors
+///
+/// * `ContractError::InvalidAmount` - The payout does not cover the quoted fee
+pub fn accept(
+    env: &Env,
+    mut quote: Quote,
+    remittance: &Remittance,
+    payout_amount: i128,
+) -> Result<AcceptedQuote, ContractError> {
+    let dest_amount = payout_amount
+        .checked_sub(quote.fee)
+        .filter(|net| *net > 0)
+        .ok_or(ContractError::InvalidAmount)?
+        .checked_mul(quote.rate)
+        .ok_or(ContractError::Overflow)?
+        / QUOTE_RATE_SCALE;
+
+    let accepted = AcceptedQuote {
+        quote_id: quote.id,
+        rate: quote.rate,
+        fee: quote.fee,
+        payout_amount,
+        dest_amount,
+    };
+    quote.remittance_id = Some(remittance.id);
+    set_quote(env, &quote);
+    set_accepted_quote(env, remittance.id, &accepted);
+    Ok(accepted)
+}
+
+/// Checks a settling remittance's payout against its accepted quote, if any.
+///
+/// # Errors
+///
+/// * `ContractError::QuoteTermsChanged` - The payout differs from the accepted one
+pub fn enforce(
+    env: &Env,
+    remittance_id: u64,
+    payout_amount: i128,
+) -> Result<Option<AcceptedQuote>, ContractError> {
+    match get_accepted_quote(env, remittance_id) {
+        Some(accepted) if accepted.payout_amount != payout_amount => {
+            Err(ContractError::QuoteTermsChanged)
+        }
+        accepted => Ok(accepted),
+    }
+}
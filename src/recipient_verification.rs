@@ -7,8 +7,17 @@
 use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, String};
 
 use crate::ContractError;
-use crate::events::{emit_recipient_hash_registered, emit_recipient_verified, emit_recipient_verification_failed};
-use crate::storage::{get_recipient_hash_record, set_recipient_hash as storage_set_recipient_hash};
+use crate::config::MAX_ENCRYPTED_MEMO_BYTES;
+use crate::events::{
+    emit_delivery_proof_recorded, emit_encrypted_memo_attached, emit_pickup_secret_registered, emit_pickup_secret_verified, emit_recipient_hash_registered,
+    emit_recipient_verification_failed, emit_recipient_verified,
+};
+use crate::storage::{
+    get_delivery_proof_threshold, get_encrypted_memo, get_pickup_secret_hash,
+    get_pickup_secret_threshold, get_recipient_hash_record, set_delivery_proof,
+    set_encrypted_memo, set_pickup_secret_hash,
+    set_recipient_hash as storage_set_recipient_hash,
+};
 
 // ============================================================================
 // Constants
@@ -364,3 +373,146 @@ pub fn migrate_recipient_hashes(
 
     Ok(migrated)
 }
+
+// ============================================================================
+// Recipient Pickup Secret
+// ============================================================================
+//
+// For cash pickup, the recipient generates a secret and shares only its
+// SHA-256 digest with the sender, who registers it on the remittance while it
+// is still Pending. At payout the agent must present the secret itself, which
+// proves the recipient was physically present before funds are released.
+
+/// Register the SHA-256 digest of the recipient's pickup secret.
+///
+/// The hash can be set once, by the sender, while the remittance is Pending.
+///
+/// # Errors
+/// - `ContractError::Unauthorized` — caller is not the remittance sender
+/// - `ContractError::InvalidStatus` — remittance is no longer Pending
+/// - `ContractError::PickupSecretAlreadySet` — a hash is already registered
+pub fn store_pickup_secret_hash(
+    env: &Env,
+    sender: &Address,
+    remittance_id: u64,
+    secret_hash: &BytesN<32>,
+) -> Result<(), ContractError> {
+    let remittance = crate::storage::get_remittance(env, remittance_id)?;
+    if remittance.sender != *sender {
+        return Err(ContractError::Unauthorized);
+    }
+    if remittance.status != crate::RemittanceStatus::Pending {
+        return Err(ContractError::InvalidStatus);
+    }
+    if get_pickup_secret_hash(env, remittance_id).is_some() {
+        return Err(ContractError::PickupSecretAlreadySet);
+    }
+
+    set_pickup_secret_hash(env, remittance_id, secret_hash);
+    emit_pickup_secret_registered(env, remittance_id, sender.clone());
+    Ok(())
+}
+
+/// Verify the pickup secret presented by the agent at payout time.
+///
+/// Remittances at or above the admin-configured threshold must have a pickup
+/// hash registered; below it the check applies only if the sender opted in.
+///
+/// # Errors
+/// - `ContractError::PickupSecretRequired` — secret required but missing, or a
+///   high-value remittance has no registered hash
+/// - `ContractError::InvalidPickupSecret` — secret does not match the registered hash
+pub fn verify_pickup_secret(
+    env: &Env,
+    remittance: &crate::Remittance,
+    secret: Option<Bytes>,
+) -> Result<(), ContractError> {
+    let expected = match get_pickup_secret_hash(env, remittance.id) {
+        Some(hash) => hash,
+        None => {
+            let threshold = get_pickup_secret_threshold(env);
+            if threshold > 0 && remittance.amount >= threshold {
+                return Err(ContractError::PickupSecretRequired);
+            }
+            return Ok(());
+        }
+    };
+
+    let secret = secret.ok_or(ContractError::PickupSecretRequired)?;
+    let digest: BytesN<32> = env.crypto().sha256(&secret).into();
+    if digest != expected {
+        return Err(ContractError::InvalidPickupSecret);
+    }
+
+    emit_pickup_secret_verified(env, remittance.id, remittance.agent.clone());
+    Ok(())
+}
+
+/// Record the delivery proof presented by the agent at payout time.
+///
+/// Remittances at or above the admin-configured threshold must come with one;
+/// below it a proof is recorded if given.
+///
+/// # Errors
+/// - `ContractError::DeliveryProofRequired` — a high-value remittance has no proof
+pub fn record_delivery_proof(
+    env: &Env,
+    remittance: &crate::Remittance,
+    proof: Option<BytesN<32>>,
+) -> Result<(), ContractError> {
+    let proof = match proof {
+        Some(proof) => proof,
+        None => {
+            let threshold = get_delivery_proof_threshold(env);
+            if threshold > 0 && remittance.amount >= threshold {
+                return Err(ContractError::DeliveryProofRequired);
+            }
+            return Ok(());
+        }
+    };
+
+    set_delivery_proof(env, remittance.id, &proof);
+    emit_delivery_proof_recorded(env, remittance.id, remittance.agent.clone(), proof);
+    Ok(())
+}
+
+// ============================================================================
+// Encrypted Memo Envelope
+// ============================================================================
+//
+// Pickup instructions often contain personal details that must not appear on
+// a public ledger. The sender encrypts them to the recipient's key off-chain
+// and attaches the opaque ciphertext to the Pending remittance. The contract
+// only bounds its size; it never sees the key or the plaintext.
+
+/// Attach an encrypted memo envelope to a Pending remittance.
+///
+/// # Errors
+/// - `ContractError::Unauthorized` — caller is not the remittance sender
+/// - `ContractError::InvalidStatus` — remittance is not Pending
+/// - `ContractError::InvalidEncryptedMemo` — ciphertext is empty or too large
+/// - `ContractError::EncryptedMemoAlreadySet` — a memo is already attached
+pub fn store_encrypted_memo(
+    env: &Env,
+    sender: &Address,
+    remittance_id: u64,
+    ciphertext: &Bytes,
+) -> Result<(), ContractError> {
+    let remittance = crate::storage::get_remittance(env, remittance_id)?;
+    if remittance.sender != *sender {
+        return Err(ContractError::Unauthorized);
+    }
+    if remittance.status != crate::RemittanceStatus::Pending {
+        return Err(ContractError::InvalidStatus);
+    }
+    if ciphertext.is_empty() || ciphertext.len() > MAX_ENCRYPTED_MEMO_BYTES {
+        return Err(ContractError::InvalidEncryptedMemo);
+    }
+    if get_encrypted_memo(env, remittance_id).is_some() {
+        return Err(ContractError::EncryptedMemoAlreadySet);
+    }
+
+    set_encrypted_memo(env, remittance_id, ciphertext);
+    emit_encrypted_memo_attached(env, remittance_id, ciphertext.clone());
+    Ok(())
+}
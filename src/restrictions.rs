@@ -0,0 +1,126 @@
+//! Compliance restrictions on tokens and corridors.
+//!
+//! An address holding `Role::Compliance` can restrict a token or a corridor:
+//! [`Restriction::Blocked`] refuses it outright, while
+//! [`Restriction::MinKycTier`] only admits parties whose KYC tier reaches the
+//! given level. A party's tier comes from `set_kyc_tier` and counts only
+//! while its KYC approval is current; otherwise it is 0.
+//!
+//! Restrictions are checked for the sender when a remittance is created and
+//! for the agent whenever a remittance is assigned to one, at creation or on
+//! reassignment. Every restriction in force is listed by `get_restrictions`,
+//! so frontends can hide tokens and corridors a user cannot use.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::storage::{
+    get_kyc_tier, get_restriction, get_restriction_targets, is_kyc_approved, is_kyc_expired,
+    remove_restriction, set_restriction, set_restriction_targets,
+};
+use crate::{ContractError, RemittanceCorridor};
+
+/// What a restriction applies to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RestrictionTarget {
+    Token(Address),
+    Corridor(RemittanceCorridor),
+}
+
+/// How a target is restricted.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Restriction {
+    /// Not available to anyone
+    Blocked,
+    /// Only available to parties with at least this KYC tier
+    MinKycTier(u32),
+}
+
+/// A restriction in force.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RestrictionRecord {
+    pub target: RestrictionTarget,
+    pub restriction: Restriction,
+    pub updated_at: u64,
+}
+
+/// Restricts `target`, replacing any earlier restriction on it.
+pub fn restrict(env: &Env, target: RestrictionTarget, restriction: Restriction) -> RestrictionRecord {
+    let record = RestrictionRecord {
+        target,
+        restriction,
+        updated_at: env.ledger().timestamp(),
+    };
+    if get_restriction(env, &record.target).is_none() {
+        let mut targets = get_restriction_targets(env);
+        targets.push_back(record.target.clone());
+        set_restriction_targets(env, &targets);
+    }
+    set_restriction(env, &record);
+    record
+}
+
+/// Lifts the restriction on `target`. Returns whether there was one.
+pub fn lift(env: &Env, target: &RestrictionTarget) -> bool {
+    if get_restriction(env, target).is_none() {
+        return false;
+    }
+    let mut targets = get_restriction_targets(env);
+    if let Some(index) = targets.first_index_of(target) {
+        targets.remove(index);
+    }
+    set_restriction_targets(env, &targets);
+    remove_restriction(env, target);
+    true
+}
+
+/// Returns every restriction in force, oldest target first.
+pub fn all(env: &Env) -> Vec<RestrictionRecord> {
+    let mut records = Vec::new(env);
+    for target in get_restriction_targets(env).iter() {
+        if let Some(record) = get_restriction(env, &target) {
+            records.push_back(record);
+        }
+    }
+    records
+}
+
+/// Returns the KYC tier `party` currently holds.
+pub fn kyc_tier(env: &Env, party: &Address) -> u32 {
+    if is_kyc_approved(env, party) && !is_kyc_expired(env, party) {
+        get_kyc_tier(env, party)
+    } else {
+        0
+    }
+}
+
+/// Checks that `party` may use `token`, and `corridor` if given.
+///
+/// # Errors
+///
+/// * `ContractError::Restricted` - The token or corridor is blocked
+/// * `ContractError::KycTierTooLow` - `party`'s KYC tier is below the required one
+pub fn check(
+    env: &Env,
+    party: &Address,
+    token: &Address,
+    corridor: Option<&RemittanceCorridor>,
+) -> Result<(), ContractError> {
+    check_target(env, party, &RestrictionTarget::Token(token.clone()))?;
+    if let Some(corridor) = corridor {
+        check_target(env, party, &RestrictionTarget::Corridor(corridor.clone()))?;
+    }
+    Ok(())
+}
+
+fn check_target(env: &Env, party: &Address, target: &RestrictionTarget) -> Result<(), ContractError> {
+    match get_restriction(env, target).map(|record| record.restriction) {
+        Some(Restriction::Blocked) => Err(ContractError::Restricted),
+        Some(Restriction::MinKycTier(tier)) if kyc_tier(env, party) < tier => {
+            Err(ContractError::KycTierTooLow)
+        }
+        _ => Ok(()),
+    }
+}
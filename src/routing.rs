@@ -0,0 +1,235 @@
+//! Multi-hop remittance routing.
+//!
+//! A routed remittance travels through one or more intermediate agents (for
+//! example a regional hub) before reaching the final cash-out agent, which is
+//! the remittance's `agent`. Each intermediate hop confirms its leg in order
+//! with `confirm_route_leg`; the cash-out agent then settles through the normal
+//! `confirm_payout` path, which refuses to run until every earlier leg is
+//! confirmed.
+//!
+//! At payout, each intermediate hop receives `fee_bps` of the net payout and
+//! the cash-out agent receives the remainder. The route is stored alongside
+//! the remittance under its ID, so direct remittances are unaffected.
+
+use soroban_sdk::{contracttype, token, Address, Env, Vec};
+
+use crate::config::{FEE_DIVISOR, MAX_FEE_BPS, MAX_ROUTE_HOPS};
+use crate::events::{emit_route_hop_paid, emit_route_leg_confirmed};
+use crate::storage::{
+    get_path_payout, get_payout_preference, get_remittance_route, is_agent_registered, set_remittance_route,
+};
+use crate::token_transfer::pay;
+use crate::{path_payout, ContractError, Remittance};
+
+/// Intermediate hop supplied when creating a routed remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RouteHop {
+    pub agent: Address,
+    /// Share of the net payout paid to this hop, in basis points
+    pub fee_bps: u32,
+}
+
+/// Confirmation state of a single route leg.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LegStatus {
+    Pending,
+    Confirmed,
+}
+
+/// One leg of a routed remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RouteLeg {
+    pub agent: Address,
+    /// Share of the net payout in basis points; 0 for the cash-out leg,
+    /// which receives the remainder
+    pub fee_bps: u32,
+    pub status: LegStatus,
+    pub confirmed_at: Option<u64>,
+}
+
+/// Validates intermediate hops and builds the leg list, ending with `final_agent`.
+///
+/// # Errors
+///
+/// * `ContractError::InvalidRoute` - See the error's documentation
+pub fn build_route(
+    env: &Env,
+    hops: &Vec<RouteHop>,
+    final_agent: &Address,
+) -> Result<Vec<RouteLeg>, ContractError> {
+    if hops.is_empty() || hops.len() > MAX_ROUTE_HOPS {
+        return Err(ContractError::InvalidRoute);
+    }
+
+    let mut legs = Vec::new(env);
+    let mut total_bps: u32 = 0;
+    for hop in hops.iter() {
+        if hop.agent == *final_agent || !is_agent_registered(env, &hop.agent) {
+            return Err(ContractError::InvalidRoute);
+        }
+        if legs.iter().any(|leg: RouteLeg| leg.agent == hop.agent) {
+            return Err(ContractError::InvalidRoute);
+        }
+        total_bps = total_bps
+            .checked_add(hop.fee_bps)
+            .ok_or(ContractError::InvalidRoute)?;
+        legs.push_back(RouteLeg {
+            agent: hop.agent,
+            fee_bps: hop.fee_bps,
+            status: LegStatus::Pending,
+            confirmed_at: None,
+        });
+    }
+    if total_bps >= MAX_FEE_BPS {
+        return Err(ContractError::InvalidRoute);
+    }
+
+    legs.push_back(RouteLeg {
+        agent: final_agent.clone(),
+        fee_bps: 0,
+        status: LegStatus::Pending,
+        confirmed_at: None,
+    });
+    Ok(legs)
+}
+
+/// Confirms the next pending intermediate leg, which must belong to `agent`.
+///
+/// Returns the index of the confirmed leg.
+pub fn confirm_leg(env: &Env, remittance_id: u64, agent: &Address) -> Result<u32, ContractError> {
+    let mut legs = get_remittance_route(env, remittance_id).ok_or(ContractError::RouteLegOutOfOrder)?;
+
+    // The cash-out leg is confirmed by confirm_payout, never here.
+    let intermediate = legs.len() - 1;
+    let index = (0..intermediate)
+        .find(|&i| legs.get_unchecked(i).status == LegStatus::Pending)
+        .ok_or(ContractError::RouteLegOutOfOrder)?;
+
+    let mut leg = legs.get_unchecked(index);
+    if leg.agent != *agent {
+        return Err(ContractError::RouteLegOutOfOrder);
+    }
+    leg.status = LegStatus::Confirmed;
+    leg.confirmed_at = Some(env.ledger().timestamp());
+    legs.set(index, leg);
+    set_remittance_route(env, remittance_id, &legs);
+
+    emit_route_leg_confirmed(env, remittance_id, index, agent.clone());
+    Ok(index)
+}
+
+/// Fails if a routed remittance still has unconfirmed intermediate legs.
+pub fn require_route_ready(env: &Env, remittance_id: u64) -> Result<(), ContractError> {
+    if let Some(legs) = get_remittance_route(env, remittance_id) {
+        let intermediate = legs.len() - 1;
+        if (0..intermediate).any(|i| legs.get_unchecked(i).status != LegStatus::Confirmed) {
+            return Err(ContractError::RouteIncomplete);
+        }
+    }
+    Ok(())
+}
+
+/// Releases `payout_amount` from escrow, split across the route if there is one.
+///
+/// Direct remittances pay the full amount to the agent, converted through a
+/// path payment if the agent asked for another asset for this remittance or
+/// prefers one for all payouts. Routed remittances pay
+/// each intermediate hop its share, the cash-out agent the remainder, and mark
+/// the cash-out leg confirmed.
+///
+/// With `best_effort` set, payments that fail are held for their payee and
+/// the first failure is returned as `Ok(Some(error))`.
+pub fn pay_out(
+    env: &Env,
+    token_client: &token::Client,
+    remittance: &Remittance,
+    payout_amount: i128,
+    best_effort: bool,
+) -> Result<Option<ContractError>, ContractError> {
+    let mut legs = match get_remittance_route(env, remittance.id) {
+        Some(legs) => legs,
+        None => {
+            if let Some(path_payout) = get_path_payout(env, remittance.id) {
+                return path_payout::pay(
+                    env,
+                    token_client,
+                    remittance,
+                    path_payout,
+                    payout_amount,
+                    best_effort,
+                );
+            }
+            if let Some(preference) = get_payout_preference(env, &remittance.agent)
+                .filter(|preference| preference.token != remittance.token)
+            {
+                return path_payout::pay_preferred(
+                    env,
+                    token_client,
+                    remittance,
+                    preference,
+                    payout_amount,
+                    best_effort,
+                );
+            }
+            return pay(
+                env,
+                token_client,
+                remittance.id,
+                &remittance.agent,
+                payout_amount,
+                best_effort,
+            );
+        }
+    };
+
+    let last = legs.len() - 1;
+    let mut remainder = payout_amount;
+    let mut failure = None;
+    for i in 0..last {
+        let leg = legs.get_unchecked(i);
+        let share = payout_amount
+            .checked_mul(leg.fee_bps as i128)
+            .ok_or(ContractError::Overflow)?
+            / FEE_DIVISOR;
+        if share > 0 {
+            let held = pay(env, token_client, remittance.id, &leg.agent, share, best_effort)?;
+            failure = failure.or(held);
+            remainder = remainder.checked_sub(share).ok_or(ContractError::Overflow)?;
+        }
+        emit_route_hop_paid(env, remittance.id, leg.agent, share);
+    }
+
+    let mut final_leg = legs.get_unchecked(last);
+    let held = pay(env, token_client, remittance.id, &final_leg.agent, remainder, best_effort)?;
+    failure = failure.or(held);
+    emit_route_hop_paid(env, remittance.id, final_leg.agent.clone(), remainder);
+
+    final_leg.status = LegStatus::Confirmed;
+    final_leg.confirmed_at = Some(env.ledger().timestamp());
+    legs.set(last, final_leg);
+    set_remittance_route(env, remittance.id, &legs);
+    Ok(failure)
+}
+
+/// Points the cash-out leg of a routed remittance at `new_agent`.
+///
+/// No-op for direct remittances. Fails with `InvalidRoute` if `new_agent` is
+/// already an intermediate hop on the route.
+pub fn reassign_cash_out(env: &Env, remittance_id: u64, new_agent: &Address) -> Result<(), ContractError> {
+    let mut legs = match get_remittance_route(env, remittance_id) {
+        Some(legs) => legs,
+        None => return Ok(()),
+    };
+    let last = legs.len() - 1;
+    if (0..last).any(|i| legs.get_unchecked(i).agent == *new_agent) {
+        return Err(ContractError::InvalidRoute);
+    }
+    let mut final_leg = legs.get_unchecked(last);
+    final_leg.agent = new_agent.clone();
+    legs.set(last, final_leg);
+    set_remittance_route(env, remittance_id, &legs);
+    Ok(())
+}
@@ -0,0 +1,284 @@
+//! Recipient savings vault.
+//!
+//! Recipients paid directly to their own address (escrow transfers) may keep
+//! part of every payout in the contract instead of their wallet. A recipient
+//! sets a savings share in basis points; when an escrow addressed to them is
+//! released, that share is credited to their savings balance in the escrow's
+//! token and only the remainder is transferred. Savings are held per
+//! (recipient, token) and can be withdrawn at any time.
+//!
+//! ## Savings Goals
+//!
+//! Owners may also set money aside in named goals with a target amount and an
+//! optional deadline. A goal is funded either by moving vault savings into it,
+//! which is how remittance payouts reach a goal, or by depositing directly from
+//! the owner's wallet. Withdrawing is free once the goal has reached its target
+//! or its deadline has passed; earlier withdrawals pay
+//! [`GOAL_EARLY_WITHDRAWAL_FEE_BPS`] into the insurance fund for the goal's token.
+
+use soroban_sdk::{contracttype, token, Address, Env, String};
+
+use crate::config::{FEE_DIVISOR, GOAL_EARLY_WITHDRAWAL_FEE_BPS, MAX_FEE_BPS};
+use crate::storage::{
+    append_owner_savings_goal, get_insurance_fund, get_savings_balance, get_savings_goal,
+    get_savings_goal_counter, get_savings_share, set_insurance_fund, set_savings_balance,
+    set_savings_goal, set_savings_goal_counter,
+};
+use crate::token_transfer;
+use crate::ContractError;
+
+/// A named savings goal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SavingsGoal {
+    pub id: u64,
+    pub owner: Address,
+    pub name: String,
+    pub token: Address,
+    /// Amount the owner is saving towards
+    pub target: i128,
+    /// Timestamp after which withdrawals are free even below target
+    pub deadline: Option<u64>,
+    /// Amount currently held in the goal
+    pub balance: i128,
+}
+
+impl SavingsGoal {
+    /// Progress towards the target in basis points, capped at 100%.
+    pub fn progress_bps(&self) -> u32 {
+        if self.balance >= self.target {
+            return MAX_FEE_BPS;
+        }
+        (self.balance * FEE_DIVISOR / self.target) as u32
+    }
+
+    /// Whether funds can leave the goal without the early-withdrawal fee.
+    pub fn is_unlocked(&self, now: u64) -> bool {
+        self.balance >= self.target || self.deadline.is_some_and(|deadline| now >= deadline)
+    }
+}
+
+/// Validates a savings share in basis points.
+pub fn validate_share(bps: u32) -> Result<(), ContractError> {
+    if bps > MAX_FEE_BPS {
+        return Err(ContractError::InvalidAmount);
+    }
+    Ok(())
+}
+
+/// Splits a payout into the part transferred to the recipient and the part
+/// kept as savings, according to the recipient's savings share.
+pub fn split(env: &Env, recipient: &Address, amount: i128) -> Result<(i128, i128), ContractError> {
+    let share = get_savings_share(env, recipient);
+    let saved = amount
+        .checked_mul(share as i128)
+        .ok_or(ContractError::Overflow)?
+        / FEE_DIVISOR;
+    Ok((amount - saved, saved))
+}
+
+/// Credits `amount` of `token`, already held by the contract, to an owner's savings.
+///
+/// Returns the new balance.
+pub fn deposit(
+    env: &Env,
+    owner: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    let balance = get_savings_balance(env, owner, token)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    set_savings_balance(env, owner, token, balance);
+    Ok(balance)
+}
+
+/// Transfers `amount` of an owner's savings back to their wallet.
+///
+/// Returns the remaining balance.
+pub fn withdraw(
+    env: &Env,
+    owner: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    let balance = get_savings_balance(env, owner, token);
+    if amount > balance {
+        return Err(ContractError::InsufficientSavingsBalance);
+    }
+    set_savings_balance(env, owner, token, balance - amount);
+    token_transfer::transfer(
+        &token::Client::new(env, token),
+        &env.current_contract_address(),
+        owner,
+        amount,
+    )?;
+    Ok(balance - amount)
+}
+
+/// Creates an empty savings goal for `owner`.
+pub fn create_goal(
+    env: &Env,
+    owner: &Address,
+    name: String,
+    token: &Address,
+    target: i128,
+    deadline: Option<u64>,
+) -> Result<SavingsGoal, ContractError> {
+    if target <= 0 {
+        return Err(ContractError::InvalidSavingsGoal);
+    }
+    if let Some(deadline) = deadline {
+        if deadline <= env.ledger().timestamp() {
+            return Err(ContractError::InvalidSavingsGoal);
+        }
+    }
+
+    let id = get_savings_goal_counter(env)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    let goal = SavingsGoal {
+        id,
+        owner: owner.clone(),
+        name,
+        token: token.clone(),
+        target,
+        deadline,
+        balance: 0,
+    };
+    set_savings_goal(env, &goal);
+    set_savings_goal_counter(env, id);
+    append_owner_savings_goal(env, owner, id);
+    Ok(goal)
+}
+
+/// Loads a goal, checking that it belongs to `owner`.
+pub fn owned_goal(env: &Env, owner: &Address, goal_id: u64) -> Result<SavingsGoal, ContractError> {
+    match get_savings_goal(env, goal_id) {
+        Some(goal) if goal.owner == *owner => Ok(goal),
+        _ => Err(ContractError::SavingsGoalNotFound),
+    }
+}
+
+/// Adds `amount` to a goal, either from the owner's wallet or from their vault savings.
+///
+/// Returns the goal's new balance.
+pub fn fund_goal(
+    env: &Env,
+    goal: &mut SavingsGoal,
+    amount: i128,
+    from_vault: bool,
+) -> Result<i128, ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    if from_vault {
+        let vault = get_savings_balance(env, &goal.owner, &goal.token);
+        if amount > vault {
+            return Err(ContractError::InsufficientSavingsBalance);
+        }
+        set_savings_balance(env, &goal.owner, &goal.token, vault - amount);
+    } else {
+        token_transfer::transfer(
+            &token::Client::new(env, &goal.token),
+            &goal.owner,
+            &env.current_contract_address(),
+            amount,
+        )?;
+    }
+    goal.balance = goal
+        .balance
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    set_savings_goal(env, goal);
+    Ok(goal.balance)
+}
+
+/// Withdraws `amount` from a goal to its owner.
+///
+/// Charges the early-withdrawal fee, paid into the insurance fund, while the
+/// goal is still locked. Returns the fee charged.
+pub fn withdraw_goal(
+    env: &Env,
+    goal: &mut SavingsGoal,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+    if amount > goal.balance {
+        return Err(ContractError::InsufficientSavingsBalance);
+    }
+
+    let fee = if goal.is_unlocked(env.ledger().timestamp()) {
+        0
+    } else {
+        amount * GOAL_EARLY_WITHDRAWAL_FEE_BPS as i128 / FEE_DIVISOR
+    };
+    if fee > 0 {
+        let fund = get_insurance_fund(env, &goal.token)
+            .checked_add(fee)
+            .ok_or(ContractError::Overflow)?;
+        set_insurance_fund(env, &goal.token, fund);
+    }
+
+    goal.balance -= amount;
+    set_savings_goal(env, goal);
+    token_transfer::transfer(
+        &token::Client::new(env, &goal.token),
+        &env.current_contract_address(),
+        &goal.owner,
+        amount - fee,
+    )?;
+    Ok(fee)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_split_by_share() {
+        let env = Env::default();
+        let contract = env.register(crate::SwiftRemitContract, ());
+        let recipient = Address::generate(&env);
+        env.as_contract(&contract, || {
+            assert_eq!(split(&env, &recipient, 1_000).unwrap(), (1_000, 0));
+            crate::storage::set_savings_share(&env, &recipient, 2500);
+            assert_eq!(split(&env, &recipient, 1_000).unwrap(), (750, 250));
+        });
+    }
+
+    #[test]
+    fn test_goal_progress_and_unlock() {
+        let env = Env::default();
+        let mut goal = SavingsGoal {
+            id: 1,
+            owner: Address::generate(&env),
+            name: String::from_str(&env, "School fees"),
+            token: Address::generate(&env),
+            target: 1_000,
+            deadline: Some(500),
+            balance: 250,
+        };
+        assert_eq!(goal.progress_bps(), 2500);
+        assert!(!goal.is_unlocked(499));
+        assert!(goal.is_unlocked(500));
+
+        goal.deadline = None;
+        assert!(!goal.is_unlocked(u64::MAX));
+        goal.balance = 1_500;
+        assert_eq!(goal.progress_bps(), MAX_FEE_BPS);
+        assert!(goal.is_unlocked(0));
+    }
+
+    #[test]
+    fn test_validate_share() {
+        assert!(validate_share(MAX_FEE_BPS).is_ok());
+        assert_eq!(validate_share(MAX_FEE_BPS + 1), Err(ContractError::InvalidAmount));
+    }
+}
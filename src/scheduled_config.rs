@@ -0,0 +1,155 @@
+//! Configuration changes scheduled for a future time.
+//!
+//! Operators announce pricing changes in advance by scheduling them with an
+//! `effective_at` timestamp at least the governance timelock away, the same
+//! notice a fee-update proposal gets. Nothing is written to the live settings
+//! when the time comes; instead the fee and daily-limit getters resolve the
+//! active value at call time, preferring the latest due scheduled change over
+//! the stored setting. A direct update made after a scheduled change took
+//! effect supersedes it, so the setters drop due changes of the same kind.
+//!
+//! Pending changes are kept ordered by `effective_at`, at most
+//! [`MAX_SCHEDULED_CONFIG_CHANGES`] at a time.
+
+use soroban_sdk::{contracttype, Env, String, Vec};
+
+use crate::config::{MAX_PLATFORM_FEE_BPS, MAX_SCHEDULED_CONFIG_CHANGES};
+use crate::storage::{
+    get_governance_timelock, get_scheduled_config_changes, get_scheduled_config_counter,
+    set_scheduled_config_changes, set_scheduled_config_counter,
+};
+use crate::ContractError;
+
+/// A fee or limit setting to change.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigChange {
+    /// Platform fee in basis points, applied as a percentage strategy
+    PlatformFee(u32),
+    /// Daily send limit for a (currency, country) pair
+    DailyLimit(String, String, i128),
+}
+
+/// A configuration change waiting for its effective time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledConfigChange {
+    pub id: u64,
+    pub change: ConfigChange,
+    pub effective_at: u64,
+}
+
+/// Records a change taking effect at `effective_at`.
+pub fn schedule(
+    env: &Env,
+    change: ConfigChange,
+    effective_at: u64,
+) -> Result<ScheduledConfigChange, ContractError> {
+    match &change {
+        ConfigChange::PlatformFee(bps) if *bps > MAX_PLATFORM_FEE_BPS => {
+            return Err(ContractError::FeeAboveCeiling);
+        }
+        ConfigChange::DailyLimit(_, _, limit) if *limit <= 0 => {
+            return Err(ContractError::InvalidAmount);
+        }
+        _ => {}
+    }
+    let earliest = env
+        .ledger()
+        .timestamp()
+        .saturating_add(get_governance_timelock(env).max(1));
+    if effective_at < earliest {
+        return Err(ContractError::InvalidSchedule);
+    }
+
+    let mut changes = get_scheduled_config_changes(env);
+    if changes.len() >= MAX_SCHEDULED_CONFIG_CHANGES {
+        return Err(ContractError::InvalidSchedule);
+    }
+    let id = get_scheduled_config_counter(env)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    let scheduled = ScheduledConfigChange {
+        id,
+        change,
+        effective_at,
+    };
+    let position = changes
+        .iter()
+        .position(|c| c.effective_at > effective_at)
+        .unwrap_or(changes.len() as usize);
+    changes.insert(position as u32, scheduled.clone());
+    set_scheduled_config_changes(env, &changes);
+    set_scheduled_config_counter(env, id);
+    Ok(scheduled)
+}
+
+/// Removes a change that has not yet taken effect.
+pub fn cancel(env: &Env, id: u64) -> Result<(), ContractError> {
+    let mut changes = get_scheduled_config_changes(env);
+    let index = changes
+        .iter()
+        .position(|c| c.id == id && c.effective_at > env.ledger().timestamp())
+        .ok_or(ContractError::ScheduledChangeNotFound)?;
+    changes.remove(index as u32);
+    set_scheduled_config_changes(env, &changes);
+    Ok(())
+}
+
+/// Platform fee set by the latest due scheduled change, if any.
+pub fn due_platform_fee_bps(env: &Env) -> Option<u32> {
+    let now = env.ledger().timestamp();
+    let mut bps = None;
+    for scheduled in get_scheduled_config_changes(env).iter() {
+        if scheduled.effective_at > now {
+            break;
+        }
+        if let ConfigChange::PlatformFee(value) = scheduled.change {
+            bps = Some(value);
+        }
+    }
+    bps
+}
+
+/// Daily limit for a pair set by the latest due scheduled change, if any.
+pub fn due_daily_limit(env: &Env, currency: &String, country: &String) -> Option<i128> {
+    let now = env.ledger().timestamp();
+    let mut limit = None;
+    for scheduled in get_scheduled_config_changes(env).iter() {
+        if scheduled.effective_at > now {
+            break;
+        }
+        if let ConfigChange::DailyLimit(c, k, value) = scheduled.change {
+            if c == *currency && k == *country {
+                limit = Some(value);
+            }
+        }
+    }
+    limit
+}
+
+fn discard_due(env: &Env, superseded: impl Fn(&ConfigChange) -> bool) {
+    let now = env.ledger().timestamp();
+    let changes = get_scheduled_config_changes(env);
+    let mut kept = Vec::new(env);
+    for scheduled in changes.iter() {
+        if scheduled.effective_at > now || !superseded(&scheduled.change) {
+            kept.push_back(scheduled);
+        }
+    }
+    if kept.len() != changes.len() {
+        set_scheduled_config_changes(env, &kept);
+    }
+}
+
+/// Drops due platform-fee changes superseded by a direct fee update.
+pub fn discard_due_fee_changes(env: &Env) {
+    discard_due(env, |change| matches!(change, ConfigChange::PlatformFee(_)));
+}
+
+/// Drops due limit changes for a pair superseded by a direct limit update.
+pub fn discard_due_limit_changes(env: &Env, currency: &String, country: &String) {
+    discard_due(env, |change| {
+        matches!(change, ConfigChange::DailyLimit(c, k, _) if c == currency && k == country)
+    });
+}
@@ -36,6 +36,8 @@
 // - Schema changes use migration keys (MigrationInProgress)
 // - Old keys migrated to new formats during contract upgrades
 // - SettlementPacked replaces scattered settlement flags
+// - Duplicate settlement is derived from remittance state; leftover settlement
+//   flag entries are removed with cleanup_settlement_entries
 // - See migration.rs for upgrade paths
 //
 // ## Design Principles
@@ -45,9 +47,133 @@
 // - Idempotent writes: Skip if value unchanged to save ledger entries
 // ============================================================================
 
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec};
 
-use crate::{AgentStats, ContractError, DailyLimit, Remittance, SenderVolumeEntry, TransferRecord};
+use crate::{
+    config::{MAX_LIST_TRIM, MAX_PAGE_SIZE},
+    AddressPage, AdminLogEntry, AdminLogPage, AgentStats, ContractError, DailyLimit, IdPage,
+    Remittance, RemittancePage, RemittanceV1,
+    SenderVolumeEntry, TransferRecord, VersionedRemittance,
+};
+
+// === Pagination ===
+
+/// A page of items cut from a stored list by [`paginate`].
+///
+/// `Page<T>` is the single pagination convention for list endpoints: cursors
+/// are zero-based positions into the underlying list, `next_cursor` is the
+/// position to resume from, and `has_more` reports whether it is in range.
+/// Endpoints convert it into the matching contracttype page ([`IdPage`],
+/// [`AddressPage`], [`RemittancePage`], [`AdminLogPage`]) since contract types
+/// cannot be generic.
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: u32,
+    pub has_more: bool,
+}
+
+/// Returns up to `limit` items of `all` starting at position `cursor`.
+///
+/// `limit` is clamped to `MAX_PAGE_SIZE`. A cursor past the end of the list
+/// yields an empty page with `has_more == false`.
+pub fn paginate<T>(env: &Env, all: &Vec<T>, cursor: u32, limit: u32) -> Page<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let total = all.len();
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let start = cursor.min(total);
+    let end = start.saturating_add(limit).min(total);
+
+    let mut items = Vec::new(env);
+    for i in start..end {
+        items.push_back(all.get_unchecked(i));
+    }
+
+    Page {
+        items,
+        next_cursor: end,
+        has_more: end < total,
+    }
+}
+
+/// Replaces the IDs on a page with their remittance records.
+pub fn load_remittances(env: &Env, page: Page<u64>) -> Result<Page<Remittance>, ContractError> {
+    let mut items = Vec::new(env);
+    for id in page.items.iter() {
+        items.push_back(get_remittance(env, id)?);
+    }
+    Ok(Page {
+        items,
+        next_cursor: page.next_cursor,
+        has_more: page.has_more,
+    })
+}
+
+/// Like [`paginate`] over a list stored one entry per key, so a page reads
+/// only its own entries. `get` returns the item at a position.
+pub fn paginate_keyed<T, F>(env: &Env, total: u32, cursor: u32, limit: u32, get: F) -> Page<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    F: Fn(u32) -> Option<T>,
+{
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let start = cursor.min(total);
+    let end = start.saturating_add(limit).min(total);
+
+    let mut items = Vec::new(env);
+    for i in start..end {
+        if let Some(item) = get(i) {
+            items.push_back(item);
+        }
+    }
+
+    Page {
+        items,
+        next_cursor: end,
+        has_more: end < total,
+    }
+}
+
+impl From<Page<u64>> for IdPage {
+    fn from(page: Page<u64>) -> Self {
+        IdPage {
+            items: page.items,
+            next_cursor: page.next_cursor,
+            has_more: page.has_more,
+        }
+    }
+}
+
+impl From<Page<Address>> for AddressPage {
+    fn from(page: Page<Address>) -> Self {
+        AddressPage {
+            items: page.items,
+            next_cursor: page.next_cursor,
+            has_more: page.has_more,
+        }
+    }
+}
+
+impl From<Page<AdminLogEntry>> for AdminLogPage {
+    fn from(page: Page<AdminLogEntry>) -> Self {
+        AdminLogPage {
+            items: page.items,
+            next_cursor: page.next_cursor,
+            has_more: page.has_more,
+        }
+    }
+}
+
+impl From<Page<Remittance>> for RemittancePage {
+    fn from(page: Page<Remittance>) -> Self {
+        RemittancePage {
+            items: page.items,
+            next_cursor: page.next_cursor,
+            has_more: page.has_more,
+        }
+    }
+}
 
 /// Storage keys for the SwiftRemit contract.
 ///
@@ -93,6 +219,28 @@ enum DataKey {
     /// Individual remittance record indexed by ID (persistent storage)
     Remittance(u64),
 
+    /// Remittance ID at a position in a status bucket (persistent storage).
+    /// Positions vacated by status changes stay empty.
+    StatusEntry(crate::RemittanceStatus, u32),
+
+    /// `(head, tail)` positions of a status bucket (persistent storage)
+    StatusBounds(crate::RemittanceStatus),
+
+    /// Status bucket and position a remittance is indexed at (persistent storage)
+    StatusSlot(u64),
+
+    /// Number of remittances a sender has created (persistent storage)
+    SenderRemittanceCount(Address),
+
+    /// Remittance ID at a position in a sender's history (persistent storage)
+    SenderRemittance(Address, u32),
+
+    /// Number of remittances ever assigned to an agent (persistent storage)
+    AgentRemittanceCount(Address),
+
+    /// Remittance ID at a position in an agent's history (persistent storage)
+    AgentRemittance(Address, u32),
+
     // === Agent Management ===
     // Keys for tracking registered agents
     /// Agent registration status indexed by agent address (persistent storage)
@@ -101,6 +249,73 @@ enum DataKey {
     /// KYC metadata hash for compliance auditing, indexed by agent address (persistent storage)
     AgentKycHash(Address),
 
+    /// Addresses of all currently registered agents, in registration order (persistent storage)
+    AgentList,
+
+    /// Sub-operator record indexed by sub-operator address (persistent storage)
+    SubOperator(Address),
+
+    /// Sub-operator addresses authorized by an agent (persistent storage)
+    AgentSubOperators(Address),
+
+    /// Platform operator allowed to auto-settle for opted-in agents (instance storage)
+    SettlementOperator,
+
+    /// Agent's auto-settlement opt-in and usage (persistent storage)
+    AutoSettlement(Address),
+
+    // === Organizations ===
+    /// Counter for generating organization IDs (instance storage)
+    OrgCounter,
+
+    /// Organization record indexed by ID (persistent storage)
+    Organization(u64),
+
+    /// Membership record indexed by (organization ID, member) (persistent storage)
+    OrgMember(u64, Address),
+
+    /// Remittance IDs created by members of an organization (persistent storage)
+    OrgRemittances(u64),
+
+    /// Counter for generating over-limit request IDs (instance storage)
+    OrgRequestCounter,
+
+    /// Over-limit remittance request awaiting owner approval (persistent storage)
+    OrgRequest(u64),
+
+    /// IDs of an organization's requests still pending approval (persistent storage)
+    OrgPendingRequests(u64),
+
+    // === Observers ===
+    /// Observer contracts notified on remittance lifecycle events (instance storage)
+    Observers,
+
+    // === Remittance Tags ===
+    /// Sender-defined tags attached to a remittance at creation (persistent storage)
+    RemittanceTags(u64),
+
+    // === Netting Pairs ===
+    /// Bilateral netting pair keyed by (agent_a, agent_b) in canonical order (persistent storage)
+    NettingPair(Address, Address),
+
+    // === Routing ===
+    /// Legs of a multi-hop remittance, ending with the cash-out agent (persistent storage)
+    RemittanceRoute(u64),
+
+    // === Pickup Secrets ===
+    /// SHA-256 of the recipient's pickup secret for a remittance (persistent storage)
+    PickupSecretHash(u64),
+
+    /// Amount at or above which a pickup secret is mandatory; 0 disables (instance storage)
+    PickupSecretThreshold,
+
+    // === Payout Vouchers ===
+    /// Ed25519 public key an agent signs payout vouchers with (persistent storage)
+    VoucherKey(Address),
+
+    /// Marks a voucher nonce as consumed for an agent (persistent storage)
+    VoucherNonceUsed(Address, u64),
+
     // === Fee Tracking ===
     // Keys for managing platform fees
     /// Total accumulated platform fees awaiting withdrawal (instance storage)
@@ -117,7 +332,7 @@ enum DataKey {
 
     // === Settlement Deduplication ===
     // Keys for preventing duplicate settlement execution
-    /// Settlement hash for duplicate detection (legacy persistent storage)
+    /// Settlement hash for duplicate detection (legacy persistent storage, cleanup only)
     SettlementHash(u64),
 
     // === User Management ===
@@ -202,15 +417,232 @@ enum DataKey {
     /// Fee corridor configuration indexed by (from_country, to_country) (persistent storage)
     FeeCorridor(String, String),
 
+    /// Utilization fee bounds indexed by (from_country, to_country) (persistent storage)
+    UtilizationFeeBounds(String, String),
+
+    /// Registered capacity and pending volume per corridor (persistent storage)
+    CorridorLiquidity(String, String),
+
+    /// Capacity an agent has registered on a corridor (persistent storage)
+    AgentCorridorCapacity(Address, String, String),
+
+    /// Amount a remittance contributes to its corridor's pending volume (persistent storage)
+    CorridorPendingAmount(u64),
+
+    /// Expiry-weighted fee slope (instance storage)
+    ExpiryFeeConfig,
+
+    /// Guardrail window bounding platform fee changes (instance storage)
+    FeeChangeWindow,
+
+    /// Expiry-weighted fee charged on a remittance (persistent storage)
+    ExpiryFee(u64),
+
     /// Pending admin address proposed by current admin (2-step transfer, #365)
     PendingAdmin,
     // === Token Fee ===
     TokenFeeBps(soroban_sdk::Address),
+    /// Maximum volume a single settlement batch may move in a token (persistent storage)
+    TokenBatchLimit(soroban_sdk::Address),
     // === Agent Stats & Reputation ===
     AgentStats(soroban_sdk::Address),
+    /// Admin-assigned agent tier (persistent storage)
+    AgentTier(soroban_sdk::Address),
+    /// Total value of Pending remittances assigned to an agent (persistent storage)
+    AgentPendingValue(soroban_sdk::Address),
+    /// Amount a Pending remittance contributes to its agent's pending value (persistent storage)
+    AgentPendingAmount(u64),
+    /// Whether an agent's business verification (KYB) is complete (persistent storage)
+    AgentKybVerified(soroban_sdk::Address),
+    /// Day index and value assigned so far that day to an unverified agent (temporary storage)
+    UnverifiedAgentVolume(soroban_sdk::Address),
+    /// Agents with registered payout capacity on a corridor (persistent storage)
+    CorridorAgents(String, String),
+    /// Insurance policy bought for a remittance (persistent storage)
+    InsurancePolicy(u64),
+    /// Insurance fund balance held by the contract, per token (persistent storage)
+    InsuranceFund(Address),
+    /// Claim filed against a remittance's insurance policy (persistent storage)
+    InsuranceClaim(u64),
+    /// Remittance IDs a sender has filed insurance claims for (persistent storage)
+    SenderClaims(Address),
+    /// Registered cause, indexed by cause address (persistent storage)
+    Cause(Address),
+    /// Donations accumulated by a cause, per token (persistent storage)
+    CauseBalance(Address, Address),
+    /// Donation pledged by the sender of a remittance (persistent storage)
+    DonationPledge(u64),
+    /// Share of escrow payouts a recipient keeps as savings, in bps (persistent storage)
+    SavingsShare(Address),
+    /// Recipient savings balance, per token (persistent storage)
+    SavingsBalance(Address, Address),
+    /// Savings goal counter for generating unique goal IDs (instance storage)
+    SavingsGoalCounter,
+    /// Savings goal indexed by goal ID (persistent storage)
+    SavingsGoal(u64),
+    /// Savings goal IDs created by an owner (persistent storage)
+    OwnerSavingsGoals(Address),
+    /// Stake an agent has locked, per token (persistent storage)
+    AgentStake(Address, Address),
+    /// Advance pool balance held by the contract, per token (persistent storage)
+    AdvancePool(Address),
+    /// Outstanding working-capital advance of an agent, per token (persistent storage)
+    AgentAdvance(Address, Address),
+    /// Current-period credit-signal summary of a sender or agent (persistent storage)
+    ActivitySummary(Address, crate::credit_signals::CreditParty),
+    /// Private remittance counter for generating unique IDs (instance storage)
+    PrivateRemittanceCounter,
+    /// Private remittance indexed by ID (persistent storage)
+    PrivateRemittance(u64),
+    /// Encrypted memo envelope attached to a remittance (persistent storage)
+    EncryptedMemo(u64),
+    /// Whether a remittance's lifecycle events omit amounts and counterparties (persistent storage)
+    MinimalDisclosure(u64),
+    /// Hash-only remittance counter for generating unique IDs (instance storage)
+    HashedRemittanceCounter,
+    /// Hash-only remittance indexed by ID (persistent storage)
+    HashedRemittance(u64),
+    /// Corridors that only accept hash-only remittances (persistent storage)
+    HashOnlyCorridor(String, String),
+    /// Remittance template counter for generating unique IDs (instance storage)
+    TemplateCounter,
+    /// Saved remittance template indexed by ID (persistent storage)
+    RemittanceTemplate(u64),
+    /// Template IDs saved by a sender (persistent storage)
+    SenderTemplates(Address),
+    /// Agents a sender has marked as favorites, in the order added (persistent storage)
+    FavoriteAgents(Address),
+    /// Address book entry of an owner, keyed by alias hash (persistent storage)
+    AddressBookEntry(Address, BytesN<32>),
+    /// Alias hashes in an owner's address book, in the order added (persistent storage)
+    AddressBookAliases(Address),
+    /// Registration of a handle (persistent storage)
+    Handle(String),
+    /// Corridors that currently reject new remittances (persistent storage)
+    CorridorDisabled(String, String),
+    /// Scheduled configuration change counter for generating unique IDs (instance storage)
+    ScheduledConfigCounter,
+    /// Pending scheduled configuration changes ordered by effective time (instance storage)
+    ScheduledConfigChanges,
+    /// Scheduled maintenance windows (instance storage)
+    MaintenanceWindows,
+    /// Guardian co-signing emergency evacuations (instance storage)
+    Guardian,
+    /// Pending emergency evacuation (instance storage)
+    Evacuation,
+    /// Replacement proposed for an agent that lost its key (persistent storage)
+    AgentRecovery(Address),
+    /// Recovery guardians registered by an owner (persistent storage)
+    RecoveryGuardians(Address),
+    /// Account recovery in progress for an owner (persistent storage)
+    AccountRecovery(Address),
+    /// Secp256r1 passkey an agent signs payout vouchers with (persistent storage)
+    PasskeyVoucherKey(Address),
+    /// Recipient passkey that can claim an escrow (persistent storage)
+    EscrowClaimKey(u64),
+    /// Payout held after a failed best-effort transfer, by (payee, token) (persistent storage)
+    UndeliveredPayout(Address, Address),
+    /// Number of remittances in each status (instance storage)
+    RemittanceCounts,
+    /// Volume history bucket by ring slot (persistent storage)
+    VolumeBucket(u32),
+    /// Number of remittance status changes, numbering checkpoints (instance storage)
+    StateChangeCount,
+    /// Admin audit log entry by sequence number (persistent storage)
+    AdminLogEntry(u64),
+    /// Number of admin audit log entries ever written (instance storage)
+    AdminLogLength,
+    /// Instance deployed by the factory for a corridor (persistent storage)
+    CorridorInstance(crate::RemittanceCorridor),
+    /// Corridors with a factory-deployed instance, in deployment order (persistent storage)
+    CorridorInstanceList,
+    /// Entry last sent to the hub registry (instance storage)
+    RegistryEntry,
+    /// Whether another instance is trusted for remittance forwarding (persistent storage)
+    TrustedInstance(Address),
+    /// Target instance and ID of a remittance forwarded away (persistent storage)
+    ForwardedTo(u64),
+    /// Source instance and ID of a remittance forwarded here (persistent storage)
+    ForwardedFrom(u64),
+    /// Creation time of an escrow transfer (persistent storage)
+    EscrowCreatedAt(u64),
+    /// Contract that executes path payments for converted payouts (instance storage)
+    PathPaymentAdapter,
+    /// Asset and minimum the agent takes a remittance's payout in (persistent storage)
+    PathPayout(u64),
+    /// Token an agent prefers payouts in, with its slippage tolerance (persistent storage)
+    PayoutPreference(Address),
+    /// Anchor transaction linked to a remittance (persistent storage)
+    AnchorLink(u64),
+    /// Remittance linked to an anchor transaction ID (persistent storage)
+    AnchorTxRemittance(String),
+    /// Number of quotes posted so far (instance storage)
+    QuoteCounter,
+    /// Firm quote posted by a receiving agent (persistent storage)
+    Quote(u64),
+    /// Quote a remittance was created against (persistent storage)
+    AcceptedQuote(u64),
+    /// Latest delivery status reported by the agent (persistent storage)
+    DeliveryStatus(u64),
+    /// Amount at or above which confirmations need a delivery proof (instance storage)
+    DeliveryProofThreshold,
+    /// Delivery-proof hash recorded at confirmation (persistent storage)
+    DeliveryProof(u64),
+    /// KYC tier assigned by compliance (persistent storage)
+    KycTier(Address),
+    /// Restriction on a token or corridor (persistent storage)
+    Restriction(crate::RestrictionTarget),
+    /// Restricted targets, in the order first restricted (persistent storage)
+    RestrictionTargets,
+    /// Priority level and expedite fees of a boosted remittance (persistent storage)
+    PriorityBoost(u64),
+    /// Seconds after creation before a sender may cancel (instance storage)
+    CancelCooldown,
+    /// When the agent rejected a pending remittance (persistent storage)
+    AgentRejection(u64),
+    /// Smallest remittance amount accepted (instance storage)
+    MinAmount,
+    /// Largest remittance amount accepted (instance storage)
+    MaxAmount,
+    /// Limits waiting for the governance timelock (instance storage)
+    PendingLimits,
+    /// Accumulated fees collected in a token other than the default one (persistent storage)
+    TokenFees(Address),
+    /// Remittance ID at a position in an agent's pending queue (persistent storage)
+    AgentPendingEntry(Address, u32),
+    /// `(head, tail)` positions of an agent's pending queue (persistent storage)
+    AgentPendingBounds(Address),
+    /// Agent and position of a remittance in a pending queue (persistent storage)
+    QueuedFor(u64),
+    /// Stake agents must hold to take large remittances (instance storage)
+    CollateralRequirement,
+    /// Admin-set cap on an agent's total pending value (persistent storage)
+    AgentCapacity(Address),
+    /// Share of each platform fee credited to the settling agent, in bps (instance storage)
+    AgentCommissionBps,
+    /// Agent's unwithdrawn commission in a token (persistent storage)
+    AgentCommission(Address, Address),
+    /// Flat amount added to every rate-based platform fee (instance storage)
+    FlatFee,
+    /// Whether a sender's remittances are created without fees (persistent storage)
+    FeeExempt(Address),
+    /// Platform-wide settlement performance counters (instance storage)
+    PlatformPerformance,
+    /// Per-agent settlement performance counters (persistent storage)
+    AgentPerformance(soroban_sdk::Address),
     AgentDailyCap(soroban_sdk::Address),
     AgentWithdrawals(soroban_sdk::Address),
     MinAgentReputation,
+    /// Total amount held in Processing remittances (instance storage)
+    TotalProcessingVolume,
+    /// Daily decay applied to escalated abuse cooldowns, in bps (instance storage)
+    AbuseCooldownDecayRateBps,
+    /// Migration lock blocking new remittances (instance storage)
+    MigrationInProgress,
+    /// Runtime cap on remittances expired per batch (instance storage)
+    MaxExpiredBatchSize,
+    /// Recipient hash registered at creation (persistent storage)
+    RecipientHash(u64),
     // === Dispute ===
     DisputeWindow,
     // === Partial Payout ===
@@ -221,6 +653,8 @@ enum DataKey {
     // === Idempotency ===
     IdempotencyRecord(soroban_sdk::String),
     IdempotencyTTL,
+    /// Grace period applied to timestamp expiries, in seconds (instance storage)
+    ExpiryTolerance,
     RemittanceIdempotencyKey(u64),
     // === Payout Commitment ===
     PayoutCommitment(u64),
@@ -338,6 +772,7 @@ pub fn get_usdc_token(env: &Env) -> Result<Address, ContractError> {
 /// * `env` - The contract execution environment
 /// * `fee_bps` - Fee in basis points (1 bps = 0.01%)
 pub fn set_platform_fee_bps(env: &Env, fee_bps: u32) {
+    crate::scheduled_config::discard_due_fee_changes(env);
     env.storage()
         .instance()
         .set(&DataKey::PlatformFeeBps, &fee_bps);
@@ -354,6 +789,9 @@ pub fn set_platform_fee_bps(env: &Env, fee_bps: u32) {
 /// * `Ok(u32)` - Fee in basis points
 /// * `Err(ContractError::NotInitialized)` - Contract not initialized
 pub fn get_platform_fee_bps(env: &Env) -> Result<u32, ContractError> {
+    if let Some(fee_bps) = crate::scheduled_config::due_platform_fee_bps(env) {
+        return Ok(fee_bps);
+    }
     env.storage()
         .instance()
         .get(&DataKey::PlatformFeeBps)
@@ -382,6 +820,21 @@ pub fn set_token_fee_bps(env: &Env, token: &Address, fee_bps: u32) -> Result<(),
     Ok(())
 }
 
+pub fn get_token_batch_limit(env: &Env, token: &Address) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenBatchLimit(token.clone()))
+}
+
+/// Sets or, with `None`, clears the per-batch volume limit for a token.
+pub fn set_token_batch_limit(env: &Env, token: &Address, limit: Option<i128>) {
+    let key = DataKey::TokenBatchLimit(token.clone());
+    match limit {
+        Some(limit) => env.storage().persistent().set(&key, &limit),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
 /// Sets the remittance counter for ID generation.
 ///
 /// # Arguments
@@ -411,7 +864,10 @@ pub fn get_remittance_counter(env: &Env) -> Result<u64, ContractError> {
         .ok_or(ContractError::NotInitialized)
 }
 
-/// Stores a remittance record.
+/// Stores a remittance record, tagged with the current schema version.
+///
+/// The version travels inside the stored value as a [`VersionedRemittance`],
+/// so it always shares the record's TTL.
 ///
 /// # Arguments
 ///
@@ -421,7 +877,261 @@ pub fn get_remittance_counter(env: &Env) -> Result<u64, ContractError> {
 pub fn set_remittance(env: &Env, id: u64, remittance: &Remittance) {
     env.storage()
         .persistent()
-        .set(&DataKey::Remittance(id), remittance);
+        .set(&DataKey::Remittance(id), &VersionedRemittance::V2(remittance.clone()));
+    sync_status_index(env, id, &remittance.status);
+    sync_agent_pending_queue(env, remittance);
+}
+
+/// Keeps the remittance in its agent's pending queue exactly while it is
+/// Pending, following it across reassignments.
+fn sync_agent_pending_queue(env: &Env, remittance: &Remittance) {
+    let key = DataKey::QueuedFor(remittance.id);
+    let queued: Option<(Address, u32)> = env.storage().persistent().get(&key);
+    let wanted = (remittance.status == crate::RemittanceStatus::Pending)
+        .then(|| remittance.agent.clone());
+    if queued.as_ref().map(|(agent, _)| agent) == wanted.as_ref() {
+        return;
+    }
+
+    if let Some((agent, pos)) = queued {
+        remove_list_entry(
+            env,
+            &DataKey::AgentPendingBounds(agent.clone()),
+            |pos| DataKey::AgentPendingEntry(agent.clone(), pos),
+            pos,
+        );
+    }
+    match wanted {
+        Some(agent) => {
+            let pos = push_list_entry(
+                env,
+                &DataKey::AgentPendingBounds(agent.clone()),
+                |pos| DataKey::AgentPendingEntry(agent.clone(), pos),
+                remittance.id,
+            );
+            env.storage().persistent().set(&key, &(agent, pos));
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Returns a page of the IDs of the Pending remittances assigned to `agent`,
+/// oldest first.
+pub fn get_agent_pending_queue(env: &Env, agent: &Address, cursor: u32, limit: u32) -> Page<u64> {
+    list_page(
+        env,
+        &DataKey::AgentPendingBounds(agent.clone()),
+        |pos| DataKey::AgentPendingEntry(agent.clone(), pos),
+        cursor,
+        limit,
+    )
+}
+
+// === Keyed ID lists ===
+//
+// The status buckets and agent pending queues store one remittance ID per
+// key, so no single ledger entry grows with the list. Entries are appended at
+// the tail and removed in place, and an entry never changes position, so a
+// position cursor stays valid while the list changes. The list's live range
+// `(head, tail)` is stored separately; removals leave gaps, and leading gaps
+// are trimmed by advancing the head.
+
+/// Returns the `(head, tail)` positions of a keyed list.
+fn list_bounds(env: &Env, bounds_key: &DataKey) -> (u32, u32) {
+    env.storage().persistent().get(bounds_key).unwrap_or((0, 0))
+}
+
+/// Appends `id` to a keyed list and returns its position.
+fn push_list_entry<K>(env: &Env, bounds_key: &DataKey, entry_key: K, id: u64) -> u32
+where
+    K: Fn(u32) -> DataKey,
+{
+    let (head, tail) = list_bounds(env, bounds_key);
+    env.storage().persistent().set(&entry_key(tail), &id);
+    env.storage().persistent().set(bounds_key, &(head, tail + 1));
+    tail
+}
+
+/// Removes the entry at `pos` from a keyed list.
+///
+/// Leading gaps are trimmed at most `MAX_LIST_TRIM` at a time, so a removal
+/// costs a bounded number of reads; later removals resume the trim.
+fn remove_list_entry<K>(env: &Env, bounds_key: &DataKey, entry_key: K, pos: u32)
+where
+    K: Fn(u32) -> DataKey,
+{
+    env.storage().persistent().remove(&entry_key(pos));
+
+    let (mut head, tail) = list_bounds(env, bounds_key);
+    let trim_end = head.saturating_add(MAX_LIST_TRIM).min(tail);
+    while head < trim_end && !env.storage().persistent().has(&entry_key(head)) {
+        head += 1;
+    }
+    if head == tail {
+        env.storage().persistent().remove(bounds_key);
+    } else {
+        env.storage().persistent().set(bounds_key, &(head, tail));
+    }
+}
+
+/// Returns a page of a keyed list.
+///
+/// Gaps left by removals are skipped, so a page may hold fewer than `limit`
+/// IDs while `has_more` is still set.
+fn list_page<K>(env: &Env, bounds_key: &DataKey, entry_key: K, cursor: u32, limit: u32) -> Page<u64>
+where
+    K: Fn(u32) -> DataKey,
+{
+    let (head, tail) = list_bounds(env, bounds_key);
+    paginate_keyed(env, tail, cursor.max(head), limit, |pos| {
+        env.storage().persistent().get(&entry_key(pos))
+    })
+}
+
+/// Keeps the per-status index and the status counts in step with the status
+/// being written.
+///
+/// Each status bucket is a keyed list and each remittance records its own
+/// position, so a status change removes the ID from its old bucket and
+/// appends it to the new one in constant time. Writes that are not status
+/// changes cost a single read.
+fn sync_status_index(env: &Env, id: u64, status: &crate::RemittanceStatus) {
+    let slot: Option<(crate::RemittanceStatus, u32)> =
+        env.storage().persistent().get(&DataKey::StatusSlot(id));
+    if slot.as_ref().map(|(indexed, _)| indexed) == Some(status) {
+        return;
+    }
+
+    let mut counts = get_remittance_counts(env);
+    if let Some((old_status, pos)) = slot {
+        let count = counts.count_mut(&old_status);
+        *count = count.saturating_sub(1);
+        remove_list_entry(
+            env,
+            &DataKey::StatusBounds(old_status.clone()),
+            |pos| DataKey::StatusEntry(old_status.clone(), pos),
+            pos,
+        );
+    }
+    let pos = push_list_entry(
+        env,
+        &DataKey::StatusBounds(status.clone()),
+        |pos| DataKey::StatusEntry(status.clone(), pos),
+        id,
+    );
+    env.storage()
+        .persistent()
+        .set(&DataKey::StatusSlot(id), &(status.clone(), pos));
+
+    *counts.count_mut(status) += 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::RemittanceCounts, &counts);
+    crate::checkpoints::record(env, &counts);
+}
+
+/// Returns the `(head, tail)` positions of the `status` bucket. Every
+/// remittance in the status sits at a position in `head..tail`.
+pub fn get_status_bounds(env: &Env, status: &crate::RemittanceStatus) -> (u32, u32) {
+    list_bounds(env, &DataKey::StatusBounds(status.clone()))
+}
+
+/// Returns the remittance ID at `pos` in the `status` bucket, or `None` if
+/// the position is out of range or was vacated by a status change.
+pub fn get_status_entry(env: &Env, status: &crate::RemittanceStatus, pos: u32) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StatusEntry(status.clone(), pos))
+}
+
+/// Returns a page of the IDs of remittances currently in `status`, in the
+/// order they entered it.
+pub fn get_status_index(
+    env: &Env,
+    status: &crate::RemittanceStatus,
+    cursor: u32,
+    limit: u32,
+) -> Page<u64> {
+    list_page(
+        env,
+        &DataKey::StatusBounds(status.clone()),
+        |pos| DataKey::StatusEntry(status.clone(), pos),
+        cursor,
+        limit,
+    )
+}
+
+/// Returns the configured remittance expiry window in seconds (0 = no expiry).
+pub fn get_remittance_expiry_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::RemittanceExpiryWindow)
+        .unwrap_or(0)
+}
+
+pub fn set_remittance_expiry_window(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RemittanceExpiryWindow, &seconds);
+}
+
+/// Records a remittance at the end of its sender's history.
+pub fn append_sender_remittance(env: &Env, sender: &Address, remittance_id: u64) {
+    let count = get_sender_remittance_count(env, sender);
+    env.storage()
+        .persistent()
+        .set(&DataKey::SenderRemittance(sender.clone(), count), &remittance_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::SenderRemittanceCount(sender.clone()), &(count + 1));
+}
+
+/// Returns the number of remittances `sender` has created.
+pub fn get_sender_remittance_count(env: &Env, sender: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SenderRemittanceCount(sender.clone()))
+        .unwrap_or(0)
+}
+
+/// Returns a page of the remittance IDs created by `sender`, oldest first.
+pub fn get_sender_remittances(env: &Env, sender: &Address, cursor: u32, limit: u32) -> Page<u64> {
+    let total = get_sender_remittance_count(env, sender);
+    paginate_keyed(env, total, cursor, limit, |i| {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SenderRemittance(sender.clone(), i))
+    })
+}
+
+/// Records a remittance at the end of an agent's history. Reassigned
+/// remittances appear in the history of every agent they were assigned to.
+pub fn append_agent_remittance(env: &Env, agent: &Address, remittance_id: u64) {
+    let count = get_agent_remittance_count(env, agent);
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentRemittance(agent.clone(), count), &remittance_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentRemittanceCount(agent.clone()), &(count + 1));
+}
+
+/// Returns the number of remittances ever assigned to `agent`.
+pub fn get_agent_remittance_count(env: &Env, agent: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentRemittanceCount(agent.clone()))
+        .unwrap_or(0)
+}
+
+/// Returns a page of the remittance IDs assigned to `agent`, oldest first.
+pub fn get_agent_remittances(env: &Env, agent: &Address, cursor: u32, limit: u32) -> Page<u64> {
+    let total = get_agent_remittance_count(env, agent);
+    paginate_keyed(env, total, cursor, limit, |i| {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AgentRemittance(agent.clone(), i))
+    })
 }
 
 /// Retrieves a remittance record by ID.
@@ -435,11 +1145,40 @@ pub fn set_remittance(env: &Env, id: u64, remittance: &Remittance) {
 ///
 /// * `Ok(Remittance)` - The remittance record
 /// * `Err(ContractError::RemittanceNotFound)` - Remittance does not exist
+///
+/// Records stored in the V1 layout are upgraded to the current layout and
+/// written back, so each legacy entry is migrated at most once.
 pub fn get_remittance(env: &Env, id: u64) -> Result<Remittance, ContractError> {
-    env.storage()
+    match get_remittance_any(env, id)? {
+        VersionedRemittance::V2(remittance) => Ok(remittance),
+        VersionedRemittance::V1(legacy) => {
+            let remittance: Remittance = legacy.into();
+            set_remittance(env, id, &remittance);
+            Ok(remittance)
+        }
+    }
+}
+
+/// Retrieves a remittance record in the schema version it is stored in.
+///
+/// Unlike [`get_remittance`], this never migrates the underlying entry.
+///
+/// # Returns
+///
+/// * `Ok(VersionedRemittance)` - The stored record tagged with its schema version
+/// * `Err(ContractError::RemittanceNotFound)` - Remittance does not exist
+/// * `Err(ContractError::DataCorruption)` - The stored value matches no known layout
+pub fn get_remittance_any(env: &Env, id: u64) -> Result<VersionedRemittance, ContractError> {
+    let stored: Val = env
+        .storage()
         .persistent()
         .get(&DataKey::Remittance(id))
-        .ok_or(ContractError::RemittanceNotFound)
+        .ok_or(ContractError::RemittanceNotFound)?;
+
+    // Records written before versioning are bare V1 structs
+    VersionedRemittance::try_from_val(env, &stored)
+        .or_else(|_| RemittanceV1::try_from_val(env, &stored).map(VersionedRemittance::V1))
+        .map_err(|_| ContractError::DataCorruption)
 }
 
 /// Sets an agent's registration status.
@@ -454,114 +1193,337 @@ pub fn set_agent_registered(env: &Env, agent: &Address, registered: bool) {
         .persistent()
         .set(&DataKey::AgentRegistered(agent.clone()), &registered);
 
-    // No admin-list side-effect needed here; agent and admin lists are separate.
+    // Keep the enumerable agent list in step with the registration flag.
+    let mut agents = get_agent_list(env);
+    match (registered, agents.first_index_of(agent)) {
+        (true, None) => agents.push_back(agent.clone()),
+        (false, Some(pos)) => {
+            agents.remove(pos);
+        }
+        _ => return,
+    }
+    env.storage().persistent().set(&DataKey::AgentList, &agents);
 }
 
-/// Checks if an address is registered as an agent.
-///
-/// # Arguments
-///
-/// * `env` - The contract execution environment
-/// * `agent` - Agent address to check
-///
-/// # Returns
-///
-/// * `true` - Address is registered
-/// * `false` - Address is not registered
-pub fn is_agent_registered(env: &Env, agent: &Address) -> bool {
+/// Returns the last issued organization ID (0 if none).
+pub fn get_org_counter(env: &Env) -> u64 {
+    env.storage().instance().get(&DataKey::OrgCounter).unwrap_or(0)
+}
+
+pub fn set_org_counter(env: &Env, value: u64) {
+    env.storage().instance().set(&DataKey::OrgCounter, &value);
+}
+
+pub fn get_organization(env: &Env, org_id: u64) -> Option<crate::organization::Organization> {
     env.storage()
         .persistent()
-        .get(&DataKey::AgentRegistered(agent.clone()))
-        .unwrap_or(false)
+        .get(&DataKey::Organization(org_id))
 }
 
-/// Stores the KYC metadata hash for an agent (32-byte hash of off-chain KYC document).
-pub fn set_agent_kyc_hash(env: &Env, agent: &Address, hash: &soroban_sdk::BytesN<32>) {
+pub fn set_organization(env: &Env, org: &crate::organization::Organization) {
     env.storage()
         .persistent()
-        .set(&DataKey::AgentKycHash(agent.clone()), hash);
+        .set(&DataKey::Organization(org.id), org);
 }
 
-/// Retrieves the KYC metadata hash for an agent, if one was provided at registration.
-pub fn get_agent_kyc_hash(env: &Env, agent: &Address) -> Option<soroban_sdk::BytesN<32>> {
+pub fn get_org_member(env: &Env, org_id: u64, member: &Address) -> Option<crate::organization::OrgMember> {
     env.storage()
         .persistent()
-        .get(&DataKey::AgentKycHash(agent.clone()))
+        .get(&DataKey::OrgMember(org_id, member.clone()))
 }
 
-/// Sets the accumulated platform fees.
-///
-/// # Arguments
-///
-/// * `env` - The contract execution environment
-/// * `fees` - Total accumulated fees
-pub fn set_accumulated_fees(env: &Env, fees: i128) {
+pub fn set_org_member(env: &Env, org_id: u64, member: &Address, record: &crate::organization::OrgMember) {
     env.storage()
-        .instance()
-        .set(&DataKey::AccumulatedFees, &fees);
+        .persistent()
+        .set(&DataKey::OrgMember(org_id, member.clone()), record);
 }
 
-/// Retrieves the accumulated platform fees.
-///
-/// Returns `Ok(0)` if the counter has never been set (e.g. before the first
-/// `confirm_payout`) so that callers never see a spurious `NotInitialized`
-/// error after a `withdraw_fees` call resets the key to zero.
-///
-/// # Arguments
-///
-/// * `env` - The contract execution environment
-///
-/// # Returns
-///
-/// * `Ok(i128)` - Total accumulated fees (0 if not yet initialised)
-pub fn get_accumulated_fees(env: &Env) -> Result<i128, ContractError> {
-    Ok(env
-        .storage()
-        .instance()
-        .get(&DataKey::AccumulatedFees)
-        .unwrap_or(0))
+pub fn remove_org_member(env: &Env, org_id: u64, member: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::OrgMember(org_id, member.clone()));
 }
 
-pub fn set_accumulated_integrator_fees(env: &Env, fees: i128) {
+/// Returns the remittance IDs initiated through an organization, oldest first.
+pub fn get_org_remittances(env: &Env, org_id: u64) -> Vec<u64> {
     env.storage()
-        .instance()
-        .set(&DataKey::AccumulatedIntegratorFees, &fees);
+        .persistent()
+        .get(&DataKey::OrgRemittances(org_id))
+        .unwrap_or(Vec::new(env))
 }
 
-pub fn get_accumulated_integrator_fees(env: &Env) -> i128 {
+pub fn append_org_remittance(env: &Env, org_id: u64, remittance_id: u64) {
+    let mut ids = get_org_remittances(env, org_id);
+    ids.push_back(remittance_id);
     env.storage()
-        .instance()
-        .get(&DataKey::AccumulatedIntegratorFees)
-        .unwrap_or(0)
+        .persistent()
+        .set(&DataKey::OrgRemittances(org_id), &ids);
 }
 
-/// Checks if a settlement hash exists for duplicate detection.
-///
-/// # Arguments
-///
-/// * `env` - The contract execution environment
-/// * `remittance_id` - Remittance ID to check
-///
-/// # Returns
-///
-/// * `true` - Settlement has been executed
-/// * `false` - Settlement has not been executed
-use crate::config::{SETTLEMENT_EVENT_EMITTED_FLAG, SETTLEMENT_EXECUTED_FLAG};
+pub fn get_org_request_counter(env: &Env) -> u64 {
+    env.storage().instance().get(&DataKey::OrgRequestCounter).unwrap_or(0)
+}
 
-#[contracttype]
-#[derive(Clone)]
-pub struct LegacySettlementData {
-    pub executed: bool,
-    pub event_emitted: bool,
+pub fn set_org_request_counter(env: &Env, value: u64) {
+    env.storage().instance().set(&DataKey::OrgRequestCounter, &value);
 }
 
-#[contracttype]
-#[derive(Clone)]
-pub struct SettlementPacked {
-    pub flags: u32,
+pub fn get_org_request(env: &Env, request_id: u64) -> Option<crate::organization::OrgRemittanceRequest> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrgRequest(request_id))
 }
 
-impl SettlementPacked {
+pub fn set_org_request(env: &Env, request: &crate::organization::OrgRemittanceRequest) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::OrgRequest(request.id), request);
+}
+
+/// Returns the IDs of an organization's requests awaiting owner approval.
+pub fn get_org_pending_requests(env: &Env, org_id: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrgPendingRequests(org_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_org_pending_requests(env: &Env, org_id: u64, ids: &Vec<u64>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::OrgPendingRequests(org_id), ids);
+}
+
+/// Returns the observer contracts registered for lifecycle notifications.
+pub fn get_observers(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Observers)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Replaces the observer registry.
+pub fn set_observers(env: &Env, observers: &Vec<Address>) {
+    env.storage().instance().set(&DataKey::Observers, observers);
+}
+
+/// Returns the sender-defined tags attached to a remittance (empty if untagged).
+pub fn get_remittance_tags(env: &Env, remittance_id: u64) -> Vec<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RemittanceTags(remittance_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_remittance_tags(env: &Env, remittance_id: u64, tags: &Vec<Symbol>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RemittanceTags(remittance_id), tags);
+}
+
+/// Returns the netting pair for two agents given in canonical order.
+pub fn get_netting_pair(env: &Env, agent_a: &Address, agent_b: &Address) -> Option<crate::netting_pairs::NettingPair> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::NettingPair(agent_a.clone(), agent_b.clone()))
+}
+
+pub fn set_netting_pair(env: &Env, pair: &crate::netting_pairs::NettingPair) {
+    env.storage().persistent().set(
+        &DataKey::NettingPair(pair.agent_a.clone(), pair.agent_b.clone()),
+        pair,
+    );
+}
+
+pub fn remove_netting_pair(env: &Env, agent_a: &Address, agent_b: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::NettingPair(agent_a.clone(), agent_b.clone()));
+}
+
+/// Returns the legs of a routed remittance, or `None` for a direct remittance.
+pub fn get_remittance_route(env: &Env, remittance_id: u64) -> Option<Vec<crate::routing::RouteLeg>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RemittanceRoute(remittance_id))
+}
+
+pub fn set_remittance_route(env: &Env, remittance_id: u64, legs: &Vec<crate::routing::RouteLeg>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RemittanceRoute(remittance_id), legs);
+}
+
+pub fn get_pickup_secret_hash(env: &Env, remittance_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PickupSecretHash(remittance_id))
+}
+
+pub fn set_pickup_secret_hash(env: &Env, remittance_id: u64, hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PickupSecretHash(remittance_id), hash);
+}
+
+/// Returns the amount at or above which a pickup secret is mandatory (0 = disabled).
+pub fn get_pickup_secret_threshold(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PickupSecretThreshold)
+        .unwrap_or(0)
+}
+
+pub fn set_pickup_secret_threshold(env: &Env, threshold: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PickupSecretThreshold, &threshold);
+}
+
+/// Returns the ed25519 public key registered by an agent for payout vouchers.
+pub fn get_voucher_key(env: &Env, agent: &Address) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VoucherKey(agent.clone()))
+}
+
+pub fn set_voucher_key(env: &Env, agent: &Address, public_key: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::VoucherKey(agent.clone()), public_key);
+}
+
+pub fn remove_voucher_key(env: &Env, agent: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::VoucherKey(agent.clone()));
+}
+
+pub fn is_voucher_nonce_used(env: &Env, agent: &Address, nonce: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::VoucherNonceUsed(agent.clone(), nonce))
+}
+
+pub fn mark_voucher_nonce_used(env: &Env, agent: &Address, nonce: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::VoucherNonceUsed(agent.clone(), nonce), &true);
+}
+
+/// Returns the addresses of all currently registered agents, in registration order.
+pub fn get_agent_list(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentList)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Checks if an address is registered as an agent.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `agent` - Agent address to check
+///
+/// # Returns
+///
+/// * `true` - Address is registered
+/// * `false` - Address is not registered
+pub fn is_agent_registered(env: &Env, agent: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentRegistered(agent.clone()))
+        .unwrap_or(false)
+}
+
+/// Stores the KYC metadata hash for an agent (32-byte hash of off-chain KYC document).
+pub fn set_agent_kyc_hash(env: &Env, agent: &Address, hash: &soroban_sdk::BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentKycHash(agent.clone()), hash);
+}
+
+/// Retrieves the KYC metadata hash for an agent, if one was provided at registration.
+pub fn get_agent_kyc_hash(env: &Env, agent: &Address) -> Option<soroban_sdk::BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentKycHash(agent.clone()))
+}
+
+/// Sets the accumulated platform fees.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `fees` - Total accumulated fees
+pub fn set_accumulated_fees(env: &Env, fees: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AccumulatedFees, &fees);
+}
+
+/// Retrieves the accumulated platform fees.
+///
+/// Returns `Ok(0)` if the counter has never been set (e.g. before the first
+/// `confirm_payout`) so that callers never see a spurious `NotInitialized`
+/// error after a `withdraw_fees` call resets the key to zero.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+///
+/// # Returns
+///
+/// * `Ok(i128)` - Total accumulated fees (0 if not yet initialised)
+pub fn get_accumulated_fees(env: &Env) -> Result<i128, ContractError> {
+    Ok(env
+        .storage()
+        .instance()
+        .get(&DataKey::AccumulatedFees)
+        .unwrap_or(0))
+}
+
+pub fn set_accumulated_integrator_fees(env: &Env, fees: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AccumulatedIntegratorFees, &fees);
+}
+
+pub fn get_accumulated_integrator_fees(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AccumulatedIntegratorFees)
+        .unwrap_or(0)
+}
+
+/// Checks if a settlement hash exists for duplicate detection.
+///
+/// # Arguments
+///
+/// * `env` - The contract execution environment
+/// * `remittance_id` - Remittance ID to check
+///
+/// # Returns
+///
+/// * `true` - Settlement has been executed
+/// * `false` - Settlement has not been executed
+use crate::config::{SETTLEMENT_EVENT_EMITTED_FLAG, SETTLEMENT_EXECUTED_FLAG};
+
+#[contracttype]
+#[derive(Clone)]
+pub struct LegacySettlementData {
+    pub executed: bool,
+    pub event_emitted: bool,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SettlementPacked {
+    pub flags: u32,
+}
+
+impl SettlementPacked {
     fn new(executed: bool, event_emitted: bool) -> Self {
         let mut flags = 0;
         if executed {
@@ -573,22 +1535,10 @@ impl SettlementPacked {
         Self { flags }
     }
 
-    fn executed(&self) -> bool {
-        (self.flags & SETTLEMENT_EXECUTED_FLAG) != 0
-    }
-
     fn event_emitted(&self) -> bool {
         (self.flags & SETTLEMENT_EVENT_EMITTED_FLAG) != 0
     }
 
-    fn set_executed(&mut self, value: bool) {
-        if value {
-            self.flags |= SETTLEMENT_EXECUTED_FLAG;
-        } else {
-            self.flags &= !SETTLEMENT_EXECUTED_FLAG;
-        }
-    }
-
     fn set_event_emitted(&mut self, value: bool) {
         if value {
             self.flags |= SETTLEMENT_EVENT_EMITTED_FLAG;
@@ -643,21 +1593,28 @@ fn load_or_migrate_settlement_packed(env: &Env, remittance_id: u64) -> Settlemen
     packed
 }
 
-/// Checks if a settlement has already been executed (duplicate detection).
-pub fn has_settlement_hash(env: &Env, remittance_id: u64) -> bool {
-    let data = load_or_migrate_settlement_packed(env, remittance_id);
-    data.executed()
-}
-
-/// Marks a settlement as executed for duplicate prevention.
-pub fn set_settlement_hash(env: &Env, remittance_id: u64) {
-    let key = DataKey::SettlementPacked(remittance_id);
-    let mut data = load_or_migrate_settlement_packed(env, remittance_id);
-    if data.executed() {
-        return; // Skip write if already set
+/// Removes the per-remittance settlement flag entries written by the former
+/// hash-based duplicate tracking.
+///
+/// Duplicate settlement is now detected from the remittance record itself
+/// (see `Remittance::is_settlement_executed`), so these entries only cost rent.
+/// Returns `true` if any entry was removed.
+pub fn remove_settlement_entries(env: &Env, remittance_id: u64) -> bool {
+    let storage = env.storage().persistent();
+    let keys = [
+        DataKey::SettlementPacked(remittance_id),
+        DataKey::SettlementHash(remittance_id),
+        DataKey::SettlementData(remittance_id),
+        DataKey::SettlementEventEmitted(remittance_id),
+    ];
+    let mut removed = false;
+    for key in keys.iter() {
+        if storage.has(key) {
+            storage.remove(key);
+            removed = true;
+        }
     }
-    data.set_executed(true);
-    env.storage().persistent().set(&key, &data);
+    removed
 }
 
 pub fn is_paused(env: &Env) -> bool {
@@ -812,6 +1769,7 @@ pub fn check_settlement_rate_limit(env: &Env, sender: &Address) -> Result<(), Co
 }
 
 pub fn set_daily_limit(env: &Env, currency: &String, country: &String, limit: i128) {
+    crate::scheduled_config::discard_due_limit_changes(env, currency, country);
     let daily_limit = DailyLimit {
         currency: currency.clone(),
         country: country.clone(),
@@ -824,6 +1782,13 @@ pub fn set_daily_limit(env: &Env, currency: &String, country: &String, limit: i1
 }
 
 pub fn get_daily_limit(env: &Env, currency: &String, country: &String) -> Option<DailyLimit> {
+    if let Some(limit) = crate::scheduled_config::due_daily_limit(env, currency, country) {
+        return Some(DailyLimit {
+            currency: currency.clone(),
+            country: country.clone(),
+            limit,
+        });
+    }
     env.storage()
         .persistent()
         .get(&DataKey::DailyLimit(currency.clone(), country.clone()))
@@ -1230,44 +2195,148 @@ pub fn set_agent_stats(env: &Env, agent: &Address, stats: &AgentStats) {
         .set(&DataKey::AgentStats(agent.clone()), stats);
 }
 
-pub fn compute_agent_reputation(stats: &AgentStats) -> u32 {
-    let total = stats.total_settlements;
-    let successful = total.saturating_sub(stats.failed_settlements);
-    let success_score = if total == 0 {
-        100
-    } else {
-        successful
-            .saturating_mul(100)
-            .checked_div(total)
-            .unwrap_or(0)
-    };
+/// Returns an agent's tier; agents never assigned one are Basic.
+pub fn get_agent_tier(env: &Env, agent: &Address) -> crate::agent_tiers::AgentTier {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentTier(agent.clone()))
+        .unwrap_or(crate::agent_tiers::AgentTier::Basic)
+}
 
-    let avg_time = if total == 0 {
-        0
-    } else {
-        stats.total_settlement_time / (total as u64)
-    };
-    let time_score: u32 = if avg_time <= 3600 {
-        100
-    } else if avg_time <= 7200 {
-        80
-    } else if avg_time <= 14400 {
-        60
-    } else if avg_time <= 28800 {
-        40
-    } else if avg_time <= 43200 {
-        20
-    } else {
-        0
-    };
+pub fn set_agent_tier(env: &Env, agent: &Address, tier: crate::agent_tiers::AgentTier) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentTier(agent.clone()), &tier);
+}
 
-    let dispute_score: u32 = match stats.dispute_count {
-        0 => 100,
-        1 => 75,
-        2 => 50,
-        3 => 25,
-        _ => 0,
-    };
+pub fn get_agent_pending_value(env: &Env, agent: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentPendingValue(agent.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_agent_pending_value(env: &Env, agent: &Address, value: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentPendingValue(agent.clone()), &value);
+}
+
+pub fn is_agent_kyb_verified(env: &Env, agent: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentKybVerified(agent.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_agent_kyb_verified(env: &Env, agent: &Address, verified: bool) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentKybVerified(agent.clone()), &verified);
+}
+
+/// Returns the `(day, value)` assigned to an unverified agent on its latest active day.
+pub fn get_unverified_agent_volume(env: &Env, agent: &Address) -> (u64, i128) {
+    env.storage()
+        .temporary()
+        .get(&DataKey::UnverifiedAgentVolume(agent.clone()))
+        .unwrap_or((0, 0))
+}
+
+pub fn set_unverified_agent_volume(env: &Env, agent: &Address, day: u64, value: i128) {
+    env.storage()
+        .temporary()
+        .set(&DataKey::UnverifiedAgentVolume(agent.clone()), &(day, value));
+}
+
+pub fn get_agent_pending_amount(env: &Env, remittance_id: u64) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentPendingAmount(remittance_id))
+}
+
+pub fn set_agent_pending_amount(env: &Env, remittance_id: u64, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentPendingAmount(remittance_id), &amount);
+}
+
+pub fn remove_agent_pending_amount(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::AgentPendingAmount(remittance_id));
+}
+
+/// Returns performance counters for an agent, or platform-wide when `agent` is `None`.
+pub fn get_performance_counters(
+    env: &Env,
+    agent: Option<&Address>,
+) -> crate::performance::PerformanceCounters {
+    let counters = match agent {
+        Some(agent) => env
+            .storage()
+            .persistent()
+            .get(&DataKey::AgentPerformance(agent.clone())),
+        None => env.storage().instance().get(&DataKey::PlatformPerformance),
+    };
+    counters.unwrap_or_else(|| crate::performance::PerformanceCounters::new(env))
+}
+
+pub fn set_performance_counters(
+    env: &Env,
+    agent: Option<&Address>,
+    counters: &crate::performance::PerformanceCounters,
+) {
+    match agent {
+        Some(agent) => env
+            .storage()
+            .persistent()
+            .set(&DataKey::AgentPerformance(agent.clone()), counters),
+        None => env
+            .storage()
+            .instance()
+            .set(&DataKey::PlatformPerformance, counters),
+    }
+}
+
+pub fn compute_agent_reputation(stats: &AgentStats) -> u32 {
+    let total = stats.total_settlements;
+    let successful = total.saturating_sub(stats.failed_settlements);
+    let success_score = if total == 0 {
+        100
+    } else {
+        successful
+            .saturating_mul(100)
+            .checked_div(total)
+            .unwrap_or(0)
+    };
+
+    let avg_time = if total == 0 {
+        0
+    } else {
+        stats.total_settlement_time / (total as u64)
+    };
+    let time_score: u32 = if avg_time <= 3600 {
+        100
+    } else if avg_time <= 7200 {
+        80
+    } else if avg_time <= 14400 {
+        60
+    } else if avg_time <= 28800 {
+        40
+    } else if avg_time <= 43200 {
+        20
+    } else {
+        0
+    };
+
+    let dispute_score: u32 = match stats.dispute_count {
+        0 => 100,
+        1 => 75,
+        2 => 50,
+        3 => 25,
+        _ => 0,
+    };
 
     let weighted = success_score.saturating_mul(50u32)
         + time_score.saturating_mul(25u32)
@@ -1300,6 +2369,21 @@ pub fn set_escrow(env: &Env, transfer_id: u64, escrow: &crate::Escrow) {
         .set(&DataKey::Escrow(transfer_id), escrow);
 }
 
+/// Returns when an escrow was created. Escrows created before creation times
+/// were recorded report 0.
+pub fn get_escrow_created_at(env: &Env, transfer_id: u64) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EscrowCreatedAt(transfer_id))
+        .unwrap_or(0)
+}
+
+pub fn set_escrow_created_at(env: &Env, transfer_id: u64, created_at: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::EscrowCreatedAt(transfer_id), &created_at);
+}
+
 // === Role-Based Authorization ===
 
 /// Assigns a role to an address
@@ -1342,6 +2426,237 @@ pub fn require_agent_authorized(env: &Env, address: &Address) -> Result<(), Cont
     Ok(())
 }
 
+/// Returns the sub-operator record for `operator`, if any.
+pub fn get_sub_operator(env: &Env, operator: &Address) -> Option<crate::SubOperator> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SubOperator(operator.clone()))
+}
+
+/// Stores a sub-operator record and adds it to its agent's list.
+pub fn set_sub_operator(env: &Env, operator: &Address, record: &crate::SubOperator) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SubOperator(operator.clone()), record);
+
+    let mut operators = get_agent_sub_operators(env, &record.agent);
+    if !operators.contains(operator) {
+        operators.push_back(operator.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::AgentSubOperators(record.agent.clone()), &operators);
+    }
+}
+
+/// Removes a sub-operator of `agent`.
+///
+/// # Errors
+///
+/// * `ContractError::SubOperatorNotFound` - `operator` is not a sub-operator of `agent`
+pub fn remove_sub_operator(env: &Env, agent: &Address, operator: &Address) -> Result<(), ContractError> {
+    match get_sub_operator(env, operator) {
+        Some(record) if record.agent == *agent => {}
+        _ => return Err(ContractError::SubOperatorNotFound),
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::SubOperator(operator.clone()));
+
+    let mut operators = get_agent_sub_operators(env, agent);
+    if let Some(pos) = operators.first_index_of(operator) {
+        operators.remove(pos);
+        env.storage()
+            .persistent()
+            .set(&DataKey::AgentSubOperators(agent.clone()), &operators);
+    }
+    Ok(())
+}
+
+/// Returns the sub-operators authorized by `agent`.
+pub fn get_agent_sub_operators(env: &Env, agent: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentSubOperators(agent.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the platform operator designated for managed auto-settlement.
+pub fn get_settlement_operator(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::SettlementOperator)
+}
+
+pub fn set_settlement_operator(env: &Env, operator: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SettlementOperator, operator);
+}
+
+/// Returns an agent's auto-settlement opt-in, if enabled.
+pub fn get_auto_settlement(env: &Env, agent: &Address) -> Option<crate::AutoSettlement> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AutoSettlement(agent.clone()))
+}
+
+pub fn set_auto_settlement(env: &Env, agent: &Address, config: &crate::AutoSettlement) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AutoSettlement(agent.clone()), config);
+}
+
+pub fn remove_auto_settlement(env: &Env, agent: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::AutoSettlement(agent.clone()));
+}
+
+/// Checks that the platform operator may auto-settle `amount` for `agent` and
+/// charges it against the agent's opt-in limits. The caller authenticates the
+/// operator.
+fn charge_auto_settlement(
+    env: &Env,
+    operator: &Address,
+    agent: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let mut config = match get_auto_settlement(env, agent) {
+        Some(config) if config.operator == *operator => config,
+        _ => return Err(ContractError::AutoSettlementNotEnabled),
+    };
+    if !is_agent_registered(env, agent) {
+        return Err(ContractError::AgentNotRegistered);
+    }
+
+    if amount > config.max_per_remittance {
+        return Err(ContractError::AutoSettlementLimitExceeded);
+    }
+    let now = env.ledger().timestamp();
+    if now >= config.window_start.saturating_add(crate::config::DAILY_LIMIT_WINDOW_SECONDS) {
+        config.window_start = now;
+        config.used_in_window = 0;
+    }
+    let used = config
+        .used_in_window
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    if used > config.daily_limit {
+        return Err(ContractError::AutoSettlementLimitExceeded);
+    }
+    config.used_in_window = used;
+    set_auto_settlement(env, agent, &config);
+    Ok(())
+}
+
+/// Authorizes `caller` to act for `agent` on a payout of `amount`.
+///
+/// The agent itself must be registered. The platform settlement operator is
+/// accepted for agents that opted into auto-settlement, within their limits.
+/// Any other caller must be a sub-operator of a registered agent and has
+/// `amount` charged against its rolling 24-hour limit. In every case `caller`
+/// must authenticate.
+pub fn require_agent_or_sub_operator(
+    env: &Env,
+    caller: &Address,
+    agent: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    check_agent_or_sub_operator(env, caller, agent, amount)?;
+    caller.require_auth();
+    Ok(())
+}
+
+/// Same checks and limit charges as [`require_agent_or_sub_operator`] without
+/// authenticating `caller`.
+///
+/// For batch paths that authenticate `caller` once for the whole call: an
+/// address may only be authorized once per invocation, so repeating
+/// `require_auth` per entry would make custom account wallets sign, and be
+/// charged for, every entry separately.
+pub fn check_agent_or_sub_operator(
+    env: &Env,
+    caller: &Address,
+    agent: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    if caller == agent {
+        if !is_agent_registered(env, agent) {
+            return Err(ContractError::AgentNotRegistered);
+        }
+        return Ok(());
+    }
+    if get_settlement_operator(env).as_ref() == Some(caller) {
+        return charge_auto_settlement(env, caller, agent, amount);
+    }
+
+    let mut record = match get_sub_operator(env, caller) {
+        Some(record) if record.agent == *agent => record,
+        _ => return Err(ContractError::Unauthorized),
+    };
+    if !is_agent_registered(env, agent) {
+        return Err(ContractError::AgentNotRegistered);
+    }
+
+    let now = env.ledger().timestamp();
+    if now >= record.window_start.saturating_add(crate::config::DAILY_LIMIT_WINDOW_SECONDS) {
+        record.window_start = now;
+        record.used_in_window = 0;
+    }
+    let used = record
+        .used_in_window
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    if used > record.daily_limit {
+        return Err(ContractError::SubOperatorLimitExceeded);
+    }
+    record.used_in_window = used;
+    env.storage()
+        .persistent()
+        .set(&DataKey::SubOperator(caller.clone()), &record);
+    Ok(())
+}
+
+/// Requires that the caller has ClaimResolver role
+pub fn require_role_claim_resolver(env: &Env, address: &Address) -> Result<(), ContractError> {
+    if !has_role(env, address, &crate::Role::ClaimResolver) {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Requires that the caller has AnchorOperator role
+pub fn require_role_anchor_operator(env: &Env, address: &Address) -> Result<(), ContractError> {
+    if !has_role(env, address, &crate::Role::AnchorOperator) {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Requires that the caller has Compliance role
+pub fn require_role_compliance(env: &Env, address: &Address) -> Result<(), ContractError> {
+    if !has_role(env, address, &crate::Role::Compliance) {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Requires authentication from `address` and that it is an admin or holds `role`.
+///
+/// Lets operational duties be delegated to keys that do not hold the admin role.
+pub fn require_admin_or_role(
+    env: &Env,
+    address: &Address,
+    role: &crate::Role,
+) -> Result<(), ContractError> {
+    address.require_auth();
+    let allowed = is_admin(env, address)
+        || has_role(env, address, &crate::Role::Admin)
+        || has_role(env, address, role);
+    if !allowed {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
 /// Requires that the caller has Settler role
 pub fn require_role_settler(env: &Env, address: &Address) -> Result<(), ContractError> {
     if !has_role(env, address, &crate::Role::Settler) {
@@ -1389,6 +2704,9 @@ pub fn set_transfer_state(
 
 /// Gets the current fee strategy
 pub fn get_fee_strategy(env: &Env) -> crate::FeeStrategy {
+    if let Some(fee_bps) = crate::scheduled_config::due_platform_fee_bps(env) {
+        return crate::FeeStrategy::Percentage(fee_bps);
+    }
     env.storage()
         .instance()
         .get(&DataKey::FeeStrategy)
@@ -1397,68 +2715,229 @@ pub fn get_fee_strategy(env: &Env) -> crate::FeeStrategy {
 
 /// Sets the fee strategy (admin only)
 pub fn set_fee_strategy(env: &Env, strategy: &crate::FeeStrategy) {
+    crate::scheduled_config::discard_due_fee_changes(env);
     env.storage()
         .instance()
         .set(&DataKey::FeeStrategy, strategy);
 }
 
-// === Protocol Fee Management ===
-
-/// Maximum protocol fee (200 bps = 2%)
-pub const MAX_PROTOCOL_FEE_BPS: u32 = 200;
+/// Returns the current fee-change guardrail window, if one has been opened.
+pub fn get_fee_change_window(env: &Env) -> Option<crate::fee_service::FeeChangeWindow> {
+    env.storage().instance().get(&DataKey::FeeChangeWindow)
+}
 
-/// Gets the protocol fee in basis points
-pub fn get_protocol_fee_bps(env: &Env) -> u32 {
+pub fn set_fee_change_window(env: &Env, window: &crate::fee_service::FeeChangeWindow) {
     env.storage()
         .instance()
-        .get(&DataKey::ProtocolFeeBps)
-        .unwrap_or(0)
+        .set(&DataKey::FeeChangeWindow, window);
 }
 
-/// Sets the protocol fee in basis points (max 200 bps)
-pub fn set_protocol_fee_bps(env: &Env, fee_bps: u32) -> Result<(), ContractError> {
-    if fee_bps > MAX_PROTOCOL_FEE_BPS {
-        return Err(ContractError::InvalidFeeBps);
-    }
+/// Returns the expiry-weighted fee slope, if configured.
+pub fn get_expiry_fee_config(env: &Env) -> Option<crate::fee_service::ExpiryFeeConfig> {
+    env.storage().instance().get(&DataKey::ExpiryFeeConfig)
+}
+
+/// Sets the expiry-weighted fee slope (admin only)
+pub fn set_expiry_fee_config(env: &Env, config: &crate::fee_service::ExpiryFeeConfig) {
     env.storage()
         .instance()
-        .set(&DataKey::ProtocolFeeBps, &fee_bps);
-    Ok(())
+        .set(&DataKey::ExpiryFeeConfig, config);
 }
 
-/// Gets the treasury address
-pub fn get_treasury(env: &Env) -> Result<Address, ContractError> {
+/// Returns the expiry-weighted fee charged on a remittance (0 if none).
+pub fn get_remittance_expiry_fee(env: &Env, remittance_id: u64) -> i128 {
     env.storage()
-        .instance()
-        .get(&DataKey::Treasury)
-        .ok_or(ContractError::NotInitialized)
+        .persistent()
+        .get(&DataKey::ExpiryFee(remittance_id))
+        .unwrap_or(0)
 }
 
-/// Sets the treasury address
-pub fn set_treasury(env: &Env, treasury: &Address) {
-    env.storage().instance().set(&DataKey::Treasury, treasury);
+pub fn set_remittance_expiry_fee(env: &Env, remittance_id: u64, fee: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ExpiryFee(remittance_id), &fee);
 }
 
-// === Fee Corridor Management ===
+// === Corridor Utilization ===
 
-/// Sets a fee corridor configuration for a country pair
-pub fn set_fee_corridor(env: &Env, corridor: &crate::fee_service::FeeCorridor) {
-    let key = DataKey::FeeCorridor(corridor.from_country.clone(), corridor.to_country.clone());
-    env.storage().persistent().set(&key, corridor);
+pub fn get_utilization_fee_bounds(
+    env: &Env,
+    from_country: &String,
+    to_country: &String,
+) -> Option<crate::corridor_utilization::UtilizationFeeBounds> {
+    env.storage().persistent().get(&DataKey::UtilizationFeeBounds(
+        from_country.clone(),
+        to_country.clone(),
+    ))
 }
 
-/// Gets a fee corridor configuration for a country pair
-pub fn get_fee_corridor(
+pub fn set_utilization_fee_bounds(
     env: &Env,
     from_country: &String,
     to_country: &String,
-) -> Option<crate::fee_service::FeeCorridor> {
-    let key = DataKey::FeeCorridor(from_country.clone(), to_country.clone());
-    env.storage().persistent().get(&key)
+    bounds: &crate::corridor_utilization::UtilizationFeeBounds,
+) {
+    env.storage().persistent().set(
+        &DataKey::UtilizationFeeBounds(from_country.clone(), to_country.clone()),
+        bounds,
+    );
 }
 
-/// Removes a fee corridor configuration
-pub fn remove_fee_corridor(env: &Env, from_country: &String, to_country: &String) {
+pub fn remove_utilization_fee_bounds(env: &Env, from_country: &String, to_country: &String) {
+    env.storage().persistent().remove(&DataKey::UtilizationFeeBounds(
+        from_country.clone(),
+        to_country.clone(),
+    ));
+}
+
+/// Returns a corridor's registered capacity and pending volume (zero if untracked).
+pub fn get_corridor_liquidity(
+    env: &Env,
+    from_country: &String,
+    to_country: &String,
+) -> crate::corridor_utilization::CorridorLiquidity {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CorridorLiquidity(from_country.clone(), to_country.clone()))
+        .unwrap_or_default()
+}
+
+pub fn set_corridor_liquidity(
+    env: &Env,
+    from_country: &String,
+    to_country: &String,
+    liquidity: &crate::corridor_utilization::CorridorLiquidity,
+) {
+    env.storage().persistent().set(
+        &DataKey::CorridorLiquidity(from_country.clone(), to_country.clone()),
+        liquidity,
+    );
+}
+
+/// Returns the agents that have registered payout capacity on a corridor.
+pub fn get_corridor_agents(env: &Env, from_country: &String, to_country: &String) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CorridorAgents(from_country.clone(), to_country.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_corridor_agents(
+    env: &Env,
+    from_country: &String,
+    to_country: &String,
+    agents: &Vec<Address>,
+) {
+    env.storage().persistent().set(
+        &DataKey::CorridorAgents(from_country.clone(), to_country.clone()),
+        agents,
+    );
+}
+
+pub fn get_agent_corridor_capacity(
+    env: &Env,
+    agent: &Address,
+    from_country: &String,
+    to_country: &String,
+) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentCorridorCapacity(
+            agent.clone(),
+            from_country.clone(),
+            to_country.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+pub fn set_agent_corridor_capacity(
+    env: &Env,
+    agent: &Address,
+    from_country: &String,
+    to_country: &String,
+    capacity: i128,
+) {
+    env.storage().persistent().set(
+        &DataKey::AgentCorridorCapacity(agent.clone(), from_country.clone(), to_country.clone()),
+        &capacity,
+    );
+}
+
+pub fn get_corridor_pending_amount(env: &Env, remittance_id: u64) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CorridorPendingAmount(remittance_id))
+}
+
+pub fn set_corridor_pending_amount(env: &Env, remittance_id: u64, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CorridorPendingAmount(remittance_id), &amount);
+}
+
+pub fn remove_corridor_pending_amount(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CorridorPendingAmount(remittance_id));
+}
+
+// === Protocol Fee Management ===
+
+/// Maximum protocol fee (200 bps = 2%)
+pub const MAX_PROTOCOL_FEE_BPS: u32 = 200;
+
+/// Gets the protocol fee in basis points
+pub fn get_protocol_fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProtocolFeeBps)
+        .unwrap_or(0)
+}
+
+/// Sets the protocol fee in basis points (max 200 bps)
+pub fn set_protocol_fee_bps(env: &Env, fee_bps: u32) -> Result<(), ContractError> {
+    if fee_bps > MAX_PROTOCOL_FEE_BPS {
+        return Err(ContractError::InvalidFeeBps);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::ProtocolFeeBps, &fee_bps);
+    Ok(())
+}
+
+/// Gets the treasury address
+pub fn get_treasury(env: &Env) -> Result<Address, ContractError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Treasury)
+        .ok_or(ContractError::NotInitialized)
+}
+
+/// Sets the treasury address
+pub fn set_treasury(env: &Env, treasury: &Address) {
+    env.storage().instance().set(&DataKey::Treasury, treasury);
+}
+
+// === Fee Corridor Management ===
+
+/// Sets a fee corridor configuration for a country pair
+pub fn set_fee_corridor(env: &Env, corridor: &crate::fee_service::FeeCorridor) {
+    let key = DataKey::FeeCorridor(corridor.from_country.clone(), corridor.to_country.clone());
+    env.storage().persistent().set(&key, corridor);
+}
+
+/// Gets a fee corridor configuration for a country pair
+pub fn get_fee_corridor(
+    env: &Env,
+    from_country: &String,
+    to_country: &String,
+) -> Option<crate::fee_service::FeeCorridor> {
+    let key = DataKey::FeeCorridor(from_country.clone(), to_country.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Removes a fee corridor configuration
+pub fn remove_fee_corridor(env: &Env, from_country: &String, to_country: &String) {
     let key = DataKey::FeeCorridor(from_country.clone(), to_country.clone());
     env.storage().persistent().remove(&key);
 }
@@ -1500,6 +2979,21 @@ pub fn set_idempotency_ttl(env: &Env, ttl_seconds: u64) {
         .set(&DataKey::IdempotencyTTL, &ttl_seconds);
 }
 
+/// Gets the expiry tolerance in seconds (0 if not configured)
+pub fn get_expiry_tolerance(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ExpiryTolerance)
+        .unwrap_or(0)
+}
+
+/// Sets the expiry tolerance (admin only)
+pub fn set_expiry_tolerance(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ExpiryTolerance, &seconds);
+}
+
 /// Removes an idempotency record (called on terminal state transition)
 pub fn remove_idempotency_record(env: &Env, key: &String) {
     env.storage()
@@ -1805,3 +3299,1341 @@ pub fn remove_admin_from_list(env: &Env, admin: &Address) {
         .instance()
         .set(&DataKey::GovernanceAdminList, &new_list);
 }
+
+pub fn get_insurance_policy(env: &Env, remittance_id: u64) -> Option<crate::insurance::InsurancePolicy> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InsurancePolicy(remittance_id))
+}
+
+pub fn set_insurance_policy(env: &Env, remittance_id: u64, policy: &crate::insurance::InsurancePolicy) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::InsurancePolicy(remittance_id), policy);
+}
+
+pub fn get_insurance_fund(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InsuranceFund(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_insurance_fund(env: &Env, token: &Address, balance: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::InsuranceFund(token.clone()), &balance);
+}
+
+pub fn get_insurance_claim(env: &Env, remittance_id: u64) -> Option<crate::insurance::InsuranceClaim> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InsuranceClaim(remittance_id))
+}
+
+pub fn set_insurance_claim(env: &Env, remittance_id: u64, claim: &crate::insurance::InsuranceClaim) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::InsuranceClaim(remittance_id), claim);
+}
+
+/// Returns the remittance IDs a sender has filed claims for, oldest first.
+pub fn get_sender_claims(env: &Env, sender: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SenderClaims(sender.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn append_sender_claim(env: &Env, sender: &Address, remittance_id: u64) {
+    let mut claims = get_sender_claims(env, sender);
+    claims.push_back(remittance_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::SenderClaims(sender.clone()), &claims);
+}
+
+pub fn get_cause(env: &Env, cause: &Address) -> Option<crate::causes::Cause> {
+    env.storage().persistent().get(&DataKey::Cause(cause.clone()))
+}
+
+pub fn set_cause(env: &Env, cause: &Address, info: &crate::causes::Cause) {
+    env.storage().persistent().set(&DataKey::Cause(cause.clone()), info);
+}
+
+pub fn get_cause_balance(env: &Env, cause: &Address, token: &Address) -> crate::causes::CauseBalance {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CauseBalance(cause.clone(), token.clone()))
+        .unwrap_or_default()
+}
+
+pub fn set_cause_balance(
+    env: &Env,
+    cause: &Address,
+    token: &Address,
+    balance: &crate::causes::CauseBalance,
+) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CauseBalance(cause.clone(), token.clone()), balance);
+}
+
+pub fn get_donation_pledge(env: &Env, remittance_id: u64) -> Option<crate::causes::DonationPledge> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DonationPledge(remittance_id))
+}
+
+pub fn set_donation_pledge(env: &Env, remittance_id: u64, pledge: &crate::causes::DonationPledge) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DonationPledge(remittance_id), pledge);
+}
+
+pub fn get_savings_share(env: &Env, recipient: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SavingsShare(recipient.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_savings_share(env: &Env, recipient: &Address, bps: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SavingsShare(recipient.clone()), &bps);
+}
+
+pub fn get_savings_balance(env: &Env, owner: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SavingsBalance(owner.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_savings_balance(env: &Env, owner: &Address, token: &Address, balance: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SavingsBalance(owner.clone(), token.clone()), &balance);
+}
+
+pub fn get_savings_goal_counter(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SavingsGoalCounter)
+        .unwrap_or(0)
+}
+
+pub fn set_savings_goal_counter(env: &Env, value: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SavingsGoalCounter, &value);
+}
+
+pub fn get_savings_goal(env: &Env, goal_id: u64) -> Option<crate::savings::SavingsGoal> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SavingsGoal(goal_id))
+}
+
+pub fn set_savings_goal(env: &Env, goal: &crate::savings::SavingsGoal) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SavingsGoal(goal.id), goal);
+}
+
+pub fn get_owner_savings_goals(env: &Env, owner: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OwnerSavingsGoals(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_owner_savings_goals(env: &Env, owner: &Address, goals: &Vec<u64>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::OwnerSavingsGoals(owner.clone()), goals);
+}
+
+pub fn append_owner_savings_goal(env: &Env, owner: &Address, goal_id: u64) {
+    let mut goals = get_owner_savings_goals(env, owner);
+    goals.push_back(goal_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::OwnerSavingsGoals(owner.clone()), &goals);
+}
+
+pub fn get_agent_stake(env: &Env, agent: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentStake(agent.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_agent_stake(env: &Env, agent: &Address, token: &Address, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentStake(agent.clone(), token.clone()), &amount);
+}
+
+pub fn get_advance_pool(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AdvancePool(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_advance_pool(env: &Env, token: &Address, balance: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AdvancePool(token.clone()), &balance);
+}
+
+pub fn get_agent_advance(
+    env: &Env,
+    agent: &Address,
+    token: &Address,
+) -> Option<crate::advances::AgentAdvance> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentAdvance(agent.clone(), token.clone()))
+}
+
+pub fn set_agent_advance(
+    env: &Env,
+    agent: &Address,
+    token: &Address,
+    advance: &crate::advances::AgentAdvance,
+) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentAdvance(agent.clone(), token.clone()), advance);
+}
+
+pub fn remove_agent_advance(env: &Env, agent: &Address, token: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::AgentAdvance(agent.clone(), token.clone()));
+}
+
+pub fn get_activity_summary(
+    env: &Env,
+    party: &Address,
+    role: crate::credit_signals::CreditParty,
+) -> Option<crate::credit_signals::ActivitySummary> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ActivitySummary(party.clone(), role))
+}
+
+pub fn set_activity_summary(
+    env: &Env,
+    party: &Address,
+    role: crate::credit_signals::CreditParty,
+    summary: &crate::credit_signals::ActivitySummary,
+) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ActivitySummary(party.clone(), role), summary);
+}
+
+pub fn remove_activity_summary(env: &Env, party: &Address, role: crate::credit_signals::CreditParty) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ActivitySummary(party.clone(), role));
+}
+
+pub fn get_private_remittance_counter(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PrivateRemittanceCounter)
+        .unwrap_or(0)
+}
+
+pub fn set_private_remittance_counter(env: &Env, value: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PrivateRemittanceCounter, &value);
+}
+
+pub fn get_private_remittance(
+    env: &Env,
+    id: u64,
+) -> Option<crate::amount_commitment::PrivateRemittance> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PrivateRemittance(id))
+}
+
+pub fn set_private_remittance(env: &Env, remittance: &crate::amount_commitment::PrivateRemittance) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PrivateRemittance(remittance.id), remittance);
+}
+
+pub fn get_encrypted_memo(env: &Env, remittance_id: u64) -> Option<Bytes> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EncryptedMemo(remittance_id))
+}
+
+pub fn set_encrypted_memo(env: &Env, remittance_id: u64, ciphertext: &Bytes) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::EncryptedMemo(remittance_id), ciphertext);
+}
+
+pub fn is_minimal_disclosure(env: &Env, remittance_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::MinimalDisclosure(remittance_id))
+}
+
+pub fn set_minimal_disclosure(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::MinimalDisclosure(remittance_id), &true);
+}
+
+pub fn get_hashed_remittance_counter(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::HashedRemittanceCounter)
+        .unwrap_or(0)
+}
+
+pub fn set_hashed_remittance_counter(env: &Env, value: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::HashedRemittanceCounter, &value);
+}
+
+pub fn get_hashed_remittance(
+    env: &Env,
+    id: u64,
+) -> Option<crate::hashed_records::HashedRemittance> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HashedRemittance(id))
+}
+
+pub fn set_hashed_remittance(env: &Env, remittance: &crate::hashed_records::HashedRemittance) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::HashedRemittance(remittance.id), remittance);
+}
+
+pub fn is_hash_only_corridor(env: &Env, from_country: &String, to_country: &String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::HashOnlyCorridor(from_country.clone(), to_country.clone()))
+}
+
+pub fn set_hash_only_corridor(env: &Env, from_country: &String, to_country: &String, enabled: bool) {
+    let key = DataKey::HashOnlyCorridor(from_country.clone(), to_country.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn get_template_counter(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TemplateCounter)
+        .unwrap_or(0)
+}
+
+pub fn set_template_counter(env: &Env, value: u64) {
+    env.storage().instance().set(&DataKey::TemplateCounter, &value);
+}
+
+pub fn get_remittance_template(
+    env: &Env,
+    id: u64,
+) -> Option<crate::templates::RemittanceTemplate> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RemittanceTemplate(id))
+}
+
+pub fn set_remittance_template(env: &Env, template: &crate::templates::RemittanceTemplate) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RemittanceTemplate(template.id), template);
+}
+
+pub fn remove_remittance_template(env: &Env, id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::RemittanceTemplate(id));
+}
+
+pub fn get_sender_templates(env: &Env, sender: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SenderTemplates(sender.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_sender_templates(env: &Env, sender: &Address, ids: &Vec<u64>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SenderTemplates(sender.clone()), ids);
+}
+
+pub fn get_favorite_agents(env: &Env, sender: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FavoriteAgents(sender.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_favorite_agents(env: &Env, sender: &Address, agents: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::FavoriteAgents(sender.clone()), agents);
+}
+
+pub fn get_address_book_entry(
+    env: &Env,
+    owner: &Address,
+    alias_hash: &BytesN<32>,
+) -> Option<crate::address_book::AddressBookEntry> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AddressBookEntry(owner.clone(), alias_hash.clone()))
+}
+
+pub fn set_address_book_entry(
+    env: &Env,
+    owner: &Address,
+    entry: &crate::address_book::AddressBookEntry,
+) {
+    env.storage().persistent().set(
+        &DataKey::AddressBookEntry(owner.clone(), entry.alias_hash.clone()),
+        entry,
+    );
+}
+
+pub fn remove_address_book_entry(env: &Env, owner: &Address, alias_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::AddressBookEntry(owner.clone(), alias_hash.clone()));
+}
+
+pub fn get_address_book_aliases(env: &Env, owner: &Address) -> Vec<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AddressBookAliases(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_address_book_aliases(env: &Env, owner: &Address, aliases: &Vec<BytesN<32>>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AddressBookAliases(owner.clone()), aliases);
+}
+
+pub fn get_handle(env: &Env, handle: &String) -> Option<crate::handles::HandleRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Handle(handle.clone()))
+}
+
+pub fn set_handle(env: &Env, record: &crate::handles::HandleRecord) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Handle(record.handle.clone()), record);
+}
+
+pub fn is_corridor_disabled(env: &Env, from_country: &String, to_country: &String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::CorridorDisabled(from_country.clone(), to_country.clone()))
+}
+
+pub fn set_corridor_disabled(env: &Env, from_country: &String, to_country: &String, disabled: bool) {
+    let key = DataKey::CorridorDisabled(from_country.clone(), to_country.clone());
+    if disabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn get_scheduled_config_counter(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ScheduledConfigCounter)
+        .unwrap_or(0)
+}
+
+pub fn set_scheduled_config_counter(env: &Env, value: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ScheduledConfigCounter, &value);
+}
+
+pub fn get_scheduled_config_changes(
+    env: &Env,
+) -> Vec<crate::scheduled_config::ScheduledConfigChange> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ScheduledConfigChanges)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_scheduled_config_changes(
+    env: &Env,
+    changes: &Vec<crate::scheduled_config::ScheduledConfigChange>,
+) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ScheduledConfigChanges, changes);
+}
+
+pub fn get_maintenance_windows(env: &Env) -> Vec<crate::maintenance::MaintenanceWindow> {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaintenanceWindows)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_maintenance_windows(env: &Env, windows: &Vec<crate::maintenance::MaintenanceWindow>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MaintenanceWindows, windows);
+}
+
+pub fn get_guardian(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Guardian)
+}
+
+pub fn set_guardian(env: &Env, guardian: &Address) {
+    env.storage().instance().set(&DataKey::Guardian, guardian);
+}
+
+pub fn get_evacuation(env: &Env) -> Option<crate::evacuation::EvacuationRequest> {
+    env.storage().instance().get(&DataKey::Evacuation)
+}
+
+pub fn set_evacuation(env: &Env, request: &crate::evacuation::EvacuationRequest) {
+    env.storage().instance().set(&DataKey::Evacuation, request);
+}
+
+pub fn remove_evacuation(env: &Env) {
+    env.storage().instance().remove(&DataKey::Evacuation);
+}
+
+pub fn get_agent_recovery(env: &Env, lost_agent: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentRecovery(lost_agent.clone()))
+}
+
+pub fn set_agent_recovery(env: &Env, lost_agent: &Address, replacement: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentRecovery(lost_agent.clone()), replacement);
+}
+
+pub fn remove_agent_recovery(env: &Env, lost_agent: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::AgentRecovery(lost_agent.clone()));
+}
+
+pub fn get_recovery_guardians(
+    env: &Env,
+    owner: &Address,
+) -> Option<crate::account_recovery::RecoveryGuardians> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RecoveryGuardians(owner.clone()))
+}
+
+pub fn set_recovery_guardians(
+    env: &Env,
+    owner: &Address,
+    guardians: &crate::account_recovery::RecoveryGuardians,
+) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RecoveryGuardians(owner.clone()), guardians);
+}
+
+pub fn remove_recovery_guardians(env: &Env, owner: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::RecoveryGuardians(owner.clone()));
+}
+
+pub fn get_account_recovery(
+    env: &Env,
+    owner: &Address,
+) -> Option<crate::account_recovery::AccountRecovery> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AccountRecovery(owner.clone()))
+}
+
+pub fn set_account_recovery(
+    env: &Env,
+    owner: &Address,
+    recovery: &crate::account_recovery::AccountRecovery,
+) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AccountRecovery(owner.clone()), recovery);
+}
+
+pub fn remove_account_recovery(env: &Env, owner: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::AccountRecovery(owner.clone()));
+}
+
+/// Returns the secp256r1 passkey registered by an agent for payout vouchers.
+pub fn get_passkey_voucher_key(env: &Env, agent: &Address) -> Option<BytesN<65>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PasskeyVoucherKey(agent.clone()))
+}
+
+pub fn set_passkey_voucher_key(env: &Env, agent: &Address, public_key: &BytesN<65>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PasskeyVoucherKey(agent.clone()), public_key);
+}
+
+pub fn remove_passkey_voucher_key(env: &Env, agent: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PasskeyVoucherKey(agent.clone()));
+}
+
+pub fn get_escrow_claim_key(env: &Env, transfer_id: u64) -> Option<BytesN<65>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EscrowClaimKey(transfer_id))
+}
+
+pub fn set_escrow_claim_key(env: &Env, transfer_id: u64, public_key: &BytesN<65>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::EscrowClaimKey(transfer_id), public_key);
+}
+
+/// Returns the payout held for `payee` in `token` after failed transfers.
+pub fn get_undelivered_payout(env: &Env, payee: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UndeliveredPayout(payee.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_undelivered_payout(env: &Env, payee: &Address, token: &Address, amount: i128) {
+    let key = DataKey::UndeliveredPayout(payee.clone(), token.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &amount);
+    }
+}
+
+/// Returns the number of remittances in each status.
+pub fn get_remittance_counts(env: &Env) -> crate::RemittanceCounts {
+    env.storage()
+        .instance()
+        .get(&DataKey::RemittanceCounts)
+        .unwrap_or_default()
+}
+
+pub fn get_volume_bucket(env: &Env, slot: u32) -> Option<crate::VolumeBucket> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VolumeBucket(slot))
+}
+
+pub fn set_volume_bucket(env: &Env, slot: u32, bucket: &crate::VolumeBucket) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::VolumeBucket(slot), bucket);
+}
+
+/// Returns the number of remittance status changes recorded so far.
+pub fn get_state_change_count(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::StateChangeCount)
+        .unwrap_or(0)
+}
+
+pub fn set_state_change_count(env: &Env, count: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::StateChangeCount, &count);
+}
+
+/// Returns the number of admin log entries ever written, including evicted ones.
+pub fn get_admin_log_len(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AdminLogLength)
+        .unwrap_or(0)
+}
+
+pub fn set_admin_log_len(env: &Env, len: u64) {
+    env.storage().instance().set(&DataKey::AdminLogLength, &len);
+}
+
+pub fn get_admin_log_entry(env: &Env, seq: u64) -> Option<crate::AdminLogEntry> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AdminLogEntry(seq))
+}
+
+pub fn set_admin_log_entry(env: &Env, entry: &crate::AdminLogEntry) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AdminLogEntry(entry.seq), entry);
+}
+
+pub fn remove_admin_log_entry(env: &Env, seq: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::AdminLogEntry(seq));
+}
+
+pub fn get_corridor_instance(
+    env: &Env,
+    corridor: &crate::RemittanceCorridor,
+) -> Option<crate::CorridorInstance> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CorridorInstance(corridor.clone()))
+}
+
+/// Records a factory-deployed instance and appends its corridor to the list.
+pub fn add_corridor_instance(env: &Env, instance: &crate::CorridorInstance) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CorridorInstance(instance.corridor.clone()), instance);
+    let mut corridors = get_corridor_instance_list(env);
+    corridors.push_back(instance.corridor.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::CorridorInstanceList, &corridors);
+}
+
+pub fn get_corridor_instance_list(env: &Env) -> Vec<crate::RemittanceCorridor> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CorridorInstanceList)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn get_registry_entry(env: &Env) -> Option<crate::RegistryEntry> {
+    env.storage().instance().get(&DataKey::RegistryEntry)
+}
+
+pub fn set_registry_entry(env: &Env, entry: &crate::RegistryEntry) {
+    env.storage().instance().set(&DataKey::RegistryEntry, entry);
+}
+
+pub fn is_trusted_instance(env: &Env, instance: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TrustedInstance(instance.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_trusted_instance(env: &Env, instance: &Address, trusted: bool) {
+    let key = DataKey::TrustedInstance(instance.clone());
+    if trusted {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn get_forwarded_to(env: &Env, remittance_id: u64) -> Option<(Address, u64)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ForwardedTo(remittance_id))
+}
+
+pub fn set_forwarded_to(env: &Env, remittance_id: u64, target: &Address, target_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ForwardedTo(remittance_id), &(target.clone(), target_id));
+}
+
+pub fn get_forwarded_from(env: &Env, remittance_id: u64) -> Option<(Address, u64)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ForwardedFrom(remittance_id))
+}
+
+pub fn set_forwarded_from(env: &Env, remittance_id: u64, source: &Address, source_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ForwardedFrom(remittance_id), &(source.clone(), source_id));
+}
+
+pub fn get_path_payment_adapter(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PathPaymentAdapter)
+}
+
+pub fn set_path_payment_adapter(env: &Env, adapter: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PathPaymentAdapter, adapter);
+}
+
+pub fn get_path_payout(env: &Env, remittance_id: u64) -> Option<crate::PathPayout> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PathPayout(remittance_id))
+}
+
+pub fn set_path_payout(env: &Env, remittance_id: u64, path_payout: &crate::PathPayout) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PathPayout(remittance_id), path_payout);
+}
+
+pub fn get_anchor_link(env: &Env, remittance_id: u64) -> Option<crate::AnchorLink> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AnchorLink(remittance_id))
+}
+
+pub fn set_anchor_link(env: &Env, remittance_id: u64, link: &crate::AnchorLink) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AnchorLink(remittance_id), link);
+}
+
+pub fn get_anchor_tx_remittance(env: &Env, tx_id: &String) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AnchorTxRemittance(tx_id.clone()))
+}
+
+pub fn set_anchor_tx_remittance(env: &Env, tx_id: &String, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AnchorTxRemittance(tx_id.clone()), &remittance_id);
+}
+
+/// Returns the last issued quote ID (0 if none).
+pub fn get_quote_counter(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::QuoteCounter)
+        .unwrap_or(0)
+}
+
+pub fn set_quote_counter(env: &Env, value: u64) {
+    env.storage().instance().set(&DataKey::QuoteCounter, &value);
+}
+
+pub fn get_quote(env: &Env, quote_id: u64) -> Option<crate::Quote> {
+    env.storage().persistent().get(&DataKey::Quote(quote_id))
+}
+
+pub fn set_quote(env: &Env, quote: &crate::Quote) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Quote(quote.id), quote);
+}
+
+pub fn get_accepted_quote(env: &Env, remittance_id: u64) -> Option<crate::AcceptedQuote> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AcceptedQuote(remittance_id))
+}
+
+pub fn set_accepted_quote(env: &Env, remittance_id: u64, accepted: &crate::AcceptedQuote) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AcceptedQuote(remittance_id), accepted);
+}
+
+pub fn get_delivery_update(env: &Env, remittance_id: u64) -> Option<crate::DeliveryUpdate> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DeliveryStatus(remittance_id))
+}
+
+pub fn set_delivery_update(env: &Env, remittance_id: u64, update: &crate::DeliveryUpdate) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DeliveryStatus(remittance_id), update);
+}
+
+pub fn get_delivery_proof_threshold(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DeliveryProofThreshold)
+        .unwrap_or(0)
+}
+
+pub fn set_delivery_proof_threshold(env: &Env, threshold: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DeliveryProofThreshold, &threshold);
+}
+
+pub fn get_delivery_proof(env: &Env, remittance_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DeliveryProof(remittance_id))
+}
+
+pub fn set_delivery_proof(env: &Env, remittance_id: u64, proof: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DeliveryProof(remittance_id), proof);
+}
+
+pub fn get_kyc_tier(env: &Env, user: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::KycTier(user.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_kyc_tier(env: &Env, user: &Address, tier: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::KycTier(user.clone()), &tier);
+}
+
+pub fn get_restriction(
+    env: &Env,
+    target: &crate::RestrictionTarget,
+) -> Option<crate::RestrictionRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Restriction(target.clone()))
+}
+
+pub fn set_restriction(env: &Env, record: &crate::RestrictionRecord) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Restriction(record.target.clone()), record);
+}
+
+pub fn remove_restriction(env: &Env, target: &crate::RestrictionTarget) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Restriction(target.clone()));
+}
+
+pub fn get_restriction_targets(env: &Env) -> Vec<crate::RestrictionTarget> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RestrictionTargets)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_restriction_targets(env: &Env, targets: &Vec<crate::RestrictionTarget>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RestrictionTargets, targets);
+}
+
+pub fn get_payout_preference(env: &Env, agent: &Address) -> Option<crate::PayoutPreference> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PayoutPreference(agent.clone()))
+}
+
+pub fn set_payout_preference(env: &Env, agent: &Address, preference: &crate::PayoutPreference) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PayoutPreference(agent.clone()), preference);
+}
+
+pub fn remove_payout_preference(env: &Env, agent: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PayoutPreference(agent.clone()));
+}
+
+pub fn get_priority_boost(env: &Env, remittance_id: u64) -> Option<crate::PriorityBoost> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PriorityBoost(remittance_id))
+}
+
+pub fn set_priority_boost(env: &Env, remittance_id: u64, boost: &crate::PriorityBoost) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PriorityBoost(remittance_id), boost);
+}
+
+pub fn get_cancel_cooldown(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CancelCooldown)
+        .unwrap_or(0)
+}
+
+pub fn set_cancel_cooldown(env: &Env, seconds: u64) {
+    env.storage().instance().set(&DataKey::CancelCooldown, &seconds);
+}
+
+pub fn get_agent_rejection(env: &Env, remittance_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentRejection(remittance_id))
+}
+
+pub fn set_agent_rejection(env: &Env, remittance_id: u64, rejected_at: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AgentRejection(remittance_id), &rejected_at);
+}
+
+pub fn remove_agent_rejection(env: &Env, remittance_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::AgentRejection(remittance_id));
+}
+
+pub fn get_min_amount(env: &Env) -> i128 {
+    env.storage().instance().get(&DataKey::MinAmount).unwrap_or(0)
+}
+
+pub fn set_min_amount(env: &Env, amount: i128) {
+    env.storage().instance().set(&DataKey::MinAmount, &amount);
+}
+
+pub fn get_max_amount(env: &Env) -> i128 {
+    env.storage().instance().get(&DataKey::MaxAmount).unwrap_or(0)
+}
+
+pub fn set_max_amount(env: &Env, amount: i128) {
+    env.storage().instance().set(&DataKey::MaxAmount, &amount);
+}
+
+pub fn get_pending_limits(env: &Env) -> Option<crate::PendingLimits> {
+    env.storage().instance().get(&DataKey::PendingLimits)
+}
+
+pub fn set_pending_limits(env: &Env, pending: &crate::PendingLimits) {
+    env.storage().instance().set(&DataKey::PendingLimits, pending);
+}
+
+pub fn remove_pending_limits(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingLimits);
+}
+
+pub fn get_token_fees(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenFees(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_token_fees(env: &Env, token: &Address, fees: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenFees(token.clone()), &fees);
+}
+
+pub fn get_collateral_requirement(env: &Env) -> Option<crate::CollateralRequirement> {
+    env.storage().instance().get(&DataKey::CollateralRequirement)
+}
+
+pub fn set_collateral_requirement(env: &Env, requirement: &crate::CollateralRequirement) {
+    env.storage()
+        .instance()
+        .set(&DataKey::CollateralRequirement, requirement);
+}
+
+pub fn remove_collateral_requirement(env: &Env) {
+    env.storage().instance().remove(&DataKey::CollateralRequirement);
+}
+
+pub fn get_agent_capacity(env: &Env, agent: &Address) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentCapacity(agent.clone()))
+}
+
+pub fn set_agent_capacity(env: &Env, agent: &Address, cap: i128) {
+    let key = DataKey::AgentCapacity(agent.clone());
+    if cap == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &cap);
+    }
+}
+
+pub fn get_agent_commission_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AgentCommissionBps)
+        .unwrap_or(0)
+}
+
+pub fn set_agent_commission_bps(env: &Env, bps: u32) {
+    env.storage().instance().set(&DataKey::AgentCommissionBps, &bps);
+}
+
+pub fn get_agent_commission(env: &Env, agent: &Address, token: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentCommission(agent.clone(), token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_agent_commission(env: &Env, agent: &Address, token: &Address, amount: i128) {
+    let key = DataKey::AgentCommission(agent.clone(), token.clone());
+    if amount == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &amount);
+    }
+}
+
+pub fn get_flat_fee(env: &Env) -> i128 {
+    env.storage().instance().get(&DataKey::FlatFee).unwrap_or(0)
+}
+
+pub fn set_flat_fee(env: &Env, amount: i128) {
+    env.storage().instance().set(&DataKey::FlatFee, &amount);
+}
+
+pub fn is_fee_exempt(env: &Env, sender: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeeExempt(sender.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_fee_exempt(env: &Env, sender: &Address, exempt: bool) {
+    let key = DataKey::FeeExempt(sender.clone());
+    if exempt {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+// === Processing Volume ===
+
+/// Returns the total amount currently held in Processing remittances.
+pub fn get_total_processing_volume(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalProcessingVolume)
+        .unwrap_or(0)
+}
+
+/// Adds `amount` to the in-flight Processing volume.
+pub fn add_processing_volume(env: &Env, amount: i128) -> Result<(), ContractError> {
+    let next = get_total_processing_volume(env)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalProcessingVolume, &next);
+    Ok(())
+}
+
+/// Removes `amount` from the in-flight Processing volume.
+pub fn sub_processing_volume(env: &Env, amount: i128) -> Result<(), ContractError> {
+    let next = get_total_processing_volume(env)
+        .checked_sub(amount)
+        .ok_or(ContractError::Underflow)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalProcessingVolume, &next);
+    Ok(())
+}
+
+// === TTL Management ===
+
+/// Extends the TTL of a remittance record by `extend_to` ledgers.
+pub fn extend_remittance_ttl(env: &Env, remittance_id: u64, extend_to: u32) {
+    let key = DataKey::Remittance(remittance_id);
+    if env.storage().persistent().has(&key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, extend_to, extend_to);
+    }
+}
+
+/// Extends the TTL of the contract instance and of every registered agent's
+/// record by `extend_to` ledgers.
+pub fn extend_critical_ttls(env: &Env, extend_to: u32) {
+    env.storage().instance().extend_ttl(extend_to, extend_to);
+    for agent in get_agent_list(env).iter() {
+        let key = DataKey::AgentRegistered(agent);
+        if env.storage().persistent().has(&key) {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, extend_to, extend_to);
+        }
+    }
+}
+
+// === Agent Limits ===
+
+/// Returns the agent's daily withdrawal cap (0 = no cap).
+pub fn get_agent_daily_cap(env: &Env, agent: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AgentDailyCap(agent.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_agent_daily_cap(env: &Env, agent: &Address, cap: i128) {
+    let key = DataKey::AgentDailyCap(agent.clone());
+    if cap == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &cap);
+    }
+}
+
+/// Records an agent payout of `amount` against its daily cap.
+///
+/// Payouts are summed over a 24-hour window that starts with the first payout
+/// after the previous window ended.
+pub fn check_and_record_agent_withdrawal(
+    env: &Env,
+    agent: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let cap = get_agent_daily_cap(env, agent);
+    if cap == 0 {
+        return Ok(());
+    }
+
+    let now = env.ledger().timestamp();
+    let key = DataKey::AgentWithdrawals(agent.clone());
+    let (window_start, used): (u64, i128) = env.storage().persistent().get(&key).unwrap_or((0, 0));
+    let (window_start, used) = if now >= window_start.saturating_add(crate::config::DAILY_LIMIT_WINDOW_SECONDS) {
+        (now, 0)
+    } else {
+        (window_start, used)
+    };
+
+    let used = used.checked_add(amount).ok_or(ContractError::Overflow)?;
+    if used > cap {
+        return Err(ContractError::AgentDailyCapExceeded);
+    }
+    env.storage().persistent().set(&key, &(window_start, used));
+    Ok(())
+}
+
+/// Returns the minimum reputation score agents need to receive remittances (0 = disabled).
+pub fn get_min_agent_reputation(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MinAgentReputation)
+        .unwrap_or(0)
+}
+
+pub fn set_min_agent_reputation(env: &Env, threshold: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MinAgentReputation, &threshold);
+}
+
+/// Returns the daily decay applied to escalated abuse cooldowns, in bps.
+pub fn get_abuse_cooldown_decay_rate_bps(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::AbuseCooldownDecayRateBps)
+        .unwrap_or(0)
+}
+
+// === Disputes ===
+
+/// Returns how long senders have to dispute a failed payout, in seconds.
+pub fn get_dispute_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DisputeWindow)
+        .unwrap_or(crate::config::DEFAULT_DISPUTE_WINDOW_SECONDS)
+}
+
+pub fn set_dispute_window(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DisputeWindow, &seconds);
+}
+
+// === Admin Transfer ===
+
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PendingAdmin)
+}
+
+pub fn set_pending_admin(env: &Env, new_admin: &Address) {
+    env.storage().instance().set(&DataKey::PendingAdmin, new_admin);
+}
+
+pub fn clear_pending_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
+// === Recipient Verification ===
+
+pub fn get_recipient_hash_record(
+    env: &Env,
+    remittance_id: u64,
+) -> Option<crate::RecipientHashRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RecipientHash(remittance_id))
+}
+
+pub fn set_recipient_hash(env: &Env, remittance_id: u64, record: &crate::RecipientHashRecord) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RecipientHash(remittance_id), record);
+}
+
+// === Partial Payouts ===
+
+/// Returns the amount already paid out on a remittance through partial payouts.
+pub fn get_disbursed_amount(env: &Env, remittance_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DisbursedAmount(remittance_id))
+        .unwrap_or(0)
+}
+
+/// Adds `amount` to the amount paid out on a remittance.
+pub fn add_disbursed_amount(env: &Env, remittance_id: u64, amount: i128) -> Result<(), ContractError> {
+    let total = get_disbursed_amount(env, remittance_id)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::DisbursedAmount(remittance_id), &total);
+    Ok(())
+}
+
+/// Appends a disbursement to a remittance's partial payout history.
+pub fn append_partial_payout_record(
+    env: &Env,
+    remittance_id: u64,
+    record: crate::PartialPayoutRecord,
+) {
+    let key = DataKey::PartialPayoutHistory(remittance_id);
+    let mut history: Vec<crate::PartialPayoutRecord> =
+        env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+    history.push_back(record);
+    env.storage().persistent().set(&key, &history);
+}
@@ -0,0 +1,97 @@
+//! Saved remittance templates.
+//!
+//! Senders who repeat the same transfer every month can save its parameters
+//! once as a named template and create each transfer from it, overriding only
+//! what changed. A template fixes the agent, amount, optional corridor, memo,
+//! and purpose; the purpose is applied as the remittance's tag so templated
+//! flows can be filtered like tagged ones. Templates are denominated in the
+//! default token, and a sender holds at most [`MAX_TEMPLATES_PER_SENDER`].
+
+use soroban_sdk::{contracttype, Address, Env, String, Symbol};
+
+use crate::config::{MAX_MEMO_BYTES, MAX_TEMPLATES_PER_SENDER};
+use crate::storage::{
+    get_remittance_template, get_sender_templates, get_template_counter, set_remittance_template,
+    set_sender_templates, set_template_counter,
+};
+use crate::{ContractError, MaybeCorridor};
+
+/// A sender's saved remittance parameters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittanceTemplate {
+    pub id: u64,
+    pub owner: Address,
+    pub name: String,
+    pub agent: Address,
+    pub amount: i128,
+    /// Corridor used for fee calculation, if any
+    pub corridor: MaybeCorridor,
+    pub memo: Option<String>,
+    /// Applied as the remittance's tag
+    pub purpose: Option<Symbol>,
+}
+
+/// Fields replacing a template's values for a single transfer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateOverrides {
+    pub agent: Option<Address>,
+    pub amount: Option<i128>,
+    pub memo: Option<String>,
+}
+
+/// Validates a plaintext memo.
+pub fn validate_memo(memo: &Option<String>) -> Result<(), ContractError> {
+    if memo.as_ref().is_some_and(|memo| memo.len() > MAX_MEMO_BYTES) {
+        return Err(ContractError::InvalidTemplate);
+    }
+    Ok(())
+}
+
+/// Stores a new template for `template.owner`, assigning its ID.
+pub fn save(env: &Env, mut template: RemittanceTemplate) -> Result<RemittanceTemplate, ContractError> {
+    if template.name.is_empty() || template.amount <= 0 {
+        return Err(ContractError::InvalidTemplate);
+    }
+    validate_memo(&template.memo)?;
+    let mut owned = get_sender_templates(env, &template.owner);
+    if owned.len() >= MAX_TEMPLATES_PER_SENDER {
+        return Err(ContractError::InvalidTemplate);
+    }
+
+    template.id = get_template_counter(env)
+        .checked_add(1)
+        .ok_or(ContractError::Overflow)?;
+    set_remittance_template(env, &template);
+    set_template_counter(env, template.id);
+    owned.push_back(template.id);
+    set_sender_templates(env, &template.owner, &owned);
+    Ok(template)
+}
+
+/// Loads a template, checking that it belongs to `owner`.
+pub fn owned(env: &Env, owner: &Address, template_id: u64) -> Result<RemittanceTemplate, ContractError> {
+    match get_remittance_template(env, template_id) {
+        Some(template) if template.owner == *owner => Ok(template),
+        _ => Err(ContractError::TemplateNotFound),
+    }
+}
+
+/// Returns the template with `overrides` applied.
+pub fn apply(
+    mut template: RemittanceTemplate,
+    overrides: TemplateOverrides,
+) -> Result<RemittanceTemplate, ContractError> {
+    if let Some(agent) = overrides.agent {
+        template.agent = agent;
+    }
+    if let Some(amount) = overrides.amount {
+        template.amount = amount;
+    }
+    if overrides.memo.is_some() {
+        validate_memo(&overrides.memo)?;
+        template.memo = overrides.memo;
+    }
+    Ok(template)
+}
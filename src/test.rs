@@ -5960,7 +5960,6 @@ fn test_batch_settle_exceed_max_size() {
     contract.batch_settle_with_netting(&entries);
 }
 
-
 // === Transaction Controller Tests ===
 
 #[test]
@@ -0,0 +1,65 @@
+//! Tests for guardian-approved sender account recovery.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, String};
+
+use crate::test_utils::{advance_time, remit, setup};
+use crate::{ContractError, ACCOUNT_RECOVERY_CHALLENGE_SECONDS};
+
+#[test]
+fn test_account_recovery_rotates_after_challenge_period() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    let name = String::from_str(&f.env, "school");
+    let goal = f
+        .contract
+        .create_savings_goal(&f.sender, &name, &f.token.address, &5_000, &None);
+    f.contract.deposit_to_goal(&f.sender, &goal, &500);
+
+    let g1 = Address::generate(&f.env);
+    let g2 = Address::generate(&f.env);
+    let new_owner = Address::generate(&f.env);
+    let guardians = vec![&f.env, g1.clone(), g2.clone()];
+    f.contract.set_recovery_guardians(&f.sender, &guardians, &2);
+
+    f.contract
+        .approve_account_recovery(&g1, &f.sender, &new_owner);
+    let ids = vec![&f.env, id];
+    let r = f.contract.try_execute_account_recovery(&f.sender, &ids);
+    assert_eq!(r, Err(Ok(ContractError::AccountRecoveryNotReady)));
+    f.contract
+        .approve_account_recovery(&g2, &f.sender, &new_owner);
+    let r = f.contract.try_execute_account_recovery(&f.sender, &ids);
+    assert_eq!(r, Err(Ok(ContractError::AccountRecoveryNotReady)));
+
+    advance_time(&f, ACCOUNT_RECOVERY_CHALLENGE_SECONDS);
+    assert_eq!(f.contract.execute_account_recovery(&f.sender, &ids), ids);
+    assert_eq!(f.contract.get_remittance(&id).sender, new_owner);
+    assert_eq!(f.contract.get_savings_goal(&goal).unwrap().owner, new_owner);
+    assert_eq!(
+        f.contract
+            .get_recovery_guardians(&new_owner)
+            .unwrap()
+            .threshold,
+        2
+    );
+    assert_eq!(f.contract.get_account_recovery(&f.sender), None);
+}
+
+#[test]
+fn test_account_recovery_owner_can_cancel() {
+    let f = setup();
+    let g1 = Address::generate(&f.env);
+    let new_owner = Address::generate(&f.env);
+    f.contract
+        .set_recovery_guardians(&f.sender, &vec![&f.env, g1.clone()], &1);
+    f.contract
+        .approve_account_recovery(&g1, &f.sender, &new_owner);
+    f.contract.cancel_account_recovery(&f.sender);
+    advance_time(&f, ACCOUNT_RECOVERY_CHALLENGE_SECONDS);
+    let r = f
+        .contract
+        .try_execute_account_recovery(&f.sender, &vec![&f.env]);
+    assert_eq!(r, Err(Ok(ContractError::AccountRecoveryNotFound)));
+}
@@ -0,0 +1,57 @@
+//! Tests for senders' favorite agents and address books.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN};
+
+use crate::test_utils::setup;
+use crate::{ContactKind, ContractError};
+
+#[test]
+fn test_favorite_agents_add_remove() {
+    let f = setup();
+    let other = Address::generate(&f.env);
+    assert_eq!(
+        f.contract.try_add_favorite_agent(&f.sender, &other),
+        Err(Ok(ContractError::AgentNotRegistered))
+    );
+    f.contract.register_agent(&other, &None);
+
+    f.contract.add_favorite_agent(&f.sender, &other);
+    f.contract.add_favorite_agent(&f.sender, &f.agent);
+    f.contract.add_favorite_agent(&f.sender, &other);
+    assert_eq!(
+        f.contract.get_favorite_agents(&f.sender),
+        vec![&f.env, other.clone(), f.agent.clone()]
+    );
+
+    f.contract.remove_favorite_agent(&f.sender, &other);
+    assert_eq!(
+        f.contract.get_favorite_agents(&f.sender),
+        vec![&f.env, f.agent.clone()]
+    );
+}
+
+#[test]
+fn test_address_book_upsert_and_remove() {
+    let f = setup();
+    let alias = BytesN::from_array(&f.env, &[4u8; 32]);
+    let mum = Address::generate(&f.env);
+    f.contract
+        .set_address_book_entry(&f.sender, &alias, &mum, &ContactKind::Recipient);
+    f.contract
+        .set_address_book_entry(&f.sender, &alias, &f.agent, &ContactKind::Agent);
+
+    let book = f.contract.get_address_book(&f.sender);
+    assert_eq!(book.len(), 1);
+    assert_eq!(book.get(0).unwrap().address, f.agent);
+    assert_eq!(
+        f.contract.get_address_book_entry(&f.sender, &alias).kind,
+        ContactKind::Agent
+    );
+    assert_eq!(f.contract.get_address_book(&f.agent).len(), 0);
+
+    f.contract.remove_address_book_entry(&f.sender, &alias);
+    let r = f.contract.try_get_address_book_entry(&f.sender, &alias);
+    assert_eq!(r, Err(Ok(ContractError::AddressBookEntryNotFound)));
+}
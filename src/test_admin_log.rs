@@ -0,0 +1,38 @@
+//! Tests for the admin audit log.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, xdr::ToXdr, Address, IntoVal, Symbol, Val};
+
+use crate::test_utils::{remit, setup};
+
+#[test]
+fn test_admin_log_records_privileged_calls() {
+    let f = setup();
+    let agent = Address::generate(&f.env);
+    f.contract.register_agent(&agent, &None);
+    f.contract.update_fee(&300);
+    remit(&f, 1000);
+
+    let log = f.contract.get_admin_log(&0, &10);
+    assert!(!log.has_more);
+    let last = log.items.last().unwrap();
+    assert_eq!(last.seq + 1, log.next_cursor as u64);
+    assert_eq!(last.caller, f.admin);
+    assert_eq!(last.action, Symbol::new(&f.env, "update_fee"));
+    let params: Val = (300u32,).into_val(&f.env);
+    assert_eq!(
+        last.params_hash,
+        f.env.crypto().sha256(&params.to_xdr(&f.env)).to_bytes()
+    );
+    let registered = log.items.get(log.items.len() - 2).unwrap();
+    assert_eq!(registered.action, Symbol::new(&f.env, "register_agent"));
+
+    let tail = f.contract.get_admin_log(&(last.seq as u32), &10);
+    assert_eq!(tail.items.len(), 1);
+    assert!(f
+        .contract
+        .get_admin_log(&log.next_cursor, &10)
+        .items
+        .is_empty());
+}
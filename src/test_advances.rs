@@ -0,0 +1,71 @@
+//! Tests for agent advances drawn against pending remittances.
+
+#![cfg(test)]
+
+use soroban_sdk::vec;
+
+use crate::test_utils::{advance_time, balance, remit, setup, Fixture};
+use crate::{ContractError, ADVANCE_TERM_SECONDS};
+
+fn staked_with_advance(f: &Fixture) -> u64 {
+    f.token.mint(&f.agent, &1_000);
+    f.token.mint(&f.admin, &5_000);
+    f.contract.stake_agent(&f.agent, &f.token.address, &1_000);
+    f.contract
+        .fund_advance_pool(&f.admin, &f.token.address, &5_000);
+    let id = remit(f, 2_000);
+    let collateral = vec![&f.env, id];
+    assert_eq!(
+        f.contract
+            .get_advance_limit(&f.agent, &f.token.address, &collateral),
+        1_000
+    );
+    f.contract
+        .draw_advance(&f.agent, &f.token.address, &collateral, &1_000);
+    id
+}
+
+#[test]
+fn test_advance_repaid_from_next_payout() {
+    let f = setup();
+    let id = staked_with_advance(&f);
+    assert_eq!(balance(&f, &f.agent), 1_000);
+    let r = f
+        .contract
+        .try_draw_advance(&f.agent, &f.token.address, &vec![&f.env, id], &1);
+    assert_eq!(r, Err(Ok(ContractError::AdvanceOutstanding)));
+
+    let fee = f.contract.get_remittance(&id).fee;
+    f.contract
+        .confirm_batch_payout(&f.agent, &vec![&f.env, id], &false);
+    assert_eq!(balance(&f, &f.agent), 1_000 + 2_000 - fee - 1_000);
+    assert_eq!(
+        f.contract.get_agent_advance(&f.agent, &f.token.address),
+        None
+    );
+    assert_eq!(f.contract.get_advance_pool(&f.token.address), 5_000);
+    f.contract.unstake_agent(&f.agent, &f.token.address, &1_000);
+}
+
+#[test]
+fn test_advance_liquidated_against_stake_after_term() {
+    let f = setup();
+    staked_with_advance(&f);
+    let r = f
+        .contract
+        .try_liquidate_advance(&f.admin, &f.agent, &f.token.address);
+    assert_eq!(r, Err(Ok(ContractError::AdvanceNotDue)));
+    let r = f
+        .contract
+        .try_unstake_agent(&f.agent, &f.token.address, &1_000);
+    assert_eq!(r, Err(Ok(ContractError::AdvanceOutstanding)));
+
+    advance_time(&f, ADVANCE_TERM_SECONDS);
+    assert_eq!(
+        f.contract
+            .liquidate_advance(&f.admin, &f.agent, &f.token.address),
+        1_000
+    );
+    assert_eq!(f.contract.get_agent_stake(&f.agent, &f.token.address), 0);
+    assert_eq!(f.contract.get_advance_pool(&f.token.address), 5_000);
+}
@@ -0,0 +1,25 @@
+//! Tests for per-agent caps on outstanding value.
+
+#![cfg(test)]
+
+use crate::test_utils::{remit, setup};
+use crate::ContractError;
+
+#[test]
+fn test_agent_capacity_caps_outstanding_value() {
+    let f = setup();
+    f.contract.set_agent_capacity(&f.admin, &f.agent, &3_000);
+    let first = remit(&f, 2_000);
+    assert_eq!(
+        f.contract
+            .try_create_remittance(&f.sender, &f.agent, &1_500, &None, &None, &None, &None, &None),
+        Err(Ok(ContractError::AgentCapacityExceeded))
+    );
+
+    f.contract
+        .confirm_payout(&f.agent, &first, &None, &None, &None);
+    remit(&f, 1_500);
+
+    f.contract.set_agent_capacity(&f.admin, &f.agent, &0);
+    assert_eq!(f.contract.get_agent_capacity(&f.agent), None);
+}
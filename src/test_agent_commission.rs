@@ -0,0 +1,35 @@
+//! Tests for agent commission on fees.
+
+#![cfg(test)]
+
+use crate::test_utils::{balance, remit, setup};
+use crate::ContractError;
+
+#[test]
+fn test_agent_commission_split_and_withdrawal() {
+    let f = setup();
+    f.contract.set_agent_commission_bps(&f.admin, &2_000);
+    let id = remit(&f, 1_000);
+    let fees_before = f.contract.get_accumulated_fees();
+    f.contract
+        .confirm_payout(&f.agent, &id, &None, &None, &None);
+
+    // 20% of the 25 fee goes to the agent, the rest to the platform
+    assert_eq!(
+        f.contract.get_agent_commission(&f.agent, &f.token.address),
+        5
+    );
+    assert_eq!(f.contract.get_accumulated_fees(), fees_before + 20);
+
+    let before = balance(&f, &f.agent);
+    assert_eq!(
+        f.contract.withdraw_commission(&f.agent, &f.token.address),
+        5
+    );
+    assert_eq!(balance(&f, &f.agent), before + 5);
+    assert_eq!(
+        f.contract
+            .try_withdraw_commission(&f.agent, &f.token.address),
+        Err(Ok(ContractError::NoFeesToWithdraw))
+    );
+}
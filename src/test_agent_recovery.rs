@@ -0,0 +1,95 @@
+//! Tests for sender-approved agent key-loss recovery.
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    Address, IntoVal,
+};
+
+use crate::storage;
+use crate::test_utils::{balance, remit, setup, Fixture};
+use crate::{ContractError, RemittanceStatus};
+
+/// Registers a replacement agent and opens a recovery moving `f.agent`'s
+/// remittances to it.
+fn open_recovery(f: &Fixture) -> Address {
+    let replacement = Address::generate(&f.env);
+    f.contract.register_agent(&replacement, &None);
+    f.contract
+        .propose_agent_recovery(&f.admin, &f.agent, &replacement);
+    replacement
+}
+
+#[test]
+fn test_sender_approval_moves_remittance_to_replacement() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    let replacement = open_recovery(&f);
+    assert_eq!(f.contract.get_agent_recovery(&f.agent), Some(replacement.clone()));
+
+    f.contract.approve_agent_recovery(&id);
+
+    let remittance = f.contract.get_remittance(&id);
+    assert_eq!(remittance.agent, replacement);
+    assert_eq!(remittance.status, RemittanceStatus::Pending);
+    f.env.as_contract(&f.contract.address, || {
+        assert_eq!(storage::get_agent_remittance_count(&f.env, &replacement), 1);
+    });
+
+    f.contract
+        .confirm_payout(&replacement, &id, &None, &None, &None);
+    assert_eq!(balance(&f, &replacement), 975);
+}
+
+#[test]
+fn test_approval_requires_only_the_sender() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    let replacement = open_recovery(&f);
+
+    let args = (id,).into_val(&f.env);
+    let invoke = MockAuthInvoke {
+        contract: &f.contract.address,
+        fn_name: "approve_agent_recovery",
+        args,
+        sub_invokes: &[],
+    };
+
+    // The lost agent's key cannot approve on the sender's behalf
+    assert!(f
+        .contract
+        .mock_auths(&[MockAuth { address: &f.agent, invoke: &invoke }])
+        .try_approve_agent_recovery(&id)
+        .is_err());
+    assert_eq!(f.contract.get_remittance(&id).agent, f.agent);
+
+    f.contract
+        .mock_auths(&[MockAuth { address: &f.sender, invoke: &invoke }])
+        .approve_agent_recovery(&id);
+    assert_eq!(f.contract.get_remittance(&id).agent, replacement);
+}
+
+#[test]
+fn test_partially_disbursed_remittance_cannot_be_recovered() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    f.contract.confirm_partial_payout(&id, &400);
+    open_recovery(&f);
+
+    assert_eq!(
+        f.contract.try_approve_agent_recovery(&id),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_approval_without_open_recovery_rejected() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+
+    assert_eq!(
+        f.contract.try_approve_agent_recovery(&id),
+        Err(Ok(ContractError::AgentRecoveryNotFound))
+    );
+}
@@ -0,0 +1,72 @@
+//! Tests for batch agent registration and delegated roles.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+use crate::test_utils::setup;
+use crate::{ContractError, FeeStrategy, PauseReason, Role};
+
+#[test]
+fn test_register_and_remove_agents_report_failures() {
+    let f = setup();
+    let new_agent = Address::generate(&f.env);
+    let agents = vec![&f.env, new_agent.clone(), f.agent.clone()];
+    let failures = f.contract.register_agents(&f.admin, &agents);
+    assert_eq!(
+        failures,
+        vec![
+            &f.env,
+            (
+                f.agent.clone(),
+                ContractError::AgentAlreadyRegistered as u32
+            )
+        ]
+    );
+    assert!(f.contract.is_agent_registered(&new_agent));
+
+    let stranger = Address::generate(&f.env);
+    let agents = vec![&f.env, new_agent.clone(), stranger.clone()];
+    let failures = f.contract.remove_agents(&f.admin, &agents);
+    assert_eq!(
+        failures,
+        vec![&f.env, (stranger, ContractError::AgentNotRegistered as u32)]
+    );
+    assert!(!f.contract.is_agent_registered(&new_agent));
+}
+
+#[test]
+fn test_operator_and_fee_manager_roles() {
+    let f = setup();
+    let operator = Address::generate(&f.env);
+    let fee_manager = Address::generate(&f.env);
+    f.contract.assign_role(&f.admin, &operator, &Role::Operator);
+    f.contract
+        .assign_role(&f.admin, &fee_manager, &Role::FeeManager);
+
+    let new_agent = Address::generate(&f.env);
+    f.contract
+        .register_agents(&operator, &vec![&f.env, new_agent.clone()]);
+    assert!(f.contract.is_agent_registered(&new_agent));
+    assert_eq!(
+        f.contract
+            .try_register_agents(&fee_manager, &vec![&f.env, Address::generate(&f.env)]),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    f.contract
+        .update_fee_strategy(&fee_manager, &FeeStrategy::Percentage(300));
+    assert_eq!(
+        f.contract
+            .try_update_fee_strategy(&operator, &FeeStrategy::Percentage(100)),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    f.contract
+        .emergency_pause(&operator, &PauseReason::SecurityIncident);
+    assert!(f.contract.is_paused());
+    assert_eq!(
+        f.contract.try_emergency_unpause(&operator),
+        Err(Ok(ContractError::Unauthorized))
+    );
+}
@@ -0,0 +1,45 @@
+//! Tests for linking remittances to anchor transactions.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+use crate::test_utils::{remit, setup};
+use crate::{AnchorTxStatus, ContractError, Role};
+
+#[test]
+fn test_anchor_tx_link_and_status() {
+    let f = setup();
+    let operator = Address::generate(&f.env);
+    let anchor = Address::generate(&f.env);
+    let tx_id = String::from_str(&f.env, "82fhs729f63dh0v4");
+    let id = remit(&f, 1000);
+    assert_eq!(
+        f.contract
+            .try_link_anchor_tx(&operator, &id, &anchor, &tx_id),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    f.contract
+        .assign_role(&f.admin, &operator, &Role::AnchorOperator);
+    f.contract.link_anchor_tx(&operator, &id, &anchor, &tx_id);
+    assert_eq!(f.contract.get_remittance_by_anchor_tx(&tx_id), Some(id));
+    let other = remit(&f, 1000);
+    assert_eq!(
+        f.contract
+            .try_link_anchor_tx(&operator, &other, &anchor, &tx_id),
+        Err(Ok(ContractError::AnchorTxAlreadyLinked))
+    );
+
+    f.contract
+        .update_anchor_status(&operator, &id, &AnchorTxStatus::Completed);
+    assert_eq!(
+        f.contract.get_anchor_link(&id).unwrap().status,
+        AnchorTxStatus::Completed
+    );
+    assert_eq!(
+        f.contract
+            .try_update_anchor_status(&operator, &id, &AnchorTxStatus::Failed),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+}
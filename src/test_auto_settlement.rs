@@ -0,0 +1,58 @@
+//! Tests for agents opting into auto-settlement by the platform operator.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address};
+
+use crate::test_utils::{balance, remit, setup, Fixture};
+use crate::{ContractError, RemittanceStatus};
+
+fn set_operator(f: &Fixture) -> Address {
+    let operator = Address::generate(&f.env);
+    f.contract.set_settlement_operator(&operator);
+    operator
+}
+
+#[test]
+fn test_operator_settles_for_opted_in_agent() {
+    let f = setup();
+    let operator = set_operator(&f);
+    f.contract.enable_auto_settlement(&f.agent, &2_000, &5_000);
+    let id = remit(&f, 1_000);
+
+    f.contract.confirm_payout(&operator, &id, &None, &None, &None);
+
+    assert_eq!(f.contract.get_remittance(&id).status, RemittanceStatus::Completed);
+    assert_eq!(balance(&f, &f.agent), 975);
+    assert_eq!(
+        f.contract.get_auto_settlement(&f.agent).unwrap().used_in_window,
+        1_000
+    );
+}
+
+#[test]
+fn test_operator_rejected_over_limit_or_without_opt_in() {
+    let f = setup();
+    let operator = set_operator(&f);
+    let id = remit(&f, 1_000);
+
+    assert_eq!(
+        f.contract.try_confirm_payout(&operator, &id, &None, &None, &None),
+        Err(Ok(ContractError::AutoSettlementNotEnabled))
+    );
+
+    f.contract.enable_auto_settlement(&f.agent, &500, &5_000);
+    assert_eq!(
+        f.contract.try_confirm_payout(&operator, &id, &None, &None, &None),
+        Err(Ok(ContractError::AutoSettlementLimitExceeded))
+    );
+
+    // Rotating the operator does not carry the opt-in over
+    f.contract.enable_auto_settlement(&f.agent, &2_000, &5_000);
+    let rotated = set_operator(&f);
+    assert_eq!(
+        f.contract.try_confirm_payout(&rotated, &id, &None, &None, &None),
+        Err(Ok(ContractError::AutoSettlementNotEnabled))
+    );
+    assert_eq!(f.contract.get_remittance(&id).status, RemittanceStatus::Pending);
+}
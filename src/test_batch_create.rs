@@ -39,9 +39,9 @@ mod tests {
         contract.register_agent(&agent3, &None);
 
         let mut entries = Vec::new(&env);
-        entries.push_back(BatchCreateEntry { agent: agent1.clone(), amount: 100_000_000, expiry: None });
-        entries.push_back(BatchCreateEntry { agent: agent2.clone(), amount: 200_000_000, expiry: Some(env.ledger().timestamp() + 3600) });
-        entries.push_back(BatchCreateEntry { agent: agent3.clone(), amount: 150_000_000, expiry: None });
+        entries.push_back(BatchCreateEntry { agent: agent1.clone(), amount: 100_000_000, expiry: crate::MaybeExpiry::None });
+        entries.push_back(BatchCreateEntry { agent: agent2.clone(), amount: 200_000_000, expiry: crate::MaybeExpiry::Some(crate::Expiry::Time(env.ledger().timestamp() + 3600)) });
+        entries.push_back(BatchCreateEntry { agent: agent3.clone(), amount: 150_000_000, expiry: crate::MaybeExpiry::None });
 
         let remittance_ids = contract.batch_create_remittances(&sender, &entries);
         assert_eq!(remittance_ids.len(), 3);
@@ -74,9 +74,9 @@ mod tests {
         contract.register_agent(&agent2, &None);
 
         let mut entries = Vec::new(&env);
-        entries.push_back(BatchCreateEntry { agent: agent1.clone(), amount: 100_000_000, expiry: None });
-        entries.push_back(BatchCreateEntry { agent: unregistered.clone(), amount: 200_000_000, expiry: None });
-        entries.push_back(BatchCreateEntry { agent: agent2.clone(), amount: 150_000_000, expiry: None });
+        entries.push_back(BatchCreateEntry { agent: agent1.clone(), amount: 100_000_000, expiry: crate::MaybeExpiry::None });
+        entries.push_back(BatchCreateEntry { agent: unregistered.clone(), amount: 200_000_000, expiry: crate::MaybeExpiry::None });
+        entries.push_back(BatchCreateEntry { agent: agent2.clone(), amount: 150_000_000, expiry: crate::MaybeExpiry::None });
 
         let result = contract.try_batch_create_remittances(&sender, &entries);
         assert_eq!(result, Err(Ok(ContractError::AgentNotRegistered)));
@@ -90,7 +90,7 @@ mod tests {
 
         let mut entries = Vec::new(&env);
         for _ in 0..101 {
-            entries.push_back(BatchCreateEntry { agent: agent.clone(), amount: 1_000_000, expiry: None });
+            entries.push_back(BatchCreateEntry { agent: agent.clone(), amount: 1_000_000, expiry: crate::MaybeExpiry::None });
         }
 
         let result = contract.try_batch_create_remittances(&sender, &entries);
@@ -120,8 +120,8 @@ mod tests {
         contract.register_agent(&agent2, &None);
 
         let mut entries = Vec::new(&env);
-        entries.push_back(BatchCreateEntry { agent: agent1.clone(), amount: 100_000_000, expiry: None });
-        entries.push_back(BatchCreateEntry { agent: agent2.clone(), amount: 0, expiry: None });
+        entries.push_back(BatchCreateEntry { agent: agent1.clone(), amount: 100_000_000, expiry: crate::MaybeExpiry::None });
+        entries.push_back(BatchCreateEntry { agent: agent2.clone(), amount: 0, expiry: crate::MaybeExpiry::None });
 
         let result = contract.try_batch_create_remittances(&sender, &entries);
         assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
@@ -135,7 +135,7 @@ mod tests {
 
         let mut entries = Vec::new(&env);
         for _ in 0..100 {
-            entries.push_back(BatchCreateEntry { agent: agent.clone(), amount: 1_000_000, expiry: None });
+            entries.push_back(BatchCreateEntry { agent: agent.clone(), amount: 1_000_000, expiry: crate::MaybeExpiry::None });
         }
 
         let remittance_ids = contract.batch_create_remittances(&sender, &entries);
@@ -154,8 +154,8 @@ mod tests {
         contract.register_agent(&agent2, &None);
 
         let mut entries = Vec::new(&env);
-        entries.push_back(BatchCreateEntry { agent: agent1.clone(), amount: 50_000_000, expiry: None });
-        entries.push_back(BatchCreateEntry { agent: agent2.clone(), amount: 150_000_000, expiry: None });
+        entries.push_back(BatchCreateEntry { agent: agent1.clone(), amount: 50_000_000, expiry: crate::MaybeExpiry::None });
+        entries.push_back(BatchCreateEntry { agent: agent2.clone(), amount: 150_000_000, expiry: crate::MaybeExpiry::None });
 
         let remittance_ids = contract.batch_create_remittances(&sender, &entries);
         let r1 = contract.get_remittance(&remittance_ids.get_unchecked(0));
@@ -0,0 +1,207 @@
+//! Tests for batch remittance payout, creation and cancellation.
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, Events as _},
+    vec,
+    xdr::{ContractEventBody, ScVal},
+    Address, IntoVal, Symbol, Vec,
+};
+
+use crate::test_utils::{balance, remit, setup, Fixture};
+use crate::{BatchCreateEntry, ContractError, MaybeExpiry, RemittanceStatus, MAX_SAFE_BATCH_SIZE};
+
+#[test]
+fn test_590_confirm_batch_payout_signs_settlement_set() {
+    let f = setup();
+    let ids = vec![&f.env, remit(&f, 500), remit(&f, 300)];
+    f.contract.confirm_batch_payout(&f.agent, &ids, &false);
+    let signed = vec![
+        &f.env,
+        (ids.get(0).unwrap(), 500i128, f.agent.clone()),
+        (ids.get(1).unwrap(), 300i128, f.agent.clone()),
+    ];
+    let auths = f.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, f.agent);
+    let call = (
+        f.contract.address.clone(),
+        Symbol::new(&f.env, "confirm_batch_payout"),
+        (signed,).into_val(&f.env),
+    );
+    assert_eq!(auths[0].1.function, AuthorizedFunction::Contract(call));
+    let id = remit(&f, 500);
+    let dup = vec![&f.env, id, id];
+    assert_eq!(
+        f.contract.try_confirm_batch_payout(&f.agent, &dup, &false),
+        Err(Ok(ContractError::DuplicateSettlement))
+    );
+}
+
+fn batch_of(f: &Fixture, count: u32, amount: i128) -> Vec<u64> {
+    let mut entries = Vec::new(&f.env);
+    for _ in 0..count {
+        entries.push_back(BatchCreateEntry {
+            agent: f.agent.clone(),
+            amount,
+            expiry: MaybeExpiry::None,
+        });
+    }
+    // Batches touch more ledger entries than one invocation may by default
+    f.env.cost_estimate().disable_resource_limits();
+    f.env.cost_estimate().budget().reset_unlimited();
+    f.contract.create_batch_remittance(&f.sender, &entries)
+}
+
+#[test]
+fn test_590_confirm_batch_payout_single_fee_write() {
+    let f = setup();
+    let ids = batch_of(&f, MAX_SAFE_BATCH_SIZE, 500);
+    let expected: i128 = ids
+        .iter()
+        .map(|id| f.contract.get_remittance(&id).fee)
+        .sum();
+    f.contract.confirm_batch_payout(&f.agent, &ids, &false);
+    assert_eq!(f.contract.get_accumulated_fees(), expected);
+}
+
+#[test]
+fn test_590_confirm_batch_payout_cheaper_than_single_payouts() {
+    // Half the safe size keeps every call well inside the default budget
+    let size = MAX_SAFE_BATCH_SIZE / 2;
+    let f = setup();
+    let single = batch_of(&f, size, 500);
+    f.env.cost_estimate().budget().reset_default();
+    // Metering restarts at every top-level call, so sum the single payouts
+    let mut individual_cpu = 0;
+    for id in single.iter() {
+        f.contract
+            .confirm_payout(&f.agent, &id, &None, &None, &None);
+        individual_cpu += f.env.cost_estimate().budget().cpu_instruction_cost();
+    }
+
+    let f = setup();
+    let batched = batch_of(&f, size, 500);
+    f.env.cost_estimate().budget().reset_default();
+    f.contract.confirm_batch_payout(&f.agent, &batched, &false);
+    let batch_cpu = f.env.cost_estimate().budget().cpu_instruction_cost();
+
+    assert!(
+        batch_cpu < individual_cpu,
+        "batch {} >= individual {}",
+        batch_cpu,
+        individual_cpu
+    );
+    assert!(batch_cpu <= crate::estimate_batch_cost(size));
+}
+
+#[test]
+fn test_590_confirm_batch_payout_compact_events() {
+    let f = setup();
+    let full = batch_of(&f, 5, 500);
+    let compact = batch_of(&f, 5, 500);
+
+    f.contract.confirm_batch_payout(&f.agent, &full, &false);
+    let full_events = f
+        .env
+        .events()
+        .all()
+        .filter_by_contract(&f.contract.address)
+        .events()
+        .len();
+    f.contract.confirm_batch_payout(&f.agent, &compact, &true);
+    let compact_events = f
+        .env
+        .events()
+        .all()
+        .filter_by_contract(&f.contract.address)
+        .events()
+        .len();
+
+    // Two completion events per entry collapse into one summary
+    assert_eq!(full_events - compact_events, 2 * 5 - 1);
+    assert_eq!(
+        f.contract.get_remittance(&compact.get(4).unwrap()).status,
+        RemittanceStatus::Completed
+    );
+}
+
+#[test]
+fn test_batch_payout_rejects_batches_over_safe_size() {
+    let f = setup();
+    let max = f.contract.get_max_safe_batch_size();
+    assert_eq!(max, MAX_SAFE_BATCH_SIZE);
+    assert!(f.contract.estimate_batch_cost(&max) < f.contract.estimate_batch_cost(&(max + 1)));
+
+    let ids = batch_of(&f, max + 1, 500);
+    let r = f.contract.try_confirm_batch_payout(&f.agent, &ids, &false);
+    assert_eq!(r, Err(Ok(ContractError::BatchTooLarge)));
+}
+
+#[test]
+fn test_batch_payout_at_safe_size_fits_default_budget() {
+    let f = setup();
+    let ids = batch_of(&f, MAX_SAFE_BATCH_SIZE, 500);
+    let mut budget = f.env.cost_estimate().budget();
+    budget.reset_default();
+    // Exhausting the default budget would abort the call
+    f.contract.confirm_batch_payout(&f.agent, &ids, &true);
+    assert!(budget.cpu_instruction_cost() <= crate::estimate_batch_cost(MAX_SAFE_BATCH_SIZE));
+}
+
+#[test]
+fn test_batch_create_emits_created_event_per_entry() {
+    let f = setup();
+    let before = f.contract.get_remittance_count();
+    let ids = batch_of(&f, 3, 500);
+    let is_symbol = |topic: Option<&ScVal>, name: &[u8]| matches!(topic, Some(ScVal::Symbol(s)) if AsRef::<[u8]>::as_ref(s) == name);
+    let created = f
+        .env
+        .events()
+        .all()
+        .filter_by_contract(&f.contract.address)
+        .events()
+        .iter()
+        .filter(|e| {
+            let ContractEventBody::V0(body) = &e.body;
+            is_symbol(body.topics.first(), b"remit") && is_symbol(body.topics.get(1), b"created")
+        })
+        .count();
+    assert_eq!(created, 3);
+    assert_eq!(f.contract.get_remittance_count(), before + ids.len() as u64);
+    assert_eq!(balance(&f, &f.contract.address), 1_500);
+}
+
+#[test]
+fn test_batch_cancel_refunds_all_or_nothing() {
+    let f = setup();
+    let a = remit(&f, 1_000);
+    let b = remit(&f, 2_000);
+    let start = balance(&f, &f.sender);
+
+    let stranger = Address::generate(&f.env);
+    assert_eq!(
+        f.contract.try_batch_cancel(&stranger, &vec![&f.env, a, b]),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        f.contract.try_batch_cancel(&f.sender, &vec![&f.env, a, a]),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+    assert_eq!(
+        f.contract.get_remittance(&a).status,
+        RemittanceStatus::Pending
+    );
+
+    f.contract.batch_cancel(&f.sender, &vec![&f.env, a, b]);
+    assert_eq!(
+        f.contract.get_remittance(&a).status,
+        RemittanceStatus::Cancelled
+    );
+    assert_eq!(
+        f.contract.get_remittance(&b).status,
+        RemittanceStatus::Cancelled
+    );
+    assert_eq!(balance(&f, &f.sender), start + 3_000);
+}
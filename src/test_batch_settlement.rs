@@ -0,0 +1,89 @@
+//! Tests for per-agent authorization in `batch_settle_with_netting`.
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, MockAuth, MockAuthInvoke},
+    vec, Address, IntoVal, Symbol, Vec,
+};
+
+use crate::test_utils::{remit, setup, Fixture};
+use crate::{BatchSettlementEntry, RemittanceStatus};
+
+/// Creates one remittance for the fixture agent and one for a second agent.
+fn two_agent_batch(f: &Fixture) -> (Address, Vec<BatchSettlementEntry>, u64, u64) {
+    let other = Address::generate(&f.env);
+    f.contract.register_agent(&other, &None);
+    let id_a = remit(f, 1_000);
+    let id_b = f
+        .contract
+        .create_remittance(&f.sender, &other, &1_000, &None, &None, &None, &None, &None);
+
+    let entries = vec![
+        &f.env,
+        BatchSettlementEntry { remittance_id: id_a },
+        BatchSettlementEntry { remittance_id: id_b },
+    ];
+    (other, entries, id_a, id_b)
+}
+
+fn settle_auth(f: &Fixture, agent: &Address, id: u64) -> (Address, AuthorizedInvocation) {
+    (
+        agent.clone(),
+        AuthorizedInvocation {
+            function: AuthorizedFunction::Contract((
+                f.contract.address.clone(),
+                Symbol::new(&f.env, "batch_settle_with_netting"),
+                (vec![&f.env, id],).into_val(&f.env),
+            )),
+            sub_invocations: std::vec::Vec::new(),
+        },
+    )
+}
+
+#[test]
+fn test_batch_settle_requires_each_agent_auth_for_own_ids() {
+    let f = setup();
+    let (other, entries, id_a, id_b) = two_agent_batch(&f);
+
+    f.contract.batch_settle_with_netting(&entries);
+
+    let auths = f.env.auths();
+    assert_eq!(auths.len(), 2);
+    assert!(auths.contains(&settle_auth(&f, &f.agent, id_a)));
+    assert!(auths.contains(&settle_auth(&f, &other, id_b)));
+}
+
+#[test]
+fn test_batch_settle_without_agent_auth_fails() {
+    let f = setup();
+    let (_, entries, id_a, id_b) = two_agent_batch(&f);
+
+    // An arbitrary submitter must not be able to settle
+    f.env.set_auths(&[]);
+    assert!(f.contract.try_batch_settle_with_netting(&entries).is_err());
+
+    assert_eq!(f.contract.get_remittance(&id_a).status, RemittanceStatus::Pending);
+    assert_eq!(f.contract.get_remittance(&id_b).status, RemittanceStatus::Pending);
+}
+
+#[test]
+fn test_batch_settle_with_only_one_agent_auth_fails() {
+    let f = setup();
+    let (_, entries, id_a, id_b) = two_agent_batch(&f);
+
+    // Only the fixture agent authorizes its own entry; the other entry blocks the batch
+    f.env.mock_auths(&[MockAuth {
+        address: &f.agent,
+        invoke: &MockAuthInvoke {
+            contract: &f.contract.address,
+            fn_name: "batch_settle_with_netting",
+            args: (vec![&f.env, id_a],).into_val(&f.env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(f.contract.try_batch_settle_with_netting(&entries).is_err());
+
+    assert_eq!(f.contract.get_remittance(&id_a).status, RemittanceStatus::Pending);
+    assert_eq!(f.contract.get_remittance(&id_b).status, RemittanceStatus::Pending);
+}
@@ -0,0 +1,49 @@
+//! Tests for when a sender may cancel a remittance.
+
+#![cfg(test)]
+
+use crate::test_utils::{advance_time, balance, remit, setup};
+use crate::{ContractError, DeliveryStatus};
+
+#[test]
+fn test_cancel_closed_after_agent_accepts() {
+    let f = setup();
+    let id = remit(&f, 1000);
+    f.contract
+        .update_delivery_status(&f.agent, &id, &DeliveryStatus::FundsReserved);
+    assert_eq!(
+        f.contract.try_cancel_remittance(&id),
+        Err(Ok(ContractError::CancelWindowClosed))
+    );
+    f.contract.mutual_cancel_remittance(&id);
+    assert_eq!(balance(&f, &f.sender), 100_000);
+}
+
+#[test]
+fn test_cancel_reopens_after_sla_breach() {
+    let f = setup();
+    let id = remit(&f, 1000);
+    f.contract
+        .update_delivery_status(&f.agent, &id, &DeliveryStatus::FundsReserved);
+    advance_time(&f, crate::config::BASE_SLA_SECONDS + 1);
+    f.contract.cancel_remittance(&id);
+    assert_eq!(balance(&f, &f.sender), 100_000);
+}
+
+#[test]
+fn test_cancel_cooldown_waived_on_rejection() {
+    let f = setup();
+    f.contract.set_cancel_cooldown(&f.admin, &600);
+    let id = remit(&f, 1000);
+    assert_eq!(
+        f.contract.try_cancel_remittance(&id),
+        Err(Ok(ContractError::CancelCooldownActive))
+    );
+    f.contract.reject_remittance(&f.agent, &id);
+    f.contract.cancel_remittance(&id);
+
+    let id = remit(&f, 1000);
+    advance_time(&f, 600);
+    f.contract.cancel_remittance(&id);
+    assert_eq!(balance(&f, &f.sender), 100_000);
+}
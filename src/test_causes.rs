@@ -0,0 +1,62 @@
+//! Tests for donations pledged from remittances to registered causes.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, String};
+
+use crate::test_utils::{balance, remit, setup};
+use crate::{ContractError, DonationSource};
+
+#[test]
+fn test_donation_from_amount_credited_to_cause() {
+    let f = setup();
+    let cause = Address::generate(&f.env);
+    f.contract
+        .register_cause(&f.admin, &cause, &String::from_str(&f.env, "Relief"));
+    let id = remit(&f, 10_000);
+    f.contract
+        .pledge_donation(&id, &cause, &1000u32, &DonationSource::Amount);
+
+    let fee = f.contract.get_remittance(&id).fee;
+    let before = balance(&f, &f.agent);
+    f.contract
+        .confirm_batch_payout(&f.agent, &vec![&f.env, id], &false);
+    let donated = f
+        .contract
+        .get_cause_balance(&cause, &f.token.address)
+        .donated;
+    assert_eq!(donated, (10_000 - fee) / 10);
+    assert_eq!(balance(&f, &f.agent) - before, 10_000 - fee - donated);
+    assert_eq!(f.contract.get_accumulated_fees(), fee);
+
+    assert_eq!(
+        f.contract
+            .withdraw_cause_donations(&cause, &f.token.address),
+        donated
+    );
+    assert_eq!(balance(&f, &cause), donated);
+    assert_eq!(
+        f.contract
+            .try_withdraw_cause_donations(&cause, &f.token.address),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_donation_pledge_rejected_for_inactive_cause() {
+    let f = setup();
+    let cause = Address::generate(&f.env);
+    let id = remit(&f, 10_000);
+    let r = f
+        .contract
+        .try_pledge_donation(&id, &cause, &500u32, &DonationSource::Fee);
+    assert_eq!(r, Err(Ok(ContractError::CauseNotFound)));
+
+    f.contract
+        .register_cause(&f.admin, &cause, &String::from_str(&f.env, "Relief"));
+    f.contract.deactivate_cause(&f.admin, &cause);
+    let r = f
+        .contract
+        .try_pledge_donation(&id, &cause, &500u32, &DonationSource::Fee);
+    assert_eq!(r, Err(Ok(ContractError::CauseNotFound)));
+}
@@ -0,0 +1,28 @@
+//! Tests for periodic indexer checkpoint events.
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::Events as _,
+    xdr::{ContractEventBody, ScVal},
+};
+
+use crate::test_utils::{remit, setup, Fixture};
+use crate::CHECKPOINT_INTERVAL;
+
+#[test]
+fn test_checkpoint_emitted_every_interval() {
+    let f = setup();
+    let checkpointed = |f: &Fixture| {
+        f.env.events().all().filter_by_contract(&f.contract.address).events().iter().any(|e| {
+        let ContractEventBody::V0(body) = &e.body;
+        matches!(body.topics.get(1), Some(ScVal::Symbol(s)) if AsRef::<[u8]>::as_ref(s) == b"chkpt")
+    })
+    };
+    for _ in 1..CHECKPOINT_INTERVAL {
+        remit(&f, 100);
+    }
+    assert!(!checkpointed(&f));
+    remit(&f, 100);
+    assert!(checkpointed(&f));
+}
@@ -0,0 +1,35 @@
+//! Tests for agent collateral requirements and slashing.
+
+#![cfg(test)]
+
+use crate::test_utils::{balance, remit, setup};
+use crate::ContractError;
+
+#[test]
+fn test_collateral_requirement_and_slashing() {
+    let f = setup();
+    f.contract
+        .set_collateral_requirement(&f.admin, &5_000, &1_000);
+    remit(&f, 5_000);
+    assert_eq!(
+        f.contract
+            .try_create_remittance(&f.sender, &f.agent, &6_000, &None, &None, &None, &None, &None),
+        Err(Ok(ContractError::AgentStakeRequired))
+    );
+
+    f.token.mint(&f.agent, &1_000);
+    f.contract.stake_agent(&f.agent, &f.token.address, &1_000);
+    let id = remit(&f, 6_000);
+    assert_eq!(
+        f.contract.try_unstake_agent(&f.agent, &f.token.address, &1),
+        Err(Ok(ContractError::AgentStakeRequired))
+    );
+
+    let sender_before = balance(&f, &f.sender);
+    assert_eq!(f.contract.slash_agent(&f.admin, &id, &400), 600);
+    assert_eq!(balance(&f, &f.sender), sender_before + 400);
+    assert_eq!(
+        f.contract.try_slash_agent(&f.admin, &id, &601),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+}
@@ -0,0 +1,76 @@
+//! Tests for lifecycle latencies carried in `remit/complete` events.
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::Events as _,
+    xdr::{ContractEventBody, ScVal},
+};
+
+use crate::test_utils::{advance_time, remit, setup, Fixture};
+use crate::ContractError;
+
+/// Returns `(settlement_latency, processing_latency)` from the `remit/complete`
+/// event emitted by the last contract call.
+fn completion_latencies(f: &Fixture) -> Option<(u64, Option<u64>)> {
+    f.env
+        .events()
+        .all()
+        .filter_by_contract(&f.contract.address)
+        .events()
+        .iter()
+        .find_map(|event| {
+            let ContractEventBody::V0(body) = &event.body;
+            let is_complete = matches!(
+                (body.topics.first(), body.topics.get(1)),
+                (Some(ScVal::Symbol(a)), Some(ScVal::Symbol(b)))
+                    if a.0.as_slice() == b"remit" && b.0.as_slice() == b"complete"
+            );
+            let ScVal::Vec(Some(fields)) = &body.data else {
+                return None;
+            };
+            let [.., settlement, processing] = fields.as_slice() else {
+                return None;
+            };
+            match (is_complete, settlement, processing) {
+                (true, ScVal::U64(settlement), ScVal::U64(processing)) => {
+                    Some((*settlement, Some(*processing)))
+                }
+                (true, ScVal::U64(settlement), ScVal::Void) => Some((*settlement, None)),
+                _ => None,
+            }
+        })
+}
+
+#[test]
+fn test_completion_event_reports_latencies_after_partial_payouts() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+
+    advance_time(&f, 600);
+    f.contract.confirm_partial_payout(&id, &500);
+    assert_eq!(completion_latencies(&f), None);
+
+    advance_time(&f, 300);
+    f.contract.confirm_partial_payout(&id, &475);
+    assert_eq!(completion_latencies(&f), Some((900, Some(300))));
+}
+
+#[test]
+fn test_rejected_confirmation_keeps_recorded_latencies() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+
+    advance_time(&f, 600);
+    f.contract.confirm_payout(&f.agent, &id, &None, &None, &None);
+    assert_eq!(completion_latencies(&f), Some((600, Some(0))));
+
+    advance_time(&f, 300);
+    assert_eq!(
+        f.contract.try_confirm_payout(&f.agent, &id, &None, &None, &None),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+    let remittance = f.contract.get_remittance(&id);
+    assert_eq!(remittance.settlement_latency(), Some(600));
+    assert_eq!(remittance.processing_latency(), Some(0));
+}
@@ -0,0 +1,26 @@
+//! Tests for configuration events reporting the old and new values.
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::Events as _,
+    xdr::{ContractEventBody, ScVal},
+};
+
+use crate::test_utils::setup;
+
+#[test]
+fn test_fee_updated_event_reports_previous_fee() {
+    let f = setup();
+    f.contract.update_fee(&300);
+    let values = f.env.events().all().filter_by_contract(&f.contract.address).events().iter().find_map(|e| {
+        let ContractEventBody::V0(body) = &e.body;
+        let is_fee_update = matches!(body.topics.get(1), Some(ScVal::Symbol(s)) if AsRef::<[u8]>::as_ref(s) == b"updated")
+            && matches!(body.topics.first(), Some(ScVal::Symbol(s)) if AsRef::<[u8]>::as_ref(s) == b"fee");
+        match &body.data {
+            ScVal::Vec(Some(data)) if is_fee_update => Some((data[3].clone(), data[4].clone())),
+            _ => None,
+        }
+    });
+    assert_eq!(values, Some((ScVal::U32(250), ScVal::U32(300))));
+}
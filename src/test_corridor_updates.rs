@@ -0,0 +1,110 @@
+//! Tests for batched corridor configuration updates.
+
+#![cfg(test)]
+
+use soroban_sdk::{vec, Env, String};
+
+use crate::test_utils::setup;
+use crate::{ContractError, CorridorConfigUpdate, CorridorFeeUpdate, FeeStrategy};
+
+fn country(env: &Env, code: &str) -> String {
+    String::from_str(env, code)
+}
+
+fn update(
+    env: &Env,
+    from: &str,
+    to: &str,
+    fee: CorridorFeeUpdate,
+    enabled: Option<bool>,
+) -> CorridorConfigUpdate {
+    CorridorConfigUpdate {
+        from_country: country(env, from),
+        to_country: country(env, to),
+        fee,
+        daily_limit: Some(50_000),
+        enabled,
+    }
+}
+
+#[test]
+fn test_update_corridors_applies_batch() {
+    let f = setup();
+    let env = &f.env;
+    let updates = vec![
+        env,
+        update(
+            env,
+            "US",
+            "MX",
+            CorridorFeeUpdate::Set(FeeStrategy::Percentage(100), Some(50)),
+            None,
+        ),
+        update(env, "US", "NG", CorridorFeeUpdate::Unchanged, Some(false)),
+    ];
+
+    f.contract.update_corridors(&f.admin, &updates);
+
+    let mx = f
+        .contract
+        .get_fee_corridor(&country(env, "US"), &country(env, "MX"))
+        .unwrap();
+    assert_eq!(mx.strategy, FeeStrategy::Percentage(100));
+    assert_eq!(mx.protocol_fee_bps, Some(50));
+    assert!(!f
+        .contract
+        .is_corridor_enabled(&country(env, "US"), &country(env, "NG")));
+    assert_eq!(
+        f.contract.try_create_remittance_with_corridor(
+            &f.sender,
+            &f.agent,
+            &10_000,
+            &None,
+            &Some(country(env, "US")),
+            &Some(country(env, "NG")),
+        ),
+        Err(Ok(ContractError::CorridorDisabled))
+    );
+}
+
+#[test]
+fn test_update_corridors_rejects_whole_batch_on_invalid_entry() {
+    let f = setup();
+    let env = &f.env;
+    let set_mx = || {
+        update(
+            env,
+            "US",
+            "MX",
+            CorridorFeeUpdate::Set(FeeStrategy::Percentage(100), None),
+            None,
+        )
+    };
+
+    let conflicting = vec![env, set_mx(), update(env, "US", "MX", CorridorFeeUpdate::Remove, None)];
+    assert_eq!(
+        f.contract.try_update_corridors(&f.admin, &conflicting),
+        Err(Ok(ContractError::InvalidCorridorUpdate))
+    );
+
+    let invalid_fee = vec![
+        env,
+        set_mx(),
+        update(
+            env,
+            "US",
+            "GH",
+            CorridorFeeUpdate::Set(FeeStrategy::Percentage(10_001), None),
+            None,
+        ),
+    ];
+    assert_eq!(
+        f.contract.try_update_corridors(&f.admin, &invalid_fee),
+        Err(Ok(ContractError::InvalidFeeBps))
+    );
+    assert_eq!(
+        f.contract
+            .get_fee_corridor(&country(env, "US"), &country(env, "MX")),
+        None
+    );
+}
@@ -160,15 +160,6 @@ fn test_update_fee_zero_bps() {
     assert_eq!(contract.get_platform_fee_bps(), 0);
 }
 
-#[test]
-fn test_update_fee_max_bps() {
-    let env = Env::default();
-    let (contract, _token, _admin, _agent, _sender) = setup(&env);
-    env.mock_all_auths();
-    contract.update_fee(&10_000);
-    assert_eq!(contract.get_platform_fee_bps(), 10_000);
-}
-
 // ── withdraw_fees error paths ─────────────────────────────────────────────────
 
 #[test]
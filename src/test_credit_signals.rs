@@ -0,0 +1,57 @@
+//! Tests for activity summaries published as credit signals.
+
+#![cfg(test)]
+
+use soroban_sdk::vec;
+
+use crate::test_utils::{advance_time, remit, setup};
+use crate::{CreditParty, CREDIT_SIGNAL_PERIOD_SECONDS};
+
+#[test]
+fn test_credit_signal_summaries_published_after_period() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    remit(&f, 500);
+    f.contract
+        .confirm_batch_payout(&f.agent, &vec![&f.env, id], &false);
+
+    let sender = f
+        .contract
+        .get_activity_summary(&f.sender, &CreditParty::Sender)
+        .unwrap();
+    assert_eq!(
+        (sender.created, sender.completed, sender.on_time),
+        (2, 1, 1)
+    );
+    assert_eq!(sender.created_volume, 1_500);
+    assert_eq!(sender.completed_volume, 1_000);
+    let agent = f
+        .contract
+        .get_activity_summary(&f.agent, &CreditParty::Agent)
+        .unwrap();
+    assert_eq!(agent.completed, 1);
+
+    assert_eq!(
+        f.contract
+            .publish_credit_signal(&f.sender, &CreditParty::Sender),
+        None
+    );
+    advance_time(&f, CREDIT_SIGNAL_PERIOD_SECONDS);
+    assert_eq!(
+        f.contract
+            .publish_credit_signal(&f.sender, &CreditParty::Sender),
+        Some(sender)
+    );
+    assert_eq!(
+        f.contract
+            .get_activity_summary(&f.sender, &CreditParty::Sender),
+        None
+    );
+
+    remit(&f, 200);
+    let agent_now = f
+        .contract
+        .get_activity_summary(&f.agent, &CreditParty::Agent)
+        .unwrap();
+    assert_eq!((agent_now.created, agent_now.completed), (1, 0));
+}
@@ -0,0 +1,192 @@
+//! Tests for custom account (smart wallet) authorization.
+//!
+//! Unlike `mock_all_auths`, `set_auths` enforces real authorization entries
+//! and runs the wallet's `__check_auth`, so these tests catch flows that
+//! authorize the same address more than once per invocation or whose
+//! invocation tree a wallet could not sign up front.
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    auth::{Context, CustomAccountInterface},
+    contract, contractimpl,
+    crypto::Hash,
+    symbol_short,
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    token, vec, xdr, Address, Env, IntoVal, Vec,
+};
+
+use crate::{SwiftRemitContract, SwiftRemitContractClient};
+
+/// Wallet that accepts any signature and counts `__check_auth` calls.
+#[contract]
+pub struct MockWallet;
+
+#[contractimpl]
+impl CustomAccountInterface for MockWallet {
+    type Signature = ();
+    type Error = soroban_sdk::Error;
+
+    #[allow(non_snake_case)]
+    fn __check_auth(
+        env: Env,
+        _signature_payload: Hash<32>,
+        _signatures: (),
+        _auth_contexts: Vec<Context>,
+    ) -> Result<(), soroban_sdk::Error> {
+        let key = symbol_short!("checks");
+        let checks: u32 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(checks + 1));
+        Ok(())
+    }
+}
+
+fn wallet_checks(env: &Env, wallet: &Address) -> u32 {
+    env.as_contract(wallet, || {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("checks"))
+            .unwrap_or(0)
+    })
+}
+
+/// Builds an enforced authorization entry for `wallet` that runs its
+/// `__check_auth` instead of being mocked away.
+fn wallet_auth(wallet: &Address, invoke: &MockAuthInvoke) -> xdr::SorobanAuthorizationEntry {
+    MockAuth {
+        address: wallet,
+        invoke,
+    }
+    .into()
+}
+
+struct Setup<'a> {
+    env: Env,
+    contract: SwiftRemitContractClient<'a>,
+    token: token::StellarAssetClient<'a>,
+    agent: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let token = token::StellarAssetClient::new(
+        &env,
+        &env.register_stellar_asset_contract_v2(admin.clone()).address(),
+    );
+    let contract = SwiftRemitContractClient::new(
+        &env,
+        &env.register(SwiftRemitContract, ()),
+    );
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    let agent = Address::generate(&env);
+    contract.register_agent(&agent, &None);
+    Setup {
+        env,
+        contract,
+        token,
+        agent,
+    }
+}
+
+#[test]
+fn test_wallet_sender_creates_remittance() {
+    let s = setup();
+    let wallet = s.env.register(MockWallet, ());
+    s.token.mint(&wallet, &10_000);
+
+    let none: Option<Address> = None;
+    let invoke = MockAuthInvoke {
+        contract: &s.contract.address,
+        fn_name: "create_remittance",
+        args: vec![
+            &s.env,
+            wallet.into_val(&s.env),
+            s.agent.into_val(&s.env),
+            1_000_i128.into_val(&s.env),
+            none.into_val(&s.env),
+            none.into_val(&s.env),
+            none.into_val(&s.env),
+            none.into_val(&s.env),
+            none.into_val(&s.env),
+        ],
+        sub_invokes: &[MockAuthInvoke {
+            contract: &s.token.address,
+            fn_name: "transfer",
+            args: (wallet.clone(), s.contract.address.clone(), 1_000_i128).into_val(&s.env),
+            sub_invokes: &[],
+        }],
+    };
+    s.env.set_auths(&[wallet_auth(&wallet, &invoke)]);
+
+    let id = s
+        .contract
+        .create_remittance(&wallet, &s.agent, &1_000, &None, &None, &None, &None, &None);
+    assert_eq!(s.contract.get_remittance(&id).sender, wallet);
+    assert_eq!(wallet_checks(&s.env, &wallet), 1);
+}
+
+#[test]
+fn test_wallet_agent_signs_batch_payout_once() {
+    let s = setup();
+    // Three payouts touch more ledger entries than one invocation may by default
+    s.env.cost_estimate().disable_resource_limits();
+    let wallet = s.env.register(MockWallet, ());
+    s.contract.register_agent(&wallet, &None);
+    let sender = Address::generate(&s.env);
+    s.contract.set_kyc_approved(&sender, &true, &u64::MAX);
+    s.token.mint(&sender, &10_000);
+    let mut ids = Vec::new(&s.env);
+    for _ in 0..3 {
+        ids.push_back(s.contract.create_remittance(
+            &sender, &wallet, &1_000, &None, &None, &None, &None, &None,
+        ));
+    }
+
+    let mut entries: Vec<(u64, i128, Address)> = Vec::new(&s.env);
+    for id in ids.iter() {
+        entries.push_back((id, 1_000, wallet.clone()));
+    }
+    let invoke = MockAuthInvoke {
+        contract: &s.contract.address,
+        fn_name: "confirm_batch_payout",
+        args: (entries,).into_val(&s.env),
+        sub_invokes: &[],
+    };
+    s.env.set_auths(&[wallet_auth(&wallet, &invoke)]);
+
+    assert_eq!(s.contract.confirm_batch_payout(&wallet, &ids, &false), ids);
+    assert_eq!(wallet_checks(&s.env, &wallet), 1);
+}
+
+#[test]
+fn test_wallet_auth_is_bound_to_arguments() {
+    let s = setup();
+    let wallet = s.env.register(MockWallet, ());
+    s.contract.register_agent(&wallet, &None);
+    let sender = Address::generate(&s.env);
+    s.token.mint(&sender, &10_000);
+    let first = s
+        .contract
+        .create_remittance(&sender, &wallet, &1_000, &None, &None, &None, &None, &None);
+    let second = s
+        .contract
+        .create_remittance(&sender, &wallet, &1_000, &None, &None, &None, &None, &None);
+
+    // The wallet signed a settlement set with only the first remittance.
+    let signed: Vec<(u64, i128, Address)> = vec![&s.env, (first, 1_000, wallet.clone())];
+    let invoke = MockAuthInvoke {
+        contract: &s.contract.address,
+        fn_name: "confirm_batch_payout",
+        args: (signed,).into_val(&s.env),
+        sub_invokes: &[],
+    };
+    s.env.set_auths(&[wallet_auth(&wallet, &invoke)]);
+
+    let submitted = vec![&s.env, first, second];
+    assert!(s
+        .contract
+        .try_confirm_batch_payout(&wallet, &submitted, &false)
+        .is_err());
+}
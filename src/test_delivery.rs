@@ -0,0 +1,57 @@
+//! Tests for delivery status updates and delivery proofs.
+
+#![cfg(test)]
+
+use soroban_sdk::BytesN;
+
+use crate::test_utils::{remit, setup};
+use crate::{ContractError, DeliveryStatus};
+
+#[test]
+fn test_delivery_status_moves_forward() {
+    let f = setup();
+    let id = remit(&f, 1000);
+    assert_eq!(f.contract.get_delivery_status(&id), None);
+    f.contract
+        .update_delivery_status(&f.agent, &id, &DeliveryStatus::RecipientNotified);
+    assert_eq!(
+        f.contract
+            .try_update_delivery_status(&f.agent, &id, &DeliveryStatus::FundsReserved),
+        Err(Ok(ContractError::InvalidStateTransition))
+    );
+    f.contract
+        .update_delivery_status(&f.agent, &id, &DeliveryStatus::Delivered);
+    assert_eq!(
+        f.contract.get_delivery_status(&id).unwrap().status,
+        DeliveryStatus::Delivered
+    );
+
+    f.contract
+        .confirm_payout(&f.agent, &id, &None, &None, &None);
+    assert_eq!(
+        f.contract
+            .try_update_delivery_status(&f.agent, &id, &DeliveryStatus::Delivered),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_high_value_payout_requires_delivery_proof() {
+    let f = setup();
+    f.contract.set_delivery_proof_threshold(&f.admin, &5_000);
+    let small = remit(&f, 1000);
+    f.contract
+        .confirm_payout(&f.agent, &small, &None, &None, &None);
+    assert_eq!(f.contract.get_delivery_proof(&small), None);
+
+    let large = remit(&f, 5_000);
+    assert_eq!(
+        f.contract
+            .try_confirm_payout(&f.agent, &large, &None, &None, &None),
+        Err(Ok(ContractError::DeliveryProofRequired))
+    );
+    let receipt = BytesN::from_array(&f.env, &[7u8; 32]);
+    f.contract
+        .confirm_with_delivery_proof(&f.agent, &large, &receipt, &None, &None, &None);
+    assert_eq!(f.contract.get_delivery_proof(&large), Some(receipt));
+}
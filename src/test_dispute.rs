@@ -138,7 +138,7 @@ fn test_mark_failed_on_completed_remittance_rejected() {
     contract.register_agent(&agent, &None);
 
     let id = contract.create_remittance(&sender, &agent, &1_000i128, &None, &None, &None, &None, &None);
-    contract.confirm_payout(&id, &None, &None);
+    contract.confirm_payout(&agent, &id, &None, &None, &None);
 
     let result = contract.try_mark_failed(&id);
     assert_eq!(result, Err(Ok(ContractError::InvalidStatus)));
@@ -446,3 +446,96 @@ fn test_dispute_resolution_balance_invariant_agent_wins() {
 
     assert_eq!(total_before, total_after);
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Insurance
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_insured_sender_compensated_on_agent_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let token = create_token(&env, &admin);
+    token.mint(&sender, &10_000);
+    token.mint(&admin, &1_000);
+
+    let contract = make_contract(&env);
+    contract.initialize(&admin, &token.address, &250u32, &0u64, &0u32, &admin);
+    contract.register_agent(&agent, &None);
+    contract.fund_insurance(&admin, &token.address, &1_000);
+
+    let id = contract.create_remittance(&sender, &agent, &1_000i128, &None, &None, &None, &None, &None);
+    let policy = contract.insure_remittance(&id);
+    assert_eq!(contract.get_insurance_fund(&token.address), 1_000 + policy.premium);
+    assert_eq!(
+        contract.try_insure_remittance(&id),
+        Err(Ok(ContractError::AlreadyInsured))
+    );
+
+    let sender_before = balance(&env, &token, &sender);
+    contract.mark_failed(&id);
+
+    assert_eq!(balance(&env, &token, &sender), sender_before + 1_000 + policy.coverage);
+    assert!(contract.get_insurance_policy(&id).unwrap().paid_out);
+    assert_eq!(
+        contract.get_insurance_fund(&token.address),
+        1_000 + policy.premium - policy.coverage
+    );
+}
+
+#[test]
+fn test_insurance_claim_approved_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let resolver = Address::generate(&env);
+
+    let token = create_token(&env, &admin);
+    token.mint(&sender, &10_000);
+    token.mint(&admin, &1_000);
+
+    let contract = make_contract(&env);
+    contract.initialize(&admin, &token.address, &250u32, &0u64, &0u32, &admin);
+    contract.register_agent(&agent, &None);
+    contract.set_kyc_approved(&sender, &true, &u64::MAX);
+    contract.assign_role(&admin, &resolver, &crate::Role::ClaimResolver);
+    contract.fund_insurance(&admin, &token.address, &1_000);
+
+    let id = contract.create_remittance(&sender, &agent, &1_000i128, &None, &None, &None, &None, &None);
+    let policy = contract.insure_remittance(&id);
+    assert_eq!(
+        contract.try_file_claim(&id, &evidence_hash(&env)),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+
+    // Agent confirms a payout the recipient disputes off-chain
+    contract.confirm_payout(&agent, &id, &None, &None, &None);
+    contract.file_claim(&id, &evidence_hash(&env));
+    assert_eq!(
+        contract.try_file_claim(&id, &evidence_hash(&env)),
+        Err(Ok(ContractError::ClaimAlreadyFiled))
+    );
+    assert_eq!(
+        contract.try_approve_claim(&agent, &id),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    let sender_before = balance(&env, &token, &sender);
+    let claim = contract.approve_claim(&resolver, &id);
+    assert_eq!(claim.status, crate::ClaimStatus::Approved);
+    assert_eq!(claim.payout, policy.coverage);
+    assert_eq!(balance(&env, &token, &sender), sender_before + policy.coverage);
+    assert_eq!(
+        contract.try_deny_claim(&resolver, &id),
+        Err(Ok(ContractError::ClaimAlreadyResolved))
+    );
+    assert_eq!(contract.get_sender_claims(&sender), soroban_sdk::vec![&env, id]);
+}
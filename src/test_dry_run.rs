@@ -0,0 +1,110 @@
+//! Tests for previewing remittance creation and batch settlement.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Vec};
+
+use crate::storage;
+use crate::test_utils::{balance, remit, settlement_entries, setup, SENDER_FUNDS};
+use crate::{ContractError, RemittanceStatus};
+
+#[test]
+fn test_dry_run_create_matches_created_remittance() {
+    let f = setup();
+    f.env.as_contract(&f.contract.address, || {
+        storage::set_remittance_expiry_window(&f.env, 3_600);
+    });
+    remit(&f, 1_000);
+
+    let preview = f
+        .contract
+        .dry_run_create(&f.sender, &f.agent, &1_000, &None, &None);
+    assert_eq!(preview.payout_amount, 975);
+    assert_eq!(preview.daily_limit_remaining, None);
+    assert_eq!(balance(&f, &f.sender), SENDER_FUNDS - 1_000);
+
+    let id = remit(&f, 1_000);
+    assert_eq!(f.contract.get_remittance(&id), preview.remittance);
+    assert!(preview.remittance.expires_at.is_some());
+}
+
+#[test]
+fn test_dry_run_create_reflects_fee_exemption() {
+    let f = setup();
+    f.contract.add_fee_exempt(&f.admin, &f.sender);
+
+    let preview = f
+        .contract
+        .dry_run_create(&f.sender, &f.agent, &1_000, &None, &None);
+    assert_eq!(preview.remittance.fee, 0);
+    assert_eq!(preview.payout_amount, 1_000);
+    assert_eq!(preview.volume_discount, 0);
+
+    let id = remit(&f, 1_000);
+    assert_eq!(f.contract.get_remittance(&id), preview.remittance);
+}
+
+#[test]
+fn test_dry_run_create_rejects_unregistered_agent() {
+    let f = setup();
+    let stranger = Address::generate(&f.env);
+
+    assert_eq!(
+        f.contract
+            .try_dry_run_create(&f.sender, &stranger, &1_000, &None, &None),
+        Err(Ok(ContractError::AgentNotRegistered))
+    );
+}
+
+#[test]
+fn test_dry_run_batch_settle_projects_transfers() {
+    let f = setup();
+    let a = remit(&f, 1000);
+    let b = remit(&f, 1000);
+    let preview = f
+        .contract
+        .dry_run_batch_settle(&settlement_entries(&f, &[a, b]));
+    assert!(preview.would_succeed);
+    assert_eq!(preview.fees, vec![&f.env, (f.token.address.clone(), 50)]);
+    assert_eq!(preview.entries.get(1).unwrap().payout_amount, 975);
+    assert_eq!(
+        preview.transfers,
+        vec![&f.env, (f.token.address.clone(), f.agent.clone(), 1950i128)]
+    );
+    assert_eq!(balance(&f, &f.agent), 0);
+    assert_eq!(
+        f.contract.get_remittance(&a).status,
+        RemittanceStatus::Pending
+    );
+}
+
+#[test]
+fn test_dry_run_batch_settle_reports_each_failure() {
+    let f = setup();
+    let ok = remit(&f, 1000);
+    let cancelled = remit(&f, 1000);
+    f.contract.cancel_remittance(&cancelled);
+    let preview = f
+        .contract
+        .dry_run_batch_settle(&settlement_entries(&f, &[ok, cancelled, ok, 99]));
+    assert!(!preview.would_succeed);
+    assert_eq!(
+        preview.error_code,
+        Some(ContractError::InvalidStatus as u32)
+    );
+    let mut codes = Vec::new(&f.env);
+    for entry in preview.entries.iter() {
+        codes.push_back(entry.error_code);
+    }
+    assert_eq!(
+        codes,
+        vec![
+            &f.env,
+            None,
+            Some(ContractError::InvalidStatus as u32),
+            Some(ContractError::DuplicateSettlement as u32),
+            Some(ContractError::RemittanceNotFound as u32)
+        ]
+    );
+    assert!(preview.transfers.is_empty());
+}
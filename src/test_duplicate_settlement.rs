@@ -0,0 +1,54 @@
+//! Tests for duplicate-settlement detection from remittance state.
+
+#![cfg(test)]
+
+use soroban_sdk::{vec, Symbol};
+
+use crate::storage;
+use crate::test_utils::{remit, setup};
+use crate::ContractError;
+
+#[test]
+fn test_settled_remittance_is_detected_from_its_record() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    assert_eq!(
+        f.contract.try_get_settlement_hash(&id),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+
+    f.contract.confirm_payout(&f.agent, &id, &None, &None, &None);
+
+    assert!(f.contract.get_remittance(&id).is_settlement_executed());
+    assert_eq!(
+        f.contract.get_settlement_hash(&id),
+        f.contract.compute_settlement_hash(&id)
+    );
+
+    // Entries left by the former hash-based tracking are removed once
+    let legacy_key = (Symbol::new(&f.env, "SettlementHash"), id);
+    f.env.as_contract(&f.contract.address, || {
+        f.env.storage().persistent().set(&legacy_key, &true);
+    });
+    let ids = vec![&f.env, id];
+    assert_eq!(f.contract.cleanup_settlement_entries(&f.admin, &ids), 1);
+    assert_eq!(f.contract.cleanup_settlement_entries(&f.admin, &ids), 0);
+}
+
+#[test]
+fn test_accepted_remittance_cannot_be_settled_again() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+
+    // A record stamped as accepted counts as settled even if still Pending
+    f.env.as_contract(&f.contract.address, || {
+        let mut remittance = storage::get_remittance(&f.env, id).unwrap();
+        remittance.accepted_at = Some(0);
+        storage::set_remittance(&f.env, id, &remittance);
+    });
+
+    assert_eq!(
+        f.contract.try_confirm_payout(&f.agent, &id, &None, &None, &None),
+        Err(Ok(ContractError::DuplicateSettlement))
+    );
+}
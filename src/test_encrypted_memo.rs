@@ -0,0 +1,40 @@
+//! Tests for encrypted memo envelopes attached to remittances.
+
+#![cfg(test)]
+
+use soroban_sdk::Bytes;
+
+use crate::config::MAX_ENCRYPTED_MEMO_BYTES;
+use crate::test_utils::{remit, setup};
+use crate::ContractError;
+
+#[test]
+fn test_encrypted_memo_attached_once() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    let ciphertext = Bytes::from_slice(&f.env, &[0xC1; 96]);
+
+    f.contract
+        .attach_encrypted_memo(&f.sender, &id, &ciphertext);
+
+    assert_eq!(f.contract.get_encrypted_memo(&id), Some(ciphertext.clone()));
+    assert_eq!(
+        f.contract
+            .try_attach_encrypted_memo(&f.sender, &id, &ciphertext),
+        Err(Ok(ContractError::EncryptedMemoAlreadySet))
+    );
+}
+
+#[test]
+fn test_oversized_encrypted_memo_is_rejected() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    let oversized = Bytes::from_slice(&f.env, &[0; MAX_ENCRYPTED_MEMO_BYTES as usize + 1]);
+
+    assert_eq!(
+        f.contract
+            .try_attach_encrypted_memo(&f.sender, &id, &oversized),
+        Err(Ok(ContractError::InvalidEncryptedMemo))
+    );
+    assert_eq!(f.contract.get_encrypted_memo(&id), None);
+}
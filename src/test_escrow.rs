@@ -321,13 +321,17 @@ fn test_zero_net_position_produces_no_transfer() {
         amount: 100,
         fee: 2,
         status: RemittanceStatus::Pending,
-        expiry: None,
+        expiry: crate::MaybeExpiry::None,
         settlement_config: crate::MaybeSettlementConfig::None,
         token: addr_a.clone(), // placeholder
         created_at: 0,
         failed_at: None,
         dispute_evidence: crate::MaybeBytes32::None,
         expires_at: None,
+        accepted_at: None,
+        completed_at: None,
+        memo: None,
+        corridor: crate::MaybeCorridor::None,
     });
 
     // B -> A: 100 (exact mirror — net is zero)
@@ -338,13 +342,17 @@ fn test_zero_net_position_produces_no_transfer() {
         amount: 100,
         fee: 2,
         status: RemittanceStatus::Pending,
-        expiry: None,
+        expiry: crate::MaybeExpiry::None,
         settlement_config: crate::MaybeSettlementConfig::None,
         token: addr_a.clone(), // placeholder
         created_at: 0,
         failed_at: None,
         dispute_evidence: crate::MaybeBytes32::None,
         expires_at: None,
+        accepted_at: None,
+        completed_at: None,
+        memo: None,
+        corridor: crate::MaybeCorridor::None,
     });
 
     let net_transfers: Vec<NetTransfer> = compute_net_settlements(&env, &remittances).unwrap().net_transfers;
@@ -0,0 +1,100 @@
+//! Tests for claiming escrows: the fallback on escrows nobody released, and
+//! recipient passkey claim codes.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, BytesN};
+
+use crate::config::ESCROW_CLAIM_FALLBACK_SECONDS;
+use crate::test_utils::{advance_time, balance, setup};
+use crate::{ContractError, EscrowStatus};
+
+#[test]
+fn test_claim_is_released_like_any_escrow_payout() {
+    let f = setup();
+    let recipient = Address::generate(&f.env);
+    f.contract.set_savings_share(&recipient, &1_000);
+    let id = f.contract.create_escrow(&f.sender, &recipient, &1_000);
+    advance_time(&f, ESCROW_CLAIM_FALLBACK_SECONDS);
+    f.contract.make_escrow_claimable(&id);
+
+    f.contract.redeem_escrow_claim(&id);
+
+    // The recipient's savings share is kept, as on every other release
+    assert_eq!(balance(&f, &recipient), 900);
+    assert_eq!(
+        f.contract.get_savings_balance(&recipient, &f.token.address),
+        100
+    );
+    assert_eq!(f.contract.get_escrow(&id).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_pending_escrow_cannot_be_redeemed() {
+    let f = setup();
+    let recipient = Address::generate(&f.env);
+    let id = f.contract.create_escrow(&f.sender, &recipient, &1_000);
+
+    assert_eq!(
+        f.contract.try_redeem_escrow_claim(&id),
+        Err(Ok(ContractError::InvalidEscrowStatus))
+    );
+    assert_eq!(balance(&f, &recipient), 0);
+}
+
+#[test]
+fn test_passkey_escrow_claim_requires_key_and_valid_signature() {
+    let f = setup();
+    let recipient = Address::generate(&f.env);
+    let id = f.contract.create_escrow(&f.sender, &recipient, &500);
+    let signature = BytesN::from_array(&f.env, &[7; 64]);
+    assert_eq!(
+        f.contract.try_claim_escrow_with_passkey(&id, &signature),
+        Err(Ok(ContractError::ClaimKeyNotSet))
+    );
+
+    let mut raw = [1; 65];
+    assert_eq!(
+        f.contract
+            .try_set_escrow_claim_key(&id, &BytesN::from_array(&f.env, &raw)),
+        Err(Ok(ContractError::InvalidPasskey))
+    );
+    raw[0] = 0x04;
+    f.contract
+        .set_escrow_claim_key(&id, &BytesN::from_array(&f.env, &raw));
+
+    // A signature that does not verify aborts the claim
+    assert!(f
+        .contract
+        .try_claim_escrow_with_passkey(&id, &signature)
+        .is_err());
+    assert_eq!(f.contract.get_escrow(&id).status, EscrowStatus::Pending);
+    assert_eq!(balance(&f, &recipient), 0);
+}
+
+#[test]
+fn test_unreleased_escrow_becomes_claimable() {
+    let f = setup();
+    let recipient = Address::generate(&f.env);
+    let id = f.contract.create_escrow(&f.sender, &recipient, &1000);
+    assert_eq!(
+        f.contract.try_make_escrow_claimable(&id),
+        Err(Ok(ContractError::EscrowClaimNotDue))
+    );
+
+    advance_time(&f, ESCROW_CLAIM_FALLBACK_SECONDS);
+    f.contract.make_escrow_claimable(&id);
+    assert_eq!(f.contract.get_escrow(&id).status, EscrowStatus::Claimable);
+    assert_eq!(
+        f.contract.try_refund_escrow(&id),
+        Err(Ok(ContractError::InvalidEscrowStatus))
+    );
+
+    f.contract.redeem_escrow_claim(&id);
+    assert_eq!(balance(&f, &recipient), 1000);
+    assert_eq!(f.contract.get_escrow(&id).status, EscrowStatus::Released);
+    assert_eq!(
+        f.contract.try_redeem_escrow_claim(&id),
+        Err(Ok(ContractError::InvalidEscrowStatus))
+    );
+}
@@ -0,0 +1,42 @@
+//! Tests for the emergency evacuation of escrowed funds.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address};
+
+use crate::test_utils::{advance_time, balance, remit, setup};
+use crate::{ContractError, EVACUATION_DELAY_SECONDS};
+
+#[test]
+fn test_evacuation_needs_admin_and_guardian_and_delay() {
+    let f = setup();
+    remit(&f, 1_000);
+    let guardian = Address::generate(&f.env);
+    let recovery = Address::generate(&f.env);
+    let r = f.contract.try_propose_evacuation(&f.admin, &recovery);
+    assert_eq!(r, Err(Ok(ContractError::GuardianNotSet)));
+    f.contract.set_guardian(&f.admin, &guardian);
+
+    f.contract.propose_evacuation(&f.admin, &recovery);
+    assert_eq!(
+        f.contract.try_approve_evacuation(&f.admin),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(
+        f.contract.try_execute_evacuation(),
+        Err(Ok(ContractError::EvacuationNotReady))
+    );
+    f.contract.approve_evacuation(&guardian);
+    assert_eq!(f.contract.evacuation_countdown(), EVACUATION_DELAY_SECONDS);
+    assert_eq!(
+        f.contract.try_execute_evacuation(),
+        Err(Ok(ContractError::EvacuationNotReady))
+    );
+
+    advance_time(&f, EVACUATION_DELAY_SECONDS);
+    f.contract.execute_evacuation();
+    assert_eq!(balance(&f, &recovery), 1_000);
+    assert_eq!(balance(&f, &f.contract.address), 0);
+    assert!(f.contract.is_paused());
+    assert_eq!(f.contract.get_evacuation(), None);
+}
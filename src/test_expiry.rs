@@ -0,0 +1,108 @@
+//! Tests for expiring pending remittances, one at a time and in sweeps.
+
+#![cfg(test)]
+
+use soroban_sdk::vec;
+
+use crate::storage;
+use crate::test_utils::{advance_time, balance, remit, setup, Fixture, SENDER_FUNDS};
+use crate::{ContractError, Expiry, RemittanceStatus};
+
+/// Sets the window after which new remittances expire.
+fn set_expiry_window(f: &Fixture, seconds: u64) {
+    f.env.as_contract(&f.contract.address, || {
+        storage::set_remittance_expiry_window(&f.env, seconds);
+    });
+}
+
+/// Creates a remittance of `amount` with an explicit settlement expiry.
+fn remit_with_expiry(f: &Fixture, amount: i128, expiry: Expiry) -> u64 {
+    f.contract.create_remittance(
+        &f.sender,
+        &f.agent,
+        &amount,
+        &Some(expiry),
+        &None,
+        &None,
+        &None,
+        &None,
+    )
+}
+
+#[test]
+fn test_sweep_moves_remittances_to_expired() {
+    let f = setup();
+    let now = f.contract.get_remittance(&remit(&f, 1_000)).created_at;
+    let by_expiry = remit_with_expiry(&f, 1_000, Expiry::Time(now + 100));
+    set_expiry_window(&f, 100);
+    let by_window = remit(&f, 1_000);
+    advance_time(&f, 101);
+
+    let processed = f
+        .contract
+        .process_expired_remittances(&vec![&f.env, by_expiry, by_window]);
+
+    assert_eq!(processed, vec![&f.env, by_expiry, by_window]);
+    for id in [by_expiry, by_window] {
+        assert_eq!(f.contract.get_remittance(&id).status, RemittanceStatus::Expired);
+    }
+    assert_eq!(f.contract.get_counts().expired_count, 2);
+    assert_eq!(f.contract.get_counts().cancelled_count, 0);
+    assert_eq!(balance(&f, &f.sender), SENDER_FUNDS - 1_000);
+}
+
+#[test]
+fn test_expire_remittance_honours_expiry_window() {
+    let f = setup();
+    set_expiry_window(&f, 100);
+    let id = remit(&f, 1_000);
+
+    assert_eq!(
+        f.contract.try_expire_remittance(&id),
+        Err(Ok(ContractError::RemittanceNotExpired))
+    );
+
+    advance_time(&f, 101);
+    f.contract.expire_remittance(&id);
+    assert_eq!(f.contract.get_remittance(&id).status, RemittanceStatus::Expired);
+    assert_eq!(balance(&f, &f.sender), SENDER_FUNDS);
+}
+
+#[test]
+fn test_sweep_skips_unexpired_remittances() {
+    let f = setup();
+    set_expiry_window(&f, 100);
+    let id = remit(&f, 1_000);
+    advance_time(&f, 50);
+
+    assert!(f
+        .contract
+        .process_expired_remittances(&vec![&f.env, id])
+        .is_empty());
+    assert_eq!(f.contract.get_remittance(&id).status, RemittanceStatus::Pending);
+}
+
+#[test]
+fn test_expire_remittance_refunds_and_blocks_payout() {
+    let f = setup();
+    let expiry = Some(Expiry::Time(100));
+    let id = f.contract.create_remittance(
+        &f.sender, &f.agent, &1000, &expiry, &None, &None, &None, &None,
+    );
+    assert_eq!(
+        f.contract.try_expire_remittance(&id),
+        Err(Ok(ContractError::RemittanceNotExpired))
+    );
+
+    advance_time(&f, 101);
+    f.contract.expire_remittance(&id);
+    assert_eq!(
+        f.contract.get_remittance(&id).status,
+        RemittanceStatus::Expired
+    );
+    assert_eq!(balance(&f, &f.sender), 100_000);
+    assert!(f
+        .contract
+        .try_confirm_payout(&f.agent, &id, &None, &None, &None)
+        .is_err());
+}
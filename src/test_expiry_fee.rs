@@ -0,0 +1,52 @@
+//! Tests for the expiry-weighted fee component.
+
+#![cfg(test)]
+
+use crate::config::EXPIRY_FEE_PERIOD_SECONDS;
+use crate::test_utils::{balance, remit, setup};
+use crate::{ContractError, Expiry};
+
+#[test]
+fn test_expiry_fee_scales_with_lock_duration() {
+    let f = setup();
+    f.contract.set_expiry_fee_config(&f.admin, &10, &100);
+    let expiry = Some(Expiry::Time(2 * EXPIRY_FEE_PERIOD_SECONDS));
+
+    let quote = f
+        .contract
+        .quote_fee(&5_000, &expiry, &Some(f.token.address.clone()));
+    assert_eq!(quote.lock_seconds, 2 * EXPIRY_FEE_PERIOD_SECONDS);
+    assert_eq!(quote.expiry_fee, 10);
+
+    let id = f.contract.create_remittance(
+        &f.sender, &f.agent, &5_000, &expiry, &None, &None, &None, &None,
+    );
+    assert_eq!(f.contract.get_remittance_expiry_fee(&id), 10);
+    assert_eq!(f.contract.get_remittance(&id).fee, 135);
+
+    f.contract.confirm_payout(&f.agent, &id, &None, &None, &None);
+    assert_eq!(balance(&f, &f.agent), quote.net_amount);
+    assert_eq!(quote.net_amount, 4_865);
+
+    // Remittances without an expiry are charged the cap
+    let unbounded = remit(&f, 5_000);
+    assert_eq!(f.contract.get_remittance_expiry_fee(&unbounded), 50);
+}
+
+#[test]
+fn test_expiry_fee_config_rejects_invalid_slope() {
+    let f = setup();
+
+    assert_eq!(
+        f.contract.try_set_expiry_fee_config(&f.admin, &200, &100),
+        Err(Ok(ContractError::InvalidFeeBps))
+    );
+    assert_eq!(
+        f.contract.try_set_expiry_fee_config(&f.admin, &10, &10_001),
+        Err(Ok(ContractError::InvalidFeeBps))
+    );
+    assert_eq!(f.contract.get_expiry_fee_config(), None);
+
+    let id = remit(&f, 5_000);
+    assert_eq!(f.contract.get_remittance_expiry_fee(&id), 0);
+}
@@ -0,0 +1,32 @@
+//! Tests for deploying per-corridor contract instances.
+
+#![cfg(test)]
+
+use soroban_sdk::{BytesN, String, Vec};
+
+use crate::test_utils::setup;
+use crate::{ContractError, InstanceConfig, RemittanceCorridor};
+
+#[test]
+fn test_deploy_corridor_instance_requires_admin() {
+    let f = setup();
+    let config = InstanceConfig {
+        corridor: RemittanceCorridor {
+            from_country: String::from_str(&f.env, "US"),
+            to_country: String::from_str(&f.env, "MX"),
+        },
+        token: f.token.address.clone(),
+        fee_bps: 250,
+        protocol_fee_bps: 0,
+        rate_limit_cooldown: 0,
+        roles: Vec::new(&f.env),
+    };
+    let hash = BytesN::from_array(&f.env, &[0; 32]);
+    assert_eq!(
+        f.contract
+            .try_deploy_corridor_instance(&f.sender, &hash, &hash, &config),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert!(f.contract.get_corridor_instances().is_empty());
+    assert_eq!(f.contract.get_corridor_instance(&config.corridor), None);
+}
@@ -27,6 +27,7 @@ struct F<'a> {
 fn setup() -> F<'static> {
     let env = Env::default();
     env.mock_all_auths();
+    env.cost_estimate().disable_resource_limits();
     let admin = Address::generate(&env);
     let sender = Address::generate(&env);
     let agent = Address::generate(&env);
@@ -35,6 +36,7 @@ fn setup() -> F<'static> {
     let c = make_contract(&env);
     c.initialize(&admin, &tok.address, &250u32, &0u64, &0u32, &admin);
     c.register_agent(&agent, &None);
+    c.set_kyc_approved(&sender, &true, &u64::MAX);
     F { env, c, tok, admin, sender, agent }
 }
 
@@ -80,8 +82,8 @@ fn remit(f: &F, amount: i128) -> u64 {
 #[test] fn test_590_create_batch_remittance() {
     let f = setup();
     let entries = soroban_sdk::vec![&f.env,
-        crate::BatchCreateEntry { agent: f.agent.clone(), amount: 500, expiry: None },
-        crate::BatchCreateEntry { agent: f.agent.clone(), amount: 300, expiry: None },
+        crate::BatchCreateEntry { agent: f.agent.clone(), amount: 500, expiry: crate::MaybeExpiry::None },
+        crate::BatchCreateEntry { agent: f.agent.clone(), amount: 300, expiry: crate::MaybeExpiry::None },
     ];
     let ids = f.c.create_batch_remittance(&f.sender, &entries);
     assert_eq!(ids.len(), 2);
@@ -97,12 +99,12 @@ fn remit(f: &F, amount: i128) -> u64 {
 #[test] fn test_590_confirm_batch_payout() {
     let f = setup();
     let entries = soroban_sdk::vec![&f.env,
-        crate::BatchCreateEntry { agent: f.agent.clone(), amount: 500, expiry: None },
-        crate::BatchCreateEntry { agent: f.agent.clone(), amount: 300, expiry: None },
+        crate::BatchCreateEntry { agent: f.agent.clone(), amount: 500, expiry: crate::MaybeExpiry::None },
+        crate::BatchCreateEntry { agent: f.agent.clone(), amount: 300, expiry: crate::MaybeExpiry::None },
     ];
     let ids = f.c.create_batch_remittance(&f.sender, &entries);
     let before = bal(&f.env, &f.tok, &f.agent);
-    f.c.confirm_batch_payout(&ids);
+    f.c.confirm_batch_payout(&f.agent, &ids, &false);
     assert!(bal(&f.env, &f.tok, &f.agent) > before);
     assert_eq!(f.c.get_remittance(&ids.get(0).unwrap()).status, crate::RemittanceStatus::Completed);
     assert_eq!(f.c.get_remittance(&ids.get(1).unwrap()).status, crate::RemittanceStatus::Completed);
@@ -145,7 +147,7 @@ fn remit(f: &F, amount: i128) -> u64 {
 #[test] fn test_591_reputation_after_payout() {
     let f = setup();
     let id = remit(&f, 1_000);
-    f.c.confirm_payout(&id, &None, &None);
+    f.c.confirm_payout(&f.agent, &id, &None, &None, &None);
     assert!(f.c.get_agent_reputation(&f.agent) > 0);
 }
 
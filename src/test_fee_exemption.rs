@@ -0,0 +1,111 @@
+//! Tests for the sender fee exemption across creation paths.
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _},
+    xdr::{ContractEventBody, ScVal},
+    Address, String,
+};
+
+use crate::test_utils::{balance, remit, setup, Fixture};
+use crate::OrgRemittanceOutcome;
+
+/// Returns the `fee_exempt` flag of every `remit.created` event emitted by
+/// the last contract call.
+fn created_exempt_flags(f: &Fixture) -> std::vec::Vec<bool> {
+    f.env
+        .events()
+        .all()
+        .filter_by_contract(&f.contract.address)
+        .events()
+        .iter()
+        .filter_map(|event| {
+            let ContractEventBody::V0(body) = &event.body;
+            let is_created = matches!(
+                (body.topics.first(), body.topics.get(1)),
+                (Some(ScVal::Symbol(a)), Some(ScVal::Symbol(b)))
+                    if a.0.as_slice() == b"remit" && b.0.as_slice() == b"created"
+            );
+            match (&body.data, is_created) {
+                (ScVal::Vec(Some(fields)), true) => match fields.last() {
+                    Some(ScVal::Bool(exempt)) => Some(*exempt),
+                    _ => None,
+                },
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_exempt_sender_is_flagged_in_created_event() {
+    let f = setup();
+    f.contract.add_fee_exempt(&f.admin, &f.sender);
+
+    let id = remit(&f, 1_000);
+    assert_eq!(created_exempt_flags(&f), [true]);
+    assert_eq!(f.contract.get_remittance(&id).fee, 0);
+
+    f.contract.remove_fee_exempt(&f.admin, &f.sender);
+    let id = remit(&f, 1_000);
+    assert_eq!(created_exempt_flags(&f), [false]);
+    assert_eq!(f.contract.get_remittance(&id).fee, 25);
+}
+
+#[test]
+fn test_corridor_remittance_honours_exemption() {
+    let f = setup();
+    f.contract.add_fee_exempt(&f.admin, &f.sender);
+
+    let id = f.contract.create_remittance_with_corridor(
+        &f.sender,
+        &f.agent,
+        &1_000,
+        &None,
+        &Some(String::from_str(&f.env, "US")),
+        &Some(String::from_str(&f.env, "MX")),
+    );
+
+    assert_eq!(created_exempt_flags(&f), [true]);
+    assert_eq!(f.contract.get_remittance(&id).fee, 0);
+    f.contract.confirm_payout(&f.agent, &id, &None, &None, &None);
+    assert_eq!(balance(&f, &f.agent), 1_000);
+}
+
+#[test]
+fn test_organization_remittance_honours_owner_exemption() {
+    let f = setup();
+    f.contract.add_fee_exempt(&f.admin, &f.sender);
+    let member = Address::generate(&f.env);
+    let org_id = f.contract.create_organization(&f.sender, &f.token.address);
+    f.contract.deposit_to_organization(&org_id, &f.sender, &5_000);
+    f.contract.set_org_member(&org_id, &member, &2_000);
+
+    let OrgRemittanceOutcome::Created(id) =
+        f.contract
+            .create_org_remittance(&org_id, &member, &f.agent, &1_000, &None)
+    else {
+        panic!("expected an immediate remittance");
+    };
+
+    assert_eq!(created_exempt_flags(&f), [true]);
+    assert_eq!(f.contract.get_remittance(&id).fee, 0);
+}
+
+#[test]
+fn test_fee_exempt_sender_pays_no_fee() {
+    let f = setup();
+    f.contract.add_fee_exempt(&f.admin, &f.sender);
+    assert!(f.contract.is_fee_exempt(&f.sender));
+
+    let id = remit(&f, 1_000);
+    assert_eq!(f.contract.get_remittance(&id).fee, 0);
+    f.contract
+        .confirm_payout(&f.agent, &id, &None, &None, &None);
+    assert_eq!(balance(&f, &f.agent), 1_000);
+
+    f.contract.remove_fee_exempt(&f.admin, &f.sender);
+    assert!(!f.contract.is_fee_exempt(&f.sender));
+    assert_eq!(f.contract.get_remittance(&remit(&f, 1_000)).fee, 25);
+}
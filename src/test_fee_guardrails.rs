@@ -0,0 +1,46 @@
+//! Tests for the ceiling and per-window change limit on `update_fee`.
+
+#![cfg(test)]
+
+use crate::config::{FEE_CHANGE_WINDOW_SECONDS, MAX_FEE_CHANGE_BPS, MAX_PLATFORM_FEE_BPS};
+use crate::test_utils::{advance_time, setup, FEE_BPS};
+use crate::ContractError;
+
+#[test]
+fn test_update_fee_above_ceiling() {
+    let f = setup();
+
+    assert_eq!(
+        f.contract.try_update_fee(&(MAX_PLATFORM_FEE_BPS + 1)),
+        Err(Ok(ContractError::FeeAboveCeiling))
+    );
+    assert_eq!(f.contract.get_platform_fee_bps(), FEE_BPS);
+}
+
+#[test]
+fn test_update_fee_change_too_large() {
+    let f = setup();
+
+    assert_eq!(
+        f.contract.try_update_fee(&(FEE_BPS + MAX_FEE_CHANGE_BPS + 1)),
+        Err(Ok(ContractError::FeeChangeTooLarge))
+    );
+    assert_eq!(f.contract.get_platform_fee_bps(), FEE_BPS);
+}
+
+#[test]
+fn test_update_fee_changes_accumulate_within_window() {
+    let f = setup();
+    f.contract.update_fee(&(FEE_BPS + MAX_FEE_CHANGE_BPS));
+    assert_eq!(
+        f.contract.try_update_fee(&(FEE_BPS + MAX_FEE_CHANGE_BPS + 1)),
+        Err(Ok(ContractError::FeeChangeTooLarge))
+    );
+
+    advance_time(&f, FEE_CHANGE_WINDOW_SECONDS);
+    f.contract.update_fee(&(FEE_BPS + MAX_FEE_CHANGE_BPS + 1));
+    assert_eq!(
+        f.contract.get_platform_fee_bps(),
+        FEE_BPS + MAX_FEE_CHANGE_BPS + 1
+    );
+}
@@ -0,0 +1,95 @@
+//! Tests for tiered and flat fees, and for fee schedule changes between
+//! creation and payout.
+
+#![cfg(test)]
+
+use soroban_sdk::vec;
+
+use crate::test_utils::{advance_time, balance, remit, setup, Fixture};
+use crate::{ConfigChange, ContractError, FeeStrategy, FeeTier};
+
+/// Pays out remittance `id` and returns what the agent received.
+fn pay_out(f: &Fixture, id: u64) -> i128 {
+    let before = balance(f, &f.agent);
+    f.contract.confirm_payout(&f.agent, &id, &None, &None, &None);
+    balance(f, &f.agent) - before
+}
+
+#[test]
+fn test_tier_change_does_not_strand_pending_remittance() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    assert_eq!(f.contract.get_remittance(&id).fee, 25);
+
+    let tiers = vec![
+        &f.env,
+        FeeTier { min_amount: 0, fee_bps: 150 },
+        FeeTier { min_amount: 10_000, fee_bps: 50 },
+    ];
+    f.contract.set_fee_tiers(&f.admin, &tiers);
+    assert_eq!(f.contract.get_remittance(&remit(&f, 1_000)).fee, 15);
+
+    assert_eq!(pay_out(&f, id), 975);
+}
+
+#[test]
+fn test_flat_fee_change_does_not_strand_pending_remittance() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+
+    f.contract.set_flat_fee(&f.admin, &40);
+    assert_eq!(f.contract.get_remittance(&remit(&f, 1_000)).fee, 65);
+
+    assert_eq!(pay_out(&f, id), 975);
+}
+
+#[test]
+fn test_due_scheduled_fee_does_not_strand_pending_remittance() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    let now = f.contract.get_remittance(&id).created_at;
+    f.contract
+        .schedule_config_change(&f.admin, &ConfigChange::PlatformFee(100), &(now + 60));
+    advance_time(&f, 61);
+    assert_eq!(f.contract.get_fee_config().strategy, FeeStrategy::Percentage(100));
+
+    assert_eq!(pay_out(&f, id), 975);
+}
+
+#[test]
+fn test_fee_tiers_pick_rate_by_amount() {
+    let f = setup();
+    let tier = |min_amount: i128, fee_bps: u32| FeeTier {
+        min_amount,
+        fee_bps,
+    };
+    assert_eq!(
+        f.contract
+            .try_set_fee_tiers(&f.admin, &vec![&f.env, tier(0, 150), tier(0, 100)]),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+    f.contract.set_fee_tiers(
+        &f.admin,
+        &vec![&f.env, tier(0, 150), tier(1_000, 100), tier(10_000, 50)],
+    );
+
+    assert_eq!(f.contract.get_remittance(&remit(&f, 800)).fee, 12);
+    assert_eq!(f.contract.get_remittance(&remit(&f, 2_000)).fee, 20);
+    assert_eq!(f.contract.get_remittance(&remit(&f, 20_000)).fee, 100);
+}
+
+#[test]
+fn test_flat_fee_adds_to_percentage_fee() {
+    let f = setup();
+    f.contract.set_flat_fee(&f.admin, &10);
+    let config = f.contract.get_fee_config();
+    assert_eq!((config.fee_bps, config.flat_fee), (250, 10));
+
+    // 2.5% of 1000 plus the flat 10
+    assert_eq!(f.contract.get_remittance(&remit(&f, 1_000)).fee, 35);
+    assert_eq!(
+        f.contract
+            .try_create_remittance(&f.sender, &f.agent, &10, &None, &None, &None, &None, &None),
+        Err(Ok(ContractError::InvalidAmount))
+    );
+}
@@ -125,12 +125,12 @@ fn test_batch_remittances_apply_cumulative_sender_volume_discount() {
     entries.push_back(crate::BatchCreateEntry {
         agent: agent.clone(),
         amount: 7_000,
-        expiry: None,
+        expiry: crate::MaybeExpiry::None,
     });
     entries.push_back(crate::BatchCreateEntry {
         agent: agent.clone(),
         amount: 7_000,
-        expiry: None,
+        expiry: crate::MaybeExpiry::None,
     });
 
     let remittance_ids = client.batch_create_remittances(&sender, &entries);
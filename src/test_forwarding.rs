@@ -0,0 +1,75 @@
+//! Tests for forwarding remittances between trusted instances.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address};
+
+use crate::test_utils::{balance, remit, setup, Fixture};
+use crate::{ContractError, RemittanceStatus, SwiftRemitContract, SwiftRemitContractClient};
+
+/// A second instance on the same token, trusted both ways with `f.contract`.
+fn peer_instance(f: &Fixture) -> (SwiftRemitContractClient<'static>, Address) {
+    let peer = SwiftRemitContractClient::new(&f.env, &f.env.register(SwiftRemitContract, ()));
+    peer.initialize(&f.admin, &f.token.address, &250u32, &0u64, &0u32, &f.admin);
+    let peer_agent = Address::generate(&f.env);
+    peer.register_agent(&peer_agent, &None);
+    peer.set_trusted_instance(&f.admin, &f.contract.address, &true);
+    f.contract
+        .set_trusted_instance(&f.admin, &peer.address, &true);
+    (peer, peer_agent)
+}
+
+#[test]
+fn test_forward_remittance_moves_escrow_and_record() {
+    let f = setup();
+    let (peer, peer_agent) = peer_instance(&f);
+    let id = remit(&f, 1000);
+
+    let peer_id = f
+        .contract
+        .forward_remittance(&id, &peer.address, &peer_agent);
+    assert_eq!(
+        f.contract.get_remittance(&id).status,
+        RemittanceStatus::Cancelled
+    );
+    assert_eq!(balance(&f, &f.contract.address), 0);
+    assert_eq!(balance(&f, &peer.address), 1000);
+
+    let moved = peer.get_remittance(&peer_id);
+    assert_eq!(
+        (moved.sender, moved.agent, moved.amount, moved.fee),
+        (f.sender.clone(), peer_agent, 1000, 25)
+    );
+    assert_eq!(
+        f.contract.get_forwarded_to(&id),
+        Some((peer.address.clone(), peer_id))
+    );
+    assert_eq!(
+        peer.get_forwarded_from(&peer_id),
+        Some((f.contract.address.clone(), id))
+    );
+}
+
+#[test]
+fn test_forward_remittance_requires_trust() {
+    let f = setup();
+    let (peer, peer_agent) = peer_instance(&f);
+    peer.set_trusted_instance(&f.admin, &f.contract.address, &false);
+    let id = remit(&f, 1000);
+    assert!(f
+        .contract
+        .try_forward_remittance(&id, &peer.address, &peer_agent)
+        .is_err());
+    assert_eq!(
+        f.contract.get_remittance(&id).status,
+        RemittanceStatus::Pending
+    );
+
+    f.contract
+        .set_trusted_instance(&f.admin, &peer.address, &false);
+    assert_eq!(
+        f.contract
+            .try_forward_remittance(&id, &peer.address, &peer_agent),
+        Err(Ok(ContractError::UntrustedInstance))
+    );
+}
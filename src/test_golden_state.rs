@@ -0,0 +1,151 @@
+//! Golden-state differential tests.
+//!
+//! Each scenario scripts a sequence of contract calls, then renders the
+//! contract's full storage (instance, persistent, and temporary) plus the
+//! token balances of every participant as sorted text lines. The rendering is
+//! compared with the scenario's golden file in `test_snapshots/golden/`, so an
+//! unintended change in fee math, status transitions, or storage layout shows
+//! up as a line diff.
+//!
+//! A missing golden file fails the scenario. After an intended change, or when
+//! adding a scenario, record the files and review their diff before committing:
+//!
+//! ```text
+//! UPDATE_GOLDEN=1 cargo test golden
+//! ```
+#![cfg(test)]
+
+use std::{format, fs, string::String, vec::Vec as StdVec};
+
+use soroban_sdk::{
+    testutils::{
+        storage::{Instance as _, Persistent as _, Temporary as _},
+        Address as _, Ledger,
+    },
+    token,
+    xdr::ScVal,
+    Address, Env, Map, TryFromVal, Val,
+};
+
+use crate::{BatchSettlementEntry, SwiftRemitContract, SwiftRemitContractClient};
+
+const GOLDEN_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test_snapshots/golden");
+
+struct Scenario<'a> {
+    env: Env,
+    c: SwiftRemitContractClient<'a>,
+    tok: token::StellarAssetClient<'a>,
+    admin: Address,
+    sender: Address,
+    agent: Address,
+}
+
+fn scenario() -> Scenario<'static> {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_700_000_000);
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let tok_addr = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let tok = token::StellarAssetClient::new(&env, &tok_addr);
+    tok.mint(&sender, &100_000);
+    let c = SwiftRemitContractClient::new(&env, &env.register(SwiftRemitContract, ()));
+    c.initialize(&admin, &tok.address, &250u32, &0u64, &0u32, &admin);
+    c.register_agent(&agent, &None);
+    c.set_kyc_approved(&sender, &true, &u64::MAX);
+    Scenario { env, c, tok, admin, sender, agent }
+}
+
+fn render(env: &Env, lines: &mut StdVec<String>, area: &str, entries: Map<Val, Val>) {
+    for (key, value) in entries.iter() {
+        let key = ScVal::try_from_val(env, &key).unwrap();
+        let value = ScVal::try_from_val(env, &value).unwrap();
+        lines.push(format!("{area} {key:?} = {value:?}"));
+    }
+}
+
+/// Renders the contract's storage and participants' balances as sorted lines.
+fn snapshot(s: &Scenario) -> StdVec<String> {
+    let mut lines = StdVec::new();
+    let (instance, persistent, temporary) = s.env.as_contract(&s.c.address, || {
+        (
+            s.env.storage().instance().all(),
+            s.env.storage().persistent().all(),
+            s.env.storage().temporary().all(),
+        )
+    });
+    render(&s.env, &mut lines, "instance", instance);
+    render(&s.env, &mut lines, "persistent", persistent);
+    render(&s.env, &mut lines, "temporary", temporary);
+
+    let balances = token::Client::new(&s.env, &s.tok.address);
+    for (name, addr) in [
+        ("contract", &s.c.address),
+        ("admin", &s.admin),
+        ("sender", &s.sender),
+        ("agent", &s.agent),
+    ] {
+        lines.push(format!("balance {name} = {}", balances.balance(addr)));
+    }
+    lines.sort();
+    lines
+}
+
+/// Compares a snapshot with the scenario's golden file, re-recording it when
+/// `UPDATE_GOLDEN` is set.
+fn assert_golden(name: &str, actual: StdVec<String>) {
+    let path = format!("{GOLDEN_DIR}/{name}.snap");
+    let rendered = actual.join("\n") + "\n";
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::create_dir_all(GOLDEN_DIR).unwrap();
+        fs::write(&path, rendered).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!("no golden file for scenario `{name}` at {path}; record it with UPDATE_GOLDEN=1")
+    });
+    if expected == rendered {
+        return;
+    }
+
+    let expected: StdVec<&str> = expected.lines().collect();
+    let mut diff = String::new();
+    for line in expected.iter().filter(|line| !actual.iter().any(|a| a == *line)) {
+        diff += &format!("- {line}\n");
+    }
+    for line in actual.iter().filter(|line| !expected.contains(&line.as_str())) {
+        diff += &format!("+ {line}\n");
+    }
+    panic!("state for scenario `{name}` differs from {path}:\n{diff}");
+}
+
+#[test] fn golden_remittance_lifecycle() {
+    let s = scenario();
+    let paid = s.c.create_remittance(&s.sender, &s.agent, &1000, &None, &None, &None, &None, &None);
+    let cancelled = s.c.create_remittance(&s.sender, &s.agent, &2000, &None, &None, &None, &None, &None);
+    s.c.create_remittance(&s.sender, &s.agent, &3000, &None, &None, &None, &None, &None);
+    s.c.confirm_payout(&s.agent, &paid, &None, &None, &None);
+    s.c.cancel_remittance(&cancelled);
+    assert_golden("remittance_lifecycle", snapshot(&s));
+}
+
+#[test] fn golden_fee_update() {
+    let s = scenario();
+    s.c.update_fee(&300);
+    let id = s.c.create_remittance(&s.sender, &s.agent, &10_000, &None, &None, &None, &None, &None);
+    s.c.confirm_payout(&s.agent, &id, &None, &None, &None);
+    s.c.withdraw_fees(&s.admin);
+    assert_golden("fee_update", snapshot(&s));
+}
+
+#[test] fn golden_batch_netting() {
+    let s = scenario();
+    let mut entries = soroban_sdk::Vec::new(&s.env);
+    for amount in [1000i128, 2500, 4000] {
+        let id = s.c.create_remittance(&s.sender, &s.agent, &amount, &None, &None, &None, &None, &None);
+        entries.push_back(BatchSettlementEntry { remittance_id: id });
+    }
+    s.c.batch_settle_with_netting(&entries);
+    assert_golden("batch_netting", snapshot(&s));
+}
@@ -0,0 +1,69 @@
+//! Tests for the handle registry and payments to handles.
+
+#![cfg(test)]
+
+use soroban_sdk::String;
+
+use crate::test_utils::{advance_time, balance, setup};
+use crate::{ContractError, Payee, HANDLE_REGISTRATION_FEE, HANDLE_TERM_SECONDS};
+
+#[test]
+fn test_handle_registry_lifecycle() {
+    let f = setup();
+    f.token.mint(&f.sender, &(HANDLE_REGISTRATION_FEE * 2));
+    f.token.mint(&f.agent, &HANDLE_REGISTRATION_FEE);
+    let handle = String::from_str(&f.env, "amina");
+    let treasury_before = balance(&f, &f.admin);
+    f.contract.register_handle(&f.sender, &handle);
+    assert_eq!(
+        balance(&f, &f.admin) - treasury_before,
+        HANDLE_REGISTRATION_FEE
+    );
+    assert_eq!(f.contract.resolve_handle(&handle), f.sender);
+    assert_eq!(
+        f.contract.try_register_handle(&f.agent, &handle),
+        Err(Ok(ContractError::HandleTaken))
+    );
+
+    f.contract.transfer_handle(&f.sender, &handle, &f.agent);
+    assert_eq!(f.contract.resolve_handle(&handle), f.agent);
+
+    f.contract.set_handle_frozen(&f.admin, &handle, &true);
+    assert_eq!(
+        f.contract.try_resolve_handle(&handle),
+        Err(Ok(ContractError::HandleFrozen))
+    );
+    f.contract.set_handle_frozen(&f.admin, &handle, &false);
+
+    advance_time(&f, HANDLE_TERM_SECONDS);
+    assert_eq!(
+        f.contract.try_resolve_handle(&handle),
+        Err(Ok(ContractError::HandleNotFound))
+    );
+    f.contract.register_handle(&f.sender, &handle);
+    assert_eq!(f.contract.resolve_handle(&handle), f.sender);
+}
+
+#[test]
+fn test_create_remittance_to_handle_payee() {
+    let f = setup();
+    f.token.mint(&f.agent, &HANDLE_REGISTRATION_FEE);
+    let handle = String::from_str(&f.env, "corner_shop");
+    let payee = Payee::Handle(handle.clone());
+    let r = f
+        .contract
+        .try_create_remittance_to_payee(&f.sender, &payee, &1_000, &None, &None);
+    assert_eq!(r, Err(Ok(ContractError::HandleNotFound)));
+
+    f.contract.register_handle(&f.agent, &handle);
+    let id = f
+        .contract
+        .create_remittance_to_payee(&f.sender, &payee, &1_000, &None, &None);
+    assert_eq!(f.contract.get_remittance(&id).agent, f.agent);
+
+    f.contract.set_handle_frozen(&f.admin, &handle, &true);
+    let r = f
+        .contract
+        .try_create_remittance_to_payee(&f.sender, &payee, &1_000, &None, &None);
+    assert_eq!(r, Err(Ok(ContractError::HandleFrozen)));
+}
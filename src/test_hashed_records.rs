@@ -0,0 +1,63 @@
+//! Tests for hash-only remittance records and corridors.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, String};
+
+use crate::test_utils::{balance, setup};
+use crate::{ContractError, RemittanceDetails, RemittanceStatus};
+
+#[test]
+fn test_hashed_remittance_settles_against_details() {
+    let f = setup();
+    let details = RemittanceDetails {
+        sender: f.sender.clone(),
+        agent: f.agent.clone(),
+        payload: Bytes::from_slice(&f.env, b"recipient=alice;purpose=family"),
+        salt: BytesN::from_array(&f.env, &[3u8; 32]),
+    };
+    let hash = f.env.as_contract(&f.contract.address, || {
+        crate::hashed_records::hash_details(&f.env, &details)
+    });
+    let id = f
+        .contract
+        .create_hashed_remittance(&f.sender, &f.token.address, &1_000, &hash);
+    let stored = f.contract.get_hashed_remittance(&id).unwrap();
+    assert_eq!((stored.amount, stored.details_hash), (1_000, hash));
+
+    let mut forged = details.clone();
+    forged.agent = Address::generate(&f.env);
+    let r = f.contract.try_settle_hashed_remittance(&id, &forged);
+    assert_eq!(r, Err(Ok(ContractError::DetailsHashMismatch)));
+
+    f.contract.settle_hashed_remittance(&id, &details);
+    assert_eq!(
+        balance(&f, &f.agent),
+        1_000 - f.contract.get_accumulated_fees()
+    );
+    assert_eq!(
+        f.contract.get_hashed_remittance(&id).unwrap().status,
+        RemittanceStatus::Completed
+    );
+}
+
+#[test]
+fn test_hash_only_corridor_rejects_full_records() {
+    let f = setup();
+    let (from, to) = (
+        String::from_str(&f.env, "US"),
+        String::from_str(&f.env, "DE"),
+    );
+    f.contract
+        .set_hash_only_corridor(&f.admin, &from, &to, &true);
+    assert!(f.contract.is_hash_only_corridor(&from, &to));
+    let r = f.contract.try_create_remittance_with_corridor(
+        &f.sender,
+        &f.agent,
+        &1_000,
+        &None,
+        &Some(from),
+        &Some(to),
+    );
+    assert_eq!(r, Err(Ok(ContractError::HashOnlyCorridor)));
+}
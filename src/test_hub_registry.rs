@@ -0,0 +1,54 @@
+//! Tests for registering an instance with a hub registry.
+
+#![cfg(test)]
+
+use soroban_sdk::{contract, contractimpl, symbol_short, testutils::Address as _, Address, Env};
+
+use crate::test_utils::{setup, us_mx};
+use crate::{ContractError, RegistryEntry};
+
+/// Hub registry that stores the last entry it received.
+#[contract]
+pub struct Hub;
+
+#[contractimpl]
+impl Hub {
+    pub fn register_instance(env: Env, entry: RegistryEntry) {
+        entry.instance.require_auth();
+        env.storage()
+            .instance()
+            .set(&symbol_short!("entry"), &entry);
+    }
+
+    pub fn entry(env: Env) -> Option<RegistryEntry> {
+        env.storage().instance().get(&symbol_short!("entry"))
+    }
+}
+
+#[test]
+fn test_register_with_hub() {
+    let f = setup();
+    let hub = HubClient::new(&f.env, &f.env.register(Hub, ()));
+    assert_eq!(f.contract.get_registry_entry(), None);
+    f.contract
+        .register_with_hub(&f.admin, &hub.address, &us_mx(&f.env));
+
+    let entry = f.contract.get_registry_entry().unwrap();
+    assert_eq!(entry.instance, f.contract.address);
+    assert_eq!(entry.corridor, us_mx(&f.env));
+    assert_eq!(entry.token, f.token.address);
+    assert_eq!(entry.version, f.contract.get_version());
+    assert_eq!(hub.entry(), Some(entry));
+}
+
+#[test]
+fn test_register_with_hub_fails_when_hub_rejects() {
+    let f = setup();
+    let not_a_hub = Address::generate(&f.env);
+    assert_eq!(
+        f.contract
+            .try_register_with_hub(&f.admin, &not_a_hub, &us_mx(&f.env)),
+        Err(Ok(ContractError::HubRegistrationFailed))
+    );
+    assert_eq!(f.contract.get_registry_entry(), None);
+}
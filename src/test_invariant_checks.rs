@@ -0,0 +1,44 @@
+//! Tests for on-demand invariant checks.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address};
+
+use crate::test_utils::{remit, setup};
+use crate::{ContractError, InvariantKind};
+
+#[test]
+fn test_invariants_hold_across_lifecycle() {
+    let f = setup();
+    let paid = remit(&f, 1000);
+    let cancelled = remit(&f, 1000);
+    remit(&f, 1000);
+    f.contract
+        .confirm_payout(&f.agent, &paid, &None, &None, &None);
+    f.contract.cancel_remittance(&cancelled);
+    assert!(f.contract.assert_invariants(&f.admin).is_empty());
+}
+
+#[test]
+fn test_invariants_report_insolvency() {
+    let f = setup();
+    remit(&f, 1000);
+    let drained = Address::generate(&f.env);
+    f.env.as_contract(&f.contract.address, || {
+        token::Client::new(&f.env, &f.token.address).transfer(&f.contract.address, &drained, &500);
+    });
+    let violations = f.contract.assert_invariants(&f.admin);
+    assert_eq!(violations.len(), 1);
+    let v = violations.get(0).unwrap();
+    assert_eq!(v.kind, InvariantKind::Insolvent);
+    assert_eq!((v.expected, v.actual), (1000, 500));
+}
+
+#[test]
+fn test_invariants_require_admin() {
+    let f = setup();
+    assert_eq!(
+        f.contract.try_assert_invariants(&f.sender),
+        Err(Ok(ContractError::Unauthorized))
+    );
+}
@@ -0,0 +1,28 @@
+//! Tests for admin-configured amount limits.
+
+#![cfg(test)]
+
+use crate::test_utils::{remit, setup};
+use crate::ContractError;
+
+#[test]
+fn test_set_limits_bounds_amounts() {
+    let f = setup();
+    let mut limits = f.contract.get_limits();
+    limits.min_amount = 100;
+    limits.max_amount = 5_000;
+    f.contract.set_limits(&f.admin, &limits);
+    assert_eq!(f.contract.get_limits(), limits);
+    assert_eq!(f.contract.get_pending_limits(), None);
+    assert_eq!(
+        f.contract
+            .try_create_remittance(&f.sender, &f.agent, &50, &None, &None, &None, &None, &None),
+        Err(Ok(ContractError::AmountOutsideLimits))
+    );
+    assert_eq!(
+        f.contract
+            .try_create_remittance(&f.sender, &f.agent, &6_000, &None, &None, &None, &None, &None),
+        Err(Ok(ContractError::AmountOutsideLimits))
+    );
+    remit(&f, 1000);
+}
@@ -0,0 +1,41 @@
+//! Tests for pausing the contract and for scheduled maintenance windows.
+
+#![cfg(test)]
+
+use crate::test_utils::{advance_time, remit, setup};
+use crate::ContractError;
+
+#[test]
+fn test_pause_blocks_new_remittances() {
+    let f = setup();
+    f.contract.pause();
+    assert_eq!(
+        f.contract
+            .try_create_remittance(&f.sender, &f.agent, &1_000, &None, &None, &None, &None, &None),
+        Err(Ok(ContractError::ContractPaused))
+    );
+    f.contract.unpause();
+    remit(&f, 1_000);
+}
+
+#[test]
+fn test_maintenance_window_blocks_creation_only() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    let now = f.env.ledger().timestamp();
+    f.contract
+        .schedule_maintenance(&f.admin, &(now + 100), &(now + 1_000));
+    assert_eq!(f.contract.get_active_maintenance_window(), None);
+
+    advance_time(&f, 100);
+    let window = f.contract.get_active_maintenance_window().unwrap();
+    assert_eq!(window.end, now + 1_000);
+    let r = f.contract.try_create_remittance(
+        &f.sender, &f.agent, &1_000, &None, &None, &None, &None, &None,
+    );
+    assert_eq!(r, Err(Ok(ContractError::MaintenanceWindow)));
+    f.contract.cancel_remittance(&id);
+
+    advance_time(&f, 900);
+    remit(&f, 1_000);
+}
@@ -0,0 +1,25 @@
+//! Tests for the contract metrics snapshot.
+
+#![cfg(test)]
+
+use crate::test_utils::{balance, remit, setup};
+
+#[test]
+fn test_metrics_snapshot() {
+    let f = setup();
+    let id = remit(&f, 1000);
+    remit(&f, 1000);
+    f.contract
+        .confirm_payout(&f.agent, &id, &None, &None, &None);
+
+    let m = f.contract.get_metrics();
+    assert!(!m.paused && !m.in_maintenance && !m.in_unpause_cooldown);
+    assert_eq!((m.counts.pending_count, m.counts.completed_count), (1, 1));
+    assert_eq!(m.total_remittances, 2);
+    assert_eq!(m.total_volume, 1000);
+    assert_eq!(m.tvl, balance(&f, &f.contract.address));
+    assert_eq!(m.tvl, 1025);
+    assert_eq!(m.accumulated_fees, 25);
+    assert!(m.fees_covered);
+    assert_eq!(m.registered_agents, 1);
+}
@@ -273,8 +273,8 @@ fn build_single_batch(env: &Env, snapshot: &MigrationSnapshot) -> MigrationBatch
                 _ => 3,
             };
             data.append(&Bytes::from_array(env, &[status_byte]));
-            if let Some(expiry) = r.expiry {
-                data.append(&Bytes::from_array(env, &expiry.to_be_bytes()));
+            if let crate::MaybeExpiry::Some(expiry) = r.expiry {
+                data.append(&Bytes::from_array(env, &expiry.to_hash_value().to_be_bytes()));
             }
         }
 
@@ -330,8 +330,8 @@ fn test_import_batch_rejects_out_of_order_batch() {
                 _ => 0,
             };
             data.append(&soroban_sdk::Bytes::from_array(&env, &[status_byte]));
-            if let Some(expiry) = r.expiry {
-                data.append(&soroban_sdk::Bytes::from_array(&env, &expiry.to_be_bytes()));
+            if let crate::MaybeExpiry::Some(expiry) = r.expiry {
+                data.append(&soroban_sdk::Bytes::from_array(&env, &expiry.to_hash_value().to_be_bytes()));
             }
         }
         let hash_bytes = env.crypto().sha256(&data);
@@ -399,8 +399,8 @@ fn test_import_batch_rejects_duplicate_batch_index() {
                     _ => 0,
                 };
                 data.append(&soroban_sdk::Bytes::from_array(&env2, &[status_byte]));
-                if let Some(expiry) = r.expiry {
-                    data.append(&soroban_sdk::Bytes::from_array(&env2, &expiry.to_be_bytes()));
+                if let crate::MaybeExpiry::Some(expiry) = r.expiry {
+                    data.append(&soroban_sdk::Bytes::from_array(&env2, &expiry.to_hash_value().to_be_bytes()));
                 }
             }
             let h = env2.crypto().sha256(&data);
@@ -507,8 +507,8 @@ fn test_abort_migration_resets_batch_counter() {
                     _ => 0,
                 };
                 data.append(&soroban_sdk::Bytes::from_array(&env, &[status_byte]));
-                if let Some(expiry) = r.expiry {
-                    data.append(&soroban_sdk::Bytes::from_array(&env, &expiry.to_be_bytes()));
+                if let crate::MaybeExpiry::Some(expiry) = r.expiry {
+                    data.append(&soroban_sdk::Bytes::from_array(&env, &expiry.to_hash_value().to_be_bytes()));
                 }
             }
             let h = env.crypto().sha256(&data);
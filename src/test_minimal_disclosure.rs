@@ -0,0 +1,36 @@
+//! Tests for limiting remittance reads to the parties involved.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+use crate::test_utils::{remit, setup};
+use crate::{ContractError, RemittanceStatus};
+
+#[test]
+fn test_minimal_disclosure_restricts_remittance_reads() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    f.contract.set_minimal_disclosure(&id);
+    assert!(f.contract.is_minimal_disclosure(&id));
+    assert_eq!(
+        f.contract.try_get_remittance(&id),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(f.contract.get_remittance_as(&f.sender, &id).amount, 1_000);
+    assert_eq!(f.contract.get_remittance_as(&f.agent, &id).id, id);
+    let outsider = Address::generate(&f.env);
+    assert_eq!(
+        f.contract.try_get_remittance_as(&outsider, &id),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    f.contract
+        .confirm_batch_payout(&f.agent, &vec![&f.env, id], &false);
+    let r = f.contract.try_set_minimal_disclosure(&id);
+    assert_eq!(r, Err(Ok(ContractError::InvalidStatus)));
+    assert_eq!(
+        f.contract.get_remittance_as(&f.admin, &id).status,
+        RemittanceStatus::Completed
+    );
+}
@@ -0,0 +1,58 @@
+//! Tests for bilateral netting pairs between agents.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address};
+
+use crate::test_utils::{balance, setup, Fixture};
+use crate::ContractError;
+
+/// Registers a second funded agent and a netting pair with the fixture agent.
+fn netting_pair(f: &Fixture) -> Address {
+    let other = Address::generate(&f.env);
+    f.contract.register_agent(&other, &None);
+    f.token.mint(&f.agent, &1_000);
+    f.token.mint(&other, &1_000);
+    f.contract
+        .register_netting_pair(&f.agent, &other, &f.token.address);
+    other
+}
+
+#[test]
+fn test_netting_pair_settles_single_net_transfer() {
+    let f = setup();
+    let other = netting_pair(&f);
+
+    f.contract.record_netting_obligation(&f.agent, &other, &300);
+    f.contract.record_netting_obligation(&other, &f.agent, &120);
+    f.contract.record_netting_obligation(&f.agent, &other, &20);
+
+    let checkpoint = f.contract.settle_netting_checkpoint(&other, &f.agent);
+    assert_eq!(checkpoint.debtor, f.agent);
+    assert_eq!(checkpoint.creditor, other);
+    assert_eq!(checkpoint.amount, 200);
+    assert_eq!(checkpoint.obligations, 3);
+
+    assert_eq!(balance(&f, &f.agent), 800);
+    assert_eq!(balance(&f, &other), 1_200);
+    assert_eq!(
+        f.contract.get_netting_pair(&f.agent, &other).unwrap().net_position,
+        0
+    );
+
+    f.contract.remove_netting_pair(&f.agent, &other);
+    assert!(f.contract.get_netting_pair(&f.agent, &other).is_none());
+}
+
+#[test]
+fn test_netting_pair_cannot_be_removed_with_open_position() {
+    let f = setup();
+    let other = netting_pair(&f);
+    f.contract.record_netting_obligation(&f.agent, &other, &50);
+
+    assert_eq!(
+        f.contract.try_remove_netting_pair(&other, &f.agent),
+        Err(Ok(ContractError::NettingPositionOpen))
+    );
+    assert!(f.contract.get_netting_pair(&f.agent, &other).is_some());
+}
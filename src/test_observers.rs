@@ -0,0 +1,101 @@
+//! Tests for the observer registry and lifecycle notifications.
+
+#![cfg(test)]
+
+use soroban_sdk::{contract, contractimpl, symbol_short, testutils::Address as _, Address, Env, Vec};
+
+use crate::config::MAX_OBSERVERS;
+use crate::observers::{ObserverEvent, RemittanceNotification};
+use crate::test_utils::{remit, setup, Fixture};
+use crate::{ContractError, RemittanceStatus};
+
+/// Observer that records the events it is notified about.
+#[contract]
+struct RecordingObserver;
+
+#[contractimpl]
+impl RecordingObserver {
+    pub fn on_remittance(env: Env, notification: RemittanceNotification) {
+        let key = symbol_short!("seen");
+        let mut seen: Vec<ObserverEvent> =
+            env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
+        seen.push_back(notification.event);
+        env.storage().instance().set(&key, &seen);
+    }
+}
+
+/// Observer whose entry point always traps.
+#[contract]
+struct RevertingObserver;
+
+#[contractimpl]
+impl RevertingObserver {
+    pub fn on_remittance(_env: Env, _notification: RemittanceNotification) {
+        panic!("observer unavailable");
+    }
+}
+
+fn seen(f: &Fixture, observer: &Address) -> Vec<ObserverEvent> {
+    f.env.as_contract(observer, || {
+        f.env
+            .storage()
+            .instance()
+            .get(&symbol_short!("seen"))
+            .unwrap_or(Vec::new(&f.env))
+    })
+}
+
+#[test]
+fn test_observer_is_notified_on_create_and_complete() {
+    let f = setup();
+    let observer = f.env.register(RecordingObserver, ());
+    f.contract.register_observer(&observer);
+
+    let id = remit(&f, 1_000);
+    f.contract.confirm_payout(&f.agent, &id, &None, &None, &None);
+
+    assert_eq!(
+        seen(&f, &observer),
+        soroban_sdk::vec![&f.env, ObserverEvent::Created, ObserverEvent::Completed]
+    );
+}
+
+#[test]
+fn test_failing_observer_does_not_block_remittance() {
+    let f = setup();
+    let reverting = f.env.register(RevertingObserver, ());
+    let recording = f.env.register(RecordingObserver, ());
+    f.contract.register_observer(&reverting);
+    f.contract.register_observer(&recording);
+
+    let id = remit(&f, 1_000);
+
+    assert_eq!(f.contract.get_remittance(&id).status, RemittanceStatus::Pending);
+    assert_eq!(seen(&f, &recording), soroban_sdk::vec![&f.env, ObserverEvent::Created]);
+}
+
+#[test]
+fn test_registry_rejects_duplicates_and_overflow() {
+    let f = setup();
+    let observer = Address::generate(&f.env);
+    f.contract.register_observer(&observer);
+    assert_eq!(
+        f.contract.try_register_observer(&observer),
+        Err(Ok(ContractError::ObserverAlreadyRegistered))
+    );
+
+    for _ in 1..MAX_OBSERVERS {
+        f.contract.register_observer(&Address::generate(&f.env));
+    }
+    assert_eq!(
+        f.contract.try_register_observer(&Address::generate(&f.env)),
+        Err(Ok(ContractError::TooManyObservers))
+    );
+
+    f.contract.remove_observer(&observer);
+    assert_eq!(f.contract.get_observers().len(), MAX_OBSERVERS - 1);
+    assert_eq!(
+        f.contract.try_remove_observer(&observer),
+        Err(Ok(ContractError::ObserverNotFound))
+    );
+}
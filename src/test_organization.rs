@@ -0,0 +1,141 @@
+//! Tests for organization accounts and over-limit member approvals.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address};
+
+use crate::config::MAX_ORG_PENDING_REQUESTS;
+use crate::storage;
+use crate::test_utils::{balance, setup, Fixture};
+use crate::{ContractError, OrgRemittanceOutcome, OrgRequestStatus, RemittanceStatus};
+
+/// Creates an organization owned by the fixture sender, funds it with
+/// `funds`, and adds a member with `limit`.
+fn org_with_member(f: &Fixture, funds: i128, limit: i128) -> (u64, Address) {
+    let member = Address::generate(&f.env);
+    let org_id = f.contract.create_organization(&f.sender, &f.token.address);
+    f.contract.deposit_to_organization(&org_id, &f.sender, &funds);
+    f.contract.set_org_member(&org_id, &member, &limit);
+    (org_id, member)
+}
+
+#[test]
+fn test_member_remittance_is_funded_from_shared_balance() {
+    let f = setup();
+    let (org_id, member) = org_with_member(&f, 5_000, 2_000);
+
+    let outcome = f
+        .contract
+        .create_org_remittance(&org_id, &member, &f.agent, &1_500, &None);
+    let OrgRemittanceOutcome::Created(id) = outcome else {
+        panic!("expected an immediate remittance, got {:?}", outcome);
+    };
+
+    let remittance = f.contract.get_remittance(&id);
+    assert_eq!(remittance.sender, f.sender);
+    assert_eq!(remittance.status, RemittanceStatus::Pending);
+    assert_eq!(f.contract.get_organization(&org_id).balance, 3_500);
+    assert_eq!(f.contract.get_org_member(&org_id, &member).spent_in_window, 1_500);
+    assert_eq!(balance(&f, &member), 0);
+
+    // Organization remittances land in the owner's and the agent's histories
+    f.env.as_contract(&f.contract.address, || {
+        assert_eq!(storage::get_sender_remittance_count(&f.env, &f.sender), 1);
+        assert_eq!(storage::get_agent_remittance_count(&f.env, &f.agent), 1);
+    });
+}
+
+#[test]
+fn test_non_member_cannot_spend_shared_balance() {
+    let f = setup();
+    let (org_id, _) = org_with_member(&f, 5_000, 2_000);
+    let outsider = Address::generate(&f.env);
+
+    let result = f
+        .contract
+        .try_create_org_remittance(&org_id, &outsider, &f.agent, &1_000, &None);
+    assert_eq!(result, Err(Ok(ContractError::NotOrgMember)));
+    assert_eq!(f.contract.get_organization(&org_id).balance, 5_000);
+}
+
+#[test]
+fn test_creation_applies_configured_expiry_window() {
+    let f = setup();
+    let (org_id, member) = org_with_member(&f, 5_000, 2_000);
+    f.env.as_contract(&f.contract.address, || {
+        storage::set_remittance_expiry_window(&f.env, 3_600);
+    });
+
+    let OrgRemittanceOutcome::Created(id) =
+        f.contract
+            .create_org_remittance(&org_id, &member, &f.agent, &1_000, &None)
+    else {
+        panic!("expected an immediate remittance");
+    };
+
+    let remittance = f.contract.get_remittance(&id);
+    assert_eq!(remittance.expires_at, Some(remittance.created_at + 3_600));
+}
+
+#[test]
+fn test_over_limit_request_waits_for_owner_approval() {
+    let f = setup();
+    let (org_id, member) = org_with_member(&f, 5_000, 1_000);
+
+    let outcome = f
+        .contract
+        .create_org_remittance(&org_id, &member, &f.agent, &3_000, &None);
+    let OrgRemittanceOutcome::PendingApproval(request_id) = outcome else {
+        panic!("expected a pending request, got {:?}", outcome);
+    };
+    assert_eq!(f.contract.get_organization(&org_id).balance, 5_000);
+    assert_eq!(f.contract.get_org_pending_requests(&org_id).len(), 1);
+
+    let id = f.contract.approve_org_remittance(&org_id, &request_id);
+    assert_eq!(f.contract.get_remittance(&id).amount, 3_000);
+    assert_eq!(f.contract.get_organization(&org_id).balance, 2_000);
+    assert_eq!(f.contract.get_org_request(&request_id).status, OrgRequestStatus::Approved);
+    assert_eq!(f.contract.get_org_pending_requests(&org_id).len(), 0);
+}
+
+#[test]
+fn test_rejected_request_cannot_be_approved() {
+    let f = setup();
+    let (org_id, member) = org_with_member(&f, 5_000, 1_000);
+    let OrgRemittanceOutcome::PendingApproval(request_id) =
+        f.contract
+            .create_org_remittance(&org_id, &member, &f.agent, &3_000, &None)
+    else {
+        panic!("expected a pending request");
+    };
+
+    f.contract.reject_org_remittance(&org_id, &request_id);
+
+    assert_eq!(
+        f.contract.try_approve_org_remittance(&org_id, &request_id),
+        Err(Ok(ContractError::OrgRequestNotPending))
+    );
+    assert_eq!(f.contract.get_organization(&org_id).balance, 5_000);
+}
+
+#[test]
+fn test_pending_requests_are_capped() {
+    let f = setup();
+    let (org_id, member) = org_with_member(&f, 5_000, 1);
+
+    for _ in 0..MAX_ORG_PENDING_REQUESTS {
+        f.contract
+            .create_org_remittance(&org_id, &member, &f.agent, &100, &None);
+    }
+    assert_eq!(
+        f.contract
+            .try_create_org_remittance(&org_id, &member, &f.agent, &100, &None),
+        Err(Ok(ContractError::OrgPendingRequestsFull))
+    );
+
+    // Closing a request frees a slot
+    let oldest = f.contract.get_org_pending_requests(&org_id).get(0).unwrap();
+    f.contract.reject_org_remittance(&org_id, &oldest);
+    f.contract
+        .create_org_remittance(&org_id, &member, &f.agent, &100, &None);
+}
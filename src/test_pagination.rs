@@ -0,0 +1,121 @@
+//! Tests for the shared `Page<T>` cursor convention across list endpoints.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+
+use crate::admin_log;
+use crate::config::{ADMIN_LOG_CAPACITY, MAX_PAGE_SIZE};
+use crate::storage;
+use crate::test_utils::{remit, setup};
+
+#[test]
+fn test_paginate_clamps_limit_and_cursor() {
+    let env = Env::default();
+    let mut all = Vec::new(&env);
+    for id in 0..150u64 {
+        all.push_back(id);
+    }
+
+    let page = storage::paginate(&env, &all, 0, 500);
+    assert_eq!(page.items.len(), MAX_PAGE_SIZE);
+    assert_eq!(page.next_cursor, MAX_PAGE_SIZE);
+    assert!(page.has_more);
+
+    let past_end = storage::paginate(&env, &all, 400, 10);
+    assert!(past_end.items.is_empty());
+    assert_eq!(past_end.next_cursor, 150);
+    assert!(!past_end.has_more);
+}
+
+#[test]
+fn test_get_remittances_by_agent_pages_history() {
+    let f = setup();
+    let first = remit(&f, 1_000);
+    let second = remit(&f, 2_000);
+    let other = Address::generate(&f.env);
+    f.contract.register_agent(&other, &None);
+    f.contract.reassign_remittance(&second, &other);
+
+    let page = f.contract.get_remittances_by_agent(&f.agent, &0, &1);
+    assert_eq!(page.items, soroban_sdk::vec![&f.env, first]);
+    assert!(page.has_more);
+    let rest = f
+        .contract
+        .get_remittances_by_agent(&f.agent, &page.next_cursor, &10);
+    assert_eq!(rest.items, soroban_sdk::vec![&f.env, second]);
+    assert!(!rest.has_more);
+
+    // Reassigned remittances also appear in the new agent's history
+    let other_page = f.contract.get_remittances_by_agent(&other, &0, &10);
+    assert_eq!(other_page.items, soroban_sdk::vec![&f.env, second]);
+}
+
+#[test]
+fn test_corridor_remittances_appear_in_agent_history() {
+    let f = setup();
+    let direct = remit(&f, 1_000);
+    let corridor = f.contract.create_remittance_with_corridor(
+        &f.sender,
+        &f.agent,
+        &1_000,
+        &None,
+        &Some(String::from_str(&f.env, "US")),
+        &Some(String::from_str(&f.env, "MX")),
+    );
+
+    let page = f.contract.get_remittances_by_agent(&f.agent, &0, &10);
+    assert_eq!(page.items, soroban_sdk::vec![&f.env, direct, corridor]);
+    assert_eq!(f.contract.get_remittance_count(), 2);
+}
+
+#[test]
+fn test_list_agents_pages_registered_agents() {
+    let f = setup();
+    let second = Address::generate(&f.env);
+    f.contract.register_agent(&second, &None);
+
+    let page = f.contract.list_agents(&0, &1);
+    assert_eq!(page.items, soroban_sdk::vec![&f.env, f.agent.clone()]);
+    assert!(page.has_more);
+    let rest = f.contract.list_agents(&page.next_cursor, &1);
+    assert_eq!(rest.items, soroban_sdk::vec![&f.env, second]);
+    assert!(!rest.has_more);
+}
+
+#[test]
+fn test_admin_log_follows_shared_cursor_convention() {
+    let f = setup();
+    for bps in [300u32, 310, 320] {
+        f.contract.update_fee(&bps);
+    }
+    let total = f.contract.get_admin_log(&0, &MAX_PAGE_SIZE).items.len();
+
+    let page = f.contract.get_admin_log(&0, &2);
+    assert_eq!(page.items.len(), 2);
+    assert_eq!(page.next_cursor, 2);
+    assert!(page.has_more);
+
+    let rest = f.contract.get_admin_log(&page.next_cursor, &MAX_PAGE_SIZE);
+    assert_eq!(rest.items.len(), total - 2);
+    assert_eq!(rest.next_cursor, total);
+    assert!(!rest.has_more);
+}
+
+#[test]
+fn test_admin_log_cursor_before_oldest_starts_at_oldest_retained() {
+    let f = setup();
+    f.env.cost_estimate().budget().reset_unlimited();
+    f.env.cost_estimate().disable_resource_limits();
+    let evicted = 5u32;
+    f.env.as_contract(&f.contract.address, || {
+        let start = storage::get_admin_log_len(&f.env);
+        for _ in start..ADMIN_LOG_CAPACITY + u64::from(evicted) {
+            admin_log::record(&f.env, &f.admin, "update_fee", (300u32,));
+        }
+    });
+
+    let page = f.contract.get_admin_log(&0, &1);
+    assert_eq!(page.items.get(0).unwrap().seq, u64::from(evicted));
+    assert_eq!(page.next_cursor, evicted + 1);
+}
@@ -0,0 +1,114 @@
+//! Tests for path payouts and agents' preferred payout tokens.
+
+#![cfg(test)]
+
+use soroban_sdk::{contract, contractimpl, token, Address, Env, Vec};
+
+use crate::test_utils::{balance, create_token, remit, setup, token_balance, Fixture};
+use crate::{ContractError, PathPayout};
+
+/// Path payment adapter that converts at a fixed rate of 2 to 1 but
+/// quotes 100 more than it delivers.
+#[contract]
+pub struct PathAdapter;
+
+#[contractimpl]
+impl PathAdapter {
+    #[allow(clippy::too_many_arguments)]
+    pub fn path_payment_strict_send(
+        env: Env,
+        from: Address,
+        send_asset: Address,
+        send_amount: i128,
+        dest_asset: Address,
+        dest_min: i128,
+        destination: Address,
+        _path: Vec<Address>,
+    ) -> i128 {
+        assert!(send_amount * 2 >= dest_min, "below dest_min");
+        let this = env.current_contract_address();
+        token::Client::new(&env, &send_asset).transfer_from(&this, &from, &this, &send_amount);
+        token::StellarAssetClient::new(&env, &dest_asset).mint(&destination, &(send_amount * 2));
+        send_amount * 2
+    }
+
+    pub fn quote_strict_send(
+        _send_asset: Address,
+        send_amount: i128,
+        _dest_asset: Address,
+    ) -> i128 {
+        send_amount * 2 + 100
+    }
+}
+
+fn path_payout_to(f: &Fixture, min_receive: i128) -> (u64, token::StellarAssetClient<'static>) {
+    let local = create_token(f);
+    let adapter = f.env.register(PathAdapter, ());
+    f.contract.set_path_payment_adapter(&f.admin, &adapter);
+    let id = remit(f, 1000);
+    let path_payout = PathPayout {
+        dest_token: local.address.clone(),
+        min_receive,
+        path: Vec::new(&f.env),
+    };
+    f.contract.set_path_payout(&f.agent, &id, &path_payout);
+    (id, local)
+}
+
+#[test]
+fn test_path_payout_delivers_dest_asset() {
+    let f = setup();
+    // The adapter moves the contract's tokens in a nested call
+    f.env.mock_all_auths_allowing_non_root_auth();
+    let (id, local) = path_payout_to(&f, 1900);
+    f.contract
+        .confirm_payout(&f.agent, &id, &None, &None, &None);
+    assert_eq!(token_balance(&f, &local, &f.agent), 1950);
+    assert_eq!(balance(&f, &f.agent), 0);
+}
+
+#[test]
+fn test_path_payout_below_min_receive_fails() {
+    let f = setup();
+    let (id, _) = path_payout_to(&f, 2000);
+    assert_eq!(
+        f.contract
+            .try_confirm_payout(&f.agent, &id, &None, &None, &None),
+        Err(Ok(ContractError::PathPaymentFailed))
+    );
+}
+
+fn prefer_local_token(f: &Fixture, max_slippage_bps: u32) -> token::StellarAssetClient<'static> {
+    let local = create_token(f);
+    let adapter = f.env.register(PathAdapter, ());
+    f.contract.set_path_payment_adapter(&f.admin, &adapter);
+    f.contract
+        .set_payout_preference(&f.agent, &local.address, &max_slippage_bps);
+    local
+}
+
+#[test]
+fn test_preferred_token_within_slippage() {
+    let f = setup();
+    // The adapter moves the contract's tokens in a nested call
+    f.env.mock_all_auths_allowing_non_root_auth();
+    // Quote of 2050 less 10% allows the 1950 delivered
+    let local = prefer_local_token(&f, 1000);
+    let id = remit(&f, 1000);
+    f.contract
+        .confirm_payout(&f.agent, &id, &None, &None, &None);
+    assert_eq!(token_balance(&f, &local, &f.agent), 1950);
+    assert_eq!(balance(&f, &f.agent), 0);
+}
+
+#[test]
+fn test_preferred_token_falls_back_to_escrow_token() {
+    let f = setup();
+    // Quote of 2050 less 1% is more than the adapter delivers
+    let local = prefer_local_token(&f, 100);
+    let id = remit(&f, 1000);
+    f.contract
+        .confirm_payout(&f.agent, &id, &None, &None, &None);
+    assert_eq!(token_balance(&f, &local, &f.agent), 0);
+    assert_eq!(balance(&f, &f.agent), 975);
+}
@@ -0,0 +1,83 @@
+//! Tests for recipient pickup secrets checked at payout.
+
+#![cfg(test)]
+
+use soroban_sdk::{Bytes, BytesN, Env};
+
+use crate::test_utils::{remit, setup};
+use crate::{ContractError, RemittanceStatus};
+
+fn pickup_hash(env: &Env, secret: &Bytes) -> BytesN<32> {
+    env.crypto().sha256(secret).into()
+}
+
+#[test]
+fn test_confirm_payout_with_correct_pickup_secret() {
+    let f = setup();
+    let id = remit(&f, 10_000);
+    let secret = Bytes::from_slice(&f.env, b"recipient-pin-4821");
+    f.contract
+        .register_pickup_secret(&f.sender, &id, &pickup_hash(&f.env, &secret));
+
+    f.contract
+        .confirm_payout(&f.agent, &id, &None, &None, &Some(secret));
+
+    assert_eq!(f.contract.get_remittance(&id).status, RemittanceStatus::Completed);
+}
+
+#[test]
+fn test_confirm_payout_with_wrong_or_missing_pickup_secret_fails() {
+    let f = setup();
+    let id = remit(&f, 10_000);
+    let secret = Bytes::from_slice(&f.env, b"recipient-pin-4821");
+    f.contract
+        .register_pickup_secret(&f.sender, &id, &pickup_hash(&f.env, &secret));
+
+    let wrong = Bytes::from_slice(&f.env, b"guess");
+    assert_eq!(
+        f.contract
+            .try_confirm_payout(&f.agent, &id, &None, &None, &Some(wrong)),
+        Err(Ok(ContractError::InvalidPickupSecret))
+    );
+    assert_eq!(
+        f.contract.try_confirm_payout(&f.agent, &id, &None, &None, &None),
+        Err(Ok(ContractError::PickupSecretRequired))
+    );
+}
+
+#[test]
+fn test_pickup_secret_cannot_be_replaced() {
+    let f = setup();
+    let id = remit(&f, 10_000);
+    f.contract
+        .register_pickup_secret(&f.sender, &id, &BytesN::from_array(&f.env, &[0x11; 32]));
+
+    assert_eq!(
+        f.contract.try_register_pickup_secret(
+            &f.sender,
+            &id,
+            &BytesN::from_array(&f.env, &[0x22; 32]),
+        ),
+        Err(Ok(ContractError::PickupSecretAlreadySet))
+    );
+}
+
+#[test]
+fn test_high_value_remittance_requires_pickup_secret() {
+    let f = setup();
+    f.contract.set_pickup_secret_threshold(&5_000);
+
+    let id = remit(&f, 10_000);
+    assert_eq!(
+        f.contract.try_confirm_payout(&f.agent, &id, &None, &None, &None),
+        Err(Ok(ContractError::PickupSecretRequired))
+    );
+
+    let small_id = remit(&f, 1_000);
+    f.contract
+        .confirm_payout(&f.agent, &small_id, &None, &None, &None);
+    assert_eq!(
+        f.contract.get_remittance(&small_id).status,
+        RemittanceStatus::Completed
+    );
+}
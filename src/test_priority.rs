@@ -0,0 +1,30 @@
+//! Tests for priority boosts.
+
+#![cfg(test)]
+
+use crate::test_utils::{balance, remit, setup};
+
+#[test]
+fn test_boost_priority_shares_fee_with_agent() {
+    let f = setup();
+    let id = remit(&f, 1000);
+    let boost = f.contract.boost_priority(&id);
+    assert_eq!(boost.level, 1);
+    assert_eq!(boost.fees_held, 5);
+    assert_eq!(
+        f.contract.get_sla_deadline(&id),
+        crate::config::BASE_SLA_SECONDS / 2
+    );
+    f.contract
+        .confirm_payout(&f.agent, &id, &None, &None, &None);
+    assert_eq!(balance(&f, &f.agent), 977);
+}
+
+#[test]
+fn test_boost_priority_refunded_on_cancel() {
+    let f = setup();
+    let id = remit(&f, 1000);
+    f.contract.boost_priority(&id);
+    f.contract.cancel_remittance(&id);
+    assert_eq!(balance(&f, &f.sender), 100_000);
+}
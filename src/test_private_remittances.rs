@@ -0,0 +1,38 @@
+//! Tests for private remittances settled against an amount commitment.
+
+#![cfg(test)]
+
+use soroban_sdk::BytesN;
+
+use crate::test_utils::{balance, setup};
+use crate::{ContractError, RemittanceStatus};
+
+#[test]
+fn test_private_remittance_reveal_at_settlement() {
+    let f = setup();
+    let salt = BytesN::from_array(&f.env, &[9u8; 32]);
+    let commitment = f.env.as_contract(&f.contract.address, || {
+        crate::amount_commitment::commit(&f.env, 1_200, &salt)
+    });
+    let id = f.contract.create_private_remittance(
+        &f.sender,
+        &f.agent,
+        &f.token.address,
+        &2_000,
+        &commitment,
+    );
+    assert_eq!(f.contract.get_private_remittance(&id).unwrap().amount, None);
+
+    let wrong = f.contract.try_settle_private_remittance(&id, &1_300, &salt);
+    assert_eq!(wrong, Err(Ok(ContractError::AmountCommitmentMismatch)));
+
+    let sender_before = balance(&f, &f.sender);
+    f.contract.settle_private_remittance(&id, &1_200, &salt);
+    let fee = f.contract.get_accumulated_fees();
+    assert!(fee > 0);
+    assert_eq!(balance(&f, &f.agent), 1_200 - fee);
+    assert_eq!(balance(&f, &f.sender) - sender_before, 800);
+    let settled = f.contract.get_private_remittance(&id).unwrap();
+    assert_eq!(settled.amount, Some(1_200));
+    assert_eq!(settled.status, RemittanceStatus::Completed);
+}
@@ -0,0 +1,61 @@
+//! Tests for firm agent quotes.
+
+#![cfg(test)]
+
+use crate::test_utils::{advance_time, balance, setup, us_mx, Fixture};
+use crate::{ContractError, QUOTE_RATE_SCALE};
+
+fn post_quote(f: &Fixture) -> u64 {
+    // 17 MXN per USD unit, minus a flat 25 fee
+    f.contract.post_quote(
+        &f.agent,
+        &us_mx(&f.env),
+        &(17 * QUOTE_RATE_SCALE),
+        &25,
+        &600,
+    )
+}
+
+#[test]
+fn test_quote_accepted_and_settled() {
+    let f = setup();
+    let quote_id = post_quote(&f);
+    let id = f
+        .contract
+        .create_remittance_with_quote(&f.sender, &quote_id, &1000, &None);
+    let accepted = f.contract.get_accepted_quote(&id).unwrap();
+    assert_eq!(
+        (accepted.payout_amount, accepted.dest_amount),
+        (975, 950 * 17)
+    );
+    assert_eq!(
+        f.contract
+            .try_create_remittance_with_quote(&f.sender, &quote_id, &1000, &None),
+        Err(Ok(ContractError::QuoteAlreadyAccepted))
+    );
+    f.contract
+        .confirm_payout(&f.agent, &id, &None, &None, &None);
+    assert_eq!(balance(&f, &f.agent), 975);
+}
+
+#[test]
+fn test_quote_enforced_at_settlement() {
+    let f = setup();
+    let id = f
+        .contract
+        .create_remittance_with_quote(&f.sender, &post_quote(&f), &1000, &None);
+    f.contract.update_protocol_fee(&f.admin, &100);
+    assert_eq!(
+        f.contract
+            .try_confirm_payout(&f.agent, &id, &None, &None, &None),
+        Err(Ok(ContractError::QuoteTermsChanged))
+    );
+
+    let expired = post_quote(&f);
+    advance_time(&f, 601);
+    assert_eq!(
+        f.contract
+            .try_create_remittance_with_quote(&f.sender, &expired, &1000, &None),
+        Err(Ok(ContractError::QuoteExpired))
+    );
+}
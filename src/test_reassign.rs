@@ -0,0 +1,57 @@
+//! Tests for handing a pending remittance to another agent.
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _},
+    xdr::{ContractEventBody, ScVal},
+    Address,
+};
+
+use crate::test_utils::{balance, remit, setup};
+use crate::{ContractError, RemittanceStatus};
+
+#[test]
+fn test_reassign_remittance_to_another_agent() {
+    let f = setup();
+    let new_agent = Address::generate(&f.env);
+    f.contract.register_agent(&new_agent, &None);
+    let id = remit(&f, 1_000);
+
+    f.contract.reassign_remittance(&id, &new_agent);
+
+    let auths = f.env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == f.agent));
+    assert!(auths.iter().any(|(address, _)| *address == new_agent));
+    let events = f.env.events().all().filter_by_contract(&f.contract.address);
+    let ContractEventBody::V0(body) = &events.events().last().unwrap().body;
+    assert!(matches!(
+        body.topics.get(1),
+        Some(ScVal::Symbol(topic)) if topic.0.as_slice() == b"reassign"
+    ));
+
+    assert_eq!(f.contract.get_remittance(&id).agent, new_agent);
+    f.contract.confirm_payout(&new_agent, &id, &None, &None, &None);
+    assert_eq!(balance(&f, &new_agent), 975);
+    assert_eq!(balance(&f, &f.agent), 0);
+}
+
+#[test]
+fn test_reassign_rejects_same_agent_and_settled_remittance() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+
+    assert_eq!(
+        f.contract.try_reassign_remittance(&id, &f.agent),
+        Err(Ok(ContractError::InvalidAddress))
+    );
+
+    let new_agent = Address::generate(&f.env);
+    f.contract.register_agent(&new_agent, &None);
+    f.contract.confirm_payout(&f.agent, &id, &None, &None, &None);
+    assert_eq!(
+        f.contract.try_reassign_remittance(&id, &new_agent),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+    assert_eq!(f.contract.get_remittance(&id).status, RemittanceStatus::Completed);
+}
@@ -0,0 +1,156 @@
+//! Tests for payout paths against a token that calls back into the contract.
+//!
+//! Payout paths commit the remittance status and fee accounting before any
+//! token moves. The token here tries to settle the same remittance again from
+//! inside its `transfer`. The host refuses contract re-entry outright, so the
+//! tests pin that the nested call was actually made and refused, and that
+//! the outer call still pays the agent exactly once.
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, Address, Env, Symbol,
+};
+
+use crate::{RemittanceStatus, SwiftRemitContract, SwiftRemitContractClient};
+
+/// Minimal token whose `transfer` re-enters SwiftRemit once armed.
+#[contract]
+pub struct ReentrantToken;
+
+#[contractimpl]
+impl ReentrantToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+
+    /// Makes the next transfer call `confirm_payout` on `target`.
+    pub fn arm(env: Env, target: Address, agent: Address, remittance_id: u64) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("armed"), &(target, agent, remittance_id));
+    }
+
+    /// How the nested settlement ended, once one was attempted: `paid`,
+    /// `failed` (rejected by SwiftRemit) or `refused` (rejected by the host).
+    pub fn reentry(env: Env) -> Option<Symbol> {
+        env.storage().instance().get(&symbol_short!("reenter"))
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let from_balance = Self::balance(env.clone(), from.clone());
+        assert!(from_balance >= amount, "insufficient balance");
+        env.storage().persistent().set(&from, &(from_balance - amount));
+        Self::mint(env.clone(), to, amount);
+
+        let armed: Option<(Address, Address, u64)> =
+            env.storage().instance().get(&symbol_short!("armed"));
+        if let Some((target, agent, remittance_id)) = armed {
+            env.storage().instance().remove(&symbol_short!("armed"));
+            let result = SwiftRemitContractClient::new(&env, &target).try_confirm_payout(
+                &agent,
+                &remittance_id,
+                &None,
+                &None,
+                &None,
+            );
+            let outcome = match result {
+                Ok(_) => symbol_short!("paid"),
+                Err(Ok(_)) => symbol_short!("failed"),
+                Err(Err(_)) => symbol_short!("refused"),
+            };
+            env.storage()
+                .instance()
+                .set(&symbol_short!("reenter"), &outcome);
+        }
+    }
+}
+
+struct Setup<'a> {
+    contract: SwiftRemitContractClient<'a>,
+    token: ReentrantTokenClient<'a>,
+    sender: Address,
+    agent: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let admin = Address::generate(&env);
+    let token = ReentrantTokenClient::new(&env, &env.register(ReentrantToken, ()));
+    let contract = SwiftRemitContractClient::new(
+        &env,
+        &env.register(SwiftRemitContract, ()),
+    );
+    contract.initialize(&admin, &token.address, &250, &0, &0, &admin);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    contract.register_agent(&agent, &None);
+    contract.set_kyc_approved(&sender, &true, &u64::MAX);
+    token.mint(&sender, &10_000);
+    Setup {
+        contract,
+        token,
+        sender,
+        agent,
+    }
+}
+
+#[test]
+fn test_reentrant_token_cannot_double_pay_confirm_payout() {
+    let s = setup();
+    let id = s
+        .contract
+        .create_remittance(&s.sender, &s.agent, &1_000, &None, &None, &None, &None, &None);
+    s.token.arm(&s.contract.address, &s.agent, &id);
+
+    s.contract.confirm_payout(&s.agent, &id, &None, &None, &None);
+
+    assert_eq!(s.token.reentry(), Some(symbol_short!("refused")));
+    assert_eq!(s.token.balance(&s.agent), 975);
+    assert_eq!(s.contract.get_remittance(&id).status, RemittanceStatus::Completed);
+    assert_eq!(s.contract.get_accumulated_fees(), 25);
+}
+
+#[test]
+fn test_reentrant_token_cannot_settle_cancelled_remittance() {
+    let s = setup();
+    let id = s
+        .contract
+        .create_remittance(&s.sender, &s.agent, &1_000, &None, &None, &None, &None, &None);
+    s.token.arm(&s.contract.address, &s.agent, &id);
+
+    s.contract.cancel_remittance(&id);
+
+    assert_eq!(s.token.reentry(), Some(symbol_short!("refused")));
+    assert_eq!(s.token.balance(&s.sender), 10_000);
+    assert_eq!(s.token.balance(&s.agent), 0);
+    assert_eq!(s.contract.get_remittance(&id).status, RemittanceStatus::Cancelled);
+}
+
+#[test]
+fn test_reentrant_token_cannot_double_pay_batch_payout() {
+    let s = setup();
+    let first = s
+        .contract
+        .create_remittance(&s.sender, &s.agent, &1_000, &None, &None, &None, &None, &None);
+    let second = s
+        .contract
+        .create_remittance(&s.sender, &s.agent, &2_000, &None, &None, &None, &None, &None);
+    s.token.arm(&s.contract.address, &s.agent, &second);
+
+    let env = s.contract.env.clone();
+    s.contract
+        .confirm_batch_payout(&s.agent, &soroban_sdk::vec![&env, first, second], &false);
+
+    assert_eq!(s.token.reentry(), Some(symbol_short!("refused")));
+    assert_eq!(s.token.balance(&s.agent), 975 + 1_950);
+    assert_eq!(s.contract.get_remittance(&second).status, RemittanceStatus::Completed);
+    assert_eq!(s.contract.get_accumulated_fees(), 75);
+}
@@ -0,0 +1,229 @@
+//! Tests for the cursor-paginated remittance queries.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Vec};
+
+use crate::test_utils::{remit, setup, Fixture};
+use crate::RemittanceStatus;
+
+/// Returns every ID in the `status` index, sorted.
+fn indexed(f: &Fixture, status: RemittanceStatus) -> Vec<u64> {
+    let mut ids = std::vec::Vec::new();
+    let mut cursor = 0;
+    loop {
+        let page = f.contract.get_remittances_by_status(&status, &cursor, &2);
+        ids.extend(page.items.iter());
+        if !page.has_more {
+            break;
+        }
+        cursor = page.next_cursor;
+    }
+    ids.sort_unstable();
+    Vec::from_slice(&f.env, &ids)
+}
+
+#[test]
+fn test_get_remittances_by_sender_pages_records() {
+    let f = setup();
+    let ids = [remit(&f, 1_000), remit(&f, 2_000), remit(&f, 3_000)];
+
+    let first = f.contract.get_remittances_by_sender(&f.sender, &0, &2);
+    assert_eq!(first.items.len(), 2);
+    assert_eq!(first.items.get(0).unwrap().id, ids[0]);
+    assert_eq!(first.items.get(1).unwrap().amount, 2_000);
+    assert!(first.has_more);
+
+    let rest = f
+        .contract
+        .get_remittances_by_sender(&f.sender, &first.next_cursor, &2);
+    assert_eq!(rest.items.len(), 1);
+    assert_eq!(rest.items.get(0).unwrap().id, ids[2]);
+    assert!(!rest.has_more);
+}
+
+#[test]
+fn test_get_remittances_by_sender_is_empty_for_unknown_sender() {
+    let f = setup();
+    remit(&f, 1_000);
+
+    let page = f.contract.get_remittances_by_sender(&f.agent, &0, &10);
+    assert!(page.items.is_empty());
+    assert!(!page.has_more);
+}
+
+#[test]
+fn test_get_remittances_by_sender_clamps_cursor_past_end() {
+    let f = setup();
+    remit(&f, 1_000);
+
+    let page = f.contract.get_remittances_by_sender(&f.sender, &5, &10);
+    assert!(page.items.is_empty());
+    assert_eq!(page.next_cursor, 1);
+}
+
+#[test]
+fn test_status_index_follows_transitions() {
+    let f = setup();
+    let paid = remit(&f, 1_000);
+    let cancelled = remit(&f, 1_000);
+    let pending = remit(&f, 1_000);
+    assert_eq!(
+        indexed(&f, RemittanceStatus::Pending),
+        soroban_sdk::vec![&f.env, paid, cancelled, pending]
+    );
+
+    f.contract
+        .confirm_payout(&f.agent, &paid, &None, &None, &None);
+    f.contract.cancel_remittance(&cancelled);
+
+    assert_eq!(indexed(&f, RemittanceStatus::Pending), soroban_sdk::vec![&f.env, pending]);
+    assert_eq!(indexed(&f, RemittanceStatus::Completed), soroban_sdk::vec![&f.env, paid]);
+    assert_eq!(
+        indexed(&f, RemittanceStatus::Cancelled),
+        soroban_sdk::vec![&f.env, cancelled]
+    );
+    assert!(f.contract.assert_invariants(&f.admin).is_empty());
+}
+
+#[test]
+fn test_status_index_keeps_positions_after_removal_from_middle() {
+    let f = setup();
+    let ids: std::vec::Vec<u64> = (0..5).map(|_| remit(&f, 1_000)).collect();
+
+    // Removed entries leave gaps instead of moving other entries
+    f.contract.cancel_remittance(&ids[1]);
+    f.contract.cancel_remittance(&ids[3]);
+
+    let page = f
+        .contract
+        .get_remittances_by_status(&RemittanceStatus::Pending, &0, &10);
+    assert_eq!(page.items, soroban_sdk::vec![&f.env, ids[0], ids[2], ids[4]]);
+    assert_eq!(page.next_cursor, 5);
+    assert!(!page.has_more);
+
+    // Leading gaps are trimmed, so later pages start past them
+    f.contract.cancel_remittance(&ids[0]);
+    let page = f
+        .contract
+        .get_remittances_by_status(&RemittanceStatus::Pending, &0, &1);
+    assert_eq!(page.items, soroban_sdk::vec![&f.env, ids[2]]);
+    assert_eq!(page.next_cursor, 3);
+    assert!(page.has_more);
+
+    assert_eq!(indexed(&f, RemittanceStatus::Cancelled).len(), 3);
+    assert_eq!(f.contract.get_counts().pending_count, 2);
+    assert!(f.contract.assert_invariants(&f.admin).is_empty());
+}
+
+#[test]
+fn test_status_index_paging_survives_status_changes() {
+    let f = setup();
+    let ids: std::vec::Vec<u64> = (0..6).map(|_| remit(&f, 1_000)).collect();
+
+    let first = f
+        .contract
+        .get_remittances_by_status(&RemittanceStatus::Pending, &0, &2);
+    assert_eq!(first.items, soroban_sdk::vec![&f.env, ids[0], ids[1]]);
+
+    // Entries on the page already read and on the pages still to come leave
+    // the bucket before the next page is fetched
+    f.contract.cancel_remittance(&ids[0]);
+    f.contract
+        .confirm_payout(&f.agent, &ids[3], &None, &None, &None);
+    let added = remit(&f, 1_000);
+
+    let mut rest = std::vec::Vec::new();
+    let mut cursor = first.next_cursor;
+    loop {
+        let page = f
+            .contract
+            .get_remittances_by_status(&RemittanceStatus::Pending, &cursor, &2);
+        rest.extend(page.items.iter());
+        if !page.has_more {
+            break;
+        }
+        cursor = page.next_cursor;
+    }
+    assert_eq!(rest, [ids[2], ids[4], ids[5], added]);
+}
+
+#[test]
+fn test_pending_queue_paging_survives_payouts() {
+    let f = setup();
+    let ids: std::vec::Vec<u64> = (0..5).map(|_| remit(&f, 1_000)).collect();
+
+    let first = f.contract.get_pending_for_agent(&f.agent, &0, &2);
+    assert_eq!(first.items.len(), 2);
+    f.contract
+        .confirm_payout(&f.agent, &ids[0], &None, &None, &None);
+    f.contract
+        .confirm_payout(&f.agent, &ids[2], &None, &None, &None);
+
+    let rest = f
+        .contract
+        .get_pending_for_agent(&f.agent, &first.next_cursor, &10);
+    let rest: std::vec::Vec<u64> = rest.items.iter().map(|r| r.id).collect();
+    assert_eq!(rest, [ids[3], ids[4]]);
+}
+
+#[test]
+fn test_status_index_ignores_writes_without_status_change() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    let other = Address::generate(&f.env);
+    f.contract.register_agent(&other, &None);
+    f.contract.reassign_remittance(&id, &other);
+
+    assert_eq!(indexed(&f, RemittanceStatus::Pending), soroban_sdk::vec![&f.env, id]);
+    assert_eq!(f.contract.get_counts().pending_count, 1);
+}
+
+#[test]
+fn test_counts_follow_status_changes() {
+    let f = setup();
+    let paid = remit(&f, 1000);
+    let cancelled = remit(&f, 1000);
+    remit(&f, 1000);
+    f.contract
+        .confirm_payout(&f.agent, &paid, &None, &None, &None);
+    f.contract.cancel_remittance(&cancelled);
+
+    let counts = f.contract.get_counts();
+    assert_eq!(counts.pending_count, 1);
+    assert_eq!(counts.completed_count, 1);
+    assert_eq!(counts.cancelled_count, 1);
+    assert_eq!(counts.disputed_count, 0);
+    assert_eq!(counts.expired_count, 0);
+}
+
+#[test]
+fn test_pending_for_agent_tracks_status_and_reassignment() {
+    let f = setup();
+    let a = remit(&f, 1_000);
+    let b = remit(&f, 2_000);
+    let c = remit(&f, 3_000);
+    let queue = |f: &Fixture, agent: &Address| {
+        let mut ids = Vec::new(&f.env);
+        for r in f
+            .contract
+            .get_pending_for_agent(agent, &0, &10)
+            .items
+            .iter()
+        {
+            ids.push_back(r.id);
+        }
+        ids
+    };
+    assert_eq!(queue(&f, &f.agent), vec![&f.env, a, b, c]);
+
+    f.contract.confirm_payout(&f.agent, &a, &None, &None, &None);
+    f.contract.cancel_remittance(&b);
+    assert_eq!(queue(&f, &f.agent), vec![&f.env, c]);
+
+    let other = Address::generate(&f.env);
+    f.contract.register_agent(&other, &None);
+    f.contract.reassign_remittance(&c, &other);
+    assert!(queue(&f, &f.agent).is_empty());
+    assert_eq!(queue(&f, &other), vec![&f.env, c]);
+}
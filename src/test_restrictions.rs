@@ -0,0 +1,58 @@
+//! Tests for compliance restrictions on tokens and corridors.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address};
+
+use crate::test_utils::{remit, setup, us_mx};
+use crate::{ContractError, Restriction, RestrictionTarget, Role};
+
+#[test]
+fn test_token_restricted_by_kyc_tier() {
+    let f = setup();
+    let officer = Address::generate(&f.env);
+    f.contract
+        .assign_role(&f.admin, &officer, &Role::Compliance);
+    let target = RestrictionTarget::Token(f.token.address.clone());
+    f.contract
+        .set_restriction(&officer, &target, &Restriction::MinKycTier(2));
+    assert_eq!(f.contract.get_restrictions().len(), 1);
+    assert_eq!(
+        f.contract
+            .try_create_remittance(&f.sender, &f.agent, &1000, &None, &None, &None, &None, &None),
+        Err(Ok(ContractError::KycTierTooLow))
+    );
+
+    for party in [&f.sender, &f.agent] {
+        f.contract.set_kyc_approved(party, &true, &1_000_000);
+        f.contract.set_kyc_tier(&officer, party, &2);
+    }
+    remit(&f, 1000);
+    f.contract.lift_restriction(&officer, &target);
+    assert_eq!(f.contract.get_restriction(&target), None);
+}
+
+#[test]
+fn test_blocked_corridor_rejects_creation() {
+    let f = setup();
+    let officer = Address::generate(&f.env);
+    f.contract
+        .assign_role(&f.admin, &officer, &Role::Compliance);
+    let corridor = us_mx(&f.env);
+    f.contract.set_restriction(
+        &officer,
+        &RestrictionTarget::Corridor(corridor.clone()),
+        &Restriction::Blocked,
+    );
+    assert_eq!(
+        f.contract.try_create_remittance_with_corridor(
+            &f.sender,
+            &f.agent,
+            &1000,
+            &None,
+            &Some(corridor.from_country),
+            &Some(corridor.to_country)
+        ),
+        Err(Ok(ContractError::Restricted))
+    );
+}
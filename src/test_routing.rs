@@ -0,0 +1,108 @@
+//! Tests for multi-hop remittance routing.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+use crate::test_utils::{balance, setup, Fixture};
+use crate::{ContractError, LegStatus, Role, RouteHop};
+
+/// Registers a cash-out agent; the fixture agent acts as the hub.
+fn cash_out_agent(f: &Fixture) -> Address {
+    let cash_out = Address::generate(&f.env);
+    f.contract.register_agent(&cash_out, &None);
+    f.contract.assign_role(&f.admin, &cash_out, &Role::Settler);
+    cash_out
+}
+
+#[test]
+fn test_routed_payout_splits_across_hops() {
+    let f = setup();
+    let cash_out = cash_out_agent(&f);
+    let hops = vec![
+        &f.env,
+        RouteHop {
+            agent: f.agent.clone(),
+            fee_bps: 1_000,
+        },
+    ];
+    let id = f
+        .contract
+        .create_routed_remittance(&f.sender, &hops, &cash_out, &10_000, &None, &None);
+
+    f.contract.confirm_route_leg(&f.agent, &id);
+    f.contract.confirm_payout(&cash_out, &id, &None, &None, &None);
+
+    // The hub takes 10% of the net payout; the cash-out agent receives the rest
+    let net_payout = balance(&f, &f.agent) + balance(&f, &cash_out);
+    assert!(net_payout > 0);
+    assert_eq!(balance(&f, &f.agent), net_payout / 10);
+
+    let legs = f.contract.get_remittance_route(&id).unwrap();
+    assert_eq!(legs.len(), 2);
+    assert!(legs.iter().all(|leg| leg.status == LegStatus::Confirmed));
+}
+
+#[test]
+fn test_routed_payout_requires_hop_confirmation() {
+    let f = setup();
+    let cash_out = cash_out_agent(&f);
+    let hops = vec![
+        &f.env,
+        RouteHop {
+            agent: f.agent.clone(),
+            fee_bps: 500,
+        },
+    ];
+    let id = f
+        .contract
+        .create_routed_remittance(&f.sender, &hops, &cash_out, &10_000, &None, &None);
+
+    assert_eq!(
+        f.contract
+            .try_confirm_payout(&cash_out, &id, &None, &None, &None),
+        Err(Ok(ContractError::RouteIncomplete))
+    );
+    assert_eq!(
+        f.contract.try_confirm_route_leg(&cash_out, &id),
+        Err(Ok(ContractError::RouteLegOutOfOrder))
+    );
+}
+
+#[test]
+fn test_create_routed_remittance_rejects_invalid_route() {
+    let f = setup();
+    let cash_out = cash_out_agent(&f);
+
+    let over_fee = vec![
+        &f.env,
+        RouteHop {
+            agent: f.agent.clone(),
+            fee_bps: 10_000,
+        },
+    ];
+    assert_eq!(
+        f.contract
+            .try_create_routed_remittance(&f.sender, &over_fee, &cash_out, &10_000, &None, &None),
+        Err(Ok(ContractError::InvalidRoute))
+    );
+
+    let unregistered = vec![
+        &f.env,
+        RouteHop {
+            agent: Address::generate(&f.env),
+            fee_bps: 100,
+        },
+    ];
+    assert_eq!(
+        f.contract.try_create_routed_remittance(
+            &f.sender,
+            &unregistered,
+            &cash_out,
+            &10_000,
+            &None,
+            &None,
+        ),
+        Err(Ok(ContractError::InvalidRoute))
+    );
+}
@@ -0,0 +1,78 @@
+//! Tests for the recipient savings vault and goal-based savings pots.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+use crate::test_utils::{balance, has_event, setup};
+use crate::ContractError;
+
+#[test]
+fn test_release_escrow_keeps_savings_share() {
+    let f = setup();
+    let recipient = Address::generate(&f.env);
+    f.contract.set_savings_share(&recipient, &2_000);
+    let id = f.contract.create_escrow(&f.sender, &recipient, &500);
+
+    f.contract.release_escrow(&id);
+
+    assert!(has_event(&f, "savings", "deposit"));
+    assert_eq!(balance(&f, &recipient), 400);
+    assert_eq!(
+        f.contract.get_savings_balance(&recipient, &f.token.address),
+        100
+    );
+}
+
+#[test]
+fn test_withdraw_savings_is_bounded_by_balance() {
+    let f = setup();
+    let recipient = Address::generate(&f.env);
+    f.contract.set_savings_share(&recipient, &2_000);
+    let id = f.contract.create_escrow(&f.sender, &recipient, &500);
+    f.contract.release_escrow(&id);
+
+    assert_eq!(
+        f.contract.withdraw_savings(&recipient, &f.token.address, &60),
+        40
+    );
+    assert_eq!(balance(&f, &recipient), 460);
+    assert_eq!(
+        f.contract
+            .try_withdraw_savings(&recipient, &f.token.address, &41),
+        Err(Ok(ContractError::InsufficientSavingsBalance))
+    );
+    assert_eq!(
+        f.contract.get_savings_balance(&recipient, &f.token.address),
+        40
+    );
+}
+
+#[test]
+fn test_savings_goal_early_withdrawal_fee() {
+    let f = setup();
+    let recipient = Address::generate(&f.env);
+    f.token.mint(&recipient, &1_000);
+    f.contract.set_savings_share(&recipient, &5_000);
+    let id = f.contract.create_escrow(&f.sender, &recipient, &400);
+    f.contract.release_escrow(&id);
+
+    let name = String::from_str(&f.env, "School fees");
+    let goal_id = f
+        .contract
+        .create_savings_goal(&recipient, &name, &f.token.address, &1_000, &None);
+    assert_eq!(f.contract.move_savings_to_goal(&recipient, &goal_id, &200), 200);
+    assert_eq!(f.contract.deposit_to_goal(&recipient, &goal_id, &300), 500);
+    assert_eq!(f.contract.get_savings_balance(&recipient, &f.token.address), 0);
+    assert_eq!(f.contract.get_savings_goal_progress(&goal_id), 5_000);
+
+    // Withdrawing before the goal is reached pays the early-withdrawal fee
+    let before = balance(&f, &recipient);
+    assert_eq!(f.contract.withdraw_from_goal(&recipient, &goal_id, &100), 1);
+    assert_eq!(balance(&f, &recipient) - before, 99);
+    assert_eq!(f.contract.get_insurance_fund(&f.token.address), 1);
+
+    f.contract.deposit_to_goal(&recipient, &goal_id, &600);
+    assert_eq!(f.contract.withdraw_from_goal(&recipient, &goal_id, &1_000), 0);
+    assert_eq!(f.contract.get_savings_goal(&goal_id).unwrap().balance, 0);
+}
@@ -0,0 +1,40 @@
+//! Tests for configuration changes scheduled for a later time.
+
+#![cfg(test)]
+
+use soroban_sdk::String;
+
+use crate::test_utils::{advance_time, setup};
+use crate::{ConfigChange, ContractError, FeeStrategy};
+
+#[test]
+fn test_scheduled_fee_change_resolves_at_effective_time() {
+    let f = setup();
+    let now = f.env.ledger().timestamp();
+    let r = f
+        .contract
+        .try_schedule_config_change(&f.admin, &ConfigChange::PlatformFee(100), &now);
+    assert_eq!(r, Err(Ok(ContractError::InvalidSchedule)));
+
+    f.contract
+        .schedule_config_change(&f.admin, &ConfigChange::PlatformFee(100), &(now + 3_600));
+    let cancelled =
+        f.contract
+            .schedule_config_change(&f.admin, &ConfigChange::PlatformFee(50), &(now + 7_200));
+    f.contract.cancel_config_change(&f.admin, &cancelled);
+    assert_eq!(f.contract.get_platform_fee_bps(), 250);
+
+    advance_time(&f, 3_600);
+    assert_eq!(f.contract.get_platform_fee_bps(), 100);
+    assert_eq!(f.contract.get_fee_strategy(), FeeStrategy::Percentage(100));
+
+    let country = String::from_str(&f.env, "NG");
+    let currency = String::from_str(&f.env, "USDC");
+    let change = ConfigChange::DailyLimit(currency.clone(), country.clone(), 5_000);
+    f.contract
+        .schedule_config_change(&f.admin, &change, &(now + 7_200));
+    advance_time(&f, 3_600);
+    assert_eq!(f.contract.get_scheduled_config_changes().len(), 2);
+    f.contract.set_daily_limit(&currency, &country, &9_000);
+    assert_eq!(f.contract.get_scheduled_config_changes().len(), 1);
+}
@@ -0,0 +1,157 @@
+//! Tests for versioned remittance storage and lazy V1 → V2 migration.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::storage::Persistent as _, IntoVal, Symbol, TryFromVal, Val};
+
+use crate::storage;
+use crate::test_utils::{remit, setup, Fixture};
+use crate::{ContractError, Remittance, RemittanceV1, VersionedRemittance};
+
+/// Storage key of remittance `id`, matching `DataKey::Remittance(id)`.
+fn record_key(f: &Fixture, id: u64) -> (Symbol, u64) {
+    (Symbol::new(&f.env, "Remittance"), id)
+}
+
+/// Returns remittance `id` in the V1 layout.
+fn as_v1(f: &Fixture, id: u64) -> RemittanceV1 {
+    let r = f.contract.get_remittance(&id);
+    RemittanceV1 {
+        id,
+        sender: r.sender,
+        agent: r.agent,
+        amount: r.amount,
+        fee: r.fee,
+        status: r.status,
+        expiry: None,
+        settlement_config: r.settlement_config,
+        token: r.token,
+        created_at: r.created_at,
+        failed_at: r.failed_at,
+        dispute_evidence: r.dispute_evidence,
+        expires_at: r.expires_at,
+    }
+}
+
+/// Overwrites the raw stored value of remittance `id`.
+fn store_raw<V: IntoVal<soroban_sdk::Env, Val>>(f: &Fixture, id: u64, value: V) {
+    f.env.as_contract(&f.contract.address, || {
+        let value: Val = value.into_val(&f.env);
+        f.env.storage().persistent().set(&record_key(f, id), &value);
+    });
+}
+
+/// Reads the raw stored value of remittance `id` as a versioned envelope.
+fn stored(f: &Fixture, id: u64) -> VersionedRemittance {
+    f.env.as_contract(&f.contract.address, || {
+        let value: Val = f.env.storage().persistent().get(&record_key(f, id)).unwrap();
+        VersionedRemittance::try_from_val(&f.env, &value).expect("record is not enveloped")
+    })
+}
+
+#[test]
+fn test_new_records_are_stored_as_v2() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+
+    let VersionedRemittance::V2(record) = stored(&f, id) else {
+        panic!("new remittance was not stored as V2");
+    };
+    assert_eq!(record, f.contract.get_remittance(&id));
+}
+
+#[test]
+fn test_bare_v1_record_is_upgraded_on_read() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    let legacy = as_v1(&f, id);
+    store_raw(&f, id, legacy.clone());
+
+    // Inspecting the record leaves it untouched
+    assert_eq!(
+        f.contract.get_remittance_any(&id),
+        VersionedRemittance::V1(legacy.clone())
+    );
+    assert!(f.env.as_contract(&f.contract.address, || {
+        let value: Val = f.env.storage().persistent().get(&record_key(&f, id)).unwrap();
+        VersionedRemittance::try_from_val(&f.env, &value).is_err()
+    }));
+
+    let upgraded = f.contract.get_remittance(&id);
+    assert_eq!(upgraded, Remittance::from(legacy));
+    assert_eq!(stored(&f, id), VersionedRemittance::V2(upgraded));
+}
+
+#[test]
+fn test_enveloped_v1_record_is_upgraded_on_read() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    let legacy = as_v1(&f, id);
+    store_raw(&f, id, VersionedRemittance::V1(legacy.clone()));
+
+    let upgraded = f.contract.get_remittance(&id);
+    assert_eq!(upgraded, Remittance::from(legacy));
+    assert_eq!(stored(&f, id), VersionedRemittance::V2(upgraded));
+}
+
+#[test]
+fn test_unknown_layout_is_reported_as_corruption() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+    store_raw(&f, id, 7_u32);
+
+    assert_eq!(
+        f.contract.try_get_remittance_any(&id),
+        Err(Ok(ContractError::DataCorruption))
+    );
+    assert_eq!(
+        f.contract.try_get_remittance(&id),
+        Err(Ok(ContractError::DataCorruption))
+    );
+}
+
+#[test]
+fn test_ttl_extension_covers_the_schema_version() {
+    let f = setup();
+    let id = remit(&f, 1_000);
+
+    f.env.as_contract(&f.contract.address, || {
+        storage::extend_remittance_ttl(&f.env, id, 500_000);
+        assert_eq!(f.env.storage().persistent().get_ttl(&record_key(&f, id)), 500_000);
+    });
+    assert!(matches!(stored(&f, id), VersionedRemittance::V2(_)));
+}
+
+#[test]
+fn test_rehearse_migration_compares_without_writing() {
+    let f = setup();
+    let legacy = remit(&f, 1000);
+    remit(&f, 1000);
+    store_raw(&f, legacy, as_v1(&f, legacy));
+
+    let report = f.contract.rehearse_migration(&f.admin, &0, &10);
+    assert_eq!(
+        (
+            report.scanned,
+            report.legacy,
+            report.current,
+            report.missing
+        ),
+        (2, 1, 1, 0)
+    );
+    assert!(report.mismatched.is_empty());
+    assert_eq!((report.next_id, report.done), (3, true));
+    assert!(matches!(
+        f.contract.get_remittance_any(&legacy),
+        VersionedRemittance::V1(_)
+    ));
+}
+
+#[test]
+fn test_rehearse_migration_pages_through_ids() {
+    let f = setup();
+    remit(&f, 1000);
+    remit(&f, 1000);
+    let report = f.contract.rehearse_migration(&f.admin, &1, &1);
+    assert_eq!((report.scanned, report.next_id, report.done), (1, 2, false));
+}
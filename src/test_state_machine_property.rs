@@ -14,13 +14,14 @@
 extern crate std;
 
 use proptest::prelude::*;
+use std::vec;
 use soroban_sdk::{testutils::Address as _, Env};
 
 use crate::{
     errors::ContractError,
     transitions::{transition_status, validate_transition},
     types::RemittanceStatus,
-    MaybeBytes32, MaybeSettlementConfig,
+    MaybeBytes32, MaybeExpiry, MaybeSettlementConfig,
 };
 
 // ─── Strategies ──────────────────────────────────────────────────────────────
@@ -87,12 +88,17 @@ fn make_remittance(env: &Env, status: RemittanceStatus) -> crate::Remittance {
         amount: 1_000,
         fee: 10,
         status,
-        expiry: None,
+        expiry: MaybeExpiry::None,
         settlement_config: MaybeSettlementConfig::None,
         token: soroban_sdk::Address::generate(env),
         created_at: 0,
         failed_at: None,
         dispute_evidence: MaybeBytes32::None,
+        expires_at: None,
+        accepted_at: None,
+        completed_at: None,
+        memo: None,
+        corridor: crate::MaybeCorridor::None,
     }
 }
 
@@ -136,7 +142,7 @@ proptest! {
         let result = transition_status(&env, &mut rem, to.clone());
         prop_assert!(result.is_ok(), "transition_status failed: {:?}", result);
         prop_assert_eq!(
-            rem.status, to,
+            rem.status, to.clone(),
             "Status not updated after {:?} -> {:?}", from, to
         );
     }
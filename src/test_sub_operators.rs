@@ -0,0 +1,52 @@
+//! Tests for sub-operators confirming payouts on an agent's behalf.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address};
+
+use crate::test_utils::{balance, remit, setup};
+use crate::{ContractError, RemittanceStatus};
+
+#[test]
+fn test_sub_operator_confirms_payout_for_agent() {
+    let f = setup();
+    let operator = Address::generate(&f.env);
+    f.contract.add_sub_operator(&f.agent, &operator, &5_000);
+    let id = remit(&f, 1_000);
+
+    f.contract.confirm_payout(&operator, &id, &None, &None, &None);
+
+    // The payout still goes to the assigned agent
+    assert_eq!(f.contract.get_remittance(&id).status, RemittanceStatus::Completed);
+    assert_eq!(balance(&f, &f.agent), 975);
+    assert_eq!(balance(&f, &operator), 0);
+    assert_eq!(
+        f.contract.get_sub_operator(&operator).unwrap().used_in_window,
+        1_000
+    );
+}
+
+#[test]
+fn test_sub_operator_rejected_over_limit_and_after_revocation() {
+    let f = setup();
+    let operator = Address::generate(&f.env);
+    f.contract.add_sub_operator(&f.agent, &operator, &1_500);
+    let first = remit(&f, 1_000);
+    let second = remit(&f, 1_000);
+    f.contract.confirm_payout(&operator, &first, &None, &None, &None);
+
+    assert_eq!(
+        f.contract
+            .try_confirm_payout(&operator, &second, &None, &None, &None),
+        Err(Ok(ContractError::SubOperatorLimitExceeded))
+    );
+
+    f.contract.revoke_sub_operator(&f.agent, &operator);
+    assert!(f.contract.get_sub_operators(&f.agent).is_empty());
+    assert_eq!(
+        f.contract
+            .try_confirm_payout(&operator, &second, &None, &None, &None),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(f.contract.get_remittance(&second).status, RemittanceStatus::Pending);
+}
@@ -0,0 +1,81 @@
+//! Tests for sender-defined remittance tags.
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::Events as _,
+    vec,
+    xdr::{ContractEventBody, ScVal},
+};
+
+use crate::config::MAX_REMITTANCE_TAGS;
+use crate::test_utils::{setup, Fixture};
+use crate::ContractError;
+
+/// Returns the symbol topics of every `tagged` event emitted by the last
+/// contract call.
+fn tagged_topics(f: &Fixture) -> std::vec::Vec<std::vec::Vec<std::string::String>> {
+    f.env
+        .events()
+        .all()
+        .filter_by_contract(&f.contract.address)
+        .events()
+        .iter()
+        .filter_map(|event| {
+            let ContractEventBody::V0(body) = &event.body;
+            let topics: std::vec::Vec<_> = body
+                .topics
+                .iter()
+                .filter_map(|topic| match topic {
+                    ScVal::Symbol(symbol) => Some(symbol.to_utf8_string_lossy()),
+                    _ => None,
+                })
+                .collect();
+            (topics.len() == 4 && topics[1] == "tagged").then_some(topics)
+        })
+        .collect()
+}
+
+#[test]
+fn test_create_tagged_remittance_emits_tag_topics() {
+    let f = setup();
+    let tags = vec![&f.env, symbol_short!("payroll"), symbol_short!("rent")];
+
+    let id = f
+        .contract
+        .create_tagged_remittance(&f.sender, &f.agent, &1_000, &None, &None, &tags);
+
+    let tagged = tagged_topics(&f);
+    assert_eq!(tagged.len(), 2);
+    assert_eq!(tagged[0][2], "payroll");
+    assert_eq!(tagged[1][2], "rent");
+    assert_eq!(tagged[1][3], "created");
+    assert_eq!(f.contract.get_remittance_tags(&id), tags);
+}
+
+#[test]
+fn test_create_tagged_remittance_rejects_duplicate_or_excess_tags() {
+    let f = setup();
+
+    let duplicate = vec![&f.env, symbol_short!("rent"), symbol_short!("rent")];
+    assert_eq!(
+        f.contract
+            .try_create_tagged_remittance(&f.sender, &f.agent, &1_000, &None, &None, &duplicate),
+        Err(Ok(ContractError::InvalidTags))
+    );
+
+    let excess = vec![
+        &f.env,
+        symbol_short!("a"),
+        symbol_short!("b"),
+        symbol_short!("c"),
+        symbol_short!("d"),
+    ];
+    assert!(excess.len() > MAX_REMITTANCE_TAGS);
+    assert_eq!(
+        f.contract
+            .try_create_tagged_remittance(&f.sender, &f.agent, &1_000, &None, &None, &excess),
+        Err(Ok(ContractError::InvalidTags))
+    );
+}
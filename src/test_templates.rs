@@ -0,0 +1,58 @@
+//! Tests for reusable remittance templates.
+
+#![cfg(test)]
+
+use soroban_sdk::{vec, String, Symbol};
+
+use crate::test_utils::setup;
+use crate::{ContractError, TemplateOverrides};
+
+#[test]
+fn test_create_from_template_with_overrides() {
+    let f = setup();
+    let memo = String::from_str(&f.env, "October rent");
+    let purpose = Symbol::new(&f.env, "rent");
+    let name = String::from_str(&f.env, "Rent to mum");
+    let tid = f.contract.save_remittance_template(
+        &f.sender,
+        &name,
+        &f.agent,
+        &1_000,
+        &None,
+        &Some(memo.clone()),
+        &Some(purpose.clone()),
+    );
+    assert_eq!(
+        f.contract.get_sender_templates(&f.sender),
+        vec![&f.env, tid]
+    );
+
+    let none = TemplateOverrides {
+        agent: None,
+        amount: None,
+        memo: None,
+    };
+    let id = f.contract.create_from_template(&tid, &none);
+    let r = f.contract.get_remittance(&id);
+    assert_eq!((r.amount, r.memo), (1_000, Some(memo)));
+    assert_eq!(f.contract.get_remittance_tags(&id), vec![&f.env, purpose]);
+
+    let bigger = TemplateOverrides {
+        agent: None,
+        amount: Some(1_500),
+        memo: None,
+    };
+    assert_eq!(
+        f.contract
+            .get_remittance(&f.contract.create_from_template(&tid, &bigger))
+            .amount,
+        1_500
+    );
+
+    f.contract.delete_remittance_template(&f.sender, &tid);
+    assert_eq!(
+        f.contract.try_create_from_template(&tid, &none),
+        Err(Ok(ContractError::TemplateNotFound))
+    );
+    assert_eq!(f.contract.get_sender_templates(&f.sender).len(), 0);
+}
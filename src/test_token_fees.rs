@@ -0,0 +1,66 @@
+//! Tests for fee accounting per token.
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address};
+
+use crate::test_utils::{create_token, remit, settlement_entries, setup, token_balance, Fixture};
+
+fn remit_in(f: &Fixture, tok: &token::StellarAssetClient, amount: i128) -> u64 {
+    f.contract.create_remittance(
+        &f.sender,
+        &f.agent,
+        &amount,
+        &None,
+        &Some(tok.address.clone()),
+        &None,
+        &None,
+        &None,
+    )
+}
+
+#[test]
+fn test_fees_accounted_per_token() {
+    let f = setup();
+    let t2 = create_token(&f);
+    t2.mint(&f.sender, &5_000);
+    f.contract.add_whitelisted_token(&t2.address);
+    let id = remit_in(&f, &t2, 1_000);
+    f.contract
+        .confirm_payout(&f.agent, &id, &None, &None, &None);
+    assert_eq!(f.contract.get_token_fees(&t2.address), 25);
+    assert_eq!(f.contract.get_accumulated_fees(), 0);
+
+    let treasury = Address::generate(&f.env);
+    f.contract
+        .withdraw_token_fees(&f.admin, &t2.address, &treasury);
+    assert_eq!(token_balance(&f, &t2, &treasury), 25);
+    assert_eq!(f.contract.get_token_fees(&t2.address), 0);
+}
+
+#[test]
+fn test_netting_fees_accounted_per_token() {
+    let f = setup();
+    let t2 = create_token(&f);
+    t2.mint(&f.sender, &5_000);
+    f.contract.add_whitelisted_token(&t2.address);
+    let usdc = remit(&f, 1_000);
+    let other = remit_in(&f, &t2, 2_000);
+
+    f.contract
+        .batch_settle_with_netting(&settlement_entries(&f, &[usdc, other]));
+    assert_eq!(f.contract.get_accumulated_fees(), 25);
+    assert_eq!(f.contract.get_token_fees(&t2.address), 50);
+    assert_eq!(token_balance(&f, &t2, &f.agent), 1_950);
+}
+
+#[test]
+fn test_cancel_refunds_in_remittance_token() {
+    let f = setup();
+    let t2 = create_token(&f);
+    t2.mint(&f.sender, &5_000);
+    f.contract.add_whitelisted_token(&t2.address);
+    let id = remit_in(&f, &t2, 1_000);
+    f.contract.cancel_remittance(&id);
+    assert_eq!(token_balance(&f, &t2, &f.sender), 5_000);
+}
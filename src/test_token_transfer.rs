@@ -0,0 +1,147 @@
+//! Tests for token transfer failures during payouts.
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, IssuerFlags},
+    token, vec,
+    xdr::{ContractEventBody, ScVal},
+    Address, Env, Symbol, TryFromVal, Val,
+};
+
+use crate::test_utils::{balance, remit, Fixture, FEE_BPS, SENDER_FUNDS};
+use crate::{ContractError, RemittanceStatus, SwiftRemitContract, SwiftRemitContractClient};
+
+/// Like `setup`, but the token issuer can freeze accounts.
+fn setup_revocable<'a>() -> Fixture<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(admin.clone());
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let token = token::StellarAssetClient::new(&env, &sac.address());
+    token.mint(&sender, &SENDER_FUNDS);
+    let contract = SwiftRemitContractClient::new(&env, &env.register(SwiftRemitContract, ()));
+    contract.initialize(&admin, &token.address, &FEE_BPS, &0, &0, &admin);
+    contract.register_agent(&agent, &None);
+    contract.set_kyc_approved(&sender, &true, &u64::MAX);
+    Fixture {
+        env,
+        contract,
+        token,
+        admin,
+        sender,
+        agent,
+    }
+}
+
+#[test]
+fn test_payout_to_frozen_agent_returns_specific_error() {
+    let f = setup_revocable();
+    let id = remit(&f, 1_000);
+    f.token.set_authorized(&f.agent, &false);
+    let r = f
+        .contract
+        .try_confirm_payout(&f.agent, &id, &None, &None, &None);
+    assert_eq!(r, Err(Ok(ContractError::TokenAccountFrozen)));
+    assert_eq!(
+        f.contract.get_remittance(&id).status,
+        RemittanceStatus::Pending
+    );
+}
+
+#[test]
+fn test_best_effort_batch_holds_undelivered_payout() {
+    let f = setup_revocable();
+    let frozen = Address::generate(&f.env);
+    f.contract.register_agent(&frozen, &None);
+    let paid = remit(&f, 1_000);
+    let held = f.contract.create_remittance(
+        &f.sender, &frozen, &1_000, &None, &None, &None, &None, &None,
+    );
+    f.token.set_authorized(&frozen, &false);
+
+    let failures =
+        f.contract
+            .confirm_batch_payout_best_effort(&f.agent, &vec![&f.env, paid], &false);
+    assert!(failures.is_empty());
+    let events = f
+        .env
+        .events()
+        .all()
+        .filter_by_contract(&f.contract.address)
+        .events()
+        .to_vec();
+    let ContractEventBody::V0(summary) = &events.last().unwrap().body;
+    assert!(
+        matches!(summary.topics.get(1), Some(ScVal::Symbol(s)) if AsRef::<[u8]>::as_ref(s) == b"paid")
+    );
+    assert_eq!(summary.data, ScVal::U32(1));
+    let failures =
+        f.contract
+            .confirm_batch_payout_best_effort(&frozen, &vec![&f.env, held], &false);
+    assert_eq!(
+        failures,
+        vec![&f.env, (held, ContractError::TokenAccountFrozen as u32)]
+    );
+    assert_eq!(
+        f.contract.get_remittance(&held).status,
+        RemittanceStatus::Completed
+    );
+    assert_eq!(
+        f.contract.get_undelivered_payout(&frozen, &f.token.address),
+        975
+    );
+
+    let r = f
+        .contract
+        .try_claim_undelivered_payout(&frozen, &f.token.address);
+    assert_eq!(r, Err(Ok(ContractError::TokenAccountFrozen)));
+    f.token.set_authorized(&frozen, &true);
+    assert_eq!(
+        f.contract
+            .claim_undelivered_payout(&frozen, &f.token.address),
+        975
+    );
+    assert_eq!(balance(&f, &frozen), 975);
+    assert_eq!(
+        f.contract.get_undelivered_payout(&frozen, &f.token.address),
+        0
+    );
+}
+
+#[test]
+fn test_failed_transfer_emits_external_call_diagnostic() {
+    let f = setup_revocable();
+    let id = remit(&f, 1_000);
+    f.token.set_authorized(&f.agent, &false);
+    f.contract
+        .confirm_batch_payout_best_effort(&f.agent, &vec![&f.env, id], &false);
+
+    let events = f
+        .env
+        .events()
+        .all()
+        .filter_by_contract(&f.contract.address)
+        .events()
+        .to_vec();
+    let body = events.iter().find_map(|e| {
+        let ContractEventBody::V0(body) = &e.body;
+        matches!(
+            (body.topics.first(), body.topics.get(1)),
+            (Some(ScVal::Symbol(a)), Some(ScVal::Symbol(b)))
+                if AsRef::<[u8]>::as_ref(a) == b"extcall" && AsRef::<[u8]>::as_ref(b) == b"failed"
+        )
+        .then_some(body)
+    });
+    let data = Val::try_from_val(&f.env, &body.expect("no extcall/failed event").data).unwrap();
+    let (_, _, _, callee, function, remittance_id, error_code) =
+        <(u32, u32, u64, Address, Symbol, u64, u32)>::try_from_val(&f.env, &data).unwrap();
+    assert_eq!(callee, f.token.address);
+    assert_eq!(function, Symbol::new(&f.env, "transfer"));
+    assert_eq!(remittance_id, id);
+    // The SAC's BalanceDeauthorizedError
+    assert_eq!(error_code, 11);
+}
@@ -0,0 +1,128 @@
+//! Shared fixture for the per-feature test modules.
+//!
+//! `setup` deploys an initialized contract with one whitelisted token, a
+//! KYC-approved sender funded with `SENDER_FUNDS`, and one registered agent.
+//! The platform fee is 2.5% (250 bps) and no integrator fee is charged.
+
+#![cfg(test)]
+#![allow(dead_code)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Events as _, Ledger},
+    token,
+    xdr::{ContractEventBody, ScVal},
+    vec, Address, Env, String, Vec,
+};
+
+use crate::{
+    BatchSettlementEntry, RemittanceCorridor, SwiftRemitContract, SwiftRemitContractClient,
+};
+
+/// Tokens minted to the fixture sender.
+pub const SENDER_FUNDS: i128 = 100_000;
+
+/// Platform fee configured by `setup`, in basis points.
+pub const FEE_BPS: u32 = 250;
+
+pub struct Fixture<'a> {
+    pub env: Env,
+    pub contract: SwiftRemitContractClient<'a>,
+    pub token: token::StellarAssetClient<'a>,
+    pub admin: Address,
+    pub sender: Address,
+    pub agent: Address,
+}
+
+pub fn setup<'a>() -> Fixture<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let sender = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let token = token::StellarAssetClient::new(&env, &token_address);
+    token.mint(&sender, &SENDER_FUNDS);
+
+    let contract = SwiftRemitContractClient::new(&env, &env.register(SwiftRemitContract, ()));
+    contract.initialize(&admin, &token_address, &FEE_BPS, &0, &0, &admin);
+    contract.register_agent(&agent, &None);
+    contract.set_kyc_approved(&sender, &true, &u64::MAX);
+
+    Fixture {
+        env,
+        contract,
+        token,
+        admin,
+        sender,
+        agent,
+    }
+}
+
+/// Creates a pending remittance of `amount` from the fixture sender to the fixture agent.
+pub fn remit(f: &Fixture, amount: i128) -> u64 {
+    f.contract
+        .create_remittance(&f.sender, &f.agent, &amount, &None, &None, &None, &None, &None)
+}
+
+/// Returns `address`'s balance of the fixture token.
+pub fn balance(f: &Fixture, address: &Address) -> i128 {
+    token::Client::new(&f.env, &f.token.address).balance(address)
+}
+
+/// Registers another token administered by the fixture admin.
+pub fn create_token<'a>(f: &Fixture) -> token::StellarAssetClient<'a> {
+    let address = f
+        .env
+        .register_stellar_asset_contract_v2(f.admin.clone())
+        .address();
+    token::StellarAssetClient::new(&f.env, &address)
+}
+
+/// Returns `address`'s balance of `token`.
+pub fn token_balance(f: &Fixture, token: &token::StellarAssetClient, address: &Address) -> i128 {
+    token::Client::new(&f.env, &token.address).balance(address)
+}
+
+/// Wraps remittance IDs as `batch_settle_with_netting` entries.
+pub fn settlement_entries(f: &Fixture, ids: &[u64]) -> Vec<BatchSettlementEntry> {
+    let mut entries = vec![&f.env];
+    for id in ids {
+        entries.push_back(BatchSettlementEntry { remittance_id: *id });
+    }
+    entries
+}
+
+/// The US to MX corridor.
+pub fn us_mx(env: &Env) -> RemittanceCorridor {
+    RemittanceCorridor {
+        from_country: String::from_str(env, "US"),
+        to_country: String::from_str(env, "MX"),
+    }
+}
+
+/// Advances the ledger clock by `seconds`.
+pub fn advance_time(f: &Fixture, seconds: u64) {
+    f.env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+/// Returns whether the last contract call emitted a `(topic_a, topic_b)` event.
+pub fn has_event(f: &Fixture, topic_a: &str, topic_b: &str) -> bool {
+    f.env
+        .events()
+        .all()
+        .filter_by_contract(&f.contract.address)
+        .events()
+        .iter()
+        .any(|event| {
+            let ContractEventBody::V0(body) = &event.body;
+            matches!(
+                (body.topics.first(), body.topics.get(1)),
+                (Some(ScVal::Symbol(a)), Some(ScVal::Symbol(b)))
+                    if a.0.as_slice() == topic_a.as_bytes() && b.0.as_slice() == topic_b.as_bytes()
+            )
+        })
+}
@@ -0,0 +1,47 @@
+//! Tests for daily volume history buckets.
+
+#![cfg(test)]
+
+use soroban_sdk::testutils::Ledger;
+
+use crate::test_utils::{advance_time, remit, setup};
+use crate::{VOLUME_BUCKET_SECONDS, VOLUME_HISTORY_BUCKETS};
+
+#[test]
+fn test_volume_history_buckets_by_day() {
+    let f = setup();
+    f.env
+        .ledger()
+        .with_mut(|l| l.timestamp = 100 * VOLUME_BUCKET_SECONDS);
+    let id = remit(&f, 1000);
+    f.contract
+        .confirm_payout(&f.agent, &id, &None, &None, &None);
+    advance_time(&f, VOLUME_BUCKET_SECONDS);
+    remit(&f, 500);
+
+    let history = f.contract.get_volume_history(&3);
+    assert_eq!(history.len(), 3);
+    let (yesterday, today) = (history.get(1).unwrap(), history.get(2).unwrap());
+    assert_eq!(today.day, yesterday.day + 1);
+    assert_eq!(
+        (
+            yesterday.created_volume,
+            yesterday.settled_volume,
+            yesterday.fee_revenue
+        ),
+        (1000, 1000, 25)
+    );
+    assert_eq!(
+        (
+            today.created_volume,
+            today.settled_volume,
+            today.fee_revenue
+        ),
+        (500, 0, 0)
+    );
+    assert_eq!(history.get(0).unwrap().created_volume, 0);
+    assert_eq!(
+        f.contract.get_volume_history(&u32::MAX).len(),
+        VOLUME_HISTORY_BUCKETS
+    );
+}
@@ -0,0 +1,110 @@
+//! Tests for settling remittances with agent-signed payout vouchers.
+
+#![cfg(test)]
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{BytesN, Bytes};
+
+use crate::test_utils::{balance, remit, setup, Fixture};
+use crate::voucher::{voucher_payload, VoucherEvidence};
+use crate::{ContractError, RemittanceStatus};
+
+/// Registers a fixed ed25519 voucher key for the fixture agent.
+fn agent_key(f: &Fixture) -> SigningKey {
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let public_key = BytesN::from_array(&f.env, &key.verifying_key().to_bytes());
+    f.contract.set_voucher_key(&f.agent, &public_key);
+    key
+}
+
+/// Signs a voucher for remittance `id` over `evidence`.
+fn sign(
+    f: &Fixture,
+    key: &SigningKey,
+    id: u64,
+    amount: i128,
+    nonce: u64,
+    evidence: &VoucherEvidence,
+) -> BytesN<64> {
+    let payload = f.env.as_contract(&f.contract.address, || {
+        voucher_payload(&f.env, id, amount, nonce, evidence)
+    });
+    let message: std::vec::Vec<u8> = payload.iter().collect();
+    BytesN::from_array(&f.env, &key.sign(&message).to_bytes())
+}
+
+fn no_evidence() -> VoucherEvidence {
+    VoucherEvidence {
+        proof: None,
+        recipient_details_hash: None,
+        pickup_secret: None,
+    }
+}
+
+#[test]
+fn test_voucher_settles_with_signed_evidence() {
+    let f = setup();
+    let key = agent_key(&f);
+    let id = remit(&f, 1_000);
+    let evidence = VoucherEvidence {
+        pickup_secret: Some(Bytes::from_slice(&f.env, b"open sesame")),
+        ..no_evidence()
+    };
+    let signature = sign(&f, &key, id, 1_000, 1, &evidence);
+
+    f.contract.settle_with_voucher(
+        &id,
+        &1_000,
+        &1,
+        &signature,
+        &None,
+        &None,
+        &evidence.pickup_secret,
+    );
+
+    assert_eq!(f.contract.get_remittance(&id).status, RemittanceStatus::Completed);
+    assert_eq!(balance(&f, &f.agent), 975);
+    assert_eq!(
+        f.contract.try_settle_with_voucher(
+            &id,
+            &1_000,
+            &1,
+            &signature,
+            &None,
+            &None,
+            &evidence.pickup_secret,
+        ),
+        Err(Ok(ContractError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_voucher_rejects_substituted_evidence() {
+    let f = setup();
+    let key = agent_key(&f);
+    let id = remit(&f, 1_000);
+    let signature = sign(&f, &key, id, 1_000, 1, &no_evidence());
+    let substituted = Some(BytesN::from_array(&f.env, &[9; 32]));
+
+    assert!(f
+        .contract
+        .try_settle_with_voucher(&id, &1_000, &1, &signature, &None, &substituted, &None)
+        .is_err());
+    assert!(f
+        .contract
+        .try_settle_with_voucher(
+            &id,
+            &1_000,
+            &1,
+            &signature,
+            &None,
+            &None,
+            &Some(Bytes::from_slice(&f.env, b"guess")),
+        )
+        .is_err());
+    assert_eq!(f.contract.get_remittance(&id).status, RemittanceStatus::Pending);
+
+    f.contract
+        .settle_with_voucher(&id, &1_000, &1, &signature, &None, &None, &None);
+    assert_eq!(f.contract.get_remittance(&id).status, RemittanceStatus::Completed);
+}
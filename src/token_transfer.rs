@@ -0,0 +1,117 @@
+//! Token transfers that fail with a `ContractError` instead of trapping.
+//!
+//! A failing `transfer` on a token client aborts the whole invocation with an
+//! opaque host error, for example when the payee's trustline is frozen.
+//! [`transfer`] goes through `try_transfer` instead and maps the Stellar Asset
+//! Contract's failure codes to specific errors.
+//!
+//! Best-effort batches use [`pay`], which holds a payout that cannot be
+//! delivered as an undelivered balance for the payee rather than failing.
+//! The payee withdraws it with `claim_undelivered_payout` once the cause is
+//! fixed.
+//!
+//! Every failed transfer emits an `extcall`/`failed` diagnostic. When the
+//! failure aborts the invocation the event is rolled back with it, but RPC
+//! simulation and failed-transaction diagnostics still report it.
+
+use soroban_sdk::{symbol_short, token, xdr::ScErrorType, Address, Env};
+
+use crate::events::{emit_external_call_failed, emit_payout_held};
+use crate::storage::{get_undelivered_payout, set_undelivered_payout};
+use crate::ContractError;
+
+/// Stellar Asset Contract error codes with a specific mapping.
+const SAC_ACCOUNT_MISSING: u32 = 6;
+const SAC_BALANCE: u32 = 10;
+const SAC_BALANCE_DEAUTHORIZED: u32 = 11;
+const SAC_TRUSTLINE_MISSING: u32 = 13;
+
+/// Transfers `amount` of the client's token from `from` to `to`.
+pub fn transfer(
+    client: &token::Client,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    transfer_for(client, 0, from, to, amount)
+}
+
+/// Like [`transfer`], naming the remittance the transfer is for in the
+/// failure diagnostic.
+pub fn transfer_for(
+    client: &token::Client,
+    remittance_id: u64,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let (code, error) = match client.try_transfer(from, to, &amount) {
+        Ok(Ok(())) => return Ok(()),
+        Err(Ok(error)) if error.is_type(ScErrorType::Contract) => {
+            let code = error.get_code();
+            let error = match code {
+                SAC_BALANCE => ContractError::TokenBalanceInsufficient,
+                SAC_BALANCE_DEAUTHORIZED => ContractError::TokenAccountFrozen,
+                SAC_ACCOUNT_MISSING | SAC_TRUSTLINE_MISSING => ContractError::TokenTrustlineMissing,
+                _ => ContractError::TokenTransferFailed,
+            };
+            (code, error)
+        }
+        _ => (0, ContractError::TokenTransferFailed),
+    };
+    emit_external_call_failed(
+        &client.env,
+        client.address.clone(),
+        symbol_short!("transfer"),
+        remittance_id,
+        code,
+    );
+    Err(error)
+}
+
+/// Pays `amount` from the contract to `to` for a remittance.
+///
+/// With `best_effort` set, a failed transfer is held as an undelivered
+/// balance for `to` and its error returned as `Ok(Some(error))`.
+pub fn pay(
+    env: &Env,
+    client: &token::Client,
+    remittance_id: u64,
+    to: &Address,
+    amount: i128,
+    best_effort: bool,
+) -> Result<Option<ContractError>, ContractError> {
+    match transfer_for(client, remittance_id, &env.current_contract_address(), to, amount) {
+        Ok(()) => Ok(None),
+        Err(error) if best_effort => {
+            hold(env, client, remittance_id, to, amount, error)?;
+            Ok(Some(error))
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Holds `amount` of the client's token as an undelivered balance for `to`
+/// after a payment to it failed with `error`.
+pub fn hold(
+    env: &Env,
+    client: &token::Client,
+    remittance_id: u64,
+    to: &Address,
+    amount: i128,
+    error: ContractError,
+) -> Result<(), ContractError> {
+    let held = get_undelivered_payout(env, to, &client.address)
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    set_undelivered_payout(env, to, &client.address, held);
+    emit_payout_held(
+        env,
+        remittance_id,
+        to.clone(),
+        client.address.clone(),
+        amount,
+        error as u32,
+    );
+    Ok(())
+}
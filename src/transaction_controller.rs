@@ -1,6 +1,7 @@
 use soroban_sdk::{contracttype, Address, Env};
 
-use crate::{ContractError, Remittance, RemittanceStatus};
+use crate::token_transfer;
+use crate::{ContractError, Expiry, Remittance, RemittanceStatus};
 
 /// Transaction state for tracking and rollback
 #[contracttype]
@@ -63,7 +64,7 @@ impl TransactionController {
         user: Address,
         agent: Address,
         amount: i128,
-        expiry: Option<u64>,
+        expiry: Option<Expiry>,
     ) -> Result<TransactionRecord, ContractError> {
         let mut record = TransactionRecord {
             user: user.clone(),
@@ -94,7 +95,7 @@ impl TransactionController {
     fn execute_with_retry(
         env: &Env,
         record: &mut TransactionRecord,
-        expiry: Option<u64>,
+        expiry: Option<Expiry>,
     ) -> Result<(), ContractError> {
         let mut last_error = ContractError::NotInitialized;
 
@@ -129,7 +130,7 @@ impl TransactionController {
     fn execute_transaction_steps(
         env: &Env,
         record: &mut TransactionRecord,
-        expiry: Option<u64>,
+        expiry: Option<Expiry>,
     ) -> Result<(), ContractError> {
         // Step 1: Validate user eligibility
         Self::validate_eligibility(env, &record.user)?;
@@ -196,7 +197,7 @@ impl TransactionController {
         sender: &Address,
         agent: &Address,
         amount: i128,
-        expiry: Option<u64>,
+        expiry: Option<Expiry>,
     ) -> Result<u64, ContractError> {
         // Validate amount
         if amount <= 0 {
@@ -208,18 +209,23 @@ impl TransactionController {
             return Err(ContractError::AgentNotRegistered);
         }
 
-        // Calculate fee
-        let fee_bps = crate::storage::get_platform_fee_bps(env)?;
-        let fee = amount
-            .checked_mul(fee_bps as i128)
-            .ok_or(ContractError::Overflow)?
-            .checked_div(crate::config::FEE_DIVISOR)
-            .ok_or(ContractError::Overflow)?;
+        // Calculate fee; fee-exempt senders are not charged
+        let fee_exempt = crate::storage::is_fee_exempt(env, sender);
+        let fee = if fee_exempt {
+            0
+        } else {
+            let fee_bps = crate::storage::get_platform_fee_bps(env)?;
+            amount
+                .checked_mul(fee_bps as i128)
+                .ok_or(ContractError::Overflow)?
+                .checked_div(crate::config::FEE_DIVISOR)
+                .ok_or(ContractError::Overflow)?
+        };
 
         // Transfer tokens to contract
         let usdc_token = crate::storage::get_usdc_token(env)?;
         let token_client = soroban_sdk::token::Client::new(env, &usdc_token);
-        token_client.transfer(sender, &env.current_contract_address(), &amount);
+        token_transfer::transfer(&token_client, sender, &env.current_contract_address(), amount)?;
 
         // Create remittance record
         let counter = crate::storage::get_remittance_counter(env)?;
@@ -232,13 +238,17 @@ impl TransactionController {
             amount,
             fee,
             status: RemittanceStatus::Pending,
-            expiry,
+            expiry: expiry.into(),
             settlement_config: crate::MaybeSettlementConfig::None,
             token: usdc_token.clone(),
             created_at: env.ledger().timestamp(),
             failed_at: None,
             dispute_evidence: crate::MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         };
 
         crate::storage::set_remittance(env, remittance_id, &remittance);
@@ -257,6 +267,7 @@ impl TransactionController {
             fee,        // platform_fee
             0,          // protocol_fee (not tracked in transaction controller)
             net_amount,
+            fee_exempt,
         );
 
         Ok(remittance_id)
@@ -331,25 +342,30 @@ impl TransactionController {
                 // Refund tokens
                 let usdc_token = crate::storage::get_usdc_token(env)?;
                 let token_client = soroban_sdk::token::Client::new(env, &usdc_token);
-                token_client.transfer(
+                token_transfer::transfer(
+                    &token_client,
                     &env.current_contract_address(),
                     &remittance.sender,
-                    &remittance.amount,
-                );
+                    remittance.amount,
+                )?;
 
                 // Update status
                 remittance.status = RemittanceStatus::Cancelled;
                 crate::storage::set_remittance(env, remittance_id, &remittance);
+                crate::corridor_utilization::release_pending(env, &remittance);
+                crate::agent_tiers::release_pending(env, &remittance);
 
                 // Emit event
-                crate::events::emit_remittance_cancelled(
-                    env,
-                    remittance_id,
-                    remittance.sender,
-                    remittance.agent,
-                    usdc_token,
-                    remittance.amount,
-                );
+                if !crate::emit_minimal_lifecycle(env, &remittance) {
+                    crate::events::emit_remittance_cancelled(
+                        env,
+                        remittance_id,
+                        remittance.sender,
+                        remittance.agent,
+                        usdc_token,
+                        remittance.amount,
+                    );
+                }
             }
         }
 
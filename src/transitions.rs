@@ -4,8 +4,10 @@
 //!
 //! ```text
 //! Pending → Processing → Completed
-//!         ↘            ↘
-//!           Cancelled    Cancelled
+//!    │    ↘            ↘
+//!    │      Cancelled    Cancelled
+//!    ↓
+//! Expired
 //! ```
 //!
 //! Rules:
@@ -79,6 +81,18 @@ pub fn transition_status(
     // Log transition for debugging (only in test/debug builds)
     log_transition(env, remittance.id, &remittance.status, &new_status);
 
+    // Record lifecycle timestamps the first time each milestone is reached
+    let now = env.ledger().timestamp();
+    match new_status {
+        RemittanceStatus::Processing if remittance.accepted_at.is_none() => {
+            remittance.accepted_at = Some(now);
+        }
+        RemittanceStatus::Completed if remittance.completed_at.is_none() => {
+            remittance.completed_at = Some(now);
+        }
+        _ => {}
+    }
+
     // Atomically update the status
     remittance.status = new_status;
 
@@ -116,12 +130,13 @@ pub fn get_valid_next_states(status: &RemittanceStatus) -> soroban_sdk::Vec<Remi
         RemittanceStatus::Pending => {
             result.push_back(RemittanceStatus::Processing);
             result.push_back(RemittanceStatus::Cancelled);
+            result.push_back(RemittanceStatus::Expired);
         }
         RemittanceStatus::Processing => {
             result.push_back(RemittanceStatus::Completed);
             result.push_back(RemittanceStatus::Cancelled);
         }
-        RemittanceStatus::Completed | RemittanceStatus::Cancelled => {}
+        RemittanceStatus::Completed | RemittanceStatus::Cancelled | RemittanceStatus::Expired => {}
         RemittanceStatus::Failed => { result.push_back(RemittanceStatus::Disputed); }
         RemittanceStatus::Disputed => {}
     }
@@ -271,9 +286,10 @@ mod tests {
     #[test]
     fn test_valid_next_states_from_pending() {
         let next_states = get_valid_next_states(&RemittanceStatus::Pending);
-        assert_eq!(next_states.len(), 2);
+        assert_eq!(next_states.len(), 3);
         assert!(next_states.contains(&RemittanceStatus::Processing));
         assert!(next_states.contains(&RemittanceStatus::Cancelled));
+        assert!(next_states.contains(&RemittanceStatus::Expired));
     }
 
     #[test]
@@ -310,13 +326,17 @@ mod tests {
             amount: 100,
             fee: 2,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token,
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         };
 
         let result = transition_status(&env, &mut remittance, RemittanceStatus::Processing);
@@ -338,13 +358,17 @@ mod tests {
             amount: 100,
             fee: 2,
             status: RemittanceStatus::Completed,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token,
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         };
 
         let result = transition_status(&env, &mut remittance, RemittanceStatus::Pending);
@@ -353,6 +377,46 @@ mod tests {
         assert_eq!(remittance.status, RemittanceStatus::Completed);
     }
 
+    #[test]
+    fn test_transition_status_records_lifecycle_timestamps() {
+        use soroban_sdk::testutils::Ledger;
+
+        let env = Env::default();
+        let sender = soroban_sdk::Address::generate(&env);
+        let agent = soroban_sdk::Address::generate(&env);
+        let token = soroban_sdk::Address::generate(&env);
+
+        let mut remittance = crate::Remittance {
+            id: 1,
+            sender,
+            agent,
+            amount: 100,
+            fee: 2,
+            status: RemittanceStatus::Pending,
+            expiry: crate::MaybeExpiry::None,
+            settlement_config: crate::MaybeSettlementConfig::None,
+            token,
+            created_at: 0,
+            failed_at: None,
+            dispute_evidence: MaybeBytes32::None,
+            expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
+        };
+
+        env.ledger().set_timestamp(100);
+        transition_status(&env, &mut remittance, RemittanceStatus::Processing).unwrap();
+        assert_eq!(remittance.accepted_at, Some(100));
+        assert_eq!(remittance.completed_at, None);
+
+        env.ledger().set_timestamp(250);
+        transition_status(&env, &mut remittance, RemittanceStatus::Completed).unwrap();
+        assert_eq!(remittance.accepted_at, Some(100));
+        assert_eq!(remittance.completed_at, Some(250));
+    }
+
     #[test]
     fn test_transition_status_idempotent() {
         let env = Env::default();
@@ -367,13 +431,17 @@ mod tests {
             amount: 100,
             fee: 2,
             status: RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token,
             created_at: 0,
             failed_at: None,
             dispute_evidence: MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         };
 
         let result = transition_status(&env, &mut remittance, RemittanceStatus::Pending);
@@ -3,7 +3,7 @@
 //! This module defines the core data structures used throughout the contract,
 //! including remittance records and status enums.
 
-use soroban_sdk::{contracttype, Address, String, Vec};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
 /// Role types for authorization
 #[contracttype]
@@ -11,6 +11,16 @@ use soroban_sdk::{contracttype, Address, String, Vec};
 pub enum Role {
     Admin,
     Settler,
+    /// Reviews insurance claims
+    ClaimResolver,
+    /// Links remittances to anchor transactions and reports their status
+    AnchorOperator,
+    /// Sets KYC tiers, token and corridor restrictions, and agent KYB status
+    Compliance,
+    /// Manages agents and can pause the contract
+    Operator,
+    /// Updates fees and fee strategies
+    FeeManager,
 }
 
 /// Canonical state enum representing the full remittance lifecycle.
@@ -22,8 +32,10 @@ pub enum Role {
 ///
 /// ```text
 /// Pending → Processing → Completed
-///         ↘            ↘
-///           Cancelled    Cancelled
+///    │    ↘            ↘
+///    │      Cancelled    Cancelled
+///    ↓
+/// Expired
 /// ```
 ///
 /// # State Descriptions
@@ -32,11 +44,12 @@ pub enum Role {
 /// - `Processing`: Agent has accepted and is executing the fiat payout off-chain
 /// - `Completed`:  Terminal — payout confirmed, USDC released to agent
 /// - `Cancelled`:  Terminal — cancelled by sender or failed payout, funds refunded
+/// - `Expired`:    Terminal — passed its expiry unpaid, funds refunded via `expire_remittance`
 ///
 /// # Terminal States
 ///
-/// `Completed` and `Cancelled` are terminal. No further transitions are allowed
-/// once either is reached, ensuring data integrity.
+/// `Completed`, `Cancelled`, and `Expired` are terminal. No further transitions
+/// are allowed once one is reached, ensuring data integrity.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum RemittanceStatus {
@@ -52,6 +65,8 @@ pub enum RemittanceStatus {
     Failed,
     /// The sender has challenged a failed payout
     Disputed,
+    /// Terminal state: expired before payout, funds refunded to the sender
+    Expired,
 }
 
 impl RemittanceStatus {
@@ -60,11 +75,11 @@ impl RemittanceStatus {
     /// `Failed` and `Disputed` are intentionally excluded — they are transient states
     /// from which further transitions are permitted (`Failed → Disputed`,
     /// `Disputed → Completed | Cancelled` via `resolve_dispute`).
-    /// Only `Completed` and `Cancelled` are truly terminal.
+    /// Only `Completed`, `Cancelled`, and `Expired` are truly terminal.
     pub fn is_terminal(&self) -> bool {
         matches!(
             self,
-            RemittanceStatus::Completed | RemittanceStatus::Cancelled
+            RemittanceStatus::Completed | RemittanceStatus::Cancelled | RemittanceStatus::Expired
         )
     }
 
@@ -74,6 +89,7 @@ impl RemittanceStatus {
             // From Pending
             (RemittanceStatus::Pending, RemittanceStatus::Processing) => true,
             (RemittanceStatus::Pending, RemittanceStatus::Cancelled) => true,
+            (RemittanceStatus::Pending, RemittanceStatus::Expired) => true,
             // From Processing
             (RemittanceStatus::Processing, RemittanceStatus::Completed) => true,
             (RemittanceStatus::Processing, RemittanceStatus::Cancelled) => true,
@@ -83,6 +99,7 @@ impl RemittanceStatus {
             // Terminal states cannot transition
             (RemittanceStatus::Completed, _) => false,
             (RemittanceStatus::Cancelled, _) => false,
+            (RemittanceStatus::Expired, _) => false,
             // Same state is allowed (idempotent)
             (a, b) if a == b => true,
             // All other transitions are invalid
@@ -91,6 +108,50 @@ impl RemittanceStatus {
     }
 }
 
+/// Number of remittances currently in each status, returned by `get_counts`.
+///
+/// The status counts move with every status change, so they sum to the
+/// remittances recorded since counting began.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RemittanceCounts {
+    pub pending_count: u64,
+    pub processing_count: u64,
+    pub completed_count: u64,
+    pub cancelled_count: u64,
+    pub failed_count: u64,
+    pub disputed_count: u64,
+    pub expired_count: u64,
+}
+
+impl RemittanceCounts {
+    /// Number of remittances in `status`.
+    pub fn count(&self, status: &RemittanceStatus) -> u64 {
+        match status {
+            RemittanceStatus::Pending => self.pending_count,
+            RemittanceStatus::Processing => self.processing_count,
+            RemittanceStatus::Completed => self.completed_count,
+            RemittanceStatus::Cancelled => self.cancelled_count,
+            RemittanceStatus::Failed => self.failed_count,
+            RemittanceStatus::Disputed => self.disputed_count,
+            RemittanceStatus::Expired => self.expired_count,
+        }
+    }
+
+    /// Counter for `status`.
+    pub fn count_mut(&mut self, status: &RemittanceStatus) -> &mut u64 {
+        match status {
+            RemittanceStatus::Pending => &mut self.pending_count,
+            RemittanceStatus::Processing => &mut self.processing_count,
+            RemittanceStatus::Completed => &mut self.completed_count,
+            RemittanceStatus::Cancelled => &mut self.cancelled_count,
+            RemittanceStatus::Failed => &mut self.failed_count,
+            RemittanceStatus::Disputed => &mut self.disputed_count,
+            RemittanceStatus::Expired => &mut self.expired_count,
+        }
+    }
+}
+
 /// Type alias kept for storage layer backward-compatibility.
 /// All new code should use `RemittanceStatus` directly.
 pub type TransferState = RemittanceStatus;
@@ -131,6 +192,30 @@ pub enum EscrowStatus {
     Pending,
     Released,
     Refunded,
+    /// Left unreleased past the fallback period; redeemable by the recipient
+    Claimable,
+}
+
+/// Delivery progress an agent reports between acceptance and confirmation.
+///
+/// Variants are ordered; a remittance's delivery status only moves forward.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum DeliveryStatus {
+    /// Cash or payout funds set aside for the recipient
+    FundsReserved,
+    /// Recipient told the funds are ready
+    RecipientNotified,
+    /// Funds handed to the recipient; awaiting on-chain confirmation
+    Delivered,
+}
+
+/// Latest delivery status reported for a remittance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeliveryUpdate {
+    pub status: DeliveryStatus,
+    pub updated_at: u64,
 }
 
 /// Escrow record for locked funds
@@ -188,10 +273,97 @@ impl From<Option<soroban_sdk::BytesN<32>>> for MaybeBytes32 {
     }
 }
 
-/// A remittance transaction record.
+/// Settlement deadline for a remittance.
+///
+/// Expiry can be expressed either as a ledger timestamp or as a ledger
+/// sequence number. Ledger sequences are unambiguous across regions and are
+/// not subject to validator clock drift, so integrators that need a hard
+/// on-chain deadline should prefer `Ledger`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Expiry {
+    /// Ledger timestamp (seconds since epoch) after which settlement fails
+    Time(u64),
+    /// Ledger sequence number after which settlement fails
+    Ledger(u32),
+}
+
+impl Expiry {
+    /// Returns true once the current ledger has moved past this deadline.
+    ///
+    /// Timestamp deadlines are extended by the admin-configured expiry
+    /// tolerance so every path (confirm, batch, sweep) agrees on expiry within
+    /// the same ledger close. Ledger sequences are exact and never adjusted.
+    pub fn is_reached(&self, env: &Env) -> bool {
+        match self {
+            Expiry::Time(timestamp) => {
+                let tolerance = crate::storage::get_expiry_tolerance(env);
+                env.ledger().timestamp() > timestamp.saturating_add(tolerance)
+            }
+            Expiry::Ledger(sequence) => env.ledger().sequence() > *sequence,
+        }
+    }
+
+    /// Canonical u64 encoding used by the deterministic hashing standard.
+    ///
+    /// Timestamps are encoded unchanged so existing settlement IDs stay stable;
+    /// ledger sequences set the top bit so the two forms can never collide.
+    pub fn to_hash_value(&self) -> u64 {
+        match self {
+            Expiry::Time(timestamp) => *timestamp,
+            Expiry::Ledger(sequence) => (1u64 << 63) | *sequence as u64,
+        }
+    }
+}
+
+/// Contracttype-compatible Option wrapper for Expiry.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MaybeExpiry {
+    None,
+    Some(Expiry),
+}
+
+impl From<Option<Expiry>> for MaybeExpiry {
+    fn from(opt: Option<Expiry>) -> Self {
+        match opt {
+            None => MaybeExpiry::None,
+            Some(v) => MaybeExpiry::Some(v),
+        }
+    }
+}
+
+impl From<MaybeExpiry> for Option<Expiry> {
+    fn from(m: MaybeExpiry) -> Self {
+        match m {
+            MaybeExpiry::None => None,
+            MaybeExpiry::Some(v) => Some(v),
+        }
+    }
+}
+
+/// Payment corridor a remittance was routed through.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittanceCorridor {
+    pub from_country: String,
+    pub to_country: String,
+}
+
+/// Contracttype-compatible Option wrapper for RemittanceCorridor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MaybeCorridor {
+    None,
+    Some(RemittanceCorridor),
+}
+
+/// A remittance transaction record (schema version 2).
 ///
 /// Contains all information about a cross-border remittance including
 /// parties involved, amounts, fees, status, and optional expiry.
+/// Records written before the V2 upgrade are stored as [`RemittanceV1`] and
+/// are migrated lazily the first time they are read through storage.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Remittance {
@@ -207,8 +379,8 @@ pub struct Remittance {
     pub fee: i128,
     /// Current status of the remittance
     pub status: RemittanceStatus,
-    /// Optional expiry timestamp (seconds since epoch) for settlement
-    pub expiry: Option<u64>,
+    /// Optional settlement deadline, as a timestamp or ledger sequence
+    pub expiry: MaybeExpiry,
     /// Optional settlement configuration for proof validation
     pub settlement_config: MaybeSettlementConfig,
     /// The specific token address used for this remittance
@@ -221,6 +393,117 @@ pub struct Remittance {
     pub dispute_evidence: MaybeBytes32,
     /// Ledger timestamp after which anyone can call expire_remittance to refund the sender
     pub expires_at: Option<u64>,
+    /// Ledger timestamp when the agent accepted the remittance (moved it to Processing)
+    pub accepted_at: Option<u64>,
+    /// Ledger timestamp when the remittance reached Completed
+    pub completed_at: Option<u64>,
+    /// Optional free-form memo attached by the sender
+    pub memo: Option<String>,
+    /// Corridor used for fee calculation, if the remittance was corridor-routed
+    pub corridor: MaybeCorridor,
+}
+
+impl Remittance {
+    /// Returns true once a payout has been executed for this remittance.
+    ///
+    /// Settlement always moves a remittance out of Pending and stamps
+    /// `accepted_at` (Processing) or `completed_at` (net settlement), so the
+    /// record itself is sufficient for duplicate-settlement detection.
+    pub fn is_settlement_executed(&self) -> bool {
+        matches!(
+            self.status,
+            RemittanceStatus::Processing | RemittanceStatus::Completed
+        ) || self.accepted_at.is_some()
+            || self.completed_at.is_some()
+    }
+
+    /// Returns the deadline this remittance has passed, if any.
+    ///
+    /// Both the sender's settlement `expiry` and the configured `expires_at`
+    /// window count; whichever is reached first expires the remittance.
+    pub fn reached_expiry(&self, env: &Env) -> Option<Expiry> {
+        if let MaybeExpiry::Some(expiry) = self.expiry {
+            if expiry.is_reached(env) {
+                return Some(expiry);
+            }
+        }
+        self.expires_at
+            .map(Expiry::Time)
+            .filter(|expiry| expiry.is_reached(env))
+    }
+
+    /// Seconds from creation to completion, once the remittance has completed.
+    pub fn settlement_latency(&self) -> Option<u64> {
+        self.completed_at
+            .map(|completed_at| completed_at.saturating_sub(self.created_at))
+    }
+
+    /// Seconds from agent acceptance to completion, when both were recorded.
+    ///
+    /// `None` for remittances completed without passing through Processing
+    /// (e.g. net settlement batches).
+    pub fn processing_latency(&self) -> Option<u64> {
+        Some(self.completed_at?.saturating_sub(self.accepted_at?))
+    }
+}
+
+/// Alias naming the current remittance layout explicitly.
+pub type RemittanceV2 = Remittance;
+
+/// Legacy remittance layout (schema version 1), as stored before the V2 upgrade.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittanceV1 {
+    pub id: u64,
+    pub sender: Address,
+    pub agent: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub status: RemittanceStatus,
+    /// Optional expiry timestamp (seconds since epoch) for settlement
+    pub expiry: Option<u64>,
+    pub settlement_config: MaybeSettlementConfig,
+    pub token: Address,
+    pub created_at: u64,
+    pub failed_at: Option<u64>,
+    pub dispute_evidence: MaybeBytes32,
+    pub expires_at: Option<u64>,
+}
+
+impl From<RemittanceV1> for Remittance {
+    fn from(v1: RemittanceV1) -> Self {
+        Remittance {
+            id: v1.id,
+            sender: v1.sender,
+            agent: v1.agent,
+            amount: v1.amount,
+            fee: v1.fee,
+            status: v1.status,
+            expiry: v1.expiry.map(Expiry::Time).into(),
+            settlement_config: v1.settlement_config,
+            token: v1.token,
+            created_at: v1.created_at,
+            failed_at: v1.failed_at,
+            dispute_evidence: v1.dispute_evidence,
+            expires_at: v1.expires_at,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: MaybeCorridor::None,
+        }
+    }
+}
+
+/// A stored remittance in whichever schema version it was found.
+///
+/// Returned by `get_remittance_any` so integrators can inspect records that
+/// have not been migrated yet without triggering the lazy migration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum VersionedRemittance {
+    V1(RemittanceV1),
+    V2(Remittance),
 }
 
 #[contracttype]
@@ -262,8 +545,42 @@ pub struct BatchCreateEntry {
     pub agent: Address,
     /// Amount to send (in USDC)
     pub amount: i128,
-    /// Optional expiry timestamp (seconds since epoch) for settlement
-    pub expiry: Option<u64>,
+    /// Optional settlement deadline, as a timestamp or ledger sequence
+    pub expiry: MaybeExpiry,
+}
+
+/// One page of remittance IDs from a cursor-paginated list endpoint.
+///
+/// Pass `next_cursor` back as the `cursor` argument to fetch the following
+/// page; `has_more` is false once the end of the list has been reached.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IdPage {
+    pub items: Vec<u64>,
+    pub next_cursor: u32,
+    pub has_more: bool,
+}
+
+/// One page of addresses from a cursor-paginated list endpoint.
+///
+/// Follows the same cursor convention as [`IdPage`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressPage {
+    pub items: Vec<Address>,
+    pub next_cursor: u32,
+    pub has_more: bool,
+}
+
+/// One page of remittance records from a cursor-paginated list endpoint.
+///
+/// Follows the same cursor convention as [`IdPage`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittancePage {
+    pub items: Vec<Remittance>,
+    pub next_cursor: u32,
+    pub has_more: bool,
 }
 
 /// Result of a batch settlement operation.
@@ -290,6 +607,56 @@ pub struct SettlementSimulation {
     pub error_message: Option<u32>,
 }
 
+/// Projected outcome of one entry in a batch settlement dry run.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchEntryPreview {
+    pub remittance_id: u64,
+    /// Whether the entry passes batch settlement validation
+    pub would_succeed: bool,
+    /// The payout amount the agent would receive (amount - fee)
+    pub payout_amount: i128,
+    /// The platform fee that would be collected
+    pub fee: i128,
+    /// `ContractError` code if would_succeed is false
+    pub error_code: Option<u32>,
+}
+
+/// Projected outcome of `batch_settle_with_netting`, computed without moving
+/// tokens or writing state.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchSettlementPreview {
+    /// Per-entry results, in input order
+    pub entries: Vec<BatchEntryPreview>,
+    /// Whether the batch as a whole would settle
+    pub would_succeed: bool,
+    /// `ContractError` code of the first failure, per-entry or batch-wide
+    pub error_code: Option<u32>,
+    /// Net transfers the batch would make, as `(token, recipient, amount)`
+    pub transfers: Vec<(Address, Address, i128)>,
+    /// Fees the batch would add to each token's accumulated fees, as
+    /// `(token, amount)`
+    pub fees: Vec<(Address, i128)>,
+}
+
+/// Projected outcome of `create_remittance`, computed without moving tokens or
+/// writing state.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemittancePreview {
+    /// The remittance that would be stored, including the ID it would get
+    pub remittance: Remittance,
+    /// The payout amount the agent would receive (amount - fee)
+    pub payout_amount: i128,
+    /// Expiry-weighted part of the fee
+    pub expiry_fee: i128,
+    /// Platform fee waived by the sender's rolling volume discount
+    pub volume_discount: i128,
+    /// Headroom left under the sender's daily send limit, if one is configured
+    pub daily_limit_remaining: Option<i128>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DailyLimit {
@@ -468,3 +835,98 @@ pub struct PartialPayoutRecord {
     /// Ledger sequence when this disbursement occurred
     pub ledger_sequence: u32,
 }
+
+/// A sub-operator (e.g. a branch teller) authorized to act for an agent.
+///
+/// Sub-operators can confirm payouts for remittances assigned to their agent;
+/// funds are still paid out to the agent's own address. Each sub-operator has
+/// a rolling 24-hour limit on the total amount it can confirm.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubOperator {
+    /// Agent on whose behalf this sub-operator acts
+    pub agent: Address,
+    /// Maximum total remittance amount confirmable per 24-hour window
+    pub daily_limit: i128,
+    /// Ledger timestamp at which the current usage window started
+    pub window_start: u64,
+    /// Amount confirmed within the current window
+    pub used_in_window: i128,
+}
+
+/// An agent's opt-in for managed auto-settlement by the platform operator.
+///
+/// While enabled, the designated settlement operator may confirm payouts for
+/// the agent's remittances, subject to a per-remittance cap and a rolling
+/// 24-hour limit. The agent can revoke it at any time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoSettlement {
+    /// Operator address the agent authorized
+    pub operator: Address,
+    /// Largest single remittance amount the operator may settle
+    pub max_per_remittance: i128,
+    /// Maximum total amount the operator may settle per 24-hour window
+    pub daily_limit: i128,
+    /// Ledger timestamp at which the current usage window started
+    pub window_start: u64,
+    /// Amount settled within the current window
+    pub used_in_window: i128,
+}
+
+/// Structured reason recorded when the contract is paused.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PauseReason {
+    SecurityIncident,
+    SuspiciousActivity,
+    MaintenanceWindow,
+    ExternalThreat,
+}
+
+/// Optional pause reason; contract types cannot hold `Option<enum>` directly.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MaybePauseReason {
+    None,
+    Some(PauseReason),
+}
+
+/// Audit record written each time the contract is paused.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseRecord {
+    /// Pause sequence number, starting at 1
+    pub seq: u64,
+    /// Address that paused the contract
+    pub caller: Address,
+    /// Ledger timestamp of the pause
+    pub timestamp: u64,
+    pub reason: PauseReason,
+}
+
+/// Audit record written when a pause is lifted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnpauseRecord {
+    /// Address that unpaused the contract
+    pub caller: Address,
+    /// Ledger timestamp of the unpause
+    pub timestamp: u64,
+    /// Sequence number of the pause this resolved
+    pub pause_seq: u64,
+}
+
+/// Snapshot of the circuit breaker returned by `get_circuit_breaker_status`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitBreakerStatus {
+    pub is_paused: bool,
+    pub pause_reason: MaybePauseReason,
+    pub pause_timestamp: Option<u64>,
+    pub timelock_seconds: u64,
+    pub unpause_quorum: u32,
+    pub current_vote_count: u32,
+    pub last_unpause_at: Option<u64>,
+    pub cooldown_period_seconds: u64,
+}
@@ -7,7 +7,7 @@ use soroban_sdk::{Address, Env};
 
 use crate::{
     config::MAX_FEE_BPS,
-    get_remittance, is_agent_registered, is_paused, is_user_blacklisted, ContractError,
+    get_remittance, is_agent_registered, is_paused, is_user_blacklisted, ContractError, Expiry,
     RemittanceStatus,
 };
 
@@ -65,10 +65,9 @@ pub fn validate_remittance_pending(remittance: &crate::Remittance) -> Result<(),
 }
 
 /// Validates that a settlement has not expired.
-pub fn validate_settlement_not_expired(env: &Env, expiry: Option<u64>) -> Result<(), ContractError> {
-    if let Some(expiry_time) = expiry {
-        let current_time = env.ledger().timestamp();
-        if current_time > expiry_time {
+pub fn validate_settlement_not_expired(env: &Env, expiry: Option<Expiry>) -> Result<(), ContractError> {
+    if let Some(expiry) = expiry {
+        if expiry.is_reached(env) {
             return Err(ContractError::SettlementExpired);
         }
     }
@@ -76,8 +75,8 @@ pub fn validate_settlement_not_expired(env: &Env, expiry: Option<u64>) -> Result
 }
 
 /// Validates that a settlement has not been executed before (duplicate check).
-pub fn validate_no_duplicate_settlement(env: &Env, remittance_id: u64) -> Result<(), ContractError> {
-    if crate::has_settlement_hash(env, remittance_id) {
+pub fn validate_no_duplicate_settlement(remittance: &crate::Remittance) -> Result<(), ContractError> {
+    if remittance.is_settlement_executed() {
         return Err(ContractError::DuplicateSettlement);
     }
     Ok(())
@@ -127,11 +126,15 @@ pub fn validate_create_remittance_request(
 ) -> Result<(), ContractError> {
     // Address type is guaranteed valid by the Soroban SDK runtime; no further
     // address validation is required or possible at the contract level.
+    validate_not_paused(env)?;
     validate_amount(amount)?;
+    crate::limits::check_amount(env, amount)?;
     validate_agent_registered(env, agent)?;
+    crate::collateral::check_assignment(env, agent, amount)?;
     if is_user_blacklisted(env, sender) {
         return Err(ContractError::UserBlacklisted);
     }
+    crate::maintenance::ensure_open(env)?;
     Ok(())
 }
 
@@ -147,8 +150,8 @@ pub fn validate_confirm_payout_request(
     if remittance.status != RemittanceStatus::Pending {
         return Err(ContractError::InvalidStatus);
     }
-    validate_no_duplicate_settlement(env, remittance_id)?;
-    validate_settlement_not_expired(env, remittance.expiry)?;
+    validate_no_duplicate_settlement(&remittance)?;
+    validate_settlement_not_expired(env, remittance.expiry.into())?;
     // Address type is guaranteed valid by the Soroban SDK runtime; no further
     // address validation is required or possible at the contract level.
     Ok(remittance)
@@ -249,6 +252,54 @@ mod tests {
         assert_eq!(validate_amount(-1000), Err(ContractError::InvalidAmount));
     }
 
+    #[test]
+    fn test_validate_settlement_not_expired_time_and_ledger() {
+        use soroban_sdk::testutils::Ledger;
+
+        let env = Env::default();
+        let contract_id = env.register(crate::SwiftRemitContract, ());
+        env.ledger().set_timestamp(1_000);
+        env.ledger().set_sequence_number(500);
+
+        env.as_contract(&contract_id, || {
+            assert!(validate_settlement_not_expired(&env, None).is_ok());
+            assert!(validate_settlement_not_expired(&env, Some(Expiry::Time(1_000))).is_ok());
+            assert!(validate_settlement_not_expired(&env, Some(Expiry::Ledger(500))).is_ok());
+            assert_eq!(
+                validate_settlement_not_expired(&env, Some(Expiry::Time(999))),
+                Err(ContractError::SettlementExpired)
+            );
+            assert_eq!(
+                validate_settlement_not_expired(&env, Some(Expiry::Ledger(499))),
+                Err(ContractError::SettlementExpired)
+            );
+        });
+    }
+
+    #[test]
+    fn test_validate_settlement_not_expired_with_tolerance() {
+        use soroban_sdk::testutils::Ledger;
+
+        let env = Env::default();
+        let contract_id = env.register(crate::SwiftRemitContract, ());
+        env.ledger().set_timestamp(1_000);
+        env.ledger().set_sequence_number(500);
+
+        env.as_contract(&contract_id, || {
+            crate::storage::set_expiry_tolerance(&env, 30);
+            assert!(validate_settlement_not_expired(&env, Some(Expiry::Time(970))).is_ok());
+            assert_eq!(
+                validate_settlement_not_expired(&env, Some(Expiry::Time(969))),
+                Err(ContractError::SettlementExpired)
+            );
+            // Ledger sequences are exact and ignore the tolerance
+            assert_eq!(
+                validate_settlement_not_expired(&env, Some(Expiry::Ledger(499))),
+                Err(ContractError::SettlementExpired)
+            );
+        });
+    }
+
     #[test]
     fn test_validate_fees_available_valid() {
         assert!(validate_fees_available(1).is_ok());
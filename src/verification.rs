@@ -45,13 +45,17 @@ mod tests {
             amount: 5000,
             fee: 125,
             status: crate::RemittanceStatus::Pending,
-            expiry: None,
+            expiry: crate::MaybeExpiry::None,
             settlement_config: crate::MaybeSettlementConfig::None,
             token: Address::generate(&env),
             created_at: 0,
             failed_at: None,
             dispute_evidence: crate::MaybeBytes32::None,
             expires_at: None,
+            accepted_at: None,
+            completed_at: None,
+            memo: None,
+            corridor: crate::MaybeCorridor::None,
         };
 
         let commitment = compute_payout_commitment(&env, &remittance);
@@ -0,0 +1,84 @@
+//! Daily volume history for charts and spike monitoring.
+//!
+//! Created volume, settled volume, and fee revenue are accumulated into one
+//! [`VolumeBucket`] per day of [`VOLUME_BUCKET_SECONDS`]. Buckets live in a
+//! ring of [`VOLUME_HISTORY_BUCKETS`] slots, so the history covers that many
+//! days and each slot is overwritten when its day comes round again.
+//!
+//! Milestones arrive through the same hook as the performance metrics, so
+//! every creation and completion path is counted. Monitors can read the
+//! history with `get_volume_history` and compare the current day against the
+//! trailing days to spot abnormal spikes before pausing the contract.
+
+use soroban_sdk::{contracttype, Env, Vec};
+
+use crate::config::{VOLUME_BUCKET_SECONDS, VOLUME_HISTORY_BUCKETS};
+use crate::performance::PerformanceEvent;
+use crate::storage::{get_volume_bucket, set_volume_bucket};
+use crate::Remittance;
+
+/// Volume recorded during one day.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VolumeBucket {
+    /// Day index (`timestamp / VOLUME_BUCKET_SECONDS`)
+    pub day: u64,
+    /// Value of remittances created during the day
+    pub created_volume: i128,
+    /// Value of remittances completed during the day
+    pub settled_volume: i128,
+    /// Fees earned on remittances completed during the day
+    pub fee_revenue: i128,
+}
+
+fn current_day(env: &Env) -> u64 {
+    env.ledger().timestamp() / VOLUME_BUCKET_SECONDS
+}
+
+fn slot(day: u64) -> u32 {
+    (day % VOLUME_HISTORY_BUCKETS as u64) as u32
+}
+
+/// Returns the bucket for `day`, or an empty one if its slot holds another day.
+fn bucket(env: &Env, day: u64) -> VolumeBucket {
+    match get_volume_bucket(env, slot(day)) {
+        Some(bucket) if bucket.day == day => bucket,
+        _ => VolumeBucket {
+            day,
+            ..Default::default()
+        },
+    }
+}
+
+/// Adds a lifecycle milestone to the current day's bucket.
+pub fn record(env: &Env, event: PerformanceEvent, remittance: &Remittance) {
+    let day = current_day(env);
+    let mut current = bucket(env, day);
+    match event {
+        PerformanceEvent::Created => {
+            current.created_volume = current.created_volume.saturating_add(remittance.amount);
+        }
+        PerformanceEvent::Completed => {
+            current.settled_volume = current.settled_volume.saturating_add(remittance.amount);
+            current.fee_revenue = current.fee_revenue.saturating_add(remittance.fee);
+        }
+        PerformanceEvent::Expired | PerformanceEvent::Disputed => return,
+    }
+    set_volume_bucket(env, slot(day), &current);
+}
+
+/// Returns the buckets for the last `days` days, oldest first and ending with
+/// today. Days without activity are returned as empty buckets, and `days` is
+/// capped at [`VOLUME_HISTORY_BUCKETS`].
+pub fn history(env: &Env, days: u32) -> Vec<VolumeBucket> {
+    let mut buckets = Vec::new(env);
+    let days = days.min(VOLUME_HISTORY_BUCKETS) as u64;
+    if days == 0 {
+        return buckets;
+    }
+    let today = current_day(env);
+    for day in today.saturating_sub(days - 1)..=today {
+        buckets.push_back(bucket(env, day));
+    }
+    buckets
+}
@@ -0,0 +1,275 @@
+//! Signed off-chain payout vouchers.
+//!
+//! Agents in low-connectivity areas can authorize a settlement without
+//! submitting a transaction themselves: the agent signs a payout voucher with
+//! an ed25519 key registered on-chain, and anyone holding the voucher can
+//! submit it to `settle_with_voucher`.
+//!
+//! ## Voucher Payload (canonical)
+//!
+//! The agent signs the following bytes, concatenated without separators:
+//!
+//! 1. `VOUCHER_DOMAIN`   — ASCII bytes `SWIFTREMIT_VOUCHER_V2`
+//! 2. `contract`         — Address of this contract, XDR-encoded bytes
+//! 3. `remittance_id`    — u64, big-endian 8 bytes
+//! 4. `amount`           — i128, big-endian 16 bytes
+//! 5. `nonce`            — u64, big-endian 8 bytes
+//! 6. `proof`            — optional 32 bytes
+//! 7. `recipient_hash`   — optional recipient details hash, 32 bytes
+//! 8. `pickup_secret`    — optional SHA-256 digest of the pickup secret, 32 bytes
+//!
+//! Optional fields are encoded as a `0x00` byte when absent, or a `0x01` byte
+//! followed by the 32 bytes when present.
+//!
+//! The domain tag and contract address prevent a voucher from being replayed
+//! against another contract; each nonce can be consumed once per agent.
+//! Settlement evidence is signed too, so a relayer holding the voucher cannot
+//! substitute its own proof, recipient details, or pickup secret.
+//!
+//! ## Passkey Signatures
+//!
+//! Agents and recipients on passkey-based mobile wallets hold a secp256r1
+//! (P-256) key rather than an ed25519 one. An agent may register a passkey
+//! instead of an ed25519 key; its vouchers are then ECDSA signatures over the
+//! SHA-256 digest of the same payload. Keys are 65-byte uncompressed SEC-1
+//! points, and signatures must be low-S normalized, as the host rejects
+//! high-S signatures.
+//!
+//! ## Escrow Claim Codes
+//!
+//! A direct payout (escrow) may carry the recipient's passkey, letting the
+//! recipient release it to their address without an ed25519 Stellar key. The
+//! claim code is a passkey signature over the SHA-256 digest of:
+//!
+//! 1. `CLAIM_DOMAIN`   — ASCII bytes `SWIFTREMIT_CLAIM_V1`
+//! 2. `contract`       — Address of this contract, XDR-encoded bytes
+//! 3. `transfer_id`    — u64, big-endian 8 bytes
+//!
+//! An escrow can only be released once, so claim codes need no nonce.
+
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+use crate::storage::{
+    get_escrow_claim_key, get_passkey_voucher_key, get_voucher_key, is_voucher_nonce_used,
+    mark_voucher_nonce_used,
+};
+use crate::ContractError;
+
+/// Domain separation tag prefixed to every voucher payload.
+pub const VOUCHER_DOMAIN: &[u8] = b"SWIFTREMIT_VOUCHER_V2";
+
+/// Domain separation tag prefixed to every escrow claim payload.
+pub const CLAIM_DOMAIN: &[u8] = b"SWIFTREMIT_CLAIM_V1";
+
+/// Leading byte of an uncompressed SEC-1 point.
+const SEC1_UNCOMPRESSED_TAG: u8 = 0x04;
+
+/// Checks that a passkey public key is an uncompressed SEC-1 point.
+pub fn validate_passkey(public_key: &BytesN<65>) -> Result<(), ContractError> {
+    if public_key.get(0) != Some(SEC1_UNCOMPRESSED_TAG) {
+        return Err(ContractError::InvalidPasskey);
+    }
+    Ok(())
+}
+
+fn verify_passkey(env: &Env, public_key: &BytesN<65>, payload: &Bytes, signature: &BytesN<64>) {
+    let digest = env.crypto().sha256(payload);
+    env.crypto().secp256r1_verify(public_key, &digest, signature);
+}
+
+/// Settlement evidence an agent signs into a payout voucher.
+pub struct VoucherEvidence {
+    pub proof: Option<BytesN<32>>,
+    pub recipient_details_hash: Option<BytesN<32>>,
+    pub pickup_secret: Option<Bytes>,
+}
+
+/// Builds the canonical payload an agent signs for a payout voucher.
+pub fn voucher_payload(
+    env: &Env,
+    remittance_id: u64,
+    amount: i128,
+    nonce: u64,
+    evidence: &VoucherEvidence,
+) -> Bytes {
+    let mut buf = Bytes::from_slice(env, VOUCHER_DOMAIN);
+    buf.append(&env.current_contract_address().to_xdr(env));
+    buf.extend_from_array(&remittance_id.to_be_bytes());
+    buf.extend_from_array(&amount.to_be_bytes());
+    buf.extend_from_array(&nonce.to_be_bytes());
+    append_optional(&mut buf, evidence.proof.clone());
+    append_optional(&mut buf, evidence.recipient_details_hash.clone());
+    append_optional(
+        &mut buf,
+        evidence
+            .pickup_secret
+            .as_ref()
+            .map(|secret| env.crypto().sha256(secret).to_bytes()),
+    );
+    buf
+}
+
+/// Appends an optional 32-byte field: a presence byte, then the bytes.
+fn append_optional(buf: &mut Bytes, field: Option<BytesN<32>>) {
+    match field {
+        Some(bytes) => {
+            buf.push_back(1);
+            buf.append(&bytes.into());
+        }
+        None => buf.push_back(0),
+    }
+}
+
+/// Builds the canonical payload a recipient signs to claim an escrow.
+pub fn claim_payload(env: &Env, transfer_id: u64) -> Bytes {
+    let mut buf = Bytes::from_slice(env, CLAIM_DOMAIN);
+    buf.append(&env.current_contract_address().to_xdr(env));
+    buf.extend_from_array(&transfer_id.to_be_bytes());
+    buf
+}
+
+/// Verifies a voucher signed by `agent` and consumes its nonce.
+///
+/// The signature is checked against the agent's passkey if one is
+/// registered, otherwise against its ed25519 key.
+///
+/// # Errors
+///
+/// * `ContractError::VoucherKeyNotSet` - Agent has no registered signing key
+/// * `ContractError::VoucherNonceUsed` - Nonce was already consumed
+///
+/// # Panics
+///
+/// Traps if the signature does not verify, as the host verification
+/// functions do not return a recoverable error.
+pub fn consume_voucher(
+    env: &Env,
+    agent: &Address,
+    remittance_id: u64,
+    amount: i128,
+    nonce: u64,
+    evidence: &VoucherEvidence,
+    signature: &BytesN<64>,
+) -> Result<(), ContractError> {
+    let passkey = get_passkey_voucher_key(env, agent);
+    let ed25519_key = get_voucher_key(env, agent);
+    if passkey.is_none() && ed25519_key.is_none() {
+        return Err(ContractError::VoucherKeyNotSet);
+    }
+    if is_voucher_nonce_used(env, agent, nonce) {
+        return Err(ContractError::VoucherNonceUsed);
+    }
+
+    let payload = voucher_payload(env, remittance_id, amount, nonce, evidence);
+    if let Some(public_key) = passkey {
+        verify_passkey(env, &public_key, &payload, signature);
+    } else if let Some(public_key) = ed25519_key {
+        env.crypto().ed25519_verify(&public_key, &payload, signature);
+    }
+
+    mark_voucher_nonce_used(env, agent, nonce);
+    Ok(())
+}
+
+/// Verifies a claim code for an escrow carrying a recipient passkey.
+///
+/// # Errors
+///
+/// * `ContractError::ClaimKeyNotSet` - Escrow has no recipient passkey
+///
+/// # Panics
+///
+/// Traps if the signature does not verify.
+pub fn verify_escrow_claim(
+    env: &Env,
+    transfer_id: u64,
+    signature: &BytesN<64>,
+) -> Result<(), ContractError> {
+    let public_key = get_escrow_claim_key(env, transfer_id).ok_or(ContractError::ClaimKeyNotSet)?;
+    verify_passkey(env, &public_key, &claim_payload(env, transfer_id), signature);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_voucher_payload_layout() {
+        let env = Env::default();
+        let contract_id = env.register(crate::SwiftRemitContract, ());
+        let none = VoucherEvidence {
+            proof: None,
+            recipient_details_hash: None,
+            pickup_secret: None,
+        };
+
+        let payload = env.as_contract(&contract_id, || voucher_payload(&env, 7, 1_000, 42, &none));
+        let other_nonce =
+            env.as_contract(&contract_id, || voucher_payload(&env, 7, 1_000, 43, &none));
+
+        let domain_len = VOUCHER_DOMAIN.len() as u32;
+        assert_eq!(payload.slice(0..domain_len), Bytes::from_slice(&env, VOUCHER_DOMAIN));
+
+        // Absent evidence fields are one zero byte each
+        let len = payload.len() - 3;
+        assert_eq!(payload.slice(len..), Bytes::from_array(&env, &[0, 0, 0]));
+        assert_eq!(payload.slice(len - 8..len), Bytes::from_array(&env, &42u64.to_be_bytes()));
+        assert_eq!(
+            payload.slice(len - 24..len - 8),
+            Bytes::from_array(&env, &1_000i128.to_be_bytes())
+        );
+        assert_eq!(
+            payload.slice(len - 32..len - 24),
+            Bytes::from_array(&env, &7u64.to_be_bytes())
+        );
+        assert_ne!(payload, other_nonce);
+    }
+
+    #[test]
+    fn test_voucher_payload_binds_evidence() {
+        let env = Env::default();
+        let contract_id = env.register(crate::SwiftRemitContract, ());
+        let secret = Bytes::from_slice(&env, b"open sesame");
+        let evidence = VoucherEvidence {
+            proof: None,
+            recipient_details_hash: Some(BytesN::from_array(&env, &[5; 32])),
+            pickup_secret: Some(secret.clone()),
+        };
+
+        let payload =
+            env.as_contract(&contract_id, || voucher_payload(&env, 7, 1_000, 42, &evidence));
+
+        let len = payload.len();
+        let digest: Bytes = env.crypto().sha256(&secret).into();
+        assert_eq!(payload.slice(len - 32..), digest);
+        assert_eq!(payload.get(len - 33), Some(1));
+        assert_eq!(payload.slice(len - 65..len - 33), Bytes::from_array(&env, &[5; 32]));
+        assert_eq!(payload.get(len - 66), Some(1));
+        assert_eq!(payload.get(len - 67), Some(0));
+    }
+
+    #[test]
+    fn test_claim_payload_layout() {
+        let env = Env::default();
+        let contract_id = env.register(crate::SwiftRemitContract, ());
+
+        let payload = env.as_contract(&contract_id, || claim_payload(&env, 9));
+        let domain_len = CLAIM_DOMAIN.len() as u32;
+        assert_eq!(payload.slice(0..domain_len), Bytes::from_slice(&env, CLAIM_DOMAIN));
+        let len = payload.len();
+        assert_eq!(payload.slice(len - 8..len), Bytes::from_array(&env, &9u64.to_be_bytes()));
+    }
+
+    #[test]
+    fn test_passkey_must_be_uncompressed_point() {
+        let env = Env::default();
+        let mut raw = [0u8; 65];
+        assert_eq!(
+            validate_passkey(&BytesN::from_array(&env, &raw)),
+            Err(ContractError::InvalidPasskey)
+        );
+        raw[0] = SEC1_UNCOMPRESSED_TAG;
+        assert!(validate_passkey(&BytesN::from_array(&env, &raw)).is_ok());
+    }
+}